@@ -1,79 +1,274 @@
 //! 加密模块
 //!
 //! 提供 AES-256-GCM 加密/解密功能，用于账户导入导出的加密保护。
+//! 密钥派生算法由文件版本决定（见 `versions`）：v1/v2 为 PBKDF2，v3 起为 Argon2id。
 
+mod stream;
 mod versions;
 
-pub use versions::{get_current_iterations, get_pbkdf2_iterations, CURRENT_FILE_VERSION};
+pub use stream::{decrypt_stream, encrypt_stream, STREAM_CHUNK_SIZE};
+pub use versions::{get_kdf, get_pbkdf2_iterations, Kdf, CURRENT_FILE_VERSION};
 
 use aes_gcm::{
     aead::{Aead, KeyInit},
     Aes256Gcm, Nonce,
 };
+use argon2::{Algorithm, Argon2, Params, Version};
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use pbkdf2::pbkdf2_hmac_array;
 use rand::RngCore;
 use sha2::Sha256;
 
 use crate::error::{CoreError, CoreResult};
+use versions::{
+    VERSION_3_ARGON2_ITERATIONS, VERSION_3_ARGON2_MEMORY_KIB, VERSION_3_ARGON2_PARALLELISM,
+};
 
-// 从版本管理自动获取当前版本的迭代次数
-const PBKDF2_ITERATIONS: u32 = get_current_iterations();
 const SALT_LENGTH: usize = 16;
 const NONCE_LENGTH: usize = 12;
 const KEY_LENGTH: usize = 32; // AES-256
 
+/// Argon2id 盐值前缀，完整格式为 `argon2id$m=<KiB>,t=<迭代>,p=<并行度>$<Base64 盐值>`
+const ARGON2_SALT_PREFIX: &str = "argon2id";
+
+/// 导入文件中 Argon2 参数的上限，防止构造的文件在校验密码前耗尽内存或 CPU
+const MAX_ARGON2_MEMORY_KIB: u32 = 256 * 1024;
+const MAX_ARGON2_ITERATIONS: u32 = 10;
+const MAX_ARGON2_PARALLELISM: u32 = 8;
+
+/// Argon2id 参数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Argon2Params {
+    /// 内存开销（KiB）
+    pub memory_kib: u32,
+    /// 迭代次数
+    pub iterations: u32,
+    /// 并行度
+    pub parallelism: u32,
+}
+
+impl Default for Argon2Params {
+    fn default() -> Self {
+        Self {
+            memory_kib: VERSION_3_ARGON2_MEMORY_KIB,
+            iterations: VERSION_3_ARGON2_ITERATIONS,
+            parallelism: VERSION_3_ARGON2_PARALLELISM,
+        }
+    }
+}
+
+impl Argon2Params {
+    /// 将参数与盐值编码为一个字符串，随密文一起保存
+    fn encode_salt(self, salt: &[u8]) -> String {
+        format!(
+            "{ARGON2_SALT_PREFIX}$m={},t={},p={}${}",
+            self.memory_kib,
+            self.iterations,
+            self.parallelism,
+            BASE64.encode(salt)
+        )
+    }
+
+    /// 从编码后的盐值中解析参数与原始盐值
+    fn decode_salt(encoded: &str) -> CoreResult<(Self, Vec<u8>)> {
+        let invalid = || CoreError::SerializationError(format!("Invalid Argon2 salt: {encoded}"));
+
+        let mut parts = encoded.split('$');
+        if parts.next() != Some(ARGON2_SALT_PREFIX) {
+            return Err(invalid());
+        }
+        let (Some(params), Some(salt_b64), None) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+
+        let (mut memory_kib, mut iterations, mut parallelism) = (None, None, None);
+        for pair in params.split(',') {
+            let (key, value) = pair.split_once('=').ok_or_else(invalid)?;
+            let value: u32 = value.parse().map_err(|_| invalid())?;
+            match key {
+                "m" => memory_kib = Some(value),
+                "t" => iterations = Some(value),
+                "p" => parallelism = Some(value),
+                _ => return Err(invalid()),
+            }
+        }
+
+        let params = Self {
+            memory_kib: memory_kib.ok_or_else(invalid)?,
+            iterations: iterations.ok_or_else(invalid)?,
+            parallelism: parallelism.ok_or_else(invalid)?,
+        };
+        if params.memory_kib > MAX_ARGON2_MEMORY_KIB
+            || params.iterations > MAX_ARGON2_ITERATIONS
+            || params.parallelism > MAX_ARGON2_PARALLELISM
+        {
+            return Err(CoreError::ValidationError(format!(
+                "Argon2 params out of range: m={}, t={}, p={} \
+                 (max m={MAX_ARGON2_MEMORY_KIB}, t={MAX_ARGON2_ITERATIONS}, p={MAX_ARGON2_PARALLELISM})",
+                params.memory_kib, params.iterations, params.parallelism
+            )));
+        }
+        let salt = BASE64
+            .decode(salt_b64)
+            .map_err(|e| CoreError::SerializationError(format!("Invalid salt: {e}")))?;
+        Ok((params, salt))
+    }
+}
+
 /// 从密码派生加密密钥（支持自定义迭代次数）
 fn derive_key_with_iterations(password: &str, salt: &[u8], iterations: u32) -> [u8; KEY_LENGTH] {
     pbkdf2_hmac_array::<Sha256, KEY_LENGTH>(password.as_bytes(), salt, iterations)
 }
 
-/// 从密码派生加密密钥（使用默认迭代次数）
-fn derive_key(password: &str, salt: &[u8]) -> [u8; KEY_LENGTH] {
-    derive_key_with_iterations(password, salt, PBKDF2_ITERATIONS)
+/// 使用 Argon2id 从密码派生加密密钥
+fn derive_key_argon2(
+    password: &str,
+    salt: &[u8],
+    params: Argon2Params,
+) -> CoreResult<[u8; KEY_LENGTH]> {
+    let params = Params::new(
+        params.memory_kib,
+        params.iterations,
+        params.parallelism,
+        Some(KEY_LENGTH),
+    )
+    .map_err(|e| CoreError::SerializationError(format!("Invalid Argon2 params: {e}")))?;
+
+    let mut key = [0u8; KEY_LENGTH];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .map_err(|e| CoreError::SerializationError(format!("Key derivation failed: {e}")))?;
+    Ok(key)
+}
+
+/// 使用派生密钥加密，返回 (`nonce`, `ciphertext`)
+fn seal(key: &[u8; KEY_LENGTH], plaintext: &[u8]) -> CoreResult<([u8; NONCE_LENGTH], Vec<u8>)> {
+    let mut nonce_bytes = [0u8; NONCE_LENGTH];
+    rand::rng().fill_bytes(&mut nonce_bytes);
+
+    let cipher = Aes256Gcm::new_from_slice(key)
+        .map_err(|e| CoreError::SerializationError(format!("Failed to create cipher: {e}")))?;
+    let nonce = Nonce::from_slice(&nonce_bytes);
+
+    let ciphertext = cipher
+        .encrypt(nonce, plaintext)
+        .map_err(|e| CoreError::SerializationError(format!("Encryption failed: {e}")))?;
+
+    Ok((nonce_bytes, ciphertext))
+}
+
+/// 使用派生密钥解密（Base64 编码的 nonce 与密文）
+fn open(key: &[u8; KEY_LENGTH], nonce_b64: &str, ciphertext_b64: &str) -> CoreResult<Vec<u8>> {
+    let nonce_bytes = BASE64
+        .decode(nonce_b64)
+        .map_err(|e| CoreError::SerializationError(format!("Invalid nonce: {e}")))?;
+    let ciphertext = BASE64
+        .decode(ciphertext_b64)
+        .map_err(|e| CoreError::SerializationError(format!("Invalid ciphertext: {e}")))?;
+    open_raw(key, &nonce_bytes, &ciphertext)
+}
+
+/// 使用派生密钥解密
+fn open_raw(key: &[u8; KEY_LENGTH], nonce_bytes: &[u8], ciphertext: &[u8]) -> CoreResult<Vec<u8>> {
+    if nonce_bytes.len() != NONCE_LENGTH {
+        return Err(CoreError::SerializationError(format!(
+            "Invalid nonce length: {}",
+            nonce_bytes.len()
+        )));
+    }
+
+    let cipher = Aes256Gcm::new_from_slice(key)
+        .map_err(|e| CoreError::SerializationError(format!("Failed to create cipher: {e}")))?;
+    let nonce = Nonce::from_slice(nonce_bytes);
+
+    cipher.decrypt(nonce, ciphertext).map_err(|_| {
+        CoreError::SerializationError(
+            "Decryption failed: invalid password or corrupted data".to_string(),
+        )
+    })
 }
 
-/// 加密数据
+/// 加密数据（使用 `CURRENT_FILE_VERSION` 对应的密钥派生算法）
 ///
 /// # Arguments
 /// * `plaintext` - 要加密的明文数据
 /// * `password` - 加密密码
 ///
 /// # Returns
-/// 返回 (`salt_base64`, `nonce_base64`, `ciphertext_base64`) 元组
+/// 返回 (`salt`, `nonce_base64`, `ciphertext_base64`) 元组。
+/// Argon2id 的盐值带有算法标记与参数（`argon2id$m=..,t=..,p=..$<Base64>`），
+/// PBKDF2 的盐值为纯 Base64。
 pub fn encrypt(plaintext: &[u8], password: &str) -> CoreResult<(String, String, String)> {
-    // 生成随机盐和 nonce
-    let mut salt = [0u8; SALT_LENGTH];
-    let mut nonce_bytes = [0u8; NONCE_LENGTH];
-    rand::rng().fill_bytes(&mut salt);
-    rand::rng().fill_bytes(&mut nonce_bytes);
+    let (salt, nonce, ciphertext) = encrypt_raw(plaintext, password)?;
+    Ok((salt, BASE64.encode(nonce), BASE64.encode(ciphertext)))
+}
 
-    // 派生密钥
-    let key = derive_key(password, &salt);
+/// 按当前版本加密，返回 (`salt`, `nonce`, `ciphertext`)
+fn encrypt_raw(
+    plaintext: &[u8],
+    password: &str,
+) -> CoreResult<(String, [u8; NONCE_LENGTH], Vec<u8>)> {
+    let (salt, key) = derive_new_key(password)?;
+    let (nonce, ciphertext) = seal(&key, plaintext)?;
+    Ok((salt, nonce, ciphertext))
+}
 
-    // 创建加密器
-    let cipher = Aes256Gcm::new_from_slice(&key)
-        .map_err(|e| CoreError::SerializationError(format!("Failed to create cipher: {e}")))?;
-    let nonce = Nonce::from_slice(&nonce_bytes);
+/// 生成随机盐值并按当前版本派生密钥，返回 (`salt`, `key`)
+fn derive_new_key(password: &str) -> CoreResult<(String, [u8; KEY_LENGTH])> {
+    let mut salt = [0u8; SALT_LENGTH];
+    rand::rng().fill_bytes(&mut salt);
 
-    // 加密
-    let ciphertext = cipher
-        .encrypt(nonce, plaintext)
-        .map_err(|e| CoreError::SerializationError(format!("Encryption failed: {e}")))?;
+    let (salt, key) = match get_kdf(CURRENT_FILE_VERSION) {
+        Some(Kdf::Pbkdf2 { iterations }) => (
+            BASE64.encode(salt),
+            derive_key_with_iterations(password, &salt, iterations),
+        ),
+        Some(Kdf::Argon2id) => {
+            let params = Argon2Params::default();
+            (
+                params.encode_salt(&salt),
+                derive_key_argon2(password, &salt, params)?,
+            )
+        }
+        None => {
+            return Err(CoreError::SerializationError(format!(
+                "Unsupported file version: {CURRENT_FILE_VERSION}"
+            )))
+        }
+    };
+    Ok((salt, key))
+}
 
-    Ok((
-        BASE64.encode(salt),
-        BASE64.encode(nonce_bytes),
-        BASE64.encode(ciphertext),
-    ))
+/// 按文件版本派生密钥
+fn derive_key_for_version(
+    password: &str,
+    salt: &str,
+    version: u32,
+) -> CoreResult<[u8; KEY_LENGTH]> {
+    match get_kdf(version) {
+        Some(Kdf::Pbkdf2 { iterations }) => {
+            let salt = BASE64
+                .decode(salt)
+                .map_err(|e| CoreError::SerializationError(format!("Invalid salt: {e}")))?;
+            Ok(derive_key_with_iterations(password, &salt, iterations))
+        }
+        Some(Kdf::Argon2id) => {
+            let (params, salt) = Argon2Params::decode_salt(salt)?;
+            derive_key_argon2(password, &salt, params)
+        }
+        None => Err(CoreError::SerializationError(format!(
+            "Unsupported file version: {version}"
+        ))),
+    }
 }
 
-/// 解密数据
+/// 解密数据（按 `CURRENT_FILE_VERSION` 解密）
 ///
 /// # Arguments
 /// * `ciphertext_b64` - Base64 编码的密文
 /// * `password` - 解密密码
-/// * `salt_b64` - Base64 编码的盐值
+/// * `salt` - `encrypt` 返回的盐值
 /// * `nonce_b64` - Base64 编码的 nonce
 ///
 /// # Returns
@@ -81,18 +276,99 @@ pub fn encrypt(plaintext: &[u8], password: &str) -> CoreResult<(String, String,
 pub fn decrypt(
     ciphertext_b64: &str,
     password: &str,
-    salt_b64: &str,
+    salt: &str,
     nonce_b64: &str,
 ) -> CoreResult<Vec<u8>> {
-    decrypt_with_iterations(
+    decrypt_with_version(
         ciphertext_b64,
         password,
-        salt_b64,
+        salt,
         nonce_b64,
-        PBKDF2_ITERATIONS,
+        CURRENT_FILE_VERSION,
     )
 }
 
+/// 按文件版本选择密钥派生算法解密数据
+///
+/// # Arguments
+/// * `ciphertext_b64` - Base64 编码的密文
+/// * `password` - 解密密码
+/// * `salt` - 盐值（v3 起带有 Argon2 参数）
+/// * `nonce_b64` - Base64 编码的 nonce
+/// * `version` - 文件版本号
+///
+/// # Returns
+/// 返回解密后的明文数据
+pub fn decrypt_with_version(
+    ciphertext_b64: &str,
+    password: &str,
+    salt: &str,
+    nonce_b64: &str,
+    version: u32,
+) -> CoreResult<Vec<u8>> {
+    let key = derive_key_for_version(password, salt, version)?;
+    open(&key, nonce_b64, ciphertext_b64)
+}
+
+/// 自描述密文的魔数
+const PAYLOAD_MAGIC: &[u8; 4] = b"DNSO";
+
+/// 加密数据并打包为自描述密文
+///
+/// 格式（整体 Base64 编码）：
+/// `"DNSO" | 版本号 (1 字节) | 盐值长度 (1 字节) | 盐值 | nonce (12 字节) | 密文`，
+/// 盐值为 `encrypt` 返回的字符串（Argon2id 时包含参数）。
+/// 使用 `decrypt_auto` 解密，调用方无需关心版本与密钥派生参数。
+pub fn encrypt_payload(plaintext: &[u8], password: &str) -> CoreResult<String> {
+    let (salt, nonce, ciphertext) = encrypt_raw(plaintext, password)?;
+    let version = u8::try_from(CURRENT_FILE_VERSION).map_err(|_| {
+        CoreError::SerializationError(format!(
+            "File version {CURRENT_FILE_VERSION} does not fit in payload header"
+        ))
+    })?;
+    let salt_len = u8::try_from(salt.len())
+        .map_err(|_| CoreError::SerializationError("Salt too long".to_string()))?;
+
+    let mut payload =
+        Vec::with_capacity(PAYLOAD_MAGIC.len() + 2 + salt.len() + nonce.len() + ciphertext.len());
+    payload.extend_from_slice(PAYLOAD_MAGIC);
+    payload.push(version);
+    payload.push(salt_len);
+    payload.extend_from_slice(salt.as_bytes());
+    payload.extend_from_slice(&nonce);
+    payload.extend_from_slice(&ciphertext);
+    Ok(BASE64.encode(payload))
+}
+
+/// 解密 `encrypt_payload` 生成的自描述密文
+///
+/// 从头部读取版本号，经 `versions` 查出密钥派生算法后解密。
+/// 没有头部的旧数据请使用 `decrypt_with_version`。
+pub fn decrypt_auto(payload_b64: &str, password: &str) -> CoreResult<Vec<u8>> {
+    let invalid =
+        |detail: &str| CoreError::SerializationError(format!("Invalid payload: {detail}"));
+
+    let payload = BASE64
+        .decode(payload_b64)
+        .map_err(|e| CoreError::SerializationError(format!("Invalid payload: {e}")))?;
+    let rest = payload
+        .strip_prefix(PAYLOAD_MAGIC)
+        .ok_or_else(|| invalid("missing version header"))?;
+
+    let (&version, rest) = rest.split_first().ok_or_else(|| invalid("truncated"))?;
+    let (&salt_len, rest) = rest.split_first().ok_or_else(|| invalid("truncated"))?;
+    let salt_len = usize::from(salt_len);
+    if rest.len() < salt_len + NONCE_LENGTH {
+        return Err(invalid("truncated"));
+    }
+    let (salt, rest) = rest.split_at(salt_len);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LENGTH);
+    let salt = std::str::from_utf8(salt).map_err(|_| invalid("salt is not UTF-8"))?;
+
+    let key = derive_key_for_version(password, salt, u32::from(version))?;
+    open_raw(&key, nonce, ciphertext)
+}
+
 /// 使用自定义迭代次数解密数据（用于向后兼容）
 ///
 /// # Arguments
@@ -111,29 +387,58 @@ pub fn decrypt_with_iterations(
     nonce_b64: &str,
     iterations: u32,
 ) -> CoreResult<Vec<u8>> {
-    // 解码 Base64
     let salt = BASE64
         .decode(salt_b64)
         .map_err(|e| CoreError::SerializationError(format!("Invalid salt: {e}")))?;
-    let nonce_bytes = BASE64
-        .decode(nonce_b64)
-        .map_err(|e| CoreError::SerializationError(format!("Invalid nonce: {e}")))?;
-    let ciphertext = BASE64
-        .decode(ciphertext_b64)
-        .map_err(|e| CoreError::SerializationError(format!("Invalid ciphertext: {e}")))?;
 
     // 使用指定迭代次数派生密钥
     let key = derive_key_with_iterations(password, &salt, iterations);
+    open(&key, nonce_b64, ciphertext_b64)
+}
 
-    // 创建解密器
-    let cipher = Aes256Gcm::new_from_slice(&key)
-        .map_err(|e| CoreError::SerializationError(format!("Failed to create cipher: {e}")))?;
-    let nonce = Nonce::from_slice(&nonce_bytes);
+/// 使用新密码重新加密（修改主密码时的密钥轮换）
+///
+/// 先用旧密码解密，再用新密码按 `CURRENT_FILE_VERSION` 加密，返回新的
+/// (`salt`, `nonce_base64`, `ciphertext_base64`)。解密或加密任一步失败都直接返回错误，
+/// 调用方只有在拿到完整结果后才需要替换旧数据。
+///
+/// 旧数据的版本由盐值格式判断：带 Argon2 标记的按 Argon2id 解密，
+/// 否则依次尝试各 PBKDF2 版本（AES-GCM 校验失败即说明版本不符）。
+pub fn reencrypt(
+    ciphertext_b64: &str,
+    salt: &str,
+    nonce_b64: &str,
+    old_password: &str,
+    new_password: &str,
+) -> CoreResult<(String, String, String)> {
+    let plaintext = decrypt_any_version(ciphertext_b64, old_password, salt, nonce_b64)?;
+    encrypt(&plaintext, new_password)
+}
 
-    // 解密
-    cipher.decrypt(nonce, ciphertext.as_ref()).map_err(|_| {
-        CoreError::SerializationError(
-            "Decryption failed: invalid password or corrupted data".to_string(),
-        )
-    })
+/// 按盐值格式推断版本并解密
+fn decrypt_any_version(
+    ciphertext_b64: &str,
+    password: &str,
+    salt: &str,
+    nonce_b64: &str,
+) -> CoreResult<Vec<u8>> {
+    if salt.starts_with(&format!("{ARGON2_SALT_PREFIX}$")) {
+        return Argon2Params::decode_salt(salt).and_then(|(params, salt)| {
+            let key = derive_key_argon2(password, &salt, params)?;
+            open(&key, nonce_b64, ciphertext_b64)
+        });
+    }
+
+    let mut last_error = None;
+    for version in (1..=CURRENT_FILE_VERSION).rev() {
+        if let Some(iterations) = get_pbkdf2_iterations(version) {
+            match decrypt_with_iterations(ciphertext_b64, password, salt, nonce_b64, iterations) {
+                Ok(plaintext) => return Ok(plaintext),
+                Err(e) => last_error = Some(e),
+            }
+        }
+    }
+    Err(last_error.unwrap_or_else(|| {
+        CoreError::SerializationError("No PBKDF2 version available".to_string())
+    }))
 }
@@ -0,0 +1,199 @@
+//! 分块流式加密
+//!
+//! 大文件按固定大小分块加密，内存占用与文件大小无关。
+//!
+//! 格式：
+//! - 头部：`"DNSS" | 版本号 (1 字节) | 盐值长度 (1 字节) | 盐值 | nonce 前缀 (7 字节)`
+//! - 数据块：`密文长度 (u32, 大端) | 密文`，重复至结束
+//!
+//! 每块的 nonce 为 `nonce 前缀 | 块序号 (u32, 大端) | 末块标记 (1 字节)`，
+//! 末块标记保证截断或重排的密文无法通过校验。
+
+use std::io::{ErrorKind, Read, Write};
+
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes256Gcm, Nonce,
+};
+use rand::RngCore;
+
+use super::{derive_key_for_version, derive_new_key, CURRENT_FILE_VERSION, NONCE_LENGTH};
+use crate::error::{CoreError, CoreResult};
+
+/// 每块明文的大小（64 KiB）
+pub const STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
+/// 流式密文的魔数
+const STREAM_MAGIC: &[u8; 4] = b"DNSS";
+/// nonce 前缀长度（剩余 5 字节为块序号与末块标记）
+const NONCE_PREFIX_LENGTH: usize = 7;
+/// AES-GCM 认证标签长度
+const TAG_LENGTH: usize = 16;
+
+fn io_error(e: &std::io::Error) -> CoreError {
+    CoreError::StorageError(format!("I/O error: {e}"))
+}
+
+fn invalid(detail: &str) -> CoreError {
+    CoreError::SerializationError(format!("Invalid encrypted stream: {detail}"))
+}
+
+/// 计算第 `counter` 块的 nonce
+fn chunk_nonce(prefix: [u8; NONCE_PREFIX_LENGTH], counter: u32, last: bool) -> [u8; NONCE_LENGTH] {
+    let mut nonce = [0u8; NONCE_LENGTH];
+    nonce[..NONCE_PREFIX_LENGTH].copy_from_slice(&prefix);
+    nonce[NONCE_PREFIX_LENGTH..NONCE_LENGTH - 1].copy_from_slice(&counter.to_be_bytes());
+    nonce[NONCE_LENGTH - 1] = u8::from(last);
+    nonce
+}
+
+/// 读取至多一块明文（不足一块说明已到结尾）
+fn read_chunk<R: Read>(reader: &mut R) -> CoreResult<Vec<u8>> {
+    let mut chunk = Vec::with_capacity(STREAM_CHUNK_SIZE);
+    reader
+        .take(STREAM_CHUNK_SIZE as u64)
+        .read_to_end(&mut chunk)
+        .map_err(|e| io_error(&e))?;
+    Ok(chunk)
+}
+
+/// 读取一块密文，流结束时返回 `None`
+fn read_sealed_chunk<R: Read>(reader: &mut R) -> CoreResult<Option<Vec<u8>>> {
+    let mut len_buf = [0u8; 4];
+    let mut filled = 0;
+    while filled < len_buf.len() {
+        match reader.read(&mut len_buf[filled..]) {
+            Ok(0) if filled == 0 => return Ok(None),
+            Ok(0) => return Err(invalid("truncated chunk header")),
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(io_error(&e)),
+        }
+    }
+
+    let len = u32::from_be_bytes(len_buf) as usize;
+    if len > STREAM_CHUNK_SIZE + TAG_LENGTH {
+        return Err(invalid("chunk too large"));
+    }
+    let mut chunk = vec![0u8; len];
+    reader.read_exact(&mut chunk).map_err(|e| match e.kind() {
+        ErrorKind::UnexpectedEof => invalid("truncated chunk"),
+        _ => io_error(&e),
+    })?;
+    Ok(Some(chunk))
+}
+
+/// 流式加密：从 `reader` 读取明文，分块加密后写入 `writer`
+pub fn encrypt_stream<R: Read, W: Write>(
+    mut reader: R,
+    mut writer: W,
+    password: &str,
+) -> CoreResult<()> {
+    let (salt, key) = derive_new_key(password)?;
+    let cipher = Aes256Gcm::new_from_slice(&key)
+        .map_err(|e| CoreError::SerializationError(format!("Failed to create cipher: {e}")))?;
+    let mut prefix = [0u8; NONCE_PREFIX_LENGTH];
+    rand::rng().fill_bytes(&mut prefix);
+
+    let version = u8::try_from(CURRENT_FILE_VERSION)
+        .map_err(|_| invalid("file version does not fit in header"))?;
+    let salt_len = u8::try_from(salt.len()).map_err(|_| invalid("salt too long"))?;
+    let mut header = Vec::with_capacity(STREAM_MAGIC.len() + 2 + salt.len() + prefix.len());
+    header.extend_from_slice(STREAM_MAGIC);
+    header.push(version);
+    header.push(salt_len);
+    header.extend_from_slice(salt.as_bytes());
+    header.extend_from_slice(&prefix);
+    writer.write_all(&header).map_err(|e| io_error(&e))?;
+
+    // 预读下一块，以便判断当前块是否为末块
+    let mut current = read_chunk(&mut reader)?;
+    let mut counter: u32 = 0;
+    loop {
+        let next = if current.len() == STREAM_CHUNK_SIZE {
+            read_chunk(&mut reader)?
+        } else {
+            Vec::new()
+        };
+        let last = next.is_empty();
+
+        let nonce = chunk_nonce(prefix, counter, last);
+        let sealed = cipher
+            .encrypt(Nonce::from_slice(&nonce), current.as_slice())
+            .map_err(|e| CoreError::SerializationError(format!("Encryption failed: {e}")))?;
+        let sealed_len = u32::try_from(sealed.len()).map_err(|_| invalid("chunk too large"))?;
+        writer
+            .write_all(&sealed_len.to_be_bytes())
+            .and_then(|()| writer.write_all(&sealed))
+            .map_err(|e| io_error(&e))?;
+
+        if last {
+            break;
+        }
+        current = next;
+        counter = counter
+            .checked_add(1)
+            .ok_or_else(|| invalid("too many chunks"))?;
+    }
+
+    writer.flush().map_err(|e| io_error(&e))
+}
+
+/// 流式解密：从 `reader` 读取 `encrypt_stream` 生成的密文，解密后写入 `writer`
+///
+/// 明文按块写出，末块校验失败（如密文被截断）时返回错误，
+/// 此时已写出的内容不完整，调用方应丢弃。
+pub fn decrypt_stream<R: Read, W: Write>(
+    mut reader: R,
+    mut writer: W,
+    password: &str,
+) -> CoreResult<()> {
+    let mut fixed = [0u8; 6];
+    reader.read_exact(&mut fixed).map_err(|e| match e.kind() {
+        ErrorKind::UnexpectedEof => invalid("truncated header"),
+        _ => io_error(&e),
+    })?;
+    if &fixed[..4] != STREAM_MAGIC {
+        return Err(invalid("missing stream header"));
+    }
+    let version = u32::from(fixed[4]);
+    let mut salt = vec![0u8; usize::from(fixed[5])];
+    let mut prefix = [0u8; NONCE_PREFIX_LENGTH];
+    reader
+        .read_exact(&mut salt)
+        .and_then(|()| reader.read_exact(&mut prefix))
+        .map_err(|e| match e.kind() {
+            ErrorKind::UnexpectedEof => invalid("truncated header"),
+            _ => io_error(&e),
+        })?;
+    let salt = String::from_utf8(salt).map_err(|_| invalid("salt is not UTF-8"))?;
+
+    let key = derive_key_for_version(password, &salt, version)?;
+    let cipher = Aes256Gcm::new_from_slice(&key)
+        .map_err(|e| CoreError::SerializationError(format!("Failed to create cipher: {e}")))?;
+
+    let mut current = read_sealed_chunk(&mut reader)?.ok_or_else(|| invalid("no data"))?;
+    let mut counter: u32 = 0;
+    loop {
+        let next = read_sealed_chunk(&mut reader)?;
+        let nonce = chunk_nonce(prefix, counter, next.is_none());
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(&nonce), current.as_slice())
+            .map_err(|_| {
+                CoreError::SerializationError(
+                    "Decryption failed: invalid password or corrupted data".to_string(),
+                )
+            })?;
+        writer.write_all(&plaintext).map_err(|e| io_error(&e))?;
+
+        match next {
+            Some(chunk) => current = chunk,
+            None => break,
+        }
+        counter = counter
+            .checked_add(1)
+            .ok_or_else(|| invalid("too many chunks"))?;
+    }
+
+    writer.flush().map_err(|e| io_error(&e))
+}
@@ -6,7 +6,7 @@
 //! - 文件版本号不暴露加密参数，参数在代码中隐式定义
 //! - Version 1: PBKDF2-HMAC-SHA256, 100,000 次迭代
 //! - Version 2: PBKDF2-HMAC-SHA256, 600,000 次迭代（OWASP 2023 推荐）
-//! - 将来可扩展到 Version 3（Argon2 等算法）
+//! - Version 3: Argon2id（参数随盐值一起保存，便于日后调整）
 
 /// Version 1: PBKDF2-HMAC-SHA256, 100,000 次迭代
 const VERSION_1_ITERATIONS: u32 = 100_000;
@@ -14,18 +14,40 @@ const VERSION_1_ITERATIONS: u32 = 100_000;
 /// Version 2: PBKDF2-HMAC-SHA256, 600,000 次迭代（OWASP 2023 推荐）
 const VERSION_2_ITERATIONS: u32 = 600_000;
 
+/// Version 3: Argon2id 默认参数（OWASP 推荐：19 MiB 内存、2 次迭代、并行度 1）
+pub(crate) const VERSION_3_ARGON2_MEMORY_KIB: u32 = 19_456;
+pub(crate) const VERSION_3_ARGON2_ITERATIONS: u32 = 2;
+pub(crate) const VERSION_3_ARGON2_PARALLELISM: u32 = 1;
+
 /// 当前文件格式版本号
 ///
-/// 修改此常量即可切换版本（迭代次数会自动从版本号派生）
-pub const CURRENT_FILE_VERSION: u32 = 2;
+/// 修改此常量即可切换版本（密钥派生算法会自动从版本号派生）
+pub const CURRENT_FILE_VERSION: u32 = 3;
+
+/// 密钥派生算法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kdf {
+    /// PBKDF2-HMAC-SHA256（Version 1/2）
+    Pbkdf2 { iterations: u32 },
+    /// Argon2id（Version 3 起）
+    Argon2id,
+}
 
-/// 获取当前版本的迭代次数（编译时计算）
+/// 获取指定文件版本的密钥派生算法
 ///
-/// 从 `CURRENT_FILE_VERSION` 自动派生，确保加密和解密使用相同参数
-pub const fn get_current_iterations() -> u32 {
-    match get_pbkdf2_iterations(CURRENT_FILE_VERSION) {
-        Some(iterations) => iterations,
-        None => panic!("Invalid CURRENT_FILE_VERSION"),
+/// # Returns
+/// - `Some(kdf)` - 该版本对应的密钥派生算法
+/// - `None` - 不支持的版本号
+pub const fn get_kdf(version: u32) -> Option<Kdf> {
+    match version {
+        1 => Some(Kdf::Pbkdf2 {
+            iterations: VERSION_1_ITERATIONS,
+        }),
+        2 => Some(Kdf::Pbkdf2 {
+            iterations: VERSION_2_ITERATIONS,
+        }),
+        3 => Some(Kdf::Argon2id),
+        _ => None,
     }
 }
 
@@ -36,11 +58,10 @@ pub const fn get_current_iterations() -> u32 {
 ///
 /// # Returns
 /// - `Some(iterations)` - 该版本对应的迭代次数
-/// - `None` - 不支持的版本号
+/// - `None` - 不支持的版本号，或该版本不使用 PBKDF2
 pub const fn get_pbkdf2_iterations(version: u32) -> Option<u32> {
-    match version {
-        1 => Some(VERSION_1_ITERATIONS),
-        2 => Some(VERSION_2_ITERATIONS),
+    match get_kdf(version) {
+        Some(Kdf::Pbkdf2 { iterations }) => Some(iterations),
         _ => None,
     }
 }
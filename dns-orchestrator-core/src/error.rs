@@ -18,6 +18,10 @@ pub enum CoreError {
     #[error("Account not found: {0}")]
     AccountNotFound(String),
 
+    /// 已存在使用相同凭证的账户（内容为已有账户 ID）
+    #[error("Account already exists: {0}")]
+    DuplicateAccount(String),
+
     /// 域名未找到
     #[error("Domain not found: {0}")]
     DomainNotFound(String),
@@ -70,6 +74,14 @@ pub enum CoreError {
     #[error("Network error: {0}")]
     NetworkError(String),
 
+    /// 远端服务限流（如 WHOIS 服务器拒绝频繁查询）
+    #[error("Rate limited: {0}")]
+    RateLimited(String),
+
+    /// 操作超时
+    #[error("Timeout: {0}")]
+    Timeout(String),
+
     /// 需要迁移数据格式（v1.7.0 凭证格式升级）
     #[error("Credential data migration required")]
     MigrationRequired,
@@ -19,13 +19,15 @@ pub mod utils;
 // Re-export 常用类型
 pub use error::{CoreError, CoreResult};
 pub use services::ServiceContext;
-pub use traits::{AccountRepository, CredentialStore, ProviderRegistry};
+pub use traits::{
+    AccountRepository, AuditSink, CredentialStore, MetricsRecorder, ProviderRegistry,
+};
 
 // Re-export provider 库的公共类型
 pub use dns_orchestrator_provider::{
     BatchCreateFailure, BatchCreateResult, BatchDeleteFailure, BatchDeleteResult,
-    BatchUpdateFailure, BatchUpdateItem, BatchUpdateResult, CreateDnsRecordRequest, DnsProvider,
-    DnsRecord, DnsRecordType, DomainStatus, PaginatedResponse, PaginationParams,
-    ProviderCredentials, ProviderDomain, ProviderError, ProviderMetadata, ProviderType,
-    RecordQueryParams, UpdateDnsRecordRequest,
+    BatchUpdateFailure, BatchUpdateItem, BatchUpdateResult, CancellationToken,
+    CreateDnsRecordRequest, DnsProvider, DnsRecord, DnsRecordType, DomainStatus, PaginatedResponse,
+    PaginationParams, ProviderCredentials, ProviderDomain, ProviderError, ProviderMetadata,
+    ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
 };
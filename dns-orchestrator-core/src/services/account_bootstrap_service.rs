@@ -4,9 +4,9 @@
 
 use std::sync::Arc;
 
-use dns_orchestrator_provider::create_provider;
+use dns_orchestrator_provider::{create_provider, ProviderCredentials};
 
-use crate::error::CoreResult;
+use crate::error::{CoreError, CoreResult};
 use crate::types::AccountStatus;
 
 use super::{AccountMetadataService, CredentialManagementService};
@@ -74,71 +74,89 @@ impl AccountBootstrapService {
 
         // 3. 逐个恢复账户
         for account in &accounts {
-            let Some(credentials) = all_credentials.get(&account.id) else {
-                log::warn!("No credentials found for account: {}", account.id);
-                if let Err(e) = self
-                    .metadata_service
-                    .update_status(
-                        &account.id,
-                        AccountStatus::Error,
-                        Some("凭证不存在".to_string()),
-                    )
-                    .await
-                {
-                    log::warn!("Failed to update status for account {}: {e}", account.id);
-                }
-                error_count += 1;
-                continue;
-            };
-
-            // 直接使用 ProviderCredentials 创建 provider
-            let provider = match create_provider(credentials.clone()) {
-                Ok(p) => p,
-                Err(e) => {
-                    log::warn!(
-                        "Failed to create provider for account {}: {}",
-                        account.id,
-                        e
-                    );
-                    if let Err(update_err) = self
-                        .metadata_service
-                        .update_status(
-                            &account.id,
-                            AccountStatus::Error,
-                            Some(format!("创建 Provider 失败: {e}")),
-                        )
-                        .await
-                    {
-                        log::warn!(
-                            "Failed to update status for account {}: {update_err}",
-                            account.id
-                        );
-                    }
-                    error_count += 1;
-                    continue;
-                }
-            };
+            let credentials = all_credentials
+                .get(&account.id)
+                .ok_or_else(|| "凭证不存在".to_string());
+            match self.restore_account(&account.id, credentials).await {
+                AccountStatus::Active => success_count += 1,
+                AccountStatus::Error => error_count += 1,
+            }
+        }
 
-            // 注册 provider
+        Ok(RestoreResult {
+            success_count,
+            error_count,
+        })
+    }
+
+    /// 重新加载单个账户（修改凭证后调用，无需恢复全部账户）
+    ///
+    /// 重新读取该账户的凭证、重建 Provider 并注册，返回新的账户状态。
+    /// 失败时会注销旧的 Provider，避免继续使用过期凭证。
+    pub async fn reload_account(&self, account_id: &str) -> CoreResult<AccountStatus> {
+        if self
+            .metadata_service
+            .get_account(account_id)
+            .await?
+            .is_none()
+        {
+            return Err(CoreError::AccountNotFound(account_id.to_string()));
+        }
+
+        let credentials = self.credential_service.load_credentials(account_id).await;
+        let status = self
+            .restore_account(
+                account_id,
+                credentials.as_ref().map_err(ToString::to_string),
+            )
+            .await;
+
+        if status == AccountStatus::Error {
             self.credential_service
-                .register_provider(account.id.clone(), provider)
+                .unregister_provider(account_id)
                 .await;
+        }
+
+        Ok(status)
+    }
 
-            // 更新状态为 Active
-            if let Err(e) = self
-                .metadata_service
-                .update_status(&account.id, AccountStatus::Active, None)
-                .await
-            {
-                log::warn!("Failed to update status for account {}: {e}", account.id);
+    /// 用给定凭证重建并注册单个账户的 Provider，同时更新账户状态
+    ///
+    /// `credentials` 为 `Err` 时，其内容作为错误信息写入账户状态。
+    async fn restore_account(
+        &self,
+        account_id: &str,
+        credentials: Result<&ProviderCredentials, String>,
+    ) -> AccountStatus {
+        let provider = match credentials {
+            Ok(credentials) => create_provider(credentials.clone()).map_err(|e| {
+                log::warn!("Failed to create provider for account {account_id}: {e}");
+                format!("创建 Provider 失败: {e}")
+            }),
+            Err(message) => {
+                log::warn!("No credentials found for account {account_id}: {message}");
+                Err(message)
             }
+        };
+
+        let (status, error) = match provider {
+            Ok(provider) => {
+                self.credential_service
+                    .register_provider(account_id.to_string(), provider)
+                    .await;
+                (AccountStatus::Active, None)
+            }
+            Err(message) => (AccountStatus::Error, Some(message)),
+        };
 
-            success_count += 1;
+        if let Err(e) = self
+            .metadata_service
+            .update_status(account_id, status.clone(), error)
+            .await
+        {
+            log::warn!("Failed to update status for account {account_id}: {e}");
         }
 
-        Ok(RestoreResult {
-            success_count,
-            error_count,
-        })
+        status
     }
 }
@@ -6,18 +6,23 @@ use std::sync::Arc;
 
 use chrono::Utc;
 
+use dns_orchestrator_provider::{ProviderCredentials, ProviderError};
+
 use crate::error::{CoreError, CoreResult};
+use crate::traits::{AuditSink, NoopAuditSink};
 use crate::types::{
-    Account, AccountStatus, BatchDeleteFailure, BatchDeleteResult, CreateAccountRequest,
-    UpdateAccountRequest,
+    Account, AccountStatus, AccountValidationResult, AuditEvent, AuditOperation,
+    BatchDeleteFailure, BatchDeleteResult, CreateAccountRequest, UpdateAccountRequest,
+    ValidateAccountsResult,
 };
 
-use super::{AccountMetadataService, CredentialManagementService};
+use super::{record_audit, AccountMetadataService, CredentialManagementService};
 
 /// 账户生命周期服务
 pub struct AccountLifecycleService {
     metadata_service: Arc<AccountMetadataService>,
     credential_service: Arc<CredentialManagementService>,
+    audit_sink: Arc<dyn AuditSink>,
 }
 
 impl AccountLifecycleService {
@@ -30,18 +35,29 @@ impl AccountLifecycleService {
         Self {
             metadata_service,
             credential_service,
+            audit_sink: Arc::new(NoopAuditSink),
         }
     }
 
+    /// 设置审计日志输出
+    #[must_use]
+    pub fn with_audit_sink(mut self, audit_sink: Arc<dyn AuditSink>) -> Self {
+        self.audit_sink = audit_sink;
+        self
+    }
+
     /// 创建账户
     ///
-    /// 完整流程：验证凭证 -> 保存凭证 -> 注册 Provider -> 保存元数据
+    /// 完整流程：检查重复 -> 验证凭证 -> 保存凭证 -> 注册 Provider -> 保存元数据
+    /// 已有账户使用相同凭证时返回 `DuplicateAccount`
     /// 如果保存元数据失败，会自动清理已保存的凭证和已注册的 Provider
     ///
     /// # v1.7.0 变更
     /// `request.credentials` 已经是 `ProviderCredentials` 类型，无需调用 `from_map()` 转换
     pub async fn create_account(&self, request: CreateAccountRequest) -> CoreResult<Account> {
-        // 1. 验证凭证
+        // 1. 检查重复账户，再验证凭证
+        self.ensure_not_duplicate(&request.credentials, None)
+            .await?;
         let provider = self
             .credential_service
             .validate_and_create_provider(&request.credentials)
@@ -91,6 +107,13 @@ impl AccountLifecycleService {
             return Err(e);
         }
 
+        record_audit(
+            self.audit_sink.as_ref(),
+            AuditEvent::new(&account.id, AuditOperation::CreateAccount, &account.id)
+                .with_after(account_summary(&account)),
+        )
+        .await;
+
         Ok(account)
     }
 
@@ -108,10 +131,14 @@ impl AccountLifecycleService {
             .get_account(&request.id)
             .await?
             .ok_or_else(|| CoreError::AccountNotFound(request.id.clone()))?;
+        let before = account_summary(&account);
+        let credentials_updated = request.credentials.is_some();
 
         // 2. 如果提供了新凭证，验证并更新
         if let Some(ref new_credentials) = request.credentials {
-            // 2.1 验证凭证
+            // 2.1 检查重复并验证凭证
+            self.ensure_not_duplicate(new_credentials, Some(&request.id))
+                .await?;
             let new_provider = self
                 .credential_service
                 .validate_and_create_provider(new_credentials)
@@ -147,6 +174,18 @@ impl AccountLifecycleService {
         // 5. 保存更新后的账户
         self.metadata_service.save_account(&account).await?;
 
+        let mut after = account_summary(&account);
+        if credentials_updated {
+            after.push_str(" [credentials updated]");
+        }
+        record_audit(
+            self.audit_sink.as_ref(),
+            AuditEvent::new(&account.id, AuditOperation::UpdateAccount, &account.id)
+                .with_before(before)
+                .with_after(after),
+        )
+        .await;
+
         Ok(account)
     }
 
@@ -155,7 +194,8 @@ impl AccountLifecycleService {
     /// 流程：先删除元数据，再清理内存和凭证（避免出现"幽灵账户"）
     pub async fn delete_account(&self, account_id: &str) -> CoreResult<()> {
         // 1. 检查账户存在
-        self.metadata_service
+        let account = self
+            .metadata_service
             .get_account(account_id)
             .await?
             .ok_or_else(|| CoreError::AccountNotFound(account_id.to_string()))?;
@@ -173,6 +213,13 @@ impl AccountLifecycleService {
             log::warn!("Failed to delete credentials for {account_id}: {e}");
         }
 
+        record_audit(
+            self.audit_sink.as_ref(),
+            AuditEvent::new(account_id, AuditOperation::DeleteAccount, account_id)
+                .with_before(account_summary(&account)),
+        )
+        .await;
+
         Ok(())
     }
 
@@ -202,4 +249,122 @@ impl AccountLifecycleService {
             failures,
         })
     }
+
+    /// 校验所有已注册账户的凭证
+    ///
+    /// 逐个调用 Provider 的 `validate_credentials`：
+    /// - 凭证被拒绝时将账户标记为 Error
+    /// - 此前处于 Error 状态的账户校验通过后恢复为 Active
+    /// - 网络等其他错误只记录在结果中，不改变账户状态
+    ///
+    /// 平台层可定时调用，及时发现上游已吊销的凭证。
+    pub async fn validate_all_accounts(&self) -> CoreResult<ValidateAccountsResult> {
+        let mut account_ids = self.credential_service.list_registered_account_ids().await;
+        account_ids.sort();
+
+        let mut results = Vec::with_capacity(account_ids.len());
+        for account_id in account_ids {
+            // 校验期间账户可能已被删除
+            let Some(provider) = self.credential_service.get_provider(&account_id).await else {
+                continue;
+            };
+
+            let (valid, error) = match provider.validate_credentials().await {
+                Ok(true) => {
+                    self.restore_account_status(&account_id).await;
+                    (true, None)
+                }
+                Ok(false) => {
+                    let msg = CoreError::InvalidCredentials(provider.id().to_string()).to_string();
+                    self.mark_account_invalid(&account_id, &msg).await;
+                    (false, Some(msg))
+                }
+                Err(e @ ProviderError::InvalidCredentials { .. }) => {
+                    let msg = e.to_string();
+                    self.mark_account_invalid(&account_id, &msg).await;
+                    (false, Some(msg))
+                }
+                Err(e) => {
+                    log::warn!("Failed to validate credentials for account {account_id}: {e}");
+                    (false, Some(e.to_string()))
+                }
+            };
+
+            results.push(AccountValidationResult {
+                account_id,
+                valid,
+                error,
+                checked_at: Utc::now(),
+            });
+        }
+
+        let valid_count = results.iter().filter(|r| r.valid).count();
+        Ok(ValidateAccountsResult {
+            valid_count,
+            invalid_count: results.len() - valid_count,
+            results,
+        })
+    }
+
+    /// 已有其他账户使用相同凭证时返回 `DuplicateAccount`
+    async fn ensure_not_duplicate(
+        &self,
+        credentials: &ProviderCredentials,
+        exclude_account_id: Option<&str>,
+    ) -> CoreResult<()> {
+        match self
+            .credential_service
+            .find_duplicate_account(credentials, exclude_account_id)
+            .await?
+        {
+            Some(existing_id) => {
+                log::warn!("Credentials already used by account {existing_id}");
+                Err(CoreError::DuplicateAccount(existing_id))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// 标记账户为无效状态
+    async fn mark_account_invalid(&self, account_id: &str, error_msg: &str) {
+        if let Err(e) = self
+            .metadata_service
+            .update_status(
+                account_id,
+                AccountStatus::Error,
+                Some(error_msg.to_string()),
+            )
+            .await
+        {
+            log::error!("Failed to mark account {account_id} as invalid: {e}");
+            return;
+        }
+        log::warn!("Account {account_id} marked as invalid: {error_msg}");
+    }
+
+    /// 凭证恢复有效时，将处于 Error 状态的账户恢复为 Active
+    async fn restore_account_status(&self, account_id: &str) {
+        let is_error = matches!(
+            self.metadata_service.get_account(account_id).await,
+            Ok(Some(Account {
+                status: Some(AccountStatus::Error),
+                ..
+            }))
+        );
+        if !is_error {
+            return;
+        }
+        if let Err(e) = self
+            .metadata_service
+            .update_status(account_id, AccountStatus::Active, None)
+            .await
+        {
+            log::warn!("Failed to restore status for account {account_id}: {e}");
+        }
+    }
+}
+
+/// 生成账户的审计摘要，如 `My Account (cloudflare)`
+fn account_summary(account: &Account) -> String {
+    format!("{} ({})", account.name, account.provider)
 }
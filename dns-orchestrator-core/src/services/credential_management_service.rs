@@ -5,6 +5,7 @@
 use std::sync::Arc;
 
 use dns_orchestrator_provider::{create_provider, DnsProvider, ProviderCredentials};
+use sha2::{Digest, Sha256};
 
 use crate::error::{CoreError, CoreResult};
 use crate::traits::{CredentialStore, CredentialsMap, ProviderRegistry};
@@ -88,6 +89,35 @@ impl CredentialManagementService {
         self.credential_store.load_all().await
     }
 
+    /// 查找使用相同凭证的已有账户
+    ///
+    /// 只比较同一服务商类型下凭证的 SHA-256 摘要，不比较明文。
+    ///
+    /// # Arguments
+    /// * `credentials` - 待检查的凭证
+    /// * `exclude_account_id` - 需要排除的账户（更新凭证时排除自身）
+    pub async fn find_duplicate_account(
+        &self,
+        credentials: &ProviderCredentials,
+        exclude_account_id: Option<&str>,
+    ) -> CoreResult<Option<String>> {
+        let fingerprint = credentials_fingerprint(credentials)?;
+        let provider_type = credentials.provider_type();
+
+        for (account_id, existing) in self.credential_store.load_all().await? {
+            if Some(account_id.as_str()) == exclude_account_id
+                || existing.provider_type() != provider_type
+            {
+                continue;
+            }
+            if credentials_fingerprint(&existing)? == fingerprint {
+                return Ok(Some(account_id));
+            }
+        }
+
+        Ok(None)
+    }
+
     /// 注册 Provider 到 Registry
     pub async fn register_provider(&self, account_id: String, provider: Arc<dyn DnsProvider>) {
         self.provider_registry.register(account_id, provider).await;
@@ -97,4 +127,21 @@ impl CredentialManagementService {
     pub async fn unregister_provider(&self, account_id: &str) {
         self.provider_registry.unregister(account_id).await;
     }
+
+    /// 获取已注册的 Provider
+    pub async fn get_provider(&self, account_id: &str) -> Option<Arc<dyn DnsProvider>> {
+        self.provider_registry.get(account_id).await
+    }
+
+    /// 列出所有已注册 Provider 的账户 ID
+    pub async fn list_registered_account_ids(&self) -> Vec<String> {
+        self.provider_registry.list_account_ids().await
+    }
+}
+
+/// 计算凭证摘要（SHA-256）
+fn credentials_fingerprint(credentials: &ProviderCredentials) -> CoreResult<[u8; 32]> {
+    let serialized = serde_json::to_vec(credentials)
+        .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+    Ok(Sha256::digest(&serialized).into())
 }
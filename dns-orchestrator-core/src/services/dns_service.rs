@@ -1,15 +1,25 @@
 //! DNS 记录管理服务
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use dns_orchestrator_provider::ProviderError;
+use dns_orchestrator_provider::{
+    canonical_record_name, parse_zone_file, records_from_csv, records_to_csv, records_to_zone_file,
+    CancellationToken, DnsProvider, ProviderError, RecordData,
+};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
 
 use crate::error::{CoreError, CoreResult};
 use crate::services::ServiceContext;
 use crate::types::{
-    BatchDeleteFailure, BatchDeleteRequest, BatchDeleteResult, CreateDnsRecordRequest, DnsRecord,
-    DnsRecordType, PaginatedResponse, RecordQueryParams, UpdateDnsRecordRequest,
+    ApplyTemplateResult, AuditEvent, AuditOperation, BatchCreateResult, BatchDeleteFailure,
+    BatchDeleteRequest, BatchDeleteResult, CreateDnsRecordRequest, DeleteMatchingResult, DnsRecord,
+    DnsRecordType, PaginatedResponse, RecordDeleteFilter, RecordExport, RecordExportFormat,
+    RecordImportAction, RecordImportOutcome, RecordImportResult, RecordMergeStrategy,
+    RecordQueryParams, RecordTemplate, UpdateDnsRecordRequest,
 };
+use crate::utils::idn::{record_name_to_ascii, record_name_to_unicode};
 
 /// DNS 记录管理服务
 pub struct DnsService {
@@ -40,37 +50,189 @@ impl DnsService {
             page_size: page_size.unwrap_or(20),
             keyword,
             record_type,
+            cursor: None,
         };
 
-        match provider.list_records(domain_id, &params).await {
-            Ok(response) => Ok(response),
+        match self
+            .ctx
+            .observe_provider_call(
+                provider.as_ref(),
+                "list_records",
+                provider.list_records(domain_id, &params),
+            )
+            .await
+        {
+            Ok(mut response) => {
+                if self.ctx.unicode_record_names {
+                    for record in &mut response.items {
+                        record.name = record_name_to_unicode(&record.name);
+                    }
+                }
+                Ok(response)
+            }
+            Err(e) => Err(self.handle_provider_error(account_id, e).await),
+        }
+    }
+
+    /// 拉取域名下的全部 DNS 记录（可取消）
+    ///
+    /// 取消令牌触发后不再发起后续分页请求，返回 `Provider(Cancelled)` 错误。
+    pub async fn list_all_records(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        cancel: &CancellationToken,
+    ) -> CoreResult<Vec<DnsRecord>> {
+        let provider = self.ctx.get_provider(account_id).await?;
+        match self
+            .ctx
+            .observe_provider_call(
+                provider.as_ref(),
+                "list_all_records",
+                provider.list_all_records_cancellable(domain_id, cancel),
+            )
+            .await
+        {
+            Ok(mut records) => {
+                if self.ctx.unicode_record_names {
+                    for record in &mut records {
+                        record.name = record_name_to_unicode(&record.name);
+                    }
+                }
+                Ok(records)
+            }
             Err(e) => Err(self.handle_provider_error(account_id, e).await),
         }
     }
 
     /// 创建 DNS 记录
+    ///
+    /// 记录名中的 Unicode 标签会先转换为 punycode。
     pub async fn create_record(
         &self,
         account_id: &str,
         request: CreateDnsRecordRequest,
     ) -> CoreResult<DnsRecord> {
+        let request = CreateDnsRecordRequest {
+            name: record_name_to_ascii(&request.name)?,
+            ..request
+        };
         let provider = self.ctx.get_provider(account_id).await?;
-        match provider.create_record(&request).await {
-            Ok(record) => Ok(record),
+        match self
+            .ctx
+            .observe_provider_call(
+                provider.as_ref(),
+                "create_record",
+                provider.create_record(&request),
+            )
+            .await
+        {
+            Ok(record) => {
+                // 记录数变化，域名列表缓存失效
+                self.ctx.domain_cache.invalidate(account_id).await;
+                self.ctx
+                    .audit(
+                        AuditEvent::new(account_id, AuditOperation::CreateRecord, &record.id)
+                            .with_after(record_summary(&record)),
+                    )
+                    .await;
+                Ok(record)
+            }
             Err(e) => Err(self.handle_provider_error(account_id, e).await),
         }
     }
 
+    /// 批量创建 DNS 记录
+    ///
+    /// 委托给 Provider 的批量接口，所有请求的 `domain_id` 统一设为 `domain_id`。
+    /// 单条失败记录在结果的 `failures` 中（`request_index` 对应请求下标），不影响其余记录。
+    pub async fn create_records(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        requests: Vec<CreateDnsRecordRequest>,
+    ) -> CoreResult<BatchCreateResult> {
+        if requests.is_empty() {
+            return Ok(BatchCreateResult {
+                success_count: 0,
+                failed_count: 0,
+                created_records: Vec::new(),
+                failures: Vec::new(),
+            });
+        }
+
+        let requests = requests
+            .into_iter()
+            .map(|request| {
+                Ok(CreateDnsRecordRequest {
+                    domain_id: domain_id.to_string(),
+                    name: record_name_to_ascii(&request.name)?,
+                    ..request
+                })
+            })
+            .collect::<CoreResult<Vec<_>>>()?;
+
+        let provider = self.ctx.get_provider(account_id).await?;
+        let result = match self
+            .ctx
+            .observe_provider_call(
+                provider.as_ref(),
+                "batch_create_records",
+                provider.batch_create_records(&requests),
+            )
+            .await
+        {
+            Ok(result) => result,
+            Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+        };
+
+        if !result.created_records.is_empty() {
+            self.ctx.domain_cache.invalidate(account_id).await;
+        }
+        for record in &result.created_records {
+            self.ctx
+                .audit(
+                    AuditEvent::new(account_id, AuditOperation::CreateRecord, &record.id)
+                        .with_after(record_summary(record)),
+                )
+                .await;
+        }
+
+        Ok(result)
+    }
+
     /// 更新 DNS 记录
+    ///
+    /// 记录名中的 Unicode 标签会先转换为 punycode。
     pub async fn update_record(
         &self,
         account_id: &str,
         record_id: &str,
         request: UpdateDnsRecordRequest,
     ) -> CoreResult<DnsRecord> {
+        let request = UpdateDnsRecordRequest {
+            name: record_name_to_ascii(&request.name)?,
+            ..request
+        };
         let provider = self.ctx.get_provider(account_id).await?;
-        match provider.update_record(record_id, &request).await {
-            Ok(record) => Ok(record),
+        match self
+            .ctx
+            .observe_provider_call(
+                provider.as_ref(),
+                "update_record",
+                provider.update_record(record_id, &request),
+            )
+            .await
+        {
+            Ok(record) => {
+                self.ctx
+                    .audit(
+                        AuditEvent::new(account_id, AuditOperation::UpdateRecord, record_id)
+                            .with_after(record_summary(&record)),
+                    )
+                    .await;
+                Ok(record)
+            }
             Err(e) => Err(self.handle_provider_error(account_id, e).await),
         }
     }
@@ -83,8 +245,26 @@ impl DnsService {
         domain_id: &str,
     ) -> CoreResult<()> {
         let provider = self.ctx.get_provider(account_id).await?;
-        match provider.delete_record(record_id, domain_id).await {
-            Ok(()) => Ok(()),
+        match self
+            .ctx
+            .observe_provider_call(
+                provider.as_ref(),
+                "delete_record",
+                provider.delete_record(record_id, domain_id),
+            )
+            .await
+        {
+            Ok(()) => {
+                self.ctx.domain_cache.invalidate(account_id).await;
+                self.ctx
+                    .audit(AuditEvent::new(
+                        account_id,
+                        AuditOperation::DeleteRecord,
+                        record_id,
+                    ))
+                    .await;
+                Ok(())
+            }
             Err(e) => Err(self.handle_provider_error(account_id, e).await),
         }
     }
@@ -100,7 +280,9 @@ impl DnsService {
         let mut success_count = 0;
         let mut failures = Vec::new();
 
-        // 并行删除所有记录
+        // 并行删除所有记录（并发数受 Provider 的 batch_concurrency 限制）
+        let concurrency = provider.batch_concurrency().max(1);
+        let ctx = &self.ctx;
         let delete_futures: Vec<_> = request
             .record_ids
             .iter()
@@ -109,7 +291,14 @@ impl DnsService {
                 let domain_id = request.domain_id.clone();
                 let record_id = record_id.clone();
                 async move {
-                    match provider.delete_record(&record_id, &domain_id).await {
+                    match ctx
+                        .observe_provider_call(
+                            provider.as_ref(),
+                            "delete_record",
+                            provider.delete_record(&record_id, &domain_id),
+                        )
+                        .await
+                    {
                         Ok(()) => Ok(record_id),
                         Err(e) => Err((record_id, e)),
                     }
@@ -117,11 +306,27 @@ impl DnsService {
             })
             .collect();
 
-        let results = futures::future::join_all(delete_futures).await;
+        let results: Vec<_> = futures::stream::iter(delete_futures)
+            .buffer_unordered(concurrency)
+            .collect()
+            .await;
+
+        if results.iter().any(Result::is_ok) {
+            self.ctx.domain_cache.invalidate(account_id).await;
+        }
 
         for result in results {
             match result {
-                Ok(_) => success_count += 1,
+                Ok(record_id) => {
+                    success_count += 1;
+                    self.ctx
+                        .audit(AuditEvent::new(
+                            account_id,
+                            AuditOperation::DeleteRecord,
+                            record_id,
+                        ))
+                        .await;
+                }
                 Err((record_id, e)) => {
                     // 检查是否是凭证失效
                     if let ProviderError::InvalidCredentials { .. } = &e {
@@ -144,6 +349,458 @@ impl DnsService {
         })
     }
 
+    /// 删除域名下匹配过滤条件的全部记录，返回已删除记录的摘要
+    ///
+    /// 过滤条件不能为空（仅 `*` 的名称模式也视为空），否则返回 `ValidationError`，
+    /// 避免误删整个区域。
+    pub async fn delete_records_matching(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        filter: &RecordDeleteFilter,
+    ) -> CoreResult<DeleteMatchingResult> {
+        if filter.is_trivial() {
+            return Err(CoreError::ValidationError(
+                "Delete filter must specify a record type, name pattern or value".to_string(),
+            ));
+        }
+        let filter = RecordDeleteFilter {
+            name_pattern: filter
+                .name_pattern
+                .as_deref()
+                .map(record_name_to_ascii)
+                .transpose()?,
+            ..filter.clone()
+        };
+
+        let provider = self.ctx.get_provider(account_id).await?;
+        let records = match self
+            .ctx
+            .observe_provider_call(
+                provider.as_ref(),
+                "list_all_records",
+                list_all_records(provider.as_ref(), domain_id),
+            )
+            .await
+        {
+            Ok(records) => records,
+            Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+        };
+        let matched: Vec<DnsRecord> = records
+            .into_iter()
+            .filter(|record| filter.matches(record))
+            .collect();
+        if matched.is_empty() {
+            return Ok(DeleteMatchingResult::default());
+        }
+
+        let result = self
+            .batch_delete_records(
+                account_id,
+                BatchDeleteRequest {
+                    domain_id: domain_id.to_string(),
+                    record_ids: matched.iter().map(|record| record.id.clone()).collect(),
+                },
+            )
+            .await?;
+
+        let deleted = matched
+            .iter()
+            .filter(|record| {
+                !result
+                    .failures
+                    .iter()
+                    .any(|failure| failure.record_id == record.id)
+            })
+            .map(record_summary)
+            .collect();
+        Ok(DeleteMatchingResult {
+            deleted,
+            failures: result.failures,
+        })
+    }
+
+    /// 应用记录模板
+    ///
+    /// 替换模板变量后批量创建记录；名称、类型与数据都相同的记录已存在时跳过。
+    pub async fn apply_template(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        template: &RecordTemplate,
+        vars: &HashMap<String, String>,
+    ) -> CoreResult<ApplyTemplateResult> {
+        let requests = template
+            .expand(domain_id, vars)?
+            .into_iter()
+            .map(|request| {
+                Ok(CreateDnsRecordRequest {
+                    name: record_name_to_ascii(&request.name)?,
+                    ..request
+                })
+            })
+            .collect::<CoreResult<Vec<_>>>()?;
+        let provider = self.ctx.get_provider(account_id).await?;
+
+        let domain = match self
+            .ctx
+            .observe_provider_call(
+                provider.as_ref(),
+                "get_domain",
+                provider.get_domain(domain_id),
+            )
+            .await
+        {
+            Ok(domain) => domain,
+            Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+        };
+        let existing = match self
+            .ctx
+            .observe_provider_call(
+                provider.as_ref(),
+                "list_all_records",
+                list_all_records(provider.as_ref(), domain_id),
+            )
+            .await
+        {
+            Ok(records) => records,
+            Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+        };
+        let (skipped, to_create): (Vec<_>, Vec<_>) = requests.into_iter().partition(|req| {
+            existing.iter().any(|r| {
+                canonical_record_name(&r.name, &domain.name)
+                    == canonical_record_name(&req.name, &domain.name)
+                    && r.data == req.data
+            })
+        });
+
+        if to_create.is_empty() {
+            return Ok(ApplyTemplateResult {
+                created: Vec::new(),
+                skipped,
+                failures: Vec::new(),
+            });
+        }
+
+        let result = match self
+            .ctx
+            .observe_provider_call(
+                provider.as_ref(),
+                "batch_create_records",
+                provider.batch_create_records(&to_create),
+            )
+            .await
+        {
+            Ok(result) => result,
+            Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+        };
+
+        if !result.created_records.is_empty() {
+            self.ctx.domain_cache.invalidate(account_id).await;
+        }
+        for record in &result.created_records {
+            self.ctx
+                .audit(
+                    AuditEvent::new(account_id, AuditOperation::CreateRecord, &record.id)
+                        .with_after(record_summary(record)),
+                )
+                .await;
+        }
+
+        Ok(ApplyTemplateResult {
+            created: result.created_records,
+            skipped,
+            failures: result.failures,
+        })
+    }
+
+    /// 导出域名下的全部 DNS 记录
+    ///
+    /// 区域名称取自 Provider 返回的域名，记录类型不做过滤。
+    pub async fn export_records(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        format: RecordExportFormat,
+    ) -> CoreResult<RecordExport> {
+        let provider = self.ctx.get_provider(account_id).await?;
+
+        let domain = match self
+            .ctx
+            .observe_provider_call(
+                provider.as_ref(),
+                "get_domain",
+                provider.get_domain(domain_id),
+            )
+            .await
+        {
+            Ok(domain) => domain,
+            Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+        };
+        let records = match self
+            .ctx
+            .observe_provider_call(
+                provider.as_ref(),
+                "list_all_records",
+                list_all_records(provider.as_ref(), domain_id),
+            )
+            .await
+        {
+            Ok(records) => records,
+            Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+        };
+
+        let origin = domain.name.trim_end_matches('.').to_string();
+        let (content, extension) = match format {
+            RecordExportFormat::Bind => (records_to_zone_file(&records, &origin), "zone"),
+            RecordExportFormat::Csv => (records_to_csv(&records)?, "csv"),
+            RecordExportFormat::Json => {
+                let content = serde_json::to_string_pretty(&RecordExportJson {
+                    origin: &origin,
+                    records: &records,
+                })
+                .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+                (content, "json")
+            }
+        };
+
+        let suggested_filename = format!(
+            "{origin}-{}.{extension}",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        );
+
+        Ok(RecordExport {
+            record_count: records.len(),
+            origin,
+            content,
+            suggested_filename,
+        })
+    }
+
+    /// 从 BIND 区域文件 / JSON / CSV 导入记录到域名
+    ///
+    /// 与已有记录数据完全相同的导入项直接跳过；名称与类型相同但数据不同时按 `merge_strategy`
+    /// 跳过或更新已有记录。新记录通过 Provider 的批量接口创建，返回每条导入项的处理明细。
+    pub async fn import_records(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        content: &str,
+        format: RecordExportFormat,
+        merge_strategy: RecordMergeStrategy,
+    ) -> CoreResult<RecordImportResult> {
+        let provider = self.ctx.get_provider(account_id).await?;
+
+        let domain = match self
+            .ctx
+            .observe_provider_call(
+                provider.as_ref(),
+                "get_domain",
+                provider.get_domain(domain_id),
+            )
+            .await
+        {
+            Ok(domain) => domain,
+            Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+        };
+        let origin = domain.name.trim_end_matches('.').to_lowercase();
+        let requests = parse_record_import(content, format, &origin, domain_id)?;
+
+        let existing = match self
+            .ctx
+            .observe_provider_call(
+                provider.as_ref(),
+                "list_all_records",
+                list_all_records(provider.as_ref(), domain_id),
+            )
+            .await
+        {
+            Ok(records) => records,
+            Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+        };
+
+        // 1. 规划：先认领数据完全相同的已有记录，再按策略处理同名同类型的冲突
+        let same_key = |record: &DnsRecord, request: &CreateDnsRecordRequest| {
+            canonical_record_name(&record.name, &origin)
+                == canonical_record_name(&request.name, &origin)
+                && record.data.record_type() == request.data.record_type()
+        };
+        let mut claimed = vec![false; existing.len()];
+        let mut plans: Vec<Option<ImportPlan>> = requests
+            .iter()
+            .map(|request| {
+                let index = existing.iter().enumerate().position(|(i, record)| {
+                    !claimed[i] && same_key(record, request) && record.data == request.data
+                })?;
+                claimed[index] = true;
+                Some(ImportPlan::Skip)
+            })
+            .collect();
+        for (plan, request) in plans.iter_mut().zip(&requests) {
+            if plan.is_some() {
+                continue;
+            }
+            let conflict = existing.iter().any(|record| same_key(record, request));
+            *plan = Some(match merge_strategy {
+                RecordMergeStrategy::SkipExisting if conflict => ImportPlan::Skip,
+                RecordMergeStrategy::SkipExisting => ImportPlan::Create,
+                RecordMergeStrategy::Overwrite => existing
+                    .iter()
+                    .enumerate()
+                    .position(|(i, record)| !claimed[i] && same_key(record, request))
+                    .map_or(ImportPlan::Create, |index| {
+                        claimed[index] = true;
+                        ImportPlan::Update(existing[index].id.clone())
+                    }),
+            });
+        }
+
+        let mut outcomes: Vec<RecordImportOutcome> = requests
+            .iter()
+            .map(|request| RecordImportOutcome {
+                name: canonical_record_name(&request.name, &origin),
+                record_type: request.data.record_type(),
+                value: request.data.display_value(),
+                action: RecordImportAction::Skipped,
+                record_id: None,
+                reason: None,
+            })
+            .collect();
+
+        // 2. 批量创建新记录
+        let (create_indices, to_create): (Vec<usize>, Vec<CreateDnsRecordRequest>) = plans
+            .iter()
+            .zip(&requests)
+            .enumerate()
+            .filter(|(_, (plan, _))| matches!(plan, Some(ImportPlan::Create)))
+            .map(|(index, (_, request))| (index, request.clone()))
+            .unzip();
+        let mut created_records = Vec::new();
+        if !to_create.is_empty() {
+            match self
+                .ctx
+                .observe_provider_call(
+                    provider.as_ref(),
+                    "batch_create_records",
+                    provider.batch_create_records(&to_create),
+                )
+                .await
+            {
+                Ok(result) => {
+                    for failure in &result.failures {
+                        if let Some(&index) = create_indices.get(failure.request_index) {
+                            outcomes[index].action = RecordImportAction::Failed;
+                            outcomes[index].reason = Some(failure.reason.clone());
+                        }
+                    }
+                    // 创建成功的记录与未失败的请求按顺序对应
+                    let succeeded: Vec<usize> = create_indices
+                        .iter()
+                        .copied()
+                        .filter(|&index| outcomes[index].action != RecordImportAction::Failed)
+                        .collect();
+                    for (index, record) in succeeded.into_iter().zip(&result.created_records) {
+                        outcomes[index].action = RecordImportAction::Created;
+                        outcomes[index].record_id = Some(record.id.clone());
+                    }
+                    created_records = result.created_records;
+                }
+                Err(e) => {
+                    let reason = self.handle_provider_error(account_id, e).await.to_string();
+                    for &index in &create_indices {
+                        outcomes[index].action = RecordImportAction::Failed;
+                        outcomes[index].reason = Some(reason.clone());
+                    }
+                }
+            }
+        }
+
+        // 3. 更新已有记录（并发数受 Provider 的 batch_concurrency 限制）
+        let concurrency = provider.batch_concurrency().max(1);
+        let ctx = &self.ctx;
+        let update_futures: Vec<_> = plans
+            .iter()
+            .zip(&requests)
+            .enumerate()
+            .filter_map(|(index, (plan, request))| match plan {
+                Some(ImportPlan::Update(record_id)) => Some((index, record_id, request)),
+                _ => None,
+            })
+            .map(|(index, record_id, request)| {
+                let provider = provider.clone();
+                let update = UpdateDnsRecordRequest {
+                    domain_id: domain_id.to_string(),
+                    name: request.name.clone(),
+                    ttl: request.ttl,
+                    data: request.data.clone(),
+                    proxied: request.proxied,
+                    comment: request.comment.clone(),
+                    tags: request.tags.clone(),
+                };
+                async move {
+                    let result = ctx
+                        .observe_provider_call(
+                            provider.as_ref(),
+                            "update_record",
+                            provider.update_record(record_id, &update),
+                        )
+                        .await;
+                    (index, result)
+                }
+            })
+            .collect();
+        let update_results: Vec<_> = futures::stream::iter(update_futures)
+            .buffered(concurrency)
+            .collect()
+            .await;
+
+        let mut updated_records = Vec::new();
+        for (index, result) in update_results {
+            match result {
+                Ok(record) => {
+                    outcomes[index].action = RecordImportAction::Updated;
+                    outcomes[index].record_id = Some(record.id.clone());
+                    updated_records.push(record);
+                }
+                Err(e) => {
+                    outcomes[index].action = RecordImportAction::Failed;
+                    outcomes[index].reason =
+                        Some(self.handle_provider_error(account_id, e).await.to_string());
+                }
+            }
+        }
+
+        // 4. 缓存失效与审计
+        if !created_records.is_empty() || !updated_records.is_empty() {
+            self.ctx.domain_cache.invalidate(account_id).await;
+        }
+        for record in &created_records {
+            self.ctx
+                .audit(
+                    AuditEvent::new(account_id, AuditOperation::CreateRecord, &record.id)
+                        .with_after(record_summary(record)),
+                )
+                .await;
+        }
+        for record in &updated_records {
+            self.ctx
+                .audit(
+                    AuditEvent::new(account_id, AuditOperation::UpdateRecord, &record.id)
+                        .with_after(record_summary(record)),
+                )
+                .await;
+        }
+
+        let count = |action| outcomes.iter().filter(|o| o.action == action).count();
+        Ok(RecordImportResult {
+            created_count: count(RecordImportAction::Created),
+            updated_count: count(RecordImportAction::Updated),
+            skipped_count: count(RecordImportAction::Skipped),
+            failed_count: count(RecordImportAction::Failed),
+            outcomes,
+        })
+    }
+
     /// 处理 Provider 错误，如果是凭证失效则更新账户状态
     async fn handle_provider_error(&self, account_id: &str, err: ProviderError) -> CoreError {
         if let ProviderError::InvalidCredentials { .. } = &err {
@@ -154,3 +811,102 @@ impl DnsService {
         CoreError::Provider(err)
     }
 }
+
+/// 生成记录的审计摘要，如 `www A 1.2.3.4 TTL=600`
+fn record_summary(record: &DnsRecord) -> String {
+    let record_type = format!("{:?}", record.data.record_type()).to_uppercase();
+    format!(
+        "{} {record_type} {} TTL={}",
+        record.name,
+        record.data.display_value(),
+        record.ttl
+    )
+}
+
+/// JSON 格式的记录导出内容
+#[derive(Serialize)]
+struct RecordExportJson<'a> {
+    origin: &'a str,
+    records: &'a [DnsRecord],
+}
+
+/// 单条导入记录的处理计划
+enum ImportPlan {
+    /// 已存在，跳过
+    Skip,
+    /// 新建
+    Create,
+    /// 更新指定 ID 的已有记录
+    Update(String),
+}
+
+/// JSON 格式的导入内容（与导出格式兼容，多余字段忽略）
+#[derive(Deserialize)]
+struct RecordImportJson {
+    records: Vec<RecordImportJsonRecord>,
+}
+
+/// JSON 导入中的单条记录
+#[derive(Deserialize)]
+struct RecordImportJsonRecord {
+    name: String,
+    ttl: u32,
+    data: RecordData,
+    #[serde(default)]
+    proxied: Option<bool>,
+    #[serde(default)]
+    comment: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// 按格式解析导入内容，所有请求的 `domain_id` 设为目标域名
+fn parse_record_import(
+    content: &str,
+    format: RecordExportFormat,
+    origin: &str,
+    domain_id: &str,
+) -> CoreResult<Vec<CreateDnsRecordRequest>> {
+    let requests = match format {
+        RecordExportFormat::Bind => parse_zone_file(content, origin)?,
+        RecordExportFormat::Csv => records_from_csv(content, domain_id)?,
+        RecordExportFormat::Json => {
+            let file: RecordImportJson = serde_json::from_str(content)
+                .map_err(|e| CoreError::ValidationError(format!("无效的记录 JSON: {e}")))?;
+            file.records
+                .into_iter()
+                .map(|record| CreateDnsRecordRequest {
+                    domain_id: String::new(),
+                    name: record.name,
+                    ttl: record.ttl,
+                    data: record.data,
+                    proxied: record.proxied,
+                    comment: record.comment,
+                    tags: record.tags,
+                    routing_policy: None,
+                })
+                .collect()
+        }
+    };
+
+    requests
+        .into_iter()
+        .map(|request| {
+            Ok(CreateDnsRecordRequest {
+                domain_id: domain_id.to_string(),
+                name: record_name_to_ascii(&request.name)?,
+                ..request
+            })
+        })
+        .collect()
+}
+
+/// 拉取域名下的全部记录（分页与游标转发由 Provider 的默认实现处理）
+async fn list_all_records(
+    provider: &dyn DnsProvider,
+    domain_id: &str,
+) -> Result<Vec<DnsRecord>, ProviderError> {
+    provider
+        .list_all_records_cancellable(domain_id, &CancellationToken::new())
+        .await
+}
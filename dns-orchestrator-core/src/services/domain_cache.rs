@@ -0,0 +1,103 @@
+//! 域名列表缓存
+//!
+//! 按账户缓存 Provider 返回的域名分页结果，减少重复的 API 调用
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use dns_orchestrator_provider::{PaginatedResponse, ProviderDomain};
+use tokio::sync::RwLock;
+
+/// 缓存条目
+struct CachedDomainPage {
+    /// Provider 返回的分页结果
+    response: PaginatedResponse<ProviderDomain>,
+    /// 写入时间
+    cached_at: Instant,
+}
+
+/// 单个账户的分页缓存，键为 `(page, page_size)`
+type AccountPages = HashMap<(u32, u32), CachedDomainPage>;
+
+/// 域名列表缓存
+///
+/// 以 `account_id` 为键，同一账户下按 `(page, page_size)` 区分分页。
+/// 未设置 TTL 时缓存处于禁用状态。
+pub struct DomainListCache {
+    ttl: Option<Duration>,
+    entries: RwLock<HashMap<String, AccountPages>>,
+}
+
+impl DomainListCache {
+    /// 创建缓存，`ttl` 为 `None` 时禁用缓存
+    #[must_use]
+    pub fn new(ttl: Option<Duration>) -> Self {
+        Self {
+            ttl,
+            entries: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// 缓存是否启用
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.ttl.is_some()
+    }
+
+    /// 读取未过期的缓存条目
+    pub async fn get(
+        &self,
+        account_id: &str,
+        page: u32,
+        page_size: u32,
+    ) -> Option<PaginatedResponse<ProviderDomain>> {
+        let ttl = self.ttl?;
+        let entries = self.entries.read().await;
+        entries
+            .get(account_id)?
+            .get(&(page, page_size))
+            .filter(|entry| entry.cached_at.elapsed() < ttl)
+            .map(|entry| entry.response.clone())
+    }
+
+    /// 写入缓存条目
+    pub async fn insert(
+        &self,
+        account_id: &str,
+        page: u32,
+        page_size: u32,
+        response: PaginatedResponse<ProviderDomain>,
+    ) {
+        if self.ttl.is_none() {
+            return;
+        }
+        self.entries
+            .write()
+            .await
+            .entry(account_id.to_string())
+            .or_default()
+            .insert(
+                (page, page_size),
+                CachedDomainPage {
+                    response,
+                    cached_at: Instant::now(),
+                },
+            );
+    }
+
+    /// 使指定账户的所有缓存失效
+    pub async fn invalidate(&self, account_id: &str) {
+        self.entries.write().await.remove(account_id);
+    }
+
+    /// 清空所有缓存
+    pub async fn clear(&self) {
+        self.entries.write().await.clear();
+    }
+}
+
+impl Default for DomainListCache {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
@@ -2,7 +2,8 @@
 
 use std::sync::Arc;
 
-use dns_orchestrator_provider::ProviderError;
+use dns_orchestrator_provider::{DnsProvider, ProviderDomain, ProviderError};
+use futures::future::join_all;
 
 use crate::error::{CoreError, CoreResult};
 use crate::services::{DomainMetadataService, ServiceContext};
@@ -21,20 +22,57 @@ impl DomainService {
     }
 
     /// 列出账号下的所有域名（分页）
+    ///
+    /// 启用域名列表缓存时优先返回未过期的缓存，`refresh` 为 true 时跳过缓存。
     pub async fn list_domains(
         &self,
         account_id: &str,
         page: Option<u32>,
         page_size: Option<u32>,
+        refresh: bool,
     ) -> CoreResult<PaginatedResponse<AppDomain>> {
         let provider = self.ctx.get_provider(account_id).await?;
 
         let params = PaginationParams {
             page: page.unwrap_or(1),
             page_size: page_size.unwrap_or(20),
+            cursor: None,
         };
 
-        match provider.list_domains(&params).await {
+        let cached = if refresh || !self.ctx.domain_cache.is_enabled() {
+            None
+        } else {
+            let cached = self
+                .ctx
+                .domain_cache
+                .get(account_id, params.page, params.page_size)
+                .await;
+            self.ctx
+                .metrics
+                .record_cache_lookup("domain_list", cached.is_some());
+            cached
+        };
+        let result = if let Some(response) = cached {
+            Ok(response)
+        } else {
+            let result = self
+                .ctx
+                .observe_provider_call(
+                    provider.as_ref(),
+                    "list_domains",
+                    provider.list_domains(&params),
+                )
+                .await;
+            if let Ok(response) = &result {
+                self.ctx
+                    .domain_cache
+                    .insert(account_id, params.page, params.page_size, response.clone())
+                    .await;
+            }
+            result
+        };
+
+        match result {
             Ok(lib_response) => {
                 let mut domains: Vec<AppDomain> = lib_response
                     .items
@@ -76,7 +114,15 @@ impl DomainService {
     pub async fn get_domain(&self, account_id: &str, domain_id: &str) -> CoreResult<AppDomain> {
         let provider = self.ctx.get_provider(account_id).await?;
 
-        match provider.get_domain(domain_id).await {
+        match self
+            .ctx
+            .observe_provider_call(
+                provider.as_ref(),
+                "get_domain",
+                provider.get_domain(domain_id),
+            )
+            .await
+        {
             Ok(provider_domain) => Ok(AppDomain::from_provider(
                 provider_domain,
                 account_id.to_string(),
@@ -85,6 +131,43 @@ impl DomainService {
         }
     }
 
+    /// 在所有已注册账户中查找指定域名
+    ///
+    /// 并发查询各账户的域名列表，返回所有匹配项（账户 ID 与域名）。
+    /// 单个账户查询失败时仅记录日志，不影响其他账户的结果。
+    pub async fn find_domain_across_accounts(&self, name: &str) -> Vec<(String, AppDomain)> {
+        let target = normalize_domain_name(name);
+        let account_ids = self.ctx.provider_registry.list_account_ids().await;
+
+        let futures = account_ids.into_iter().map(|account_id| {
+            let target = &target;
+            async move {
+                let provider = self.ctx.get_provider(&account_id).await.ok()?;
+                match self
+                    .ctx
+                    .observe_provider_call(
+                        provider.as_ref(),
+                        "find_domain",
+                        find_domain_in_provider(provider.as_ref(), target),
+                    )
+                    .await
+                {
+                    Ok(found) => found.map(|d| {
+                        let domain = AppDomain::from_provider(d, account_id.clone());
+                        (account_id, domain)
+                    }),
+                    Err(e) => {
+                        log::warn!("Failed to search domains in account {account_id}: {e}");
+                        self.handle_provider_error(&account_id, e).await;
+                        None
+                    }
+                }
+            }
+        });
+
+        join_all(futures).await.into_iter().flatten().collect()
+    }
+
     /// 处理 Provider 错误，如果是凭证失效则更新账户状态
     async fn handle_provider_error(&self, account_id: &str, err: ProviderError) -> CoreError {
         if let ProviderError::InvalidCredentials { .. } = &err {
@@ -95,3 +178,39 @@ impl DomainService {
         CoreError::Provider(err)
     }
 }
+
+/// 跨账户搜索时每页拉取的域名数量
+const SEARCH_PAGE_SIZE: u32 = 100;
+
+/// 规范化域名（小写、去掉末尾的点）
+fn normalize_domain_name(name: &str) -> String {
+    name.trim().trim_end_matches('.').to_ascii_lowercase()
+}
+
+/// 逐页遍历 Provider 的域名列表，查找名称匹配的域名
+async fn find_domain_in_provider(
+    provider: &dyn DnsProvider,
+    target: &str,
+) -> Result<Option<ProviderDomain>, ProviderError> {
+    let mut params = PaginationParams {
+        page: 1,
+        page_size: SEARCH_PAGE_SIZE,
+        cursor: None,
+    };
+
+    loop {
+        let response = provider.list_domains(&params).await?;
+        if let Some(domain) = response
+            .items
+            .into_iter()
+            .find(|d| normalize_domain_name(&d.name) == target)
+        {
+            return Ok(Some(domain));
+        }
+        if !response.has_more {
+            return Ok(None);
+        }
+        params.page += 1;
+        params.cursor = response.next_cursor;
+    }
+}
@@ -3,17 +3,26 @@
 use std::collections::HashSet;
 use std::sync::Arc;
 
-use dns_orchestrator_provider::{create_provider, ProviderCredentials};
+use dns_orchestrator_provider::{create_provider, ProviderCredentials, ProviderError};
 
 use crate::crypto;
 use crate::error::{CoreError, CoreResult};
 use crate::services::ServiceContext;
 use crate::types::{
     Account, AccountStatus, ExportAccountsRequest, ExportAccountsResponse, ExportFile,
-    ExportFileHeader, ExportedAccount, ImportAccountsRequest, ImportFailure, ImportPreview,
-    ImportPreviewAccount, ImportResult,
+    ExportFileHeader, ExportedAccount, ImportAccountAction, ImportAccountsRequest, ImportFailure,
+    ImportMergeStrategy, ImportPreview, ImportPreviewAccount, ImportResult,
 };
 
+/// 单个账号的导入计划
+struct ImportPlan {
+    action: ImportAccountAction,
+    /// 导入后的账号名称
+    name: String,
+    /// 被覆盖的现有账号（仅 `Overwrite`）
+    target: Option<Account>,
+}
+
 /// 账户导入导出服务
 pub struct ImportExportService {
     ctx: Arc<ServiceContext>,
@@ -35,16 +44,16 @@ impl ImportExportService {
         let export_file: ExportFile = serde_json::from_str(content)
             .map_err(|e| CoreError::ImportExportError(format!("无效的导入文件: {e}")))?;
 
-        // 2. 检查文件格式版本并获取加密参数
-        let kdf_iterations = if export_file.header.encrypted {
-            crypto::get_pbkdf2_iterations(export_file.header.version).ok_or_else(|| {
+        // 2. 检查文件格式版本并获取密钥派生算法
+        let kdf = if export_file.header.encrypted {
+            Some(crypto::get_kdf(export_file.header.version).ok_or_else(|| {
                 CoreError::ImportExportError(format!(
                     "不支持的文件版本: {}",
                     export_file.header.version
                 ))
-            })?
+            })?)
         } else {
-            0 // 未加密文件不需要迭代次数
+            None // 未加密文件不需要密钥派生
         };
 
         // 3. 如果加密但未提供密码，返回 None 表示需要密码
@@ -57,33 +66,41 @@ impl ImportExportService {
             let password = password
                 .ok_or_else(|| CoreError::ImportExportError("加密文件需要提供密码".to_string()))?;
 
-            log::info!(
-                "解密版本 {} 的文件，使用 PBKDF2-HMAC-SHA256 ({} 次迭代)",
-                export_file.header.version,
-                kdf_iterations
-            );
+            match kdf {
+                Some(crypto::Kdf::Pbkdf2 { iterations }) => log::info!(
+                    "解密版本 {} 的文件，使用 PBKDF2-HMAC-SHA256 ({iterations} 次迭代)",
+                    export_file.header.version
+                ),
+                _ => log::info!(
+                    "解密版本 {} 的文件，使用 Argon2id",
+                    export_file.header.version
+                ),
+            }
 
             let ciphertext = export_file
                 .data
                 .as_str()
                 .ok_or_else(|| CoreError::ImportExportError("无效的加密数据".to_string()))?;
-            let salt = export_file
-                .header
-                .salt
-                .as_ref()
-                .ok_or_else(|| CoreError::ImportExportError("缺少加密盐值".to_string()))?;
-            let nonce = export_file
-                .header
-                .nonce
-                .as_ref()
-                .ok_or_else(|| CoreError::ImportExportError("缺少加密 nonce".to_string()))?;
-
-            // 使用版本对应的迭代次数解密
-            let plaintext =
-                crypto::decrypt_with_iterations(ciphertext, password, salt, nonce, kdf_iterations)
-                    .map_err(|_| {
-                        CoreError::ImportExportError("解密失败，请检查密码是否正确".to_string())
-                    })?;
+            // 新文件的盐值与 nonce 打包在自描述密文中；头部带有二者的是旧格式
+            let plaintext = match (&export_file.header.salt, &export_file.header.nonce) {
+                (None, None) => crypto::decrypt_auto(ciphertext, password),
+                (Some(salt), Some(nonce)) => crypto::decrypt_with_version(
+                    ciphertext,
+                    password,
+                    salt,
+                    nonce,
+                    export_file.header.version,
+                ),
+                (None, Some(_)) => {
+                    return Err(CoreError::ImportExportError("缺少加密盐值".to_string()))
+                }
+                (Some(_), None) => {
+                    return Err(CoreError::ImportExportError("缺少加密 nonce".to_string()))
+                }
+            }
+            .map_err(|_| {
+                CoreError::ImportExportError("解密失败，请检查密码是否正确".to_string())
+            })?;
 
             serde_json::from_slice(&plaintext)
                 .map_err(|e| CoreError::ImportExportError(format!("解析账号数据失败: {e}")))?
@@ -92,9 +109,87 @@ impl ImportExportService {
                 .map_err(|e| CoreError::ImportExportError(format!("解析账号数据失败: {e}")))?
         };
 
+        // 5. 校验账号数量与文件头一致
+        if let Some(count) = export_file
+            .header
+            .account_count
+            .filter(|count| *count != accounts.len())
+        {
+            return Err(CoreError::ImportExportError(format!(
+                "账号数量与文件头不符: 文件头为 {count}，实际为 {}",
+                accounts.len()
+            )));
+        }
+
         Ok((export_file, Some(accounts)))
     }
 
+    /// 按合并策略为每个待导入账号规划处理方式
+    ///
+    /// 重名判断同时考虑现有账号和本次已规划导入的账号；
+    /// `Overwrite` 时每个现有账号最多被覆盖一次，文件内的后续同名账号会被跳过。
+    fn plan_imports(
+        accounts: &[ExportedAccount],
+        existing: &[Account],
+        strategy: ImportMergeStrategy,
+    ) -> Vec<ImportPlan> {
+        let mut taken: HashSet<String> = existing.iter().map(|a| a.name.clone()).collect();
+        let mut overwritten: HashSet<&str> = HashSet::new();
+
+        accounts
+            .iter()
+            .map(|exported| {
+                let name = exported.name.clone();
+                let skip = |name: String| ImportPlan {
+                    action: ImportAccountAction::Skip,
+                    name,
+                    target: None,
+                };
+
+                if taken.insert(name.clone()) {
+                    return ImportPlan {
+                        action: ImportAccountAction::Create,
+                        name,
+                        target: None,
+                    };
+                }
+
+                match strategy {
+                    ImportMergeStrategy::SkipExisting => skip(name),
+                    ImportMergeStrategy::Overwrite => {
+                        match existing
+                            .iter()
+                            .find(|a| a.name == name && !overwritten.contains(a.id.as_str()))
+                        {
+                            Some(target) => {
+                                overwritten.insert(target.id.as_str());
+                                ImportPlan {
+                                    action: ImportAccountAction::Overwrite,
+                                    name,
+                                    target: Some(target.clone()),
+                                }
+                            }
+                            None => skip(name),
+                        }
+                    }
+                    ImportMergeStrategy::Rename => {
+                        // 候选数量多于已占用的名称，一定能找到可用名称
+                        let new_name = (2..=taken.len() + 2)
+                            .map(|n| format!("{name} ({n})"))
+                            .find(|candidate| !taken.contains(candidate))
+                            .unwrap_or(name);
+                        taken.insert(new_name.clone());
+                        ImportPlan {
+                            action: ImportAccountAction::Rename,
+                            name: new_name,
+                            target: None,
+                        }
+                    }
+                }
+            })
+            .collect()
+    }
+
     /// 导出账户
     ///
     /// # Arguments
@@ -106,16 +201,23 @@ impl ImportExportService {
         app_version: &str,
     ) -> CoreResult<ExportAccountsResponse> {
         // 1. 获取选中账号的元数据
+        if request.account_ids.is_empty() {
+            return Err(CoreError::NoAccountsSelected);
+        }
+
         let all_accounts = self.ctx.account_repository.find_all().await?;
+        if let Some(missing) = request
+            .account_ids
+            .iter()
+            .find(|id| !all_accounts.iter().any(|a| &a.id == *id))
+        {
+            return Err(CoreError::AccountNotFound(missing.clone()));
+        }
         let selected_accounts: Vec<&Account> = all_accounts
             .iter()
             .filter(|a| request.account_ids.contains(&a.id))
             .collect();
 
-        if selected_accounts.is_empty() {
-            return Err(CoreError::NoAccountsSelected);
-        }
-
         // 2. 加载凭证并构建导出数据
         let mut exported_accounts = Vec::new();
         for account in selected_accounts {
@@ -141,6 +243,7 @@ impl ImportExportService {
         }
 
         // 3. 序列化账号数据
+        let account_count = Some(exported_accounts.len());
         let accounts_json = serde_json::to_value(&exported_accounts)
             .map_err(|e| CoreError::SerializationError(e.to_string()))?;
 
@@ -156,19 +259,20 @@ impl ImportExportService {
             let plaintext = serde_json::to_vec(&accounts_json)
                 .map_err(|e| CoreError::SerializationError(e.to_string()))?;
 
-            let (salt, nonce, ciphertext) = crypto::encrypt(&plaintext, password)
+            let payload = crypto::encrypt_payload(&plaintext, password)
                 .map_err(|e| CoreError::ImportExportError(e.to_string()))?;
 
             ExportFile {
                 header: ExportFileHeader {
                     version: crypto::CURRENT_FILE_VERSION,
                     encrypted: true,
-                    salt: Some(salt),
-                    nonce: Some(nonce),
+                    salt: None,
+                    nonce: None,
                     exported_at: now.to_rfc3339(),
                     app_version: app_version.to_string(),
+                    account_count,
                 },
-                data: serde_json::Value::String(ciphertext),
+                data: serde_json::Value::String(payload),
             }
         } else {
             ExportFile {
@@ -179,6 +283,7 @@ impl ImportExportService {
                     nonce: None,
                     exported_at: now.to_rfc3339(),
                     app_version: app_version.to_string(),
+                    account_count,
                 },
                 data: accounts_json,
             }
@@ -189,21 +294,34 @@ impl ImportExportService {
             .map_err(|e| CoreError::SerializationError(e.to_string()))?;
 
         let suggested_filename = format!(
-            "dns-orchestrator-backup-{}.dnso",
-            chrono::Local::now().format("%Y%m%d-%H%M%S")
+            "dns-orchestrator-backup-{}{}.dnso",
+            chrono::Local::now().format("%Y%m%d-%H%M%S"),
+            if request.encrypt { "" } else { "-unencrypted" }
         );
 
+        let warning = if request.encrypt {
+            None
+        } else {
+            log::warn!(
+                "未加密导出 {} 个账号，凭证以明文保存",
+                exported_accounts.len()
+            );
+            Some("此导出未加密：所有凭证均以明文保存，请妥善保管文件并在不再需要时删除".to_string())
+        };
+
         Ok(ExportAccountsResponse {
             content,
             suggested_filename,
+            warning,
         })
     }
 
-    /// 预览导入文件
+    /// 预览导入文件，并按合并策略给出每个账号的处理方式
     pub async fn preview_import(
         &self,
         content: &str,
         password: Option<&str>,
+        strategy: ImportMergeStrategy,
     ) -> CoreResult<ImportPreview> {
         // 1. 解析并解密
         let (export_file, accounts_opt) = Self::parse_and_decrypt_accounts(content, password)?;
@@ -212,7 +330,7 @@ impl ImportExportService {
         let Some(accounts) = accounts_opt else {
             return Ok(ImportPreview {
                 encrypted: true,
-                account_count: 0,
+                account_count: export_file.header.account_count.unwrap_or(0),
                 accounts: None,
             });
         };
@@ -221,13 +339,17 @@ impl ImportExportService {
         let existing_accounts = self.ctx.account_repository.find_all().await?;
         let existing_names: HashSet<_> =
             existing_accounts.iter().map(|a| a.name.as_str()).collect();
+        let plans = Self::plan_imports(&accounts, &existing_accounts, strategy);
 
         let preview_accounts: Vec<ImportPreviewAccount> = accounts
             .iter()
-            .map(|a| ImportPreviewAccount {
+            .zip(plans)
+            .map(|(a, plan)| ImportPreviewAccount {
                 name: a.name.clone(),
                 provider: a.provider.clone(),
                 has_conflict: existing_names.contains(a.name.as_str()),
+                action: plan.action,
+                import_name: plan.name,
             })
             .collect();
 
@@ -250,13 +372,24 @@ impl ImportExportService {
         let accounts = accounts_opt
             .ok_or_else(|| CoreError::ImportExportError("加密文件需要提供密码".to_string()))?;
 
-        // 2. 逐个导入账号
+        // 2. 按合并策略规划
+        let existing_accounts = self.ctx.account_repository.find_all().await?;
+        let plans = Self::plan_imports(&accounts, &existing_accounts, request.merge_strategy);
+
+        // 3. 逐个导入账号
         let mut success_count = 0;
         let mut failures = Vec::new();
+        let mut invalid_credentials = Vec::new();
+        let mut skipped = Vec::new();
         let now = chrono::Utc::now();
 
-        for exported in accounts {
-            // 2.1 转换凭证并创建 provider 实例
+        for (exported, plan) in accounts.into_iter().zip(plans) {
+            if plan.action == ImportAccountAction::Skip {
+                skipped.push(exported.name);
+                continue;
+            }
+
+            // 3.1 转换凭证并创建 provider 实例
             let credentials =
                 match ProviderCredentials::from_map(&exported.provider, &exported.credentials) {
                     Ok(c) => c,
@@ -279,10 +412,33 @@ impl ImportExportService {
                 }
             };
 
-            // 2.2 生成新的账号 ID
-            let account_id = uuid::Uuid::new_v4().to_string();
+            // 3.2 按需校验凭证（网络错误等无法判断的情况按有效处理）
+            let invalid_reason = if request.validate_credentials {
+                match provider.validate_credentials().await {
+                    Ok(true) => None,
+                    Ok(false) => {
+                        Some(CoreError::InvalidCredentials(provider.id().to_string()).to_string())
+                    }
+                    Err(e @ ProviderError::InvalidCredentials { .. }) => Some(e.to_string()),
+                    Err(e) => {
+                        log::warn!(
+                            "Failed to validate credentials for '{}': {e}",
+                            exported.name
+                        );
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+
+            // 3.3 覆盖时沿用现有账号 ID，否则生成新的
+            let account_id = plan
+                .target
+                .as_ref()
+                .map_or_else(|| uuid::Uuid::new_v4().to_string(), |a| a.id.clone());
 
-            // 2.3 保存凭证
+            // 3.4 保存凭证
             if let Err(e) = self
                 .ctx
                 .credential_store
@@ -296,28 +452,33 @@ impl ImportExportService {
                 continue;
             }
 
-            // 2.4 注册 provider
+            // 3.5 注册 provider（覆盖时替换原有实例）
             self.ctx
                 .provider_registry
                 .register(account_id.clone(), provider)
                 .await;
 
-            // 2.5 创建账号元数据
+            // 3.6 创建账号元数据
             let account = Account {
                 id: account_id.clone(),
-                name: exported.name.clone(),
+                name: plan.name,
                 provider: exported.provider,
-                created_at: now,
+                created_at: plan.target.as_ref().map_or(now, |a| a.created_at),
                 updated_at: now,
-                status: Some(AccountStatus::Active),
-                error: None,
+                status: Some(if invalid_reason.is_some() {
+                    AccountStatus::Error
+                } else {
+                    AccountStatus::Active
+                }),
+                error: invalid_reason.clone(),
             };
 
-            // 2.6 保存到仓库，失败时 cleanup
+            // 3.7 保存到仓库，失败时 cleanup（覆盖的账号保留，不做清理）
             if let Err(e) = self.ctx.account_repository.save(&account).await {
-                // Cleanup: 删除凭证和注销 provider
-                let _ = self.ctx.credential_store.remove(&account_id).await;
-                self.ctx.provider_registry.unregister(&account_id).await;
+                if plan.target.is_none() {
+                    let _ = self.ctx.credential_store.remove(&account_id).await;
+                    self.ctx.provider_registry.unregister(&account_id).await;
+                }
 
                 failures.push(ImportFailure {
                     name: exported.name,
@@ -326,12 +487,20 @@ impl ImportExportService {
                 continue;
             }
 
-            success_count += 1;
+            match invalid_reason {
+                Some(reason) => invalid_credentials.push(ImportFailure {
+                    name: exported.name,
+                    reason,
+                }),
+                None => success_count += 1,
+            }
         }
 
         Ok(ImportResult {
             success_count,
             failures,
+            invalid_credentials,
+            skipped,
         })
     }
 }
@@ -5,6 +5,7 @@ mod account_lifecycle_service;
 mod account_metadata_service;
 mod credential_management_service;
 mod dns_service;
+mod domain_cache;
 mod domain_metadata_service;
 mod domain_service;
 mod import_export_service;
@@ -17,22 +18,26 @@ pub use account_lifecycle_service::AccountLifecycleService;
 pub use account_metadata_service::AccountMetadataService;
 pub use credential_management_service::CredentialManagementService;
 pub use dns_service::DnsService;
+pub use domain_cache::DomainListCache;
 pub use domain_metadata_service::DomainMetadataService;
 pub use domain_service::DomainService;
 pub use import_export_service::ImportExportService;
 pub use migration_service::{MigrationResult, MigrationService};
 pub use provider_metadata_service::ProviderMetadataService;
-pub use toolbox::ToolboxService;
+pub use toolbox::{ResolverConfig, ToolboxOptions, ToolboxService};
 
+use std::future::Future;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use dns_orchestrator_provider::DnsProvider;
 
 use crate::error::{CoreError, CoreResult};
 use crate::traits::{
-    AccountRepository, CredentialStore, DomainMetadataRepository, ProviderRegistry,
+    AccountRepository, AuditSink, CallOutcome, CredentialStore, DomainMetadataRepository,
+    MetricsRecorder, NoopAuditSink, NoopMetricsRecorder, ProviderRegistry,
 };
-use crate::types::AccountStatus;
+use crate::types::{AccountStatus, AuditEvent};
 
 /// 服务上下文 - 持有所有依赖
 ///
@@ -46,6 +51,14 @@ pub struct ServiceContext {
     pub provider_registry: Arc<dyn ProviderRegistry>,
     /// 域名元数据仓库
     pub domain_metadata_repository: Arc<dyn DomainMetadataRepository>,
+    /// 域名列表缓存（默认禁用）
+    pub domain_cache: DomainListCache,
+    /// 审计日志输出（默认丢弃）
+    pub audit_sink: Arc<dyn AuditSink>,
+    /// 指标采集（默认丢弃）
+    pub metrics: Arc<dyn MetricsRecorder>,
+    /// 列出记录时是否把 punycode 记录名解码为 Unicode（默认关闭）
+    pub unicode_record_names: bool,
 }
 
 impl ServiceContext {
@@ -62,9 +75,43 @@ impl ServiceContext {
             account_repository,
             provider_registry,
             domain_metadata_repository,
+            domain_cache: DomainListCache::default(),
+            audit_sink: Arc::new(NoopAuditSink),
+            metrics: Arc::new(NoopMetricsRecorder),
+            unicode_record_names: false,
         }
     }
 
+    /// 设置审计日志输出
+    #[must_use]
+    pub fn with_audit_sink(mut self, audit_sink: Arc<dyn AuditSink>) -> Self {
+        self.audit_sink = audit_sink;
+        self
+    }
+
+    /// 设置指标采集
+    #[must_use]
+    pub fn with_metrics_recorder(mut self, metrics: Arc<dyn MetricsRecorder>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// 启用域名列表缓存，条目在 `ttl` 后过期
+    #[must_use]
+    pub fn with_domain_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.domain_cache = DomainListCache::new(Some(ttl));
+        self
+    }
+
+    /// 列出记录时把 punycode 记录名解码为 Unicode 显示
+    ///
+    /// 写入路径始终会把 Unicode 记录名转换回 punycode，因此解码后的名称可以直接用于更新。
+    #[must_use]
+    pub fn with_unicode_record_names(mut self, enabled: bool) -> Self {
+        self.unicode_record_names = enabled;
+        self
+    }
+
     /// 获取 Provider 实例
     pub async fn get_provider(&self, account_id: &str) -> CoreResult<Arc<dyn DnsProvider>> {
         self.provider_registry
@@ -91,4 +138,45 @@ impl ServiceContext {
         }
         log::warn!("Account {account_id} marked as invalid: {error_msg}");
     }
+
+    /// 执行一次 Provider 调用并上报耗时与结果
+    ///
+    /// 指标采集未启用时直接返回调用结果，不计时。
+    pub(crate) async fn observe_provider_call<T, E>(
+        &self,
+        provider: &dyn DnsProvider,
+        operation: &'static str,
+        call: impl Future<Output = Result<T, E>>,
+    ) -> Result<T, E> {
+        if !self.metrics.enabled() {
+            return call.await;
+        }
+
+        let started = Instant::now();
+        let result = call.await;
+        let outcome = if result.is_ok() {
+            CallOutcome::Success
+        } else {
+            CallOutcome::Error
+        };
+        self.metrics
+            .record_provider_call(provider.id(), operation, started.elapsed(), outcome);
+        result
+    }
+
+    /// 记录审计事件
+    ///
+    /// 写入失败时只记录日志，不影响调用方。
+    pub async fn audit(&self, event: AuditEvent) {
+        record_audit(self.audit_sink.as_ref(), event).await;
+    }
+}
+
+/// 写入审计事件，失败时记录日志
+pub(crate) async fn record_audit(sink: &dyn AuditSink, event: AuditEvent) {
+    let operation = event.operation;
+    let target = event.target.clone();
+    if let Err(e) = sink.record(event).await {
+        log::error!("Failed to record audit event {operation:?} on {target}: {e}");
+    }
 }
@@ -1,16 +1,32 @@
 //! DNS 查询模块
 
-use std::net::IpAddr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::str::FromStr;
 
+use base64::{engine::general_purpose::STANDARD, Engine};
 use futures::future::join_all;
 use hickory_resolver::{
-    config::{NameServerConfigGroup, ResolverConfig, ResolverOpts},
-    name_server::TokioConnectionProvider,
-    TokioResolver,
+    config::{ResolverConfig, ResolverOpts},
+    proto::{
+        op::{Edns, Message, MessageType, OpCode, Query, ResponseCode},
+        rr::{
+            rdata::{
+                opt::{ClientSubnet, EdnsCode, EdnsOption},
+                TLSA,
+            },
+            Name, RData, Record, RecordType,
+        },
+        ProtoErrorKind,
+    },
+    ResolveError, TokioResolver,
 };
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpStream, UdpSocket};
+use tokio::time::{timeout, Duration};
 
+use super::resolver::{self, build_resolver};
 use crate::error::{CoreError, CoreResult};
-use crate::types::{DnsLookupRecord, DnsLookupResult};
+use crate::types::{CaaRecord, DnsLookupRecord, DnsLookupResult};
 
 /// DNS 查询
 pub async fn dns_lookup(
@@ -18,306 +34,980 @@ pub async fn dns_lookup(
     record_type: &str,
     nameserver: Option<&str>,
 ) -> CoreResult<DnsLookupResult> {
-    // 获取系统默认 DNS 服务器地址
-    fn get_system_dns() -> String {
-        let config = ResolverConfig::default();
-        let servers: Vec<String> = config
-            .name_servers()
-            .iter()
-            .map(|ns| ns.socket_addr.ip().to_string())
-            .collect();
-        if servers.is_empty() {
-            "系统默认".to_string()
-        } else {
-            servers.join(", ")
-        }
+    dns_lookup_from(domain, record_type, nameserver, None).await
+}
+
+/// DNS 查询，可指定本地源地址
+///
+/// `bind_addr` 为 `Some` 时查询从该本机地址发出，用于多网卡环境下观察分地域（split-horizon）解析。
+pub async fn dns_lookup_from(
+    domain: &str,
+    record_type: &str,
+    nameserver: Option<&str>,
+    bind_addr: Option<IpAddr>,
+) -> CoreResult<DnsLookupResult> {
+    let config = resolver::ResolverConfig {
+        bind_addr,
+        ..resolver::ResolverConfig::default()
     }
+    .for_nameserver(nameserver);
+    dns_lookup_with_config(domain, record_type, &config).await
+}
 
-    // 根据 nameserver 参数决定使用自定义还是系统默认
-    let (resolver, used_nameserver) = if let Some(ns) = nameserver {
-        if ns.is_empty() {
-            let system_dns = get_system_dns();
-            let provider = TokioConnectionProvider::default();
-            let resolver = TokioResolver::builder_with_config(ResolverConfig::default(), provider)
-                .with_options(ResolverOpts::default())
-                .build();
-            (resolver, system_dns)
-        } else {
-            let ns_ip: IpAddr = ns
-                .parse()
-                .map_err(|_| CoreError::ValidationError(format!("无效的 DNS 服务器地址: {ns}")))?;
-
-            let config = ResolverConfig::from_parts(
-                None,
-                vec![],
-                NameServerConfigGroup::from_ips_clear(&[ns_ip], 53, true),
-            );
-            let provider = TokioConnectionProvider::default();
-            let resolver = TokioResolver::builder_with_config(config, provider)
-                .with_options(ResolverOpts::default())
-                .build();
-            (resolver, ns.to_string())
-        }
-    } else {
-        let system_dns = get_system_dns();
-        let provider = TokioConnectionProvider::default();
-        let resolver = TokioResolver::builder_with_config(ResolverConfig::default(), provider)
-            .with_options(ResolverOpts::default())
-            .build();
-        (resolver, system_dns)
-    };
+/// DNS 查询（使用指定的 resolver 配置）
+pub async fn dns_lookup_with_config(
+    domain: &str,
+    record_type: &str,
+    config: &resolver::ResolverConfig,
+) -> CoreResult<DnsLookupResult> {
+    let (resolver, used_nameserver) = build_resolver(config, ResolverOpts::default()).await?;
 
-    let mut records: Vec<DnsLookupRecord> = Vec::new();
     let record_type_upper = record_type.to_uppercase();
 
-    match record_type_upper.as_str() {
-        "A" => lookup_a(&resolver, domain, &mut records).await,
-        "AAAA" => lookup_aaaa(&resolver, domain, &mut records).await,
-        "MX" => lookup_mx(&resolver, domain, &mut records).await,
-        "TXT" => lookup_txt(&resolver, domain, &mut records).await,
-        "NS" => lookup_ns(&resolver, domain, &mut records).await,
-        "CNAME" => lookup_cname(&resolver, domain, &mut records).await,
-        "SOA" => lookup_soa(&resolver, domain, &mut records).await,
-        "SRV" => lookup_srv(&resolver, domain, &mut records).await,
-        "CAA" => lookup_caa(&resolver, domain, &mut records).await,
-        "PTR" => lookup_ptr(&resolver, domain, &mut records).await,
-        "ALL" => {
-            let types = vec![
-                "A", "AAAA", "CNAME", "MX", "TXT", "NS", "SOA", "SRV", "CAA", "PTR",
-            ];
-            let ns = nameserver.map(String::from);
-            let futures: Vec<_> = types
-                .into_iter()
-                .map(|t| {
-                    let ns = ns.clone();
-                    let domain = domain.to_string();
-                    async move { dns_lookup(&domain, t, ns.as_deref()).await }
-                })
-                .collect();
-
-            let results = join_all(futures).await;
-            for result in results.into_iter().flatten() {
-                records.extend(result.records);
-            }
-        }
-        _ => {
-            return Err(CoreError::ValidationError(format!(
-                "不支持的记录类型: {record_type}"
-            )));
-        }
+    if record_type_upper != "ALL" {
+        let mut records: Vec<DnsLookupRecord> = Vec::new();
+        let nxdomain =
+            lookup_record_type(&resolver, domain, &record_type_upper, &mut records).await?;
+        return Ok(DnsLookupResult {
+            nameserver: used_nameserver,
+            records,
+            nxdomain,
+        });
     }
 
+    // 各类型共用同一个 resolver 并发查询，join_all 保持类型顺序
+    let resolver = &resolver;
+    let lookups = ALL_RECORD_TYPES.iter().map(|record_type| async move {
+        let mut records = Vec::new();
+        lookup_record_type(resolver, domain, record_type, &mut records)
+            .await
+            .map(|nxdomain| (nxdomain, records))
+    });
+
+    let (records, nxdomain) = merge_type_results(join_all(lookups).await)?;
+
     Ok(DnsLookupResult {
         nameserver: used_nameserver,
         records,
+        nxdomain,
     })
 }
 
-async fn lookup_a(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver.ipv4_lookup(domain).await {
-        for ip in response.iter() {
-            records.push(DnsLookupRecord {
-                record_type: "A".to_string(),
-                name: domain.to_string(),
-                value: ip.to_string(),
-                ttl: response
-                    .as_lookup()
-                    .record_iter()
-                    .next()
-                    .map_or(0, hickory_resolver::proto::rr::Record::ttl),
-                priority: None,
-            });
+/// 合并 "ALL" 查询中各类型的结果（按传入顺序），返回 (记录, 是否 NXDOMAIN)
+///
+/// 部分类型失败时保留其余类型的结果，全部失败才视为查询失败并返回第一个错误。
+fn merge_type_results(
+    results: Vec<CoreResult<(bool, Vec<DnsLookupRecord>)>>,
+) -> CoreResult<(Vec<DnsLookupRecord>, bool)> {
+    let mut records: Vec<DnsLookupRecord> = Vec::new();
+    let mut nxdomain = false;
+    let mut first_error = None;
+    let mut succeeded = false;
+    for result in results {
+        match result {
+            Ok((type_nxdomain, type_records)) => {
+                succeeded = true;
+                nxdomain |= type_nxdomain;
+                records.extend(type_records);
+            }
+            Err(e) => {
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    match (succeeded, first_error) {
+        (false, Some(e)) => Err(e),
+        _ => Ok((records, nxdomain)),
+    }
+}
+
+/// "ALL" 查询包含的记录类型（按输出顺序）
+///
+/// TLSA、SMIMEA、OPENPGPKEY 位于专用的子域名下，不参与 "ALL" 查询。
+const ALL_RECORD_TYPES: &[&str] = &[
+    "A", "AAAA", "CNAME", "MX", "TXT", "NS", "SOA", "SRV", "CAA", "PTR", "HTTPS", "SVCB", "URI",
+    "LOC", "HINFO",
+];
+
+/// hickory 未内置的记录类型代码
+const LOC_TYPE_CODE: u16 = 29;
+const SMIMEA_TYPE_CODE: u16 = 53;
+const URI_TYPE_CODE: u16 = 256;
+
+/// 由通用查询处理的记录类型（结果按展示格式输出）
+fn generic_record_type(record_type: &str) -> Option<RecordType> {
+    match record_type {
+        "HINFO" => Some(RecordType::HINFO),
+        "SVCB" => Some(RecordType::SVCB),
+        "HTTPS" => Some(RecordType::HTTPS),
+        "OPENPGPKEY" => Some(RecordType::OPENPGPKEY),
+        "LOC" => Some(RecordType::Unknown(LOC_TYPE_CODE)),
+        "SMIMEA" => Some(RecordType::Unknown(SMIMEA_TYPE_CODE)),
+        "URI" => Some(RecordType::Unknown(URI_TYPE_CODE)),
+        _ => None,
+    }
+}
+
+/// 记录类型名称（hickory 未内置的类型按代码映射）
+fn record_type_name(record_type: RecordType) -> String {
+    match record_type {
+        RecordType::Unknown(LOC_TYPE_CODE) => "LOC".to_string(),
+        RecordType::Unknown(SMIMEA_TYPE_CODE) => "SMIMEA".to_string(),
+        RecordType::Unknown(URI_TYPE_CODE) => "URI".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// 查询单个记录类型，返回是否 NXDOMAIN
+async fn lookup_record_type(
+    resolver: &TokioResolver,
+    domain: &str,
+    record_type: &str,
+    records: &mut Vec<DnsLookupRecord>,
+) -> CoreResult<bool> {
+    let outcome = match record_type {
+        "A" => lookup_a(resolver, domain, records).await,
+        "AAAA" => lookup_aaaa(resolver, domain, records).await,
+        "MX" => lookup_mx(resolver, domain, records).await,
+        "TXT" => lookup_txt(resolver, domain, records).await,
+        "NS" => lookup_ns(resolver, domain, records).await,
+        "CNAME" => lookup_cname(resolver, domain, records).await,
+        "SOA" => lookup_soa(resolver, domain, records).await,
+        "SRV" => lookup_srv(resolver, domain, records).await,
+        "CAA" => lookup_caa(resolver, domain, records).await,
+        "TLSA" => lookup_tlsa(resolver, domain, records).await,
+        "PTR" => lookup_ptr(resolver, domain, records).await,
+        _ => {
+            let Some(query_type) = generic_record_type(record_type) else {
+                return Err(CoreError::ValidationError(format!(
+                    "不支持的记录类型: {record_type}"
+                )));
+            };
+            lookup_generic(resolver, domain, query_type, records).await
+        }
+    };
+
+    match outcome {
+        Ok(()) => Ok(false),
+        Err(e) => classify_lookup_error(&e, domain, record_type),
+    }
+}
+
+/// 区分"没有记录"与真正的查询失败
+///
+/// - NXDOMAIN：域名不存在，返回 `Ok(true)`
+/// - NOERROR 但无应答（NODATA）：返回 `Ok(false)`
+/// - 超时：`CoreError::Timeout`
+/// - SERVFAIL、REFUSED、连接失败等：`CoreError::NetworkError`
+fn classify_lookup_error(
+    error: &ResolveError,
+    domain: &str,
+    record_type: &str,
+) -> CoreResult<bool> {
+    if let Some(proto) = error.proto() {
+        match proto.kind() {
+            ProtoErrorKind::NoRecordsFound { response_code, .. } => {
+                return match *response_code {
+                    ResponseCode::NXDomain => Ok(true),
+                    ResponseCode::NoError => Ok(false),
+                    code => Err(CoreError::NetworkError(format!(
+                        "DNS 服务器返回错误: {code} ({domain} {record_type})"
+                    ))),
+                };
+            }
+            ProtoErrorKind::Timeout => {
+                return Err(CoreError::Timeout(format!(
+                    "DNS 查询超时 ({domain} {record_type})"
+                )));
+            }
+            _ => {}
         }
     }
+    Err(CoreError::NetworkError(format!(
+        "DNS 查询失败 ({domain} {record_type}): {error}"
+    )))
+}
+
+async fn lookup_a(
+    resolver: &TokioResolver,
+    domain: &str,
+    records: &mut Vec<DnsLookupRecord>,
+) -> Result<(), ResolveError> {
+    let response = resolver.ipv4_lookup(domain).await?;
+    for ip in response.iter() {
+        records.push(DnsLookupRecord {
+            record_type: "A".to_string(),
+            name: domain.to_string(),
+            value: ip.to_string(),
+            ttl: response
+                .as_lookup()
+                .record_iter()
+                .next()
+                .map_or(0, hickory_resolver::proto::rr::Record::ttl),
+            priority: None,
+            caa: None,
+        });
+    }
+    Ok(())
 }
 
-async fn lookup_aaaa(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver.ipv6_lookup(domain).await {
-        for ip in response.iter() {
+async fn lookup_aaaa(
+    resolver: &TokioResolver,
+    domain: &str,
+    records: &mut Vec<DnsLookupRecord>,
+) -> Result<(), ResolveError> {
+    let response = resolver.ipv6_lookup(domain).await?;
+    for ip in response.iter() {
+        records.push(DnsLookupRecord {
+            record_type: "AAAA".to_string(),
+            name: domain.to_string(),
+            value: ip.to_string(),
+            ttl: response
+                .as_lookup()
+                .record_iter()
+                .next()
+                .map_or(0, hickory_resolver::proto::rr::Record::ttl),
+            priority: None,
+            caa: None,
+        });
+    }
+    Ok(())
+}
+
+async fn lookup_mx(
+    resolver: &TokioResolver,
+    domain: &str,
+    records: &mut Vec<DnsLookupRecord>,
+) -> Result<(), ResolveError> {
+    let response = resolver.mx_lookup(domain).await?;
+    for mx in response.iter() {
+        records.push(DnsLookupRecord {
+            record_type: "MX".to_string(),
+            name: domain.to_string(),
+            value: mx.exchange().to_string().trim_end_matches('.').to_string(),
+            ttl: response
+                .as_lookup()
+                .record_iter()
+                .next()
+                .map_or(0, hickory_resolver::proto::rr::Record::ttl),
+            priority: Some(mx.preference()),
+            caa: None,
+        });
+    }
+    Ok(())
+}
+
+async fn lookup_txt(
+    resolver: &TokioResolver,
+    domain: &str,
+    records: &mut Vec<DnsLookupRecord>,
+) -> Result<(), ResolveError> {
+    let response = resolver.txt_lookup(domain).await?;
+    for txt in response.iter() {
+        let txt_data: String = txt
+            .iter()
+            .map(|data| String::from_utf8_lossy(data).to_string())
+            .collect::<String>();
+        records.push(DnsLookupRecord {
+            record_type: "TXT".to_string(),
+            name: domain.to_string(),
+            value: txt_data,
+            ttl: response
+                .as_lookup()
+                .record_iter()
+                .next()
+                .map_or(0, hickory_resolver::proto::rr::Record::ttl),
+            priority: None,
+            caa: None,
+        });
+    }
+    Ok(())
+}
+
+async fn lookup_ns(
+    resolver: &TokioResolver,
+    domain: &str,
+    records: &mut Vec<DnsLookupRecord>,
+) -> Result<(), ResolveError> {
+    let response = resolver.ns_lookup(domain).await?;
+    for ns in response.iter() {
+        records.push(DnsLookupRecord {
+            record_type: "NS".to_string(),
+            name: domain.to_string(),
+            value: ns.to_string().trim_end_matches('.').to_string(),
+            ttl: response
+                .as_lookup()
+                .record_iter()
+                .next()
+                .map_or(0, hickory_resolver::proto::rr::Record::ttl),
+            priority: None,
+            caa: None,
+        });
+    }
+    Ok(())
+}
+
+async fn lookup_cname(
+    resolver: &TokioResolver,
+    domain: &str,
+    records: &mut Vec<DnsLookupRecord>,
+) -> Result<(), ResolveError> {
+    let response = resolver
+        .lookup(domain, hickory_resolver::proto::rr::RecordType::CNAME)
+        .await?;
+    for record in response.record_iter() {
+        if let Some(cname) = record.data().as_cname() {
             records.push(DnsLookupRecord {
-                record_type: "AAAA".to_string(),
+                record_type: "CNAME".to_string(),
                 name: domain.to_string(),
-                value: ip.to_string(),
-                ttl: response
-                    .as_lookup()
-                    .record_iter()
-                    .next()
-                    .map_or(0, hickory_resolver::proto::rr::Record::ttl),
+                value: cname.0.to_string().trim_end_matches('.').to_string(),
+                ttl: record.ttl(),
                 priority: None,
+                caa: None,
             });
         }
     }
+    Ok(())
 }
 
-async fn lookup_mx(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver.mx_lookup(domain).await {
-        for mx in response.iter() {
+async fn lookup_soa(
+    resolver: &TokioResolver,
+    domain: &str,
+    records: &mut Vec<DnsLookupRecord>,
+) -> Result<(), ResolveError> {
+    let response = resolver.soa_lookup(domain).await?;
+    if let Some(soa) = response.iter().next() {
+        let value = format!(
+            "{} {} {} {} {} {} {}",
+            soa.mname().to_string().trim_end_matches('.'),
+            soa.rname().to_string().trim_end_matches('.'),
+            soa.serial(),
+            soa.refresh(),
+            soa.retry(),
+            soa.expire(),
+            soa.minimum()
+        );
+        records.push(DnsLookupRecord {
+            record_type: "SOA".to_string(),
+            name: domain.to_string(),
+            value,
+            ttl: response
+                .as_lookup()
+                .record_iter()
+                .next()
+                .map_or(0, hickory_resolver::proto::rr::Record::ttl),
+            priority: None,
+            caa: None,
+        });
+    }
+    Ok(())
+}
+
+async fn lookup_srv(
+    resolver: &TokioResolver,
+    domain: &str,
+    records: &mut Vec<DnsLookupRecord>,
+) -> Result<(), ResolveError> {
+    let response = resolver.srv_lookup(domain).await?;
+    for srv in response.iter() {
+        let value = format!(
+            "{} {} {}",
+            srv.weight(),
+            srv.port(),
+            srv.target().to_string().trim_end_matches('.')
+        );
+        records.push(DnsLookupRecord {
+            record_type: "SRV".to_string(),
+            name: domain.to_string(),
+            value,
+            ttl: response
+                .as_lookup()
+                .record_iter()
+                .next()
+                .map_or(0, hickory_resolver::proto::rr::Record::ttl),
+            priority: Some(srv.priority()),
+            caa: None,
+        });
+    }
+    Ok(())
+}
+
+async fn lookup_caa(
+    resolver: &TokioResolver,
+    domain: &str,
+    records: &mut Vec<DnsLookupRecord>,
+) -> Result<(), ResolveError> {
+    let response = resolver
+        .lookup(domain, hickory_resolver::proto::rr::RecordType::CAA)
+        .await?;
+    for record in response.record_iter() {
+        if let Some(caa) = record.data().as_caa() {
+            let parsed = CaaRecord {
+                flags: caa.flags(),
+                tag: caa.tag().as_str().to_string(),
+                value: String::from_utf8_lossy(caa.raw_value()).to_string(),
+            };
             records.push(DnsLookupRecord {
-                record_type: "MX".to_string(),
+                record_type: "CAA".to_string(),
                 name: domain.to_string(),
-                value: mx.exchange().to_string().trim_end_matches('.').to_string(),
-                ttl: response
-                    .as_lookup()
-                    .record_iter()
-                    .next()
-                    .map_or(0, hickory_resolver::proto::rr::Record::ttl),
-                priority: Some(mx.preference()),
+                value: format!("{} {} \"{}\"", parsed.flags, parsed.tag, parsed.value),
+                ttl: record.ttl(),
+                priority: None,
+                caa: Some(parsed),
             });
         }
     }
+    Ok(())
 }
 
-async fn lookup_txt(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver.txt_lookup(domain).await {
-        for txt in response.iter() {
-            let txt_data: String = txt
-                .iter()
-                .map(|data| String::from_utf8_lossy(data).to_string())
-                .collect::<String>();
+async fn lookup_tlsa(
+    resolver: &TokioResolver,
+    domain: &str,
+    records: &mut Vec<DnsLookupRecord>,
+) -> Result<(), ResolveError> {
+    let response = resolver
+        .lookup(domain, hickory_resolver::proto::rr::RecordType::TLSA)
+        .await?;
+    for record in response.record_iter() {
+        if let RData::TLSA(tlsa) = record.data() {
             records.push(DnsLookupRecord {
-                record_type: "TXT".to_string(),
+                record_type: "TLSA".to_string(),
                 name: domain.to_string(),
-                value: txt_data,
-                ttl: response
-                    .as_lookup()
-                    .record_iter()
-                    .next()
-                    .map_or(0, hickory_resolver::proto::rr::Record::ttl),
+                value: format_tlsa(tlsa),
+                ttl: record.ttl(),
                 priority: None,
+                caa: None,
             });
         }
     }
+    Ok(())
+}
+
+/// 格式化 TLSA 记录值：`usage selector matching_type 证书数据(hex)`
+fn format_tlsa(tlsa: &TLSA) -> String {
+    format!(
+        "{} {} {} {}",
+        u8::from(tlsa.cert_usage()),
+        u8::from(tlsa.selector()),
+        u8::from(tlsa.matching()),
+        hex::encode(tlsa.cert_data())
+    )
 }
 
-async fn lookup_ns(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver.ns_lookup(domain).await {
-        for ns in response.iter() {
+async fn lookup_ptr(
+    resolver: &TokioResolver,
+    domain: &str,
+    records: &mut Vec<DnsLookupRecord>,
+) -> Result<(), ResolveError> {
+    let response = resolver
+        .lookup(domain, hickory_resolver::proto::rr::RecordType::PTR)
+        .await?;
+    for record in response.record_iter() {
+        if let Some(ptr) = record.data().as_ptr() {
             records.push(DnsLookupRecord {
-                record_type: "NS".to_string(),
+                record_type: "PTR".to_string(),
                 name: domain.to_string(),
-                value: ns.to_string().trim_end_matches('.').to_string(),
-                ttl: response
-                    .as_lookup()
-                    .record_iter()
-                    .next()
-                    .map_or(0, hickory_resolver::proto::rr::Record::ttl),
+                value: ptr.0.to_string().trim_end_matches('.').to_string(),
+                ttl: record.ttl(),
                 priority: None,
+                caa: None,
             });
         }
     }
+    Ok(())
 }
 
-async fn lookup_cname(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver
-        .lookup(domain, hickory_resolver::proto::rr::RecordType::CNAME)
-        .await
-    {
-        for record in response.record_iter() {
-            if let Some(cname) = record.data().as_cname() {
-                records.push(DnsLookupRecord {
-                    record_type: "CNAME".to_string(),
-                    name: domain.to_string(),
-                    value: cname.0.to_string().trim_end_matches('.').to_string(),
-                    ttl: record.ttl(),
-                    priority: None,
-                });
-            }
-        }
+/// 查询其余记录类型，记录值按展示格式输出
+async fn lookup_generic(
+    resolver: &TokioResolver,
+    domain: &str,
+    query_type: RecordType,
+    records: &mut Vec<DnsLookupRecord>,
+) -> Result<(), ResolveError> {
+    let response = resolver.lookup(domain, query_type).await?;
+    records.extend(
+        response
+            .record_iter()
+            .filter(|record| record.record_type() == query_type)
+            .filter_map(|record| answer_to_lookup_record(domain, record)),
+    );
+    Ok(())
+}
+
+/// 格式化 URI 记录（RFC 7553）：`weight "target"`，priority 单独返回
+fn parse_uri(rdata: &[u8]) -> Option<(u16, String)> {
+    let (header, target) = rdata.split_at_checked(4)?;
+    let priority = u16::from_be_bytes([header[0], header[1]]);
+    let weight = u16::from_be_bytes([header[2], header[3]]);
+    Some((
+        priority,
+        format!("{weight} \"{}\"", String::from_utf8_lossy(target)),
+    ))
+}
+
+/// 格式化 SMIMEA 记录（RFC 8162，格式同 TLSA）
+fn format_smimea(rdata: &[u8]) -> Option<String> {
+    let (header, cert_data) = rdata.split_at_checked(3)?;
+    Some(format!(
+        "{} {} {} {}",
+        header[0],
+        header[1],
+        header[2],
+        hex::encode(cert_data)
+    ))
+}
+
+/// LOC 记录 RDATA 长度
+const LOC_RDATA_LEN: usize = 16;
+/// LOC 经纬度与海拔的基准偏移（RFC 1876）
+const LOC_EQUATOR: i64 = 1 << 31;
+const LOC_ALTITUDE_BASE_CM: i64 = 10_000_000;
+
+/// 格式化 LOC 记录（RFC 1876）：`纬度 经度 海拔 大小 水平精度 垂直精度`
+fn format_loc(rdata: &[u8]) -> Option<String> {
+    if rdata.len() != LOC_RDATA_LEN || rdata[0] != 0 {
+        return None;
     }
+    let read_u32 = |offset: usize| {
+        u32::from_be_bytes([
+            rdata[offset],
+            rdata[offset + 1],
+            rdata[offset + 2],
+            rdata[offset + 3],
+        ])
+    };
+
+    Some(format!(
+        "{} {} {} {} {} {}",
+        format_loc_coordinate(read_u32(4), 'N', 'S'),
+        format_loc_coordinate(read_u32(8), 'E', 'W'),
+        format_centimeters(i64::from(read_u32(12)) - LOC_ALTITUDE_BASE_CM),
+        format_centimeters(loc_precision_cm(rdata[1])?),
+        format_centimeters(loc_precision_cm(rdata[2])?),
+        format_centimeters(loc_precision_cm(rdata[3])?),
+    ))
 }
 
-async fn lookup_soa(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver.soa_lookup(domain).await {
-        if let Some(soa) = response.iter().next() {
-            let value = format!(
-                "{} {} {} {} {} {} {}",
-                soa.mname().to_string().trim_end_matches('.'),
-                soa.rname().to_string().trim_end_matches('.'),
-                soa.serial(),
-                soa.refresh(),
-                soa.retry(),
-                soa.expire(),
-                soa.minimum()
-            );
-            records.push(DnsLookupRecord {
-                record_type: "SOA".to_string(),
-                name: domain.to_string(),
-                value,
-                ttl: response
-                    .as_lookup()
-                    .record_iter()
-                    .next()
-                    .map_or(0, hickory_resolver::proto::rr::Record::ttl),
-                priority: None,
-            });
+/// 格式化 LOC 经纬度（单位为千分之一角秒，以 2^31 为赤道/本初子午线）
+fn format_loc_coordinate(raw: u32, positive: char, negative: char) -> String {
+    let offset = i64::from(raw) - LOC_EQUATOR;
+    let hemisphere = if offset < 0 { negative } else { positive };
+    let millis = offset.unsigned_abs();
+    format!(
+        "{} {} {}.{:03} {hemisphere}",
+        millis / 3_600_000,
+        millis % 3_600_000 / 60_000,
+        millis % 60_000 / 1000,
+        millis % 1000
+    )
+}
+
+/// 解析 LOC 大小/精度字段（高 4 位为底数、低 4 位为 10 的指数，单位厘米）
+fn loc_precision_cm(value: u8) -> Option<i64> {
+    let (base, exponent) = (value >> 4, value & 0x0f);
+    if base > 9 || exponent > 9 {
+        return None;
+    }
+    Some(i64::from(base) * 10_i64.pow(u32::from(exponent)))
+}
+
+/// 将厘米格式化为带两位小数的米
+fn format_centimeters(cm: i64) -> String {
+    let sign = if cm < 0 { "-" } else { "" };
+    let abs = cm.unsigned_abs();
+    format!("{sign}{}.{:02}m", abs / 100, abs % 100)
+}
+
+/// ECS 查询超时（秒）
+const ECS_QUERY_TIMEOUT_SECS: u64 = 5;
+
+/// 解析并校验 EDNS Client Subnet（如 `203.0.113.0/24`），主机位会被清零
+fn parse_client_subnet(subnet: &str) -> CoreResult<ClientSubnet> {
+    let invalid = || CoreError::ValidationError(format!("无效的子网: {subnet}"));
+
+    let (addr, prefix) = subnet.trim().split_once('/').ok_or_else(invalid)?;
+    let addr: IpAddr = addr.parse().map_err(|_| invalid())?;
+    let prefix: u8 = prefix.parse().map_err(|_| invalid())?;
+
+    let (addr, is_private) = match addr {
+        IpAddr::V4(v4) => {
+            if prefix > 32 {
+                return Err(invalid());
+            }
+            let mask = u32::MAX.checked_shl(u32::from(32 - prefix)).unwrap_or(0);
+            let masked = Ipv4Addr::from(u32::from(v4) & mask);
+            let private = v4.is_private()
+                || v4.is_loopback()
+                || v4.is_link_local()
+                || v4.is_unspecified()
+                || v4.is_broadcast();
+            (IpAddr::V4(masked), private)
         }
+        IpAddr::V6(v6) => {
+            if prefix > 128 {
+                return Err(invalid());
+            }
+            let mask = u128::MAX.checked_shl(u32::from(128 - prefix)).unwrap_or(0);
+            let masked = Ipv6Addr::from(u128::from(v6) & mask);
+            let first = v6.segments()[0];
+            let private = v6.is_loopback()
+                || v6.is_unspecified()
+                || (first & 0xfe00) == 0xfc00
+                || (first & 0xffc0) == 0xfe80;
+            (IpAddr::V6(masked), private)
+        }
+    };
+
+    // 私有地址段不会得到有意义的地理定位应答
+    if is_private {
+        log::warn!("Rejected private client subnet for ECS query: {subnet}");
+        return Err(CoreError::ValidationError(format!(
+            "不支持私有地址段作为客户端子网: {subnet}"
+        )));
     }
+
+    Ok(ClientSubnet::new(addr, prefix, 0))
 }
 
-async fn lookup_srv(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver.srv_lookup(domain).await {
-        for srv in response.iter() {
-            let value = format!(
+/// 将应答记录转换为查询结果
+fn answer_to_lookup_record(domain: &str, record: &Record) -> Option<DnsLookupRecord> {
+    let mut priority = None;
+    let mut caa = None;
+
+    let value = match record.data() {
+        RData::A(ip) => ip.to_string(),
+        RData::AAAA(ip) => ip.to_string(),
+        RData::CNAME(name) => name.0.to_string().trim_end_matches('.').to_string(),
+        RData::NS(name) => name.0.to_string().trim_end_matches('.').to_string(),
+        RData::PTR(name) => name.0.to_string().trim_end_matches('.').to_string(),
+        RData::MX(mx) => {
+            priority = Some(mx.preference());
+            mx.exchange().to_string().trim_end_matches('.').to_string()
+        }
+        RData::TXT(txt) => txt
+            .iter()
+            .map(|data| String::from_utf8_lossy(data).to_string())
+            .collect::<String>(),
+        RData::SRV(srv) => {
+            priority = Some(srv.priority());
+            format!(
                 "{} {} {}",
                 srv.weight(),
                 srv.port(),
                 srv.target().to_string().trim_end_matches('.')
-            );
-            records.push(DnsLookupRecord {
-                record_type: "SRV".to_string(),
-                name: domain.to_string(),
-                value,
-                ttl: response
-                    .as_lookup()
-                    .record_iter()
-                    .next()
-                    .map_or(0, hickory_resolver::proto::rr::Record::ttl),
-                priority: Some(srv.priority()),
-            });
+            )
         }
-    }
+        RData::CAA(record_caa) => {
+            let parsed = CaaRecord {
+                flags: record_caa.flags(),
+                tag: record_caa.tag().as_str().to_string(),
+                value: String::from_utf8_lossy(record_caa.raw_value()).to_string(),
+            };
+            let value = format!("{} {} \"{}\"", parsed.flags, parsed.tag, parsed.value);
+            caa = Some(parsed);
+            value
+        }
+        RData::TLSA(tlsa) => format_tlsa(tlsa),
+        RData::HINFO(hinfo) => format!(
+            "\"{}\" \"{}\"",
+            String::from_utf8_lossy(hinfo.cpu()),
+            String::from_utf8_lossy(hinfo.os())
+        ),
+        RData::SVCB(svcb) => svcb.to_string(),
+        RData::HTTPS(https) => https.to_string(),
+        RData::OPENPGPKEY(key) => STANDARD.encode(key.public_key()),
+        RData::Unknown { code, rdata } => match *code {
+            RecordType::Unknown(URI_TYPE_CODE) => {
+                let (uri_priority, value) = parse_uri(rdata.anything())?;
+                priority = Some(uri_priority);
+                value
+            }
+            RecordType::Unknown(LOC_TYPE_CODE) => format_loc(rdata.anything())?,
+            RecordType::Unknown(SMIMEA_TYPE_CODE) => format_smimea(rdata.anything())?,
+            _ => return None,
+        },
+        RData::SOA(soa) => format!(
+            "{} {} {} {} {} {} {}",
+            soa.mname().to_string().trim_end_matches('.'),
+            soa.rname().to_string().trim_end_matches('.'),
+            soa.serial(),
+            soa.refresh(),
+            soa.retry(),
+            soa.expire(),
+            soa.minimum()
+        ),
+        _ => return None,
+    };
+
+    Some(DnsLookupRecord {
+        record_type: record_type_name(record.record_type()),
+        name: domain.to_string(),
+        value,
+        ttl: record.ttl(),
+        priority,
+        caa,
+    })
 }
 
-async fn lookup_caa(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver
-        .lookup(domain, hickory_resolver::proto::rr::RecordType::CAA)
+/// 带 EDNS Client Subnet 的 DNS 查询
+///
+/// 直接向 DNS 服务器发送携带 ECS 选项的 UDP 查询，用于观察按地域调度的应答。
+/// 应答被截断（TC 位）时改用 TCP 重新查询。
+pub async fn dns_lookup_ecs(
+    domain: &str,
+    record_type: &str,
+    nameserver: Option<&str>,
+    subnet: &str,
+) -> CoreResult<DnsLookupResult> {
+    let client_subnet = parse_client_subnet(subnet)?;
+
+    let record_type_upper = record_type.to_uppercase();
+    let query_type = RecordType::from_str(&record_type_upper)
+        .ok()
+        .filter(|t| {
+            !matches!(
+                t,
+                RecordType::ANY | RecordType::AXFR | RecordType::IXFR | RecordType::Unknown(_)
+            )
+        })
+        .or_else(|| generic_record_type(&record_type_upper))
+        .ok_or_else(|| CoreError::ValidationError(format!("不支持的记录类型: {record_type}")))?;
+
+    let ns_ip: IpAddr = match nameserver.filter(|ns| !ns.is_empty()) {
+        Some(ns) => ns
+            .parse()
+            .map_err(|_| CoreError::ValidationError(format!("无效的 DNS 服务器地址: {ns}")))?,
+        None => ResolverConfig::default()
+            .name_servers()
+            .first()
+            .map(|ns| ns.socket_addr.ip())
+            .ok_or_else(|| CoreError::NetworkError("未找到可用的 DNS 服务器".to_string()))?,
+    };
+
+    let name = Name::from_str(domain)
+        .map_err(|e| CoreError::ValidationError(format!("无效的域名 {domain}: {e}")))?;
+
+    let mut edns = Edns::new();
+    edns.set_max_payload(1232);
+    edns.options_mut().insert(EdnsOption::Subnet(client_subnet));
+
+    let id: u16 = rand::random();
+    let query = Query::query(name, query_type);
+    let mut message = Message::new();
+    message
+        .set_id(id)
+        .set_message_type(MessageType::Query)
+        .set_op_code(OpCode::Query)
+        .set_recursion_desired(true)
+        .add_query(query.clone())
+        .set_edns(edns);
+    let request = message
+        .to_vec()
+        .map_err(|e| CoreError::NetworkError(format!("构造 DNS 查询失败: {e}")))?;
+
+    let server = SocketAddr::new(ns_ip, 53);
+    let query_timeout = Duration::from_secs(ECS_QUERY_TIMEOUT_SECS);
+    let timeout_error = |_| CoreError::Timeout(format!("DNS 查询超时 ({ECS_QUERY_TIMEOUT_SECS}s)"));
+
+    let mut response = timeout(query_timeout, exchange_udp(server, &request, id, &query))
         .await
+        .map_err(timeout_error)??;
+    if response.truncated() {
+        log::debug!("ECS response for {domain} truncated, retrying over TCP");
+        response = timeout(query_timeout, exchange_tcp(server, &request, id, &query))
+            .await
+            .map_err(timeout_error)??;
+    }
+
+    if !matches!(
+        response.response_code(),
+        ResponseCode::NoError | ResponseCode::NXDomain
+    ) {
+        return Err(CoreError::NetworkError(format!(
+            "DNS 服务器返回错误: {}",
+            response.response_code()
+        )));
+    }
+
+    if let Some(EdnsOption::Subnet(ecs)) = response
+        .extensions()
+        .as_ref()
+        .and_then(|edns| edns.option(EdnsCode::Subnet))
     {
-        for record in response.record_iter() {
-            if let Some(caa) = record.data().as_caa() {
-                let value = format!(
-                    "{} {} \"{}\"",
-                    if caa.issuer_critical() { 128 } else { 0 },
-                    caa.tag().as_str(),
-                    String::from_utf8_lossy(caa.raw_value())
-                );
-                records.push(DnsLookupRecord {
-                    record_type: "CAA".to_string(),
-                    name: domain.to_string(),
-                    value,
-                    ttl: record.ttl(),
-                    priority: None,
-                });
-            }
+        log::debug!(
+            "ECS response for {domain}: {}/{} scope {}",
+            ecs.addr(),
+            ecs.source_prefix(),
+            ecs.scope_prefix()
+        );
+    }
+
+    let records = response
+        .answers()
+        .iter()
+        .filter(|record| record.record_type() == query_type)
+        .filter_map(|record| answer_to_lookup_record(domain, record))
+        .collect();
+
+    Ok(DnsLookupResult {
+        nameserver: format!("{ns_ip} (ECS {subnet})"),
+        records,
+        nxdomain: response.response_code() == ResponseCode::NXDomain,
+    })
+}
+
+/// 应答是否对应本次查询（ID 与问题部分一致）
+fn is_response_to(response: &Message, id: u16, query: &Query) -> bool {
+    response.id() == id
+        && response.message_type() == MessageType::Response
+        && response.queries() == std::slice::from_ref(query)
+}
+
+/// 通过 UDP 发送查询，忽略与本次查询不匹配的应答
+async fn exchange_udp(
+    server: SocketAddr,
+    request: &[u8],
+    id: u16,
+    query: &Query,
+) -> CoreResult<Message> {
+    let bind_addr: SocketAddr = if server.is_ipv4() {
+        (Ipv4Addr::UNSPECIFIED, 0).into()
+    } else {
+        (Ipv6Addr::UNSPECIFIED, 0).into()
+    };
+    let socket = UdpSocket::bind(bind_addr)
+        .await
+        .map_err(|e| CoreError::NetworkError(e.to_string()))?;
+    socket
+        .connect(server)
+        .await
+        .map_err(|e| CoreError::NetworkError(e.to_string()))?;
+    socket
+        .send(request)
+        .await
+        .map_err(|e| CoreError::NetworkError(e.to_string()))?;
+
+    let mut buf = vec![0u8; 4096];
+    loop {
+        let len = socket
+            .recv(&mut buf)
+            .await
+            .map_err(|e| CoreError::NetworkError(e.to_string()))?;
+        match Message::from_vec(&buf[..len]) {
+            Ok(response) if is_response_to(&response, id, query) => return Ok(response),
+            Ok(response) => log::debug!(
+                "Ignoring mismatched DNS response from {server} (id {})",
+                response.id()
+            ),
+            Err(e) => log::debug!("Ignoring malformed DNS response from {server}: {e}"),
         }
     }
 }
 
-async fn lookup_ptr(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver
-        .lookup(domain, hickory_resolver::proto::rr::RecordType::PTR)
+/// 通过 TCP 发送查询（两字节长度前缀）
+async fn exchange_tcp(
+    server: SocketAddr,
+    request: &[u8],
+    id: u16,
+    query: &Query,
+) -> CoreResult<Message> {
+    let length = u16::try_from(request.len())
+        .map_err(|_| CoreError::NetworkError("DNS 查询过长".to_string()))?;
+    let mut stream = TcpStream::connect(server)
         .await
-    {
-        for record in response.record_iter() {
-            if let Some(ptr) = record.data().as_ptr() {
-                records.push(DnsLookupRecord {
-                    record_type: "PTR".to_string(),
-                    name: domain.to_string(),
-                    value: ptr.0.to_string().trim_end_matches('.').to_string(),
-                    ttl: record.ttl(),
-                    priority: None,
-                });
-            }
+        .map_err(|e| CoreError::NetworkError(e.to_string()))?;
+
+    let mut framed = Vec::with_capacity(request.len() + 2);
+    framed.extend_from_slice(&length.to_be_bytes());
+    framed.extend_from_slice(request);
+    stream
+        .write_all(&framed)
+        .await
+        .map_err(|e| CoreError::NetworkError(e.to_string()))?;
+
+    let length = stream
+        .read_u16()
+        .await
+        .map_err(|e| CoreError::NetworkError(e.to_string()))?;
+    let mut buf = vec![0u8; usize::from(length)];
+    stream
+        .read_exact(&mut buf)
+        .await
+        .map_err(|e| CoreError::NetworkError(e.to_string()))?;
+
+    let response = Message::from_vec(&buf)
+        .map_err(|e| CoreError::NetworkError(format!("解析 DNS 响应失败: {e}")))?;
+    if !is_response_to(&response, id, query) {
+        return Err(CoreError::NetworkError("DNS 响应与查询不匹配".to_string()));
+    }
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 固定域名下某类型的一条应答
+    fn fixture_record(record_type: &str) -> DnsLookupRecord {
+        DnsLookupRecord {
+            record_type: record_type.to_string(),
+            name: "example.com".to_string(),
+            value: format!("{record_type} value"),
+            ttl: 300,
+            priority: None,
+            caa: None,
         }
     }
+
+    #[test]
+    fn merge_keeps_every_all_record_type() -> CoreResult<()> {
+        let results = ALL_RECORD_TYPES
+            .iter()
+            .map(|record_type| Ok((false, vec![fixture_record(record_type)])))
+            .collect();
+
+        let (records, nxdomain) = merge_type_results(results)?;
+
+        let types: Vec<&str> = records.iter().map(|r| r.record_type.as_str()).collect();
+        assert_eq!(types, ALL_RECORD_TYPES);
+        assert!(!nxdomain);
+        Ok(())
+    }
+
+    #[test]
+    fn merge_keeps_other_types_when_some_fail() -> CoreResult<()> {
+        let results = ALL_RECORD_TYPES
+            .iter()
+            .map(|record_type| match *record_type {
+                "CAA" => Err(CoreError::NetworkError("SERVFAIL".to_string())),
+                "TXT" => Ok((true, Vec::new())),
+                _ => Ok((false, vec![fixture_record(record_type)])),
+            })
+            .collect();
+
+        let (records, nxdomain) = merge_type_results(results)?;
+
+        let expected: Vec<&str> = ALL_RECORD_TYPES
+            .iter()
+            .copied()
+            .filter(|t| !matches!(*t, "CAA" | "TXT"))
+            .collect();
+        let types: Vec<&str> = records.iter().map(|r| r.record_type.as_str()).collect();
+        assert_eq!(types, expected);
+        assert!(nxdomain);
+        Ok(())
+    }
+
+    #[test]
+    fn merge_fails_when_every_type_fails() {
+        let results = ALL_RECORD_TYPES
+            .iter()
+            .map(|record_type| Err(CoreError::NetworkError(format!("{record_type} failed"))))
+            .collect();
+
+        assert!(matches!(
+            merge_type_results(results),
+            Err(CoreError::NetworkError(detail)) if detail == "A failed"
+        ));
+    }
 }
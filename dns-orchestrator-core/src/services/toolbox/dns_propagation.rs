@@ -6,10 +6,11 @@ use std::time::Instant;
 use futures::future::join_all;
 use tokio::time::{timeout, Duration};
 
-use crate::error::CoreResult;
+use crate::error::{CoreError, CoreResult};
 use crate::types::{DnsPropagationResult, DnsPropagationServer, DnsPropagationServerResult};
 
-use super::dns::dns_lookup;
+use super::dns::dns_lookup_with_config;
+use super::resolver::ResolverConfig;
 
 /// DNS 查询超时时间（秒）
 const QUERY_TIMEOUT_SECS: u64 = 5;
@@ -102,6 +103,41 @@ fn get_global_dns_servers() -> Vec<DnsPropagationServer> {
     ]
 }
 
+/// 将服务器的应答记录序列化为可比较的字符串（排序后以 `|` 连接）
+fn answer_key(result: &DnsPropagationServerResult) -> String {
+    let mut values: Vec<_> = result
+        .records
+        .iter()
+        .map(|r| {
+            // 只比较 value 和 priority，不包含 TTL
+            // TTL 随时间变化是正常的，不应影响一致性判断
+            if let Some(priority) = r.priority {
+                format!("{}:{}", r.value, priority)
+            } else {
+                r.value.clone()
+            }
+        })
+        .collect();
+    values.sort();
+    values.join("|")
+}
+
+/// 按应答内容对查询成功的服务器分组，服务器多的分组在前
+fn group_answers(results: &[DnsPropagationServerResult]) -> Vec<(String, Vec<String>)> {
+    let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+
+    for result in results.iter().filter(|r| r.status == "success") {
+        let key = answer_key(result);
+        match groups.iter_mut().find(|(answer, _)| *answer == key) {
+            Some((_, servers)) => servers.push(result.server.name.clone()),
+            None => groups.push((key, vec![result.server.name.clone()])),
+        }
+    }
+
+    groups.sort_by_key(|(_, servers)| std::cmp::Reverse(servers.len()));
+    groups
+}
+
 /// 计算一致性百分比和唯一值
 fn calculate_consistency(results: &[DnsPropagationServerResult]) -> (f32, Vec<String>) {
     let successful_results: Vec<_> = results.iter().filter(|r| r.status == "success").collect();
@@ -114,22 +150,7 @@ fn calculate_consistency(results: &[DnsPropagationServerResult]) -> (f32, Vec<St
     let mut value_counts: HashMap<String, usize> = HashMap::new();
 
     for result in &successful_results {
-        let mut values: Vec<_> = result
-            .records
-            .iter()
-            .map(|r| {
-                // 只比较 value 和 priority，不包含 TTL
-                // TTL 随时间变化是正常的，不应影响一致性判断
-                if let Some(priority) = r.priority {
-                    format!("{}:{}", r.value, priority)
-                } else {
-                    r.value.clone()
-                }
-            })
-            .collect();
-        values.sort();
-        let key = values.join("|");
-        *value_counts.entry(key).or_insert(0) += 1;
+        *value_counts.entry(answer_key(result)).or_insert(0) += 1;
     }
 
     let total = successful_results.len();
@@ -145,6 +166,17 @@ fn calculate_consistency(results: &[DnsPropagationServerResult]) -> (f32, Vec<St
 pub async fn dns_propagation_check(
     domain: &str,
     record_type: &str,
+) -> CoreResult<DnsPropagationResult> {
+    dns_propagation_check_with_config(domain, record_type, &ResolverConfig::default()).await
+}
+
+/// DNS 传播检查（使用指定的 resolver 配置）
+///
+/// 依次使用内置的全球 DNS 服务器，`config` 中的 DNS 服务器被忽略，其余选项对每台服务器生效。
+pub async fn dns_propagation_check_with_config(
+    domain: &str,
+    record_type: &str,
+    config: &ResolverConfig,
 ) -> CoreResult<DnsPropagationResult> {
     let servers = get_global_dns_servers();
     let start_time = Instant::now();
@@ -155,11 +187,12 @@ pub async fn dns_propagation_check(
         .map(|server| {
             let domain = domain.to_string();
             let record_type = record_type.to_string();
+            let config = config.for_nameserver(Some(&server.ip));
             async move {
                 let query_start = Instant::now();
                 let result = timeout(
                     Duration::from_secs(QUERY_TIMEOUT_SECS),
-                    dns_lookup(&domain, &record_type, Some(&server.ip)),
+                    dns_lookup_with_config(&domain, &record_type, &config),
                 )
                 .await;
                 let elapsed = query_start.elapsed().as_millis() as u64;
@@ -172,6 +205,13 @@ pub async fn dns_propagation_check(
                         error: None,
                         response_time_ms: elapsed,
                     },
+                    Ok(Err(CoreError::Timeout(message))) => DnsPropagationServerResult {
+                        server,
+                        status: "timeout".to_string(),
+                        records: vec![],
+                        error: Some(message),
+                        response_time_ms: elapsed,
+                    },
                     Ok(Err(e)) => DnsPropagationServerResult {
                         server,
                         status: "error".to_string(),
@@ -195,6 +235,7 @@ pub async fn dns_propagation_check(
 
     // 计算一致性
     let (consistency_percentage, unique_values) = calculate_consistency(&results);
+    let grouped_answers = group_answers(&results);
 
     let total_time_ms = start_time.elapsed().as_millis() as u64;
 
@@ -205,5 +246,6 @@ pub async fn dns_propagation_check(
         total_time_ms,
         consistency_percentage,
         unique_values,
+        grouped_answers,
     })
 }
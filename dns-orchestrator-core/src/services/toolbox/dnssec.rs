@@ -1,19 +1,17 @@
 //! DNSSEC 验证模块
 
-use std::net::IpAddr;
 use std::time::Instant;
 
 use hickory_resolver::{
-    config::{NameServerConfigGroup, ResolverConfig, ResolverOpts},
-    name_server::TokioConnectionProvider,
+    config::ResolverOpts,
     proto::{
         dnssec::{rdata::DNSSECRData, PublicKey},
         rr::{record_data::RData, RecordType},
     },
-    TokioResolver,
 };
 
-use crate::error::{CoreError, CoreResult};
+use super::resolver::{build_resolver, ResolverConfig};
+use crate::error::CoreResult;
 use crate::types::{DnskeyRecord, DnssecResult, DsRecord, RrsigRecord};
 
 /// Get algorithm name from algorithm number (RFC 8624)
@@ -91,53 +89,25 @@ fn extract_signature_record(
 
 /// DNSSEC 验证
 pub async fn dnssec_check(domain: &str, nameserver: Option<&str>) -> CoreResult<DnssecResult> {
-    let start_time = Instant::now();
-
-    // Get system default DNS server addresses
-    fn get_system_dns() -> String {
-        let config = ResolverConfig::default();
-        let servers: Vec<String> = config
-            .name_servers()
-            .iter()
-            .map(|ns| ns.socket_addr.ip().to_string())
-            .collect();
-        if servers.is_empty() {
-            "System Default".to_string()
-        } else {
-            servers.join(", ")
-        }
-    }
-
-    // 根据 nameserver 参数决定使用自定义还是系统默认
-    let effective_ns = nameserver.filter(|s| !s.is_empty());
+    dnssec_check_with_config(
+        domain,
+        &ResolverConfig::default().for_nameserver(nameserver),
+    )
+    .await
+}
 
-    let (resolver, used_nameserver) = if let Some(ns) = effective_ns {
-        let ns_ip: IpAddr = ns
-            .parse()
-            .map_err(|_| CoreError::ValidationError(format!("Invalid DNS server address: {ns}")))?;
+/// DNSSEC 验证（使用指定的 resolver 配置）
+pub async fn dnssec_check_with_config(
+    domain: &str,
+    config: &ResolverConfig,
+) -> CoreResult<DnssecResult> {
+    let start_time = Instant::now();
 
-        let config = ResolverConfig::from_parts(
-            None,
-            vec![],
-            NameServerConfigGroup::from_ips_clear(&[ns_ip], 53, true),
-        );
-        let provider = TokioConnectionProvider::default();
-        let mut opts = ResolverOpts::default();
-        opts.validate = true; // 启用 DNSSEC 验证
-        let resolver = TokioResolver::builder_with_config(config, provider)
-            .with_options(opts)
-            .build();
-        (resolver, ns.to_string())
-    } else {
-        let system_dns = get_system_dns();
-        let provider = TokioConnectionProvider::default();
-        let mut opts = ResolverOpts::default();
-        opts.validate = true; // 启用 DNSSEC 验证
-        let resolver = TokioResolver::builder_with_config(ResolverConfig::default(), provider)
-            .with_options(opts)
-            .build();
-        (resolver, system_dns)
-    };
+    // 启用 DNSSEC 验证：无论哪种传输方式，查询都会带上 EDNS DO 位
+    let mut opts = ResolverOpts::default();
+    opts.edns0 = true;
+    opts.validate = true;
+    let (resolver, used_nameserver) = build_resolver(config, opts).await?;
 
     let mut dnskey_records = Vec::new();
     let mut ds_records = Vec::new();
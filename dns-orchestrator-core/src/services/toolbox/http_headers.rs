@@ -1,18 +1,24 @@
 //! HTTP 头检查模块
 
-use std::time::Instant;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use log::debug;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
 use reqwest::{Client, Method};
 use url::Url;
 
 use crate::error::{CoreError, CoreResult};
 use crate::types::{
-    HttpHeader, HttpHeaderCheckRequest, HttpHeaderCheckResult, HttpMethod, SecurityHeaderAnalysis,
+    HttpHeader, HttpHeaderCheckRequest, HttpHeaderCheckResult, HttpMethod, HttpTiming,
+    SecurityHeaderAnalysis,
 };
 
 const REQUEST_TIMEOUT_SECS: u64 = 10;
 
+/// 请求体大小上限（1 MiB）
+const MAX_REQUEST_BODY_BYTES: usize = 1024 * 1024;
+
 /// 必需的安全头列表
 const REQUIRED_SECURITY_HEADERS: &[&str] = &[
     "strict-transport-security",
@@ -25,7 +31,30 @@ const REQUIRED_SECURITY_HEADERS: &[&str] = &[
 const RECOMMENDED_SECURITY_HEADERS: &[&str] =
     &["referrer-policy", "permissions-policy", "x-xss-protection"];
 
+/// 记录 DNS 解析耗时的解析器（与 reqwest 默认一样使用系统解析器）
+#[derive(Default)]
+struct TimingResolver {
+    elapsed: Arc<Mutex<Option<Duration>>>,
+}
+
+impl Resolve for TimingResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let elapsed = Arc::clone(&self.elapsed);
+        Box::pin(async move {
+            let start = Instant::now();
+            let addrs: Vec<_> = tokio::net::lookup_host((name.as_str(), 0)).await?.collect();
+            if let Ok(mut total) = elapsed.lock() {
+                *total = Some(total.unwrap_or_default() + start.elapsed());
+            }
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
 /// HTTP 头检查
+///
+/// 耗时明细中 DNS 解析与首字节时间可以测得；reqwest 不暴露 TCP 连接与 TLS 握手阶段，
+/// 这两项始终为 `None`。
 pub async fn http_header_check(
     request: &HttpHeaderCheckRequest,
 ) -> CoreResult<HttpHeaderCheckResult> {
@@ -42,7 +71,10 @@ pub async fn http_header_check(
     debug!("[HTTP] Normalized URL: {url}");
 
     // 构建 HTTP 客户端
+    let resolver = Arc::new(TimingResolver::default());
+    let dns_elapsed = Arc::clone(&resolver.elapsed);
     let client = Client::builder()
+        .dns_resolver(resolver)
         .timeout(std::time::Duration::from_secs(REQUEST_TIMEOUT_SECS))
         .redirect(reqwest::redirect::Policy::limited(5))
         .build()
@@ -59,27 +91,59 @@ pub async fn http_header_check(
         HttpMethod::OPTIONS => Method::OPTIONS,
     };
 
+    // 请求体：POST/PUT/PATCH 按提供的内容发送，其他方法仅在非空时发送
+    let request_body = request
+        .body
+        .as_deref()
+        .filter(|body| !body.is_empty() || method_has_body(&request.method));
+    if request_body.is_some_and(|body| body.len() > MAX_REQUEST_BODY_BYTES) {
+        return Err(CoreError::ValidationError(format!(
+            "Request body exceeds {} KiB",
+            MAX_REQUEST_BODY_BYTES / 1024
+        )));
+    }
+
+    // 自定义请求头中已有 Content-Type 时以其为准
+    let custom_headers: Vec<&HttpHeader> = request
+        .custom_headers
+        .iter()
+        .filter(|header| !header.name.is_empty() && !header.value.is_empty())
+        .collect();
+    let content_type = if custom_headers
+        .iter()
+        .any(|header| header.name.eq_ignore_ascii_case("content-type"))
+    {
+        None
+    } else {
+        request
+            .content_type
+            .as_deref()
+            .filter(|_| request_body.is_some())
+    };
+
     // 构建请求
     let mut req_builder = client.request(method.clone(), &url);
 
     // 添加自定义请求头
-    for header in &request.custom_headers {
+    for header in &custom_headers {
         req_builder = req_builder.header(&header.name, &header.value);
     }
 
-    // 添加请求体（POST/PUT/PATCH）
-    if let Some(body) = &request.body {
-        if let Some(content_type) = &request.content_type {
-            req_builder = req_builder.header("Content-Type", content_type);
-        }
-        req_builder = req_builder.body(body.clone());
+    // 添加 Content-Type 和请求体
+    if let Some(content_type) = content_type {
+        req_builder = req_builder.header("Content-Type", content_type);
+    }
+    if let Some(body) = request_body {
+        req_builder = req_builder.body(body.to_string());
     }
 
-    // 发送请求
+    // 发送请求（send 在收到响应头后返回，据此计算首字节时间）
+    let send_start = Instant::now();
     let response = req_builder
         .send()
         .await
         .map_err(|e| CoreError::NetworkError(format!("HTTP request failed: {e}")))?;
+    let ttfb = send_start.elapsed();
 
     let elapsed = start.elapsed();
     let status_code = response.status().as_u16();
@@ -129,17 +193,15 @@ pub async fn http_header_check(
     }
 
     // 添加自定义请求头
-    for header in &request.custom_headers {
-        if !header.name.is_empty() && !header.value.is_empty() {
-            raw_request.push_str(&format!("{}: {}\r\n", header.name, header.value));
-        }
+    for header in &custom_headers {
+        raw_request.push_str(&format!("{}: {}\r\n", header.name, header.value));
     }
 
     // 添加 Content-Type 和请求体
-    if let Some(body) = &request.body {
-        if let Some(content_type) = &request.content_type {
-            raw_request.push_str(&format!("Content-Type: {content_type}\r\n"));
-        }
+    if let Some(content_type) = content_type {
+        raw_request.push_str(&format!("Content-Type: {content_type}\r\n"));
+    }
+    if let Some(body) = request_body {
         raw_request.push_str(&format!("Content-Length: {}\r\n", body.len()));
         raw_request.push_str("\r\n");
         raw_request.push_str(body);
@@ -173,9 +235,32 @@ pub async fn http_header_check(
         content_length,
         raw_request,
         raw_response,
+        timing: HttpTiming {
+            dns_ms: dns_elapsed
+                .lock()
+                .ok()
+                .and_then(|elapsed| *elapsed)
+                .map(duration_ms),
+            tcp_connect_ms: None,
+            tls_handshake_ms: None,
+            ttfb_ms: Some(duration_ms(ttfb)),
+        },
     })
 }
 
+/// 耗时转换为毫秒
+fn duration_ms(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+/// 该方法是否通常携带请求体
+const fn method_has_body(method: &HttpMethod) -> bool {
+    matches!(
+        method,
+        HttpMethod::POST | HttpMethod::PUT | HttpMethod::PATCH
+    )
+}
+
 /// 分析安全头
 fn analyze_security_headers(headers: &[HttpHeader]) -> Vec<SecurityHeaderAnalysis> {
     let mut analysis = Vec::new();
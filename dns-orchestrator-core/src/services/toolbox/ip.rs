@@ -1,23 +1,20 @@
 //! IP 地理位置查询模块
 
-use hickory_resolver::{
-    config::{ResolverConfig, ResolverOpts},
-    name_server::TokioConnectionProvider,
-    TokioResolver,
-};
+use std::collections::HashSet;
+use std::net::IpAddr;
+
+use hickory_resolver::config::ResolverOpts;
 use serde::Deserialize;
 
+use super::resolver::{build_resolver, ResolverConfig};
 use crate::error::{CoreError, CoreResult};
 use crate::types::{IpGeoInfo, IpLookupResult};
 
 /// ipwhois.io 响应结构
 #[derive(Deserialize)]
 struct IpWhoisResponse {
-    ip: String,
     success: bool,
     message: Option<String>,
-    #[serde(rename = "type")]
-    ip_type: Option<String>,
     country: Option<String>,
     country_code: Option<String>,
     region: Option<String>,
@@ -41,9 +38,11 @@ struct IpWhoisConnection {
 }
 
 /// 查询单个 IP 的地理位置
-async fn lookup_single_ip(ip: &str, client: &reqwest::Client) -> CoreResult<IpGeoInfo> {
+///
+/// 结果中的 `ip` 与 `ip_version` 取自查询的地址本身，而非服务端回显。
+async fn lookup_single_ip(ip: IpAddr, client: &reqwest::Client) -> CoreResult<IpGeoInfo> {
     let url = format!(
-        "https://ipwho.is/{ip}?fields=ip,success,message,type,country,country_code,region,city,latitude,longitude,timezone,connection"
+        "https://ipwho.is/{ip}?fields=success,message,country,country_code,region,city,latitude,longitude,timezone,connection"
     );
 
     let response: IpWhoisResponse = client
@@ -68,14 +67,7 @@ async fn lookup_single_ip(ip: &str, client: &reqwest::Client) -> CoreResult<IpGe
         return Err(CoreError::NetworkError(error_msg));
     }
 
-    let ip_version = response.ip_type.unwrap_or_else(|| {
-        if response.ip.contains(':') {
-            "IPv6"
-        } else {
-            "IPv4"
-        }
-        .to_string()
-    });
+    let ip_version = if ip.is_ipv6() { "IPv6" } else { "IPv4" }.to_string();
 
     let (isp, org, asn) = response.connection.map_or((None, None, None), |conn| {
         (
@@ -88,7 +80,7 @@ async fn lookup_single_ip(ip: &str, client: &reqwest::Client) -> CoreResult<IpGe
     let timezone = response.timezone.and_then(|tz| tz.id);
 
     Ok(IpGeoInfo {
-        ip: response.ip,
+        ip: ip.to_string(),
         ip_version,
         country: response.country,
         country_code: response.country_code,
@@ -106,6 +98,14 @@ async fn lookup_single_ip(ip: &str, client: &reqwest::Client) -> CoreResult<IpGe
 
 /// IP/域名 地理位置查询
 pub async fn ip_lookup(query: &str) -> CoreResult<IpLookupResult> {
+    ip_lookup_with_config(query, &ResolverConfig::default()).await
+}
+
+/// IP/域名 地理位置查询（域名使用指定的 resolver 配置解析）
+pub async fn ip_lookup_with_config(
+    query: &str,
+    config: &ResolverConfig,
+) -> CoreResult<IpLookupResult> {
     let query = query.trim().to_string();
     if query.is_empty() {
         return Err(CoreError::ValidationError(
@@ -116,8 +116,8 @@ pub async fn ip_lookup(query: &str) -> CoreResult<IpLookupResult> {
     let client = reqwest::Client::new();
 
     // 检查是否为 IP 地址
-    if query.parse::<std::net::IpAddr>().is_ok() {
-        let result = lookup_single_ip(&query, &client).await?;
+    if let Ok(ip) = query.parse::<IpAddr>() {
+        let result = lookup_single_ip(ip, &client).await?;
         return Ok(IpLookupResult {
             query,
             is_domain: false,
@@ -126,27 +126,24 @@ pub async fn ip_lookup(query: &str) -> CoreResult<IpLookupResult> {
     }
 
     // 作为域名处理，解析 A 和 AAAA 记录
-    let provider = TokioConnectionProvider::default();
-    let resolver = TokioResolver::builder_with_config(ResolverConfig::default(), provider)
-        .with_options(ResolverOpts::default())
-        .build();
+    let (resolver, _) = build_resolver(config, ResolverOpts::default()).await?;
 
-    let mut ips: Vec<String> = Vec::new();
+    let mut ips: Vec<IpAddr> = Vec::new();
 
     // 解析 IPv4 (A 记录)
     if let Ok(response) = resolver.ipv4_lookup(&query).await {
-        for ip in response.iter() {
-            ips.push(ip.to_string());
-        }
+        ips.extend(response.iter().map(|a| IpAddr::V4(a.0)));
     }
 
     // 解析 IPv6 (AAAA 记录)
     if let Ok(response) = resolver.ipv6_lookup(&query).await {
-        for ip in response.iter() {
-            ips.push(ip.to_string());
-        }
+        ips.extend(response.iter().map(|aaaa| IpAddr::V6(aaaa.0)));
     }
 
+    // 去除重复地址（保留解析顺序，IPv4 在前）
+    let mut seen = HashSet::new();
+    ips.retain(|ip| seen.insert(*ip));
+
     if ips.is_empty() {
         return Err(CoreError::NetworkError(format!("无法解析域名: {query}")));
     }
@@ -154,7 +151,7 @@ pub async fn ip_lookup(query: &str) -> CoreResult<IpLookupResult> {
     // 查询每个 IP 的地理位置
     let mut results = Vec::new();
     for ip in ips {
-        match lookup_single_ip(&ip, &client).await {
+        match lookup_single_ip(ip, &client).await {
             Ok(info) => results.push(info),
             Err(e) => {
                 log::warn!("查询 IP {ip} 失败: {e}");
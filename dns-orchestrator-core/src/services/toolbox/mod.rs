@@ -7,27 +7,110 @@ mod dns_propagation;
 mod dnssec;
 mod http_headers;
 mod ip;
+mod nameserver;
+mod nsec;
+mod resolver;
 mod ssl;
 mod whois;
 
-use crate::error::CoreResult;
+use std::future::Future;
+use std::net::IpAddr;
+use std::time::Duration;
+
+pub use resolver::ResolverConfig;
+
+use crate::error::{CoreError, CoreResult};
 use crate::types::{
     DnsLookupResult, DnsPropagationResult, DnssecResult, HttpHeaderCheckResult, IpLookupResult,
-    WhoisResult,
+    NsecWalkResult, WhoisResult,
 };
 
 /// 嵌入 WHOIS 服务器配置
 const WHOIS_SERVERS: &str = include_str!("whois_servers.json");
 
+/// 默认整体超时时间（秒）
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// 工具箱调用选项
+#[derive(Debug, Clone, Copy)]
+pub struct ToolboxOptions {
+    /// 整体超时时间，超时后放弃查询并返回 `CoreError::Timeout`
+    pub timeout: Duration,
+    /// DNS 查询使用的本地源地址（仅 DNS 查询使用，须为本机地址）
+    pub bind_addr: Option<IpAddr>,
+}
+
+impl Default for ToolboxOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+            bind_addr: None,
+        }
+    }
+}
+
+impl ToolboxOptions {
+    /// 使用指定的超时时间
+    #[must_use]
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            ..Self::default()
+        }
+    }
+
+    /// 指定 DNS 查询的本地源地址
+    #[must_use]
+    pub fn with_bind_addr(mut self, bind_addr: IpAddr) -> Self {
+        self.bind_addr = Some(bind_addr);
+        self
+    }
+}
+
+/// 在整体超时时间内执行查询
+///
+/// 超时后查询的 future 被丢弃，未完成的网络请求随之取消。
+async fn run_with_timeout<T>(
+    options: ToolboxOptions,
+    operation: &str,
+    future: impl Future<Output = CoreResult<T>>,
+) -> CoreResult<T> {
+    tokio::time::timeout(options.timeout, future)
+        .await
+        .map_err(|_| {
+            CoreError::Timeout(format!(
+                "{operation} did not finish within {:?}",
+                options.timeout
+            ))
+        })?
+}
+
 /// 工具箱服务（无状态，所有方法为关联函数）
 pub struct ToolboxService;
 
 impl ToolboxService {
     /// WHOIS 查询
+    ///
+    /// 结果按域名在进程内缓存 5 分钟；被 WHOIS 服务器限流时返回 `CoreError::RateLimited`。
     pub async fn whois_lookup(domain: &str) -> CoreResult<WhoisResult> {
         whois::whois_lookup(domain, WHOIS_SERVERS).await
     }
 
+    /// 批量 WHOIS 查询
+    ///
+    /// 不同 WHOIS 服务器之间并行，同一服务器的查询串行；结果顺序与输入一致。
+    pub async fn whois_lookup_batch(domains: &[&str]) -> Vec<CoreResult<WhoisResult>> {
+        whois::whois_lookup_batch(domains, WHOIS_SERVERS).await
+    }
+
+    /// WHOIS 查询（带整体超时）
+    pub async fn whois_lookup_with_options(
+        domain: &str,
+        options: ToolboxOptions,
+    ) -> CoreResult<WhoisResult> {
+        run_with_timeout(options, "WHOIS lookup", Self::whois_lookup(domain)).await
+    }
+
     /// DNS 查询
     pub async fn dns_lookup(
         domain: &str,
@@ -37,11 +120,53 @@ impl ToolboxService {
         dns::dns_lookup(domain, record_type, nameserver).await
     }
 
+    /// DNS 查询（带整体超时，可指定本地源地址）
+    pub async fn dns_lookup_with_options(
+        domain: &str,
+        record_type: &str,
+        nameserver: Option<&str>,
+        options: ToolboxOptions,
+    ) -> CoreResult<DnsLookupResult> {
+        run_with_timeout(
+            options,
+            "DNS lookup",
+            dns::dns_lookup_from(domain, record_type, nameserver, options.bind_addr),
+        )
+        .await
+    }
+
+    /// DNS 查询（使用指定的 resolver 配置）
+    pub async fn dns_lookup_with_config(
+        domain: &str,
+        record_type: &str,
+        config: &ResolverConfig,
+    ) -> CoreResult<DnsLookupResult> {
+        dns::dns_lookup_with_config(domain, record_type, config).await
+    }
+
+    /// 带 EDNS Client Subnet 的 DNS 查询
+    pub async fn dns_lookup_ecs(
+        domain: &str,
+        record_type: &str,
+        nameserver: Option<&str>,
+        subnet: &str,
+    ) -> CoreResult<DnsLookupResult> {
+        dns::dns_lookup_ecs(domain, record_type, nameserver, subnet).await
+    }
+
     /// IP/域名 地理位置查询
     pub async fn ip_lookup(query: &str) -> CoreResult<IpLookupResult> {
         ip::ip_lookup(query).await
     }
 
+    /// IP/域名 地理位置查询（域名使用指定的 resolver 配置解析）
+    pub async fn ip_lookup_with_config(
+        query: &str,
+        config: &ResolverConfig,
+    ) -> CoreResult<IpLookupResult> {
+        ip::ip_lookup_with_config(query, config).await
+    }
+
     /// SSL 证书检查
     #[cfg(feature = "rustls")]
     pub async fn ssl_check(
@@ -51,6 +176,26 @@ impl ToolboxService {
         ssl::ssl_check(domain, port).await
     }
 
+    /// SSL 证书检查，额外信任指定的 PEM 根证书（用于内部 CA）
+    #[cfg(feature = "rustls")]
+    pub async fn ssl_check_with_roots(
+        domain: &str,
+        port: Option<u16>,
+        extra_roots: &[String],
+    ) -> CoreResult<crate::types::SslCheckResult> {
+        ssl::ssl_check_with_roots(domain, port, extra_roots).await
+    }
+
+    /// SSL 证书检查（带整体超时）
+    #[cfg(feature = "rustls")]
+    pub async fn ssl_check_with_options(
+        domain: &str,
+        port: Option<u16>,
+        options: ToolboxOptions,
+    ) -> CoreResult<crate::types::SslCheckResult> {
+        run_with_timeout(options, "SSL check", Self::ssl_check(domain, port)).await
+    }
+
     /// HTTP 头检查
     pub async fn http_header_check(
         request: &crate::types::HttpHeaderCheckRequest,
@@ -58,6 +203,19 @@ impl ToolboxService {
         http_headers::http_header_check(request).await
     }
 
+    /// HTTP 头检查（带整体超时）
+    pub async fn http_header_check_with_options(
+        request: &crate::types::HttpHeaderCheckRequest,
+        options: ToolboxOptions,
+    ) -> CoreResult<HttpHeaderCheckResult> {
+        run_with_timeout(
+            options,
+            "HTTP header check",
+            Self::http_header_check(request),
+        )
+        .await
+    }
+
     /// DNS 传播检查
     pub async fn dns_propagation_check(
         domain: &str,
@@ -66,8 +224,44 @@ impl ToolboxService {
         dns_propagation::dns_propagation_check(domain, record_type).await
     }
 
-    /// DNSSEC 验证
+    /// DNS 传播检查（使用指定的 resolver 配置，DNS 服务器由内置列表决定）
+    pub async fn dns_propagation_check_with_config(
+        domain: &str,
+        record_type: &str,
+        config: &ResolverConfig,
+    ) -> CoreResult<DnsPropagationResult> {
+        dns_propagation::dns_propagation_check_with_config(domain, record_type, config).await
+    }
+
+    /// DNS 传播检查（带整体超时）
+    pub async fn dns_propagation_check_with_options(
+        domain: &str,
+        record_type: &str,
+        options: ToolboxOptions,
+    ) -> CoreResult<DnsPropagationResult> {
+        run_with_timeout(
+            options,
+            "DNS propagation check",
+            Self::dns_propagation_check(domain, record_type),
+        )
+        .await
+    }
+
+    /// DNSSEC 验证（`nameserver` 支持普通 IP、`tls://` 与 `https://`）
     pub async fn dnssec_check(domain: &str, nameserver: Option<&str>) -> CoreResult<DnssecResult> {
         dnssec::dnssec_check(domain, nameserver).await
     }
+
+    /// DNSSEC 验证（使用指定的 resolver 配置）
+    pub async fn dnssec_check_with_config(
+        domain: &str,
+        config: &ResolverConfig,
+    ) -> CoreResult<DnssecResult> {
+        dnssec::dnssec_check_with_config(domain, config).await
+    }
+
+    /// NSEC/NSEC3 区域遍历检测
+    pub async fn nsec_walk(domain: &str) -> CoreResult<NsecWalkResult> {
+        nsec::nsec_walk(domain).await
+    }
 }
@@ -0,0 +1,93 @@
+//! 自定义 DNS 服务器解析
+//!
+//! 按前缀选择传输协议：`https://` 为 DNS-over-HTTPS，`tls://` 为 DNS-over-TLS，其余按普通 IP（UDP/TCP 53 端口）处理
+
+use std::net::IpAddr;
+
+use hickory_resolver::config::{NameServerConfigGroup, ResolverConfig};
+
+use crate::error::{CoreError, CoreResult};
+
+/// 普通 DNS 默认端口
+const DNS_PORT: u16 = 53;
+/// DNS-over-HTTPS 默认端口
+const DOH_PORT: u16 = 443;
+/// DNS-over-TLS 默认端口
+const DOT_PORT: u16 = 853;
+
+/// 根据 nameserver 字符串构造 resolver 配置
+///
+/// 支持 `1.1.1.1`、`tls://dns.google`、`tls://1.1.1.1:853`、
+/// `https://cloudflare-dns.com/dns-query` 等形式，加密传输的主机名会先解析为 IP。
+pub(super) async fn resolver_config(nameserver: &str) -> CoreResult<ResolverConfig> {
+    let invalid =
+        || CoreError::ValidationError(format!("Invalid DNS server address: {nameserver}"));
+
+    let group = if let Some(rest) = nameserver.strip_prefix("https://") {
+        let (authority, path) = rest
+            .find('/')
+            .map_or((rest, None), |i| (&rest[..i], Some(&rest[i..])));
+        let (host, port) = split_host_port(authority, DOH_PORT).ok_or_else(invalid)?;
+        let ips = resolve_host(host, port).await?;
+        let mut group = NameServerConfigGroup::from_ips_https(&ips, port, host.to_string(), true);
+        if let Some(path) = path.filter(|path| *path != "/") {
+            for config in group.iter_mut() {
+                config.http_endpoint = Some(path.to_string());
+            }
+        }
+        group
+    } else if let Some(rest) = nameserver.strip_prefix("tls://") {
+        let (host, port) =
+            split_host_port(rest.trim_end_matches('/'), DOT_PORT).ok_or_else(invalid)?;
+        let ips = resolve_host(host, port).await?;
+        NameServerConfigGroup::from_ips_tls(&ips, port, host.to_string(), true)
+    } else {
+        let ip: IpAddr = nameserver.parse().map_err(|_| invalid())?;
+        NameServerConfigGroup::from_ips_clear(&[ip], DNS_PORT, true)
+    };
+
+    Ok(ResolverConfig::from_parts(None, vec![], group))
+}
+
+/// 拆分 `host[:port]`（IPv6 需写成 `[addr]:port`，或不带端口直接写地址）
+fn split_host_port(authority: &str, default_port: u16) -> Option<(&str, u16)> {
+    if authority.is_empty() {
+        return None;
+    }
+    if authority.parse::<IpAddr>().is_ok() {
+        return Some((authority, default_port));
+    }
+    if let Some(rest) = authority.strip_prefix('[') {
+        let (host, after) = rest.split_once(']')?;
+        let port = match after.strip_prefix(':') {
+            Some(port) => port.parse().ok()?,
+            None if after.is_empty() => default_port,
+            None => return None,
+        };
+        return Some((host, port));
+    }
+    match authority.rsplit_once(':') {
+        Some((host, port)) if !host.is_empty() => Some((host, port.parse().ok()?)),
+        Some(_) => None,
+        None => Some((authority, default_port)),
+    }
+}
+
+/// 解析 DNS 服务器主机名（已是 IP 时直接返回）
+async fn resolve_host(host: &str, port: u16) -> CoreResult<Vec<IpAddr>> {
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return Ok(vec![ip]);
+    }
+
+    let ips: Vec<IpAddr> = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|e| CoreError::NetworkError(format!("Failed to resolve DNS server {host}: {e}")))?
+        .map(|addr| addr.ip())
+        .collect();
+    if ips.is_empty() {
+        return Err(CoreError::NetworkError(format!(
+            "Failed to resolve DNS server {host}"
+        )));
+    }
+    Ok(ips)
+}
@@ -0,0 +1,137 @@
+//! NSEC/NSEC3 区域遍历检测模块
+//!
+//! 沿 NSEC 链逐个查询 `next_domain_name`，用于审计区域是否泄露全部记录名。
+//! NSEC3 区域的名称经过哈希，无法直接遍历，仅报告其参数；
+//! Opt-Out 标志取自查询随机不存在名称时权威部分返回的 NSEC3 记录
+//! （RFC 5155 要求 NSEC3PARAM 中的该标志为 0）。
+
+use std::collections::HashSet;
+use std::time::Instant;
+
+use hickory_resolver::{
+    config::ResolverOpts,
+    proto::{
+        dnssec::rdata::DNSSECRData,
+        rr::{record_data::RData, RecordType},
+        ProtoErrorKind,
+    },
+    TokioResolver,
+};
+
+use super::resolver::{build_resolver, ResolverConfig};
+use crate::error::{CoreError, CoreResult};
+use crate::types::NsecWalkResult;
+
+/// 单次遍历最多枚举的名称数量
+const MAX_WALK_NAMES: usize = 256;
+
+/// 规范化域名（小写、去除末尾的点）
+fn normalize_name(name: &str) -> String {
+    name.trim_end_matches('.').to_lowercase()
+}
+
+/// 查询某个名称的 NSEC 记录，返回下一个名称
+async fn query_next_name(resolver: &TokioResolver, name: &str) -> Option<String> {
+    let response = resolver.lookup(name, RecordType::NSEC).await.ok()?;
+    response
+        .record_iter()
+        .find_map(|record| match record.data() {
+            RData::DNSSEC(DNSSECRData::NSEC(nsec)) => {
+                Some(normalize_name(&nsec.next_domain_name().to_string()))
+            }
+            _ => None,
+        })
+}
+
+/// 查询随机不存在的名称，从否定应答的 NSEC3 记录中读取 Opt-Out 标志
+async fn query_nsec3_opt_out(resolver: &TokioResolver, apex: &str) -> Option<bool> {
+    let probe = format!("{:016x}.{apex}", rand::random::<u64>());
+    let error = resolver.lookup(probe.as_str(), RecordType::A).await.err()?;
+    let ProtoErrorKind::NoRecordsFound {
+        authorities: Some(authorities),
+        ..
+    } = error.proto()?.kind()
+    else {
+        return None;
+    };
+
+    let flags: Vec<bool> = authorities
+        .iter()
+        .filter_map(|record| match record.data() {
+            RData::DNSSEC(DNSSECRData::NSEC3(nsec3)) => Some(nsec3.opt_out()),
+            _ => None,
+        })
+        .collect();
+    (!flags.is_empty()).then(|| flags.contains(&true))
+}
+
+/// NSEC 区域遍历
+pub async fn nsec_walk(domain: &str) -> CoreResult<NsecWalkResult> {
+    let start_time = Instant::now();
+    let apex = normalize_name(domain);
+    if apex.is_empty() {
+        return Err(CoreError::ValidationError("域名不能为空".to_string()));
+    }
+
+    // 启用验证以在查询中设置 DO 位，获取 DNSSEC 记录
+    let mut opts = ResolverOpts::default();
+    opts.validate = true;
+    let (resolver, used_nameserver) = build_resolver(&ResolverConfig::default(), opts).await?;
+
+    let mut result = NsecWalkResult {
+        domain: apex.clone(),
+        denial_type: "none".to_string(),
+        discovered_names: Vec::new(),
+        complete: false,
+        truncated: false,
+        nsec3_opt_out: None,
+        nsec3_iterations: None,
+        nameserver: used_nameserver,
+        response_time_ms: 0,
+    };
+
+    if let Some(first) = query_next_name(&resolver, &apex).await {
+        result.denial_type = "NSEC".to_string();
+
+        let mut seen: HashSet<String> = HashSet::new();
+        seen.insert(apex.clone());
+        let mut next = Some(first);
+
+        while let Some(name) = next.take() {
+            // 链回到顶点或出现环，遍历结束
+            if name == apex || !seen.insert(name.clone()) {
+                result.complete = name == apex;
+                break;
+            }
+            if result.discovered_names.len() >= MAX_WALK_NAMES {
+                result.truncated = true;
+                break;
+            }
+            result.discovered_names.push(name.clone());
+            next = query_next_name(&resolver, &name).await;
+        }
+    } else if let Ok(response) = resolver.lookup(apex.as_str(), RecordType::NSEC3PARAM).await {
+        let iterations = response
+            .record_iter()
+            .find_map(|record| match record.data() {
+                RData::DNSSEC(DNSSECRData::NSEC3PARAM(param)) => Some(param.iterations()),
+                _ => None,
+            });
+        if let Some(iterations) = iterations {
+            result.denial_type = "NSEC3".to_string();
+            result.nsec3_iterations = Some(iterations);
+            result.nsec3_opt_out = query_nsec3_opt_out(&resolver, &apex).await;
+        }
+    }
+
+    log::debug!(
+        "NSEC walk for {apex}: type={}, names={}, complete={}, truncated={}",
+        result.denial_type,
+        result.discovered_names.len(),
+        result.complete,
+        result.truncated
+    );
+
+    result.response_time_ms = u64::try_from(start_time.elapsed().as_millis()).unwrap_or(u64::MAX);
+    Ok(result)
+}
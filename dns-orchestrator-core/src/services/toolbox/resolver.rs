@@ -0,0 +1,166 @@
+//! 工具箱共用的 resolver 配置
+//!
+//! DNS 查询、DNSSEC 验证、IP 查询与传播检查都通过 [`build_resolver`] 构造 resolver，
+//! 同一份 [`ResolverConfig`] 可在多次调用之间复用。
+
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+use hickory_resolver::{
+    config::{self, NameServerConfig, ResolverOpts},
+    name_server::TokioConnectionProvider,
+    TokioResolver,
+};
+use tokio::net::UdpSocket;
+
+use super::nameserver;
+use crate::error::{CoreError, CoreResult};
+
+/// 工具箱 resolver 配置
+///
+/// 未指定 DNS 服务器时使用系统配置；服务器地址格式同 `dns_lookup` 的 `nameserver` 参数，
+/// 按前缀选择传输协议（普通 IP、`tls://`、`https://`）。
+#[derive(Debug, Clone, Default)]
+pub struct ResolverConfig {
+    /// DNS 服务器（为空时使用系统配置）
+    pub nameservers: Vec<String>,
+    /// 单次查询超时（`None` 时使用 hickory 默认值）
+    pub timeout: Option<Duration>,
+    /// 查询使用的本地源地址（须为本机地址）
+    pub bind_addr: Option<IpAddr>,
+    /// 是否启用 EDNS0（DNSSEC 验证始终启用）
+    pub edns0: bool,
+}
+
+impl ResolverConfig {
+    /// 使用系统 DNS 配置
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 追加一个 DNS 服务器
+    #[must_use]
+    pub fn with_nameserver(mut self, nameserver: impl Into<String>) -> Self {
+        self.nameservers.push(nameserver.into());
+        self
+    }
+
+    /// 设置单次查询超时
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// 指定查询的本地源地址
+    #[must_use]
+    pub fn with_bind_addr(mut self, bind_addr: IpAddr) -> Self {
+        self.bind_addr = Some(bind_addr);
+        self
+    }
+
+    /// 启用或关闭 EDNS0
+    #[must_use]
+    pub fn with_edns0(mut self, edns0: bool) -> Self {
+        self.edns0 = edns0;
+        self
+    }
+
+    /// 仅使用单个 DNS 服务器（空字符串视为系统配置），其余选项保持不变
+    pub(super) fn for_nameserver(&self, nameserver: Option<&str>) -> Self {
+        Self {
+            nameservers: nameserver
+                .filter(|ns| !ns.is_empty())
+                .map(|ns| vec![ns.to_string()])
+                .unwrap_or_default(),
+            ..self.clone()
+        }
+    }
+}
+
+/// 按配置构造 resolver，返回 resolver 与实际使用的 DNS 服务器描述
+///
+/// `opts` 为调用方需要的基础选项（如 DNSSEC 验证），超时与 EDNS0 由 `config` 覆盖。
+pub(super) async fn build_resolver(
+    config: &ResolverConfig,
+    mut opts: ResolverOpts,
+) -> CoreResult<(TokioResolver, String)> {
+    let (resolver_config, used_nameserver) = if config.nameservers.is_empty() {
+        (config::ResolverConfig::default(), system_nameservers())
+    } else {
+        let mut servers: Vec<NameServerConfig> = Vec::new();
+        for ns in &config.nameservers {
+            servers.extend_from_slice(nameserver::resolver_config(ns).await?.name_servers());
+        }
+        (
+            config::ResolverConfig::from_parts(None, vec![], servers),
+            config.nameservers.join(", "),
+        )
+    };
+
+    let resolver_config = match config.bind_addr {
+        Some(bind_addr) => bind_resolver_config(&resolver_config, bind_addr).await?,
+        None => resolver_config,
+    };
+
+    if let Some(timeout) = config.timeout {
+        opts.timeout = timeout;
+    }
+    opts.edns0 |= config.edns0;
+
+    let resolver =
+        TokioResolver::builder_with_config(resolver_config, TokioConnectionProvider::default())
+            .with_options(opts)
+            .build();
+    Ok((resolver, used_nameserver))
+}
+
+/// 系统默认 DNS 服务器地址（用于结果展示）
+pub(super) fn system_nameservers() -> String {
+    let servers: Vec<String> = config::ResolverConfig::default()
+        .name_servers()
+        .iter()
+        .map(|ns| ns.socket_addr.ip().to_string())
+        .collect();
+    if servers.is_empty() {
+        "系统默认".to_string()
+    } else {
+        servers.join(", ")
+    }
+}
+
+/// 让同地址族的 DNS 服务器都从指定的本地源地址发出查询
+///
+/// 源地址不是本机地址，或没有与之同地址族的 DNS 服务器时返回 `ValidationError`。
+async fn bind_resolver_config(
+    config: &config::ResolverConfig,
+    bind_addr: IpAddr,
+) -> CoreResult<config::ResolverConfig> {
+    // 能绑定说明是本机地址
+    UdpSocket::bind(SocketAddr::new(bind_addr, 0))
+        .await
+        .map_err(|e| CoreError::ValidationError(format!("源地址 {bind_addr} 不是本机地址: {e}")))?;
+
+    let servers: Vec<NameServerConfig> = config
+        .name_servers()
+        .iter()
+        .filter(|ns| ns.socket_addr.is_ipv4() == bind_addr.is_ipv4())
+        .cloned()
+        .map(|mut ns| {
+            ns.bind_addr = Some(SocketAddr::new(bind_addr, 0));
+            ns
+        })
+        .collect();
+    if servers.is_empty() {
+        return Err(CoreError::ValidationError(format!(
+            "源地址 {bind_addr} 与 DNS 服务器的地址族不一致"
+        )));
+    }
+
+    Ok(config::ResolverConfig::from_parts(
+        config.domain().cloned(),
+        config.search().to_vec(),
+        servers,
+    ))
+}
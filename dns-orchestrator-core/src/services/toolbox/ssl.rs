@@ -6,16 +6,18 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use log::{debug, error, trace, warn};
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
 use rustls::crypto::CryptoProvider;
-use rustls::{ClientConfig, RootCertStore};
-use rustls_pki_types::{CertificateDer, ServerName};
+use rustls::{ClientConfig, DigitallySignedStruct, RootCertStore, SignatureScheme};
+use rustls_pki_types::pem::PemObject;
+use rustls_pki_types::{CertificateDer, ServerName, UnixTime};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpStream;
 use tokio::time::timeout;
 use tokio_rustls::TlsConnector;
 use x509_parser::prelude::*;
 
-use crate::error::CoreResult;
+use crate::error::{CoreError, CoreResult};
 use crate::types::{CertChainItem, SslCertInfo, SslCheckResult};
 
 // 超时配置常量
@@ -70,9 +72,26 @@ async fn check_http_connection(domain: &str, port: u16) -> bool {
 /// SSL 证书检查（使用 rustls 纯异步实现）
 #[cfg(feature = "rustls")]
 pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckResult> {
+    ssl_check_with_roots(domain, port, &[]).await
+}
+
+/// SSL 证书检查，额外信任指定的根证书
+///
+/// `extra_roots` 为 PEM 格式（每项可包含多张证书），与内置的 webpki 根证书合并，
+/// 用于校验内部 CA 签发的证书。PEM 无法解析时返回 `ValidationError`。
+#[cfg(feature = "rustls")]
+pub async fn ssl_check_with_roots(
+    domain: &str,
+    port: Option<u16>,
+    extra_roots: &[String],
+) -> CoreResult<SslCheckResult> {
     // 确保 CryptoProvider 已初始化
     ensure_crypto_provider();
 
+    let mut root_store = RootCertStore::empty();
+    root_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    add_extra_roots(&mut root_store, extra_roots)?;
+
     let port = port.unwrap_or(443);
     let domain = domain.to_string();
 
@@ -120,9 +139,6 @@ pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckRe
     };
 
     // 2. 配置 rustls 客户端
-    let mut root_store = RootCertStore::empty();
-    root_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
-
     let config = ClientConfig::builder()
         .with_root_certificates(root_store)
         .with_no_client_auth();
@@ -143,7 +159,7 @@ pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckRe
     // 3. TLS 握手（带超时）
     trace!("[SSL] Performing TLS handshake...");
     let tls_start = std::time::Instant::now();
-    let tls_result = timeout(TLS_TIMEOUT, connector.connect(server_name, stream)).await;
+    let tls_result = timeout(TLS_TIMEOUT, connector.connect(server_name.clone(), stream)).await;
 
     let tls_stream = match tls_result {
         Ok(Ok(stream)) => {
@@ -155,6 +171,14 @@ pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckRe
         }
         Ok(Err(e)) => {
             warn!("[SSL] TLS handshake failed: {e}");
+            // 证书不受信任时跳过校验重新握手，仍返回证书详情
+            if let Some(reason) = certificate_error(&e) {
+                if let Some(result) =
+                    check_untrusted(&domain, port, server_name, &reason.to_string()).await
+                {
+                    return Ok(result);
+                }
+            }
             // TLS 握手失败，检查是否为 HTTP
             trace!("[SSL] Checking if HTTP connection...");
             if check_http_connection(&domain, port).await {
@@ -225,41 +249,20 @@ pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckRe
         }
     };
 
-    let cert_der = certs[0].as_ref();
-
-    // 5. 解析叶子证书
-    trace!("[SSL] Parsing certificate...");
-    let (_, cert) = match X509Certificate::from_der(cert_der) {
-        Ok(c) => c,
+    // 5. 解析证书信息与完整证书链
+    let cert_info = match build_cert_info(&domain, certs) {
+        Ok(info) => info,
         Err(e) => {
-            warn!("[SSL] Certificate parsing failed: {e}");
             return Ok(SslCheckResult {
                 domain,
                 port,
                 connection_status: "https".to_string(),
                 cert_info: None,
-                error: Some(format!("证书解析失败: {e}")),
+                error: Some(e),
             });
         }
     };
 
-    // 6. 解析证书信息
-    let mut cert_info = parse_certificate(&domain, port, &cert);
-
-    // 7. 解析完整证书链
-    cert_info.certificate_chain = certs
-        .iter()
-        .filter_map(|c: &CertificateDer| {
-            X509Certificate::from_der(c.as_ref())
-                .ok()
-                .map(|(_, parsed)| CertChainItem {
-                    subject: parsed.subject().to_string(),
-                    issuer: parsed.issuer().to_string(),
-                    is_ca: parsed.is_ca(),
-                })
-        })
-        .collect();
-
     debug!(
         "[SSL] Check completed: {} - valid={}, expired={}, days_remaining={}, chain_length={}, total_time={:?}",
         domain,
@@ -279,13 +282,262 @@ pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckRe
     })
 }
 
+/// 跳过证书校验重新握手，返回不受信任证书的详情
+///
+/// 自签名证书会在错误信息中明确说明；无法重新获取证书时返回 `None`。
+#[cfg(feature = "rustls")]
+async fn check_untrusted(
+    domain: &str,
+    port: u16,
+    server_name: ServerName<'static>,
+    reason: &str,
+) -> Option<SslCheckResult> {
+    trace!("[SSL] Retrying handshake without certificate verification...");
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let config = ClientConfig::builder()
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(AcceptAnyServerCert(provider)))
+        .with_no_client_auth();
+    let connector = TlsConnector::from(Arc::new(config));
+
+    let stream = timeout(
+        CONNECT_TIMEOUT,
+        TcpStream::connect(format!("{domain}:{port}")),
+    )
+    .await
+    .ok()?
+    .ok()?;
+    let tls_stream = timeout(TLS_TIMEOUT, connector.connect(server_name, stream))
+        .await
+        .ok()?
+        .ok()?;
+    let (_, tls_conn) = tls_stream.get_ref();
+    let certs = tls_conn.peer_certificates().filter(|c| !c.is_empty())?;
+
+    let mut cert_info = build_cert_info(domain, certs).ok()?;
+    cert_info.is_valid = false;
+    let leaf_self_signed = cert_info
+        .certificate_chain
+        .first()
+        .is_some_and(|leaf| leaf.self_signed);
+    let error = if leaf_self_signed {
+        "自签名证书，不受信任".to_string()
+    } else {
+        format!("证书不受信任: {reason}")
+    };
+    debug!("[SSL] Untrusted certificate for {domain}: {error}");
+
+    Some(SslCheckResult {
+        domain: domain.to_string(),
+        port,
+        connection_status: "https".to_string(),
+        cert_info: Some(cert_info),
+        error: Some(error),
+    })
+}
+
+/// 从握手错误中提取证书校验错误
+#[cfg(feature = "rustls")]
+fn certificate_error(e: &std::io::Error) -> Option<&rustls::CertificateError> {
+    match e.get_ref()?.downcast_ref::<rustls::Error>()? {
+        rustls::Error::InvalidCertificate(err) => Some(err),
+        _ => None,
+    }
+}
+
+/// 接受任意服务器证书的校验器（仅用于读取不受信任的证书，握手签名仍正常校验）
+#[cfg(feature = "rustls")]
+#[derive(Debug)]
+struct AcceptAnyServerCert(Arc<CryptoProvider>);
+
+#[cfg(feature = "rustls")]
+impl ServerCertVerifier for AcceptAnyServerCert {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+/// 由服务器发送的证书链构造证书信息，失败时返回错误描述
+#[cfg(feature = "rustls")]
+fn build_cert_info(domain: &str, certs: &[CertificateDer]) -> Result<SslCertInfo, String> {
+    // 解析叶子证书
+    trace!("[SSL] Parsing certificate...");
+    let (_, cert) = X509Certificate::from_der(certs[0].as_ref()).map_err(|e| {
+        warn!("[SSL] Certificate parsing failed: {e}");
+        format!("证书解析失败: {e}")
+    })?;
+
+    let mut cert_info = parse_certificate(domain, &cert);
+    // 按服务器发送的顺序保留完整证书链（无法解析的证书也保留原文）
+    cert_info.certificate_chain = certs.iter().map(chain_item).collect();
+    Ok(cert_info)
+}
+
+/// 解析证书链中的单张证书
+#[cfg(feature = "rustls")]
+fn chain_item(der: &CertificateDer) -> CertChainItem {
+    let pem = der_to_pem(der.as_ref());
+    match X509Certificate::from_der(der.as_ref()) {
+        Ok((_, parsed)) => CertChainItem {
+            subject: parsed.subject().to_string(),
+            issuer: parsed.issuer().to_string(),
+            is_ca: parsed.is_ca(),
+            self_signed: is_self_signed(&parsed),
+            name_constrained: matches!(parsed.name_constraints(), Ok(Some(_))),
+            key_usage: key_usage_names(&parsed),
+            extended_key_usage: extended_key_usage_names(&parsed),
+            pem,
+        },
+        Err(e) => {
+            warn!("[SSL] Chain certificate parsing failed: {e}");
+            CertChainItem {
+                subject: String::new(),
+                issuer: String::new(),
+                is_ca: false,
+                self_signed: false,
+                name_constrained: false,
+                key_usage: Vec::new(),
+                extended_key_usage: Vec::new(),
+                pem,
+            }
+        }
+    }
+}
+
+/// 判断证书是否自签名：主题与颁发者相同，且签名可由自身公钥验证
+#[cfg(feature = "rustls")]
+fn is_self_signed(cert: &X509Certificate) -> bool {
+    cert.subject().as_raw() == cert.issuer().as_raw() && cert.verify_signature(None).is_ok()
+}
+
+/// 密钥用途名称（RFC 5280 4.2.1.3）
+#[cfg(feature = "rustls")]
+fn key_usage_names(cert: &X509Certificate) -> Vec<String> {
+    let Ok(Some(ext)) = cert.key_usage() else {
+        return Vec::new();
+    };
+    let usage = ext.value;
+    [
+        (usage.digital_signature(), "digitalSignature"),
+        (usage.non_repudiation(), "nonRepudiation"),
+        (usage.key_encipherment(), "keyEncipherment"),
+        (usage.data_encipherment(), "dataEncipherment"),
+        (usage.key_agreement(), "keyAgreement"),
+        (usage.key_cert_sign(), "keyCertSign"),
+        (usage.crl_sign(), "cRLSign"),
+        (usage.encipher_only(), "encipherOnly"),
+        (usage.decipher_only(), "decipherOnly"),
+    ]
+    .into_iter()
+    .filter(|(set, _)| *set)
+    .map(|(_, name)| name.to_string())
+    .collect()
+}
+
+/// 扩展密钥用途名称（RFC 5280 4.2.1.12），未知用途以 OID 表示
+#[cfg(feature = "rustls")]
+fn extended_key_usage_names(cert: &X509Certificate) -> Vec<String> {
+    let Ok(Some(ext)) = cert.extended_key_usage() else {
+        return Vec::new();
+    };
+    let usage = ext.value;
+    [
+        (usage.any, "anyExtendedKeyUsage"),
+        (usage.server_auth, "serverAuth"),
+        (usage.client_auth, "clientAuth"),
+        (usage.code_signing, "codeSigning"),
+        (usage.email_protection, "emailProtection"),
+        (usage.time_stamping, "timeStamping"),
+        (usage.ocsp_signing, "OCSPSigning"),
+    ]
+    .into_iter()
+    .filter(|(set, _)| *set)
+    .map(|(_, name)| name.to_string())
+    .chain(usage.other.iter().map(ToString::to_string))
+    .collect()
+}
+
+/// PEM 每行的 base64 字符数
+#[cfg(feature = "rustls")]
+const PEM_LINE_LENGTH: usize = 64;
+
+/// 将 DER 编码的证书转换为 PEM 文本
+#[cfg(feature = "rustls")]
+fn der_to_pem(der: &[u8]) -> String {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+
+    let encoded = STANDARD.encode(der);
+    let mut pem = String::from("-----BEGIN CERTIFICATE-----\n");
+    for line in encoded.as_bytes().chunks(PEM_LINE_LENGTH) {
+        pem.push_str(&String::from_utf8_lossy(line));
+        pem.push('\n');
+    }
+    pem.push_str("-----END CERTIFICATE-----\n");
+    pem
+}
+
+/// 将 PEM 格式的根证书加入信任库
+#[cfg(feature = "rustls")]
+fn add_extra_roots(root_store: &mut RootCertStore, extra_roots: &[String]) -> CoreResult<()> {
+    for pem in extra_roots {
+        let mut found = false;
+        for cert in CertificateDer::pem_slice_iter(pem.as_bytes()) {
+            let cert =
+                cert.map_err(|e| CoreError::ValidationError(format!("无效的 PEM 根证书: {e}")))?;
+            root_store
+                .add(cert)
+                .map_err(|e| CoreError::ValidationError(format!("无效的根证书: {e}")))?;
+            found = true;
+        }
+        if !found {
+            return Err(CoreError::ValidationError("PEM 中未找到证书".to_string()));
+        }
+    }
+    Ok(())
+}
+
 /// 解析证书信息
 #[cfg(feature = "rustls")]
-fn parse_certificate(
-    query: &str,
-    _port: u16,
-    cert: &x509_parser::certificate::X509Certificate,
-) -> SslCertInfo {
+fn parse_certificate(query: &str, cert: &x509_parser::certificate::X509Certificate) -> SslCertInfo {
     let subject = cert.subject().to_string();
     let issuer = cert.issuer().to_string();
     let valid_from = cert.validity().not_before.to_rfc2822().unwrap_or_default();
@@ -331,11 +583,11 @@ fn parse_certificate(
         .or_else(|| san.first().cloned())
         .unwrap_or_else(|| query.to_string());
 
-    // 检查域名是否匹配（CN 或 SAN 中任意一个）
-    let domain_matches = check_domain_match(query, cn.as_deref(), &san);
+    // 检查证书是否覆盖查询的域名
+    let hostname_matches = check_domain_match(query, cn.as_deref(), &san);
 
     // is_valid = 未过期 且 域名匹配
-    let is_valid = !is_expired && domain_matches;
+    let is_valid = !is_expired && hostname_matches;
 
     let serial_number = cert.serial.to_str_radix(16).to_uppercase();
     let signature_algorithm = cert.signature_algorithm.algorithm.to_string();
@@ -352,6 +604,7 @@ fn parse_certificate(
         days_remaining,
         is_expired,
         is_valid,
+        hostname_matches,
         san,
         serial_number,
         signature_algorithm,
@@ -359,26 +612,20 @@ fn parse_certificate(
     }
 }
 
-/// 检查查询的域名/IP 是否与证书的 CN 或 SAN 匹配
+/// 检查查询的域名是否与证书匹配
+///
+/// 与浏览器一致（RFC 6125）：存在 SAN 时只按 SAN 匹配，没有 SAN 时才回退到 CN。
 #[cfg(feature = "rustls")]
 fn check_domain_match(query: &str, cn: Option<&str>, san: &[String]) -> bool {
-    let query_lower = query.to_lowercase();
+    let query_lower = query.trim_end_matches('.').to_lowercase();
 
-    // 检查 CN
-    if let Some(cn) = cn {
-        if matches_domain(&query_lower, &cn.to_lowercase()) {
-            return true;
-        }
+    if !san.is_empty() {
+        return san
+            .iter()
+            .any(|name| matches_domain(&query_lower, &name.to_lowercase()));
     }
 
-    // 检查 SAN
-    for name in san {
-        if matches_domain(&query_lower, &name.to_lowercase()) {
-            return true;
-        }
-    }
-
-    false
+    cn.is_some_and(|cn| matches_domain(&query_lower, &cn.to_lowercase()))
 }
 
 /// 域名匹配（支持通配符）
@@ -1,30 +1,230 @@
 //! WHOIS 查询模块
+//!
+//! 注册局的 WHOIS 服务器限流严格，查询结果在进程内按域名缓存一段时间，
+//! 被限流时返回 `CoreError::RateLimited` 而不是解析出空结果。
 
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use futures::StreamExt;
 use regex::Regex;
 use whois_rust::{WhoIs, WhoIsLookupOptions};
 
 use crate::error::{CoreError, CoreResult};
 use crate::types::WhoisResult;
 
+/// WHOIS 结果缓存时长（秒）
+const CACHE_TTL_SECS: u64 = 300;
+
+/// 限流响应中常见的提示（小写）
+const RATE_LIMIT_PATTERNS: &[&str] = &[
+    "rate limit",
+    "too many queries",
+    "too many requests",
+    "query rate",
+    "limit exceeded",
+    "exceeded the maximum",
+    "quota exceeded",
+    "try again later",
+    "temporarily blocked",
+];
+
+/// 批量查询时同时访问的 WHOIS 服务器数上限
+const BATCH_CONCURRENCY: usize = 8;
+
+/// 各注册局对未注册域名的响应提示（按 TLD，小写）
+const NOT_FOUND_PATTERNS: &[(&str, &[&str])] = &[
+    ("com", &["no match for"]),
+    ("net", &["no match for"]),
+    ("org", &["domain not found", "not found"]),
+    ("io", &["is available for purchase", "not found"]),
+    ("cn", &["no matching record"]),
+    ("uk", &["no match for"]),
+    ("de", &["status: free"]),
+    ("fr", &["no entries found"]),
+    ("nl", &["is free"]),
+    ("eu", &["status: available"]),
+    ("jp", &["no match!!"]),
+    ("au", &["no data found"]),
+    ("ru", &["no entries found"]),
+];
+
+/// TLD 不在上表中时使用的通用提示（小写）
+const GENERIC_NOT_FOUND_PATTERNS: &[&str] = &[
+    "no match for",
+    "not found",
+    "no entries found",
+    "no data found",
+    "no matching record",
+    "status: free",
+    "status: available",
+    "is available for registration",
+];
+
+/// 连接被服务器重置时常见的错误信息（小写）
+const CONNECTION_RESET_PATTERNS: &[&str] = &["connection reset", "reset by peer", "broken pipe"];
+
+/// WHOIS 结果缓存，键为规范化后的域名
+static CACHE: LazyLock<Mutex<HashMap<String, (Instant, WhoisResult)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
 /// WHOIS 查询
 pub async fn whois_lookup(domain: &str, whois_servers: &str) -> CoreResult<WhoisResult> {
+    let key = normalize_domain(domain);
+    if let Some(cached) = cache_get(&key) {
+        return Ok(cached);
+    }
+
     let whois = WhoIs::from_string(whois_servers)
         .map_err(|e| CoreError::NetworkError(format!("初始化 WHOIS 客户端失败: {e}")))?;
 
     let options = WhoIsLookupOptions::from_string(domain)
         .map_err(|e| CoreError::ValidationError(format!("无效的域名: {e}")))?;
 
-    let raw = whois
-        .lookup_async(options)
+    let raw = whois.lookup_async(options).await.map_err(|e| {
+        let message = e.to_string();
+        if contains_any(&message, CONNECTION_RESET_PATTERNS) {
+            CoreError::RateLimited(format!("WHOIS 服务器断开了连接: {message}"))
+        } else {
+            CoreError::NetworkError(format!("WHOIS 查询失败: {message}"))
+        }
+    })?;
+
+    let result = parse_whois_response(domain, &raw);
+    if is_rate_limited(&result) {
+        return Err(CoreError::RateLimited(format!(
+            "WHOIS 服务器限制了 {domain} 的查询频率，请稍后再试"
+        )));
+    }
+
+    cache_insert(key, result.clone());
+    Ok(result)
+}
+
+/// 批量 WHOIS 查询
+///
+/// 按 WHOIS 服务器分组：不同服务器之间并行（最多 `BATCH_CONCURRENCY` 个），
+/// 同一服务器的查询串行执行，避免触发注册局限流。结果顺序与输入一致。
+pub async fn whois_lookup_batch(
+    domains: &[&str],
+    whois_servers: &str,
+) -> Vec<CoreResult<WhoisResult>> {
+    let servers: HashMap<String, serde_json::Value> =
+        serde_json::from_str(whois_servers).unwrap_or_default();
+
+    let mut groups: HashMap<String, Vec<(usize, &str)>> = HashMap::new();
+    for (index, domain) in domains.iter().enumerate() {
+        groups
+            .entry(whois_server_for(domain, &servers))
+            .or_default()
+            .push((index, domain));
+    }
+
+    let mut results: Vec<(usize, CoreResult<WhoisResult>)> =
+        futures::stream::iter(groups.into_values().map(|group| async move {
+            let mut results = Vec::with_capacity(group.len());
+            for (index, domain) in group {
+                results.push((index, whois_lookup(domain, whois_servers).await));
+            }
+            results
+        }))
+        .buffer_unordered(BATCH_CONCURRENCY)
+        .collect::<Vec<_>>()
         .await
-        .map_err(|e| CoreError::NetworkError(format!("WHOIS 查询失败: {e}")))?;
+        .into_iter()
+        .flatten()
+        .collect();
+
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// 查找域名对应的 WHOIS 服务器（按最长后缀匹配），找不到时以 TLD 作为分组键
+fn whois_server_for(domain: &str, servers: &HashMap<String, serde_json::Value>) -> String {
+    let domain = normalize_domain(domain);
+    let mut suffix = domain.as_str();
+    while let Some((_, rest)) = suffix.split_once('.') {
+        suffix = rest;
+        let host = match servers.get(suffix) {
+            Some(serde_json::Value::String(host)) => Some(host.as_str()),
+            Some(serde_json::Value::Object(server)) => {
+                server.get("host").and_then(serde_json::Value::as_str)
+            }
+            _ => None,
+        };
+        if let Some(host) = host {
+            return host.to_string();
+        }
+    }
+    suffix.to_string()
+}
 
-    Ok(parse_whois_response(domain, &raw))
+/// 规范化域名作为缓存键（小写、去掉首尾空白与末尾的点）
+fn normalize_domain(domain: &str) -> String {
+    domain.trim().trim_end_matches('.').to_lowercase()
+}
+
+/// 读取未过期的缓存结果
+fn cache_get(key: &str) -> Option<WhoisResult> {
+    let cache = CACHE.lock().ok()?;
+    cache
+        .get(key)
+        .filter(|(cached_at, _)| cached_at.elapsed() < Duration::from_secs(CACHE_TTL_SECS))
+        .map(|(_, result)| result.clone())
+}
+
+/// 写入缓存，并顺带清理已过期的条目
+fn cache_insert(key: String, result: WhoisResult) {
+    if let Ok(mut cache) = CACHE.lock() {
+        cache.retain(|_, (cached_at, _)| cached_at.elapsed() < Duration::from_secs(CACHE_TTL_SECS));
+        cache.insert(key, (Instant::now(), result));
+    }
+}
+
+/// 判断响应是否为限流提示
+///
+/// 只在未解析出任何注册信息时检查提示文本，避免误判正常响应中的使用条款。
+fn is_rate_limited(result: &WhoisResult) -> bool {
+    !has_registration_data(result) && contains_any(&result.raw, RATE_LIMIT_PATTERNS)
+}
+
+/// 是否解析出了任何注册信息
+fn has_registration_data(result: &WhoisResult) -> bool {
+    result.registrar.is_some()
+        || result.creation_date.is_some()
+        || result.expiration_date.is_some()
+        || !result.name_servers.is_empty()
+}
+
+/// 判断域名是否已注册
+///
+/// 解析出注册信息即视为已注册；否则按 TLD 匹配注册局的"未找到"提示。
+fn is_registered(domain: &str, result: &WhoisResult) -> bool {
+    if has_registration_data(result) {
+        return true;
+    }
+    let tld = normalize_domain(domain)
+        .rsplit('.')
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    let patterns = NOT_FOUND_PATTERNS
+        .iter()
+        .find(|(suffix, _)| *suffix == tld)
+        .map_or(GENERIC_NOT_FOUND_PATTERNS, |(_, patterns)| *patterns);
+    !contains_any(&result.raw, patterns)
+}
+
+/// 文本（忽略大小写）是否包含任一模式
+fn contains_any(text: &str, patterns: &[&str]) -> bool {
+    let text = text.to_lowercase();
+    patterns.iter().any(|pattern| text.contains(pattern))
 }
 
 /// 解析 WHOIS 原始响应
 fn parse_whois_response(domain: &str, raw: &str) -> WhoisResult {
-    WhoisResult {
+    let mut result = WhoisResult {
         domain: domain.to_string(),
         registrar: extract_field(
             raw,
@@ -64,7 +264,10 @@ fn parse_whois_response(domain: &str, raw: &str) -> WhoisResult {
         name_servers: extract_name_servers(raw),
         status: extract_status(raw),
         raw: raw.to_string(),
-    }
+        is_registered: true,
+    };
+    result.is_registered = is_registered(domain, &result);
+    result
 }
 
 /// 使用多个正则模式提取字段
@@ -0,0 +1,29 @@
+//! 审计日志输出抽象 Trait
+
+use async_trait::async_trait;
+
+use crate::error::CoreResult;
+use crate::types::AuditEvent;
+
+/// 审计日志输出 Trait
+///
+/// 服务层在每次变更操作成功后调用 `record`，平台层可将事件写入文件或数据库。
+/// 写入失败只记录日志，不影响业务操作本身。
+#[async_trait]
+pub trait AuditSink: Send + Sync {
+    /// 记录一条审计事件
+    async fn record(&self, event: AuditEvent) -> CoreResult<()>;
+}
+
+/// 空实现：丢弃所有审计事件
+///
+/// 未配置审计输出时的默认实现。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopAuditSink;
+
+#[async_trait]
+impl AuditSink for NoopAuditSink {
+    async fn record(&self, _event: AuditEvent) -> CoreResult<()> {
+        Ok(())
+    }
+}
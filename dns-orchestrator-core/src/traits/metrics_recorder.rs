@@ -0,0 +1,58 @@
+//! 指标采集抽象 Trait
+
+use std::time::Duration;
+
+/// Provider 调用结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallOutcome {
+    /// 调用成功
+    Success,
+    /// 调用失败
+    Error,
+}
+
+/// 指标采集 Trait
+///
+/// 服务层在调用 Provider、访问缓存时上报指标，平台层可接入 Prometheus 等系统。
+/// 方法为同步调用，实现方应只做计数/直方图累加等轻量操作。
+pub trait MetricsRecorder: Send + Sync {
+    /// 是否启用；返回 false 时服务层跳过计时
+    fn enabled(&self) -> bool {
+        true
+    }
+
+    /// 记录一次 Provider 调用
+    ///
+    /// `provider` 为 Provider 标识（如 `cloudflare`），`operation` 为操作名（如 `list_records`）。
+    fn record_provider_call(
+        &self,
+        provider: &'static str,
+        operation: &'static str,
+        duration: Duration,
+        outcome: CallOutcome,
+    );
+
+    /// 记录一次缓存查询
+    fn record_cache_lookup(&self, _cache: &'static str, _hit: bool) {}
+}
+
+/// 空实现：丢弃所有指标
+///
+/// 未配置指标采集时的默认实现。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopMetricsRecorder;
+
+impl MetricsRecorder for NoopMetricsRecorder {
+    fn enabled(&self) -> bool {
+        false
+    }
+
+    fn record_provider_call(
+        &self,
+        _provider: &'static str,
+        _operation: &'static str,
+        _duration: Duration,
+        _outcome: CallOutcome,
+    ) {
+    }
+}
@@ -1,11 +1,15 @@
 //! 存储层抽象 Trait 定义
 
 mod account_repository;
+mod audit_sink;
 mod credential_store;
 mod domain_metadata_repository;
+mod metrics_recorder;
 mod provider_registry;
 
 pub use account_repository::AccountRepository;
+pub use audit_sink::{AuditSink, NoopAuditSink};
 pub use credential_store::{CredentialStore, CredentialsMap, LegacyCredentialsMap};
 pub use domain_metadata_repository::DomainMetadataRepository;
+pub use metrics_recorder::{CallOutcome, MetricsRecorder, NoopMetricsRecorder};
 pub use provider_registry::{InMemoryProviderRegistry, ProviderRegistry};
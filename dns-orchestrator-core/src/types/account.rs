@@ -61,3 +61,31 @@ pub struct UpdateAccountRequest {
     /// 新的凭证（可选，提供时会覆盖原有凭证）
     pub credentials: Option<ProviderCredentials>,
 }
+
+/// 单个账户的凭证校验结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountValidationResult {
+    /// 账户 ID
+    pub account_id: String,
+    /// 凭证是否有效
+    pub valid: bool,
+    /// 校验失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// 校验时间
+    #[serde(with = "crate::utils::datetime")]
+    pub checked_at: DateTime<Utc>,
+}
+
+/// 批量凭证校验汇总
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidateAccountsResult {
+    /// 凭证有效的账户数
+    pub valid_count: usize,
+    /// 凭证失效或校验失败的账户数
+    pub invalid_count: usize,
+    /// 每个账户的校验结果
+    pub results: Vec<AccountValidationResult>,
+}
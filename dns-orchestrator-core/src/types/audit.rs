@@ -0,0 +1,76 @@
+//! 审计日志相关类型定义
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 审计操作类型
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum AuditOperation {
+    /// 创建 DNS 记录
+    CreateRecord,
+    /// 更新 DNS 记录
+    UpdateRecord,
+    /// 删除 DNS 记录
+    DeleteRecord,
+    /// 创建账户
+    CreateAccount,
+    /// 更新账户
+    UpdateAccount,
+    /// 删除账户
+    DeleteAccount,
+}
+
+/// 审计事件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEvent {
+    /// 发生时间
+    #[serde(with = "crate::utils::datetime")]
+    pub timestamp: DateTime<Utc>,
+    /// 账户 ID
+    pub account_id: String,
+    /// 操作类型
+    pub operation: AuditOperation,
+    /// 操作对象（记录 ID、账户 ID 等）
+    pub target: String,
+    /// 变更前摘要
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    /// 变更后摘要
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+}
+
+impl AuditEvent {
+    /// 创建审计事件（时间戳为当前时间）
+    #[must_use]
+    pub fn new(
+        account_id: impl Into<String>,
+        operation: AuditOperation,
+        target: impl Into<String>,
+    ) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            account_id: account_id.into(),
+            operation,
+            target: target.into(),
+            before: None,
+            after: None,
+        }
+    }
+
+    /// 设置变更前摘要
+    #[must_use]
+    pub fn with_before(mut self, before: impl Into<String>) -> Self {
+        self.before = Some(before.into());
+        self
+    }
+
+    /// 设置变更后摘要
+    #[must_use]
+    pub fn with_after(mut self, after: impl Into<String>) -> Self {
+        self.after = Some(after.into());
+        self
+    }
+}
@@ -4,7 +4,11 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use dns_orchestrator_provider::ProviderType;
+use dns_orchestrator_provider::{DnsRecordType, ProviderType};
+
+fn default_encrypt() -> bool {
+    true
+}
 
 /// 单个账号的导出数据（包含凭证）
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,16 +38,19 @@ pub struct ExportFileHeader {
     pub version: u32,
     /// 是否加密
     pub encrypted: bool,
-    /// 加密时使用的盐值（Base64 编码）
+    /// 加密时使用的盐值（仅旧格式；新文件的盐值打包在 `data` 的自描述密文中）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub salt: Option<String>,
-    /// 加密时使用的 IV/Nonce（Base64 编码）
+    /// 加密时使用的 IV/Nonce（Base64 编码，仅旧格式）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub nonce: Option<String>,
     /// 导出时间
     pub exported_at: String,
     /// 应用版本
     pub app_version: String,
+    /// 文件中的账号数量（旧版本文件没有此字段）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_count: Option<usize>,
 }
 
 /// 完整的导出文件结构
@@ -52,7 +59,7 @@ pub struct ExportFileHeader {
 pub struct ExportFile {
     /// 文件头部
     pub header: ExportFileHeader,
-    /// 账号数据（加密时为 Base64 编码的密文，未加密时为 JSON 数组）
+    /// 账号数据（加密时为 `crypto::encrypt_payload` 生成的自描述密文，未加密时为 JSON 数组）
     pub data: serde_json::Value,
 }
 
@@ -60,9 +67,10 @@ pub struct ExportFile {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ExportAccountsRequest {
-    /// 要导出的账号 ID 列表
+    /// 要导出的账号 ID 列表（只导出这些账号）
     pub account_ids: Vec<String>,
-    /// 是否加密
+    /// 是否加密（默认加密；关闭时凭证以明文写入文件）
+    #[serde(default = "default_encrypt")]
     pub encrypt: bool,
     /// 加密密码（仅当 encrypt=true 时需要）
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -77,6 +85,9 @@ pub struct ExportAccountsResponse {
     pub content: String,
     /// 建议的文件名
     pub suggested_filename: String,
+    /// 安全警告（未加密导出时提示凭证为明文）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
 }
 
 /// 导入请求
@@ -88,6 +99,39 @@ pub struct ImportAccountsRequest {
     /// 解密密码（如果文件加密）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub password: Option<String>,
+    /// 导入前校验凭证，无效的账号以错误状态导入
+    #[serde(default)]
+    pub validate_credentials: bool,
+    /// 与现有账号重名时的处理策略
+    #[serde(default)]
+    pub merge_strategy: ImportMergeStrategy,
+}
+
+/// 导入时与现有账号重名的处理策略
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ImportMergeStrategy {
+    /// 跳过已存在的同名账号
+    SkipExisting,
+    /// 用导入的凭证覆盖同名账号
+    Overwrite,
+    /// 以新名称导入（如 `name (2)`）
+    #[default]
+    Rename,
+}
+
+/// 单个账号在导入时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ImportAccountAction {
+    /// 新建账号
+    Create,
+    /// 跳过
+    Skip,
+    /// 覆盖同名账号
+    Overwrite,
+    /// 以新名称新建账号
+    Rename,
 }
 
 /// 导入预览（用于显示将要导入的账号）
@@ -96,7 +140,7 @@ pub struct ImportAccountsRequest {
 pub struct ImportPreview {
     /// 文件是否加密
     pub encrypted: bool,
-    /// 账号数量
+    /// 账号数量（加密文件未解密时取自文件头）
     pub account_count: usize,
     /// 账号预览列表（仅在未加密或已解密后可用）
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -113,16 +157,26 @@ pub struct ImportPreviewAccount {
     pub provider: ProviderType,
     /// 是否与现有账号名称冲突
     pub has_conflict: bool,
+    /// 按所选策略的处理方式
+    pub action: ImportAccountAction,
+    /// 导入后的账号名称（`Rename` 时为新名称）
+    pub import_name: String,
 }
 
 /// 导入结果
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ImportResult {
-    /// 成功导入的账号数量
+    /// 成功导入的账号数量（不含凭证无效的账号）
     pub success_count: usize,
     /// 失败的账号及原因
     pub failures: Vec<ImportFailure>,
+    /// 凭证校验未通过的账号（已导入，但标记为错误状态）
+    #[serde(default)]
+    pub invalid_credentials: Vec<ImportFailure>,
+    /// 因重名被跳过的账号名称
+    #[serde(default)]
+    pub skipped: Vec<String>,
 }
 
 /// 导入失败项
@@ -134,3 +188,92 @@ pub struct ImportFailure {
     /// 失败原因
     pub reason: String,
 }
+
+// ============ DNS 记录导入导出 ============
+
+/// DNS 记录导出/导入格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecordExportFormat {
+    /// BIND 区域文件
+    Bind,
+    /// JSON（包含 origin 与完整记录数据）
+    Json,
+    /// CSV（`name,type,ttl,value,priority,proxied`）
+    Csv,
+}
+
+/// DNS 记录导出结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordExport {
+    /// 区域名称（origin）
+    pub origin: String,
+    /// 导出的记录数量
+    pub record_count: usize,
+    /// 导出的文本内容
+    pub content: String,
+    /// 建议的文件名
+    pub suggested_filename: String,
+}
+
+/// 导入记录与已有记录（名称 + 类型相同）冲突时的处理策略
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RecordMergeStrategy {
+    /// 跳过已存在同名同类型记录的导入项
+    #[default]
+    SkipExisting,
+    /// 用导入的数据更新已有记录
+    Overwrite,
+}
+
+/// 单条记录的导入结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RecordImportAction {
+    /// 新建记录
+    Created,
+    /// 更新已有记录
+    Updated,
+    /// 已存在，跳过
+    Skipped,
+    /// 导入失败
+    Failed,
+}
+
+/// 单条记录的导入明细
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordImportOutcome {
+    /// 记录名称（相对区域名称）
+    pub name: String,
+    /// 记录类型
+    pub record_type: DnsRecordType,
+    /// 记录值
+    pub value: String,
+    /// 处理结果
+    pub action: RecordImportAction,
+    /// 新建或更新后的记录 ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub record_id: Option<String>,
+    /// 失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// DNS 记录导入结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordImportResult {
+    /// 新建的记录数
+    pub created_count: usize,
+    /// 更新的记录数
+    pub updated_count: usize,
+    /// 跳过的记录数
+    pub skipped_count: usize,
+    /// 失败的记录数
+    pub failed_count: usize,
+    /// 每条导入记录的处理明细（与解析顺序一致）
+    pub outcomes: Vec<RecordImportOutcome>,
+}
@@ -1,13 +1,19 @@
 //! 类型定义模块
 
 mod account;
+mod audit;
 mod domain;
 mod domain_metadata;
 mod export;
 mod response;
+mod template;
 mod toolbox;
 
-pub use account::{Account, AccountStatus, CreateAccountRequest, UpdateAccountRequest};
+pub use account::{
+    Account, AccountStatus, AccountValidationResult, CreateAccountRequest, UpdateAccountRequest,
+    ValidateAccountsResult,
+};
+pub use audit::{AuditEvent, AuditOperation};
 pub use domain::AppDomain;
 pub use domain_metadata::{
     BatchTagFailure, BatchTagRequest, BatchTagResult, DomainMetadata, DomainMetadataKey,
@@ -15,19 +21,26 @@ pub use domain_metadata::{
 };
 pub use export::{
     ExportAccountsRequest, ExportAccountsResponse, ExportFile, ExportFileHeader, ExportedAccount,
-    ImportAccountsRequest, ImportFailure, ImportPreview, ImportPreviewAccount, ImportResult,
+    ImportAccountAction, ImportAccountsRequest, ImportFailure, ImportMergeStrategy, ImportPreview,
+    ImportPreviewAccount, ImportResult, RecordExport, RecordExportFormat, RecordImportAction,
+    RecordImportOutcome, RecordImportResult, RecordMergeStrategy,
+};
+pub use response::{
+    ApiResponse, BatchDeleteFailure, BatchDeleteRequest, BatchDeleteResult, DeleteMatchingResult,
+    RecordDeleteFilter,
 };
-pub use response::{ApiResponse, BatchDeleteFailure, BatchDeleteRequest, BatchDeleteResult};
+pub use template::{ApplyTemplateResult, RecordTemplate, TemplateRecord};
 pub use toolbox::{
-    CertChainItem, DnsLookupRecord, DnsLookupResult, DnsPropagationResult, DnsPropagationServer,
-    DnsPropagationServerResult, DnskeyRecord, DnssecResult, DsRecord, HttpHeader,
-    HttpHeaderCheckRequest, HttpHeaderCheckResult, HttpMethod, IpGeoInfo, IpLookupResult,
-    RrsigRecord, SecurityHeaderAnalysis, SslCertInfo, SslCheckResult, WhoisResult,
+    CaaRecord, CertChainItem, DnsLookupRecord, DnsLookupResult, DnsPropagationResult,
+    DnsPropagationServer, DnsPropagationServerResult, DnskeyRecord, DnssecResult, DsRecord,
+    HttpHeader, HttpHeaderCheckRequest, HttpHeaderCheckResult, HttpMethod, HttpTiming, IpGeoInfo,
+    IpLookupResult, NsecWalkResult, RrsigRecord, SecurityHeaderAnalysis, SslCertInfo,
+    SslCheckResult, WhoisResult,
 };
 
 // Re-export provider 库的公共类型
 pub use dns_orchestrator_provider::{
-    CreateDnsRecordRequest, DnsRecord, DnsRecordType, DomainStatus, PaginatedResponse,
-    PaginationParams, ProviderCredentials, ProviderDomain, ProviderMetadata, ProviderType,
-    RecordQueryParams, UpdateDnsRecordRequest,
+    BatchCreateFailure, BatchCreateResult, CreateDnsRecordRequest, DnsRecord, DnsRecordType,
+    DomainStatus, PaginatedResponse, PaginationParams, ProviderCredentials, ProviderDomain,
+    ProviderMetadata, ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
 };
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use dns_orchestrator_provider::{DnsRecord, DnsRecordType};
+
 /// API 响应包装类型
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiResponse<T> {
@@ -53,3 +55,111 @@ pub struct BatchDeleteFailure {
     /// 失败原因
     pub reason: String,
 }
+
+/// 按条件批量删除记录的过滤条件
+///
+/// 所有已指定的条件同时满足才算匹配。
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordDeleteFilter {
+    /// 记录类型
+    #[serde(default)]
+    pub record_type: Option<DnsRecordType>,
+    /// 记录名称模式（相对名称，`@` 表示根域，`*` 匹配任意字符，不区分大小写）
+    #[serde(default)]
+    pub name_pattern: Option<String>,
+    /// 记录值包含的文本（不区分大小写）
+    #[serde(default)]
+    pub value_contains: Option<String>,
+}
+
+impl RecordDeleteFilter {
+    /// 是否没有任何实际约束（会匹配区域内全部记录）
+    #[must_use]
+    pub fn is_trivial(&self) -> bool {
+        let name_unconstrained = self
+            .name_pattern
+            .as_deref()
+            .is_none_or(|pattern| pattern.trim().chars().all(|c| c == '*'));
+        let value_unconstrained = self
+            .value_contains
+            .as_deref()
+            .is_none_or(|value| value.trim().is_empty());
+        self.record_type.is_none() && name_unconstrained && value_unconstrained
+    }
+
+    /// 判断记录是否匹配过滤条件
+    #[must_use]
+    pub fn matches(&self, record: &DnsRecord) -> bool {
+        if self
+            .record_type
+            .as_ref()
+            .is_some_and(|record_type| *record_type != record.data.record_type())
+        {
+            return false;
+        }
+
+        if let Some(pattern) = self.name_pattern.as_deref() {
+            let pattern = normalize_name(pattern);
+            if !wildcard_match(&pattern, &normalize_name(&record.name)) {
+                return false;
+            }
+        }
+
+        if let Some(value) = self.value_contains.as_deref() {
+            let value = value.trim().to_lowercase();
+            if !record.data.display_value().to_lowercase().contains(&value) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// 按条件批量删除记录的结果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteMatchingResult {
+    /// 已删除记录的摘要（如 `www A 1.2.3.4 TTL=600`）
+    pub deleted: Vec<String>,
+    /// 删除失败的记录
+    pub failures: Vec<BatchDeleteFailure>,
+}
+
+/// 规范化记录名称（空名称视为 `@`，不区分大小写）
+fn normalize_name(name: &str) -> String {
+    let name = name.trim().trim_end_matches('.');
+    if name.is_empty() {
+        "@".to_string()
+    } else {
+        name.to_lowercase()
+    }
+}
+
+/// 通配符匹配，`*` 匹配任意长度（含空）的字符
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // 最近一个 `*` 的位置及其已匹配到的文本位置，用于回溯
+    let mut star: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && pattern[p] != '*' && pattern[p] == text[t] {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
@@ -0,0 +1,215 @@
+//! DNS 记录模板相关类型定义
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use dns_orchestrator_provider::{
+    BatchCreateFailure, CreateDnsRecordRequest, DnsRecord, RecordData,
+};
+
+use crate::error::{CoreError, CoreResult};
+
+/// 模板中的单条记录
+///
+/// `name` 与 `data` 中的字符串字段可以包含 `{变量名}` 占位符，应用模板时替换。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateRecord {
+    /// 记录名称（相对名称，`@` 表示根域）
+    pub name: String,
+    /// TTL（秒）
+    pub ttl: u32,
+    /// 记录数据
+    pub data: RecordData,
+}
+
+/// DNS 记录模板
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordTemplate {
+    /// 模板 ID
+    pub id: String,
+    /// 模板名称
+    pub name: String,
+    /// 模板说明
+    pub description: String,
+    /// 模板需要的变量名
+    pub variables: Vec<String>,
+    /// 模板包含的记录
+    pub records: Vec<TemplateRecord>,
+}
+
+/// 应用模板的结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyTemplateResult {
+    /// 新建的记录
+    pub created: Vec<DnsRecord>,
+    /// 已存在而跳过的记录
+    pub skipped: Vec<CreateDnsRecordRequest>,
+    /// 创建失败的记录
+    pub failures: Vec<BatchCreateFailure>,
+}
+
+impl RecordTemplate {
+    /// 内置模板
+    #[must_use]
+    pub fn builtin() -> Vec<Self> {
+        vec![
+            Self {
+                id: "google-workspace-mx".to_string(),
+                name: "Google Workspace MX".to_string(),
+                description: "Google Workspace 邮件 MX 记录".to_string(),
+                variables: Vec::new(),
+                records: vec![mx("@", 1, "smtp.google.com")],
+            },
+            Self {
+                id: "spf".to_string(),
+                name: "SPF".to_string(),
+                description: "SPF 发件人策略（`include` 为邮件服务商的 SPF 域名）".to_string(),
+                variables: vec!["include".to_string()],
+                records: vec![txt("@", "v=spf1 include:{include} ~all")],
+            },
+            Self {
+                id: "dmarc".to_string(),
+                name: "DMARC".to_string(),
+                description: "DMARC 策略（`policy` 为 none/quarantine/reject）".to_string(),
+                variables: vec!["policy".to_string(), "report_email".to_string()],
+                records: vec![txt(
+                    "_dmarc",
+                    "v=DMARC1; p={policy}; rua=mailto:{report_email}",
+                )],
+            },
+            Self {
+                id: "google-site-verification".to_string(),
+                name: "Google Site Verification".to_string(),
+                description: "Google 域名所有权验证".to_string(),
+                variables: vec!["verification_code".to_string()],
+                records: vec![txt("@", "google-site-verification={verification_code}")],
+            },
+            Self {
+                id: "github-pages".to_string(),
+                name: "GitHub Pages".to_string(),
+                description: "GitHub Pages 根域 A 记录与 www CNAME".to_string(),
+                variables: vec!["github_user".to_string()],
+                records: vec![
+                    a("@", "185.199.108.153"),
+                    a("@", "185.199.109.153"),
+                    a("@", "185.199.110.153"),
+                    a("@", "185.199.111.153"),
+                    TemplateRecord {
+                        name: "www".to_string(),
+                        ttl: DEFAULT_TEMPLATE_TTL,
+                        data: RecordData::CNAME {
+                            target: "{github_user}.github.io".to_string(),
+                        },
+                    },
+                ],
+            },
+        ]
+    }
+
+    /// 按 ID 查找内置模板
+    #[must_use]
+    pub fn find_builtin(id: &str) -> Option<Self> {
+        Self::builtin().into_iter().find(|t| t.id == id)
+    }
+
+    /// 替换变量，展开为创建记录请求
+    ///
+    /// 缺少模板声明的变量时返回 `ValidationError`。
+    pub fn expand(
+        &self,
+        domain_id: &str,
+        vars: &HashMap<String, String>,
+    ) -> CoreResult<Vec<CreateDnsRecordRequest>> {
+        if let Some(missing) = self.variables.iter().find(|v| !vars.contains_key(*v)) {
+            return Err(CoreError::ValidationError(format!(
+                "Template '{}' requires variable '{missing}'",
+                self.id
+            )));
+        }
+
+        self.records
+            .iter()
+            .map(|record| {
+                let mut data = serde_json::to_value(&record.data)
+                    .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+                substitute_value(&mut data, vars);
+                let data = serde_json::from_value(data)
+                    .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+
+                Ok(CreateDnsRecordRequest {
+                    domain_id: domain_id.to_string(),
+                    name: substitute(&record.name, vars),
+                    ttl: record.ttl,
+                    data,
+                    proxied: None,
+                    comment: None,
+                    tags: Vec::new(),
+                    routing_policy: None,
+                })
+            })
+            .collect()
+    }
+}
+
+/// 内置模板记录的默认 TTL
+const DEFAULT_TEMPLATE_TTL: u32 = 3600;
+
+fn a(name: &str, address: &str) -> TemplateRecord {
+    TemplateRecord {
+        name: name.to_string(),
+        ttl: DEFAULT_TEMPLATE_TTL,
+        data: RecordData::A {
+            address: address.to_string(),
+        },
+    }
+}
+
+fn mx(name: &str, priority: u16, exchange: &str) -> TemplateRecord {
+    TemplateRecord {
+        name: name.to_string(),
+        ttl: DEFAULT_TEMPLATE_TTL,
+        data: RecordData::MX {
+            priority,
+            exchange: exchange.to_string(),
+        },
+    }
+}
+
+fn txt(name: &str, text: &str) -> TemplateRecord {
+    TemplateRecord {
+        name: name.to_string(),
+        ttl: DEFAULT_TEMPLATE_TTL,
+        data: RecordData::TXT {
+            text: text.to_string(),
+        },
+    }
+}
+
+/// 替换字符串中的 `{变量名}` 占位符
+fn substitute(input: &str, vars: &HashMap<String, String>) -> String {
+    vars.iter().fold(input.to_string(), |acc, (key, value)| {
+        acc.replace(&format!("{{{key}}}"), value)
+    })
+}
+
+/// 递归替换 JSON 值中所有字符串的占位符
+fn substitute_value(value: &mut serde_json::Value, vars: &HashMap<String, String>) {
+    match value {
+        serde_json::Value::String(s) => *s = substitute(s, vars),
+        serde_json::Value::Array(items) => {
+            for item in items {
+                substitute_value(item, vars);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            for item in map.values_mut() {
+                substitute_value(item, vars);
+            }
+        }
+        _ => {}
+    }
+}
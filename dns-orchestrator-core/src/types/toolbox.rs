@@ -22,6 +22,9 @@ pub struct WhoisResult {
     pub status: Vec<String>,
     /// 原始响应
     pub raw: String,
+    /// 是否已注册（未注册的域名可以注册）
+    #[serde(default)]
+    pub is_registered: bool,
 }
 
 /// DNS 查询记录结果
@@ -38,6 +41,21 @@ pub struct DnsLookupRecord {
     pub ttl: u32,
     /// 优先级（MX/SRV 记录）
     pub priority: Option<u16>,
+    /// CAA 结构化数据（仅 CAA 记录）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caa: Option<CaaRecord>,
+}
+
+/// CAA 记录结构化数据
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaaRecord {
+    /// 标志位（128 表示 critical）
+    pub flags: u8,
+    /// 标签: issue / issuewild / iodef 等
+    pub tag: String,
+    /// 标签值（如 CA 域名或 iodef URL）
+    pub value: String,
 }
 
 /// DNS 查询结果（包含 nameserver 信息）
@@ -48,6 +66,9 @@ pub struct DnsLookupResult {
     pub nameserver: String,
     /// 查询记录列表
     pub records: Vec<DnsLookupRecord>,
+    /// 域名不存在（NXDOMAIN），此时 `records` 为空
+    #[serde(default)]
+    pub nxdomain: bool,
 }
 
 /// IP 地理位置信息
@@ -90,7 +111,7 @@ pub struct IpLookupResult {
     pub query: String,
     /// 是否为域名查询
     pub is_domain: bool,
-    /// IP 地理位置结果列表
+    /// IP 地理位置结果列表（域名查询时每个解析出的地址一项，已去重）
     pub results: Vec<IpGeoInfo>,
 }
 
@@ -114,6 +135,9 @@ pub struct SslCertInfo {
     pub is_expired: bool,
     /// 是否有效
     pub is_valid: bool,
+    /// 证书是否覆盖查询的域名（按 SAN 匹配，支持通配符）
+    #[serde(default)]
+    pub hostname_matches: bool,
     /// 主题备用名称
     pub san: Vec<String>,
     /// 序列号
@@ -150,6 +174,20 @@ pub struct CertChainItem {
     pub issuer: String,
     /// 是否为 CA 证书
     pub is_ca: bool,
+    /// 是否为自签名证书（主题与颁发者相同，且签名可由自身公钥验证）
+    #[serde(default)]
+    pub self_signed: bool,
+    /// 是否带有名称约束（Name Constraints）扩展
+    #[serde(default)]
+    pub name_constrained: bool,
+    /// 密钥用途（Key Usage），如 `digitalSignature`、`keyCertSign`
+    #[serde(default)]
+    pub key_usage: Vec<String>,
+    /// 扩展密钥用途（Extended Key Usage），如 `serverAuth`；未知用途以 OID 表示
+    #[serde(default)]
+    pub extended_key_usage: Vec<String>,
+    /// 证书原文（PEM 格式）
+    pub pem: String,
 }
 
 /// HTTP 请求方法
@@ -184,9 +222,9 @@ pub struct HttpHeaderCheckRequest {
     pub method: HttpMethod,
     /// 自定义请求头列表
     pub custom_headers: Vec<HttpHeader>,
-    /// 请求体（仅 POST/PUT/PATCH）
+    /// 请求体（POST/PUT/PATCH 按原样发送，其他方法仅在非空时发送；上限 1 MiB）
     pub body: Option<String>,
-    /// 请求体内容类型
+    /// 请求体内容类型（自定义请求头中已有 Content-Type 时忽略）
     pub content_type: Option<String>,
 }
 
@@ -228,6 +266,23 @@ pub struct HttpHeaderCheckResult {
     pub raw_request: String,
     /// 原始响应报文
     pub raw_response: String,
+    /// 各阶段耗时
+    #[serde(default)]
+    pub timing: HttpTiming,
+}
+
+/// HTTP 请求各阶段耗时（毫秒），无法测得的阶段为 `None`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpTiming {
+    /// DNS 解析（跟随重定向时为各次解析之和）
+    pub dns_ms: Option<u64>,
+    /// TCP 连接
+    pub tcp_connect_ms: Option<u64>,
+    /// TLS 握手
+    pub tls_handshake_ms: Option<u64>,
+    /// 首字节时间（发出请求到收到响应头，含以上各阶段）
+    pub ttfb_ms: Option<u64>,
 }
 
 /// DNS 传播检查服务器信息
@@ -276,6 +331,9 @@ pub struct DnsPropagationResult {
     pub consistency_percentage: f32,
     /// 唯一值列表（用于检测一致性）
     pub unique_values: Vec<String>,
+    /// 按应答内容分组的服务器：`(应答记录集, 服务器名称列表)`，服务器多的分组在前
+    #[serde(default)]
+    pub grouped_answers: Vec<(String, Vec<String>)>,
 }
 
 /// DNSSEC DNSKEY 记录
@@ -365,3 +423,27 @@ pub struct DnssecResult {
     /// 错误信息（查询失败时）
     pub error: Option<String>,
 }
+
+/// NSEC 区域遍历结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NsecWalkResult {
+    /// 查询的域名（区域顶点）
+    pub domain: String,
+    /// 否定应答类型: "NSEC" | "NSEC3" | "none"
+    pub denial_type: String,
+    /// 通过 NSEC 链发现的名称（按链顺序）
+    pub discovered_names: Vec<String>,
+    /// 是否完整遍历（链回到区域顶点）
+    pub complete: bool,
+    /// 是否因达到上限而中止
+    pub truncated: bool,
+    /// NSEC3 是否启用 opt-out（仅 NSEC3 区域；否定应答中没有 NSEC3 记录时为空）
+    pub nsec3_opt_out: Option<bool>,
+    /// NSEC3 迭代次数（仅 NSEC3 区域）
+    pub nsec3_iterations: Option<u16>,
+    /// 使用的 DNS 服务器
+    pub nameserver: String,
+    /// 查询耗时（毫秒）
+    pub response_time_ms: u64,
+}
@@ -0,0 +1,49 @@
+//! 国际化域名（IDN）记录名转换
+//!
+//! Provider API 只接受 ASCII 记录名，Unicode 标签需要先转换为 punycode（`xn--` 前缀）。
+//! 按标签逐个转换，`@`、`*`、`_dmarc` 等纯 ASCII 标签原样保留。
+
+use crate::error::{CoreError, CoreResult};
+
+/// 将记录名中的 Unicode 标签转换为 punycode
+///
+/// 纯 ASCII 名称直接返回；标签无法转换时返回 `ValidationError`。
+pub fn record_name_to_ascii(name: &str) -> CoreResult<String> {
+    if name.is_ascii() {
+        return Ok(name.to_string());
+    }
+
+    name.split('.')
+        .map(|label| {
+            if label.is_ascii() {
+                return Ok(label.to_string());
+            }
+            idna::domain_to_ascii(label).map_err(|_| {
+                CoreError::ValidationError(format!("Invalid internationalized record name: {name}"))
+            })
+        })
+        .collect::<CoreResult<Vec<_>>>()
+        .map(|labels| labels.join("."))
+}
+
+/// 将记录名中的 punycode 标签解码为 Unicode（用于显示）
+///
+/// 无法解码的标签原样保留。
+#[must_use]
+pub fn record_name_to_unicode(name: &str) -> String {
+    name.split('.')
+        .map(|label| {
+            if !label
+                .get(..4)
+                .is_some_and(|p| p.eq_ignore_ascii_case("xn--"))
+            {
+                return label.to_string();
+            }
+            match idna::domain_to_unicode(label) {
+                (decoded, Ok(())) => decoded,
+                (_, Err(_)) => label.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
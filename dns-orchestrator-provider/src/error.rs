@@ -41,12 +41,22 @@ pub enum ProviderError {
         record_type: String,
     },
 
+    /// 不支持的操作（如未实现 DNSSEC 管理的 Provider）
+    UnsupportedOperation { provider: String, operation: String },
+
     /// 配额超限
     QuotaExceeded {
         provider: String,
         raw_message: Option<String>,
     },
 
+    /// 请求频率超限（`retry_after_secs` 为服务端建议的等待秒数）
+    RateLimited {
+        provider: String,
+        retry_after_secs: Option<u64>,
+        raw_message: Option<String>,
+    },
+
     /// 域名不存在
     DomainNotFound {
         provider: String,
@@ -67,12 +77,35 @@ pub enum ProviderError {
         raw_message: Option<String>,
     },
 
+    /// DNS 服务器内部错误（SERVFAIL）
+    ServerFailure {
+        provider: String,
+        raw_message: Option<String>,
+    },
+
+    /// DNS 服务器拒绝请求（REFUSED）
+    Refused {
+        provider: String,
+        raw_message: Option<String>,
+    },
+
     /// 响应解析失败
     ParseError { provider: String, detail: String },
 
     /// 序列化/反序列化失败
     SerializationError { provider: String, detail: String },
 
+    /// 操作已被调用方取消（`CancellationToken`）
+    Cancelled { provider: String, operation: String },
+
+    /// 未映射的 API 错误，保留 HTTP 状态码与 Provider 原始错误码/消息
+    ApiError {
+        provider: String,
+        status: u16,
+        provider_code: Option<String>,
+        message: String,
+    },
+
     /// 未知错误（fallback）
     Unknown {
         provider: String,
@@ -124,9 +157,26 @@ impl std::fmt::Display for ProviderError {
             } => {
                 write!(f, "[{provider}] Unsupported record type: {record_type}")
             }
+            Self::UnsupportedOperation {
+                provider,
+                operation,
+            } => {
+                write!(f, "[{provider}] Unsupported operation: {operation}")
+            }
             Self::QuotaExceeded { provider, .. } => {
                 write!(f, "[{provider}] Quota exceeded")
             }
+            Self::RateLimited {
+                provider,
+                retry_after_secs,
+                ..
+            } => {
+                if let Some(secs) = retry_after_secs {
+                    write!(f, "[{provider}] Rate limited, retry after {secs}s")
+                } else {
+                    write!(f, "[{provider}] Rate limited")
+                }
+            }
             Self::DomainNotFound {
                 provider,
                 domain,
@@ -159,12 +209,53 @@ impl std::fmt::Display for ProviderError {
                     write!(f, "[{provider}] Permission denied")
                 }
             }
+            Self::ServerFailure {
+                provider,
+                raw_message,
+            } => {
+                if let Some(msg) = raw_message {
+                    write!(f, "[{provider}] Server failure: {msg}")
+                } else {
+                    write!(f, "[{provider}] Server failure")
+                }
+            }
+            Self::Refused {
+                provider,
+                raw_message,
+            } => {
+                if let Some(msg) = raw_message {
+                    write!(f, "[{provider}] Request refused: {msg}")
+                } else {
+                    write!(f, "[{provider}] Request refused")
+                }
+            }
             Self::ParseError { provider, detail } => {
                 write!(f, "[{provider}] Parse error: {detail}")
             }
             Self::SerializationError { provider, detail } => {
                 write!(f, "[{provider}] Serialization error: {detail}")
             }
+            Self::Cancelled {
+                provider,
+                operation,
+            } => {
+                write!(f, "[{provider}] Operation cancelled: {operation}")
+            }
+            Self::ApiError {
+                provider,
+                status,
+                provider_code,
+                message,
+            } => {
+                if let Some(code) = provider_code {
+                    write!(
+                        f,
+                        "[{provider}] API error (HTTP {status}, {code}): {message}"
+                    )
+                } else {
+                    write!(f, "[{provider}] API error (HTTP {status}): {message}")
+                }
+            }
             Self::Unknown {
                 provider,
                 raw_message,
@@ -10,10 +10,22 @@ use crate::types::{ProviderCredentials, ProviderMetadata};
 use crate::providers::AliyunProvider;
 #[cfg(feature = "cloudflare")]
 use crate::providers::CloudflareProvider;
+#[cfg(feature = "desec")]
+use crate::providers::DesecProvider;
 #[cfg(feature = "dnspod")]
 use crate::providers::DnspodProvider;
+#[cfg(feature = "gcloud")]
+use crate::providers::GoogleCloudProvider;
+#[cfg(feature = "hetzner")]
+use crate::providers::HetznerProvider;
 #[cfg(feature = "huaweicloud")]
 use crate::providers::HuaweicloudProvider;
+#[cfg(feature = "namecheap")]
+use crate::providers::NamecheapProvider;
+#[cfg(feature = "rfc2136")]
+use crate::providers::Rfc2136Provider;
+#[cfg(feature = "route53")]
+use crate::providers::Route53Provider;
 
 /// 工厂函数 - 根据凭证类型创建 Provider 实例
 pub fn create_provider(credentials: ProviderCredentials) -> Result<Arc<dyn DnsProvider>> {
@@ -22,6 +34,10 @@ pub fn create_provider(credentials: ProviderCredentials) -> Result<Arc<dyn DnsPr
         ProviderCredentials::Cloudflare { api_token } => {
             Ok(Arc::new(CloudflareProvider::new(api_token)))
         }
+        #[cfg(feature = "cloudflare")]
+        ProviderCredentials::CloudflareKey { email, api_key } => Ok(Arc::new(
+            CloudflareProvider::with_global_key(email, api_key),
+        )),
         #[cfg(feature = "aliyun")]
         ProviderCredentials::Aliyun {
             access_key_id,
@@ -43,6 +59,51 @@ pub fn create_provider(credentials: ProviderCredentials) -> Result<Arc<dyn DnsPr
             access_key_id,
             secret_access_key,
         ))),
+        #[cfg(feature = "route53")]
+        ProviderCredentials::Route53 {
+            access_key_id,
+            secret_access_key,
+            region,
+        } => Ok(Arc::new(Route53Provider::new(
+            access_key_id,
+            secret_access_key,
+            region,
+        ))),
+        #[cfg(feature = "gcloud")]
+        ProviderCredentials::GoogleCloud {
+            service_account_json,
+            project_id,
+        } => Ok(Arc::new(GoogleCloudProvider::new(
+            service_account_json,
+            project_id,
+        ))),
+        #[cfg(feature = "namecheap")]
+        ProviderCredentials::Namecheap {
+            api_user,
+            api_key,
+            username,
+            client_ip,
+        } => Ok(Arc::new(NamecheapProvider::new(
+            api_user, api_key, username, client_ip,
+        ))),
+        #[cfg(feature = "hetzner")]
+        ProviderCredentials::Hetzner { api_token } => Ok(Arc::new(HetznerProvider::new(api_token))),
+        #[cfg(feature = "rfc2136")]
+        ProviderCredentials::Rfc2136 {
+            server,
+            zone,
+            tsig_key_name,
+            tsig_algorithm,
+            tsig_secret,
+        } => Ok(Arc::new(Rfc2136Provider::new(
+            server,
+            zone,
+            tsig_key_name,
+            tsig_algorithm,
+            tsig_secret,
+        ))),
+        #[cfg(feature = "desec")]
+        ProviderCredentials::DeSEC { token } => Ok(Arc::new(DesecProvider::new(token))),
     }
 }
 
@@ -57,5 +118,17 @@ pub fn get_all_provider_metadata() -> Vec<ProviderMetadata> {
         DnspodProvider::metadata(),
         #[cfg(feature = "huaweicloud")]
         HuaweicloudProvider::metadata(),
+        #[cfg(feature = "route53")]
+        Route53Provider::metadata(),
+        #[cfg(feature = "gcloud")]
+        GoogleCloudProvider::metadata(),
+        #[cfg(feature = "namecheap")]
+        NamecheapProvider::metadata(),
+        #[cfg(feature = "hetzner")]
+        HetznerProvider::metadata(),
+        #[cfg(feature = "rfc2136")]
+        Rfc2136Provider::metadata(),
+        #[cfg(feature = "desec")]
+        DesecProvider::metadata(),
     ]
 }
@@ -8,17 +8,85 @@
 //! - **统一通用的 HTTP 处理流程** - 发送请求、日志记录、读取响应
 //! - **灵活的响应解析** - 提供工具函数，但不限制解析方式
 
+use futures::future::{self, Either};
 use reqwest::RequestBuilder;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
 use serde::de::DeserializeOwned;
+use std::future::Future;
+use std::pin::pin;
 use std::time::Duration;
+use tokio_util::sync::CancellationToken;
 
 use crate::error::ProviderError;
 
+/// 限流时允许自动等待的最长时间，超过则直接返回 `RateLimited`
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(30);
+
+/// HTTP 代理配置
+///
+/// 支持 `http://`、`https://` 与 `socks5://` 代理地址，构造时即校验地址格式。
+#[derive(Debug, Clone)]
+pub struct ProxyConfig {
+    url: String,
+    no_proxy: Vec<String>,
+    proxy: reqwest::Proxy,
+}
+
+impl ProxyConfig {
+    /// 创建代理配置，地址无效时返回 `InvalidParameter`
+    pub fn new(url: impl Into<String>) -> Result<Self, ProviderError> {
+        let url = url.into();
+        let proxy = Self::build_proxy(&url, &[])?;
+        Ok(Self {
+            url,
+            no_proxy: Vec::new(),
+            proxy,
+        })
+    }
+
+    /// 设置绕过代理的主机列表（`NO_PROXY` 语法，如 `example.com`、`.internal`、`10.0.0.0/8`）
+    pub fn with_no_proxy(mut self, hosts: Vec<String>) -> Result<Self, ProviderError> {
+        self.proxy = Self::build_proxy(&self.url, &hosts)?;
+        self.no_proxy = hosts;
+        Ok(self)
+    }
+
+    /// 代理地址
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// 绕过代理的主机列表
+    pub fn no_proxy(&self) -> &[String] {
+        &self.no_proxy
+    }
+
+    pub(crate) fn to_reqwest(&self) -> reqwest::Proxy {
+        self.proxy.clone()
+    }
+
+    fn build_proxy(url: &str, no_proxy: &[String]) -> Result<reqwest::Proxy, ProviderError> {
+        let proxy = reqwest::Proxy::all(url).map_err(|e| ProviderError::InvalidParameter {
+            provider: "proxy".to_string(),
+            param: "url".to_string(),
+            detail: format!("无效的代理地址 '{url}': {e}"),
+        })?;
+        Ok(proxy.no_proxy(reqwest::NoProxy::from_string(&no_proxy.join(","))))
+    }
+}
+
+/// 包含响应头的 HTTP 响应
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
 /// HTTP 工具函数集
 pub struct HttpUtils;
 
 impl HttpUtils {
-    /// 执行 HTTP 请求并返回响应文本
+    /// 执行 HTTP 请求并返回包含响应头的完整响应
     ///
     /// 统一处理：发送请求、日志记录、错误处理
     ///
@@ -29,14 +97,14 @@ impl HttpUtils {
     /// * `url_or_action` - URL 或 Action 名称（用于日志）
     ///
     /// # Returns
-    /// * `Ok((status_code, response_text))` - 成功时返回状态码和响应文本
+    /// * `Ok(HttpResponse)` - 成功时返回状态码、响应头和响应文本
     /// * `Err(ProviderError::NetworkError)` - 网络错误
-    pub async fn execute_request(
+    pub async fn execute_request_full(
         request_builder: RequestBuilder,
         provider_name: &str,
         method_name: &str,
         url_or_action: &str,
-    ) -> Result<(u16, String), ProviderError> {
+    ) -> Result<HttpResponse, ProviderError> {
         log::debug!("[{}] {} {}", provider_name, method_name, url_or_action);
 
         // 发送请求
@@ -50,6 +118,7 @@ impl HttpUtils {
 
         let status_code = response.status().as_u16();
         log::debug!("[{}] Response Status: {}", provider_name, status_code);
+        let headers = response.headers().clone();
 
         // 读取响应体
         let response_text = response
@@ -62,7 +131,11 @@ impl HttpUtils {
 
         log::debug!("[{}] Response Body: {}", provider_name, response_text);
 
-        Ok((status_code, response_text))
+        Ok(HttpResponse {
+            status: status_code,
+            headers,
+            body: response_text,
+        })
     }
 
     /// 解析 JSON 响应
@@ -91,6 +164,26 @@ impl HttpUtils {
         })
     }
 
+    /// 解析 XML 响应
+    ///
+    /// # Arguments
+    /// * `response_text` - XML 文本
+    /// * `provider_name` - Provider 名称（用于错误消息）
+    #[cfg(any(feature = "route53", feature = "namecheap"))]
+    pub fn parse_xml<T>(response_text: &str, provider_name: &str) -> Result<T, ProviderError>
+    where
+        T: DeserializeOwned,
+    {
+        quick_xml::de::from_str(response_text).map_err(|e| {
+            log::error!("[{}] XML 解析失败: {}", provider_name, e);
+            log::error!("[{}] 原始响应: {}", provider_name, response_text);
+            ProviderError::ParseError {
+                provider: provider_name.to_string(),
+                detail: e.to_string(),
+            }
+        })
+    }
+
     /// 组合：执行请求并解析 JSON
     ///
     /// 最常用的场景：发送请求 -> 获取响应 -> 解析 JSON
@@ -103,10 +196,10 @@ impl HttpUtils {
     where
         T: DeserializeOwned,
     {
-        let (_status, text) =
-            Self::execute_request(request_builder, provider_name, method_name, url_or_action)
+        let response =
+            Self::execute_request_full(request_builder, provider_name, method_name, url_or_action)
                 .await?;
-        Self::parse_json(&text, provider_name)
+        Self::parse_json(&response.body, provider_name)
     }
 
     /// 执行 HTTP 请求并返回响应文本（带重试）
@@ -125,8 +218,10 @@ impl HttpUtils {
     /// * `Err(ProviderError)` - 所有重试都失败后返回最后一个错误
     ///
     /// # 重试策略
-    /// - 只重试网络错误（`ProviderError::NetworkError`）
+    /// - 只重试网络错误（`ProviderError::NetworkError`）和 429 限流响应
     /// - 指数退避：100ms, 200ms, 400ms, 800ms, ... (最大 10 秒)
+    /// - 429 响应优先按 `Retry-After` / `X-RateLimit-Reset` 等待（超过 30 秒则不再重试）
+    /// - 429 重试耗尽后返回 `ProviderError::RateLimited`
     /// - 业务错误（认证失败、记录不存在等）不会重试
     pub async fn execute_request_with_retry(
         request_builder: RequestBuilder,
@@ -135,15 +230,37 @@ impl HttpUtils {
         url_or_action: &str,
         max_retries: u32,
     ) -> Result<(u16, String), ProviderError> {
+        let response = Self::execute_request_full_with_retry(
+            request_builder,
+            provider_name,
+            method_name,
+            url_or_action,
+            max_retries,
+        )
+        .await?;
+        Ok((response.status, response.body))
+    }
+
+    /// 执行 HTTP 请求并返回包含响应头的完整响应（带重试）
+    ///
+    /// 重试策略同 [`Self::execute_request_with_retry`]
+    pub async fn execute_request_full_with_retry(
+        request_builder: RequestBuilder,
+        provider_name: &str,
+        method_name: &str,
+        url_or_action: &str,
+        max_retries: u32,
+    ) -> Result<HttpResponse, ProviderError> {
         if max_retries == 0 {
             // 不重试，直接执行
-            return Self::execute_request(
+            return Self::execute_request_full(
                 request_builder,
                 provider_name,
                 method_name,
                 url_or_action,
             )
-            .await;
+            .await
+            .and_then(|resp| reject_rate_limited(resp, provider_name));
         }
 
         let mut last_error = None;
@@ -155,18 +272,35 @@ impl HttpUtils {
                 None => {
                     // 无法克隆（通常是 body stream 导致），回退到不重试
                     log::warn!("[{}] 无法克隆请求，禁用重试", provider_name);
-                    return Self::execute_request(
+                    return Self::execute_request_full(
                         request_builder,
                         provider_name,
                         method_name,
                         url_or_action,
                     )
-                    .await;
+                    .await
+                    .and_then(|resp| reject_rate_limited(resp, provider_name));
                 }
             };
 
-            match Self::execute_request(req, provider_name, method_name, url_or_action).await {
-                Ok(resp) => return Ok(resp),
+            match Self::execute_request_full(req, provider_name, method_name, url_or_action).await {
+                Ok(resp) if resp.status == 429 && attempt < max_retries => {
+                    let delay =
+                        rate_limit_delay(&resp.headers).unwrap_or_else(|| backoff_delay(attempt));
+                    if delay > MAX_RETRY_AFTER {
+                        return reject_rate_limited(resp, provider_name);
+                    }
+                    log::warn!(
+                        "[{}] 请求被限流（尝试 {}/{}），{:.1}秒后重试",
+                        provider_name,
+                        attempt + 1,
+                        max_retries,
+                        delay.as_secs_f32()
+                    );
+                    tokio::time::sleep(delay).await;
+                    continue;
+                }
+                Ok(resp) => return reject_rate_limited(resp, provider_name),
                 Err(e) if attempt < max_retries && is_retryable(&e) => {
                     let delay = backoff_delay(attempt);
                     log::warn!(
@@ -187,6 +321,70 @@ impl HttpUtils {
 
         Err(last_error.unwrap())
     }
+
+    /// 执行请求，取消令牌触发时立即中止并返回 `Cancelled`
+    ///
+    /// 令牌已取消时不会发起请求；中止时丢弃进行中的请求 future，连接由 reqwest 关闭。
+    pub async fn cancellable<T>(
+        provider_name: &str,
+        operation: &str,
+        cancel: &CancellationToken,
+        request: impl Future<Output = Result<T, ProviderError>>,
+    ) -> Result<T, ProviderError> {
+        let cancelled = || ProviderError::Cancelled {
+            provider: provider_name.to_string(),
+            operation: operation.to_string(),
+        };
+        if cancel.is_cancelled() {
+            return Err(cancelled());
+        }
+
+        match future::select(pin!(request), pin!(cancel.cancelled())).await {
+            Either::Left((result, _)) => result,
+            Either::Right(((), _)) => {
+                log::debug!("[{provider_name}] {operation} 已取消");
+                Err(cancelled())
+            }
+        }
+    }
+
+    /// 遇到 `RateLimited` 时按建议的等待时间重试整个操作
+    ///
+    /// 用于在响应体中返回限流错误的 API（如 DNSPod 的 `RequestLimitExceeded`），
+    /// 未给出等待时间时使用指数退避，等待时间超过 30 秒则直接返回错误。
+    pub async fn retry_on_rate_limit<T, F, Fut>(
+        provider_name: &str,
+        max_retries: u32,
+        mut operation: F,
+    ) -> Result<T, ProviderError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, ProviderError>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match operation().await {
+                Err(ProviderError::RateLimited {
+                    retry_after_secs, ..
+                }) if attempt < max_retries
+                    && retry_after_secs.is_none_or(|secs| secs <= MAX_RETRY_AFTER.as_secs()) =>
+                {
+                    let delay = retry_after_secs
+                        .map_or_else(|| backoff_delay(attempt), Duration::from_secs);
+                    log::warn!(
+                        "[{}] 请求被限流（尝试 {}/{}），{:.1}秒后重试",
+                        provider_name,
+                        attempt + 1,
+                        max_retries,
+                        delay.as_secs_f32()
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
 }
 
 /// 判断错误是否可重试
@@ -205,3 +403,55 @@ fn backoff_delay(attempt: u32) -> Duration {
     let delay_ms = delay_ms.min(10_000); // 最大 10 秒
     Duration::from_millis(delay_ms)
 }
+
+/// 从限流响应头计算等待时间
+///
+/// 优先使用 `Retry-After`（秒数或 HTTP 日期），其次 `X-RateLimit-Reset`
+/// （较大的值视为 Unix 时间戳，否则视为剩余秒数）
+fn rate_limit_delay(headers: &HeaderMap) -> Option<Duration> {
+    retry_after(headers).or_else(|| rate_limit_reset(headers))
+}
+
+/// 解析 `Retry-After` 响应头（秒数或 HTTP 日期）
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let secs = (date.timestamp() - chrono::Utc::now().timestamp()).max(0);
+    Some(Duration::from_secs(secs as u64))
+}
+
+/// 超过该值的 `X-RateLimit-Reset` 视为 Unix 时间戳
+const RESET_TIMESTAMP_THRESHOLD: u64 = 1_000_000_000;
+
+/// 解析 `X-RateLimit-Reset` 响应头
+fn rate_limit_reset(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get("x-ratelimit-reset")?.to_str().ok()?.trim();
+    let reset = value
+        .parse::<f64>()
+        .ok()
+        .filter(|v| v.is_finite() && *v >= 0.0)?;
+    let reset = reset.ceil() as u64;
+    if reset < RESET_TIMESTAMP_THRESHOLD {
+        return Some(Duration::from_secs(reset));
+    }
+    let now = chrono::Utc::now().timestamp().max(0) as u64;
+    Some(Duration::from_secs(reset.saturating_sub(now)))
+}
+
+/// 将 429 响应转换为 `RateLimited` 错误，其余响应原样返回
+fn reject_rate_limited(
+    response: HttpResponse,
+    provider_name: &str,
+) -> Result<HttpResponse, ProviderError> {
+    if response.status != 429 {
+        return Ok(response);
+    }
+    Err(ProviderError::RateLimited {
+        provider: provider_name.to_string(),
+        retry_after_secs: rate_limit_delay(&response.headers).map(|d| d.as_secs()),
+        raw_message: (!response.body.is_empty()).then_some(response.body),
+    })
+}
@@ -1,7 +1,8 @@
 //! DNS Provider abstraction library for multiple cloud platforms
 //!
 //! This library provides a unified interface for managing DNS records across
-//! different cloud providers including Cloudflare, Aliyun, DNSPod, and Huaweicloud.
+//! different cloud providers including Cloudflare, Aliyun, DNSPod, Huaweicloud, Route 53, Google Cloud DNS, Namecheap, Hetzner,
+//! deSEC, and self-hosted servers via RFC 2136 dynamic updates.
 //!
 //! # Features
 //!
@@ -9,6 +10,12 @@
 //! - `aliyun` - Enable Aliyun DNS provider
 //! - `dnspod` - Enable Tencent Cloud DNSPod provider
 //! - `huaweicloud` - Enable Huawei Cloud DNS provider
+//! - `route53` - Enable Amazon Route 53 provider
+//! - `gcloud` - Enable Google Cloud DNS provider
+//! - `namecheap` - Enable Namecheap provider
+//! - `hetzner` - Enable Hetzner DNS provider
+//! - `rfc2136` - Enable RFC 2136 dynamic update provider (BIND, Knot, etc.)
+//! - `desec` - Enable deSEC provider
 //! - `all-providers` - Enable all providers
 //! - `native-tls` - Use native TLS backend (default)
 //! - `rustls` - Use rustls TLS backend (recommended for Android)
@@ -39,29 +46,45 @@ mod error;
 mod factory;
 mod http_client;
 mod providers;
+mod sync;
 mod traits;
 mod types;
 mod utils;
+mod zonefile;
 
 // Re-export error types
 pub use error::{ProviderError, Result};
 
+// Re-export HTTP proxy configuration
+pub use http_client::ProxyConfig;
+
 // Re-export factory functions
 pub use factory::{create_provider, get_all_provider_metadata};
 
 // Re-export core trait only (internal traits are not exported)
 pub use traits::DnsProvider;
 
+pub use tokio_util::sync::CancellationToken;
+
 // Re-export types
 pub use types::{
     BatchCreateFailure, BatchCreateResult, BatchDeleteFailure, BatchDeleteResult,
     BatchUpdateFailure, BatchUpdateItem, BatchUpdateResult, CreateDnsRecordRequest,
-    CredentialValidationError, DnsRecord, DnsRecordType, DomainStatus, FieldType,
-    PaginatedResponse, PaginationParams, ProviderCredentialField, ProviderCredentials,
-    ProviderDomain, ProviderFeatures, ProviderLimits, ProviderMetadata, ProviderType, RecordData,
-    RecordQueryParams, UpdateDnsRecordRequest,
+    CredentialValidationError, DnsRecord, DnsRecordType, DnssecState, DnssecStatus, DomainStatus,
+    FieldType, PaginatedResponse, PaginationParams, ProviderCredentialField, ProviderCredentials,
+    ProviderDomain, ProviderFeatures, ProviderHealth, ProviderLimits, ProviderMetadata,
+    ProviderType, RecordChange, RecordChangeAction, RecordData, RecordQueryParams, RoutingPolicy,
+    SyncPlan, SyncResult, UpdateDnsRecordRequest,
 };
 
+// Re-export dry-run wrapper
+pub use providers::{DryRunProvider, PlannedChange};
+
+// Re-export zone file / CSV import and export
+pub use providers::common::canonical_record_name;
+pub use providers::{records_from_csv, records_to_csv};
+pub use zonefile::{parse_zone_file, records_to_zone_file};
+
 // Re-export utils module
 pub use utils::datetime;
 
@@ -77,3 +100,21 @@ pub use providers::DnspodProvider;
 
 #[cfg(feature = "huaweicloud")]
 pub use providers::HuaweicloudProvider;
+
+#[cfg(feature = "route53")]
+pub use providers::Route53Provider;
+
+#[cfg(feature = "gcloud")]
+pub use providers::GoogleCloudProvider;
+
+#[cfg(feature = "namecheap")]
+pub use providers::NamecheapProvider;
+
+#[cfg(feature = "hetzner")]
+pub use providers::HetznerProvider;
+
+#[cfg(feature = "rfc2136")]
+pub use providers::Rfc2136Provider;
+
+#[cfg(feature = "desec")]
+pub use providers::DesecProvider;
@@ -8,7 +8,7 @@ use crate::http_client::HttpUtils;
 use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
 
 use super::{
-    ALIYUN_DNS_HOST, ALIYUN_DNS_VERSION, AliyunProvider, AliyunResponse, EMPTY_BODY_SHA256,
+    ALIYUN_DNS_VERSION, AliyunProvider, AliyunResponse, EMPTY_BODY_SHA256,
     serialize_to_query_string,
 };
 
@@ -31,16 +31,16 @@ impl AliyunProvider {
 
         // 3. 构造 URL (参数在 query string 中)
         let url = if query_string.is_empty() {
-            format!("https://{ALIYUN_DNS_HOST}/")
+            format!("https://{}/", self.host)
         } else {
-            format!("https://{ALIYUN_DNS_HOST}/?{query_string}")
+            format!("https://{}/?{query_string}", self.host)
         };
 
         // 4. 发送请求 (body 为空，使用 HttpUtils)
         let request = self
             .client
             .post(&url)
-            .header("Host", ALIYUN_DNS_HOST)
+            .header("Host", &self.host)
             .header("x-acs-action", action)
             .header("x-acs-version", ALIYUN_DNS_VERSION)
             .header("x-acs-date", &timestamp)
@@ -48,7 +48,7 @@ impl AliyunProvider {
             .header("x-acs-content-sha256", EMPTY_BODY_SHA256)
             .header("Authorization", authorization);
 
-        let (_status, response_text) = HttpUtils::execute_request_with_retry(
+        let (status, response_text) = HttpUtils::execute_request_with_retry(
             request,
             self.provider_name(),
             "POST",
@@ -62,7 +62,10 @@ impl AliyunProvider {
             && let (Some(code), Some(message)) = (error_response.code, error_response.message)
         {
             log::error!("API 错误: {code} - {message}");
-            return Err(self.map_error(RawApiError::with_code(&code, &message), ctx));
+            return Err(self.map_error(
+                RawApiError::with_code(&code, &message).with_status(status),
+                ctx,
+            ));
         }
 
         // 6. 解析成功响应
@@ -8,7 +8,10 @@ mod types;
 
 use reqwest::Client;
 
-use crate::providers::common::create_http_client;
+use crate::http_client::ProxyConfig;
+use crate::providers::common::{create_http_client, normalize_endpoint_host};
+use crate::traits::DEFAULT_BATCH_CONCURRENCY;
+use crate::types::DnsRecordType;
 
 pub(crate) use types::{
     AddDomainRecordResponse, AliyunResponse, DeleteDomainRecordResponse,
@@ -16,6 +19,7 @@ pub(crate) use types::{
     UpdateDomainRecordResponse, serialize_to_query_string,
 };
 
+/// 默认 API 端点（华东 1 杭州）
 pub(crate) const ALIYUN_DNS_HOST: &str = "alidns.cn-hangzhou.aliyuncs.com";
 pub(crate) const ALIYUN_DNS_VERSION: &str = "2015-01-09";
 /// 空 body 的 SHA256 hash (固定值)
@@ -23,20 +27,29 @@ pub(crate) const EMPTY_BODY_SHA256: &str =
     "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
 /// 阿里云 API 单页最大记录数
 pub(crate) const MAX_PAGE_SIZE: u32 = 100;
+/// 无原生支持的记录类型
+pub(crate) const UNSUPPORTED_RECORD_TYPES: &[DnsRecordType] =
+    &[DnsRecordType::Tlsa, DnsRecordType::Sshfp, DnsRecordType::Ds];
 
 /// 阿里云 DNS Provider
 pub struct AliyunProvider {
     pub(crate) client: Client,
     pub(crate) access_key_id: String,
     pub(crate) access_key_secret: String,
+    pub(crate) host: String,
     pub(crate) max_retries: u32,
+    pub(crate) batch_concurrency: usize,
 }
 
 /// 阿里云 Provider Builder
 pub struct AliyunProviderBuilder {
     access_key_id: String,
     access_key_secret: String,
+    endpoint: Option<String>,
+    region: Option<String>,
     max_retries: u32,
+    proxy: Option<ProxyConfig>,
+    batch_concurrency: usize,
 }
 
 impl AliyunProviderBuilder {
@@ -44,21 +57,60 @@ impl AliyunProviderBuilder {
         Self {
             access_key_id,
             access_key_secret,
+            endpoint: None,
+            region: None,
             max_retries: 2,
+            proxy: None,
+            batch_concurrency: DEFAULT_BATCH_CONCURRENCY,
         }
     }
 
+    /// 设置 API 端点主机名（如 `alidns.ap-southeast-1.aliyuncs.com`），优先于 `region`
+    pub fn endpoint(mut self, host: impl Into<String>) -> Self {
+        let host = normalize_endpoint_host(&host.into());
+        if !host.is_empty() {
+            self.endpoint = Some(host);
+        }
+        self
+    }
+
+    /// 设置地域（如 `ap-southeast-1`），使用 `alidns.<region>.aliyuncs.com` 端点
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        let region = region.into();
+        if !region.trim().is_empty() {
+            self.region = Some(region.trim().to_string());
+        }
+        self
+    }
+
     pub fn max_retries(mut self, retries: u32) -> Self {
         self.max_retries = retries;
         self
     }
 
+    /// 设置 HTTP 代理
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// 设置批量操作的最大并发请求数（默认 5）
+    pub fn batch_concurrency(mut self, concurrency: usize) -> Self {
+        self.batch_concurrency = concurrency.max(1);
+        self
+    }
+
     pub fn build(self) -> AliyunProvider {
         AliyunProvider {
-            client: create_http_client(),
+            client: create_http_client(self.proxy.as_ref()),
             access_key_id: self.access_key_id,
             access_key_secret: self.access_key_secret,
+            host: self
+                .endpoint
+                .or_else(|| self.region.map(|r| format!("alidns.{r}.aliyuncs.com")))
+                .unwrap_or_else(|| ALIYUN_DNS_HOST.to_string()),
             max_retries: self.max_retries,
+            batch_concurrency: self.batch_concurrency,
         }
     }
 }
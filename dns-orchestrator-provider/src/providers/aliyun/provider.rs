@@ -5,7 +5,10 @@ use chrono::DateTime;
 use serde::Serialize;
 
 use crate::error::{ProviderError, Result};
-use crate::providers::common::record_type_to_string;
+use crate::providers::common::{
+    canonical_record_name, ensure_not_proxied, ensure_record_type_supported, ensure_simple_routing,
+    record_type_to_string,
+};
 use crate::traits::{DnsProvider, ErrorContext};
 use crate::types::{
     CreateDnsRecordRequest, DnsRecord, DomainStatus, FieldType, PaginatedResponse,
@@ -16,7 +19,7 @@ use crate::types::{
 use super::{
     AddDomainRecordResponse, AliyunProvider, DeleteDomainRecordResponse,
     DescribeDomainInfoResponse, DescribeDomainRecordsResponse, DescribeDomainsResponse,
-    MAX_PAGE_SIZE, UpdateDomainRecordResponse,
+    MAX_PAGE_SIZE, UNSUPPORTED_RECORD_TYPES, UpdateDomainRecordResponse,
 };
 
 impl AliyunProvider {
@@ -52,6 +55,9 @@ impl AliyunProvider {
             "CNAME" => Ok(RecordData::CNAME {
                 target: value.to_string(),
             }),
+            "ALIAS" => Ok(RecordData::ALIAS {
+                target: value.to_string(),
+            }),
             "MX" => Ok(RecordData::MX {
                 priority: priority.ok_or_else(|| ProviderError::ParseError {
                     provider: "aliyun".to_string(),
@@ -126,7 +132,7 @@ impl AliyunProvider {
         match data {
             RecordData::A { address } => (address.clone(), None),
             RecordData::AAAA { address } => (address.clone(), None),
-            RecordData::CNAME { target } => (target.clone(), None),
+            RecordData::CNAME { target } | RecordData::ALIAS { target } => (target.clone(), None),
             RecordData::MX { priority, exchange } => (exchange.clone(), Some(*priority)),
             RecordData::TXT { text } => (text.clone(), None),
             RecordData::NS { nameserver } => (nameserver.clone(), None),
@@ -138,6 +144,9 @@ impl AliyunProvider {
                 target,
             } => (format!("{priority} {weight} {port} {target}"), None),
             RecordData::CAA { flags, tag, value } => (format!("{flags} {tag} \"{value}\""), None),
+            RecordData::TLSA { .. } | RecordData::SSHFP { .. } | RecordData::DS { .. } => {
+                (data.display_value(), None)
+            }
         }
     }
 }
@@ -148,6 +157,10 @@ impl DnsProvider for AliyunProvider {
         "aliyun"
     }
 
+    fn batch_concurrency(&self) -> usize {
+        self.batch_concurrency
+    }
+
     fn metadata() -> ProviderMetadata {
         ProviderMetadata {
             id: ProviderType::Aliyun,
@@ -173,6 +186,13 @@ impl DnsProvider for AliyunProvider {
             limits: ProviderLimits {
                 max_page_size_domains: 100,
                 max_page_size_records: 100,
+                requests_per_second: None,
+                max_batch_size: 1,
+                max_records_per_zone: None,
+                // 免费版最小 600 秒，由 API 校验
+                min_ttl: 1,
+                max_ttl: 86_400,
+                auto_ttl: None,
             },
         }
     }
@@ -332,12 +352,15 @@ impl DnsProvider for AliyunProvider {
                 Some(DnsRecord {
                     id: r.record_id,
                     domain_id: domain_id.to_string(),
-                    name: r.rr,
+                    name: canonical_record_name(&r.rr, domain_id),
                     ttl: r.ttl,
                     data,
                     proxied: None, // 阿里云不支持代理
+                    comment: None,
+                    tags: Vec::new(),
                     created_at: Self::timestamp_to_datetime(r.create_timestamp),
                     updated_at: Self::timestamp_to_datetime(r.update_timestamp),
+                    routing_policy: None,
                 })
             })
             .collect();
@@ -367,14 +390,20 @@ impl DnsProvider for AliyunProvider {
             priority: Option<u16>,
         }
 
+        ensure_record_type_supported(&req.data, UNSUPPORTED_RECORD_TYPES, "aliyun")?;
+        ensure_simple_routing(req.routing_policy.as_ref(), "aliyun")?;
+        ensure_not_proxied(req.proxied, "aliyun")?;
+        self.validate_ttl(req.ttl)?;
+
         // 从 RecordData 提取 value 和 priority
         let (value, priority) = Self::record_data_to_api(&req.data);
         let record_type = record_type_to_string(&req.data.record_type());
 
         // 阿里云的 domain_id 就是域名名称，可以直接使用
+        let name = canonical_record_name(&req.name, &req.domain_id);
         let api_req = AddDomainRecordRequest {
             domain_name: req.domain_id.clone(),
-            rr: req.name.clone(),
+            rr: name.clone(),
             record_type: record_type.to_string(),
             value,
             ttl: req.ttl,
@@ -394,12 +423,15 @@ impl DnsProvider for AliyunProvider {
         Ok(DnsRecord {
             id: response.record_id,
             domain_id: req.domain_id.clone(),
-            name: req.name.clone(),
+            name,
             ttl: req.ttl,
             data: req.data.clone(),
             proxied: None,
+            comment: None,
+            tags: Vec::new(),
             created_at: Some(now),
             updated_at: Some(now),
+            routing_policy: None,
         })
     }
 
@@ -424,13 +456,18 @@ impl DnsProvider for AliyunProvider {
             priority: Option<u16>,
         }
 
+        ensure_record_type_supported(&req.data, UNSUPPORTED_RECORD_TYPES, "aliyun")?;
+        ensure_not_proxied(req.proxied, "aliyun")?;
+        self.validate_ttl(req.ttl)?;
+
         // 从 RecordData 提取 value 和 priority
         let (value, priority) = Self::record_data_to_api(&req.data);
         let record_type = record_type_to_string(&req.data.record_type());
 
+        let name = canonical_record_name(&req.name, &req.domain_id);
         let api_req = UpdateDomainRecordRequest {
             record_id: record_id.to_string(),
-            rr: req.name.clone(),
+            rr: name.clone(),
             record_type: record_type.to_string(),
             value,
             ttl: req.ttl,
@@ -450,12 +487,15 @@ impl DnsProvider for AliyunProvider {
         Ok(DnsRecord {
             id: record_id.to_string(),
             domain_id: req.domain_id.clone(),
-            name: req.name.clone(),
+            name,
             ttl: req.ttl,
             data: req.data.clone(),
             proxied: None,
+            comment: None,
+            tags: Vec::new(),
             created_at: None,
             updated_at: Some(now),
+            routing_policy: None,
         })
     }
 
@@ -4,7 +4,7 @@ use sha2::{Digest, Sha256};
 
 use crate::providers::common::hmac_sha256;
 
-use super::{ALIYUN_DNS_HOST, ALIYUN_DNS_VERSION, AliyunProvider, EMPTY_BODY_SHA256};
+use super::{ALIYUN_DNS_VERSION, AliyunProvider, EMPTY_BODY_SHA256};
 
 impl AliyunProvider {
     /// 生成 ACS3-HMAC-SHA256 签名
@@ -18,7 +18,8 @@ impl AliyunProvider {
     ) -> String {
         // 1. 构造规范化请求头 (使用空 body 的 hash)
         let canonical_headers = format!(
-            "host:{ALIYUN_DNS_HOST}\nx-acs-action:{action}\nx-acs-content-sha256:{EMPTY_BODY_SHA256}\nx-acs-date:{timestamp}\nx-acs-signature-nonce:{nonce}\nx-acs-version:{ALIYUN_DNS_VERSION}\n"
+            "host:{}\nx-acs-action:{action}\nx-acs-content-sha256:{EMPTY_BODY_SHA256}\nx-acs-date:{timestamp}\nx-acs-signature-nonce:{nonce}\nx-acs-version:{ALIYUN_DNS_VERSION}\n",
+            self.host
         );
 
         let signed_headers =
@@ -1,5 +1,6 @@
 //! Cloudflare HTTP 请求方法（重构版：消除代码重复）
 
+use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderValue};
 use serde::{Deserialize, Serialize};
 
 use crate::error::Result;
@@ -8,15 +9,38 @@ use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
 use crate::types::PaginationParams;
 
 use super::{
-    CF_API_BASE, CloudflareDnsRecord, CloudflareProvider, CloudflareResponse, MAX_PAGE_SIZE_ZONES,
+    CF_API_BASE, CloudflareAuth, CloudflareDnsRecord, CloudflareProvider, CloudflareResponse,
+    MAX_PAGE_SIZE_ZONES,
 };
 
 impl CloudflareProvider {
     // ==================== 辅助方法 ====================
 
+    /// 按认证方式生成请求头
+    fn auth_headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        match &self.auth {
+            CloudflareAuth::ApiToken(token) => {
+                if let Ok(value) = HeaderValue::from_str(&format!("Bearer {token}")) {
+                    headers.insert(AUTHORIZATION, value);
+                }
+            }
+            CloudflareAuth::GlobalKey { email, api_key } => {
+                if let (Ok(email), Ok(key)) =
+                    (HeaderValue::from_str(email), HeaderValue::from_str(api_key))
+                {
+                    headers.insert("X-Auth-Email", email);
+                    headers.insert("X-Auth-Key", key);
+                }
+            }
+        }
+        headers
+    }
+
     /// 统一处理 Cloudflare API 响应
     fn handle_cf_response<T: for<'de> Deserialize<'de>>(
         &self,
+        status: u16,
         response_text: &str,
         ctx: ErrorContext,
     ) -> Result<T> {
@@ -33,7 +57,10 @@ impl CloudflareProvider {
                 })
                 .unwrap_or_else(|| (String::new(), "Unknown error".to_string()));
             log::error!("API 错误: {message}");
-            return Err(self.map_error(RawApiError::with_code(code, message), ctx));
+            return Err(self.map_error(
+                RawApiError::with_code(code, message).with_status(status),
+                ctx,
+            ));
         }
 
         cf_response
@@ -44,6 +71,7 @@ impl CloudflareProvider {
     /// 统一处理 Cloudflare API 响应（带分页信息）
     fn handle_cf_response_paginated<T: for<'de> Deserialize<'de>>(
         &self,
+        status: u16,
         response_text: &str,
         ctx: ErrorContext,
     ) -> Result<(Vec<T>, u32)> {
@@ -60,7 +88,10 @@ impl CloudflareProvider {
                 })
                 .unwrap_or_else(|| (String::new(), "Unknown error".to_string()));
             log::error!("API 错误: {message}");
-            return Err(self.map_error(RawApiError::with_code(code, message), ctx));
+            return Err(self.map_error(
+                RawApiError::with_code(code, message).with_status(status),
+                ctx,
+            ));
         }
 
         let total_count = cf_response.result_info.map_or(0, |i| i.total_count);
@@ -92,10 +123,10 @@ impl CloudflareProvider {
         let request = self
             .client
             .request(method.clone(), &url)
-            .header("Authorization", format!("Bearer {}", self.api_token))
+            .headers(self.auth_headers())
             .json(body);
 
-        let (_status, response_text) = HttpUtils::execute_request_with_retry(
+        let (status, response_text) = HttpUtils::execute_request_with_retry(
             request,
             self.provider_name(),
             method.as_str(),
@@ -104,7 +135,7 @@ impl CloudflareProvider {
         )
         .await?;
 
-        self.handle_cf_response(&response_text, ctx)
+        self.handle_cf_response(status, &response_text, ctx)
     }
 
     // ==================== 公开 API 方法 ====================
@@ -117,12 +148,9 @@ impl CloudflareProvider {
     ) -> Result<T> {
         let url = format!("{CF_API_BASE}{path}");
 
-        let request = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.api_token));
+        let request = self.client.get(&url).headers(self.auth_headers());
 
-        let (_status, response_text) = HttpUtils::execute_request_with_retry(
+        let (status, response_text) = HttpUtils::execute_request_with_retry(
             request,
             self.provider_name(),
             "GET",
@@ -131,7 +159,7 @@ impl CloudflareProvider {
         )
         .await?;
 
-        self.handle_cf_response(&response_text, ctx)
+        self.handle_cf_response(status, &response_text, ctx)
     }
 
     /// 执行 GET 请求 (带分页)
@@ -149,12 +177,9 @@ impl CloudflareProvider {
             params.page_size.min(MAX_PAGE_SIZE_ZONES)
         );
 
-        let request = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.api_token));
+        let request = self.client.get(&url).headers(self.auth_headers());
 
-        let (_status, response_text) = HttpUtils::execute_request_with_retry(
+        let (status, response_text) = HttpUtils::execute_request_with_retry(
             request,
             self.provider_name(),
             "GET",
@@ -163,7 +188,7 @@ impl CloudflareProvider {
         )
         .await?;
 
-        self.handle_cf_response_paginated(&response_text, ctx)
+        self.handle_cf_response_paginated(status, &response_text, ctx)
     }
 
     /// 执行 GET 请求 (带自定义 URL，用于 list_records)
@@ -174,12 +199,9 @@ impl CloudflareProvider {
     ) -> Result<(Vec<CloudflareDnsRecord>, u32)> {
         let full_url = format!("{CF_API_BASE}{url}");
 
-        let request = self
-            .client
-            .get(&full_url)
-            .header("Authorization", format!("Bearer {}", self.api_token));
+        let request = self.client.get(&full_url).headers(self.auth_headers());
 
-        let (_status, response_text) = HttpUtils::execute_request_with_retry(
+        let (status, response_text) = HttpUtils::execute_request_with_retry(
             request,
             self.provider_name(),
             "GET",
@@ -188,7 +210,7 @@ impl CloudflareProvider {
         )
         .await?;
 
-        self.handle_cf_response_paginated(&response_text, ctx)
+        self.handle_cf_response_paginated(status, &response_text, ctx)
     }
 
     /// 执行 POST 请求（直接使用 JSON Value）
@@ -217,12 +239,9 @@ impl CloudflareProvider {
     pub(crate) async fn delete(&self, path: &str, ctx: ErrorContext) -> Result<()> {
         let url = format!("{CF_API_BASE}{path}");
 
-        let request = self
-            .client
-            .delete(&url)
-            .header("Authorization", format!("Bearer {}", self.api_token));
+        let request = self.client.delete(&url).headers(self.auth_headers());
 
-        let (_status, response_text) = HttpUtils::execute_request_with_retry(
+        let (status, response_text) = HttpUtils::execute_request_with_retry(
             request,
             self.provider_name(),
             "DELETE",
@@ -245,7 +264,10 @@ impl CloudflareProvider {
                 })
                 .unwrap_or_else(|| (String::new(), "Unknown error".to_string()));
             log::error!("API 错误: {message}");
-            return Err(self.map_error(RawApiError::with_code(code, message), ctx));
+            return Err(self.map_error(
+                RawApiError::with_code(code, message).with_status(status),
+                ctx,
+            ));
         }
 
         Ok(())
@@ -7,10 +7,13 @@ mod types;
 
 use reqwest::Client;
 
+use crate::http_client::ProxyConfig;
 use crate::providers::common::create_http_client;
+use crate::traits::DEFAULT_BATCH_CONCURRENCY;
 
 pub(crate) use types::{
-    CloudflareCaaData, CloudflareDnsRecord, CloudflareResponse, CloudflareSrvData, CloudflareZone,
+    CloudflareAuditLog, CloudflareCaaData, CloudflareDnsRecord, CloudflareDnssec, CloudflareDsData,
+    CloudflareResponse, CloudflareSrvData, CloudflareSshfpData, CloudflareTlsaData, CloudflareZone,
 };
 
 pub(crate) const CF_API_BASE: &str = "https://api.cloudflare.com/client/v4";
@@ -18,25 +21,41 @@ pub(crate) const CF_API_BASE: &str = "https://api.cloudflare.com/client/v4";
 pub(crate) const MAX_PAGE_SIZE_ZONES: u32 = 50;
 /// Cloudflare DNS Records API 单页最大记录数
 pub(crate) const MAX_PAGE_SIZE_RECORDS: u32 = 100;
+/// Cloudflare Audit Logs API 单页最大条数
+pub(crate) const MAX_PAGE_SIZE_AUDIT_LOGS: u32 = 1000;
+
+/// Cloudflare 认证方式
+#[derive(Debug, Clone)]
+pub(crate) enum CloudflareAuth {
+    /// 作用域 API Token（`Authorization: Bearer`）
+    ApiToken(String),
+    /// Global API Key + 账户邮箱（`X-Auth-Email` / `X-Auth-Key`）
+    GlobalKey { email: String, api_key: String },
+}
 
 /// Cloudflare DNS Provider
 pub struct CloudflareProvider {
     pub(crate) client: Client,
-    pub(crate) api_token: String,
+    pub(crate) auth: CloudflareAuth,
     pub(crate) max_retries: u32,
+    pub(crate) batch_concurrency: usize,
 }
 
 /// Cloudflare Provider Builder
 pub struct CloudflareProviderBuilder {
-    api_token: String,
+    auth: CloudflareAuth,
     max_retries: u32,
+    proxy: Option<ProxyConfig>,
+    batch_concurrency: usize,
 }
 
 impl CloudflareProviderBuilder {
-    fn new(api_token: String) -> Self {
+    fn new(auth: CloudflareAuth) -> Self {
         Self {
-            api_token,
+            auth,
             max_retries: 2, // 默认重试 2 次
+            proxy: None,
+            batch_concurrency: DEFAULT_BATCH_CONCURRENCY,
         }
     }
 
@@ -45,11 +64,24 @@ impl CloudflareProviderBuilder {
         self
     }
 
+    /// 设置 HTTP 代理
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// 设置批量操作的最大并发请求数（默认 5）
+    pub fn batch_concurrency(mut self, concurrency: usize) -> Self {
+        self.batch_concurrency = concurrency.max(1);
+        self
+    }
+
     pub fn build(self) -> CloudflareProvider {
         CloudflareProvider {
-            client: create_http_client(),
-            api_token: self.api_token,
+            client: create_http_client(self.proxy.as_ref()),
+            auth: self.auth,
             max_retries: self.max_retries,
+            batch_concurrency: self.batch_concurrency,
         }
     }
 }
@@ -60,6 +92,15 @@ impl CloudflareProvider {
     }
 
     pub fn builder(api_token: String) -> CloudflareProviderBuilder {
-        CloudflareProviderBuilder::new(api_token)
+        CloudflareProviderBuilder::new(CloudflareAuth::ApiToken(api_token))
+    }
+
+    /// 使用 Global API Key 认证（旧版账户）
+    pub fn with_global_key(email: String, api_key: String) -> Self {
+        Self::builder_with_global_key(email, api_key).build()
+    }
+
+    pub fn builder_with_global_key(email: String, api_key: String) -> CloudflareProviderBuilder {
+        CloudflareProviderBuilder::new(CloudflareAuth::GlobalKey { email, api_key })
     }
 }
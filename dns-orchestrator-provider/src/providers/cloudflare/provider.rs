@@ -1,20 +1,27 @@
 //! Cloudflare DnsProvider trait 实现
 
 use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
 use serde::Deserialize;
 
 use crate::error::Result;
-use crate::providers::common::{full_name_to_relative, relative_to_full_name};
+use crate::providers::common::{
+    ensure_simple_routing, full_name_to_relative, parse_ds_value, parse_sshfp_value,
+    parse_tlsa_value, relative_to_full_name, validate_record_data,
+};
 use crate::traits::{DnsProvider, ErrorContext, ProviderErrorMapper};
 use crate::types::{
-    CreateDnsRecordRequest, DnsRecord, DomainStatus, FieldType, PaginatedResponse,
-    PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures, ProviderLimits,
-    ProviderMetadata, ProviderType, RecordData, RecordQueryParams, UpdateDnsRecordRequest,
+    BatchUpdateFailure, BatchUpdateResult, CreateDnsRecordRequest, DnsRecord, DnssecState,
+    DnssecStatus, DomainStatus, FieldType, PaginatedResponse, PaginationParams,
+    ProviderCredentialField, ProviderDomain, ProviderFeatures, ProviderLimits, ProviderMetadata,
+    ProviderType, RecordChange, RecordChangeAction, RecordData, RecordQueryParams,
+    UpdateDnsRecordRequest,
 };
 
 use super::{
-    CloudflareCaaData, CloudflareDnsRecord, CloudflareProvider, CloudflareSrvData, CloudflareZone,
-    MAX_PAGE_SIZE_RECORDS,
+    CloudflareAuditLog, CloudflareCaaData, CloudflareDnsRecord, CloudflareDnssec, CloudflareDsData,
+    CloudflareProvider, CloudflareSrvData, CloudflareSshfpData, CloudflareTlsaData, CloudflareZone,
+    MAX_PAGE_SIZE_AUDIT_LOGS, MAX_PAGE_SIZE_RECORDS,
 };
 
 impl CloudflareProvider {
@@ -53,6 +60,8 @@ impl CloudflareProvider {
             ttl: cf_record.ttl,
             data,
             proxied: cf_record.proxied,
+            comment: cf_record.comment,
+            tags: cf_record.tags,
             created_at: cf_record.created_on.and_then(|s| {
                 chrono::DateTime::parse_from_rfc3339(&s)
                     .ok()
@@ -63,6 +72,7 @@ impl CloudflareProvider {
                     .ok()
                     .map(|dt| dt.with_timezone(&chrono::Utc))
             }),
+            routing_policy: None,
         })
     }
 
@@ -185,6 +195,68 @@ impl CloudflareProvider {
                     }
                 }
             }
+            "TLSA" => {
+                // TLSA 记录使用 data 字段
+                if let Some(ref data) = cf_record.data {
+                    let tlsa: CloudflareTlsaData =
+                        serde_json::from_value(data.clone()).map_err(|e| {
+                            crate::error::ProviderError::ParseError {
+                                provider: self.provider_name().to_string(),
+                                detail: format!("Failed to parse TLSA data: {e}"),
+                            }
+                        })?;
+                    Ok(RecordData::TLSA {
+                        usage: tlsa.usage,
+                        selector: tlsa.selector,
+                        matching_type: tlsa.matching_type,
+                        certificate_association: tlsa.certificate.to_lowercase(),
+                    })
+                } else {
+                    // Fallback: 尝试从 content 解析 "usage selector matching_type certificate"
+                    parse_tlsa_value(&cf_record.content, self.provider_name())
+                }
+            }
+            "SSHFP" => {
+                // SSHFP 记录使用 data 字段
+                if let Some(ref data) = cf_record.data {
+                    let sshfp: CloudflareSshfpData =
+                        serde_json::from_value(data.clone()).map_err(|e| {
+                            crate::error::ProviderError::ParseError {
+                                provider: self.provider_name().to_string(),
+                                detail: format!("Failed to parse SSHFP data: {e}"),
+                            }
+                        })?;
+                    Ok(RecordData::SSHFP {
+                        algorithm: sshfp.algorithm,
+                        fingerprint_type: sshfp.fingerprint_type,
+                        fingerprint: sshfp.fingerprint.to_lowercase(),
+                    })
+                } else {
+                    // Fallback: 尝试从 content 解析 "algorithm type fingerprint"
+                    parse_sshfp_value(&cf_record.content, self.provider_name())
+                }
+            }
+            "DS" => {
+                // DS 记录使用 data 字段
+                if let Some(ref data) = cf_record.data {
+                    let ds: CloudflareDsData =
+                        serde_json::from_value(data.clone()).map_err(|e| {
+                            crate::error::ProviderError::ParseError {
+                                provider: self.provider_name().to_string(),
+                                detail: format!("Failed to parse DS data: {e}"),
+                            }
+                        })?;
+                    Ok(RecordData::DS {
+                        key_tag: ds.key_tag,
+                        algorithm: ds.algorithm,
+                        digest_type: ds.digest_type,
+                        digest: ds.digest.to_lowercase(),
+                    })
+                } else {
+                    // Fallback: 尝试从 content 解析 "key_tag algorithm digest_type digest"
+                    parse_ds_value(&cf_record.content, self.provider_name())
+                }
+            }
             _ => Err(crate::error::ProviderError::UnsupportedRecordType {
                 provider: self.provider_name().to_string(),
                 record_type: cf_record.record_type.clone(),
@@ -215,7 +287,8 @@ impl CloudflareProvider {
                 "ttl": ttl,
                 "proxied": proxied,
             }),
-            RecordData::CNAME { target } => serde_json::json!({
+            // Cloudflare 通过 CNAME 拉平（CNAME flattening）实现根域别名，读回时为 CNAME
+            RecordData::CNAME { target } | RecordData::ALIAS { target } => serde_json::json!({
                 "type": "CNAME",
                 "name": full_name,
                 "content": target,
@@ -267,7 +340,199 @@ impl CloudflareProvider {
                     "value": value,
                 }
             }),
+            RecordData::TLSA {
+                usage,
+                selector,
+                matching_type,
+                certificate_association,
+            } => serde_json::json!({
+                "type": "TLSA",
+                "name": full_name,
+                "ttl": ttl,
+                "data": {
+                    "usage": usage,
+                    "selector": selector,
+                    "matching_type": matching_type,
+                    "certificate": certificate_association,
+                }
+            }),
+            RecordData::SSHFP {
+                algorithm,
+                fingerprint_type,
+                fingerprint,
+            } => serde_json::json!({
+                "type": "SSHFP",
+                "name": full_name,
+                "ttl": ttl,
+                "data": {
+                    "algorithm": algorithm,
+                    "type": fingerprint_type,
+                    "fingerprint": fingerprint,
+                }
+            }),
+            RecordData::DS {
+                key_tag,
+                algorithm,
+                digest_type,
+                digest,
+            } => serde_json::json!({
+                "type": "DS",
+                "name": full_name,
+                "ttl": ttl,
+                "data": {
+                    "key_tag": key_tag,
+                    "algorithm": algorithm,
+                    "digest_type": digest_type,
+                    "digest": digest,
+                }
+            }),
+        }
+    }
+
+    /// 在请求体中附加记录备注与标签（未设置时不发送，更新时保留原值）
+    fn apply_record_metadata(body: &mut serde_json::Value, comment: Option<&str>, tags: &[String]) {
+        let Some(body) = body.as_object_mut() else {
+            return;
+        };
+        if let Some(comment) = comment {
+            body.insert("comment".to_string(), comment.into());
+        }
+        if !tags.is_empty() {
+            body.insert("tags".to_string(), tags.into());
+        }
+    }
+
+    /// 将审计日志条目转换为 RecordChange（非 DNS 记录操作返回 `None`）
+    fn audit_log_to_change(log: CloudflareAuditLog) -> Option<RecordChange> {
+        let action = match log.action.action_type.as_str() {
+            "rec_add" => RecordChangeAction::Create,
+            "rec_set" => RecordChangeAction::Update,
+            "rec_del" => RecordChangeAction::Delete,
+            other if other.starts_with("rec_") => RecordChangeAction::Other,
+            _ => return None,
+        };
+
+        let summary = ["type", "name", "content"]
+            .iter()
+            .filter_map(|key| log.metadata.get(key).and_then(|v| v.as_str()))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let record_summary = if summary.is_empty() {
+            log.resource
+                .and_then(|r| r.id)
+                .unwrap_or(log.action.action_type)
+        } else {
+            summary
+        };
+
+        Some(RecordChange {
+            timestamp: log.when,
+            action,
+            record_summary,
+            actor: log.actor.and_then(|a| a.email.or(a.id)),
+        })
+    }
+
+    /// 将 Zone DNSSEC 设置转换为 DnssecStatus
+    fn dnssec_to_status(&self, dnssec: CloudflareDnssec) -> Result<DnssecStatus> {
+        let state = match dnssec.status.as_str() {
+            "active" => DnssecState::Active,
+            "pending" => DnssecState::Pending,
+            "pending-disabled" => DnssecState::PendingDisable,
+            "disabled" => DnssecState::Disabled,
+            other => {
+                return Err(self.parse_error(format!("未知的 DNSSEC 状态: {other}")));
+            }
+        };
+
+        let ds_record = match (
+            dnssec.key_tag,
+            dnssec.algorithm.and_then(|a| a.parse().ok()),
+            dnssec.digest_type.and_then(|d| d.parse().ok()),
+            dnssec.digest,
+        ) {
+            (Some(key_tag), Some(algorithm), Some(digest_type), Some(digest))
+                if state != DnssecState::Disabled =>
+            {
+                Some(RecordData::DS {
+                    key_tag,
+                    algorithm,
+                    digest_type,
+                    digest: digest.to_lowercase(),
+                })
+            }
+            _ => None,
+        };
+
+        Ok(DnssecStatus {
+            state,
+            ds_records: ds_record.into_iter().collect(),
+        })
+    }
+
+    /// 批量开启/关闭代理（橙色云朵）
+    ///
+    /// 以 `batch_concurrency()` 为上限并发修改每条记录的 `proxied` 字段，
+    /// 单条失败（如记录类型不支持代理）不影响其他记录，失败项记录在返回结果中。
+    pub async fn bulk_set_proxied(
+        &self,
+        domain_id: &str,
+        record_ids: &[String],
+        proxied: bool,
+    ) -> Result<BatchUpdateResult> {
+        let zone: CloudflareZone = self
+            .get(
+                &format!("/zones/{domain_id}"),
+                ErrorContext {
+                    domain: Some(domain_id.to_string()),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let zone_name = zone.name.as_str();
+
+        let results: Vec<Result<DnsRecord>> = stream::iter(record_ids)
+            .map(|record_id| async move {
+                let ctx = ErrorContext {
+                    record_id: Some(record_id.clone()),
+                    domain: Some(domain_id.to_string()),
+                    ..Default::default()
+                };
+                let cf_record: CloudflareDnsRecord = self
+                    .patch_json(
+                        &format!("/zones/{domain_id}/dns_records/{record_id}"),
+                        serde_json::json!({ "proxied": proxied }),
+                        ctx,
+                    )
+                    .await?;
+                self.cf_record_to_dns_record(cf_record, domain_id, zone_name)
+            })
+            .buffered(self.batch_concurrency.max(1))
+            .collect()
+            .await;
+
+        let mut result = BatchUpdateResult {
+            success_count: 0,
+            failed_count: 0,
+            updated_records: Vec::new(),
+            failures: Vec::new(),
+        };
+        for (record_id, outcome) in record_ids.iter().zip(results) {
+            match outcome {
+                Ok(record) => {
+                    result.success_count += 1;
+                    result.updated_records.push(record);
+                }
+                Err(e) => {
+                    result.failed_count += 1;
+                    result.failures.push(BatchUpdateFailure {
+                        record_id: record_id.clone(),
+                        reason: e.to_string(),
+                    });
+                }
+            }
         }
+        Ok(result)
     }
 }
 
@@ -277,6 +542,10 @@ impl DnsProvider for CloudflareProvider {
         "cloudflare"
     }
 
+    fn batch_concurrency(&self) -> usize {
+        self.batch_concurrency
+    }
+
     fn metadata() -> ProviderMetadata {
         ProviderMetadata {
             id: ProviderType::Cloudflare,
@@ -291,14 +560,29 @@ impl DnsProvider for CloudflareProvider {
                     "在 Cloudflare Dashboard -> My Profile -> API Tokens 创建".to_string(),
                 ),
             }],
-            features: ProviderFeatures { proxy: true },
+            features: ProviderFeatures {
+                proxy: true,
+                record_metadata: true,
+                routing_policy: false,
+            },
             limits: ProviderLimits {
                 max_page_size_domains: 50,
                 max_page_size_records: 5000,
+                requests_per_second: Some(4.0), // 1200 次 / 5 分钟
+                max_batch_size: 1,
+                max_records_per_zone: None,
+                // 最小 60 秒（企业版可更低），1 表示自动
+                min_ttl: 60,
+                max_ttl: 86_400,
+                auto_ttl: Some(1),
             },
         }
     }
 
+    fn features(&self) -> ProviderFeatures {
+        Self::metadata().features
+    }
+
     async fn validate_credentials(&self) -> Result<bool> {
         #[derive(Deserialize)]
         struct VerifyResponse {
@@ -392,6 +676,9 @@ impl DnsProvider for CloudflareProvider {
     }
 
     async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        ensure_simple_routing(req.routing_policy.as_ref(), self.provider_name())?;
+        self.validate_ttl(req.ttl)?;
+        validate_record_data(&req.data, self.provider_name())?;
         let ctx = ErrorContext {
             record_name: Some(req.name.clone()),
             domain: Some(req.domain_id.clone()),
@@ -405,7 +692,8 @@ impl DnsProvider for CloudflareProvider {
         let zone_name = zone.name;
 
         let full_name = relative_to_full_name(&req.name, &zone_name);
-        let body = self.build_create_body(&full_name, req.ttl, &req.data, req.proxied);
+        let mut body = self.build_create_body(&full_name, req.ttl, &req.data, req.proxied);
+        Self::apply_record_metadata(&mut body, req.comment.as_deref(), &req.tags);
 
         let cf_record: CloudflareDnsRecord = self
             .post_json(&format!("/zones/{}/dns_records", req.domain_id), body, ctx)
@@ -419,6 +707,8 @@ impl DnsProvider for CloudflareProvider {
         record_id: &str,
         req: &UpdateDnsRecordRequest,
     ) -> Result<DnsRecord> {
+        self.validate_ttl(req.ttl)?;
+        validate_record_data(&req.data, self.provider_name())?;
         let ctx = ErrorContext {
             record_name: Some(req.name.clone()),
             record_id: Some(record_id.to_string()),
@@ -432,7 +722,8 @@ impl DnsProvider for CloudflareProvider {
         let zone_name = zone.name;
 
         let full_name = relative_to_full_name(&req.name, &zone_name);
-        let body = self.build_create_body(&full_name, req.ttl, &req.data, req.proxied);
+        let mut body = self.build_create_body(&full_name, req.ttl, &req.data, req.proxied);
+        Self::apply_record_metadata(&mut body, req.comment.as_deref(), &req.tags);
 
         let cf_record: CloudflareDnsRecord = self
             .patch_json(
@@ -454,4 +745,64 @@ impl DnsProvider for CloudflareProvider {
         self.delete(&format!("/zones/{domain_id}/dns_records/{record_id}"), ctx)
             .await
     }
+
+    async fn get_dnssec_status(&self, domain_id: &str) -> Result<DnssecStatus> {
+        let ctx = ErrorContext {
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let dnssec: CloudflareDnssec = self.get(&format!("/zones/{domain_id}/dnssec"), ctx).await?;
+        self.dnssec_to_status(dnssec)
+    }
+
+    async fn enable_dnssec(&self, domain_id: &str) -> Result<DnssecStatus> {
+        let ctx = ErrorContext {
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let dnssec: CloudflareDnssec = self
+            .patch_json(
+                &format!("/zones/{domain_id}/dnssec"),
+                serde_json::json!({ "status": "active" }),
+                ctx,
+            )
+            .await?;
+        self.dnssec_to_status(dnssec)
+    }
+
+    /// 基于账户审计日志（需要 Audit Logs 读取权限），最多返回最近 1000 条
+    async fn list_record_changes(
+        &self,
+        domain_id: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<RecordChange>> {
+        let ctx = ErrorContext {
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let zone: CloudflareZone = self
+            .get(&format!("/zones/{domain_id}"), ctx.clone())
+            .await?;
+        let account_id = zone
+            .account
+            .map(|account| account.id)
+            .ok_or_else(|| self.parse_error("Zone 响应中缺少 account 字段"))?;
+
+        let mut path = format!(
+            "/accounts/{account_id}/audit_logs?zone.name={}&direction=desc&per_page={MAX_PAGE_SIZE_AUDIT_LOGS}",
+            zone.name
+        );
+        if let Some(since) = since {
+            path.push_str(&format!(
+                "&since={}",
+                since.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+            ));
+        }
+
+        let logs: Vec<CloudflareAuditLog> = self.get(&path, ctx).await?;
+        Ok(logs
+            .into_iter()
+            .filter_map(Self::audit_log_to_change)
+            .collect())
+    }
 }
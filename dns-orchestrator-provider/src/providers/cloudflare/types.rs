@@ -34,6 +34,13 @@ pub struct CloudflareZone {
     pub id: String,
     pub name: String,
     pub status: String,
+    /// Zone 所属账户（查询审计日志时需要账户 ID）
+    pub account: Option<CloudflareZoneAccount>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CloudflareZoneAccount {
+    pub id: String,
 }
 
 /// Cloudflare DNS Record 结构（响应）
@@ -50,6 +57,10 @@ pub struct CloudflareDnsRecord {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub proxied: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created_on: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modified_on: Option<String>,
@@ -81,3 +92,74 @@ pub struct CloudflareCaaData {
     pub tag: String,
     pub value: String,
 }
+
+/// TLSA 记录的 data 字段
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CloudflareTlsaData {
+    pub usage: u8,
+    pub selector: u8,
+    pub matching_type: u8,
+    pub certificate: String,
+}
+
+/// SSHFP 记录的 data 字段
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CloudflareSshfpData {
+    pub algorithm: u8,
+    #[serde(rename = "type")]
+    pub fingerprint_type: u8,
+    pub fingerprint: String,
+}
+
+/// DS 记录的 data 字段
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CloudflareDsData {
+    pub key_tag: u16,
+    pub algorithm: u8,
+    pub digest_type: u8,
+    pub digest: String,
+}
+
+/// Zone DNSSEC 设置（`/zones/{zone_id}/dnssec`）
+///
+/// 状态：active, pending, disabled, pending-disabled, error；
+/// `algorithm`、`digest_type` 以字符串返回，未启用时各字段为空
+#[derive(Debug, Deserialize)]
+pub struct CloudflareDnssec {
+    pub status: String,
+    pub key_tag: Option<u16>,
+    pub algorithm: Option<String>,
+    pub digest_type: Option<String>,
+    pub digest: Option<String>,
+}
+
+/// Cloudflare 账户审计日志条目
+///
+/// DNS 记录相关操作的 `action.type` 为 `rec_add`、`rec_set`、`rec_del`，
+/// `metadata` 中包含记录的 `type`、`name`、`content`
+#[derive(Debug, Deserialize)]
+pub struct CloudflareAuditLog {
+    pub when: chrono::DateTime<chrono::Utc>,
+    pub action: CloudflareAuditAction,
+    pub actor: Option<CloudflareAuditActor>,
+    #[serde(default)]
+    pub metadata: Value,
+    pub resource: Option<CloudflareAuditResource>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CloudflareAuditAction {
+    #[serde(rename = "type")]
+    pub action_type: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CloudflareAuditActor {
+    pub email: Option<String>,
+    pub id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CloudflareAuditResource {
+    pub id: Option<String>,
+}
@@ -8,7 +8,8 @@ use reqwest::Client;
 use sha2::Sha256;
 
 use crate::error::{ProviderError, Result};
-use crate::types::DnsRecordType;
+use crate::http_client::ProxyConfig;
+use crate::types::{DnsRecordType, RecordData, RoutingPolicy};
 
 type HmacSha256 = Hmac<Sha256>;
 
@@ -22,17 +23,41 @@ const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 30;
 /// 全局共享的 HTTP Client
 static SHARED_HTTP_CLIENT: OnceLock<Client> = OnceLock::new();
 
-/// 获取共享的 HTTP Client（懒初始化，线程安全）
-pub fn create_http_client() -> Client {
-    SHARED_HTTP_CLIENT
-        .get_or_init(|| {
-            Client::builder()
-                .connect_timeout(Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS))
-                .timeout(Duration::from_secs(DEFAULT_REQUEST_TIMEOUT_SECS))
-                .build()
-                .expect("Failed to create HTTP client")
-        })
-        .clone()
+/// 获取 HTTP Client
+///
+/// 未配置代理时返回共享的 Client（懒初始化，线程安全）；
+/// 配置了代理时为该 Provider 单独创建 Client。
+pub fn create_http_client(proxy: Option<&ProxyConfig>) -> Client {
+    match proxy {
+        Some(proxy) => http_client_builder()
+            .proxy(proxy.to_reqwest())
+            .build()
+            .expect("Failed to create HTTP client"),
+        None => SHARED_HTTP_CLIENT
+            .get_or_init(|| {
+                http_client_builder()
+                    .build()
+                    .expect("Failed to create HTTP client")
+            })
+            .clone(),
+    }
+}
+
+/// 规范化自定义 API 端点：去掉协议前缀与末尾斜杠，仅保留主机名（可含端口）
+pub fn normalize_endpoint_host(endpoint: &str) -> String {
+    let endpoint = endpoint.trim();
+    endpoint
+        .strip_prefix("https://")
+        .or_else(|| endpoint.strip_prefix("http://"))
+        .unwrap_or(endpoint)
+        .trim_end_matches('/')
+        .to_string()
+}
+
+fn http_client_builder() -> reqwest::ClientBuilder {
+    Client::builder()
+        .connect_timeout(Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS))
+        .timeout(Duration::from_secs(DEFAULT_REQUEST_TIMEOUT_SECS))
 }
 
 // ============ 记录类型转换 ============
@@ -48,6 +73,10 @@ pub fn parse_record_type(record_type: &str, provider: &str) -> Result<DnsRecordT
         "NS" => Ok(DnsRecordType::Ns),
         "SRV" => Ok(DnsRecordType::Srv),
         "CAA" => Ok(DnsRecordType::Caa),
+        "ALIAS" | "ANAME" => Ok(DnsRecordType::Alias),
+        "TLSA" => Ok(DnsRecordType::Tlsa),
+        "SSHFP" => Ok(DnsRecordType::Sshfp),
+        "DS" => Ok(DnsRecordType::Ds),
         _ => Err(ProviderError::InvalidParameter {
             provider: provider.to_string(),
             param: "record_type".to_string(),
@@ -67,7 +96,134 @@ pub fn record_type_to_string(record_type: &DnsRecordType) -> &'static str {
         DnsRecordType::Ns => "NS",
         DnsRecordType::Srv => "SRV",
         DnsRecordType::Caa => "CAA",
+        DnsRecordType::Alias => "ALIAS",
+        DnsRecordType::Tlsa => "TLSA",
+        DnsRecordType::Sshfp => "SSHFP",
+        DnsRecordType::Ds => "DS",
+    }
+}
+
+/// 检查记录类型是否受支持，不支持时返回 `UnsupportedRecordType`
+pub fn ensure_record_type_supported(
+    data: &RecordData,
+    unsupported: &[DnsRecordType],
+    provider: &str,
+) -> Result<()> {
+    let record_type = data.record_type();
+    if unsupported.contains(&record_type) {
+        return Err(ProviderError::UnsupportedRecordType {
+            provider: provider.to_string(),
+            record_type: record_type_to_string(&record_type).to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// 检查路由策略：仅支持简单路由的 Provider 遇到其他策略时返回 `UnsupportedRecordType`
+pub fn ensure_simple_routing(policy: Option<&RoutingPolicy>, provider: &str) -> Result<()> {
+    match policy {
+        None | Some(RoutingPolicy::Simple) => Ok(()),
+        Some(policy) => Err(ProviderError::UnsupportedRecordType {
+            provider: provider.to_string(),
+            record_type: format!("{} routing policy", policy.name()),
+        }),
+    }
+}
+
+/// 检查代理开关：不支持代理的 Provider 收到 `proxied: Some(true)` 时返回 `UnsupportedOperation`
+pub fn ensure_not_proxied(proxied: Option<bool>, provider: &str) -> Result<()> {
+    if proxied == Some(true) {
+        return Err(ProviderError::UnsupportedOperation {
+            provider: provider.to_string(),
+            operation: "proxied records".to_string(),
+        });
     }
+    Ok(())
+}
+
+/// 校验记录数据中取值受限的字段（TLSA/SSHFP/DS 的枚举值与十六进制数据）
+pub fn validate_record_data(data: &RecordData, provider: &str) -> Result<()> {
+    let invalid = |param: &str, detail: String| ProviderError::InvalidParameter {
+        provider: provider.to_string(),
+        param: param.to_string(),
+        detail,
+    };
+    let is_hex = |value: &str| !value.is_empty() && hex::decode(value).is_ok();
+
+    match data {
+        RecordData::TLSA {
+            usage,
+            selector,
+            matching_type,
+            certificate_association,
+        } => {
+            if *usage > 3 {
+                return Err(invalid(
+                    "usage",
+                    format!("TLSA usage 须为 0-3，当前为 {usage}"),
+                ));
+            }
+            if *selector > 1 {
+                return Err(invalid(
+                    "selector",
+                    format!("TLSA selector 须为 0-1，当前为 {selector}"),
+                ));
+            }
+            if *matching_type > 2 {
+                return Err(invalid(
+                    "matching_type",
+                    format!("TLSA matching type 须为 0-2，当前为 {matching_type}"),
+                ));
+            }
+            if !is_hex(certificate_association) {
+                return Err(invalid(
+                    "certificate_association",
+                    "TLSA 证书数据须为十六进制字符串".to_string(),
+                ));
+            }
+        }
+        RecordData::SSHFP {
+            algorithm,
+            fingerprint_type,
+            fingerprint,
+        } => {
+            if !(1..=4).contains(algorithm) {
+                return Err(invalid(
+                    "algorithm",
+                    format!("SSHFP algorithm 须为 1-4，当前为 {algorithm}"),
+                ));
+            }
+            if !(1..=2).contains(fingerprint_type) {
+                return Err(invalid(
+                    "fingerprint_type",
+                    format!("SSHFP fingerprint type 须为 1-2，当前为 {fingerprint_type}"),
+                ));
+            }
+            if !is_hex(fingerprint) {
+                return Err(invalid(
+                    "fingerprint",
+                    "SSHFP 指纹须为十六进制字符串".to_string(),
+                ));
+            }
+        }
+        RecordData::DS {
+            digest_type,
+            digest,
+            ..
+        } => {
+            if !(1..=4).contains(digest_type) {
+                return Err(invalid(
+                    "digest_type",
+                    format!("DS digest type 须为 1-4，当前为 {digest_type}"),
+                ));
+            }
+            if !is_hex(digest) {
+                return Err(invalid("digest", "DS 摘要须为十六进制字符串".to_string()));
+            }
+        }
+        _ => {}
+    }
+    Ok(())
 }
 
 // ============ HMAC-SHA256 ============
@@ -86,31 +242,223 @@ pub fn normalize_domain_name(name: &str) -> String {
     name.trim_end_matches('.').to_string()
 }
 
-/// 将完整域名转换为相对名称
-/// 如: "www.example.com" + "example.com" -> "www"
-/// 如: "example.com" + "example.com" -> "@"
-pub fn full_name_to_relative(full_name: &str, zone_name: &str) -> String {
-    let full = normalize_domain_name(full_name);
-    let zone = normalize_domain_name(zone_name);
+/// 规范化记录名称
+///
+/// 所有 Provider 返回的 `DnsRecord::name` 都使用此形式：相对于区域、小写、无末尾点，
+/// 根域为 `@`，泛域名保留 `*`（如 `*`、`*.dev`）。
+/// 输入可以是空字符串、`@`、相对名称或完整域名（可带末尾点）。
+/// 如: "" / "@" / "Example.com." + "example.com" -> "@"
+/// 如: "*.example.com" + "example.com" -> "*"
+pub fn canonical_record_name(name: &str, zone_name: &str) -> String {
+    let name = normalize_domain_name(name.trim()).to_lowercase();
+    let zone = normalize_domain_name(zone_name).to_lowercase();
 
-    if full == zone {
+    if name.is_empty() || name == "@" || name == zone {
         "@".to_string()
-    } else if let Some(subdomain) = full.strip_suffix(&format!(".{zone}")) {
+    } else if let Some(subdomain) = name.strip_suffix(&format!(".{zone}")) {
         subdomain.to_string()
     } else {
-        full
+        name
     }
 }
 
+/// 将完整域名转换为相对名称（规范形式，见 [`canonical_record_name`]）
+/// 如: "www.example.com" + "example.com" -> "www"
+/// 如: "example.com" + "example.com" -> "@"
+pub fn full_name_to_relative(full_name: &str, zone_name: &str) -> String {
+    canonical_record_name(full_name, zone_name)
+}
+
 /// 将相对名称转换为完整域名
 /// 如: "www" + "example.com" -> "www.example.com"
 /// 如: "@" + "example.com" -> "example.com"
+///
+/// 已是完整域名的输入不会重复拼接区域名。
 pub fn relative_to_full_name(relative_name: &str, zone_name: &str) -> String {
     let zone = normalize_domain_name(zone_name);
 
-    if relative_name == "@" || relative_name.is_empty() {
-        zone
-    } else {
-        format!("{relative_name}.{zone}")
+    match canonical_record_name(relative_name, &zone).as_str() {
+        "@" => zone,
+        relative => format!("{relative}.{zone}"),
+    }
+}
+
+/// 将规范记录名称转换为以空字符串表示根域的相对名称（deSEC `subname` 等）
+pub fn canonical_to_subname(name: &str, zone_name: &str) -> String {
+    match canonical_record_name(name, zone_name).as_str() {
+        "@" => String::new(),
+        relative => relative.to_string(),
     }
 }
+
+// ============ 记录集（RRset）处理 ============
+//
+// 供以记录集为单位管理记录（如 route53/gcloud）或整体替换记录（如 namecheap）的 provider 使用：
+// 这类 API 没有稳定的单条记录 ID，记录 ID 由名称、类型与值编码而成。
+
+/// 编码记录 ID：完整名称、类型与值以换行分隔后十六进制编码
+pub fn encode_rrset_record_id(full_name: &str, record_type: &str, value: &str) -> String {
+    hex::encode(format!("{full_name}\n{record_type}\n{value}"))
+}
+
+/// 解码记录 ID，返回 (完整名称, 类型, 值)
+pub fn decode_rrset_record_id(record_id: &str) -> Option<(String, String, String)> {
+    let decoded = String::from_utf8(hex::decode(record_id).ok()?).ok()?;
+    let mut parts = decoded.splitn(3, '\n');
+    Some((
+        parts.next()?.to_string(),
+        parts.next()?.to_string(),
+        parts.next()?.to_string(),
+    ))
+}
+
+/// TXT 单个字符串的最大字节数
+const TXT_CHUNK_SIZE: usize = 255;
+
+/// 解析带引号的 TXT 值：`"part1" "part2"` -> `part1part2`
+pub fn parse_quoted_txt(value: &str) -> String {
+    if !value.starts_with('"') {
+        return value.to_string();
+    }
+
+    let mut text = String::new();
+    let mut in_quotes = false;
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '\\' if in_quotes => {
+                if let Some(next) = chars.next() {
+                    text.push(next);
+                }
+            }
+            _ if in_quotes => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// 将单个字符串加上引号并转义（不拆分，如 CAA 的 value）
+pub fn quote_string(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        if matches!(c, '"' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// 将 TXT 文本转换为带引号的格式（超过 255 字节时在 UTF-8 字符边界处拆分为多个字符串）
+pub fn format_quoted_txt(text: &str) -> String {
+    if text.is_empty() {
+        return "\"\"".to_string();
+    }
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    for (index, c) in text.char_indices() {
+        if index + c.len_utf8() - start > TXT_CHUNK_SIZE {
+            chunks.push(quote_string(&text[start..index]));
+            start = index;
+        }
+    }
+    chunks.push(quote_string(&text[start..]));
+    chunks.join(" ")
+}
+
+// ============ TLSA / SSHFP / DS ============
+
+/// 解析 TLSA 记录值（`usage selector matching_type hex`，证书数据可能按空格分段）
+pub fn parse_tlsa_value(value: &str, provider: &str) -> Result<RecordData> {
+    let invalid = || ProviderError::ParseError {
+        provider: provider.to_string(),
+        detail: format!("Invalid TLSA record format: '{value}'"),
+    };
+
+    let mut parts = value.split_whitespace();
+    let mut next_u8 = || {
+        parts
+            .next()
+            .and_then(|p| p.parse::<u8>().ok())
+            .ok_or_else(invalid)
+    };
+    let usage = next_u8()?;
+    let selector = next_u8()?;
+    let matching_type = next_u8()?;
+    let certificate_association = parts.collect::<String>().to_lowercase();
+    if certificate_association.is_empty() {
+        return Err(invalid());
+    }
+
+    Ok(RecordData::TLSA {
+        usage,
+        selector,
+        matching_type,
+        certificate_association,
+    })
+}
+
+/// 解析 SSHFP 记录值（`algorithm fingerprint_type hex`）
+pub fn parse_sshfp_value(value: &str, provider: &str) -> Result<RecordData> {
+    let invalid = || ProviderError::ParseError {
+        provider: provider.to_string(),
+        detail: format!("Invalid SSHFP record format: '{value}'"),
+    };
+
+    let mut parts = value.split_whitespace();
+    let mut next_u8 = || {
+        parts
+            .next()
+            .and_then(|p| p.parse::<u8>().ok())
+            .ok_or_else(invalid)
+    };
+    let algorithm = next_u8()?;
+    let fingerprint_type = next_u8()?;
+    let fingerprint = parts.collect::<String>().to_lowercase();
+    if fingerprint.is_empty() {
+        return Err(invalid());
+    }
+
+    Ok(RecordData::SSHFP {
+        algorithm,
+        fingerprint_type,
+        fingerprint,
+    })
+}
+
+/// 解析 DS 记录值（`key_tag algorithm digest_type hex`，摘要可能按空格分段）
+pub fn parse_ds_value(value: &str, provider: &str) -> Result<RecordData> {
+    let invalid = || ProviderError::ParseError {
+        provider: provider.to_string(),
+        detail: format!("Invalid DS record format: '{value}'"),
+    };
+
+    let mut parts = value.split_whitespace();
+    let key_tag = parts
+        .next()
+        .and_then(|p| p.parse::<u16>().ok())
+        .ok_or_else(invalid)?;
+    let mut next_u8 = || {
+        parts
+            .next()
+            .and_then(|p| p.parse::<u8>().ok())
+            .ok_or_else(invalid)
+    };
+    let algorithm = next_u8()?;
+    let digest_type = next_u8()?;
+    let digest = parts.collect::<String>().to_lowercase();
+    if digest.is_empty() {
+        return Err(invalid());
+    }
+
+    Ok(RecordData::DS {
+        key_tag,
+        algorithm,
+        digest_type,
+        digest,
+    })
+}
@@ -0,0 +1,212 @@
+//! CSV 导入导出
+//!
+//! 列格式：`name,type,ttl,value,priority,proxied`。
+//! - MX：`priority` 列为优先级，`value` 为邮件服务器
+//! - SRV：`priority` 列为优先级，`value` 为 `weight port target`
+//! - CAA：`value` 为 `flags tag value`
+//! - TLSA / SSHFP / DS：`value` 为标准的空格分隔格式
+
+use crate::error::{ProviderError, Result};
+use crate::providers::common::{
+    parse_ds_value, parse_record_type, parse_sshfp_value, parse_tlsa_value, record_type_to_string,
+};
+use crate::types::{CreateDnsRecordRequest, DnsRecord, DnsRecordType, RecordData};
+
+/// CSV 表头
+const HEADER: [&str; 6] = ["name", "type", "ttl", "value", "priority", "proxied"];
+
+/// 错误信息中的来源标识
+const SOURCE: &str = "csv";
+
+fn parse_error(line: u64, detail: impl std::fmt::Display) -> ProviderError {
+    ProviderError::ParseError {
+        provider: SOURCE.to_string(),
+        detail: format!("第 {line} 行: {detail}"),
+    }
+}
+
+/// 将记录导出为 CSV 文本（含表头）
+pub fn records_to_csv(records: &[DnsRecord]) -> Result<String> {
+    let mut writer = ::csv::Writer::from_writer(Vec::new());
+    let serialization_error = |e: ::csv::Error| ProviderError::SerializationError {
+        provider: SOURCE.to_string(),
+        detail: e.to_string(),
+    };
+
+    writer.write_record(HEADER).map_err(serialization_error)?;
+    for record in records {
+        let (value, priority) = split_record_data(&record.data);
+        writer
+            .write_record([
+                record.name.as_str(),
+                record_type_to_string(&record.data.record_type()),
+                &record.ttl.to_string(),
+                &value,
+                &priority.map(|p| p.to_string()).unwrap_or_default(),
+                &record.proxied.map(|p| p.to_string()).unwrap_or_default(),
+            ])
+            .map_err(serialization_error)?;
+    }
+
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| serialization_error(e.into_error().into()))?;
+    String::from_utf8(bytes).map_err(|e| ProviderError::SerializationError {
+        provider: SOURCE.to_string(),
+        detail: e.to_string(),
+    })
+}
+
+/// 从 CSV 文本（需含表头）解析创建记录请求
+///
+/// 列按表头名称匹配，顺序不限；`priority`、`proxied` 列可省略。
+/// 名称为空时视为根域 `@`。
+pub fn records_from_csv(contents: &str, domain_id: &str) -> Result<Vec<CreateDnsRecordRequest>> {
+    let mut reader = ::csv::ReaderBuilder::new()
+        .trim(::csv::Trim::All)
+        .from_reader(contents.as_bytes());
+    let csv_error = |e: ::csv::Error| {
+        let line = e.position().map_or(0, ::csv::Position::line);
+        parse_error(line, e)
+    };
+
+    let headers = reader.headers().map_err(csv_error)?.clone();
+    let column = |name: &str| headers.iter().position(|h| h.eq_ignore_ascii_case(name));
+    let required =
+        |name: &str| column(name).ok_or_else(|| parse_error(1, format!("缺少 {name} 列")));
+    let name_col = required("name")?;
+    let type_col = required("type")?;
+    let ttl_col = required("ttl")?;
+    let value_col = required("value")?;
+    let priority_col = column("priority");
+    let proxied_col = column("proxied");
+
+    let mut requests = Vec::new();
+    for row in reader.records() {
+        let row = row.map_err(csv_error)?;
+        let line = row.position().map_or(0, ::csv::Position::line);
+        let field = |index: usize| row.get(index).unwrap_or_default();
+        let optional = |index: Option<usize>| index.map(field).filter(|v| !v.is_empty());
+
+        let record_type = parse_record_type(field(type_col), SOURCE)
+            .map_err(|_| parse_error(line, format!("不支持的记录类型: {}", field(type_col))))?;
+        let ttl = field(ttl_col)
+            .parse()
+            .map_err(|_| parse_error(line, format!("无效的 TTL: {}", field(ttl_col))))?;
+        let priority = optional(priority_col)
+            .map(|p| {
+                p.parse::<u16>()
+                    .map_err(|_| parse_error(line, format!("无效的 priority: {p}")))
+            })
+            .transpose()?;
+        let proxied = optional(proxied_col)
+            .map(|p| match p.to_lowercase().as_str() {
+                "true" | "yes" | "1" => Ok(true),
+                "false" | "no" | "0" => Ok(false),
+                _ => Err(parse_error(line, format!("无效的 proxied: {p}"))),
+            })
+            .transpose()?;
+
+        let data = build_record_data(&record_type, field(value_col), priority)
+            .map_err(|detail| parse_error(line, detail))?;
+
+        let name = field(name_col);
+        requests.push(CreateDnsRecordRequest {
+            domain_id: domain_id.to_string(),
+            name: if name.is_empty() { "@" } else { name }.to_string(),
+            ttl,
+            data,
+            proxied,
+            comment: None,
+            tags: Vec::new(),
+            routing_policy: None,
+        });
+    }
+
+    Ok(requests)
+}
+
+/// 将记录数据拆分为 `value` 与 `priority` 两列
+fn split_record_data(data: &RecordData) -> (String, Option<u16>) {
+    match data {
+        RecordData::MX { priority, exchange } => (exchange.clone(), Some(*priority)),
+        RecordData::SRV {
+            priority,
+            weight,
+            port,
+            target,
+        } => (format!("{weight} {port} {target}"), Some(*priority)),
+        RecordData::CAA { flags, tag, value } => (format!("{flags} {tag} {value}"), None),
+        _ => (data.display_value(), None),
+    }
+}
+
+/// 由 `value` 与 `priority` 列还原记录数据
+fn build_record_data(
+    record_type: &DnsRecordType,
+    value: &str,
+    priority: Option<u16>,
+) -> std::result::Result<RecordData, String> {
+    if value.is_empty() {
+        return Err("value 不能为空".to_string());
+    }
+    let require_priority = || priority.ok_or_else(|| "缺少 priority".to_string());
+    let parse_u16 =
+        |field: &str, v: &str| v.parse::<u16>().map_err(|_| format!("无效的 {field}: {v}"));
+
+    let data = match record_type {
+        DnsRecordType::A => RecordData::A {
+            address: value.to_string(),
+        },
+        DnsRecordType::Aaaa => RecordData::AAAA {
+            address: value.to_string(),
+        },
+        DnsRecordType::Cname => RecordData::CNAME {
+            target: value.to_string(),
+        },
+        DnsRecordType::Alias => RecordData::ALIAS {
+            target: value.to_string(),
+        },
+        DnsRecordType::Ns => RecordData::NS {
+            nameserver: value.to_string(),
+        },
+        DnsRecordType::Txt => RecordData::TXT {
+            text: value.to_string(),
+        },
+        DnsRecordType::Mx => RecordData::MX {
+            priority: require_priority()?,
+            exchange: value.to_string(),
+        },
+        DnsRecordType::Srv => {
+            let parts: Vec<&str> = value.split_whitespace().collect();
+            let [weight, port, target] = parts[..] else {
+                return Err(format!("SRV value 应为 `weight port target`: {value}"));
+            };
+            RecordData::SRV {
+                priority: require_priority()?,
+                weight: parse_u16("weight", weight)?,
+                port: parse_u16("port", port)?,
+                target: target.to_string(),
+            }
+        }
+        DnsRecordType::Caa => {
+            let mut parts = value.splitn(3, char::is_whitespace);
+            let (Some(flags), Some(tag), Some(caa_value)) =
+                (parts.next(), parts.next(), parts.next())
+            else {
+                return Err(format!("CAA value 应为 `flags tag value`: {value}"));
+            };
+            RecordData::CAA {
+                flags: flags
+                    .parse()
+                    .map_err(|_| format!("无效的 flags: {flags}"))?,
+                tag: tag.to_string(),
+                value: caa_value.trim().to_string(),
+            }
+        }
+        DnsRecordType::Tlsa => parse_tlsa_value(value, SOURCE).map_err(|e| e.to_string())?,
+        DnsRecordType::Sshfp => parse_sshfp_value(value, SOURCE).map_err(|e| e.to_string())?,
+        DnsRecordType::Ds => parse_ds_value(value, SOURCE).map_err(|e| e.to_string())?,
+    };
+    Ok(data)
+}
@@ -0,0 +1,74 @@
+//! deSEC 错误映射
+//!
+//! 参考: <https://desec.readthedocs.io/en/latest/dns/rrsets.html>
+//!
+//! deSEC 没有业务错误码，按 HTTP 状态码映射。
+
+use crate::error::ProviderError;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::DesecProvider;
+
+/// deSEC 错误码映射实现
+impl ProviderErrorMapper for DesecProvider {
+    fn provider_name(&self) -> &'static str {
+        "desec"
+    }
+
+    fn map_error(&self, raw: RawApiError, context: ErrorContext) -> ProviderError {
+        match raw.code.as_deref() {
+            // ============ 认证错误 ============
+            Some("401") => ProviderError::InvalidCredentials {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 权限拒绝 ============
+            Some("403") => ProviderError::PermissionDenied {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 资源不存在 ============
+            Some("404") => {
+                if context.record_id.is_some() {
+                    ProviderError::RecordNotFound {
+                        provider: self.provider_name().to_string(),
+                        record_id: context.record_id.unwrap_or_default(),
+                        raw_message: Some(raw.message),
+                    }
+                } else {
+                    ProviderError::DomainNotFound {
+                        provider: self.provider_name().to_string(),
+                        domain: context.domain.unwrap_or_default(),
+                        raw_message: Some(raw.message),
+                    }
+                }
+            }
+
+            // ============ 记录已存在 ============
+            Some("409") => ProviderError::RecordExists {
+                provider: self.provider_name().to_string(),
+                record_name: context.record_name.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 参数无效 ============
+            // 包括 TTL 低于域名的 minimum_ttl、记录值格式错误等
+            Some("400" | "415") => ProviderError::InvalidParameter {
+                provider: self.provider_name().to_string(),
+                param: "input".to_string(),
+                detail: raw.message,
+            },
+
+            // ============ 服务端错误 ============
+            Some("500" | "502" | "503" | "504") => ProviderError::NetworkError {
+                provider: self.provider_name().to_string(),
+                detail: raw.message,
+            },
+
+            // ============ 其他错误 fallback ============
+            _ => self.unknown_error(raw),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! deSEC HTTP 请求方法
+
+use reqwest::header::LINK;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::error::Result;
+use crate::http_client::{HttpResponse, HttpUtils};
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::types::RRsetPatch;
+use super::{DESEC_API_BASE, DesecProvider};
+
+/// 提取错误响应中的全部错误信息
+///
+/// deSEC 的错误体可能是 `{"detail": "..."}`，也可能是按字段（批量时按记录集）组织的错误列表
+fn collect_messages(value: &serde_json::Value, out: &mut Vec<String>) {
+    match value {
+        serde_json::Value::String(s) => out.push(s.clone()),
+        serde_json::Value::Array(items) => items.iter().for_each(|v| collect_messages(v, out)),
+        serde_json::Value::Object(map) => map.values().for_each(|v| collect_messages(v, out)),
+        _ => {}
+    }
+}
+
+/// 从 `Link` 响应头中解析下一页地址
+fn next_page_url(response: &HttpResponse) -> Option<String> {
+    let link = response.headers.get(LINK)?.to_str().ok()?;
+    link.split(',').find_map(|part| {
+        let (url, params) = part.split_once(';')?;
+        params
+            .split(';')
+            .any(|p| p.trim() == "rel=\"next\"")
+            .then(|| {
+                url.trim()
+                    .trim_start_matches('<')
+                    .trim_end_matches('>')
+                    .to_string()
+            })
+    })
+}
+
+impl DesecProvider {
+    // ==================== 辅助方法 ====================
+
+    /// 统一处理 deSEC 响应错误，使用 HTTP 状态码作为错误码
+    fn handle_response_error(&self, response: &HttpResponse, ctx: ErrorContext) -> Result<()> {
+        if (200..300).contains(&response.status) {
+            return Ok(());
+        }
+
+        let mut messages = Vec::new();
+        if let Ok(value) = serde_json::from_str::<serde_json::Value>(&response.body) {
+            collect_messages(&value, &mut messages);
+        }
+        let message = if messages.is_empty() {
+            format!("HTTP {}: {}", response.status, response.body)
+        } else {
+            messages.join("; ")
+        };
+        Err(self.map_error(
+            RawApiError::with_code(response.status.to_string(), message)
+                .with_status(response.status),
+            ctx,
+        ))
+    }
+
+    /// 执行请求（完整 URL），返回完整响应
+    async fn request<B: Serialize + ?Sized>(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        body: Option<&B>,
+        ctx: ErrorContext,
+    ) -> Result<HttpResponse> {
+        let mut request = self
+            .client
+            .request(method.clone(), url)
+            .header("Authorization", format!("Token {}", self.token));
+        if let Some(body) = body {
+            if log::log_enabled!(log::Level::Debug) {
+                let body_json = serde_json::to_string_pretty(body)
+                    .unwrap_or_else(|_| "无法序列化请求体".to_string());
+                log::debug!("Request Body: {body_json}");
+            }
+            request = request.json(body);
+        }
+
+        let response = HttpUtils::execute_request_full_with_retry(
+            request,
+            self.provider_name(),
+            method.as_str(),
+            url,
+            self.max_retries,
+        )
+        .await?;
+
+        self.handle_response_error(&response, ctx)?;
+        Ok(response)
+    }
+
+    // ==================== 公开 API 方法 ====================
+
+    /// 执行 GET 请求
+    pub(crate) async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        let url = format!("{DESEC_API_BASE}{path}");
+        let response = self
+            .request::<()>(reqwest::Method::GET, &url, None, ctx)
+            .await?;
+        HttpUtils::parse_json(&response.body, self.provider_name())
+    }
+
+    /// 执行 GET 请求并按 `Link` 头中的 cursor 拉取全部页
+    ///
+    /// `path` 中已有查询参数时需以 `&` 结尾，否则以 `?` 结尾
+    pub(crate) async fn get_all_pages<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        ctx: ErrorContext,
+    ) -> Result<Vec<T>> {
+        // 传入空 cursor 以显式启用分页，避免结果过多时返回 400
+        let mut url = format!("{DESEC_API_BASE}{path}cursor=");
+        let mut items = Vec::new();
+
+        loop {
+            let response = self
+                .request::<()>(reqwest::Method::GET, &url, None, ctx.clone())
+                .await?;
+            let page: Vec<T> = HttpUtils::parse_json(&response.body, self.provider_name())?;
+            items.extend(page);
+
+            match next_page_url(&response) {
+                Some(next) if next != url => url = next,
+                _ => break,
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// 批量修改记录集（原子提交）
+    pub(crate) async fn patch_rrsets(
+        &self,
+        domain: &str,
+        rrsets: &[RRsetPatch],
+        ctx: ErrorContext,
+    ) -> Result<()> {
+        let url = format!(
+            "{DESEC_API_BASE}/domains/{}/rrsets/",
+            urlencoding::encode(domain)
+        );
+        self.request(reqwest::Method::PATCH, &url, Some(rrsets), ctx)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+//! deSEC DNS Provider
+//!
+//! deSEC 以记录集（RRset，名称 + 类型共享一个 TTL）为单位管理记录，映射规则：
+//! - 记录 ID 由「完整名称 + 类型 + 值」编码而成（见 `common::encode_rrset_record_id`）；
+//! - 创建：向记录集追加一个值，记录集不存在时创建；
+//! - 删除：从记录集移除一个值，移除后为空则删除整个记录集；
+//! - 更新：移除旧值并写入新值，通过批量 PATCH 在一次请求中原子提交；
+//! - 记录集的 TTL 为共享值，写入时整个记录集的 TTL 会更新为请求值。
+
+mod error;
+mod http;
+mod provider;
+mod types;
+
+use reqwest::Client;
+
+use crate::http_client::ProxyConfig;
+use crate::providers::common::create_http_client;
+use crate::traits::DEFAULT_BATCH_CONCURRENCY;
+use crate::types::DnsRecordType;
+
+pub(crate) const DESEC_API_BASE: &str = "https://desec.io/api/v1";
+/// deSEC 记录集单页最大数量（超过时必须使用 cursor 翻页）
+pub(crate) const MAX_PAGE_SIZE: u32 = 500;
+/// 无原生支持的记录类型
+pub(crate) const UNSUPPORTED_RECORD_TYPES: &[DnsRecordType] = &[DnsRecordType::Alias];
+
+/// deSEC DNS Provider
+pub struct DesecProvider {
+    pub(crate) client: Client,
+    pub(crate) token: String,
+    pub(crate) max_retries: u32,
+    pub(crate) batch_concurrency: usize,
+}
+
+/// deSEC DNS Provider Builder
+pub struct DesecProviderBuilder {
+    token: String,
+    max_retries: u32,
+    proxy: Option<ProxyConfig>,
+    batch_concurrency: usize,
+}
+
+impl DesecProviderBuilder {
+    fn new(token: String) -> Self {
+        Self {
+            token,
+            max_retries: 2,
+            proxy: None,
+            batch_concurrency: DEFAULT_BATCH_CONCURRENCY,
+        }
+    }
+
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// 设置 HTTP 代理
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// 设置批量操作的最大并发请求数（默认 5）
+    pub fn batch_concurrency(mut self, concurrency: usize) -> Self {
+        self.batch_concurrency = concurrency.max(1);
+        self
+    }
+
+    pub fn build(self) -> DesecProvider {
+        DesecProvider {
+            client: create_http_client(self.proxy.as_ref()),
+            token: self.token,
+            max_retries: self.max_retries,
+            batch_concurrency: self.batch_concurrency,
+        }
+    }
+}
+
+impl DesecProvider {
+    pub fn new(token: String) -> Self {
+        Self::builder(token).build()
+    }
+
+    pub fn builder(token: String) -> DesecProviderBuilder {
+        DesecProviderBuilder::new(token)
+    }
+}
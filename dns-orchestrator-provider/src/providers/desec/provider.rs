@@ -0,0 +1,577 @@
+//! deSEC DnsProvider trait 实现
+
+use async_trait::async_trait;
+
+use crate::error::{ProviderError, Result};
+use crate::providers::common::{
+    canonical_record_name, canonical_to_subname, decode_rrset_record_id, encode_rrset_record_id,
+    ensure_not_proxied, ensure_record_type_supported, ensure_simple_routing, format_quoted_txt,
+    full_name_to_relative, normalize_domain_name, parse_ds_value, parse_quoted_txt,
+    parse_sshfp_value, parse_tlsa_value, record_type_to_string, relative_to_full_name,
+    validate_record_data,
+};
+use crate::traits::{DnsProvider, ErrorContext, ProviderErrorMapper};
+use crate::types::{
+    CreateDnsRecordRequest, DnsRecord, DomainStatus, FieldType, PaginatedResponse,
+    PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures, ProviderLimits,
+    ProviderMetadata, ProviderType, RecordData, RecordQueryParams, UpdateDnsRecordRequest,
+};
+
+use super::types::{DesecDomain, RRset, RRsetPatch};
+use super::{DesecProvider, MAX_PAGE_SIZE, UNSUPPORTED_RECORD_TYPES};
+
+/// 记录集中的单个值（记录 ID 的解码结果）
+struct RecordKey {
+    /// 完整名称（不带末尾点）
+    name: String,
+    record_type: String,
+    /// 规范化后的记录值（见 `record_data_to_value`）
+    value: String,
+}
+
+impl DesecProvider {
+    /// 解码记录 ID
+    fn decode_record_id(&self, record_id: &str) -> Result<RecordKey> {
+        let (name, record_type, value) =
+            decode_rrset_record_id(record_id).ok_or_else(|| ProviderError::InvalidParameter {
+                provider: self.provider_name().to_string(),
+                param: "record_id".to_string(),
+                detail: format!("Invalid deSEC record id: '{record_id}'"),
+            })?;
+        Ok(RecordKey {
+            name,
+            record_type,
+            value,
+        })
+    }
+
+    /// 解析 deSEC 记录值为 RecordData
+    fn parse_record_data(&self, record_type: &str, value: &str) -> Result<RecordData> {
+        let parse_error = |detail: String| ProviderError::ParseError {
+            provider: self.provider_name().to_string(),
+            detail,
+        };
+
+        match record_type {
+            "A" => Ok(RecordData::A {
+                address: value.to_string(),
+            }),
+            "AAAA" => Ok(RecordData::AAAA {
+                address: value.to_string(),
+            }),
+            "CNAME" => Ok(RecordData::CNAME {
+                target: normalize_domain_name(value),
+            }),
+            "NS" => Ok(RecordData::NS {
+                nameserver: normalize_domain_name(value),
+            }),
+            "TXT" => Ok(RecordData::TXT {
+                text: parse_quoted_txt(value),
+            }),
+            "MX" => {
+                // deSEC MX 格式: "priority exchange."
+                let parts: Vec<&str> = value.splitn(2, ' ').collect();
+                if parts.len() == 2 {
+                    Ok(RecordData::MX {
+                        priority: parts[0].parse().map_err(|_| {
+                            parse_error(format!("Invalid MX priority: '{}'", parts[0]))
+                        })?,
+                        exchange: normalize_domain_name(parts[1]),
+                    })
+                } else {
+                    Err(parse_error(format!(
+                        "Invalid MX record format: expected 'priority exchange', got '{value}'"
+                    )))
+                }
+            }
+            "SRV" => {
+                // deSEC SRV 格式: "priority weight port target."
+                let parts: Vec<&str> = value.splitn(4, ' ').collect();
+                if parts.len() == 4 {
+                    Ok(RecordData::SRV {
+                        priority: parts[0].parse().map_err(|_| {
+                            parse_error(format!("Invalid SRV priority: '{}'", parts[0]))
+                        })?,
+                        weight: parts[1].parse().map_err(|_| {
+                            parse_error(format!("Invalid SRV weight: '{}'", parts[1]))
+                        })?,
+                        port: parts[2].parse().map_err(|_| {
+                            parse_error(format!("Invalid SRV port: '{}'", parts[2]))
+                        })?,
+                        target: normalize_domain_name(parts[3]),
+                    })
+                } else {
+                    Err(parse_error(format!(
+                        "Invalid SRV record format: expected 'priority weight port target', got '{value}'"
+                    )))
+                }
+            }
+            "CAA" => {
+                // deSEC CAA 格式: flags tag "value"
+                let parts: Vec<&str> = value.splitn(3, ' ').collect();
+                if parts.len() == 3 {
+                    Ok(RecordData::CAA {
+                        flags: parts[0].parse().map_err(|_| {
+                            parse_error(format!("Invalid CAA flags: '{}'", parts[0]))
+                        })?,
+                        tag: parts[1].to_string(),
+                        value: parts[2].trim_matches('"').to_string(),
+                    })
+                } else {
+                    Err(parse_error(format!(
+                        "Invalid CAA record format: expected 'flags tag value', got '{value}'"
+                    )))
+                }
+            }
+            "TLSA" => parse_tlsa_value(value, self.provider_name()),
+            "SSHFP" => parse_sshfp_value(value, self.provider_name()),
+            "DS" => parse_ds_value(value, self.provider_name()),
+            _ => Err(ProviderError::UnsupportedRecordType {
+                provider: self.provider_name().to_string(),
+                record_type: record_type.to_string(),
+            }),
+        }
+    }
+
+    /// 将 RecordData 转换为 deSEC 记录值（目标域名需带末尾点）
+    fn record_data_to_value(data: &RecordData) -> String {
+        let fqdn = |name: &str| format!("{}.", normalize_domain_name(name));
+        match data {
+            RecordData::A { address } | RecordData::AAAA { address } => address.clone(),
+            RecordData::CNAME { target } | RecordData::ALIAS { target } => fqdn(target),
+            RecordData::MX { priority, exchange } => format!("{priority} {}", fqdn(exchange)),
+            RecordData::TXT { text } => format_quoted_txt(text),
+            RecordData::NS { nameserver } => fqdn(nameserver),
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => format!("{priority} {weight} {port} {}", fqdn(target)),
+            RecordData::CAA { flags, tag, value } => format!("{flags} {tag} \"{value}\""),
+            RecordData::TLSA { .. } | RecordData::SSHFP { .. } | RecordData::DS { .. } => {
+                data.display_value()
+            }
+        }
+    }
+
+    /// 规范化记录集中的值
+    ///
+    /// deSEC 会改写提交的记录值（如 IPv6 压缩、TXT 分段），
+    /// 经 RecordData 往返一次后再比较，保证记录 ID 稳定
+    fn canonical_values(&self, rrset: &RRset) -> Vec<String> {
+        rrset
+            .records
+            .iter()
+            .map(|value| {
+                self.parse_record_data(&rrset.record_type, value)
+                    .map(|data| Self::record_data_to_value(&data))
+                    .unwrap_or_else(|_| value.clone())
+            })
+            .collect()
+    }
+
+    /// 完整名称转换为 deSEC 的 subname（根域为空字符串）
+    fn subname(full_name: &str, domain: &str) -> String {
+        canonical_to_subname(full_name, domain)
+    }
+
+    fn parse_timestamp(value: Option<&str>) -> Option<chrono::DateTime<chrono::Utc>> {
+        value.and_then(|s| {
+            chrono::DateTime::parse_from_rfc3339(s)
+                .ok()
+                .map(|dt| dt.with_timezone(&chrono::Utc))
+        })
+    }
+
+    fn domain_to_provider_domain(domain: DesecDomain) -> ProviderDomain {
+        ProviderDomain {
+            id: domain.name.clone(),
+            name: domain.name,
+            provider: ProviderType::DeSEC,
+            // 尚未发布到名称服务器的域名视为未生效
+            status: if domain.published.is_some() {
+                DomainStatus::Active
+            } else {
+                DomainStatus::Pending
+            },
+            record_count: None,
+        }
+    }
+
+    /// 查找指定名称与类型的记录集
+    async fn find_record_set(
+        &self,
+        domain: &str,
+        full_name: &str,
+        record_type: &str,
+    ) -> Result<Option<RRset>> {
+        let subname = Self::subname(full_name, domain);
+        let path = format!(
+            "/domains/{}/rrsets/?subname={}&type={}",
+            urlencoding::encode(domain),
+            urlencoding::encode(&subname),
+            urlencoding::encode(record_type)
+        );
+        let ctx = ErrorContext {
+            domain: Some(domain.to_string()),
+            ..Default::default()
+        };
+
+        let rrsets: Vec<RRset> = self.get(&path, ctx).await?;
+        Ok(rrsets
+            .into_iter()
+            .find(|rrset| rrset.subname == subname && rrset.record_type == record_type))
+    }
+
+    /// 构造记录集修改项
+    fn rrset_patch(
+        full_name: &str,
+        domain: &str,
+        record_type: &str,
+        ttl: u32,
+        records: Vec<String>,
+    ) -> RRsetPatch {
+        RRsetPatch {
+            subname: Self::subname(full_name, domain),
+            record_type: record_type.to_string(),
+            ttl,
+            records,
+        }
+    }
+}
+
+#[async_trait]
+impl DnsProvider for DesecProvider {
+    fn id(&self) -> &'static str {
+        "desec"
+    }
+
+    fn batch_concurrency(&self) -> usize {
+        self.batch_concurrency
+    }
+
+    fn metadata() -> ProviderMetadata {
+        ProviderMetadata {
+            id: ProviderType::DeSEC,
+            name: "deSEC".to_string(),
+            description: "deSEC 免费 DNSSEC 托管服务".to_string(),
+            required_fields: vec![ProviderCredentialField {
+                key: "token".to_string(),
+                label: "API Token".to_string(),
+                field_type: FieldType::Password,
+                placeholder: Some("输入 deSEC API Token".to_string()),
+                help_text: None,
+            }],
+            features: ProviderFeatures::default(),
+            limits: ProviderLimits {
+                max_page_size_domains: MAX_PAGE_SIZE,
+                max_page_size_records: MAX_PAGE_SIZE,
+                requests_per_second: Some(2.0), // 写操作限制
+                max_batch_size: 1,
+                max_records_per_zone: None,
+                // 默认最小 TTL 为 3600 秒
+                min_ttl: 3600,
+                max_ttl: 86_400,
+                auto_ttl: None,
+            },
+        }
+    }
+
+    async fn validate_credentials(&self) -> Result<bool> {
+        match self
+            .get::<Vec<DesecDomain>>("/domains/", ErrorContext::default())
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(ProviderError::InvalidCredentials { .. }) => Ok(false),
+            Err(e) => {
+                log::warn!("凭证验证失败: {e}");
+                Ok(false)
+            }
+        }
+    }
+
+    /// 拉取全部域名后在内存中分页
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> Result<PaginatedResponse<ProviderDomain>> {
+        let domains: Vec<DesecDomain> = self
+            .get_all_pages("/domains/?", ErrorContext::default())
+            .await?;
+        let total_count = domains.len() as u32;
+
+        let domains = domains
+            .into_iter()
+            .skip(((params.page.max(1) - 1) * params.page_size) as usize)
+            .take(params.page_size as usize)
+            .map(Self::domain_to_provider_domain)
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            domains,
+            params.page,
+            params.page_size,
+            total_count,
+        ))
+    }
+
+    async fn get_domain(&self, domain_id: &str) -> Result<ProviderDomain> {
+        let path = format!("/domains/{}/", urlencoding::encode(domain_id));
+        let ctx = ErrorContext {
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let domain: DesecDomain = self.get(&path, ctx).await?;
+        Ok(Self::domain_to_provider_domain(domain))
+    }
+
+    /// 记录集按值展开后在内存中过滤与分页
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        let ctx = ErrorContext {
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let mut path = format!("/domains/{}/rrsets/?", urlencoding::encode(domain_id));
+        if let Some(ref record_type) = params.record_type {
+            path.push_str(&format!("type={}&", record_type_to_string(record_type)));
+        }
+        let record_sets: Vec<RRset> = self.get_all_pages(&path, ctx).await?;
+
+        let keyword = params
+            .keyword
+            .as_ref()
+            .filter(|k| !k.is_empty())
+            .map(|k| k.to_lowercase());
+
+        let mut records = Vec::new();
+        for rrset in &record_sets {
+            let name = canonical_record_name(&rrset.subname, domain_id);
+            let full_name = relative_to_full_name(&name, domain_id).to_lowercase();
+
+            for value in &rrset.records {
+                // 跳过不支持的类型（如 DNSKEY、DS、TLSA）
+                let Ok(data) = self.parse_record_data(&rrset.record_type, value) else {
+                    continue;
+                };
+
+                if let Some(ref kw) = keyword
+                    && !name.to_lowercase().contains(kw)
+                    && !data.display_value().to_lowercase().contains(kw)
+                {
+                    continue;
+                }
+
+                records.push(DnsRecord {
+                    id: encode_rrset_record_id(
+                        &full_name,
+                        &rrset.record_type,
+                        &Self::record_data_to_value(&data),
+                    ),
+                    domain_id: domain_id.to_string(),
+                    name: name.clone(),
+                    ttl: rrset.ttl,
+                    data,
+                    proxied: None,
+                    comment: None,
+                    tags: Vec::new(),
+                    created_at: Self::parse_timestamp(rrset.created.as_deref()),
+                    updated_at: Self::parse_timestamp(rrset.touched.as_deref()),
+                    routing_policy: None,
+                });
+            }
+        }
+
+        let total_count = records.len() as u32;
+        let records = records
+            .into_iter()
+            .skip(((params.page.max(1) - 1) * params.page_size) as usize)
+            .take(params.page_size as usize)
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            records,
+            params.page,
+            params.page_size,
+            total_count,
+        ))
+    }
+
+    /// 向记录集追加一个值（记录集不存在时创建），整个记录集的 TTL 会更新为请求值
+    async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        ensure_record_type_supported(&req.data, UNSUPPORTED_RECORD_TYPES, self.provider_name())?;
+        ensure_simple_routing(req.routing_policy.as_ref(), self.provider_name())?;
+        ensure_not_proxied(req.proxied, self.provider_name())?;
+        self.validate_ttl(req.ttl)?;
+        validate_record_data(&req.data, self.provider_name())?;
+        validate_record_data(&req.data, self.provider_name())?;
+        let full_name = relative_to_full_name(&req.name, &req.domain_id).to_lowercase();
+        let record_type = record_type_to_string(&req.data.record_type());
+        let value = Self::record_data_to_value(&req.data);
+
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            domain: Some(req.domain_id.clone()),
+            ..Default::default()
+        };
+
+        let mut values = match self
+            .find_record_set(&req.domain_id, &full_name, record_type)
+            .await?
+        {
+            Some(rrset) => self.canonical_values(&rrset),
+            None => Vec::new(),
+        };
+        if values.contains(&value) {
+            return Err(ProviderError::RecordExists {
+                provider: self.provider_name().to_string(),
+                record_name: req.name.clone(),
+                raw_message: None,
+            });
+        }
+        values.push(value.clone());
+
+        let patch = Self::rrset_patch(&full_name, &req.domain_id, record_type, req.ttl, values);
+        self.patch_rrsets(&req.domain_id, &[patch], ctx).await?;
+
+        Ok(DnsRecord {
+            id: encode_rrset_record_id(&full_name, record_type, &value),
+            domain_id: req.domain_id.clone(),
+            name: full_name_to_relative(&full_name, &req.domain_id),
+            ttl: req.ttl,
+            data: req.data.clone(),
+            proxied: None,
+            comment: None,
+            tags: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            routing_policy: None,
+        })
+    }
+
+    /// 在同一个批量 PATCH 中移除旧值并写入新值，保证原子性
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<DnsRecord> {
+        ensure_record_type_supported(&req.data, UNSUPPORTED_RECORD_TYPES, self.provider_name())?;
+        ensure_not_proxied(req.proxied, self.provider_name())?;
+        self.validate_ttl(req.ttl)?;
+        validate_record_data(&req.data, self.provider_name())?;
+        validate_record_data(&req.data, self.provider_name())?;
+        let old = self.decode_record_id(record_id)?;
+        let full_name = relative_to_full_name(&req.name, &req.domain_id).to_lowercase();
+        let record_type = record_type_to_string(&req.data.record_type());
+        let value = Self::record_data_to_value(&req.data);
+
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            record_id: Some(record_id.to_string()),
+            domain: Some(req.domain_id.clone()),
+        };
+        let not_found = || ProviderError::RecordNotFound {
+            provider: self.provider_name().to_string(),
+            record_id: record_id.to_string(),
+            raw_message: None,
+        };
+
+        let old_rrset = self
+            .find_record_set(&req.domain_id, &old.name, &old.record_type)
+            .await?
+            .ok_or_else(not_found)?;
+        let old_values = self.canonical_values(&old_rrset);
+        if !old_values.contains(&old.value) {
+            return Err(not_found());
+        }
+
+        let patches = if old.name == full_name && old.record_type == record_type {
+            // 同一记录集内替换值
+            let mut values: Vec<String> = old_values
+                .into_iter()
+                .filter(|v| *v != old.value && *v != value)
+                .collect();
+            values.push(value.clone());
+            vec![Self::rrset_patch(
+                &full_name,
+                &req.domain_id,
+                record_type,
+                req.ttl,
+                values,
+            )]
+        } else {
+            // 名称或类型变化：从旧记录集移除（为空时删除），并追加到新记录集
+            let remaining: Vec<String> =
+                old_values.into_iter().filter(|v| *v != old.value).collect();
+            let mut new_values = match self
+                .find_record_set(&req.domain_id, &full_name, record_type)
+                .await?
+            {
+                Some(rrset) => self.canonical_values(&rrset),
+                None => Vec::new(),
+            };
+            if !new_values.contains(&value) {
+                new_values.push(value.clone());
+            }
+            vec![
+                Self::rrset_patch(
+                    &old.name,
+                    &req.domain_id,
+                    &old.record_type,
+                    old_rrset.ttl,
+                    remaining,
+                ),
+                Self::rrset_patch(&full_name, &req.domain_id, record_type, req.ttl, new_values),
+            ]
+        };
+
+        self.patch_rrsets(&req.domain_id, &patches, ctx).await?;
+
+        Ok(DnsRecord {
+            id: encode_rrset_record_id(&full_name, record_type, &value),
+            domain_id: req.domain_id.clone(),
+            name: full_name_to_relative(&full_name, &req.domain_id),
+            ttl: req.ttl,
+            data: req.data.clone(),
+            proxied: None,
+            comment: None,
+            tags: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            routing_policy: None,
+        })
+    }
+
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
+        let key = self.decode_record_id(record_id)?;
+        let ctx = ErrorContext {
+            record_id: Some(record_id.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let not_found = || ProviderError::RecordNotFound {
+            provider: self.provider_name().to_string(),
+            record_id: record_id.to_string(),
+            raw_message: None,
+        };
+
+        let rrset = self
+            .find_record_set(domain_id, &key.name, &key.record_type)
+            .await?
+            .ok_or_else(not_found)?;
+        let values = self.canonical_values(&rrset);
+        if !values.contains(&key.value) {
+            return Err(not_found());
+        }
+
+        // 剩余值为空时 deSEC 会删除整个记录集
+        let remaining: Vec<String> = values.into_iter().filter(|v| *v != key.value).collect();
+        let patch = Self::rrset_patch(&key.name, domain_id, &key.record_type, rrset.ttl, remaining);
+        self.patch_rrsets(domain_id, &[patch], ctx).await
+    }
+}
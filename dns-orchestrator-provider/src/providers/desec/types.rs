@@ -0,0 +1,38 @@
+//! deSEC API 类型定义
+
+use serde::{Deserialize, Serialize};
+
+// ============ deSEC API 响应结构 ============
+
+#[derive(Debug, Deserialize)]
+pub struct DesecDomain {
+    pub name: String,
+    /// 最近一次发布到名称服务器的时间，未发布时为空
+    pub published: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RRset {
+    /// 相对名称，空字符串表示根域
+    pub subname: String,
+    #[serde(rename = "type")]
+    pub record_type: String,
+    pub ttl: u32,
+    /// 记录值（区域文件格式，目标域名带末尾点）
+    #[serde(default)]
+    pub records: Vec<String>,
+    pub created: Option<String>,
+    pub touched: Option<String>,
+}
+
+// ============ deSEC API 请求结构 ============
+
+/// 批量 PATCH 中的单个记录集，`records` 为空时删除记录集
+#[derive(Debug, Serialize)]
+pub struct RRsetPatch {
+    pub subname: String,
+    #[serde(rename = "type")]
+    pub record_type: String,
+    pub ttl: u32,
+    pub records: Vec<String>,
+}
@@ -7,7 +7,7 @@ use crate::error::{ProviderError, Result};
 use crate::http_client::HttpUtils;
 use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
 
-use super::{DNSPOD_API_HOST, DNSPOD_VERSION, DnspodProvider, TencentResponse};
+use super::{DNSPOD_VERSION, DnspodProvider, TencentResponse};
 
 impl DnspodProvider {
     /// 执行腾讯云 API 请求
@@ -26,24 +26,38 @@ impl DnspodProvider {
 
         log::debug!("Request Body: {payload}");
 
-        // 2. 生成签名
+        // 2. 发送请求（响应体中的限流错误按退避重试，每次重新签名）
+        HttpUtils::retry_on_rate_limit(self.provider_name(), self.max_retries, || {
+            self.send_signed(action, &payload, ctx.clone())
+        })
+        .await
+    }
+
+    /// 签名并发送单次请求，解析腾讯云响应
+    async fn send_signed<T: for<'de> Deserialize<'de>>(
+        &self,
+        action: &str,
+        payload: &str,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        // 1. 生成签名
         let timestamp = Utc::now().timestamp();
-        let authorization = self.sign(action, &payload, timestamp);
+        let authorization = self.sign(action, payload, timestamp);
 
-        // 3. 发送请求（使用 HttpUtils）
-        let url = format!("https://{DNSPOD_API_HOST}");
+        // 2. 发送请求（使用 HttpUtils）
+        let url = format!("https://{}", self.host);
         let request = self
             .client
             .post(&url)
             .header("Content-Type", "application/json; charset=utf-8")
-            .header("Host", DNSPOD_API_HOST)
+            .header("Host", &self.host)
             .header("X-TC-Action", action)
             .header("X-TC-Version", DNSPOD_VERSION)
             .header("X-TC-Timestamp", timestamp.to_string())
             .header("Authorization", authorization)
-            .body(payload);
+            .body(payload.to_string());
 
-        let (_status, response_text) = HttpUtils::execute_request_with_retry(
+        let (status, response_text) = HttpUtils::execute_request_with_retry(
             request,
             self.provider_name(),
             "POST",
@@ -52,17 +66,20 @@ impl DnspodProvider {
         )
         .await?;
 
-        // 4. 解析响应
+        // 3. 解析响应
         let tc_response: TencentResponse<T> =
             HttpUtils::parse_json(&response_text, self.provider_name())?;
 
-        // 5. 处理错误
+        // 4. 处理错误
         if let Some(error) = tc_response.response.error {
             log::error!("API 错误: {} - {}", error.code, error.message);
-            return Err(self.map_error(RawApiError::with_code(&error.code, &error.message), ctx));
+            return Err(self.map_error(
+                RawApiError::with_code(&error.code, &error.message).with_status(status),
+                ctx,
+            ));
         }
 
-        // 6. 提取数据
+        // 5. 提取数据
         tc_response
             .response
             .data
@@ -8,32 +8,48 @@ mod types;
 
 use reqwest::Client;
 
-use crate::providers::common::create_http_client;
+use crate::http_client::ProxyConfig;
+use crate::providers::common::{create_http_client, normalize_endpoint_host};
+use crate::traits::DEFAULT_BATCH_CONCURRENCY;
+use crate::types::DnsRecordType;
 
 pub(crate) use types::{
-    CreateRecordResponse, DescribeDomainResponse, DomainListResponse, ModifyRecordResponse,
-    RecordListResponse, TencentResponse,
+    CreateRecordResponse, DescribeDomainResponse, DomainListResponse, DomainLogListResponse,
+    ModifyRecordResponse, RecordListResponse, TencentResponse,
 };
 
+/// 默认 API 端点
 pub(crate) const DNSPOD_API_HOST: &str = "dnspod.tencentcloudapi.com";
 pub(crate) const DNSPOD_SERVICE: &str = "dnspod";
 pub(crate) const DNSPOD_VERSION: &str = "2021-03-23";
 /// DNSPod API 单页最大记录数
 pub(crate) const MAX_PAGE_SIZE: u32 = 100;
+/// 无原生支持的记录类型
+pub(crate) const UNSUPPORTED_RECORD_TYPES: &[DnsRecordType] = &[
+    DnsRecordType::Alias,
+    DnsRecordType::Tlsa,
+    DnsRecordType::Sshfp,
+    DnsRecordType::Ds,
+];
 
 /// 腾讯云 DNSPod Provider
 pub struct DnspodProvider {
     pub(crate) client: Client,
     pub(crate) secret_id: String,
     pub(crate) secret_key: String,
+    pub(crate) host: String,
     pub(crate) max_retries: u32,
+    pub(crate) batch_concurrency: usize,
 }
 
 /// DNSPod Provider Builder
 pub struct DnspodProviderBuilder {
     secret_id: String,
     secret_key: String,
+    endpoint: Option<String>,
     max_retries: u32,
+    proxy: Option<ProxyConfig>,
+    batch_concurrency: usize,
 }
 
 impl DnspodProviderBuilder {
@@ -41,21 +57,47 @@ impl DnspodProviderBuilder {
         Self {
             secret_id,
             secret_key,
+            endpoint: None,
             max_retries: 2,
+            proxy: None,
+            batch_concurrency: DEFAULT_BATCH_CONCURRENCY,
         }
     }
 
+    /// 设置 API 端点主机名（如 `dnspod.intl.tencentcloudapi.com`）
+    pub fn endpoint(mut self, host: impl Into<String>) -> Self {
+        let host = normalize_endpoint_host(&host.into());
+        if !host.is_empty() {
+            self.endpoint = Some(host);
+        }
+        self
+    }
+
     pub fn max_retries(mut self, retries: u32) -> Self {
         self.max_retries = retries;
         self
     }
 
+    /// 设置 HTTP 代理
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// 设置批量操作的最大并发请求数（默认 5）
+    pub fn batch_concurrency(mut self, concurrency: usize) -> Self {
+        self.batch_concurrency = concurrency.max(1);
+        self
+    }
+
     pub fn build(self) -> DnspodProvider {
         DnspodProvider {
-            client: create_http_client(),
+            client: create_http_client(self.proxy.as_ref()),
             secret_id: self.secret_id,
             secret_key: self.secret_key,
+            host: self.endpoint.unwrap_or_else(|| DNSPOD_API_HOST.to_string()),
             max_retries: self.max_retries,
+            batch_concurrency: self.batch_concurrency,
         }
     }
 }
@@ -4,17 +4,22 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
 use crate::error::{ProviderError, Result};
-use crate::providers::common::record_type_to_string;
+use crate::providers::common::{
+    canonical_record_name, ensure_not_proxied, ensure_record_type_supported, ensure_simple_routing,
+    record_type_to_string,
+};
 use crate::traits::{DnsProvider, ErrorContext, ProviderErrorMapper};
 use crate::types::{
     CreateDnsRecordRequest, DnsRecord, DomainStatus, FieldType, PaginatedResponse,
     PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures, ProviderLimits,
-    ProviderMetadata, ProviderType, RecordData, RecordQueryParams, UpdateDnsRecordRequest,
+    ProviderMetadata, ProviderType, RecordChange, RecordChangeAction, RecordData,
+    RecordQueryParams, UpdateDnsRecordRequest,
 };
 
 use super::{
     CreateRecordResponse, DescribeDomainResponse, DnspodProvider, DomainListResponse,
-    MAX_PAGE_SIZE, ModifyRecordResponse, RecordListResponse,
+    DomainLogListResponse, MAX_PAGE_SIZE, ModifyRecordResponse, RecordListResponse,
+    UNSUPPORTED_RECORD_TYPES,
 };
 
 impl DnspodProvider {
@@ -29,6 +34,40 @@ impl DnspodProvider {
         }
     }
 
+    /// 解析一条域名操作日志
+    ///
+    /// 日志为纯文本（如 `2021-07-05 16:49:57：添加 A 记录 www 默认 192.0.2.1`），
+    /// 开头为北京时间，不含结构化的操作者信息
+    fn parse_domain_log(line: &str) -> Option<RecordChange> {
+        let time = line.get(..19)?;
+        let timestamp = chrono::NaiveDateTime::parse_from_str(time, "%Y-%m-%d %H:%M:%S")
+            .ok()?
+            .and_local_timezone(chrono::FixedOffset::east_opt(8 * 3600)?)
+            .single()?
+            .with_timezone(&chrono::Utc);
+
+        let record_summary = line[19..]
+            .trim_start_matches([':', '：', ' '])
+            .trim()
+            .to_string();
+        let action = if record_summary.contains("添加") {
+            RecordChangeAction::Create
+        } else if record_summary.contains("修改") {
+            RecordChangeAction::Update
+        } else if record_summary.contains("删除") {
+            RecordChangeAction::Delete
+        } else {
+            RecordChangeAction::Other
+        };
+
+        Some(RecordChange {
+            timestamp,
+            action,
+            record_summary,
+            actor: None,
+        })
+    }
+
     /// 解析 DNSPod 记录为 RecordData（使用 mx 字段作为 priority）
     fn parse_record_data(record_type: &str, value: &str, mx: Option<u16>) -> Result<RecordData> {
         match record_type {
@@ -115,7 +154,7 @@ impl DnspodProvider {
         match data {
             RecordData::A { address } => (address.clone(), None),
             RecordData::AAAA { address } => (address.clone(), None),
-            RecordData::CNAME { target } => (target.clone(), None),
+            RecordData::CNAME { target } | RecordData::ALIAS { target } => (target.clone(), None),
             RecordData::MX { priority, exchange } => (exchange.clone(), Some(*priority)),
             RecordData::TXT { text } => (text.clone(), None),
             RecordData::NS { nameserver } => (nameserver.clone(), None),
@@ -127,6 +166,9 @@ impl DnspodProvider {
                 target,
             } => (format!("{priority} {weight} {port} {target}"), None),
             RecordData::CAA { flags, tag, value } => (format!("{flags} {tag} \"{value}\""), None),
+            RecordData::TLSA { .. } | RecordData::SSHFP { .. } | RecordData::DS { .. } => {
+                (data.display_value(), None)
+            }
         }
     }
 }
@@ -137,6 +179,10 @@ impl DnsProvider for DnspodProvider {
         "dnspod"
     }
 
+    fn batch_concurrency(&self) -> usize {
+        self.batch_concurrency
+    }
+
     fn metadata() -> ProviderMetadata {
         ProviderMetadata {
             id: ProviderType::Dnspod,
@@ -162,6 +208,13 @@ impl DnsProvider for DnspodProvider {
             limits: ProviderLimits {
                 max_page_size_domains: 3000,
                 max_page_size_records: 3000,
+                requests_per_second: Some(20.0),
+                max_batch_size: 1,
+                max_records_per_zone: None,
+                // 免费套餐最小 600 秒，由 API 校验
+                min_ttl: 1,
+                max_ttl: 604_800,
+                auto_ttl: None,
             },
         }
     }
@@ -279,6 +332,7 @@ impl DnsProvider for DnspodProvider {
         let params = PaginationParams {
             page: 1,
             page_size: 100,
+            cursor: None,
         };
         let response = self.list_domains(&params).await?;
 
@@ -316,7 +370,7 @@ impl DnsProvider for DnspodProvider {
 
         let offset = (params.page - 1) * params.page_size;
         let req = DescribeRecordListRequest {
-            domain: domain_info.name,
+            domain: domain_info.name.clone(),
             offset,
             limit: params.page_size.min(MAX_PAGE_SIZE),
             keyword: params.keyword.clone().filter(|k| !k.is_empty()),
@@ -350,16 +404,19 @@ impl DnsProvider for DnspodProvider {
                         Some(DnsRecord {
                             id: r.record_id.to_string(),
                             domain_id: domain_id.to_string(),
-                            name: r.name,
+                            name: canonical_record_name(&r.name, &domain_info.name),
                             ttl: r.ttl,
                             data,
                             proxied: None,
+                            comment: None,
+                            tags: Vec::new(),
                             created_at: None,
                             updated_at: r.updated_on.and_then(|s| {
                                 chrono::DateTime::parse_from_rfc3339(&s)
                                     .ok()
                                     .map(|dt| dt.with_timezone(&chrono::Utc))
                             }),
+                            routing_policy: None,
                         })
                     })
                     .collect();
@@ -371,8 +428,8 @@ impl DnsProvider for DnspodProvider {
                     total_count,
                 ))
             }
-            Err(ProviderError::Unknown { raw_code, .. })
-                if raw_code.as_deref() == Some("ResourceNotFound.NoDataOfRecord") =>
+            Err(ProviderError::ApiError { provider_code, .. })
+                if provider_code.as_deref() == Some("ResourceNotFound.NoDataOfRecord") =>
             {
                 Ok(PaginatedResponse::new(
                     vec![],
@@ -386,6 +443,10 @@ impl DnsProvider for DnspodProvider {
     }
 
     async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        ensure_record_type_supported(&req.data, UNSUPPORTED_RECORD_TYPES, self.provider_name())?;
+        ensure_simple_routing(req.routing_policy.as_ref(), self.provider_name())?;
+        ensure_not_proxied(req.proxied, self.provider_name())?;
+        self.validate_ttl(req.ttl)?;
         #[derive(Serialize)]
         struct CreateRecordRequest {
             #[serde(rename = "Domain")]
@@ -410,9 +471,10 @@ impl DnsProvider for DnspodProvider {
         let (value, mx) = Self::record_data_to_api(&req.data);
         let record_type = record_type_to_string(&req.data.record_type());
 
+        let name = canonical_record_name(&req.name, &domain_info.name);
         let api_req = CreateRecordRequest {
             domain: domain_info.name,
-            sub_domain: req.name.clone(),
+            sub_domain: name.clone(),
             record_type: record_type.to_string(),
             record_line: "默认".to_string(),
             value,
@@ -432,12 +494,15 @@ impl DnsProvider for DnspodProvider {
         Ok(DnsRecord {
             id: response.record_id.to_string(),
             domain_id: req.domain_id.clone(),
-            name: req.name.clone(),
+            name,
             ttl: req.ttl,
             data: req.data.clone(),
             proxied: None,
+            comment: None,
+            tags: Vec::new(),
             created_at: Some(now),
             updated_at: Some(now),
+            routing_policy: None,
         })
     }
 
@@ -446,6 +511,9 @@ impl DnsProvider for DnspodProvider {
         record_id: &str,
         req: &UpdateDnsRecordRequest,
     ) -> Result<DnsRecord> {
+        ensure_record_type_supported(&req.data, UNSUPPORTED_RECORD_TYPES, self.provider_name())?;
+        ensure_not_proxied(req.proxied, self.provider_name())?;
+        self.validate_ttl(req.ttl)?;
         #[derive(Serialize)]
         struct ModifyRecordRequest {
             #[serde(rename = "Domain")]
@@ -480,10 +548,11 @@ impl DnsProvider for DnspodProvider {
         let (value, mx) = Self::record_data_to_api(&req.data);
         let record_type = record_type_to_string(&req.data.record_type());
 
+        let name = canonical_record_name(&req.name, &domain_info.name);
         let api_req = ModifyRecordRequest {
             domain: domain_info.name,
             record_id: record_id_num,
-            sub_domain: req.name.clone(),
+            sub_domain: name.clone(),
             record_type: record_type.to_string(),
             record_line: "默认".to_string(),
             value,
@@ -503,12 +572,15 @@ impl DnsProvider for DnspodProvider {
         Ok(DnsRecord {
             id: record_id.to_string(),
             domain_id: req.domain_id.clone(),
-            name: req.name.clone(),
+            name,
             ttl: req.ttl,
             data: req.data.clone(),
             proxied: None,
+            comment: None,
+            tags: Vec::new(),
             created_at: None,
             updated_at: Some(now),
+            routing_policy: None,
         })
     }
 
@@ -549,4 +621,54 @@ impl DnsProvider for DnspodProvider {
 
         Ok(())
     }
+    /// 使用 DescribeDomainLogList 分页拉取域名操作日志（最新的在前）
+    async fn list_record_changes(
+        &self,
+        domain_id: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<RecordChange>> {
+        #[derive(Serialize)]
+        struct DescribeDomainLogListRequest {
+            #[serde(rename = "Domain")]
+            domain: String,
+            #[serde(rename = "Offset")]
+            offset: u32,
+            #[serde(rename = "Limit")]
+            limit: u32,
+        }
+
+        let domain_info = self.get_domain(domain_id).await?;
+
+        let mut changes = Vec::new();
+        let mut offset = 0;
+        loop {
+            let req = DescribeDomainLogListRequest {
+                domain: domain_info.name.clone(),
+                offset,
+                limit: MAX_PAGE_SIZE,
+            };
+            let ctx = ErrorContext {
+                domain: Some(domain_id.to_string()),
+                ..Default::default()
+            };
+            let response: DomainLogListResponse =
+                self.request("DescribeDomainLogList", &req, ctx).await?;
+
+            let logs = response.log_list.unwrap_or_default();
+            let page_len = logs.len() as u32;
+            for change in logs.iter().filter_map(|line| Self::parse_domain_log(line)) {
+                if since.is_some_and(|since| change.timestamp < since) {
+                    return Ok(changes);
+                }
+                changes.push(change);
+            }
+
+            offset += page_len;
+            if page_len < MAX_PAGE_SIZE || response.total_count.is_some_and(|t| offset >= t) {
+                break;
+            }
+        }
+
+        Ok(changes)
+    }
 }
@@ -5,7 +5,7 @@ use sha2::{Digest, Sha256};
 
 use crate::providers::common::hmac_sha256;
 
-use super::{DNSPOD_API_HOST, DNSPOD_SERVICE, DnspodProvider};
+use super::{DNSPOD_SERVICE, DnspodProvider};
 
 impl DnspodProvider {
     /// 生成 TC3-HMAC-SHA256 签名
@@ -21,7 +21,7 @@ impl DnspodProvider {
         let canonical_query_string = "";
         let canonical_headers = format!(
             "content-type:application/json; charset=utf-8\nhost:{}\nx-tc-action:{}\n",
-            DNSPOD_API_HOST,
+            self.host,
             action.to_lowercase()
         );
         let signed_headers = "content-type;host;x-tc-action";
@@ -127,3 +127,13 @@ pub struct ModifyRecordResponse {
     #[allow(dead_code)]
     pub record_id: u64,
 }
+
+// ============ DNSPod 操作日志 ============
+
+#[derive(Debug, Deserialize)]
+pub struct DomainLogListResponse {
+    #[serde(rename = "LogList")]
+    pub log_list: Option<Vec<String>>,
+    #[serde(rename = "TotalCount")]
+    pub total_count: Option<u32>,
+}
@@ -0,0 +1,294 @@
+//! Dry-run 包装 Provider
+//!
+//! 读操作委托给内部 Provider，写操作只记录到内存中的变更计划，不会修改线上区域。
+//! 适用于批量迁移前预览将要发生的变更。
+//!
+//! 写操作按内部 Provider 的元数据校验 TTL、代理开关与路由策略，这些请求会与真实执行时一样被拒绝。
+//! 记录类型是否支持、记录数据格式等 Provider 专有的校验不在此复现，以真实执行为准。
+
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::providers::common::{ensure_not_proxied, ensure_simple_routing};
+use crate::traits::DnsProvider;
+use crate::types::{
+    BatchCreateFailure, BatchCreateResult, BatchDeleteResult, BatchUpdateFailure, BatchUpdateItem,
+    BatchUpdateResult, CreateDnsRecordRequest, DnsRecord, DnssecStatus, PaginatedResponse,
+    PaginationParams, ProviderDomain, ProviderFeatures, ProviderHealth, ProviderMetadata,
+    RecordChange, RecordQueryParams, RoutingPolicy, UpdateDnsRecordRequest,
+};
+
+/// Dry-run 模式下记录的单条变更
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "action", rename_all = "camelCase")]
+pub enum PlannedChange {
+    /// 创建记录
+    Create { request: CreateDnsRecordRequest },
+    /// 更新记录
+    #[serde(rename_all = "camelCase")]
+    Update {
+        record_id: String,
+        request: UpdateDnsRecordRequest,
+    },
+    /// 删除记录
+    #[serde(rename_all = "camelCase")]
+    Delete {
+        record_id: String,
+        domain_id: String,
+    },
+    /// 启用 DNSSEC
+    #[serde(rename_all = "camelCase")]
+    EnableDnssec { domain_id: String },
+}
+
+/// Dry-run Provider：包装任意 Provider，拦截所有写操作
+///
+/// 元数据与写操作校验沿用内部 Provider 类型 `P`。
+pub struct DryRunProvider<P> {
+    inner: Arc<P>,
+    changes: Mutex<Vec<PlannedChange>>,
+}
+
+impl<P: DnsProvider> DryRunProvider<P> {
+    /// 包装内部 Provider
+    pub fn new(inner: Arc<P>) -> Self {
+        Self {
+            inner,
+            changes: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// 获取已记录的变更计划（按调用顺序）
+    pub fn planned_changes(&self) -> Vec<PlannedChange> {
+        self.changes().clone()
+    }
+
+    /// 清空变更计划
+    pub fn clear_planned_changes(&self) {
+        self.changes().clear();
+    }
+
+    fn changes(&self) -> MutexGuard<'_, Vec<PlannedChange>> {
+        self.changes.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// 按内部 Provider 的元数据校验写操作（TTL、代理开关、路由策略）
+    fn check_write(
+        &self,
+        ttl: u32,
+        proxied: Option<bool>,
+        routing_policy: Option<&RoutingPolicy>,
+    ) -> Result<()> {
+        let features = P::metadata().features;
+        if !features.proxy {
+            ensure_not_proxied(proxied, self.id())?;
+        }
+        if !features.routing_policy {
+            ensure_simple_routing(routing_policy, self.id())?;
+        }
+        self.validate_ttl(ttl)
+    }
+
+    /// 记录一次创建，返回合成的记录（ID 为 `dry-run-<序号>`）
+    fn record_create(&self, req: &CreateDnsRecordRequest) -> DnsRecord {
+        let mut changes = self.changes();
+        changes.push(PlannedChange::Create {
+            request: req.clone(),
+        });
+        DnsRecord {
+            id: format!("dry-run-{}", changes.len()),
+            domain_id: req.domain_id.clone(),
+            name: req.name.clone(),
+            ttl: req.ttl,
+            data: req.data.clone(),
+            proxied: req.proxied,
+            comment: req.comment.clone(),
+            tags: req.tags.clone(),
+            created_at: None,
+            updated_at: None,
+            routing_policy: req.routing_policy.clone(),
+        }
+    }
+
+    /// 记录一次更新，返回合成的记录（沿用原记录 ID）
+    fn record_update(&self, record_id: &str, req: &UpdateDnsRecordRequest) -> DnsRecord {
+        self.changes().push(PlannedChange::Update {
+            record_id: record_id.to_string(),
+            request: req.clone(),
+        });
+        DnsRecord {
+            id: record_id.to_string(),
+            domain_id: req.domain_id.clone(),
+            name: req.name.clone(),
+            ttl: req.ttl,
+            data: req.data.clone(),
+            proxied: req.proxied,
+            comment: req.comment.clone(),
+            tags: req.tags.clone(),
+            created_at: None,
+            updated_at: None,
+            routing_policy: None,
+        }
+    }
+}
+
+#[async_trait]
+impl<P: DnsProvider> DnsProvider for DryRunProvider<P> {
+    fn id(&self) -> &'static str {
+        self.inner.id()
+    }
+
+    fn batch_concurrency(&self) -> usize {
+        self.inner.batch_concurrency()
+    }
+
+    fn batch_chunk_size(&self) -> usize {
+        self.inner.batch_chunk_size()
+    }
+
+    fn metadata() -> ProviderMetadata {
+        P::metadata()
+    }
+
+    fn validate_ttl(&self, ttl: u32) -> Result<()> {
+        self.inner.validate_ttl(ttl)
+    }
+
+    fn features(&self) -> ProviderFeatures {
+        self.inner.features()
+    }
+
+    async fn validate_credentials(&self) -> Result<bool> {
+        self.inner.validate_credentials().await
+    }
+
+    async fn health_check(&self) -> Result<ProviderHealth> {
+        self.inner.health_check().await
+    }
+
+    async fn get_dnssec_status(&self, domain_id: &str) -> Result<DnssecStatus> {
+        self.inner.get_dnssec_status(domain_id).await
+    }
+
+    /// 只记录变更，返回当前（未启用前）的状态
+    async fn enable_dnssec(&self, domain_id: &str) -> Result<DnssecStatus> {
+        let status = self.inner.get_dnssec_status(domain_id).await?;
+        self.changes().push(PlannedChange::EnableDnssec {
+            domain_id: domain_id.to_string(),
+        });
+        Ok(status)
+    }
+
+    async fn list_record_changes(
+        &self,
+        domain_id: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<RecordChange>> {
+        self.inner.list_record_changes(domain_id, since).await
+    }
+
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> Result<PaginatedResponse<ProviderDomain>> {
+        self.inner.list_domains(params).await
+    }
+
+    async fn get_domain(&self, domain_id: &str) -> Result<ProviderDomain> {
+        self.inner.get_domain(domain_id).await
+    }
+
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        self.inner.list_records(domain_id, params).await
+    }
+
+    async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        self.check_write(req.ttl, req.proxied, req.routing_policy.as_ref())?;
+        Ok(self.record_create(req))
+    }
+
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<DnsRecord> {
+        self.check_write(req.ttl, req.proxied, None)?;
+        Ok(self.record_update(record_id, req))
+    }
+
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
+        self.changes().push(PlannedChange::Delete {
+            record_id: record_id.to_string(),
+            domain_id: domain_id.to_string(),
+        });
+        Ok(())
+    }
+
+    async fn batch_create_records(
+        &self,
+        requests: &[CreateDnsRecordRequest],
+    ) -> Result<BatchCreateResult> {
+        let mut created_records = Vec::new();
+        let mut failures = Vec::new();
+        for (request_index, req) in requests.iter().enumerate() {
+            match self.check_write(req.ttl, req.proxied, req.routing_policy.as_ref()) {
+                Ok(()) => created_records.push(self.record_create(req)),
+                Err(e) => failures.push(BatchCreateFailure {
+                    request_index,
+                    record_name: req.name.clone(),
+                    reason: e.to_string(),
+                }),
+            }
+        }
+        Ok(BatchCreateResult {
+            success_count: created_records.len(),
+            failed_count: failures.len(),
+            created_records,
+            failures,
+        })
+    }
+
+    async fn batch_update_records(&self, updates: &[BatchUpdateItem]) -> Result<BatchUpdateResult> {
+        let mut updated_records = Vec::new();
+        let mut failures = Vec::new();
+        for item in updates {
+            match self.check_write(item.request.ttl, item.request.proxied, None) {
+                Ok(()) => updated_records.push(self.record_update(&item.record_id, &item.request)),
+                Err(e) => failures.push(BatchUpdateFailure {
+                    record_id: item.record_id.clone(),
+                    reason: e.to_string(),
+                }),
+            }
+        }
+        Ok(BatchUpdateResult {
+            success_count: updated_records.len(),
+            failed_count: failures.len(),
+            updated_records,
+            failures,
+        })
+    }
+
+    async fn batch_delete_records(
+        &self,
+        domain_id: &str,
+        record_ids: &[String],
+    ) -> Result<BatchDeleteResult> {
+        let mut changes = self.changes();
+        changes.extend(record_ids.iter().map(|record_id| PlannedChange::Delete {
+            record_id: record_id.clone(),
+            domain_id: domain_id.to_string(),
+        }));
+        Ok(BatchDeleteResult {
+            success_count: record_ids.len(),
+            failed_count: 0,
+            failures: Vec::new(),
+        })
+    }
+}
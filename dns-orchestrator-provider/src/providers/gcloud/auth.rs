@@ -0,0 +1,102 @@
+//! Google OAuth2 服务账号认证
+//!
+//! 参考: <https://developers.google.com/identity/protocols/oauth2/service-account#httprest>
+
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+
+use crate::error::{ProviderError, Result};
+use crate::http_client::HttpUtils;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::types::{JwtClaims, ServiceAccountKey, TokenErrorResponse, TokenResponse};
+use super::{DEFAULT_TOKEN_URI, GCLOUD_DNS_SCOPE, GoogleCloudProvider};
+
+/// JWT 断言有效期（Google 允许的最大值）
+const ASSERTION_LIFETIME_SECS: i64 = 3600;
+/// 令牌提前刷新的余量
+const TOKEN_REFRESH_MARGIN_SECS: i64 = 60;
+
+/// 缓存的访问令牌
+pub(crate) struct CachedToken {
+    access_token: String,
+    expires_at: DateTime<Utc>,
+}
+
+impl GoogleCloudProvider {
+    fn invalid_credentials(&self, message: impl Into<String>) -> ProviderError {
+        ProviderError::InvalidCredentials {
+            provider: self.provider_name().to_string(),
+            raw_message: Some(message.into()),
+        }
+    }
+
+    /// 获取访问令牌（优先使用缓存）
+    pub(crate) async fn access_token(&self) -> Result<String> {
+        if let Ok(guard) = self.token.lock()
+            && let Some(ref cached) = *guard
+            && cached.expires_at - Duration::seconds(TOKEN_REFRESH_MARGIN_SECS) > Utc::now()
+        {
+            return Ok(cached.access_token.clone());
+        }
+
+        let cached = self.fetch_access_token().await?;
+        let access_token = cached.access_token.clone();
+        if let Ok(mut guard) = self.token.lock() {
+            *guard = Some(cached);
+        }
+        Ok(access_token)
+    }
+
+    /// 使用 JWT Bearer 断言换取新的访问令牌
+    async fn fetch_access_token(&self) -> Result<CachedToken> {
+        let key: ServiceAccountKey = serde_json::from_str(&self.service_account_json)
+            .map_err(|e| self.invalid_credentials(format!("Invalid service account JSON: {e}")))?;
+        let token_uri = key.token_uri.as_deref().unwrap_or(DEFAULT_TOKEN_URI);
+
+        let now = Utc::now();
+        let claims = JwtClaims {
+            iss: &key.client_email,
+            scope: GCLOUD_DNS_SCOPE,
+            aud: token_uri,
+            iat: now.timestamp(),
+            exp: now.timestamp() + ASSERTION_LIFETIME_SECS,
+        };
+        let encoding_key = EncodingKey::from_rsa_pem(key.private_key.as_bytes())
+            .map_err(|e| self.invalid_credentials(format!("Invalid private key: {e}")))?;
+        let assertion =
+            jsonwebtoken::encode(&Header::new(Algorithm::RS256), &claims, &encoding_key)
+                .map_err(|e| self.invalid_credentials(format!("Failed to sign JWT: {e}")))?;
+
+        let request = self.client.post(token_uri).form(&[
+            ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+            ("assertion", assertion.as_str()),
+        ]);
+        let (status, response_text) = HttpUtils::execute_request_with_retry(
+            request,
+            self.provider_name(),
+            "POST",
+            token_uri,
+            self.max_retries,
+        )
+        .await?;
+
+        if !(200..300).contains(&status) {
+            if let Ok(error) = serde_json::from_str::<TokenErrorResponse>(&response_text) {
+                let message = error.error_description.unwrap_or(error.error);
+                return Err(self.invalid_credentials(message));
+            }
+            return Err(self.map_error(
+                RawApiError::new(response_text).with_status(status),
+                ErrorContext::default(),
+            ));
+        }
+
+        let token: TokenResponse = HttpUtils::parse_json(&response_text, self.provider_name())?;
+        Ok(CachedToken {
+            access_token: token.access_token,
+            expires_at: now
+                + Duration::seconds(token.expires_in.unwrap_or(ASSERTION_LIFETIME_SECS)),
+        })
+    }
+}
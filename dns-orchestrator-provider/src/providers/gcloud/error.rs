@@ -0,0 +1,99 @@
+//! Google Cloud DNS 错误映射
+//!
+//! 参考: <https://cloud.google.com/dns/docs/troubleshooting>
+//!
+//! 错误码优先取 `error.errors[].reason`（如 `notFound`），缺失时取 gRPC 状态（如 `NOT_FOUND`）。
+//! `notFound` 同时用于托管区域和记录集，需结合上下文区分。
+
+use crate::error::ProviderError;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::GoogleCloudProvider;
+
+/// Google Cloud DNS 错误码映射实现
+impl ProviderErrorMapper for GoogleCloudProvider {
+    fn provider_name(&self) -> &'static str {
+        "gcloud"
+    }
+
+    fn map_error(&self, raw: RawApiError, context: ErrorContext) -> ProviderError {
+        match raw.code.as_deref() {
+            // ============ 认证错误 ============
+            Some("authError" | "UNAUTHENTICATED" | "401") => ProviderError::InvalidCredentials {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 权限拒绝 ============
+            Some("forbidden" | "accessNotConfigured" | "PERMISSION_DENIED" | "403") => {
+                ProviderError::PermissionDenied {
+                    provider: self.provider_name().to_string(),
+                    raw_message: Some(raw.message),
+                }
+            }
+
+            // ============ 频率限制 ============
+            Some("rateLimitExceeded" | "userRateLimitExceeded") => ProviderError::RateLimited {
+                provider: self.provider_name().to_string(),
+                retry_after_secs: None,
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 配额限制 ============
+            Some("quotaExceeded" | "RESOURCE_EXHAUSTED") => ProviderError::QuotaExceeded {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 资源不存在 ============
+            Some("notFound" | "NOT_FOUND" | "404") => {
+                if context.record_id.is_some() {
+                    ProviderError::RecordNotFound {
+                        provider: self.provider_name().to_string(),
+                        record_id: context.record_id.unwrap_or_default(),
+                        raw_message: Some(raw.message),
+                    }
+                } else {
+                    ProviderError::DomainNotFound {
+                        provider: self.provider_name().to_string(),
+                        domain: context.domain.unwrap_or_default(),
+                        raw_message: Some(raw.message),
+                    }
+                }
+            }
+
+            // ============ 记录已存在 ============
+            Some("alreadyExists" | "ALREADY_EXISTS") => ProviderError::RecordExists {
+                provider: self.provider_name().to_string(),
+                record_name: context.record_name.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 参数无效 ============
+            Some(
+                "invalid"
+                | "invalidParameter"
+                | "required"
+                | "conditionNotMet"
+                | "INVALID_ARGUMENT"
+                | "FAILED_PRECONDITION"
+                | "400",
+            ) => ProviderError::InvalidParameter {
+                provider: self.provider_name().to_string(),
+                param: "input".to_string(),
+                detail: raw.message,
+            },
+
+            // ============ 服务端错误 ============
+            Some("backendError" | "internalError" | "UNAVAILABLE" | "INTERNAL") => {
+                ProviderError::NetworkError {
+                    provider: self.provider_name().to_string(),
+                    detail: raw.message,
+                }
+            }
+
+            // ============ 其他错误 fallback ============
+            _ => self.unknown_error(raw),
+        }
+    }
+}
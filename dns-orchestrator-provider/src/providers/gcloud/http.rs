@@ -0,0 +1,109 @@
+//! Google Cloud DNS HTTP 请求方法
+
+use serde::de::DeserializeOwned;
+
+use crate::error::Result;
+use crate::http_client::HttpUtils;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::types::ErrorResponse;
+use super::{GCLOUD_DNS_API_BASE, GoogleCloudProvider};
+
+impl GoogleCloudProvider {
+    // ==================== 辅助方法 ====================
+
+    /// 统一处理 Cloud DNS 响应错误
+    fn handle_response_error(
+        &self,
+        status: u16,
+        response_text: &str,
+        ctx: ErrorContext,
+    ) -> Result<()> {
+        if (200..300).contains(&status) {
+            return Ok(());
+        }
+
+        if let Ok(error) = serde_json::from_str::<ErrorResponse>(response_text) {
+            // 优先使用具体的 reason，其次是 gRPC 状态
+            let code = error
+                .error
+                .errors
+                .into_iter()
+                .find_map(|e| e.reason)
+                .or(error.error.status)
+                .unwrap_or_else(|| status.to_string());
+            return Err(self.map_error(
+                RawApiError::with_code(
+                    code,
+                    error
+                        .error
+                        .message
+                        .unwrap_or_else(|| "No error message provided by API".to_string()),
+                )
+                .with_status(status),
+                ctx,
+            ));
+        }
+
+        // 回退到通用错误
+        Err(self.unknown_error(RawApiError::new(response_text).with_status(status)))
+    }
+
+    /// 构造项目下资源的完整 URL
+    fn url(&self, path: &str) -> String {
+        format!(
+            "{GCLOUD_DNS_API_BASE}/projects/{}/{path}",
+            urlencoding::encode(&self.project_id)
+        )
+    }
+
+    // ==================== 公开 API 方法 ====================
+
+    /// 执行 GET 请求
+    pub(crate) async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        let url = self.url(path);
+        let token = self.access_token().await?;
+        let request = self.client.get(&url).bearer_auth(token).query(query);
+
+        let (status, response_text) = HttpUtils::execute_request_with_retry(
+            request,
+            self.provider_name(),
+            "GET",
+            &url,
+            self.max_retries,
+        )
+        .await?;
+
+        self.handle_response_error(status, &response_text, ctx)?;
+        HttpUtils::parse_json(&response_text, self.provider_name())
+    }
+
+    /// 执行 POST 请求（JSON body）
+    pub(crate) async fn post<T: DeserializeOwned, B: serde::Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        let url = self.url(path);
+        let token = self.access_token().await?;
+        let request = self.client.post(&url).bearer_auth(token).json(body);
+
+        let (status, response_text) = HttpUtils::execute_request_with_retry(
+            request,
+            self.provider_name(),
+            "POST",
+            &url,
+            self.max_retries,
+        )
+        .await?;
+
+        self.handle_response_error(status, &response_text, ctx)?;
+        HttpUtils::parse_json(&response_text, self.provider_name())
+    }
+}
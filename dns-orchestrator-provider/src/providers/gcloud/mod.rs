@@ -0,0 +1,103 @@
+//! Google Cloud DNS Provider
+//!
+//! Cloud DNS 以记录集（名称 + 类型）为单位管理记录，且只能通过变更（Change）
+//! 提交修改：每个变更包含 `additions` 与 `deletions`，删除项必须与当前记录集完全一致。
+//! 本 Provider 将记录集中的每个值映射为一条独立记录（ID 编码方式见
+//! `common::encode_rrset_record_id`），增删改时对整个记录集执行「删除旧集合 + 添加新集合」。
+//!
+//! 认证使用服务账号 JSON 密钥，通过 OAuth2 JWT Bearer 流程换取访问令牌并缓存至过期前。
+
+mod auth;
+mod error;
+mod http;
+mod provider;
+pub(crate) mod types;
+
+use std::sync::Mutex;
+
+use reqwest::Client;
+
+use crate::http_client::ProxyConfig;
+use crate::providers::common::create_http_client;
+use crate::traits::DEFAULT_BATCH_CONCURRENCY;
+use crate::types::DnsRecordType;
+
+pub(crate) const GCLOUD_DNS_API_BASE: &str = "https://dns.googleapis.com/dns/v1";
+/// Cloud DNS 读写权限范围
+pub(crate) const GCLOUD_DNS_SCOPE: &str = "https://www.googleapis.com/auth/ndev.clouddns.readwrite";
+/// 服务账号 JSON 未提供 `token_uri` 时使用的默认地址
+pub(crate) const DEFAULT_TOKEN_URI: &str = "https://oauth2.googleapis.com/token";
+/// Cloud DNS API 单页最大数量
+pub(crate) const MAX_PAGE_SIZE: u32 = 500;
+/// 无原生支持的记录类型
+pub(crate) const UNSUPPORTED_RECORD_TYPES: &[DnsRecordType] = &[DnsRecordType::Alias];
+
+/// Google Cloud DNS Provider
+pub struct GoogleCloudProvider {
+    pub(crate) client: Client,
+    pub(crate) service_account_json: String,
+    pub(crate) project_id: String,
+    pub(crate) max_retries: u32,
+    pub(crate) batch_concurrency: usize,
+    /// 缓存的访问令牌
+    pub(crate) token: Mutex<Option<auth::CachedToken>>,
+}
+
+/// Google Cloud DNS Provider Builder
+pub struct GoogleCloudProviderBuilder {
+    service_account_json: String,
+    project_id: String,
+    max_retries: u32,
+    proxy: Option<ProxyConfig>,
+    batch_concurrency: usize,
+}
+
+impl GoogleCloudProviderBuilder {
+    fn new(service_account_json: String, project_id: String) -> Self {
+        Self {
+            service_account_json,
+            project_id,
+            max_retries: 2,
+            proxy: None,
+            batch_concurrency: DEFAULT_BATCH_CONCURRENCY,
+        }
+    }
+
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// 设置 HTTP 代理
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// 设置批量操作的最大并发请求数（默认 5）
+    pub fn batch_concurrency(mut self, concurrency: usize) -> Self {
+        self.batch_concurrency = concurrency.max(1);
+        self
+    }
+
+    pub fn build(self) -> GoogleCloudProvider {
+        GoogleCloudProvider {
+            client: create_http_client(self.proxy.as_ref()),
+            service_account_json: self.service_account_json,
+            project_id: self.project_id,
+            max_retries: self.max_retries,
+            batch_concurrency: self.batch_concurrency,
+            token: Mutex::new(None),
+        }
+    }
+}
+
+impl GoogleCloudProvider {
+    pub fn new(service_account_json: String, project_id: String) -> Self {
+        Self::builder(service_account_json, project_id).build()
+    }
+
+    pub fn builder(service_account_json: String, project_id: String) -> GoogleCloudProviderBuilder {
+        GoogleCloudProviderBuilder::new(service_account_json, project_id)
+    }
+}
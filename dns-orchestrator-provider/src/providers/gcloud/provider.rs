@@ -0,0 +1,669 @@
+//! Google Cloud DNS DnsProvider trait 实现
+
+use async_trait::async_trait;
+
+use crate::error::{ProviderError, Result};
+use crate::providers::common::{
+    decode_rrset_record_id, encode_rrset_record_id, ensure_not_proxied,
+    ensure_record_type_supported, ensure_simple_routing, format_quoted_txt, full_name_to_relative,
+    normalize_domain_name, parse_ds_value, parse_quoted_txt, parse_sshfp_value, parse_tlsa_value,
+    record_type_to_string, relative_to_full_name, validate_record_data,
+};
+use crate::traits::{DnsProvider, ErrorContext, ProviderErrorMapper};
+use crate::types::{
+    CreateDnsRecordRequest, DnsRecord, DomainStatus, FieldType, PaginatedResponse,
+    PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures, ProviderLimits,
+    ProviderMetadata, ProviderType, RecordData, RecordQueryParams, UpdateDnsRecordRequest,
+};
+
+use super::types::{
+    ChangeRequest, ChangeResponse, ListManagedZonesResponse, ListRrsetsResponse, ManagedZone,
+    ResourceRecordSet,
+};
+use super::{GoogleCloudProvider, MAX_PAGE_SIZE, UNSUPPORTED_RECORD_TYPES};
+
+/// 记录集中的单个值（记录 ID 的解码结果）
+struct RecordKey {
+    /// 完整名称（不带末尾点）
+    name: String,
+    record_type: String,
+    /// Cloud DNS 格式的原始值
+    value: String,
+}
+
+impl GoogleCloudProvider {
+    /// 解码记录 ID
+    fn decode_record_id(&self, record_id: &str) -> Result<RecordKey> {
+        let (name, record_type, value) =
+            decode_rrset_record_id(record_id).ok_or_else(|| ProviderError::InvalidParameter {
+                provider: self.provider_name().to_string(),
+                param: "record_id".to_string(),
+                detail: format!("Invalid Cloud DNS record id: '{record_id}'"),
+            })?;
+        Ok(RecordKey {
+            name,
+            record_type,
+            value,
+        })
+    }
+
+    /// 解析 Cloud DNS 记录值为 RecordData
+    fn parse_record_data(&self, record_type: &str, value: &str) -> Result<RecordData> {
+        let parse_error = |detail: String| ProviderError::ParseError {
+            provider: self.provider_name().to_string(),
+            detail,
+        };
+
+        match record_type {
+            "A" => Ok(RecordData::A {
+                address: value.to_string(),
+            }),
+            "AAAA" => Ok(RecordData::AAAA {
+                address: value.to_string(),
+            }),
+            "CNAME" => Ok(RecordData::CNAME {
+                target: normalize_domain_name(value),
+            }),
+            "NS" => Ok(RecordData::NS {
+                nameserver: normalize_domain_name(value),
+            }),
+            "TXT" => Ok(RecordData::TXT {
+                text: parse_quoted_txt(value),
+            }),
+            "MX" => {
+                // Cloud DNS MX 格式: "priority exchange."
+                let parts: Vec<&str> = value.splitn(2, ' ').collect();
+                if parts.len() == 2 {
+                    Ok(RecordData::MX {
+                        priority: parts[0].parse().map_err(|_| {
+                            parse_error(format!("Invalid MX priority: '{}'", parts[0]))
+                        })?,
+                        exchange: normalize_domain_name(parts[1]),
+                    })
+                } else {
+                    Err(parse_error(format!(
+                        "Invalid MX record format: expected 'priority exchange', got '{value}'"
+                    )))
+                }
+            }
+            "SRV" => {
+                // Cloud DNS SRV 格式: "priority weight port target."
+                let parts: Vec<&str> = value.splitn(4, ' ').collect();
+                if parts.len() == 4 {
+                    Ok(RecordData::SRV {
+                        priority: parts[0].parse().map_err(|_| {
+                            parse_error(format!("Invalid SRV priority: '{}'", parts[0]))
+                        })?,
+                        weight: parts[1].parse().map_err(|_| {
+                            parse_error(format!("Invalid SRV weight: '{}'", parts[1]))
+                        })?,
+                        port: parts[2].parse().map_err(|_| {
+                            parse_error(format!("Invalid SRV port: '{}'", parts[2]))
+                        })?,
+                        target: normalize_domain_name(parts[3]),
+                    })
+                } else {
+                    Err(parse_error(format!(
+                        "Invalid SRV record format: expected 'priority weight port target', got '{value}'"
+                    )))
+                }
+            }
+            "CAA" => {
+                // Cloud DNS CAA 格式: flags tag "value"
+                let parts: Vec<&str> = value.splitn(3, ' ').collect();
+                if parts.len() == 3 {
+                    Ok(RecordData::CAA {
+                        flags: parts[0].parse().map_err(|_| {
+                            parse_error(format!("Invalid CAA flags: '{}'", parts[0]))
+                        })?,
+                        tag: parts[1].to_string(),
+                        value: parts[2].trim_matches('"').to_string(),
+                    })
+                } else {
+                    Err(parse_error(format!(
+                        "Invalid CAA record format: expected 'flags tag value', got '{value}'"
+                    )))
+                }
+            }
+            "TLSA" => parse_tlsa_value(value, self.provider_name()),
+            "SSHFP" => parse_sshfp_value(value, self.provider_name()),
+            "DS" => parse_ds_value(value, self.provider_name()),
+            _ => Err(ProviderError::UnsupportedRecordType {
+                provider: self.provider_name().to_string(),
+                record_type: record_type.to_string(),
+            }),
+        }
+    }
+
+    /// 将 RecordData 转换为 Cloud DNS 记录值（目标域名需带末尾点）
+    fn record_data_to_value(data: &RecordData) -> String {
+        let fqdn = |name: &str| format!("{}.", normalize_domain_name(name));
+        match data {
+            RecordData::A { address } | RecordData::AAAA { address } => address.clone(),
+            RecordData::CNAME { target } | RecordData::ALIAS { target } => fqdn(target),
+            RecordData::MX { priority, exchange } => format!("{priority} {}", fqdn(exchange)),
+            RecordData::TXT { text } => format_quoted_txt(text),
+            RecordData::NS { nameserver } => fqdn(nameserver),
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => format!("{priority} {weight} {port} {}", fqdn(target)),
+            RecordData::CAA { flags, tag, value } => format!("{flags} {tag} \"{value}\""),
+            RecordData::TLSA { .. } | RecordData::SSHFP { .. } | RecordData::DS { .. } => {
+                data.display_value()
+            }
+        }
+    }
+
+    /// 转换托管区域为 ProviderDomain（域名 ID 使用区域名称）
+    fn zone_to_domain(zone: ManagedZone) -> ProviderDomain {
+        ProviderDomain {
+            id: zone.name,
+            name: normalize_domain_name(&zone.dns_name).to_lowercase(),
+            provider: ProviderType::GoogleCloud,
+            status: DomainStatus::Active,
+            record_count: None,
+        }
+    }
+
+    /// 获取全部托管区域（按 pageToken 翻页）
+    async fn list_all_managed_zones(&self) -> Result<Vec<ManagedZone>> {
+        let max_results = MAX_PAGE_SIZE.to_string();
+        let mut zones = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut query = vec![("maxResults", max_results.as_str())];
+            if let Some(ref token) = page_token {
+                query.push(("pageToken", token.as_str()));
+            }
+
+            let response: ListManagedZonesResponse = self
+                .get("managedZones", &query, ErrorContext::default())
+                .await?;
+            zones.extend(response.managed_zones);
+
+            match response.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => break,
+            }
+        }
+
+        Ok(zones)
+    }
+
+    /// 获取托管区域中的全部记录集（按 pageToken 翻页）
+    async fn list_all_record_sets(&self, zone: &str) -> Result<Vec<ResourceRecordSet>> {
+        let path = format!("managedZones/{}/rrsets", urlencoding::encode(zone));
+        let ctx = ErrorContext {
+            domain: Some(zone.to_string()),
+            ..Default::default()
+        };
+        let max_results = MAX_PAGE_SIZE.to_string();
+        let mut record_sets = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut query = vec![("maxResults", max_results.as_str())];
+            if let Some(ref token) = page_token {
+                query.push(("pageToken", token.as_str()));
+            }
+
+            let response: ListRrsetsResponse = self.get(&path, &query, ctx.clone()).await?;
+            record_sets.extend(response.rrsets);
+
+            match response.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => break,
+            }
+        }
+
+        Ok(record_sets)
+    }
+
+    /// 查找指定名称与类型的记录集（不含路由策略）
+    async fn find_record_set(
+        &self,
+        zone: &str,
+        full_name: &str,
+        record_type: &str,
+    ) -> Result<Option<ResourceRecordSet>> {
+        let path = format!("managedZones/{}/rrsets", urlencoding::encode(zone));
+        let name = format!("{full_name}.");
+        let ctx = ErrorContext {
+            domain: Some(zone.to_string()),
+            ..Default::default()
+        };
+
+        let response: ListRrsetsResponse = self
+            .get(&path, &[("name", &name), ("type", record_type)], ctx)
+            .await?;
+        Ok(response.rrsets.into_iter().find(|rrset| {
+            rrset.name.eq_ignore_ascii_case(&name)
+                && rrset.record_type == record_type
+                && rrset.routing_policy.is_none()
+        }))
+    }
+
+    /// 构造记录集
+    fn record_set(
+        name: &str,
+        record_type: &str,
+        ttl: u32,
+        rrdatas: Vec<String>,
+    ) -> ResourceRecordSet {
+        ResourceRecordSet {
+            name: format!("{name}."),
+            record_type: record_type.to_string(),
+            ttl: Some(ttl),
+            rrdatas,
+            routing_policy: None,
+        }
+    }
+
+    /// 提交变更（删除项必须与当前记录集完全一致）
+    async fn submit_change(
+        &self,
+        zone: &str,
+        additions: Vec<ResourceRecordSet>,
+        deletions: Vec<ResourceRecordSet>,
+        ctx: ErrorContext,
+    ) -> Result<()> {
+        let path = format!("managedZones/{}/changes", urlencoding::encode(zone));
+        let body = ChangeRequest {
+            additions,
+            deletions,
+        };
+        let _response: ChangeResponse = self.post(&path, &body, ctx).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DnsProvider for GoogleCloudProvider {
+    fn id(&self) -> &'static str {
+        "gcloud"
+    }
+
+    fn batch_concurrency(&self) -> usize {
+        self.batch_concurrency
+    }
+
+    fn metadata() -> ProviderMetadata {
+        ProviderMetadata {
+            id: ProviderType::GoogleCloud,
+            name: "Google Cloud DNS".to_string(),
+            description: "Google Cloud DNS 服务".to_string(),
+            required_fields: vec![
+                ProviderCredentialField {
+                    key: "serviceAccountJson".to_string(),
+                    label: "服务账号 JSON 密钥".to_string(),
+                    field_type: FieldType::Password,
+                    placeholder: Some("粘贴服务账号 JSON 密钥内容".to_string()),
+                    help_text: Some("服务账号需要 DNS Administrator 角色".to_string()),
+                },
+                ProviderCredentialField {
+                    key: "projectId".to_string(),
+                    label: "Project ID".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("输入 Project ID".to_string()),
+                    help_text: None,
+                },
+            ],
+            features: ProviderFeatures::default(),
+            limits: ProviderLimits {
+                max_page_size_domains: MAX_PAGE_SIZE,
+                max_page_size_records: MAX_PAGE_SIZE,
+                requests_per_second: None,
+                max_batch_size: 1,
+                max_records_per_zone: Some(10_000),
+                min_ttl: 0,
+                max_ttl: 2_147_483_647,
+                auto_ttl: None,
+            },
+        }
+    }
+
+    async fn validate_credentials(&self) -> Result<bool> {
+        match self
+            .get::<ListManagedZonesResponse>(
+                "managedZones",
+                &[("maxResults", "1")],
+                ErrorContext::default(),
+            )
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(ProviderError::InvalidCredentials { .. }) => Ok(false),
+            Err(e) => {
+                log::warn!("凭证验证失败: {e}");
+                Ok(false)
+            }
+        }
+    }
+
+    /// Cloud DNS 使用 pageToken 翻页，这里拉取全部托管区域后在内存中分页
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> Result<PaginatedResponse<ProviderDomain>> {
+        let zones = self.list_all_managed_zones().await?;
+        let total_count = zones.len() as u32;
+
+        let domains = zones
+            .into_iter()
+            .skip(((params.page.max(1) - 1) * params.page_size) as usize)
+            .take(params.page_size as usize)
+            .map(Self::zone_to_domain)
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            domains,
+            params.page,
+            params.page_size,
+            total_count,
+        ))
+    }
+
+    async fn get_domain(&self, domain_id: &str) -> Result<ProviderDomain> {
+        let path = format!("managedZones/{}", urlencoding::encode(domain_id));
+        let ctx = ErrorContext {
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let zone: ManagedZone = self.get(&path, &[], ctx).await?;
+        Ok(Self::zone_to_domain(zone))
+    }
+
+    /// 记录集按值展开后在内存中过滤与分页
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        let domain_info = self.get_domain(domain_id).await?;
+        let record_sets = self.list_all_record_sets(domain_id).await?;
+
+        let keyword = params
+            .keyword
+            .as_ref()
+            .filter(|k| !k.is_empty())
+            .map(|k| k.to_lowercase());
+        let type_filter = params.record_type.as_ref().map(record_type_to_string);
+
+        let mut records = Vec::new();
+        for rrset in &record_sets {
+            // 跳过 SOA 和带路由策略的记录集
+            if rrset.record_type == "SOA" || rrset.routing_policy.is_some() {
+                continue;
+            }
+            if type_filter.is_some_and(|t| t != rrset.record_type) {
+                continue;
+            }
+
+            let full_name = normalize_domain_name(&rrset.name).to_lowercase();
+            let name = full_name_to_relative(&full_name, &domain_info.name);
+
+            for value in &rrset.rrdatas {
+                let Ok(data) = self.parse_record_data(&rrset.record_type, value) else {
+                    continue;
+                };
+
+                if let Some(ref kw) = keyword
+                    && !name.to_lowercase().contains(kw)
+                    && !data.display_value().to_lowercase().contains(kw)
+                {
+                    continue;
+                }
+
+                records.push(DnsRecord {
+                    id: encode_rrset_record_id(&full_name, &rrset.record_type, value),
+                    domain_id: domain_id.to_string(),
+                    name: name.clone(),
+                    ttl: rrset.ttl.unwrap_or(300),
+                    data,
+                    proxied: None,
+                    comment: None,
+                    tags: Vec::new(),
+                    created_at: None,
+                    updated_at: None,
+                    routing_policy: None,
+                });
+            }
+        }
+
+        let total_count = records.len() as u32;
+        let records = records
+            .into_iter()
+            .skip(((params.page.max(1) - 1) * params.page_size) as usize)
+            .take(params.page_size as usize)
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            records,
+            params.page,
+            params.page_size,
+            total_count,
+        ))
+    }
+
+    /// 向记录集追加一个值（记录集不存在时创建），整个记录集的 TTL 会更新为请求值
+    async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        ensure_record_type_supported(&req.data, UNSUPPORTED_RECORD_TYPES, self.provider_name())?;
+        ensure_simple_routing(req.routing_policy.as_ref(), self.provider_name())?;
+        ensure_not_proxied(req.proxied, self.provider_name())?;
+        self.validate_ttl(req.ttl)?;
+        validate_record_data(&req.data, self.provider_name())?;
+        validate_record_data(&req.data, self.provider_name())?;
+        let domain_info = self.get_domain(&req.domain_id).await?;
+        let full_name = relative_to_full_name(&req.name, &domain_info.name).to_lowercase();
+        let record_type = record_type_to_string(&req.data.record_type());
+        let value = Self::record_data_to_value(&req.data);
+
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            domain: Some(req.domain_id.clone()),
+            ..Default::default()
+        };
+
+        let (additions, deletions) = match self
+            .find_record_set(&req.domain_id, &full_name, record_type)
+            .await?
+        {
+            Some(rrset) => {
+                if rrset.rrdatas.contains(&value) {
+                    return Err(ProviderError::RecordExists {
+                        provider: self.provider_name().to_string(),
+                        record_name: req.name.clone(),
+                        raw_message: None,
+                    });
+                }
+                let mut values = rrset.rrdatas.clone();
+                values.push(value.clone());
+                (
+                    vec![Self::record_set(&full_name, record_type, req.ttl, values)],
+                    vec![rrset],
+                )
+            }
+            None => (
+                vec![Self::record_set(
+                    &full_name,
+                    record_type,
+                    req.ttl,
+                    vec![value.clone()],
+                )],
+                Vec::new(),
+            ),
+        };
+
+        self.submit_change(&req.domain_id, additions, deletions, ctx)
+            .await?;
+
+        Ok(DnsRecord {
+            id: encode_rrset_record_id(&full_name, record_type, &value),
+            domain_id: req.domain_id.clone(),
+            name: full_name_to_relative(&full_name, &domain_info.name),
+            ttl: req.ttl,
+            data: req.data.clone(),
+            proxied: None,
+            comment: None,
+            tags: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            routing_policy: None,
+        })
+    }
+
+    /// 在同一个变更中移除旧值并写入新值，保证原子性
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<DnsRecord> {
+        ensure_record_type_supported(&req.data, UNSUPPORTED_RECORD_TYPES, self.provider_name())?;
+        ensure_not_proxied(req.proxied, self.provider_name())?;
+        self.validate_ttl(req.ttl)?;
+        validate_record_data(&req.data, self.provider_name())?;
+        validate_record_data(&req.data, self.provider_name())?;
+        let old = self.decode_record_id(record_id)?;
+        let domain_info = self.get_domain(&req.domain_id).await?;
+        let full_name = relative_to_full_name(&req.name, &domain_info.name).to_lowercase();
+        let record_type = record_type_to_string(&req.data.record_type());
+        let value = Self::record_data_to_value(&req.data);
+
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            record_id: Some(record_id.to_string()),
+            domain: Some(req.domain_id.clone()),
+        };
+        let not_found = || ProviderError::RecordNotFound {
+            provider: self.provider_name().to_string(),
+            record_id: record_id.to_string(),
+            raw_message: None,
+        };
+
+        let old_rrset = self
+            .find_record_set(&req.domain_id, &old.name, &old.record_type)
+            .await?
+            .ok_or_else(not_found)?;
+        if !old_rrset.rrdatas.contains(&old.value) {
+            return Err(not_found());
+        }
+
+        let mut additions = Vec::new();
+        let mut deletions = Vec::new();
+
+        if old.name == full_name && old.record_type == record_type {
+            // 同一记录集内替换值
+            let mut values: Vec<String> = old_rrset
+                .rrdatas
+                .iter()
+                .filter(|v| **v != old.value && **v != value)
+                .cloned()
+                .collect();
+            values.push(value.clone());
+            additions.push(Self::record_set(&full_name, record_type, req.ttl, values));
+            deletions.push(old_rrset);
+        } else {
+            // 名称或类型变化：从旧记录集移除，并追加到新记录集
+            let remaining: Vec<String> = old_rrset
+                .rrdatas
+                .iter()
+                .filter(|v| **v != old.value)
+                .cloned()
+                .collect();
+            if !remaining.is_empty() {
+                additions.push(Self::record_set(
+                    &old.name,
+                    &old.record_type,
+                    old_rrset.ttl.unwrap_or(300),
+                    remaining,
+                ));
+            }
+            deletions.push(old_rrset);
+
+            let mut new_values = match self
+                .find_record_set(&req.domain_id, &full_name, record_type)
+                .await?
+            {
+                Some(rrset) => {
+                    let values = rrset.rrdatas.clone();
+                    deletions.push(rrset);
+                    values
+                }
+                None => Vec::new(),
+            };
+            if !new_values.contains(&value) {
+                new_values.push(value.clone());
+            }
+            additions.push(Self::record_set(
+                &full_name,
+                record_type,
+                req.ttl,
+                new_values,
+            ));
+        }
+
+        self.submit_change(&req.domain_id, additions, deletions, ctx)
+            .await?;
+
+        Ok(DnsRecord {
+            id: encode_rrset_record_id(&full_name, record_type, &value),
+            domain_id: req.domain_id.clone(),
+            name: full_name_to_relative(&full_name, &domain_info.name),
+            ttl: req.ttl,
+            data: req.data.clone(),
+            proxied: None,
+            comment: None,
+            tags: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            routing_policy: None,
+        })
+    }
+
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
+        let key = self.decode_record_id(record_id)?;
+        let ctx = ErrorContext {
+            record_id: Some(record_id.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let not_found = || ProviderError::RecordNotFound {
+            provider: self.provider_name().to_string(),
+            record_id: record_id.to_string(),
+            raw_message: None,
+        };
+
+        let rrset = self
+            .find_record_set(domain_id, &key.name, &key.record_type)
+            .await?
+            .ok_or_else(not_found)?;
+        if !rrset.rrdatas.contains(&key.value) {
+            return Err(not_found());
+        }
+
+        // 集合为空时仅删除，否则写回剩余值
+        let remaining: Vec<String> = rrset
+            .rrdatas
+            .iter()
+            .filter(|v| **v != key.value)
+            .cloned()
+            .collect();
+        let additions = if remaining.is_empty() {
+            Vec::new()
+        } else {
+            vec![Self::record_set(
+                &key.name,
+                &key.record_type,
+                rrset.ttl.unwrap_or(300),
+                remaining,
+            )]
+        };
+
+        self.submit_change(domain_id, additions, vec![rrset], ctx)
+            .await
+    }
+}
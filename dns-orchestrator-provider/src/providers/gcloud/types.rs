@@ -0,0 +1,113 @@
+//! Google Cloud DNS API 类型定义
+
+use serde::{Deserialize, Serialize};
+
+// ============ OAuth2 ============
+
+/// 服务账号 JSON 密钥（仅需要的字段）
+#[derive(Debug, Deserialize)]
+pub struct ServiceAccountKey {
+    pub client_email: String,
+    pub private_key: String,
+    pub token_uri: Option<String>,
+}
+
+/// JWT Bearer 断言声明
+#[derive(Debug, Serialize)]
+pub struct JwtClaims<'a> {
+    pub iss: &'a str,
+    pub scope: &'a str,
+    pub aud: &'a str,
+    pub iat: i64,
+    pub exp: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TokenResponse {
+    pub access_token: String,
+    pub expires_in: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TokenErrorResponse {
+    pub error: String,
+    pub error_description: Option<String>,
+}
+
+// ============ Cloud DNS API 响应结构 ============
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListManagedZonesResponse {
+    #[serde(default)]
+    pub managed_zones: Vec<ManagedZone>,
+    pub next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedZone {
+    /// 区域名称（资源标识，如 `example-com`）
+    pub name: String,
+    /// DNS 名称（如 `example.com.`）
+    pub dns_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListRrsetsResponse {
+    #[serde(default)]
+    pub rrsets: Vec<ResourceRecordSet>,
+    pub next_page_token: Option<String>,
+}
+
+/// 记录集（同时用于变更请求）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceRecordSet {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub record_type: String,
+    pub ttl: Option<u32>,
+    #[serde(default)]
+    pub rrdatas: Vec<String>,
+    /// 路由策略（地理/加权等），无法映射到单值模型
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing_policy: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChangeRequest {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub additions: Vec<ResourceRecordSet>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub deletions: Vec<ResourceRecordSet>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChangeResponse {
+    #[allow(dead_code)]
+    pub id: Option<String>,
+    #[allow(dead_code)]
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ErrorResponse {
+    pub error: ErrorBody,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ErrorBody {
+    pub message: Option<String>,
+    /// gRPC 风格状态，如 `NOT_FOUND`
+    pub status: Option<String>,
+    #[serde(default)]
+    pub errors: Vec<ErrorItem>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ErrorItem {
+    /// 错误原因，如 `notFound`、`alreadyExists`
+    pub reason: Option<String>,
+}
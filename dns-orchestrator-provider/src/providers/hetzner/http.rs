@@ -0,0 +1,108 @@
+//! Hetzner HTTP 请求方法
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::error::Result;
+use crate::http_client::HttpUtils;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::types::ErrorResponse;
+use super::{HETZNER_API_BASE, HetznerProvider};
+
+impl HetznerProvider {
+    // ==================== 辅助方法 ====================
+
+    /// 统一处理 Hetzner 响应错误
+    ///
+    /// Hetzner 的错误体没有业务错误码，使用 HTTP 状态码作为错误码
+    fn handle_response_error(
+        &self,
+        status: u16,
+        response_text: &str,
+        ctx: ErrorContext,
+    ) -> Result<()> {
+        if (200..300).contains(&status) {
+            return Ok(());
+        }
+
+        let message = serde_json::from_str::<ErrorResponse>(response_text)
+            .ok()
+            .and_then(|e| e.error.map(|b| b.message).or(e.message))
+            .filter(|m| !m.is_empty())
+            .unwrap_or_else(|| format!("HTTP {status}: {response_text}"));
+        Err(self.map_error(
+            RawApiError::with_code(status.to_string(), message).with_status(status),
+            ctx,
+        ))
+    }
+
+    /// 执行请求，返回响应文本
+    async fn request<B: Serialize + ?Sized>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&B>,
+        ctx: ErrorContext,
+    ) -> Result<String> {
+        let url = format!("{HETZNER_API_BASE}{path}");
+
+        let mut request = self
+            .client
+            .request(method.clone(), &url)
+            .header("Auth-API-Token", &self.api_token);
+        if let Some(body) = body {
+            if log::log_enabled!(log::Level::Debug) {
+                let body_json = serde_json::to_string_pretty(body)
+                    .unwrap_or_else(|_| "无法序列化请求体".to_string());
+                log::debug!("Request Body: {body_json}");
+            }
+            request = request.json(body);
+        }
+
+        let (status, response_text) = HttpUtils::execute_request_with_retry(
+            request,
+            self.provider_name(),
+            method.as_str(),
+            &url,
+            self.max_retries,
+        )
+        .await?;
+
+        self.handle_response_error(status, &response_text, ctx)?;
+        Ok(response_text)
+    }
+
+    // ==================== 公开 API 方法 ====================
+
+    /// 执行 GET 请求
+    pub(crate) async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        let response_text = self
+            .request::<()>(reqwest::Method::GET, path, None, ctx)
+            .await?;
+        HttpUtils::parse_json(&response_text, self.provider_name())
+    }
+
+    /// 执行带 body 的请求（POST/PUT）
+    pub(crate) async fn request_with_body<T: DeserializeOwned, B: Serialize + ?Sized>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: &B,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        let response_text = self.request(method, path, Some(body), ctx).await?;
+        HttpUtils::parse_json(&response_text, self.provider_name())
+    }
+
+    /// 执行 DELETE 请求
+    pub(crate) async fn delete(&self, path: &str, ctx: ErrorContext) -> Result<()> {
+        self.request::<()>(reqwest::Method::DELETE, path, None, ctx)
+            .await?;
+        Ok(())
+    }
+}
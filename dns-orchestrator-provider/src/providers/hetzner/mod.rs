@@ -0,0 +1,85 @@
+//! Hetzner DNS Provider
+
+mod error;
+mod http;
+mod provider;
+mod types;
+
+use reqwest::Client;
+
+use crate::http_client::ProxyConfig;
+use crate::providers::common::create_http_client;
+use crate::traits::DEFAULT_BATCH_CONCURRENCY;
+use crate::types::DnsRecordType;
+
+pub(crate) const HETZNER_API_BASE: &str = "https://dns.hetzner.com/api/v1";
+/// Hetzner API 单页最大数量
+pub(crate) const MAX_PAGE_SIZE: u32 = 100;
+/// 单次 bulk 请求的最大记录数（官方未公布上限，取保守值）
+pub(crate) const MAX_BATCH_SIZE: u32 = 100;
+/// 无原生支持的记录类型
+pub(crate) const UNSUPPORTED_RECORD_TYPES: &[DnsRecordType] =
+    &[DnsRecordType::Alias, DnsRecordType::Sshfp];
+
+/// Hetzner DNS Provider
+pub struct HetznerProvider {
+    pub(crate) client: Client,
+    pub(crate) api_token: String,
+    pub(crate) max_retries: u32,
+    pub(crate) batch_concurrency: usize,
+}
+
+/// Hetzner DNS Provider Builder
+pub struct HetznerProviderBuilder {
+    api_token: String,
+    max_retries: u32,
+    proxy: Option<ProxyConfig>,
+    batch_concurrency: usize,
+}
+
+impl HetznerProviderBuilder {
+    fn new(api_token: String) -> Self {
+        Self {
+            api_token,
+            max_retries: 2,
+            proxy: None,
+            batch_concurrency: DEFAULT_BATCH_CONCURRENCY,
+        }
+    }
+
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// 设置 HTTP 代理
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// 设置批量操作的最大并发请求数（默认 5）
+    pub fn batch_concurrency(mut self, concurrency: usize) -> Self {
+        self.batch_concurrency = concurrency.max(1);
+        self
+    }
+
+    pub fn build(self) -> HetznerProvider {
+        HetznerProvider {
+            client: create_http_client(self.proxy.as_ref()),
+            api_token: self.api_token,
+            max_retries: self.max_retries,
+            batch_concurrency: self.batch_concurrency,
+        }
+    }
+}
+
+impl HetznerProvider {
+    pub fn new(api_token: String) -> Self {
+        Self::builder(api_token).build()
+    }
+
+    pub fn builder(api_token: String) -> HetznerProviderBuilder {
+        HetznerProviderBuilder::new(api_token)
+    }
+}
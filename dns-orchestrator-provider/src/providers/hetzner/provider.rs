@@ -0,0 +1,731 @@
+//! Hetzner DnsProvider trait 实现
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::error::{ProviderError, Result};
+use crate::providers::common::{
+    canonical_record_name, ensure_not_proxied, ensure_record_type_supported, ensure_simple_routing,
+    format_quoted_txt, normalize_domain_name, parse_ds_value, parse_quoted_txt, parse_tlsa_value,
+    record_type_to_string, relative_to_full_name, validate_record_data,
+};
+use crate::traits::{DnsProvider, ErrorContext, ProviderErrorMapper};
+use crate::types::{
+    BatchCreateFailure, BatchCreateResult, BatchUpdateFailure, BatchUpdateItem, BatchUpdateResult,
+    CreateDnsRecordRequest, DnsRecord, DomainStatus, FieldType, PaginatedResponse,
+    PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures, ProviderLimits,
+    ProviderMetadata, ProviderType, RecordData, RecordQueryParams, UpdateDnsRecordRequest,
+};
+
+use super::types::{
+    BulkCreateResponse, BulkRecordsRequest, BulkUpdateResponse, GetZoneResponse, HetznerRecord,
+    HetznerZone, ListRecordsResponse, ListZonesResponse, RecordBody, RecordResponse,
+};
+use super::{HetznerProvider, MAX_BATCH_SIZE, MAX_PAGE_SIZE, UNSUPPORTED_RECORD_TYPES};
+
+/// 区域未返回默认 TTL 时使用的值
+const DEFAULT_ZONE_TTL: u32 = 86400;
+
+impl HetznerProvider {
+    /// 解析 Hetzner 时间戳（如 `2020-05-04 10:17:12.348 +0000 UTC`）
+    fn parse_timestamp(value: Option<&str>) -> Option<DateTime<Utc>> {
+        let value = value?.trim_end_matches(" UTC");
+        DateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f %z")
+            .ok()
+            .map(|dt| dt.with_timezone(&Utc))
+    }
+
+    /// 解析目标域名：带末尾点为绝对名称，否则相对于区域
+    fn resolve_target(value: &str, zone_name: &str) -> String {
+        if value.ends_with('.') {
+            normalize_domain_name(value)
+        } else {
+            relative_to_full_name(value, zone_name)
+        }
+    }
+
+    /// 解析 Hetzner 记录值为 RecordData
+    fn parse_record_data(
+        &self,
+        record_type: &str,
+        value: &str,
+        zone_name: &str,
+    ) -> Result<RecordData> {
+        let parse_error = |detail: String| ProviderError::ParseError {
+            provider: self.provider_name().to_string(),
+            detail,
+        };
+
+        match record_type {
+            "A" => Ok(RecordData::A {
+                address: value.to_string(),
+            }),
+            "AAAA" => Ok(RecordData::AAAA {
+                address: value.to_string(),
+            }),
+            "CNAME" => Ok(RecordData::CNAME {
+                target: Self::resolve_target(value, zone_name),
+            }),
+            "NS" => Ok(RecordData::NS {
+                nameserver: Self::resolve_target(value, zone_name),
+            }),
+            "TXT" => Ok(RecordData::TXT {
+                text: parse_quoted_txt(value),
+            }),
+            "MX" => {
+                // Hetzner MX 格式: "priority exchange"
+                let parts: Vec<&str> = value.splitn(2, ' ').collect();
+                if parts.len() == 2 {
+                    Ok(RecordData::MX {
+                        priority: parts[0].parse().map_err(|_| {
+                            parse_error(format!("Invalid MX priority: '{}'", parts[0]))
+                        })?,
+                        exchange: Self::resolve_target(parts[1], zone_name),
+                    })
+                } else {
+                    Err(parse_error(format!(
+                        "Invalid MX record format: expected 'priority exchange', got '{value}'"
+                    )))
+                }
+            }
+            "SRV" => {
+                // Hetzner SRV 格式: "priority weight port target"
+                let parts: Vec<&str> = value.splitn(4, ' ').collect();
+                if parts.len() == 4 {
+                    Ok(RecordData::SRV {
+                        priority: parts[0].parse().map_err(|_| {
+                            parse_error(format!("Invalid SRV priority: '{}'", parts[0]))
+                        })?,
+                        weight: parts[1].parse().map_err(|_| {
+                            parse_error(format!("Invalid SRV weight: '{}'", parts[1]))
+                        })?,
+                        port: parts[2].parse().map_err(|_| {
+                            parse_error(format!("Invalid SRV port: '{}'", parts[2]))
+                        })?,
+                        target: Self::resolve_target(parts[3], zone_name),
+                    })
+                } else {
+                    Err(parse_error(format!(
+                        "Invalid SRV record format: expected 'priority weight port target', got '{value}'"
+                    )))
+                }
+            }
+            "CAA" => {
+                // Hetzner CAA 格式: flags tag "value"
+                let parts: Vec<&str> = value.splitn(3, ' ').collect();
+                if parts.len() == 3 {
+                    Ok(RecordData::CAA {
+                        flags: parts[0].parse().map_err(|_| {
+                            parse_error(format!("Invalid CAA flags: '{}'", parts[0]))
+                        })?,
+                        tag: parts[1].to_string(),
+                        value: parts[2].trim_matches('"').to_string(),
+                    })
+                } else {
+                    Err(parse_error(format!(
+                        "Invalid CAA record format: expected 'flags tag value', got '{value}'"
+                    )))
+                }
+            }
+            "TLSA" => parse_tlsa_value(value, self.provider_name()),
+            "DS" => parse_ds_value(value, self.provider_name()),
+            _ => Err(ProviderError::UnsupportedRecordType {
+                provider: self.provider_name().to_string(),
+                record_type: record_type.to_string(),
+            }),
+        }
+    }
+
+    /// 将 RecordData 转换为 Hetzner 记录值（目标域名带末尾点，避免被视为相对名称）
+    fn record_data_to_value(data: &RecordData) -> String {
+        let fqdn = |name: &str| format!("{}.", normalize_domain_name(name));
+        match data {
+            RecordData::A { address } | RecordData::AAAA { address } => address.clone(),
+            RecordData::CNAME { target } | RecordData::ALIAS { target } => fqdn(target),
+            RecordData::MX { priority, exchange } => format!("{priority} {}", fqdn(exchange)),
+            RecordData::TXT { text } => format_quoted_txt(text),
+            RecordData::NS { nameserver } => fqdn(nameserver),
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => format!("{priority} {weight} {port} {}", fqdn(target)),
+            RecordData::CAA { flags, tag, value } => format!("{flags} {tag} \"{value}\""),
+            RecordData::TLSA { .. } | RecordData::SSHFP { .. } | RecordData::DS { .. } => {
+                data.display_value()
+            }
+        }
+    }
+
+    /// 构造请求体，TTL 为 0 时不传，使用区域默认 TTL
+    fn record_body(
+        id: Option<String>,
+        domain_id: &str,
+        name: &str,
+        zone_name: &str,
+        ttl: u32,
+        data: &RecordData,
+    ) -> RecordBody {
+        RecordBody {
+            id,
+            zone_id: domain_id.to_string(),
+            record_type: record_type_to_string(&data.record_type()).to_string(),
+            name: canonical_record_name(name, zone_name),
+            value: Self::record_data_to_value(data),
+            ttl: (ttl > 0).then_some(ttl),
+        }
+    }
+
+    /// 转换 Hetzner 记录为 DnsRecord，TTL 未设置或为 0 时使用区域默认 TTL
+    fn convert_record(&self, record: HetznerRecord, zone: &HetznerZone) -> Result<DnsRecord> {
+        let data = self.parse_record_data(&record.record_type, &record.value, &zone.name)?;
+        Ok(DnsRecord {
+            id: record.id,
+            domain_id: record.zone_id,
+            name: canonical_record_name(&record.name, &zone.name),
+            ttl: record
+                .ttl
+                .filter(|t| *t > 0)
+                .or(zone.ttl)
+                .unwrap_or(DEFAULT_ZONE_TTL),
+            data,
+            proxied: None,
+            comment: None,
+            tags: Vec::new(),
+            created_at: Self::parse_timestamp(record.created.as_deref()),
+            updated_at: Self::parse_timestamp(record.modified.as_deref()),
+            routing_policy: None,
+        })
+    }
+
+    fn zone_to_domain(zone: HetznerZone) -> ProviderDomain {
+        let status = if zone.paused {
+            DomainStatus::Paused
+        } else {
+            match zone.status.as_deref() {
+                Some("verified") => DomainStatus::Active,
+                Some("pending") => DomainStatus::Pending,
+                Some("failed") => DomainStatus::Error,
+                _ => DomainStatus::Unknown,
+            }
+        };
+        ProviderDomain {
+            id: zone.id,
+            name: zone.name,
+            provider: ProviderType::Hetzner,
+            status,
+            record_count: zone.records_count,
+        }
+    }
+
+    async fn get_zone(&self, zone_id: &str) -> Result<HetznerZone> {
+        let ctx = ErrorContext {
+            domain: Some(zone_id.to_string()),
+            ..Default::default()
+        };
+        let response: GetZoneResponse = self.get(&format!("/zones/{zone_id}"), ctx).await?;
+        Ok(response.zone)
+    }
+
+    /// 获取区域中的全部记录（Hetzner 不支持按名称/值搜索）
+    async fn list_all_records(&self, zone_id: &str) -> Result<Vec<HetznerRecord>> {
+        let ctx = ErrorContext {
+            domain: Some(zone_id.to_string()),
+            ..Default::default()
+        };
+        let mut records = Vec::new();
+        let mut page = 1u32;
+
+        loop {
+            let path = format!(
+                "/records?zone_id={}&page={page}&per_page={MAX_PAGE_SIZE}",
+                urlencoding::encode(zone_id)
+            );
+            let response: ListRecordsResponse = self.get(&path, ctx.clone()).await?;
+            let fetched = response.records.len();
+            records.extend(response.records);
+
+            let last_page = response
+                .meta
+                .and_then(|m| m.pagination)
+                .map_or(1, |p| p.last_page);
+            if fetched == 0 || page >= last_page {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(records)
+    }
+
+    /// 批量获取请求中涉及的区域
+    async fn get_zones(
+        &self,
+        domain_ids: impl Iterator<Item = &String>,
+    ) -> Result<HashMap<String, HetznerZone>> {
+        let mut zones = HashMap::new();
+        for domain_id in domain_ids {
+            if !zones.contains_key(domain_id) {
+                zones.insert(domain_id.clone(), self.get_zone(domain_id).await?);
+            }
+        }
+        Ok(zones)
+    }
+
+    /// 批量提交前校验单条记录（与单条创建/更新的校验一致）
+    fn check_bulk_item(&self, data: &RecordData, ttl: u32, proxied: Option<bool>) -> Result<()> {
+        ensure_record_type_supported(data, UNSUPPORTED_RECORD_TYPES, self.provider_name())?;
+        ensure_not_proxied(proxied, self.provider_name())?;
+        self.validate_ttl(ttl)?;
+        validate_record_data(data, self.provider_name())
+    }
+
+    /// 单次 `POST /records/bulk` 请求创建一批记录
+    async fn bulk_create(&self, requests: &[CreateDnsRecordRequest]) -> Result<BatchCreateResult> {
+        let zones = self
+            .get_zones(requests.iter().map(|r| &r.domain_id))
+            .await?;
+        let bodies: Vec<RecordBody> = requests
+            .iter()
+            .map(|r| {
+                let zone_name = &zones[&r.domain_id].name;
+                Self::record_body(None, &r.domain_id, &r.name, zone_name, r.ttl, &r.data)
+            })
+            .collect();
+
+        let response: BulkCreateResponse = self
+            .request_with_body(
+                reqwest::Method::POST,
+                "/records/bulk",
+                &BulkRecordsRequest { records: &bodies },
+                ErrorContext::default(),
+            )
+            .await?;
+
+        // 响应不含请求序号，按 区域/名称/类型/值 将创建结果对应回请求
+        let mut created = response.records;
+        let mut result = BatchCreateResult {
+            success_count: 0,
+            failed_count: 0,
+            created_records: Vec::new(),
+            failures: Vec::new(),
+        };
+
+        for (index, (req, body)) in requests.iter().zip(&bodies).enumerate() {
+            let matched = created.iter().position(|r| {
+                r.zone_id == body.zone_id
+                    && r.name == body.name
+                    && r.record_type == body.record_type
+                    && r.value == body.value
+            });
+            let converted = matched
+                .map(|i| created.remove(i))
+                .and_then(|r| self.convert_record(r, &zones[&req.domain_id]).ok());
+
+            if let Some(record) = converted {
+                result.success_count += 1;
+                result.created_records.push(record);
+            } else {
+                let reason = if response.invalid_records.iter().any(|r| {
+                    r.zone_id == body.zone_id && r.name == body.name && r.value == body.value
+                }) {
+                    "Rejected by Hetzner as invalid record"
+                } else {
+                    "Record not found in bulk create response"
+                };
+                result.failed_count += 1;
+                result.failures.push(BatchCreateFailure {
+                    request_index: index,
+                    record_name: req.name.clone(),
+                    reason: reason.to_string(),
+                });
+            }
+        }
+
+        Ok(result)
+    }
+    /// 单次 `PUT /records/bulk` 请求更新一批记录
+    async fn bulk_update(&self, updates: &[BatchUpdateItem]) -> Result<BatchUpdateResult> {
+        let zones = self
+            .get_zones(updates.iter().map(|u| &u.request.domain_id))
+            .await?;
+        let bodies: Vec<RecordBody> = updates
+            .iter()
+            .map(|u| {
+                Self::record_body(
+                    Some(u.record_id.clone()),
+                    &u.request.domain_id,
+                    &u.request.name,
+                    &zones[&u.request.domain_id].name,
+                    u.request.ttl,
+                    &u.request.data,
+                )
+            })
+            .collect();
+
+        let response: BulkUpdateResponse = self
+            .request_with_body(
+                reqwest::Method::PUT,
+                "/records/bulk",
+                &BulkRecordsRequest { records: &bodies },
+                ErrorContext::default(),
+            )
+            .await?;
+
+        let mut updated: HashMap<String, HetznerRecord> = response
+            .records
+            .into_iter()
+            .map(|r| (r.id.clone(), r))
+            .collect();
+        let mut result = BatchUpdateResult {
+            success_count: 0,
+            failed_count: 0,
+            updated_records: Vec::new(),
+            failures: Vec::new(),
+        };
+
+        for update in updates {
+            let converted = updated.remove(&update.record_id).and_then(|r| {
+                self.convert_record(r, &zones[&update.request.domain_id])
+                    .ok()
+            });
+
+            if let Some(record) = converted {
+                result.success_count += 1;
+                result.updated_records.push(record);
+            } else {
+                let reason = if response
+                    .failed_records
+                    .iter()
+                    .any(|r| r.id.as_deref() == Some(update.record_id.as_str()))
+                {
+                    "Rejected by Hetzner"
+                } else {
+                    "Record not found in bulk update response"
+                };
+                result.failed_count += 1;
+                result.failures.push(BatchUpdateFailure {
+                    record_id: update.record_id.clone(),
+                    reason: reason.to_string(),
+                });
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl DnsProvider for HetznerProvider {
+    fn id(&self) -> &'static str {
+        "hetzner"
+    }
+
+    fn batch_concurrency(&self) -> usize {
+        self.batch_concurrency
+    }
+
+    fn batch_chunk_size(&self) -> usize {
+        MAX_BATCH_SIZE as usize
+    }
+
+    fn metadata() -> ProviderMetadata {
+        ProviderMetadata {
+            id: ProviderType::Hetzner,
+            name: "Hetzner".to_string(),
+            description: "Hetzner DNS 服务".to_string(),
+            required_fields: vec![ProviderCredentialField {
+                key: "apiToken".to_string(),
+                label: "API Token".to_string(),
+                field_type: FieldType::Password,
+                placeholder: Some("输入 Hetzner DNS API Token".to_string()),
+                help_text: Some("在 Hetzner DNS Console 的 API Tokens 页面创建".to_string()),
+            }],
+            features: ProviderFeatures::default(),
+            limits: ProviderLimits {
+                max_page_size_domains: MAX_PAGE_SIZE,
+                max_page_size_records: MAX_PAGE_SIZE,
+                requests_per_second: None,
+                max_batch_size: MAX_BATCH_SIZE,
+                max_records_per_zone: None,
+                min_ttl: 60,
+                max_ttl: 2_147_483_647,
+                auto_ttl: Some(0),
+            },
+        }
+    }
+
+    async fn validate_credentials(&self) -> Result<bool> {
+        match self
+            .get::<ListZonesResponse>("/zones?per_page=1", ErrorContext::default())
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(ProviderError::InvalidCredentials { .. }) => Ok(false),
+            Err(e) => {
+                log::warn!("凭证验证失败: {e}");
+                Ok(false)
+            }
+        }
+    }
+
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> Result<PaginatedResponse<ProviderDomain>> {
+        let page_size = params.page_size.min(MAX_PAGE_SIZE);
+        let path = format!("/zones?page={}&per_page={page_size}", params.page.max(1));
+        let response: ListZonesResponse = self.get(&path, ErrorContext::default()).await?;
+
+        let total_count = response
+            .meta
+            .and_then(|m| m.pagination)
+            .map_or(response.zones.len() as u32, |p| p.total_entries);
+        let domains = response
+            .zones
+            .into_iter()
+            .map(Self::zone_to_domain)
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            domains,
+            params.page,
+            page_size,
+            total_count,
+        ))
+    }
+
+    async fn get_domain(&self, domain_id: &str) -> Result<ProviderDomain> {
+        Ok(Self::zone_to_domain(self.get_zone(domain_id).await?))
+    }
+
+    /// 拉取全部记录后在内存中过滤与分页
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        let zone = self.get_zone(domain_id).await?;
+        let records = self.list_all_records(domain_id).await?;
+
+        let keyword = params
+            .keyword
+            .as_ref()
+            .filter(|k| !k.is_empty())
+            .map(|k| k.to_lowercase());
+        let type_filter = params.record_type.as_ref().map(record_type_to_string);
+
+        let records: Vec<DnsRecord> = records
+            .into_iter()
+            .filter(|r| type_filter.is_none_or(|t| t == r.record_type))
+            // 跳过 SOA 及不支持的类型
+            .filter_map(|r| self.convert_record(r, &zone).ok())
+            .filter(|r| {
+                keyword.as_ref().is_none_or(|kw| {
+                    r.name.to_lowercase().contains(kw)
+                        || r.data.display_value().to_lowercase().contains(kw)
+                })
+            })
+            .collect();
+
+        let total_count = records.len() as u32;
+        let records = records
+            .into_iter()
+            .skip(((params.page.max(1) - 1) * params.page_size) as usize)
+            .take(params.page_size as usize)
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            records,
+            params.page,
+            params.page_size,
+            total_count,
+        ))
+    }
+
+    async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        ensure_record_type_supported(&req.data, UNSUPPORTED_RECORD_TYPES, self.provider_name())?;
+        ensure_simple_routing(req.routing_policy.as_ref(), self.provider_name())?;
+        ensure_not_proxied(req.proxied, self.provider_name())?;
+        self.validate_ttl(req.ttl)?;
+        validate_record_data(&req.data, self.provider_name())?;
+        let zone = self.get_zone(&req.domain_id).await?;
+        let body = Self::record_body(
+            None,
+            &req.domain_id,
+            &req.name,
+            &zone.name,
+            req.ttl,
+            &req.data,
+        );
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            domain: Some(req.domain_id.clone()),
+            ..Default::default()
+        };
+
+        let response: RecordResponse = self
+            .request_with_body(reqwest::Method::POST, "/records", &body, ctx)
+            .await?;
+        self.convert_record(response.record, &zone)
+    }
+
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<DnsRecord> {
+        ensure_record_type_supported(&req.data, UNSUPPORTED_RECORD_TYPES, self.provider_name())?;
+        ensure_not_proxied(req.proxied, self.provider_name())?;
+        self.validate_ttl(req.ttl)?;
+        validate_record_data(&req.data, self.provider_name())?;
+        let zone = self.get_zone(&req.domain_id).await?;
+        let body = Self::record_body(
+            None,
+            &req.domain_id,
+            &req.name,
+            &zone.name,
+            req.ttl,
+            &req.data,
+        );
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            record_id: Some(record_id.to_string()),
+            domain: Some(req.domain_id.clone()),
+        };
+
+        let response: RecordResponse = self
+            .request_with_body(
+                reqwest::Method::PUT,
+                &format!("/records/{record_id}"),
+                &body,
+                ctx,
+            )
+            .await?;
+        self.convert_record(response.record, &zone)
+    }
+
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
+        let ctx = ErrorContext {
+            record_id: Some(record_id.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        self.delete(&format!("/records/{record_id}"), ctx).await
+    }
+
+    /// 使用 `POST /records/bulk` 批量创建，超过 `max_batch_size` 时自动分批
+    async fn batch_create_records(
+        &self,
+        requests: &[CreateDnsRecordRequest],
+    ) -> Result<BatchCreateResult> {
+        let mut result = BatchCreateResult {
+            success_count: 0,
+            failed_count: 0,
+            created_records: Vec::new(),
+            failures: Vec::new(),
+        };
+
+        // 校验失败的请求记为失败，其余照常提交；`indices` 记录提交项在原请求中的下标
+        let mut indices = Vec::new();
+        let mut valid = Vec::new();
+        for (request_index, req) in requests.iter().enumerate() {
+            let checked = ensure_simple_routing(req.routing_policy.as_ref(), self.provider_name())
+                .and_then(|()| self.check_bulk_item(&req.data, req.ttl, req.proxied));
+            match checked {
+                Ok(()) => {
+                    indices.push(request_index);
+                    valid.push(req.clone());
+                }
+                Err(e) => result.failures.push(BatchCreateFailure {
+                    request_index,
+                    record_name: req.name.clone(),
+                    reason: e.to_string(),
+                }),
+            }
+        }
+
+        let max_batch_size = Self::metadata().limits.max_batch_size.max(1) as usize;
+        for (chunk_index, chunk) in valid.chunks(max_batch_size).enumerate() {
+            let offset = chunk_index * max_batch_size;
+            // 单批请求失败时整批记为失败，不丢弃前面批次已完成的结果
+            let chunk_result = match self.bulk_create(chunk).await {
+                Ok(chunk_result) => chunk_result,
+                Err(e) => {
+                    let reason = e.to_string();
+                    result
+                        .failures
+                        .extend(chunk.iter().enumerate().map(|(i, req)| BatchCreateFailure {
+                            request_index: indices[offset + i],
+                            record_name: req.name.clone(),
+                            reason: reason.clone(),
+                        }));
+                    continue;
+                }
+            };
+            result.success_count += chunk_result.success_count;
+            result.created_records.extend(chunk_result.created_records);
+            result
+                .failures
+                .extend(chunk_result.failures.into_iter().map(|mut failure| {
+                    failure.request_index = indices[offset + failure.request_index];
+                    failure
+                }));
+        }
+        result.failed_count = result.failures.len();
+
+        Ok(result)
+    }
+
+    /// 使用 `PUT /records/bulk` 批量更新，超过 `max_batch_size` 时自动分批
+    async fn batch_update_records(&self, updates: &[BatchUpdateItem]) -> Result<BatchUpdateResult> {
+        let mut result = BatchUpdateResult {
+            success_count: 0,
+            failed_count: 0,
+            updated_records: Vec::new(),
+            failures: Vec::new(),
+        };
+
+        // 校验失败的更新记为失败，其余照常提交
+        let mut valid = Vec::new();
+        for update in updates {
+            match self.check_bulk_item(
+                &update.request.data,
+                update.request.ttl,
+                update.request.proxied,
+            ) {
+                Ok(()) => valid.push(update.clone()),
+                Err(e) => result.failures.push(BatchUpdateFailure {
+                    record_id: update.record_id.clone(),
+                    reason: e.to_string(),
+                }),
+            }
+        }
+
+        let max_batch_size = Self::metadata().limits.max_batch_size.max(1) as usize;
+        for chunk in valid.chunks(max_batch_size) {
+            let chunk_result = match self.bulk_update(chunk).await {
+                Ok(chunk_result) => chunk_result,
+                Err(e) => {
+                    let reason = e.to_string();
+                    result
+                        .failures
+                        .extend(chunk.iter().map(|update| BatchUpdateFailure {
+                            record_id: update.record_id.clone(),
+                            reason: reason.clone(),
+                        }));
+                    continue;
+                }
+            };
+            result.success_count += chunk_result.success_count;
+            result.updated_records.extend(chunk_result.updated_records);
+            result.failures.extend(chunk_result.failures);
+        }
+        result.failed_count = result.failures.len();
+
+        Ok(result)
+    }
+}
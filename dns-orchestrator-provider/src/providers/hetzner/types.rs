@@ -0,0 +1,118 @@
+//! Hetzner DNS API 类型定义
+
+use serde::{Deserialize, Serialize};
+
+// ============ Hetzner API 响应结构 ============
+
+#[derive(Debug, Deserialize)]
+pub struct ListZonesResponse {
+    #[serde(default)]
+    pub zones: Vec<HetznerZone>,
+    pub meta: Option<Meta>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetZoneResponse {
+    pub zone: HetznerZone,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HetznerZone {
+    pub id: String,
+    pub name: String,
+    /// 区域默认 TTL（记录未设置 TTL 时使用）
+    pub ttl: Option<u32>,
+    /// `verified` / `pending` / `failed`
+    pub status: Option<String>,
+    #[serde(default)]
+    pub paused: bool,
+    pub records_count: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Meta {
+    pub pagination: Option<Pagination>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Pagination {
+    pub last_page: u32,
+    pub total_entries: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListRecordsResponse {
+    #[serde(default)]
+    pub records: Vec<HetznerRecord>,
+    pub meta: Option<Meta>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RecordResponse {
+    pub record: HetznerRecord,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HetznerRecord {
+    pub id: String,
+    pub zone_id: String,
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// 相对名称，`@` 表示根域
+    pub name: String,
+    pub value: String,
+    /// 未设置或为 0 时使用区域默认 TTL
+    pub ttl: Option<u32>,
+    pub created: Option<String>,
+    pub modified: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BulkCreateResponse {
+    #[serde(default)]
+    pub records: Vec<HetznerRecord>,
+    #[serde(default)]
+    pub invalid_records: Vec<RecordBody>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BulkUpdateResponse {
+    #[serde(default)]
+    pub records: Vec<HetznerRecord>,
+    #[serde(default)]
+    pub failed_records: Vec<RecordBody>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ErrorResponse {
+    pub error: Option<ErrorBody>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ErrorBody {
+    pub message: String,
+}
+
+// ============ Hetzner API 请求结构 ============
+
+/// 记录请求体（同时用于批量接口的失败项）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordBody {
+    /// 仅批量更新时需要
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub zone_id: String,
+    #[serde(rename = "type")]
+    pub record_type: String,
+    pub name: String,
+    pub value: String,
+    /// 为 `None` 时使用区域默认 TTL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BulkRecordsRequest<'a> {
+    pub records: &'a [RecordBody],
+}
@@ -7,8 +7,8 @@ use crate::error::{ProviderError, Result};
 use crate::http_client::HttpUtils;
 use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
 
+use super::HuaweicloudProvider;
 use super::types::ErrorResponse;
-use super::{HUAWEICLOUD_DNS_HOST, HuaweicloudProvider};
 
 impl HuaweicloudProvider {
     // ==================== 辅助方法 ====================
@@ -32,13 +32,14 @@ impl HuaweicloudProvider {
                     error
                         .message
                         .unwrap_or_else(|| "No error message provided by API".to_string()),
-                ),
+                )
+                .with_status(status),
                 ctx,
             ));
         }
 
         // 回退到通用错误
-        Err(self.unknown_error(RawApiError::new(format!("HTTP {status}: {response_text}"))))
+        Err(self.unknown_error(RawApiError::new(response_text).with_status(status)))
     }
 
     /// 执行带 body 的请求（POST/PUT）
@@ -65,13 +66,13 @@ impl HuaweicloudProvider {
         let timestamp = now.format("%Y%m%dT%H%M%SZ").to_string();
 
         let headers = vec![
-            ("Host".to_string(), HUAWEICLOUD_DNS_HOST.to_string()),
+            ("Host".to_string(), self.host.clone()),
             ("X-Sdk-Date".to_string(), timestamp.clone()),
             ("Content-Type".to_string(), "application/json".to_string()),
         ];
 
         let authorization = self.sign(method, path, "", &headers, &payload, &timestamp);
-        let url = format!("https://{HUAWEICLOUD_DNS_HOST}{path}");
+        let url = format!("https://{}{path}", self.host);
 
         // 根据 method 构建请求
         let request_builder = match method {
@@ -81,7 +82,7 @@ impl HuaweicloudProvider {
         };
 
         let request = request_builder
-            .header("Host", HUAWEICLOUD_DNS_HOST)
+            .header("Host", &self.host)
             .header("X-Sdk-Date", &timestamp)
             .header("Content-Type", "application/json")
             .header("Authorization", authorization)
@@ -113,22 +114,22 @@ impl HuaweicloudProvider {
         let timestamp = now.format("%Y%m%dT%H%M%SZ").to_string();
 
         let headers = vec![
-            ("Host".to_string(), HUAWEICLOUD_DNS_HOST.to_string()),
+            ("Host".to_string(), self.host.clone()),
             ("X-Sdk-Date".to_string(), timestamp.clone()),
         ];
 
         let authorization = self.sign("GET", path, query, &headers, "", &timestamp);
 
         let url = if query.is_empty() {
-            format!("https://{HUAWEICLOUD_DNS_HOST}{path}")
+            format!("https://{}{path}", self.host)
         } else {
-            format!("https://{HUAWEICLOUD_DNS_HOST}{path}?{query}")
+            format!("https://{}{path}?{query}", self.host)
         };
 
         let request = self
             .client
             .get(&url)
-            .header("Host", HUAWEICLOUD_DNS_HOST)
+            .header("Host", &self.host)
             .header("X-Sdk-Date", &timestamp)
             .header("Authorization", authorization);
 
@@ -171,17 +172,17 @@ impl HuaweicloudProvider {
         let timestamp = now.format("%Y%m%dT%H%M%SZ").to_string();
 
         let headers = vec![
-            ("Host".to_string(), HUAWEICLOUD_DNS_HOST.to_string()),
+            ("Host".to_string(), self.host.clone()),
             ("X-Sdk-Date".to_string(), timestamp.clone()),
         ];
 
         let authorization = self.sign("DELETE", path, "", &headers, "", &timestamp);
-        let url = format!("https://{HUAWEICLOUD_DNS_HOST}{path}");
+        let url = format!("https://{}{path}", self.host);
 
         let request = self
             .client
             .delete(&url)
-            .header("Host", HUAWEICLOUD_DNS_HOST)
+            .header("Host", &self.host)
             .header("X-Sdk-Date", &timestamp)
             .header("Authorization", authorization);
 
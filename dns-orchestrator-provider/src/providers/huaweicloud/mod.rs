@@ -8,25 +8,42 @@ pub(crate) mod types;
 
 use reqwest::Client;
 
-use crate::providers::common::create_http_client;
+use crate::http_client::ProxyConfig;
+use crate::providers::common::{create_http_client, normalize_endpoint_host};
+use crate::traits::DEFAULT_BATCH_CONCURRENCY;
+use crate::types::DnsRecordType;
 
+/// 默认 API 端点（全局服务）
 pub(crate) const HUAWEICLOUD_DNS_HOST: &str = "dns.myhuaweicloud.com";
 /// 华为云 API 单页最大记录数
 pub(crate) const MAX_PAGE_SIZE: u32 = 500;
+/// 无原生支持的记录类型
+pub(crate) const UNSUPPORTED_RECORD_TYPES: &[DnsRecordType] = &[
+    DnsRecordType::Alias,
+    DnsRecordType::Tlsa,
+    DnsRecordType::Sshfp,
+    DnsRecordType::Ds,
+];
 
 /// 华为云 DNS Provider
 pub struct HuaweicloudProvider {
     pub(crate) client: Client,
     pub(crate) access_key_id: String,
     pub(crate) secret_access_key: String,
+    pub(crate) host: String,
     pub(crate) max_retries: u32,
+    pub(crate) batch_concurrency: usize,
 }
 
 /// 华为云 Provider Builder
 pub struct HuaweicloudProviderBuilder {
     access_key_id: String,
     secret_access_key: String,
+    endpoint: Option<String>,
+    region: Option<String>,
     max_retries: u32,
+    proxy: Option<ProxyConfig>,
+    batch_concurrency: usize,
 }
 
 impl HuaweicloudProviderBuilder {
@@ -34,21 +51,60 @@ impl HuaweicloudProviderBuilder {
         Self {
             access_key_id,
             secret_access_key,
+            endpoint: None,
+            region: None,
             max_retries: 2,
+            proxy: None,
+            batch_concurrency: DEFAULT_BATCH_CONCURRENCY,
         }
     }
 
+    /// 设置 API 端点主机名（如 `dns.ap-southeast-1.myhuaweicloud.com`），优先于 `region`
+    pub fn endpoint(mut self, host: impl Into<String>) -> Self {
+        let host = normalize_endpoint_host(&host.into());
+        if !host.is_empty() {
+            self.endpoint = Some(host);
+        }
+        self
+    }
+
+    /// 设置区域（如 `ap-southeast-1`），使用 `dns.<region>.myhuaweicloud.com` 端点
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        let region = region.into();
+        if !region.trim().is_empty() {
+            self.region = Some(region.trim().to_string());
+        }
+        self
+    }
+
     pub fn max_retries(mut self, retries: u32) -> Self {
         self.max_retries = retries;
         self
     }
 
+    /// 设置 HTTP 代理
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// 设置批量操作的最大并发请求数（默认 5）
+    pub fn batch_concurrency(mut self, concurrency: usize) -> Self {
+        self.batch_concurrency = concurrency.max(1);
+        self
+    }
+
     pub fn build(self) -> HuaweicloudProvider {
         HuaweicloudProvider {
-            client: create_http_client(),
+            client: create_http_client(self.proxy.as_ref()),
             access_key_id: self.access_key_id,
             secret_access_key: self.secret_access_key,
+            host: self
+                .endpoint
+                .or_else(|| self.region.map(|r| format!("dns.{r}.myhuaweicloud.com")))
+                .unwrap_or_else(|| HUAWEICLOUD_DNS_HOST.to_string()),
             max_retries: self.max_retries,
+            batch_concurrency: self.batch_concurrency,
         }
     }
 }
@@ -5,7 +5,8 @@ use serde::Serialize;
 
 use crate::error::{ProviderError, Result};
 use crate::providers::common::{
-    full_name_to_relative, normalize_domain_name, record_type_to_string, relative_to_full_name,
+    ensure_not_proxied, ensure_record_type_supported, ensure_simple_routing, full_name_to_relative,
+    normalize_domain_name, record_type_to_string, relative_to_full_name,
 };
 use crate::traits::{DnsProvider, ErrorContext};
 use crate::types::{
@@ -17,7 +18,7 @@ use crate::types::{
 use super::types::{
     CreateRecordSetResponse, ListRecordSetsResponse, ListZonesResponse, ShowPublicZoneResponse,
 };
-use super::{HuaweicloudProvider, MAX_PAGE_SIZE};
+use super::{HuaweicloudProvider, MAX_PAGE_SIZE, UNSUPPORTED_RECORD_TYPES};
 
 impl HuaweicloudProvider {
     /// 将华为云域名状态转换为内部状态
@@ -138,7 +139,7 @@ impl HuaweicloudProvider {
         match data {
             RecordData::A { address } => address.clone(),
             RecordData::AAAA { address } => address.clone(),
-            RecordData::CNAME { target } => target.clone(),
+            RecordData::CNAME { target } | RecordData::ALIAS { target } => target.clone(),
             RecordData::MX { priority, exchange } => format!("{priority} {exchange}"),
             RecordData::TXT { text } => text.clone(),
             RecordData::NS { nameserver } => nameserver.clone(),
@@ -149,6 +150,9 @@ impl HuaweicloudProvider {
                 target,
             } => format!("{priority} {weight} {port} {target}"),
             RecordData::CAA { flags, tag, value } => format!("{flags} {tag} \"{value}\""),
+            RecordData::TLSA { .. } | RecordData::SSHFP { .. } | RecordData::DS { .. } => {
+                data.display_value()
+            }
         }
     }
 }
@@ -159,6 +163,10 @@ impl DnsProvider for HuaweicloudProvider {
         "huaweicloud"
     }
 
+    fn batch_concurrency(&self) -> usize {
+        self.batch_concurrency
+    }
+
     fn metadata() -> ProviderMetadata {
         ProviderMetadata {
             id: ProviderType::Huaweicloud,
@@ -184,6 +192,12 @@ impl DnsProvider for HuaweicloudProvider {
             limits: ProviderLimits {
                 max_page_size_domains: 500,
                 max_page_size_records: 500,
+                requests_per_second: None,
+                max_batch_size: 1,
+                max_records_per_zone: None,
+                min_ttl: 1,
+                max_ttl: 2_147_483_647,
+                auto_ttl: None,
             },
         }
     }
@@ -311,6 +325,8 @@ impl DnsProvider for HuaweicloudProvider {
                     ttl: r.ttl.unwrap_or(300),
                     data,
                     proxied: None,
+                    comment: None,
+                    tags: Vec::new(),
                     created_at: r.created_at.and_then(|s| {
                         chrono::DateTime::parse_from_rfc3339(&s)
                             .ok()
@@ -321,6 +337,7 @@ impl DnsProvider for HuaweicloudProvider {
                             .ok()
                             .map(|dt| dt.with_timezone(&chrono::Utc))
                     }),
+                    routing_policy: None,
                 })
             })
             .collect();
@@ -334,6 +351,10 @@ impl DnsProvider for HuaweicloudProvider {
     }
 
     async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        ensure_record_type_supported(&req.data, UNSUPPORTED_RECORD_TYPES, "huaweicloud")?;
+        ensure_simple_routing(req.routing_policy.as_ref(), "huaweicloud")?;
+        ensure_not_proxied(req.proxied, "huaweicloud")?;
+        self.validate_ttl(req.ttl)?;
         // 获取域名信息
         let domain_info = self.get_domain(&req.domain_id).await?;
 
@@ -372,12 +393,15 @@ impl DnsProvider for HuaweicloudProvider {
         Ok(DnsRecord {
             id: response.id,
             domain_id: req.domain_id.clone(),
-            name: req.name.clone(),
+            name: full_name_to_relative(&req.name, &domain_info.name),
             ttl: req.ttl,
             data: req.data.clone(),
             proxied: None,
+            comment: None,
+            tags: Vec::new(),
             created_at: Some(now),
             updated_at: Some(now),
+            routing_policy: None,
         })
     }
 
@@ -386,6 +410,9 @@ impl DnsProvider for HuaweicloudProvider {
         record_id: &str,
         req: &UpdateDnsRecordRequest,
     ) -> Result<DnsRecord> {
+        ensure_record_type_supported(&req.data, UNSUPPORTED_RECORD_TYPES, "huaweicloud")?;
+        ensure_not_proxied(req.proxied, "huaweicloud")?;
+        self.validate_ttl(req.ttl)?;
         // 获取域名信息
         let domain_info = self.get_domain(&req.domain_id).await?;
 
@@ -424,12 +451,15 @@ impl DnsProvider for HuaweicloudProvider {
         Ok(DnsRecord {
             id: record_id.to_string(),
             domain_id: req.domain_id.clone(),
-            name: req.name.clone(),
+            name: full_name_to_relative(&req.name, &domain_info.name),
             ttl: req.ttl,
             data: req.data.clone(),
             proxied: None,
+            comment: None,
+            tags: Vec::new(),
             created_at: None,
             updated_at: Some(now),
+            routing_policy: None,
         })
     }
 
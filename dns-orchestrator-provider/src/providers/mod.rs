@@ -1,21 +1,50 @@
 //! DNS Provider implementations
 
 pub mod common;
+mod csv;
+mod dry_run;
 
 #[cfg(feature = "aliyun")]
 mod aliyun;
 #[cfg(feature = "cloudflare")]
 mod cloudflare;
+#[cfg(feature = "desec")]
+mod desec;
 #[cfg(feature = "dnspod")]
 mod dnspod;
+#[cfg(feature = "gcloud")]
+mod gcloud;
+#[cfg(feature = "hetzner")]
+mod hetzner;
 #[cfg(feature = "huaweicloud")]
 mod huaweicloud;
+#[cfg(feature = "namecheap")]
+mod namecheap;
+#[cfg(feature = "rfc2136")]
+mod rfc2136;
+#[cfg(feature = "route53")]
+mod route53;
+
+pub use self::csv::{records_from_csv, records_to_csv};
+pub use dry_run::{DryRunProvider, PlannedChange};
 
 #[cfg(feature = "aliyun")]
 pub use aliyun::AliyunProvider;
 #[cfg(feature = "cloudflare")]
 pub use cloudflare::CloudflareProvider;
+#[cfg(feature = "desec")]
+pub use desec::DesecProvider;
 #[cfg(feature = "dnspod")]
 pub use dnspod::DnspodProvider;
+#[cfg(feature = "gcloud")]
+pub use gcloud::GoogleCloudProvider;
+#[cfg(feature = "hetzner")]
+pub use hetzner::HetznerProvider;
 #[cfg(feature = "huaweicloud")]
 pub use huaweicloud::HuaweicloudProvider;
+#[cfg(feature = "namecheap")]
+pub use namecheap::NamecheapProvider;
+#[cfg(feature = "rfc2136")]
+pub use rfc2136::Rfc2136Provider;
+#[cfg(feature = "route53")]
+pub use route53::Route53Provider;
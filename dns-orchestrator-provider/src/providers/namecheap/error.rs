@@ -0,0 +1,66 @@
+//! Namecheap 错误映射
+//!
+//! 参考: <https://www.namecheap.com/support/api/error-codes/>
+//!
+//! 错误码为数字编号：`1xxxxxx` 为全局/认证错误，`2xxxxxx` 为命令相关错误，
+//! 其中 `2011xxx`/`2015xxx` 表示参数校验失败。
+
+use crate::error::ProviderError;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::NamecheapProvider;
+
+/// Namecheap 错误码映射实现
+impl ProviderErrorMapper for NamecheapProvider {
+    fn provider_name(&self) -> &'static str {
+        "namecheap"
+    }
+
+    fn map_error(&self, raw: RawApiError, context: ErrorContext) -> ProviderError {
+        // 频率限制没有固定错误码，只能根据消息判断
+        if raw.message.contains("Too many requests") {
+            return ProviderError::QuotaExceeded {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            };
+        }
+
+        match raw.code.as_deref() {
+            // ============ 认证错误 ============
+            // ApiUser/ApiKey/UserName 缺失或 API Key 无效
+            Some("1010101" | "1010102" | "1010103" | "1011102") => {
+                ProviderError::InvalidCredentials {
+                    provider: self.provider_name().to_string(),
+                    raw_message: Some(raw.message),
+                }
+            }
+
+            // ============ 权限拒绝 ============
+            // 请求 IP 不在白名单 / 无域名编辑权限
+            Some("1011150" | "1017150" | "2030166") => ProviderError::PermissionDenied {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 域名不存在 ============
+            // 域名不存在 / 不属于当前账号
+            Some("2019166" | "2016166") => ProviderError::DomainNotFound {
+                provider: self.provider_name().to_string(),
+                domain: context.domain.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 参数无效 ============
+            Some(code) if code.starts_with("2011") || code.starts_with("2015") => {
+                ProviderError::InvalidParameter {
+                    provider: self.provider_name().to_string(),
+                    param: "input".to_string(),
+                    detail: raw.message,
+                }
+            }
+
+            // ============ 其他错误 fallback ============
+            _ => self.unknown_error(raw),
+        }
+    }
+}
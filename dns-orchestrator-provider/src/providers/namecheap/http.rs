@@ -0,0 +1,123 @@
+//! Namecheap HTTP 请求方法
+
+use serde::de::{DeserializeOwned, IgnoredAny};
+
+use crate::error::{ProviderError, Result};
+use crate::http_client::HttpUtils;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::types::ApiResponse;
+use super::{NAMECHEAP_API_URL, NamecheapProvider};
+
+impl NamecheapProvider {
+    // ==================== 辅助方法 ====================
+
+    /// 构造包含认证信息的公共参数
+    fn common_params(&self, command: &str) -> Vec<(String, String)> {
+        vec![
+            ("ApiUser".to_string(), self.api_user.clone()),
+            ("ApiKey".to_string(), self.api_key.clone()),
+            ("UserName".to_string(), self.username.clone()),
+            ("ClientIp".to_string(), self.client_ip.clone()),
+            ("Command".to_string(), command.to_string()),
+        ]
+    }
+
+    /// 统一处理 Namecheap 响应错误
+    ///
+    /// Namecheap 出错时 HTTP 状态码仍为 200，需检查 `ApiResponse` 的 `Status` 属性
+    fn handle_response_error(
+        &self,
+        status: u16,
+        response_text: &str,
+        ctx: ErrorContext,
+    ) -> Result<()> {
+        if !(200..300).contains(&status) {
+            return Err(self.unknown_error(RawApiError::new(response_text).with_status(status)));
+        }
+
+        let response: ApiResponse<IgnoredAny> =
+            HttpUtils::parse_xml(response_text, self.provider_name())?;
+        if response.status.eq_ignore_ascii_case("OK") {
+            return Ok(());
+        }
+
+        let error = response.errors.and_then(|e| e.items.into_iter().next());
+        let (code, message) = match error {
+            Some(e) => (
+                e.number.unwrap_or_else(|| "UNKNOWN".to_string()),
+                e.message
+                    .unwrap_or_else(|| "No error message provided by API".to_string()),
+            ),
+            None => (
+                "UNKNOWN".to_string(),
+                "No error message provided by API".to_string(),
+            ),
+        };
+        Err(self.map_error(
+            RawApiError::with_code(code, message).with_status(status),
+            ctx,
+        ))
+    }
+
+    /// 解析成功响应中的 `CommandResponse`
+    fn parse_command_response<T: DeserializeOwned>(&self, response_text: &str) -> Result<T> {
+        let response: ApiResponse<T> = HttpUtils::parse_xml(response_text, self.provider_name())?;
+        response
+            .command_response
+            .ok_or_else(|| ProviderError::ParseError {
+                provider: self.provider_name().to_string(),
+                detail: "Missing CommandResponse in API response".to_string(),
+            })
+    }
+
+    // ==================== 公开 API 方法 ====================
+
+    /// 执行 GET 命令
+    pub(crate) async fn get<T: DeserializeOwned>(
+        &self,
+        command: &str,
+        params: &[(&str, String)],
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        let mut query = self.common_params(command);
+        query.extend(params.iter().map(|(k, v)| ((*k).to_string(), v.clone())));
+
+        let request = self.client.get(NAMECHEAP_API_URL).query(&query);
+        let (status, response_text) = HttpUtils::execute_request_with_retry(
+            request,
+            self.provider_name(),
+            "GET",
+            command,
+            self.max_retries,
+        )
+        .await?;
+
+        self.handle_response_error(status, &response_text, ctx)?;
+        self.parse_command_response(&response_text)
+    }
+
+    /// 执行 POST 命令（表单参数，用于参数较多的 setHosts）
+    pub(crate) async fn post_form<T: DeserializeOwned>(
+        &self,
+        command: &str,
+        params: Vec<(String, String)>,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        let mut form = self.common_params(command);
+        form.extend(params);
+
+        let request = self.client.post(NAMECHEAP_API_URL).form(&form);
+        let (status, response_text) = HttpUtils::execute_request_with_retry(
+            request,
+            self.provider_name(),
+            "POST",
+            command,
+            self.max_retries,
+        )
+        .await?;
+
+        self.handle_response_error(status, &response_text, ctx)?;
+        self.parse_command_response(&response_text)
+    }
+}
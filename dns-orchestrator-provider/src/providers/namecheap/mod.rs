@@ -0,0 +1,114 @@
+//! Namecheap DNS Provider
+//!
+//! Namecheap 没有单条记录的增删改接口，只能通过 `namecheap.domains.dns.setHosts`
+//! 整体替换域名的全部主机记录，且每次替换后所有 `HostId` 都会重新生成。
+//! 因此本 Provider 的增删改均为「读取全部记录 -> 修改 -> 整体写回」：
+//!
+//! - 记录 ID 由名称、类型与值编码（见 `common::encode_rrset_record_id`），不依赖 `HostId`；
+//! - 同一 Provider 实例内的写操作通过 `write_lock` 串行执行；
+//! - 但与其他客户端（控制台、其他进程）之间没有任何并发保护，
+//!   读取与写回之间发生的外部修改会被覆盖（后写者胜），这一点弱于按记录操作的 Provider；
+//! - 写回时会原样保留本 Provider 不支持的记录类型（如 URL 重定向）。
+//!
+//! API 调用方的公网 IP 必须预先加入 Namecheap 的 API 白名单，并作为 `client_ip` 传入。
+
+mod error;
+mod http;
+mod provider;
+mod types;
+
+use reqwest::Client;
+use tokio::sync::Mutex;
+
+use crate::http_client::ProxyConfig;
+use crate::providers::common::create_http_client;
+use crate::traits::DEFAULT_BATCH_CONCURRENCY;
+
+pub(crate) const NAMECHEAP_API_URL: &str = "https://api.namecheap.com/xml.response";
+/// `namecheap.domains.getList` 单页最大数量
+pub(crate) const MAX_PAGE_SIZE_DOMAINS: u32 = 100;
+/// 记录列表在内存中分页，限制单页数量
+pub(crate) const MAX_PAGE_SIZE_RECORDS: u32 = 100;
+
+/// Namecheap DNS Provider
+pub struct NamecheapProvider {
+    pub(crate) client: Client,
+    pub(crate) api_user: String,
+    pub(crate) api_key: String,
+    pub(crate) username: String,
+    pub(crate) client_ip: String,
+    pub(crate) max_retries: u32,
+    pub(crate) batch_concurrency: usize,
+    /// 串行化「读取 -> 修改 -> 写回」流程
+    pub(crate) write_lock: Mutex<()>,
+}
+
+/// Namecheap DNS Provider Builder
+pub struct NamecheapProviderBuilder {
+    api_user: String,
+    api_key: String,
+    username: String,
+    client_ip: String,
+    max_retries: u32,
+    proxy: Option<ProxyConfig>,
+    batch_concurrency: usize,
+}
+
+impl NamecheapProviderBuilder {
+    fn new(api_user: String, api_key: String, username: String, client_ip: String) -> Self {
+        Self {
+            api_user,
+            api_key,
+            username,
+            client_ip,
+            max_retries: 2,
+            proxy: None,
+            batch_concurrency: DEFAULT_BATCH_CONCURRENCY,
+        }
+    }
+
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// 设置 HTTP 代理
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// 设置批量操作的最大并发请求数（默认 5）
+    pub fn batch_concurrency(mut self, concurrency: usize) -> Self {
+        self.batch_concurrency = concurrency.max(1);
+        self
+    }
+
+    pub fn build(self) -> NamecheapProvider {
+        NamecheapProvider {
+            client: create_http_client(self.proxy.as_ref()),
+            api_user: self.api_user,
+            api_key: self.api_key,
+            username: self.username,
+            client_ip: self.client_ip,
+            max_retries: self.max_retries,
+            batch_concurrency: self.batch_concurrency,
+            write_lock: Mutex::new(()),
+        }
+    }
+}
+
+impl NamecheapProvider {
+    pub fn new(api_user: String, api_key: String, username: String, client_ip: String) -> Self {
+        Self::builder(api_user, api_key, username, client_ip).build()
+    }
+
+    pub fn builder(
+        api_user: String,
+        api_key: String,
+        username: String,
+        client_ip: String,
+    ) -> NamecheapProviderBuilder {
+        NamecheapProviderBuilder::new(api_user, api_key, username, client_ip)
+    }
+}
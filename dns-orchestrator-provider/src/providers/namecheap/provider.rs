@@ -0,0 +1,612 @@
+//! Namecheap DnsProvider trait 实现
+
+use async_trait::async_trait;
+
+use crate::error::{ProviderError, Result};
+use crate::providers::common::{
+    canonical_record_name, decode_rrset_record_id, encode_rrset_record_id, ensure_not_proxied,
+    ensure_simple_routing, full_name_to_relative, normalize_domain_name, record_type_to_string,
+    relative_to_full_name,
+};
+use crate::traits::{DnsProvider, ErrorContext, ProviderErrorMapper, RawApiError};
+use crate::types::{
+    CreateDnsRecordRequest, DnsRecord, DomainStatus, FieldType, PaginatedResponse,
+    PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures, ProviderLimits,
+    ProviderMetadata, ProviderType, RecordData, RecordQueryParams, UpdateDnsRecordRequest,
+};
+
+use super::types::{
+    DomainGetListResponse, GetHostsResponse, GetHostsResult, Host, NamecheapDomain,
+    SetHostsResponse, is_true,
+};
+use super::{MAX_PAGE_SIZE_DOMAINS, MAX_PAGE_SIZE_RECORDS, NamecheapProvider};
+
+/// Namecheap 默认 TTL（控制台中的 "Automatic"）
+const DEFAULT_TTL: u32 = 1800;
+
+/// 主机记录的标识（记录 ID 的解码结果）
+struct RecordKey {
+    /// 完整名称（不带末尾点）
+    name: String,
+    record_type: String,
+    /// 规范化后的记录值
+    value: String,
+}
+
+impl NamecheapProvider {
+    /// 拆分域名为 SLD 与 TLD（如 `example.co.uk` -> `example` + `co.uk`）
+    fn split_domain(&self, domain: &str) -> Result<(String, String)> {
+        normalize_domain_name(domain)
+            .split_once('.')
+            .map(|(sld, tld)| (sld.to_string(), tld.to_string()))
+            .ok_or_else(|| ProviderError::InvalidParameter {
+                provider: self.provider_name().to_string(),
+                param: "domain".to_string(),
+                detail: format!("Invalid domain name: '{domain}'"),
+            })
+    }
+
+    /// 解码记录 ID
+    fn decode_record_id(&self, record_id: &str) -> Result<RecordKey> {
+        let (name, record_type, value) =
+            decode_rrset_record_id(record_id).ok_or_else(|| ProviderError::InvalidParameter {
+                provider: self.provider_name().to_string(),
+                param: "record_id".to_string(),
+                detail: format!("Invalid Namecheap record id: '{record_id}'"),
+            })?;
+        Ok(RecordKey {
+            name,
+            record_type,
+            value,
+        })
+    }
+
+    /// 主机记录的规范化值（用于编码记录 ID，目标域名去掉末尾点）
+    fn host_value(host: &Host) -> String {
+        match host.record_type.as_str() {
+            "CNAME" | "NS" => normalize_domain_name(&host.address),
+            "MX" => format!(
+                "{} {}",
+                host.mx_pref.unwrap_or(10),
+                normalize_domain_name(&host.address)
+            ),
+            _ => host.address.clone(),
+        }
+    }
+
+    /// 判断主机记录是否与记录标识匹配
+    fn host_matches(host: &Host, domain: &str, key: &RecordKey) -> bool {
+        relative_to_full_name(&host.name, domain).to_lowercase() == key.name
+            && host.record_type == key.record_type
+            && Self::host_value(host) == key.value
+    }
+
+    /// 解析主机记录为 RecordData
+    fn parse_record_data(&self, host: &Host) -> Result<RecordData> {
+        let address = &host.address;
+        match host.record_type.as_str() {
+            "A" => Ok(RecordData::A {
+                address: address.clone(),
+            }),
+            "AAAA" => Ok(RecordData::AAAA {
+                address: address.clone(),
+            }),
+            "CNAME" => Ok(RecordData::CNAME {
+                target: normalize_domain_name(address),
+            }),
+            "ALIAS" => Ok(RecordData::ALIAS {
+                target: normalize_domain_name(address),
+            }),
+            "NS" => Ok(RecordData::NS {
+                nameserver: normalize_domain_name(address),
+            }),
+            "TXT" => Ok(RecordData::TXT {
+                text: address.clone(),
+            }),
+            "MX" => Ok(RecordData::MX {
+                priority: host.mx_pref.unwrap_or(10),
+                exchange: normalize_domain_name(address),
+            }),
+            "CAA" => {
+                // Namecheap CAA 格式: flags tag "value"
+                let parts: Vec<&str> = address.splitn(3, ' ').collect();
+                if parts.len() == 3 {
+                    Ok(RecordData::CAA {
+                        flags: parts[0].parse().map_err(|_| ProviderError::ParseError {
+                            provider: self.provider_name().to_string(),
+                            detail: format!("Invalid CAA flags: '{}'", parts[0]),
+                        })?,
+                        tag: parts[1].to_string(),
+                        value: parts[2].trim_matches('"').to_string(),
+                    })
+                } else {
+                    Err(ProviderError::ParseError {
+                        provider: self.provider_name().to_string(),
+                        detail: format!(
+                            "Invalid CAA record format: expected 'flags tag value', got '{address}'"
+                        ),
+                    })
+                }
+            }
+            _ => Err(ProviderError::UnsupportedRecordType {
+                provider: self.provider_name().to_string(),
+                record_type: host.record_type.clone(),
+            }),
+        }
+    }
+
+    /// 将 RecordData 转换为主机记录（Namecheap API 不支持 SRV/TLSA/SSHFP/DS）
+    fn record_data_to_host(
+        &self,
+        name: &str,
+        domain: &str,
+        ttl: u32,
+        data: &RecordData,
+    ) -> Result<Host> {
+        let (address, mx_pref) = match data {
+            RecordData::A { address } | RecordData::AAAA { address } => (address.clone(), None),
+            RecordData::CNAME { target } | RecordData::ALIAS { target } => (target.clone(), None),
+            RecordData::MX { priority, exchange } => (exchange.clone(), Some(*priority)),
+            RecordData::TXT { text } => (text.clone(), None),
+            RecordData::NS { nameserver } => (nameserver.clone(), None),
+            RecordData::CAA { flags, tag, value } => (format!("{flags} {tag} \"{value}\""), None),
+            RecordData::SRV { .. }
+            | RecordData::TLSA { .. }
+            | RecordData::SSHFP { .. }
+            | RecordData::DS { .. } => {
+                return Err(ProviderError::UnsupportedRecordType {
+                    provider: self.provider_name().to_string(),
+                    record_type: record_type_to_string(&data.record_type()).to_string(),
+                });
+            }
+        };
+
+        Ok(Host {
+            name: canonical_record_name(name, domain),
+            record_type: record_type_to_string(&data.record_type()).to_string(),
+            address,
+            mx_pref,
+            ttl: Some(ttl),
+        })
+    }
+
+    /// 转换域名为 ProviderDomain（域名 ID 使用域名本身）
+    fn to_provider_domain(domain: NamecheapDomain) -> ProviderDomain {
+        let status = if is_true(domain.is_expired.as_deref()) {
+            DomainStatus::Error
+        } else if domain
+            .is_our_dns
+            .as_deref()
+            .is_some_and(|v| v.eq_ignore_ascii_case("false"))
+        {
+            // 未使用 Namecheap DNS 服务器，此处的记录不会生效
+            DomainStatus::Paused
+        } else {
+            DomainStatus::Active
+        };
+        let name = domain.name.to_lowercase();
+        ProviderDomain {
+            id: name.clone(),
+            name,
+            provider: ProviderType::Namecheap,
+            status,
+            record_count: None,
+        }
+    }
+
+    /// 获取全部域名（按页拉取）
+    async fn list_all_domains(&self, search_term: Option<&str>) -> Result<Vec<NamecheapDomain>> {
+        let mut domains = Vec::new();
+        let mut page = 1u32;
+
+        loop {
+            let mut params = vec![
+                ("Page", page.to_string()),
+                ("PageSize", MAX_PAGE_SIZE_DOMAINS.to_string()),
+            ];
+            if let Some(term) = search_term {
+                params.push(("SearchTerm", term.to_string()));
+            }
+
+            let response: DomainGetListResponse = self
+                .get(
+                    "namecheap.domains.getList",
+                    &params,
+                    ErrorContext::default(),
+                )
+                .await?;
+            let fetched = response.result.domains.len();
+            domains.extend(response.result.domains);
+
+            if fetched == 0 || domains.len() >= response.paging.total_items as usize {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(domains)
+    }
+
+    /// 获取域名的全部主机记录
+    async fn get_hosts(&self, domain: &str) -> Result<GetHostsResult> {
+        let (sld, tld) = self.split_domain(domain)?;
+        let ctx = ErrorContext {
+            domain: Some(domain.to_string()),
+            ..Default::default()
+        };
+        let response: GetHostsResponse = self
+            .get(
+                "namecheap.domains.dns.getHosts",
+                &[("SLD", sld), ("TLD", tld)],
+                ctx,
+            )
+            .await?;
+        Ok(response.result)
+    }
+
+    /// 整体写回域名的全部主机记录
+    async fn set_hosts(
+        &self,
+        domain: &str,
+        hosts: &[Host],
+        email_type: Option<&str>,
+        ctx: ErrorContext,
+    ) -> Result<()> {
+        let (sld, tld) = self.split_domain(domain)?;
+        let mut params = vec![("SLD".to_string(), sld), ("TLD".to_string(), tld)];
+
+        for (i, host) in hosts.iter().enumerate() {
+            let n = i + 1;
+            params.push((format!("HostName{n}"), host.name.clone()));
+            params.push((format!("RecordType{n}"), host.record_type.clone()));
+            params.push((format!("Address{n}"), host.address.clone()));
+            params.push((
+                format!("TTL{n}"),
+                host.ttl.unwrap_or(DEFAULT_TTL).to_string(),
+            ));
+            if let Some(mx_pref) = host.mx_pref {
+                params.push((format!("MXPref{n}"), mx_pref.to_string()));
+            }
+        }
+
+        // 存在 MX 记录时必须使用 MX 邮件类型，否则保留原有设置（如邮件转发）
+        let email_type = if hosts.iter().any(|h| h.record_type == "MX") {
+            Some("MX")
+        } else {
+            email_type.filter(|t| !t.eq_ignore_ascii_case("MX"))
+        };
+        if let Some(email_type) = email_type {
+            params.push(("EmailType".to_string(), email_type.to_string()));
+        }
+
+        let response: SetHostsResponse = self
+            .post_form("namecheap.domains.dns.setHosts", params, ctx)
+            .await?;
+        if !is_true(Some(&response.result.is_success)) {
+            return Err(self.unknown_error(RawApiError::new("setHosts returned IsSuccess=false")));
+        }
+        Ok(())
+    }
+
+    /// 构造 DnsRecord
+    fn to_dns_record(domain_id: &str, name: &str, host: &Host, data: RecordData) -> DnsRecord {
+        DnsRecord {
+            id: encode_rrset_record_id(name, &host.record_type, &Self::host_value(host)),
+            domain_id: domain_id.to_string(),
+            name: full_name_to_relative(name, domain_id),
+            ttl: host.ttl.unwrap_or(DEFAULT_TTL),
+            data,
+            proxied: None,
+            comment: None,
+            tags: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            routing_policy: None,
+        }
+    }
+}
+
+#[async_trait]
+impl DnsProvider for NamecheapProvider {
+    fn id(&self) -> &'static str {
+        "namecheap"
+    }
+
+    fn batch_concurrency(&self) -> usize {
+        self.batch_concurrency
+    }
+
+    fn metadata() -> ProviderMetadata {
+        ProviderMetadata {
+            id: ProviderType::Namecheap,
+            name: "Namecheap".to_string(),
+            description: "Namecheap DNS 服务".to_string(),
+            required_fields: vec![
+                ProviderCredentialField {
+                    key: "apiUser".to_string(),
+                    label: "API User".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("输入 API User".to_string()),
+                    help_text: None,
+                },
+                ProviderCredentialField {
+                    key: "apiKey".to_string(),
+                    label: "API Key".to_string(),
+                    field_type: FieldType::Password,
+                    placeholder: Some("输入 API Key".to_string()),
+                    help_text: None,
+                },
+                ProviderCredentialField {
+                    key: "username".to_string(),
+                    label: "Username".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("输入账号用户名".to_string()),
+                    help_text: Some("通常与 API User 相同".to_string()),
+                },
+                ProviderCredentialField {
+                    key: "clientIp".to_string(),
+                    label: "Client IP".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("输入已加入白名单的公网 IP".to_string()),
+                    help_text: Some(
+                        "需在 Namecheap 控制台的 API Access 中将此 IP 加入白名单".to_string(),
+                    ),
+                },
+            ],
+            features: ProviderFeatures::default(),
+            limits: ProviderLimits {
+                max_page_size_domains: MAX_PAGE_SIZE_DOMAINS,
+                max_page_size_records: MAX_PAGE_SIZE_RECORDS,
+                requests_per_second: Some(20.0 / 60.0), // 20 次 / 分钟
+                max_batch_size: 1,
+                max_records_per_zone: None,
+                min_ttl: 60,
+                max_ttl: 60_000,
+                auto_ttl: None,
+            },
+        }
+    }
+
+    async fn validate_credentials(&self) -> Result<bool> {
+        match self
+            .get::<DomainGetListResponse>(
+                "namecheap.domains.getList",
+                &[("PageSize", "10".to_string())],
+                ErrorContext::default(),
+            )
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(ProviderError::InvalidCredentials { .. }) => Ok(false),
+            Err(e) => {
+                log::warn!("凭证验证失败: {e}");
+                Ok(false)
+            }
+        }
+    }
+
+    /// 拉取全部域名后在内存中分页
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> Result<PaginatedResponse<ProviderDomain>> {
+        let domains = self.list_all_domains(None).await?;
+        let total_count = domains.len() as u32;
+
+        let domains = domains
+            .into_iter()
+            .skip(((params.page.max(1) - 1) * params.page_size) as usize)
+            .take(params.page_size as usize)
+            .map(Self::to_provider_domain)
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            domains,
+            params.page,
+            params.page_size,
+            total_count,
+        ))
+    }
+
+    async fn get_domain(&self, domain_id: &str) -> Result<ProviderDomain> {
+        self.list_all_domains(Some(domain_id))
+            .await?
+            .into_iter()
+            .find(|d| d.name.eq_ignore_ascii_case(domain_id))
+            .map(Self::to_provider_domain)
+            .ok_or_else(|| ProviderError::DomainNotFound {
+                provider: self.provider_name().to_string(),
+                domain: domain_id.to_string(),
+                raw_message: None,
+            })
+    }
+
+    /// 主机记录一次性全部返回，在内存中过滤与分页
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        let hosts = self.get_hosts(domain_id).await?.hosts;
+
+        let keyword = params
+            .keyword
+            .as_ref()
+            .filter(|k| !k.is_empty())
+            .map(|k| k.to_lowercase());
+        let type_filter = params.record_type.as_ref().map(record_type_to_string);
+
+        let mut records = Vec::new();
+        for host in &hosts {
+            if type_filter.is_some_and(|t| t != host.record_type) {
+                continue;
+            }
+            // 跳过 URL 重定向等不支持的类型
+            let Ok(data) = self.parse_record_data(host) else {
+                continue;
+            };
+
+            let full_name = relative_to_full_name(&host.name, domain_id).to_lowercase();
+            let record = Self::to_dns_record(domain_id, &full_name, host, data);
+
+            if let Some(ref kw) = keyword
+                && !record.name.to_lowercase().contains(kw)
+                && !record.data.display_value().to_lowercase().contains(kw)
+            {
+                continue;
+            }
+            records.push(record);
+        }
+
+        let total_count = records.len() as u32;
+        let records = records
+            .into_iter()
+            .skip(((params.page.max(1) - 1) * params.page_size) as usize)
+            .take(params.page_size as usize)
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            records,
+            params.page,
+            params.page_size,
+            total_count,
+        ))
+    }
+
+    /// 读取全部主机记录，追加新记录后整体写回
+    async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        ensure_simple_routing(req.routing_policy.as_ref(), self.provider_name())?;
+        ensure_not_proxied(req.proxied, self.provider_name())?;
+        self.validate_ttl(req.ttl)?;
+        let host = self.record_data_to_host(&req.name, &req.domain_id, req.ttl, &req.data)?;
+        let full_name = relative_to_full_name(&req.name, &req.domain_id).to_lowercase();
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            domain: Some(req.domain_id.clone()),
+            ..Default::default()
+        };
+
+        let _guard = self.write_lock.lock().await;
+        let GetHostsResult {
+            email_type,
+            mut hosts,
+        } = self.get_hosts(&req.domain_id).await?;
+
+        let key = RecordKey {
+            name: full_name.clone(),
+            record_type: host.record_type.clone(),
+            value: Self::host_value(&host),
+        };
+        if hosts
+            .iter()
+            .any(|h| Self::host_matches(h, &req.domain_id, &key))
+        {
+            return Err(ProviderError::RecordExists {
+                provider: self.provider_name().to_string(),
+                record_name: req.name.clone(),
+                raw_message: None,
+            });
+        }
+
+        hosts.push(host.clone());
+        self.set_hosts(&req.domain_id, &hosts, email_type.as_deref(), ctx)
+            .await?;
+
+        Ok(Self::to_dns_record(
+            &req.domain_id,
+            &full_name,
+            &host,
+            req.data.clone(),
+        ))
+    }
+
+    /// 读取全部主机记录，替换目标记录后整体写回
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<DnsRecord> {
+        ensure_not_proxied(req.proxied, self.provider_name())?;
+        self.validate_ttl(req.ttl)?;
+        let old = self.decode_record_id(record_id)?;
+        let host = self.record_data_to_host(&req.name, &req.domain_id, req.ttl, &req.data)?;
+        let full_name = relative_to_full_name(&req.name, &req.domain_id).to_lowercase();
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            record_id: Some(record_id.to_string()),
+            domain: Some(req.domain_id.clone()),
+        };
+
+        let _guard = self.write_lock.lock().await;
+        let GetHostsResult {
+            email_type,
+            mut hosts,
+        } = self.get_hosts(&req.domain_id).await?;
+
+        let index = hosts
+            .iter()
+            .position(|h| Self::host_matches(h, &req.domain_id, &old))
+            .ok_or_else(|| ProviderError::RecordNotFound {
+                provider: self.provider_name().to_string(),
+                record_id: record_id.to_string(),
+                raw_message: None,
+            })?;
+
+        let new_key = RecordKey {
+            name: full_name.clone(),
+            record_type: host.record_type.clone(),
+            value: Self::host_value(&host),
+        };
+        if hosts
+            .iter()
+            .enumerate()
+            .any(|(i, h)| i != index && Self::host_matches(h, &req.domain_id, &new_key))
+        {
+            return Err(ProviderError::RecordExists {
+                provider: self.provider_name().to_string(),
+                record_name: req.name.clone(),
+                raw_message: None,
+            });
+        }
+
+        hosts[index] = host.clone();
+        self.set_hosts(&req.domain_id, &hosts, email_type.as_deref(), ctx)
+            .await?;
+
+        Ok(Self::to_dns_record(
+            &req.domain_id,
+            &full_name,
+            &host,
+            req.data.clone(),
+        ))
+    }
+
+    /// 读取全部主机记录，移除目标记录后整体写回
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
+        let key = self.decode_record_id(record_id)?;
+        let ctx = ErrorContext {
+            record_id: Some(record_id.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+
+        let _guard = self.write_lock.lock().await;
+        let GetHostsResult {
+            email_type,
+            mut hosts,
+        } = self.get_hosts(domain_id).await?;
+
+        let index = hosts
+            .iter()
+            .position(|h| Self::host_matches(h, domain_id, &key))
+            .ok_or_else(|| ProviderError::RecordNotFound {
+                provider: self.provider_name().to_string(),
+                record_id: record_id.to_string(),
+                raw_message: None,
+            })?;
+
+        hosts.remove(index);
+        self.set_hosts(domain_id, &hosts, email_type.as_deref(), ctx)
+            .await
+    }
+}
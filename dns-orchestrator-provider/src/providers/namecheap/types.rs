@@ -0,0 +1,116 @@
+//! Namecheap API 类型定义（XML）
+
+use serde::Deserialize;
+
+// ============ 通用响应结构 ============
+
+/// Namecheap 布尔属性为 `true`/`false` 字符串，大小写不固定
+pub fn is_true(value: Option<&str>) -> bool {
+    value.is_some_and(|v| v.eq_ignore_ascii_case("true"))
+}
+
+/// 所有命令共用的响应外壳
+#[derive(Debug, Deserialize)]
+pub struct ApiResponse<T> {
+    /// `OK` 或 `ERROR`
+    #[serde(rename = "@Status")]
+    pub status: String,
+    #[serde(rename = "Errors", default)]
+    pub errors: Option<ApiErrors>,
+    #[serde(rename = "CommandResponse")]
+    pub command_response: Option<T>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiErrors {
+    #[serde(rename = "Error", default)]
+    pub items: Vec<ApiErrorItem>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiErrorItem {
+    #[serde(rename = "@Number")]
+    pub number: Option<String>,
+    #[serde(rename = "$text")]
+    pub message: Option<String>,
+}
+
+// ============ namecheap.domains.getList ============
+
+#[derive(Debug, Deserialize)]
+pub struct DomainGetListResponse {
+    #[serde(rename = "DomainGetListResult")]
+    pub result: DomainGetListResult,
+    #[serde(rename = "Paging")]
+    pub paging: Paging,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DomainGetListResult {
+    #[serde(rename = "Domain", default)]
+    pub domains: Vec<NamecheapDomain>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NamecheapDomain {
+    #[serde(rename = "@Name")]
+    pub name: String,
+    #[serde(rename = "@IsExpired")]
+    pub is_expired: Option<String>,
+    /// 是否使用 Namecheap 的 DNS 服务器（否则无法管理记录）
+    #[serde(rename = "@IsOurDNS")]
+    pub is_our_dns: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Paging {
+    pub total_items: u32,
+}
+
+// ============ namecheap.domains.dns.getHosts ============
+
+#[derive(Debug, Deserialize)]
+pub struct GetHostsResponse {
+    #[serde(rename = "DomainDNSGetHostsResult")]
+    pub result: GetHostsResult,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetHostsResult {
+    /// 邮件设置类型（MX/MXE/FWD/OX 等），写回时需要保留
+    #[serde(rename = "@EmailType")]
+    pub email_type: Option<String>,
+    #[serde(rename = "host", alias = "Host", default)]
+    pub hosts: Vec<Host>,
+}
+
+/// 主机记录
+#[derive(Debug, Clone, Deserialize)]
+pub struct Host {
+    /// 主机名（相对名称，`@` 表示根域）
+    #[serde(rename = "@Name")]
+    pub name: String,
+    #[serde(rename = "@Type")]
+    pub record_type: String,
+    #[serde(rename = "@Address")]
+    pub address: String,
+    #[serde(rename = "@MXPref")]
+    pub mx_pref: Option<u16>,
+    #[serde(rename = "@TTL")]
+    pub ttl: Option<u32>,
+}
+
+// ============ namecheap.domains.dns.setHosts ============
+
+#[derive(Debug, Deserialize)]
+pub struct SetHostsResponse {
+    #[serde(rename = "DomainDNSSetHostsResult")]
+    pub result: SetHostsResult,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetHostsResult {
+    #[serde(rename = "@IsSuccess")]
+    pub is_success: String,
+}
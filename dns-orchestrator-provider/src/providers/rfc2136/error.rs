@@ -0,0 +1,71 @@
+//! RFC 2136 错误映射
+//!
+//! 参考: <https://www.rfc-editor.org/rfc/rfc2136#section-2.2>
+//!
+//! 错误码为 DNS 响应码（RCODE）的助记符，如 `SERVFAIL`、`REFUSED`。
+//! TSIG 校验失败时服务器返回 `NOTAUTH`。
+
+use crate::error::ProviderError;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::Rfc2136Provider;
+
+/// RFC 2136 响应码映射实现
+impl ProviderErrorMapper for Rfc2136Provider {
+    fn provider_name(&self) -> &'static str {
+        "rfc2136"
+    }
+
+    fn map_error(&self, raw: RawApiError, context: ErrorContext) -> ProviderError {
+        match raw.code.as_deref() {
+            // ============ 服务器错误 ============
+            Some("SERVFAIL") => ProviderError::ServerFailure {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+            Some("REFUSED") => ProviderError::Refused {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 认证错误 ============
+            Some("NOTAUTH" | "BADSIG" | "BADKEY" | "BADTIME") => {
+                ProviderError::InvalidCredentials {
+                    provider: self.provider_name().to_string(),
+                    raw_message: Some(raw.message),
+                }
+            }
+
+            // ============ 先决条件失败 ============
+            // 记录集不存在
+            Some("NXRRSET") => ProviderError::RecordNotFound {
+                provider: self.provider_name().to_string(),
+                record_id: context.record_id.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            },
+            // 记录集已存在
+            Some("YXRRSET" | "YXDOMAIN") => ProviderError::RecordExists {
+                provider: self.provider_name().to_string(),
+                record_name: context.record_name.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 区域不存在 ============
+            Some("NOTZONE" | "NXDOMAIN") => ProviderError::DomainNotFound {
+                provider: self.provider_name().to_string(),
+                domain: context.domain.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 参数无效 ============
+            Some("FORMERR") => ProviderError::InvalidParameter {
+                provider: self.provider_name().to_string(),
+                param: "message".to_string(),
+                detail: raw.message,
+            },
+
+            // ============ 其他错误 fallback ============
+            _ => self.unknown_error(raw),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! RFC 2136 动态更新 Provider（BIND / Knot / PowerDNS 等自建 DNS）
+//!
+//! - 记录增删改通过 DNS UPDATE 消息完成，列表通过 AXFR 区域传送获取；
+//! - 所有消息均使用 TSIG 签名，并校验服务器响应的 TSIG；
+//! - 统一使用 TCP 传输，避免 AXFR 与较大的 UPDATE 消息被截断；
+//! - 每个账号对应一个区域，记录 ID 由名称、类型与值编码（见 `common::encode_rrset_record_id`）。
+
+mod error;
+mod provider;
+mod transport;
+
+use std::time::Duration;
+
+use crate::error::{ProviderError, Result};
+use crate::traits::{DEFAULT_BATCH_CONCURRENCY, ProviderErrorMapper};
+
+/// 默认 DNS 端口
+pub(crate) const DEFAULT_PORT: u16 = 53;
+/// 默认单次请求超时
+pub(crate) const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+/// TSIG 允许的时间偏差（秒）
+pub(crate) const TSIG_FUDGE: u16 = 300;
+/// 记录列表在内存中分页，限制单页数量
+pub(crate) const MAX_PAGE_SIZE_RECORDS: u32 = 100;
+
+/// RFC 2136 Provider
+pub struct Rfc2136Provider {
+    /// 服务器地址（`host` 或 `host:port`）
+    pub(crate) server: String,
+    /// 区域名称（不带末尾点）
+    pub(crate) zone: String,
+    pub(crate) tsig_key_name: String,
+    pub(crate) tsig_algorithm: String,
+    /// Base64 编码的 TSIG 密钥
+    pub(crate) tsig_secret: String,
+    pub(crate) timeout: Duration,
+    pub(crate) batch_concurrency: usize,
+}
+
+/// RFC 2136 Provider Builder
+pub struct Rfc2136ProviderBuilder {
+    server: String,
+    zone: String,
+    tsig_key_name: String,
+    tsig_algorithm: String,
+    tsig_secret: String,
+    timeout: Duration,
+    batch_concurrency: usize,
+}
+
+impl Rfc2136ProviderBuilder {
+    fn new(
+        server: String,
+        zone: String,
+        tsig_key_name: String,
+        tsig_algorithm: String,
+        tsig_secret: String,
+    ) -> Self {
+        Self {
+            server,
+            zone,
+            tsig_key_name,
+            tsig_algorithm,
+            tsig_secret,
+            timeout: DEFAULT_TIMEOUT,
+            batch_concurrency: DEFAULT_BATCH_CONCURRENCY,
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// 设置批量操作的最大并发请求数（默认 5）
+    pub fn batch_concurrency(mut self, concurrency: usize) -> Self {
+        self.batch_concurrency = concurrency.max(1);
+        self
+    }
+
+    pub fn build(self) -> Rfc2136Provider {
+        Rfc2136Provider {
+            server: self.server.trim().to_string(),
+            zone: self.zone.trim().trim_end_matches('.').to_lowercase(),
+            tsig_key_name: self.tsig_key_name.trim().to_string(),
+            tsig_algorithm: self.tsig_algorithm.trim().to_lowercase(),
+            tsig_secret: self.tsig_secret.trim().to_string(),
+            timeout: self.timeout,
+            batch_concurrency: self.batch_concurrency,
+        }
+    }
+}
+
+impl Rfc2136Provider {
+    pub fn new(
+        server: String,
+        zone: String,
+        tsig_key_name: String,
+        tsig_algorithm: String,
+        tsig_secret: String,
+    ) -> Self {
+        Self::builder(server, zone, tsig_key_name, tsig_algorithm, tsig_secret).build()
+    }
+
+    pub fn builder(
+        server: String,
+        zone: String,
+        tsig_key_name: String,
+        tsig_algorithm: String,
+        tsig_secret: String,
+    ) -> Rfc2136ProviderBuilder {
+        Rfc2136ProviderBuilder::new(server, zone, tsig_key_name, tsig_algorithm, tsig_secret)
+    }
+
+    /// 校验域名 ID 是否为本账号管理的区域
+    pub(crate) fn check_zone(&self, domain_id: &str) -> Result<()> {
+        if domain_id
+            .trim_end_matches('.')
+            .eq_ignore_ascii_case(&self.zone)
+        {
+            Ok(())
+        } else {
+            Err(ProviderError::DomainNotFound {
+                provider: self.provider_name().to_string(),
+                domain: domain_id.to_string(),
+                raw_message: None,
+            })
+        }
+    }
+}
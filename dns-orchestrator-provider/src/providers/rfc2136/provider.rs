@@ -0,0 +1,546 @@
+//! RFC 2136 DnsProvider trait 实现
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use async_trait::async_trait;
+use hickory_proto::dnssec::rdata::{DNSSECRData, DS};
+use hickory_proto::dnssec::{Algorithm, DigestType};
+use hickory_proto::op::update_message::{self, UpdateMessage};
+use hickory_proto::rr::rdata::{A, AAAA, CNAME, MX, NS, SRV, SSHFP, TLSA, TXT};
+use hickory_proto::rr::{DNSClass, Name, RData, Record, RecordSet, RecordType};
+use hickory_proto::serialize::binary::{BinDecoder, Restrict};
+
+use crate::error::{ProviderError, Result};
+use crate::providers::common::{
+    decode_rrset_record_id, encode_rrset_record_id, ensure_not_proxied, ensure_simple_routing,
+    full_name_to_relative, normalize_domain_name, record_type_to_string, relative_to_full_name,
+    validate_record_data,
+};
+use crate::traits::{DnsProvider, ErrorContext, ProviderErrorMapper};
+use crate::types::{
+    CreateDnsRecordRequest, DnsRecord, DomainStatus, FieldType, PaginatedResponse,
+    PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures, ProviderLimits,
+    ProviderMetadata, ProviderType, RecordData, RecordQueryParams, UpdateDnsRecordRequest,
+};
+
+use super::{MAX_PAGE_SIZE_RECORDS, Rfc2136Provider};
+
+/// TXT 单个字符串的最大字节数
+const TXT_CHUNK_SIZE: usize = 255;
+
+impl Rfc2136Provider {
+    fn invalid_param(&self, param: &str, detail: impl Into<String>) -> ProviderError {
+        ProviderError::InvalidParameter {
+            provider: self.provider_name().to_string(),
+            param: param.to_string(),
+            detail: detail.into(),
+        }
+    }
+
+    /// 解析域名（相对名称按区域补全）
+    fn parse_name(&self, name: &str, param: &str) -> Result<Name> {
+        Name::from_utf8(format!("{}.", normalize_domain_name(name)))
+            .map_err(|e| self.invalid_param(param, e.to_string()))
+    }
+
+    fn domain(&self) -> ProviderDomain {
+        ProviderDomain {
+            id: self.zone.clone(),
+            name: self.zone.clone(),
+            provider: ProviderType::Rfc2136,
+            status: DomainStatus::Active,
+            record_count: None,
+        }
+    }
+
+    /// 记录 ID 中的值使用 RecordData 的 JSON 表示，便于无损还原
+    fn record_id(&self, full_name: &str, data: &RecordData) -> Result<String> {
+        let value = serde_json::to_string(data).map_err(|e| ProviderError::SerializationError {
+            provider: self.provider_name().to_string(),
+            detail: e.to_string(),
+        })?;
+        Ok(encode_rrset_record_id(
+            full_name,
+            record_type_to_string(&data.record_type()),
+            &value,
+        ))
+    }
+
+    /// 解码记录 ID，返回 (完整名称, 记录数据)
+    fn decode_record_id(&self, record_id: &str) -> Result<(String, RecordData)> {
+        let invalid =
+            || self.invalid_param("record_id", format!("Invalid record id: '{record_id}'"));
+        let (full_name, _, value) = decode_rrset_record_id(record_id).ok_or_else(invalid)?;
+        let data = serde_json::from_str(&value).map_err(|_| invalid())?;
+        self.check_in_zone(&full_name, &full_name)?;
+        Ok((full_name, data))
+    }
+
+    /// 将 RData 转换为 RecordData，不支持的类型返回 None
+    fn parse_rdata(rdata: &RData) -> Option<RecordData> {
+        let name = |n: &Name| normalize_domain_name(&n.to_utf8());
+        match rdata {
+            RData::A(a) => Some(RecordData::A {
+                address: a.0.to_string(),
+            }),
+            RData::AAAA(aaaa) => Some(RecordData::AAAA {
+                address: aaaa.0.to_string(),
+            }),
+            RData::CNAME(cname) => Some(RecordData::CNAME {
+                target: name(&cname.0),
+            }),
+            RData::NS(ns) => Some(RecordData::NS {
+                nameserver: name(&ns.0),
+            }),
+            RData::MX(mx) => Some(RecordData::MX {
+                priority: mx.preference(),
+                exchange: name(mx.exchange()),
+            }),
+            RData::TXT(txt) => Some(RecordData::TXT {
+                text: txt
+                    .txt_data()
+                    .iter()
+                    .map(|chunk| String::from_utf8_lossy(chunk))
+                    .collect(),
+            }),
+            RData::SRV(srv) => Some(RecordData::SRV {
+                priority: srv.priority(),
+                weight: srv.weight(),
+                port: srv.port(),
+                target: name(srv.target()),
+            }),
+            RData::CAA(caa) => Some(RecordData::CAA {
+                flags: caa.flags(),
+                tag: caa.tag().as_str().to_string(),
+                value: String::from_utf8_lossy(caa.raw_value()).into_owned(),
+            }),
+            RData::TLSA(tlsa) => Some(RecordData::TLSA {
+                usage: tlsa.cert_usage().into(),
+                selector: tlsa.selector().into(),
+                matching_type: tlsa.matching().into(),
+                certificate_association: hex::encode(tlsa.cert_data()),
+            }),
+            RData::SSHFP(sshfp) => Some(RecordData::SSHFP {
+                algorithm: sshfp.algorithm().into(),
+                fingerprint_type: sshfp.fingerprint_type().into(),
+                fingerprint: hex::encode(sshfp.fingerprint()),
+            }),
+            RData::DNSSEC(DNSSECRData::DS(ds)) => Some(RecordData::DS {
+                key_tag: ds.key_tag(),
+                algorithm: ds.algorithm().into(),
+                digest_type: ds.digest_type().into(),
+                digest: hex::encode(ds.digest()),
+            }),
+            _ => None,
+        }
+    }
+
+    /// 将 RecordData 转换为 RData
+    fn to_rdata(&self, data: &RecordData) -> Result<RData> {
+        let rdata = match data {
+            RecordData::A { address } => RData::A(A(address
+                .parse::<Ipv4Addr>()
+                .map_err(|e| self.invalid_param("address", e.to_string()))?)),
+            RecordData::AAAA { address } => RData::AAAA(AAAA(
+                address
+                    .parse::<Ipv6Addr>()
+                    .map_err(|e| self.invalid_param("address", e.to_string()))?,
+            )),
+            RecordData::CNAME { target } => RData::CNAME(CNAME(self.parse_name(target, "target")?)),
+            // ALIAS 不是标准 DNS 记录类型，动态更新无法表达
+            RecordData::ALIAS { .. } => {
+                return Err(ProviderError::UnsupportedRecordType {
+                    provider: self.provider_name().to_string(),
+                    record_type: "ALIAS".to_string(),
+                });
+            }
+            RecordData::NS { nameserver } => {
+                RData::NS(NS(self.parse_name(nameserver, "nameserver")?))
+            }
+            RecordData::MX { priority, exchange } => {
+                RData::MX(MX::new(*priority, self.parse_name(exchange, "exchange")?))
+            }
+            RecordData::TXT { text } => {
+                let chunks: Vec<&[u8]> = if text.is_empty() {
+                    vec![&[]]
+                } else {
+                    text.as_bytes().chunks(TXT_CHUNK_SIZE).collect()
+                };
+                RData::TXT(TXT::from_bytes(chunks))
+            }
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => RData::SRV(SRV::new(
+                *priority,
+                *weight,
+                *port,
+                self.parse_name(target, "target")?,
+            )),
+            RecordData::CAA { flags, tag, value } => {
+                // 按线路格式构造，保留任意 tag 与原始值
+                let tag_len = u8::try_from(tag.len())
+                    .map_err(|_| self.invalid_param("tag", "CAA tag is too long"))?;
+                let mut wire = vec![*flags, tag_len];
+                wire.extend_from_slice(tag.as_bytes());
+                wire.extend_from_slice(value.as_bytes());
+                let len = u16::try_from(wire.len())
+                    .map_err(|_| self.invalid_param("value", "CAA value is too long"))?;
+                let mut decoder = BinDecoder::new(&wire);
+                RData::read(&mut decoder, RecordType::CAA, Restrict::new(len))
+                    .map_err(|e| self.invalid_param("value", e.to_string()))?
+            }
+            RecordData::TLSA {
+                usage,
+                selector,
+                matching_type,
+                certificate_association,
+            } => {
+                validate_record_data(data, self.provider_name())?;
+                let cert_data = hex::decode(certificate_association)
+                    .map_err(|e| self.invalid_param("certificate_association", e.to_string()))?;
+                RData::TLSA(TLSA::new(
+                    (*usage).into(),
+                    (*selector).into(),
+                    (*matching_type).into(),
+                    cert_data,
+                ))
+            }
+            RecordData::SSHFP {
+                algorithm,
+                fingerprint_type,
+                fingerprint,
+            } => {
+                validate_record_data(data, self.provider_name())?;
+                let fingerprint = hex::decode(fingerprint)
+                    .map_err(|e| self.invalid_param("fingerprint", e.to_string()))?;
+                RData::SSHFP(SSHFP::new(
+                    (*algorithm).into(),
+                    (*fingerprint_type).into(),
+                    fingerprint,
+                ))
+            }
+            RecordData::DS {
+                key_tag,
+                algorithm,
+                digest_type,
+                digest,
+            } => {
+                validate_record_data(data, self.provider_name())?;
+                let digest =
+                    hex::decode(digest).map_err(|e| self.invalid_param("digest", e.to_string()))?;
+                RData::DNSSEC(DNSSECRData::DS(DS::new(
+                    *key_tag,
+                    Algorithm::from_u8(*algorithm),
+                    DigestType::from(*digest_type),
+                    digest,
+                )))
+            }
+        };
+        Ok(rdata)
+    }
+
+    /// 构造单条记录的记录集
+    fn record_set(&self, full_name: &str, ttl: u32, data: &RecordData) -> Result<RecordSet> {
+        let name = self.parse_name(full_name, "name")?;
+        Ok(RecordSet::from(Record::from_rdata(
+            name,
+            ttl,
+            self.to_rdata(data)?,
+        )))
+    }
+
+    /// 先决条件：记录集存在（RFC 2136 2.4.1）
+    fn rrset_exists(rrset: &RecordSet) -> Record {
+        let mut record = Record::update0(rrset.name().clone(), 0, rrset.record_type());
+        record.set_dns_class(DNSClass::ANY);
+        record
+    }
+
+    /// 检查记录名称是否在区域内
+    fn check_in_zone(&self, full_name: &str, display_name: &str) -> Result<()> {
+        let name = full_name.to_lowercase();
+        if name == self.zone || name.ends_with(&format!(".{}", self.zone)) {
+            Ok(())
+        } else {
+            Err(self.invalid_param(
+                "name",
+                format!("'{display_name}' is outside of zone '{}'", self.zone),
+            ))
+        }
+    }
+}
+
+#[async_trait]
+impl DnsProvider for Rfc2136Provider {
+    fn id(&self) -> &'static str {
+        "rfc2136"
+    }
+
+    fn batch_concurrency(&self) -> usize {
+        self.batch_concurrency
+    }
+
+    fn metadata() -> ProviderMetadata {
+        ProviderMetadata {
+            id: ProviderType::Rfc2136,
+            name: "RFC 2136".to_string(),
+            description: "通过 DNS UPDATE 管理 BIND、Knot 等自建 DNS 服务器".to_string(),
+            required_fields: vec![
+                ProviderCredentialField {
+                    key: "server".to_string(),
+                    label: "服务器".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("ns1.example.com:53".to_string()),
+                    help_text: Some("主 DNS 服务器地址，端口默认为 53".to_string()),
+                },
+                ProviderCredentialField {
+                    key: "zone".to_string(),
+                    label: "区域".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("example.com".to_string()),
+                    help_text: None,
+                },
+                ProviderCredentialField {
+                    key: "tsigKeyName".to_string(),
+                    label: "TSIG Key Name".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("输入 TSIG 密钥名称".to_string()),
+                    help_text: None,
+                },
+                ProviderCredentialField {
+                    key: "tsigAlgorithm".to_string(),
+                    label: "TSIG Algorithm".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("hmac-sha256".to_string()),
+                    help_text: Some("支持 hmac-sha256、hmac-sha384、hmac-sha512".to_string()),
+                },
+                ProviderCredentialField {
+                    key: "tsigSecret".to_string(),
+                    label: "TSIG Secret".to_string(),
+                    field_type: FieldType::Password,
+                    placeholder: Some("输入 Base64 编码的 TSIG 密钥".to_string()),
+                    help_text: None,
+                },
+            ],
+            features: ProviderFeatures::default(),
+            limits: ProviderLimits {
+                max_page_size_domains: 1,
+                max_page_size_records: MAX_PAGE_SIZE_RECORDS,
+                requests_per_second: None,
+                max_batch_size: 1,
+                max_records_per_zone: None,
+                // RFC 2181：TTL 最大为 2^31 - 1
+                min_ttl: 0,
+                max_ttl: 2_147_483_647,
+                auto_ttl: None,
+            },
+        }
+    }
+
+    /// 通过一次 AXFR 验证 TSIG 密钥及传送权限
+    async fn validate_credentials(&self) -> Result<bool> {
+        match self.zone_transfer(ErrorContext::default()).await {
+            Ok(_) => Ok(true),
+            Err(ProviderError::InvalidCredentials { .. }) => Ok(false),
+            Err(e) => {
+                log::warn!("凭证验证失败: {e}");
+                Ok(false)
+            }
+        }
+    }
+
+    /// 每个账号仅管理一个区域
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> Result<PaginatedResponse<ProviderDomain>> {
+        let domains = if params.page.max(1) == 1 {
+            vec![self.domain()]
+        } else {
+            Vec::new()
+        };
+        Ok(PaginatedResponse::new(
+            domains,
+            params.page,
+            params.page_size,
+            1,
+        ))
+    }
+
+    async fn get_domain(&self, domain_id: &str) -> Result<ProviderDomain> {
+        self.check_zone(domain_id)?;
+        Ok(self.domain())
+    }
+
+    /// AXFR 获取全部记录后在内存中过滤与分页
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        self.check_zone(domain_id)?;
+        let ctx = ErrorContext {
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let zone_records = self.zone_transfer(ctx).await?;
+
+        let keyword = params
+            .keyword
+            .as_ref()
+            .filter(|k| !k.is_empty())
+            .map(|k| k.to_lowercase());
+
+        let mut records = Vec::new();
+        for record in &zone_records {
+            // 跳过 SOA、DNSSEC 等不支持的类型
+            let Some(data) = Self::parse_rdata(record.data()) else {
+                continue;
+            };
+            if params
+                .record_type
+                .as_ref()
+                .is_some_and(|t| *t != data.record_type())
+            {
+                continue;
+            }
+
+            let full_name = normalize_domain_name(&record.name().to_utf8()).to_lowercase();
+            let name = full_name_to_relative(&full_name, &self.zone);
+
+            if let Some(ref kw) = keyword
+                && !name.to_lowercase().contains(kw)
+                && !data.display_value().to_lowercase().contains(kw)
+            {
+                continue;
+            }
+
+            records.push(DnsRecord {
+                id: self.record_id(&full_name, &data)?,
+                domain_id: domain_id.to_string(),
+                name,
+                ttl: record.ttl(),
+                data,
+                proxied: None,
+                comment: None,
+                tags: Vec::new(),
+                created_at: None,
+                updated_at: None,
+                routing_policy: None,
+            });
+        }
+
+        let total_count = records.len() as u32;
+        let records = records
+            .into_iter()
+            .skip(((params.page.max(1) - 1) * params.page_size) as usize)
+            .take(params.page_size as usize)
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            records,
+            params.page,
+            params.page_size,
+            total_count,
+        ))
+    }
+
+    /// 向记录集追加一条记录；服务器会忽略重复的记录
+    async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        ensure_simple_routing(req.routing_policy.as_ref(), self.provider_name())?;
+        ensure_not_proxied(req.proxied, self.provider_name())?;
+        self.validate_ttl(req.ttl)?;
+        self.check_zone(&req.domain_id)?;
+        let full_name = relative_to_full_name(&req.name, &self.zone).to_lowercase();
+        self.check_in_zone(&full_name, &req.name)?;
+
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            domain: Some(req.domain_id.clone()),
+            ..Default::default()
+        };
+
+        let rrset = self.record_set(&full_name, req.ttl, &req.data)?;
+        let message = update_message::append(rrset, self.zone_name()?, false, false);
+        self.send_update(message, ctx).await?;
+
+        Ok(DnsRecord {
+            id: self.record_id(&full_name, &req.data)?,
+            domain_id: req.domain_id.clone(),
+            name: full_name_to_relative(&full_name, &self.zone),
+            ttl: req.ttl,
+            data: req.data.clone(),
+            proxied: None,
+            comment: None,
+            tags: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            routing_policy: None,
+        })
+    }
+
+    /// 在同一个 UPDATE 消息中删除旧记录并添加新记录，服务器原子执行
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<DnsRecord> {
+        ensure_not_proxied(req.proxied, self.provider_name())?;
+        self.validate_ttl(req.ttl)?;
+        self.check_zone(&req.domain_id)?;
+        let (old_name, old_data) = self.decode_record_id(record_id)?;
+        let full_name = relative_to_full_name(&req.name, &self.zone).to_lowercase();
+        self.check_in_zone(&full_name, &req.name)?;
+
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            record_id: Some(record_id.to_string()),
+            domain: Some(req.domain_id.clone()),
+        };
+
+        let old_rrset = self.record_set(&old_name, 0, &old_data)?;
+        let prerequisite = Self::rrset_exists(&old_rrset);
+        let mut message = update_message::delete_by_rdata(old_rrset, self.zone_name()?, false);
+        message.add_pre_requisite(prerequisite);
+        for record in self
+            .record_set(&full_name, req.ttl, &req.data)?
+            .records_without_rrsigs()
+        {
+            message.add_update(record.clone());
+        }
+        self.send_update(message, ctx).await?;
+
+        Ok(DnsRecord {
+            id: self.record_id(&full_name, &req.data)?,
+            domain_id: req.domain_id.clone(),
+            name: full_name_to_relative(&full_name, &self.zone),
+            ttl: req.ttl,
+            data: req.data.clone(),
+            proxied: None,
+            comment: None,
+            tags: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            routing_policy: None,
+        })
+    }
+
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
+        self.check_zone(domain_id)?;
+        let (full_name, data) = self.decode_record_id(record_id)?;
+        let ctx = ErrorContext {
+            record_id: Some(record_id.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+
+        let rrset = self.record_set(&full_name, 0, &data)?;
+        let prerequisite = Self::rrset_exists(&rrset);
+        let mut message = update_message::delete_by_rdata(rrset, self.zone_name()?, false);
+        message.add_pre_requisite(prerequisite);
+        self.send_update(message, ctx).await
+    }
+}
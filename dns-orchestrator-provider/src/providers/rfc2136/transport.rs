@@ -0,0 +1,220 @@
+//! RFC 2136 传输层：TSIG 签名与 TCP 消息收发
+
+use std::net::{IpAddr, SocketAddr};
+
+use base64::Engine;
+use chrono::Utc;
+use hickory_proto::dnssec::rdata::tsig::TsigAlgorithm;
+use hickory_proto::dnssec::tsig::TSigner;
+use hickory_proto::op::{Message, MessageVerifier, ResponseCode};
+use hickory_proto::rr::{Name, Record, RecordType};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+use crate::error::{ProviderError, Result};
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::{DEFAULT_PORT, Rfc2136Provider, TSIG_FUDGE};
+
+/// AXFR 最多接收的消息数量，防止异常服务器无限发送
+const MAX_AXFR_MESSAGES: usize = 10_000;
+
+/// 响应码助记符
+fn rcode_name(code: ResponseCode) -> String {
+    match code {
+        ResponseCode::FormErr => "FORMERR".to_string(),
+        ResponseCode::ServFail => "SERVFAIL".to_string(),
+        ResponseCode::NXDomain => "NXDOMAIN".to_string(),
+        ResponseCode::NotImp => "NOTIMP".to_string(),
+        ResponseCode::Refused => "REFUSED".to_string(),
+        ResponseCode::YXDomain => "YXDOMAIN".to_string(),
+        ResponseCode::YXRRSet => "YXRRSET".to_string(),
+        ResponseCode::NXRRSet => "NXRRSET".to_string(),
+        ResponseCode::NotAuth => "NOTAUTH".to_string(),
+        ResponseCode::NotZone => "NOTZONE".to_string(),
+        ResponseCode::BADSIG => "BADSIG".to_string(),
+        ResponseCode::BADKEY => "BADKEY".to_string(),
+        ResponseCode::BADTIME => "BADTIME".to_string(),
+        other => u16::from(other).to_string(),
+    }
+}
+
+impl Rfc2136Provider {
+    fn credentials_err(&self, message: impl Into<String>) -> ProviderError {
+        ProviderError::InvalidCredentials {
+            provider: self.provider_name().to_string(),
+            raw_message: Some(message.into()),
+        }
+    }
+
+    /// 区域名称（FQDN）
+    pub(crate) fn zone_name(&self) -> Result<Name> {
+        Name::from_ascii(format!("{}.", self.zone)).map_err(|e| ProviderError::InvalidParameter {
+            provider: self.provider_name().to_string(),
+            param: "zone".to_string(),
+            detail: e.to_string(),
+        })
+    }
+
+    /// 构造 TSIG 签名器
+    fn signer(&self) -> Result<TSigner> {
+        let algorithm = match self.tsig_algorithm.trim_end_matches('.') {
+            "hmac-sha256" => TsigAlgorithm::HmacSha256,
+            "hmac-sha384" => TsigAlgorithm::HmacSha384,
+            "hmac-sha512" => TsigAlgorithm::HmacSha512,
+            other => {
+                return Err(self.credentials_err(format!(
+                    "Unsupported TSIG algorithm '{other}', expected hmac-sha256/384/512"
+                )));
+            }
+        };
+        let key = base64::engine::general_purpose::STANDARD
+            .decode(&self.tsig_secret)
+            .map_err(|e| self.credentials_err(format!("Invalid TSIG secret: {e}")))?;
+        let key_name = Name::from_ascii(&self.tsig_key_name)
+            .map_err(|e| self.credentials_err(format!("Invalid TSIG key name: {e}")))?;
+
+        TSigner::new(key, algorithm, key_name, TSIG_FUDGE)
+            .map_err(|e| self.credentials_err(e.to_string()))
+    }
+
+    /// 解析服务器地址，未指定端口时使用 53
+    fn server_addr(&self) -> String {
+        if let Ok(addr) = self.server.parse::<SocketAddr>() {
+            return addr.to_string();
+        }
+        if let Ok(ip) = self.server.parse::<IpAddr>() {
+            return SocketAddr::new(ip, DEFAULT_PORT).to_string();
+        }
+        if self.server.contains(':') {
+            self.server.clone()
+        } else {
+            format!("{}:{DEFAULT_PORT}", self.server)
+        }
+    }
+
+    /// 签名消息并建立连接、发送请求
+    async fn send_signed(&self, mut message: Message) -> Result<(TcpStream, MessageVerifier)> {
+        let signer = self.signer()?;
+        let now = Utc::now().timestamp() as u32;
+        let verifier = message
+            .finalize(&signer, now)
+            .map_err(|e| self.credentials_err(e.to_string()))?
+            .ok_or_else(|| self.credentials_err("TSIG signer returned no verifier"))?;
+        let bytes = message
+            .to_vec()
+            .map_err(|e| ProviderError::SerializationError {
+                provider: self.provider_name().to_string(),
+                detail: e.to_string(),
+            })?;
+        let len = u16::try_from(bytes.len()).map_err(|_| ProviderError::InvalidParameter {
+            provider: self.provider_name().to_string(),
+            param: "message".to_string(),
+            detail: "DNS message exceeds 65535 bytes".to_string(),
+        })?;
+
+        let addr = self.server_addr();
+        log::debug!("[rfc2136] 连接 {addr}");
+        let mut stream = tokio::time::timeout(self.timeout, TcpStream::connect(&addr))
+            .await
+            .map_err(|_| self.network_error(format!("Connection to {addr} timed out")))?
+            .map_err(|e| self.network_error(format!("Failed to connect to {addr}: {e}")))?;
+
+        let mut frame = Vec::with_capacity(bytes.len() + 2);
+        frame.extend_from_slice(&len.to_be_bytes());
+        frame.extend_from_slice(&bytes);
+        tokio::time::timeout(self.timeout, stream.write_all(&frame))
+            .await
+            .map_err(|_| self.network_error("Write timed out"))?
+            .map_err(|e| self.network_error(e))?;
+
+        Ok((stream, verifier))
+    }
+
+    /// 读取一条带长度前缀的 DNS 消息
+    async fn read_frame(&self, stream: &mut TcpStream) -> Result<Vec<u8>> {
+        let read = async {
+            let len = stream.read_u16().await? as usize;
+            let mut buf = vec![0u8; len];
+            stream.read_exact(&mut buf).await?;
+            Ok::<_, std::io::Error>(buf)
+        };
+        tokio::time::timeout(self.timeout, read)
+            .await
+            .map_err(|_| self.network_error("Read timed out"))?
+            .map_err(|e| self.network_error(e))
+    }
+
+    /// 检查响应码并校验 TSIG
+    ///
+    /// 错误响应可能未签名（如 BADKEY），因此先检查响应码再校验签名
+    fn check_response(
+        &self,
+        id: u16,
+        bytes: &[u8],
+        verifier: &mut MessageVerifier,
+        ctx: &ErrorContext,
+    ) -> Result<Message> {
+        let message = Message::from_vec(bytes).map_err(|e| self.parse_error(e))?;
+        if message.id() != id {
+            return Err(self.parse_error(format!(
+                "Response id {} does not match request id {id}",
+                message.id()
+            )));
+        }
+
+        let code = message.response_code();
+        if code != ResponseCode::NoError {
+            let name = rcode_name(code);
+            return Err(self.map_error(
+                RawApiError::with_code(name.clone(), format!("Server responded with {name}")),
+                ctx.clone(),
+            ));
+        }
+
+        verifier(bytes)
+            .map_err(|e| self.credentials_err(format!("TSIG verification failed: {e}")))?;
+        Ok(message)
+    }
+
+    // ==================== 公开方法 ====================
+
+    /// 发送 UPDATE 消息
+    pub(crate) async fn send_update(&self, message: Message, ctx: ErrorContext) -> Result<()> {
+        let id = message.id();
+        let (mut stream, mut verifier) = self.send_signed(message).await?;
+        let bytes = self.read_frame(&mut stream).await?;
+        self.check_response(id, &bytes, &mut verifier, &ctx)?;
+        Ok(())
+    }
+
+    /// 通过 AXFR 获取区域中的全部记录（首尾的 SOA 只保留一条）
+    pub(crate) async fn zone_transfer(&self, ctx: ErrorContext) -> Result<Vec<Record>> {
+        let message = hickory_proto::op::update_message::zone_transfer(self.zone_name()?, None);
+        let id = message.id();
+        let (mut stream, mut verifier) = self.send_signed(message).await?;
+
+        let mut records: Vec<Record> = Vec::new();
+        let mut soa_count = 0;
+        for _ in 0..MAX_AXFR_MESSAGES {
+            let bytes = self.read_frame(&mut stream).await?;
+            let mut response = self.check_response(id, &bytes, &mut verifier, &ctx)?;
+
+            for record in response.take_answers() {
+                if record.record_type() == RecordType::SOA {
+                    soa_count += 1;
+                    if soa_count > 1 {
+                        return Ok(records);
+                    }
+                }
+                records.push(record);
+            }
+
+            if soa_count == 0 {
+                return Err(self.parse_error("AXFR response does not start with SOA"));
+            }
+        }
+
+        Err(self.parse_error("AXFR did not terminate"))
+    }
+}
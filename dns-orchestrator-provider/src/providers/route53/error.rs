@@ -0,0 +1,106 @@
+//! Route 53 错误映射
+//!
+//! 参考: <https://docs.aws.amazon.com/Route53/latest/APIReference/CommonErrors.html>
+//!
+//! `InvalidChangeBatch` 涵盖了记录已存在、记录不存在、值格式错误等多种情况，
+//! 需根据错误消息进一步区分。
+
+use crate::error::ProviderError;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::Route53Provider;
+
+/// Route 53 错误码映射实现
+impl ProviderErrorMapper for Route53Provider {
+    fn provider_name(&self) -> &'static str {
+        "route53"
+    }
+
+    fn map_error(&self, raw: RawApiError, context: ErrorContext) -> ProviderError {
+        match raw.code.as_deref() {
+            // ============ 认证错误 ============
+            Some(
+                "InvalidClientTokenId"
+                | "SignatureDoesNotMatch"
+                | "IncompleteSignature"
+                | "MissingAuthenticationToken"
+                | "UnrecognizedClientException"
+                | "ExpiredToken",
+            ) => ProviderError::InvalidCredentials {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 权限拒绝 ============
+            Some("AccessDenied" | "AccessDeniedException" | "OptInRequired") => {
+                ProviderError::PermissionDenied {
+                    provider: self.provider_name().to_string(),
+                    raw_message: Some(raw.message),
+                }
+            }
+
+            // ============ 配额/频率限制 ============
+            Some(
+                "Throttling"
+                | "ThrottlingException"
+                | "PriorRequestNotComplete"
+                | "TooManyHostedZones"
+                | "LimitsExceeded",
+            ) => ProviderError::QuotaExceeded {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 域名不存在 ============
+            Some("NoSuchHostedZone" | "HostedZoneNotFound") => ProviderError::DomainNotFound {
+                provider: self.provider_name().to_string(),
+                domain: context.domain.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 变更批次错误 ============
+            Some("InvalidChangeBatch") => {
+                if raw.message.contains("already exists") {
+                    ProviderError::RecordExists {
+                        provider: self.provider_name().to_string(),
+                        record_name: context.record_name.unwrap_or_default(),
+                        raw_message: Some(raw.message),
+                    }
+                } else if raw.message.contains("not found") {
+                    ProviderError::RecordNotFound {
+                        provider: self.provider_name().to_string(),
+                        record_id: context.record_id.unwrap_or_default(),
+                        raw_message: Some(raw.message),
+                    }
+                } else {
+                    ProviderError::InvalidParameter {
+                        provider: self.provider_name().to_string(),
+                        param: "change_batch".to_string(),
+                        detail: raw.message,
+                    }
+                }
+            }
+
+            // ============ 参数无效 ============
+            Some("InvalidDomainName") => ProviderError::InvalidParameter {
+                provider: self.provider_name().to_string(),
+                param: "name".to_string(),
+                detail: raw.message,
+            },
+            Some("InvalidInput" | "ValidationError") => ProviderError::InvalidParameter {
+                provider: self.provider_name().to_string(),
+                param: "input".to_string(),
+                detail: raw.message,
+            },
+
+            // ============ 服务端错误 ============
+            Some("ServiceUnavailable" | "InternalFailure") => ProviderError::NetworkError {
+                provider: self.provider_name().to_string(),
+                detail: raw.message,
+            },
+
+            // ============ 其他错误 fallback ============
+            _ => self.unknown_error(raw),
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Route 53 HTTP 请求方法
+
+use chrono::Utc;
+use serde::de::DeserializeOwned;
+
+use crate::error::{ProviderError, Result};
+use crate::http_client::HttpUtils;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::Route53Provider;
+use super::types::{ErrorResponse, InvalidChangeBatchResponse};
+
+impl Route53Provider {
+    // ==================== 辅助方法 ====================
+
+    /// 统一处理 Route 53 响应错误
+    fn handle_response_error(
+        &self,
+        status: u16,
+        response_text: &str,
+        ctx: ErrorContext,
+    ) -> Result<()> {
+        if (200..300).contains(&status) {
+            return Ok(());
+        }
+
+        // 通用错误格式
+        if let Ok(error) = quick_xml::de::from_str::<ErrorResponse>(response_text) {
+            return Err(self.map_error(
+                RawApiError::with_code(
+                    error.error.code.unwrap_or_else(|| "UNKNOWN".to_string()),
+                    error
+                        .error
+                        .message
+                        .unwrap_or_else(|| "No error message provided by API".to_string()),
+                )
+                .with_status(status),
+                ctx,
+            ));
+        }
+
+        // ChangeResourceRecordSets 的变更批次错误格式
+        if response_text.contains("<InvalidChangeBatch")
+            && let Ok(error) = quick_xml::de::from_str::<InvalidChangeBatchResponse>(response_text)
+        {
+            let message = error
+                .messages
+                .map(|m| m.items.join("; "))
+                .filter(|m| !m.is_empty())
+                .or(error.message)
+                .unwrap_or_else(|| "No error message provided by API".to_string());
+            return Err(self.map_error(
+                RawApiError::with_code("InvalidChangeBatch", message).with_status(status),
+                ctx,
+            ));
+        }
+
+        // 回退到通用错误
+        Err(self.unknown_error(RawApiError::new(response_text).with_status(status)))
+    }
+
+    /// 执行签名请求，返回响应文本
+    async fn request(
+        &self,
+        method: &str,
+        path: &str,
+        query: &str,
+        payload: Option<String>,
+        ctx: ErrorContext,
+    ) -> Result<String> {
+        let (host, _) = self.endpoint();
+        let amz_date = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+
+        let mut headers = vec![
+            ("Host".to_string(), host.to_string()),
+            ("X-Amz-Date".to_string(), amz_date.clone()),
+        ];
+        if payload.is_some() {
+            headers.push(("Content-Type".to_string(), "application/xml".to_string()));
+        }
+
+        let payload = payload.unwrap_or_default();
+        let authorization = self.sign(method, path, query, &headers, &payload, &amz_date);
+
+        let url = if query.is_empty() {
+            format!("https://{host}{path}")
+        } else {
+            format!("https://{host}{path}?{query}")
+        };
+
+        let request_builder = match method {
+            "GET" => self.client.get(&url),
+            "POST" => self.client.post(&url),
+            _ => unreachable!("Route 53 provider only uses GET/POST"),
+        };
+
+        let mut request = request_builder.header("Authorization", authorization);
+        for (key, value) in &headers {
+            request = request.header(key.as_str(), value.as_str());
+        }
+        if !payload.is_empty() {
+            log::debug!("Request Body: {payload}");
+            request = request.body(payload);
+        }
+
+        let (status, response_text) = HttpUtils::execute_request_with_retry(
+            request,
+            self.provider_name(),
+            method,
+            &url,
+            self.max_retries,
+        )
+        .await?;
+
+        self.handle_response_error(status, &response_text, ctx)?;
+        Ok(response_text)
+    }
+
+    // ==================== 公开 API 方法 ====================
+
+    /// 执行 GET 请求
+    pub(crate) async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &str,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        let response_text = self.request("GET", path, query, None, ctx).await?;
+        HttpUtils::parse_xml(&response_text, self.provider_name())
+    }
+
+    /// 执行 POST 请求（XML body）
+    pub(crate) async fn post_xml<T: DeserializeOwned, B: serde::Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        let payload =
+            quick_xml::se::to_string(body).map_err(|e| ProviderError::SerializationError {
+                provider: self.provider_name().to_string(),
+                detail: e.to_string(),
+            })?;
+        let payload = format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>{payload}");
+
+        let response_text = self.request("POST", path, "", Some(payload), ctx).await?;
+        HttpUtils::parse_xml(&response_text, self.provider_name())
+    }
+}
@@ -0,0 +1,128 @@
+//! Amazon Route 53 Provider
+//!
+//! Route 53 以记录集（名称 + 类型）为单位管理记录，一个记录集可包含多个值。
+//! 本 Provider 将记录集中的每个值映射为一条独立的 [`DnsRecord`](crate::DnsRecord)，
+//! 记录 ID 由「完整名称 + 类型 + 值」编码而成（见 `common::encode_rrset_record_id`）；增删改通过 `ChangeResourceRecordSets`
+//! 对整个记录集执行 UPSERT/DELETE，因此同一记录集内的值共享 TTL。
+//!
+//! 加权、延迟与地理位置路由的策略记录集（`SetIdentifier`）会带上 `routing_policy` 列出，
+//! 记录 ID 额外包含记录集标识，更新与删除都在原策略记录集内进行。
+//! 别名记录（`AliasTarget`）以及 `RoutingPolicy` 无法表示的策略（故障转移、多值应答等）
+//! 无法映射到单值模型，列表时会被跳过。
+
+mod error;
+mod http;
+mod provider;
+mod sign;
+pub(crate) mod types;
+
+use reqwest::Client;
+
+use crate::http_client::ProxyConfig;
+use crate::providers::common::create_http_client;
+use crate::traits::DEFAULT_BATCH_CONCURRENCY;
+use crate::types::DnsRecordType;
+
+pub(crate) const ROUTE53_API_VERSION: &str = "2013-04-01";
+pub(crate) const ROUTE53_SERVICE: &str = "route53";
+/// 默认区域（Route 53 为全局服务，签名统一使用 us-east-1）
+pub(crate) const DEFAULT_REGION: &str = "us-east-1";
+/// ListHostedZones 单页最大数量
+pub(crate) const MAX_PAGE_SIZE_ZONES: u32 = 100;
+/// ListResourceRecordSets 单页最大数量
+pub(crate) const MAX_PAGE_SIZE_RECORDS: u32 = 300;
+/// 无原生支持的记录类型
+pub(crate) const UNSUPPORTED_RECORD_TYPES: &[DnsRecordType] = &[DnsRecordType::Alias];
+
+/// Amazon Route 53 Provider
+pub struct Route53Provider {
+    pub(crate) client: Client,
+    pub(crate) access_key_id: String,
+    pub(crate) secret_access_key: String,
+    pub(crate) region: String,
+    pub(crate) max_retries: u32,
+    pub(crate) batch_concurrency: usize,
+}
+
+/// Route 53 Provider Builder
+pub struct Route53ProviderBuilder {
+    access_key_id: String,
+    secret_access_key: String,
+    region: String,
+    max_retries: u32,
+    proxy: Option<ProxyConfig>,
+    batch_concurrency: usize,
+}
+
+impl Route53ProviderBuilder {
+    fn new(access_key_id: String, secret_access_key: String) -> Self {
+        Self {
+            access_key_id,
+            secret_access_key,
+            region: DEFAULT_REGION.to_string(),
+            max_retries: 2,
+            proxy: None,
+            batch_concurrency: DEFAULT_BATCH_CONCURRENCY,
+        }
+    }
+
+    /// 设置区域（仅用于区分 AWS 分区，如 cn-north-1 使用中国区端点）
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        let region = region.into();
+        if !region.trim().is_empty() {
+            self.region = region.trim().to_string();
+        }
+        self
+    }
+
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// 设置 HTTP 代理
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// 设置批量操作的最大并发请求数（默认 5）
+    pub fn batch_concurrency(mut self, concurrency: usize) -> Self {
+        self.batch_concurrency = concurrency.max(1);
+        self
+    }
+
+    pub fn build(self) -> Route53Provider {
+        Route53Provider {
+            client: create_http_client(self.proxy.as_ref()),
+            access_key_id: self.access_key_id,
+            secret_access_key: self.secret_access_key,
+            region: self.region,
+            max_retries: self.max_retries,
+            batch_concurrency: self.batch_concurrency,
+        }
+    }
+}
+
+impl Route53Provider {
+    pub fn new(access_key_id: String, secret_access_key: String, region: String) -> Self {
+        Self::builder(access_key_id, secret_access_key)
+            .region(region)
+            .build()
+    }
+
+    pub fn builder(access_key_id: String, secret_access_key: String) -> Route53ProviderBuilder {
+        Route53ProviderBuilder::new(access_key_id, secret_access_key)
+    }
+
+    /// 返回 API 主机名与签名区域
+    ///
+    /// Route 53 是全局服务：标准分区固定使用 us-east-1 签名，中国区使用 cn-northwest-1
+    pub(crate) fn endpoint(&self) -> (&'static str, &'static str) {
+        if self.region.starts_with("cn-") {
+            ("route53.amazonaws.com.cn", "cn-northwest-1")
+        } else {
+            ("route53.amazonaws.com", DEFAULT_REGION)
+        }
+    }
+}
@@ -0,0 +1,976 @@
+//! Route 53 DnsProvider trait 实现
+
+use async_trait::async_trait;
+
+use crate::error::{ProviderError, Result};
+use crate::providers::common::{
+    decode_rrset_record_id, encode_rrset_record_id, ensure_not_proxied,
+    ensure_record_type_supported, format_quoted_txt, full_name_to_relative, normalize_domain_name,
+    parse_ds_value, parse_quoted_txt, parse_sshfp_value, parse_tlsa_value, record_type_to_string,
+    relative_to_full_name, validate_record_data,
+};
+use crate::traits::{DnsProvider, ErrorContext, ProviderErrorMapper};
+use crate::types::{
+    CreateDnsRecordRequest, DnsRecord, DomainStatus, FieldType, PaginatedResponse,
+    PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures, ProviderLimits,
+    ProviderMetadata, ProviderType, RecordData, RecordQueryParams, RoutingPolicy,
+    UpdateDnsRecordRequest,
+};
+
+use super::types::{
+    Change, ChangeBatch, ChangeResourceRecordSetsRequest, ChangeResourceRecordSetsResponse,
+    Changes, GeoLocationBody, GetHostedZoneCountResponse, GetHostedZoneResponse,
+    ListHostedZonesResponse, ListResourceRecordSetsResponse, ResourceRecordBody, ResourceRecordSet,
+    ResourceRecordSetBody, ResourceRecordsBody,
+};
+use super::{
+    MAX_PAGE_SIZE_RECORDS, MAX_PAGE_SIZE_ZONES, ROUTE53_API_VERSION, Route53Provider,
+    UNSUPPORTED_RECORD_TYPES,
+};
+
+/// Route 53 变更请求的 XML 命名空间
+const ROUTE53_XMLNS: &str = "https://route53.amazonaws.com/doc/2013-04-01/";
+
+/// 记录集中的单个值（记录 ID 的解码结果）
+struct RecordKey {
+    /// 完整名称（不带末尾点）
+    name: String,
+    record_type: String,
+    /// Route 53 格式的原始值
+    value: String,
+    /// 策略记录集的标识（简单记录集为 `None`）
+    set_identifier: Option<String>,
+}
+
+impl Route53Provider {
+    /// 去掉托管区域 ID 的 `/hostedzone/` 前缀
+    fn zone_id(id: &str) -> String {
+        id.trim_start_matches("/hostedzone/").to_string()
+    }
+
+    /// 解码 Route 53 名称中的八进制转义（如 `\052` -> `*`）
+    fn decode_name(name: &str) -> String {
+        let bytes = name.as_bytes();
+        let mut out = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == b'\\'
+                && i + 3 < bytes.len()
+                && bytes[i + 1..i + 4].iter().all(u8::is_ascii_digit)
+                && let Ok(code) = u8::from_str_radix(&name[i + 1..i + 4], 8)
+            {
+                out.push(code);
+                i += 4;
+                continue;
+            }
+            out.push(bytes[i]);
+            i += 1;
+        }
+        normalize_domain_name(&String::from_utf8_lossy(&out)).to_lowercase()
+    }
+
+    /// 解码记录 ID
+    fn decode_record_id(&self, record_id: &str) -> Result<RecordKey> {
+        let (name, record_type, value) =
+            decode_rrset_record_id(record_id).ok_or_else(|| ProviderError::InvalidParameter {
+                provider: self.provider_name().to_string(),
+                param: "record_id".to_string(),
+                detail: format!("Invalid Route 53 record id: '{record_id}'"),
+            })?;
+        let (value, set_identifier) = match value.split_once('\n') {
+            Some((value, identifier)) => (value.to_string(), Some(identifier.to_string())),
+            None => (value, None),
+        };
+        Ok(RecordKey {
+            name,
+            record_type,
+            value,
+            set_identifier,
+        })
+    }
+
+    /// 编码记录 ID
+    ///
+    /// 策略记录集在值之后追加 `\n` 与记录集标识（Route 53 的记录值不含换行）。
+    fn record_id(
+        full_name: &str,
+        record_type: &str,
+        value: &str,
+        set_identifier: Option<&str>,
+    ) -> String {
+        match set_identifier {
+            Some(identifier) => {
+                encode_rrset_record_id(full_name, record_type, &format!("{value}\n{identifier}"))
+            }
+            None => encode_rrset_record_id(full_name, record_type, value),
+        }
+    }
+
+    /// 记录集的路由策略
+    ///
+    /// 简单记录集，以及 `RoutingPolicy` 无法表示的策略（故障转移、多值应答、
+    /// 州/省级地理位置等）返回 `None`。
+    fn rrset_routing_policy(rrset: &ResourceRecordSet) -> Option<RoutingPolicy> {
+        rrset.set_identifier.as_ref()?;
+        if let Some(weight) = rrset.weight {
+            return Some(RoutingPolicy::Weighted { weight });
+        }
+        if let Some(region) = &rrset.region {
+            return Some(RoutingPolicy::Latency {
+                region: region.clone(),
+            });
+        }
+        match &rrset.geo_location {
+            Some(geo) if geo.subdivision_code.is_none() => Some(RoutingPolicy::Geo {
+                continent: geo.continent_code.clone(),
+                country: geo.country_code.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// 解析 Route 53 记录值为 RecordData
+    fn parse_record_data(&self, record_type: &str, value: &str) -> Result<RecordData> {
+        let parse_error = |detail: String| ProviderError::ParseError {
+            provider: self.provider_name().to_string(),
+            detail,
+        };
+
+        match record_type {
+            "A" => Ok(RecordData::A {
+                address: value.to_string(),
+            }),
+            "AAAA" => Ok(RecordData::AAAA {
+                address: value.to_string(),
+            }),
+            "CNAME" => Ok(RecordData::CNAME {
+                target: normalize_domain_name(value),
+            }),
+            "NS" => Ok(RecordData::NS {
+                nameserver: normalize_domain_name(value),
+            }),
+            "TXT" => Ok(RecordData::TXT {
+                text: parse_quoted_txt(value),
+            }),
+            "MX" => {
+                // Route 53 MX 格式: "priority exchange"
+                let parts: Vec<&str> = value.splitn(2, ' ').collect();
+                if parts.len() == 2 {
+                    Ok(RecordData::MX {
+                        priority: parts[0].parse().map_err(|_| {
+                            parse_error(format!("Invalid MX priority: '{}'", parts[0]))
+                        })?,
+                        exchange: normalize_domain_name(parts[1]),
+                    })
+                } else {
+                    Err(parse_error(format!(
+                        "Invalid MX record format: expected 'priority exchange', got '{value}'"
+                    )))
+                }
+            }
+            "SRV" => {
+                // Route 53 SRV 格式: "priority weight port target"
+                let parts: Vec<&str> = value.splitn(4, ' ').collect();
+                if parts.len() == 4 {
+                    Ok(RecordData::SRV {
+                        priority: parts[0].parse().map_err(|_| {
+                            parse_error(format!("Invalid SRV priority: '{}'", parts[0]))
+                        })?,
+                        weight: parts[1].parse().map_err(|_| {
+                            parse_error(format!("Invalid SRV weight: '{}'", parts[1]))
+                        })?,
+                        port: parts[2].parse().map_err(|_| {
+                            parse_error(format!("Invalid SRV port: '{}'", parts[2]))
+                        })?,
+                        target: normalize_domain_name(parts[3]),
+                    })
+                } else {
+                    Err(parse_error(format!(
+                        "Invalid SRV record format: expected 'priority weight port target', got '{value}'"
+                    )))
+                }
+            }
+            "CAA" => {
+                // Route 53 CAA 格式: flags tag "value"
+                let parts: Vec<&str> = value.splitn(3, ' ').collect();
+                if parts.len() == 3 {
+                    Ok(RecordData::CAA {
+                        flags: parts[0].parse().map_err(|_| {
+                            parse_error(format!("Invalid CAA flags: '{}'", parts[0]))
+                        })?,
+                        tag: parts[1].to_string(),
+                        value: parts[2].trim_matches('"').to_string(),
+                    })
+                } else {
+                    Err(parse_error(format!(
+                        "Invalid CAA record format: expected 'flags tag value', got '{value}'"
+                    )))
+                }
+            }
+            "TLSA" => parse_tlsa_value(value, self.provider_name()),
+            "SSHFP" => parse_sshfp_value(value, self.provider_name()),
+            "DS" => parse_ds_value(value, self.provider_name()),
+            _ => Err(ProviderError::UnsupportedRecordType {
+                provider: self.provider_name().to_string(),
+                record_type: record_type.to_string(),
+            }),
+        }
+    }
+
+    /// 将 RecordData 转换为 Route 53 记录值
+    fn record_data_to_value(data: &RecordData) -> String {
+        match data {
+            RecordData::A { address } | RecordData::AAAA { address } => address.clone(),
+            RecordData::CNAME { target } | RecordData::ALIAS { target } => target.clone(),
+            RecordData::MX { priority, exchange } => format!("{priority} {exchange}"),
+            RecordData::TXT { text } => format_quoted_txt(text),
+            RecordData::NS { nameserver } => nameserver.clone(),
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => format!("{priority} {weight} {port} {target}"),
+            RecordData::CAA { flags, tag, value } => format!("{flags} {tag} \"{value}\""),
+            RecordData::TLSA { .. } | RecordData::SSHFP { .. } | RecordData::DS { .. } => {
+                data.display_value()
+            }
+        }
+    }
+
+    /// 获取全部托管区域（按 marker 翻页）
+    async fn list_all_hosted_zones(&self) -> Result<Vec<super::types::HostedZone>> {
+        let mut zones = Vec::new();
+        let mut marker: Option<String> = None;
+
+        loop {
+            let response = self
+                .list_hosted_zones_page(marker.as_deref(), MAX_PAGE_SIZE_ZONES)
+                .await?;
+            zones.extend(response.hosted_zones.items);
+
+            match response.next_marker {
+                Some(next) if response.is_truncated => marker = Some(next),
+                _ => break,
+            }
+        }
+
+        Ok(zones)
+    }
+
+    /// 获取账号下的托管区域总数
+    async fn hosted_zone_count(&self) -> Result<u32> {
+        let path = format!("/{ROUTE53_API_VERSION}/hostedzonecount");
+        let response: GetHostedZoneCountResponse =
+            self.get(&path, "", ErrorContext::default()).await?;
+        Ok(response.hosted_zone_count)
+    }
+
+    /// 获取单页托管区域（marker 为空表示从头开始）
+    async fn list_hosted_zones_page(
+        &self,
+        marker: Option<&str>,
+        max_items: u32,
+    ) -> Result<ListHostedZonesResponse> {
+        let path = format!("/{ROUTE53_API_VERSION}/hostedzone");
+        let mut query = format!("maxitems={max_items}");
+        if let Some(m) = marker.filter(|m| !m.is_empty()) {
+            query = format!("marker={}&{query}", urlencoding::encode(m));
+        }
+
+        self.get(&path, &query, ErrorContext::default()).await
+    }
+
+    /// 获取托管区域中的全部记录集（按 name/type/identifier 翻页）
+    async fn list_all_record_sets(&self, zone_id: &str) -> Result<Vec<ResourceRecordSet>> {
+        let path = format!("/{ROUTE53_API_VERSION}/hostedzone/{zone_id}/rrset");
+        let ctx = ErrorContext {
+            domain: Some(zone_id.to_string()),
+            ..Default::default()
+        };
+
+        let mut record_sets = Vec::new();
+        let mut next: Option<(String, Option<String>, Option<String>)> = None;
+
+        loop {
+            let mut params = vec![format!("maxitems={MAX_PAGE_SIZE_RECORDS}")];
+            if let Some((ref name, ref record_type, ref identifier)) = next {
+                params.push(format!("name={}", urlencoding::encode(name)));
+                if let Some(t) = record_type {
+                    params.push(format!("type={}", urlencoding::encode(t)));
+                }
+                if let Some(id) = identifier {
+                    params.push(format!("identifier={}", urlencoding::encode(id)));
+                }
+            }
+            params.sort();
+            let query = params.join("&");
+
+            let response: ListResourceRecordSetsResponse =
+                self.get(&path, &query, ctx.clone()).await?;
+            record_sets.extend(response.resource_record_sets.items);
+
+            match response.next_record_name {
+                Some(name) if response.is_truncated => {
+                    next = Some((
+                        name,
+                        response.next_record_type,
+                        response.next_record_identifier,
+                    ));
+                }
+                _ => break,
+            }
+        }
+
+        Ok(record_sets)
+    }
+
+    /// 查找指定名称与类型的记录集
+    ///
+    /// `set_identifier` 为 `None` 时查找简单记录集（不含路由策略），否则查找对应的策略记录集。
+    async fn find_record_set(
+        &self,
+        zone_id: &str,
+        full_name: &str,
+        record_type: &str,
+        set_identifier: Option<&str>,
+    ) -> Result<Option<ResourceRecordSet>> {
+        let path = format!("/{ROUTE53_API_VERSION}/hostedzone/{zone_id}/rrset");
+        let mut query = format!(
+            "maxitems=1&name={}&type={}",
+            urlencoding::encode(full_name),
+            urlencoding::encode(record_type)
+        );
+        if let Some(identifier) = set_identifier {
+            query.push_str(&format!("&identifier={}", urlencoding::encode(identifier)));
+        }
+        let ctx = ErrorContext {
+            domain: Some(zone_id.to_string()),
+            ..Default::default()
+        };
+
+        let response: ListResourceRecordSetsResponse = self.get(&path, &query, ctx).await?;
+        Ok(response
+            .resource_record_sets
+            .items
+            .into_iter()
+            .find(|rrset| {
+                Self::decode_name(&rrset.name) == full_name.to_lowercase()
+                    && rrset.record_type == record_type
+                    && rrset.set_identifier.as_deref() == set_identifier
+                    && rrset.alias_target.is_none()
+            }))
+    }
+
+    /// 提取记录集中的全部值
+    fn record_set_values(rrset: &ResourceRecordSet) -> Vec<String> {
+        rrset
+            .resource_records
+            .as_ref()
+            .map(|r| r.items.iter().map(|v| v.value.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    /// 构造单个变更
+    fn change(
+        action: &'static str,
+        name: &str,
+        record_type: &str,
+        ttl: u32,
+        values: Vec<String>,
+    ) -> Change {
+        Change {
+            action,
+            resource_record_set: ResourceRecordSetBody {
+                name: format!("{name}."),
+                record_type: record_type.to_string(),
+                set_identifier: None,
+                weight: None,
+                region: None,
+                geo_location: None,
+                ttl,
+                resource_records: ResourceRecordsBody {
+                    items: values
+                        .into_iter()
+                        .map(|value| ResourceRecordBody { value })
+                        .collect(),
+                },
+            },
+        }
+    }
+
+    /// 路由策略对应的记录集标识
+    ///
+    /// 由策略参数生成，策略相同的记录归入同一个记录集（如权重相同的加权记录），
+    /// 简单路由返回 `None`。
+    fn routing_set_identifier(&self, policy: Option<&RoutingPolicy>) -> Result<Option<String>> {
+        let invalid = |detail: &str| ProviderError::InvalidParameter {
+            provider: self.provider_name().to_string(),
+            param: "routing_policy".to_string(),
+            detail: detail.to_string(),
+        };
+        let identifier = match policy {
+            None | Some(RoutingPolicy::Simple) => return Ok(None),
+            Some(RoutingPolicy::Weighted { weight }) => format!("weighted-{weight}"),
+            Some(RoutingPolicy::Latency { region }) if region.is_empty() => {
+                return Err(invalid("Latency routing requires a region"));
+            }
+            Some(RoutingPolicy::Latency { region }) => format!("latency-{region}"),
+            Some(RoutingPolicy::Geo {
+                continent: Some(continent),
+                country: None,
+            }) => format!("geo-continent-{continent}"),
+            Some(RoutingPolicy::Geo {
+                continent: None,
+                country: Some(country),
+            }) => format!("geo-country-{country}"),
+            Some(RoutingPolicy::Geo { .. }) => {
+                return Err(invalid(
+                    "Geo routing requires exactly one of continent or country",
+                ));
+            }
+        };
+        Ok(Some(identifier))
+    }
+
+    /// 将路由策略写入记录集变更
+    fn apply_routing_policy(
+        rrset: &mut ResourceRecordSetBody,
+        policy: Option<&RoutingPolicy>,
+        set_identifier: Option<String>,
+    ) {
+        rrset.set_identifier = set_identifier;
+        match policy {
+            Some(RoutingPolicy::Weighted { weight }) => rrset.weight = Some(*weight),
+            Some(RoutingPolicy::Latency { region }) => rrset.region = Some(region.clone()),
+            Some(RoutingPolicy::Geo { continent, country }) => {
+                rrset.geo_location = Some(GeoLocationBody {
+                    continent_code: continent.clone(),
+                    country_code: country.clone(),
+                });
+            }
+            None | Some(RoutingPolicy::Simple) => {}
+        }
+    }
+
+    /// 从记录集中移除一个值：集合为空时删除，否则写回剩余值（保留原有路由策略）
+    fn remove_value_change(rrset: &ResourceRecordSet, name: &str, value: &str) -> Change {
+        let values = Self::record_set_values(rrset);
+        let ttl = rrset.ttl.unwrap_or(300);
+        let remaining: Vec<String> = values.iter().filter(|v| *v != value).cloned().collect();
+
+        let mut change = if remaining.is_empty() {
+            Self::change("DELETE", name, &rrset.record_type, ttl, values)
+        } else {
+            Self::change("UPSERT", name, &rrset.record_type, ttl, remaining)
+        };
+        Self::apply_routing_policy(
+            &mut change.resource_record_set,
+            Self::rrset_routing_policy(rrset).as_ref(),
+            rrset.set_identifier.clone(),
+        );
+        change
+    }
+
+    /// 提交变更批次
+    async fn change_record_sets(
+        &self,
+        zone_id: &str,
+        changes: Vec<Change>,
+        ctx: ErrorContext,
+    ) -> Result<()> {
+        let body = ChangeResourceRecordSetsRequest {
+            xmlns: ROUTE53_XMLNS,
+            change_batch: ChangeBatch {
+                changes: Changes { items: changes },
+            },
+        };
+        let path = format!("/{ROUTE53_API_VERSION}/hostedzone/{zone_id}/rrset/");
+        let _response: ChangeResourceRecordSetsResponse = self.post_xml(&path, &body, ctx).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DnsProvider for Route53Provider {
+    fn id(&self) -> &'static str {
+        "route53"
+    }
+
+    fn batch_concurrency(&self) -> usize {
+        self.batch_concurrency
+    }
+
+    fn metadata() -> ProviderMetadata {
+        ProviderMetadata {
+            id: ProviderType::Route53,
+            name: "Amazon Route 53".to_string(),
+            description: "AWS Route 53 DNS 服务".to_string(),
+            required_fields: vec![
+                ProviderCredentialField {
+                    key: "accessKeyId".to_string(),
+                    label: "Access Key ID".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("输入 Access Key ID".to_string()),
+                    help_text: None,
+                },
+                ProviderCredentialField {
+                    key: "secretAccessKey".to_string(),
+                    label: "Secret Access Key".to_string(),
+                    field_type: FieldType::Password,
+                    placeholder: Some("输入 Secret Access Key".to_string()),
+                    help_text: None,
+                },
+                ProviderCredentialField {
+                    key: "region".to_string(),
+                    label: "Region".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("us-east-1".to_string()),
+                    help_text: Some(
+                        "Route 53 为全局服务，中国区账号请填写 cn-north-1 或 cn-northwest-1"
+                            .to_string(),
+                    ),
+                },
+            ],
+            features: ProviderFeatures {
+                routing_policy: true,
+                ..ProviderFeatures::default()
+            },
+            limits: ProviderLimits {
+                max_page_size_domains: MAX_PAGE_SIZE_ZONES,
+                max_page_size_records: MAX_PAGE_SIZE_RECORDS,
+                requests_per_second: Some(5.0),
+                max_batch_size: 1,
+                max_records_per_zone: Some(10_000),
+                min_ttl: 0,
+                max_ttl: 2_147_483_647,
+                auto_ttl: None,
+            },
+        }
+    }
+
+    async fn validate_credentials(&self) -> Result<bool> {
+        let path = format!("/{ROUTE53_API_VERSION}/hostedzone");
+        match self
+            .get::<ListHostedZonesResponse>(&path, "maxitems=1", ErrorContext::default())
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(ProviderError::InvalidCredentials { .. }) => Ok(false),
+            Err(e) => {
+                log::warn!("凭证验证失败: {e}");
+                Ok(false)
+            }
+        }
+    }
+
+    /// 传入 `cursor` 时直接使用 Route 53 的 marker 翻页（空字符串表示第一页）；
+    /// 否则拉取全部托管区域后在内存中按页码分页。两种方式都返回下一页的游标。
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> Result<PaginatedResponse<ProviderDomain>> {
+        let to_domain = |z: super::types::HostedZone| ProviderDomain {
+            id: Self::zone_id(&z.id),
+            name: Self::decode_name(&z.name),
+            provider: ProviderType::Route53,
+            status: DomainStatus::Active,
+            record_count: z.resource_record_set_count,
+        };
+
+        // 游标模式：直接使用 Route 53 的 marker 翻页，不拉取全部托管区域
+        if let Some(cursor) = params.cursor.as_deref() {
+            let page_size = params.page_size.clamp(1, MAX_PAGE_SIZE_ZONES);
+            let response = self.list_hosted_zones_page(Some(cursor), page_size).await?;
+            let next_cursor = response.next_marker.filter(|_| response.is_truncated);
+            let domains: Vec<ProviderDomain> = response
+                .hosted_zones
+                .items
+                .into_iter()
+                .map(to_domain)
+                .collect();
+            let total_count = self.hosted_zone_count().await?;
+
+            return Ok(
+                PaginatedResponse::new(domains, params.page, page_size, total_count)
+                    .with_next_cursor(next_cursor),
+            );
+        }
+
+        let zones = self.list_all_hosted_zones().await?;
+        let total_count = zones.len() as u32;
+        let offset = ((params.page.max(1) - 1) * params.page_size) as usize;
+
+        // Route 53 的 marker 即下一页第一个托管区域的 ID
+        let next_cursor = zones
+            .get(offset + params.page_size as usize)
+            .map(|z| Self::zone_id(&z.id));
+        let domains = zones
+            .into_iter()
+            .skip(offset)
+            .take(params.page_size as usize)
+            .map(to_domain)
+            .collect();
+
+        Ok(
+            PaginatedResponse::new(domains, params.page, params.page_size, total_count)
+                .with_next_cursor(next_cursor),
+        )
+    }
+
+    async fn get_domain(&self, domain_id: &str) -> Result<ProviderDomain> {
+        let path = format!("/{ROUTE53_API_VERSION}/hostedzone/{domain_id}");
+        let ctx = ErrorContext {
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let response: GetHostedZoneResponse = self.get(&path, "", ctx).await?;
+
+        Ok(ProviderDomain {
+            id: Self::zone_id(&response.hosted_zone.id),
+            name: Self::decode_name(&response.hosted_zone.name),
+            provider: ProviderType::Route53,
+            status: DomainStatus::Active,
+            record_count: response.hosted_zone.resource_record_set_count,
+        })
+    }
+
+    /// 记录集按值展开后在内存中过滤与分页
+    ///
+    /// 不支持游标分页：`params.cursor` 被忽略，始终按页码分页且不返回 `next_cursor`。
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        let domain_info = self.get_domain(domain_id).await?;
+        let record_sets = self.list_all_record_sets(domain_id).await?;
+
+        let keyword = params
+            .keyword
+            .as_ref()
+            .filter(|k| !k.is_empty())
+            .map(|k| k.to_lowercase());
+        let type_filter = params.record_type.as_ref().map(record_type_to_string);
+
+        let mut records = Vec::new();
+        for rrset in &record_sets {
+            // 跳过 SOA、别名记录和无法表示的路由策略记录集
+            let routing_policy = Self::rrset_routing_policy(rrset);
+            if rrset.record_type == "SOA"
+                || rrset.alias_target.is_some()
+                || (rrset.set_identifier.is_some() && routing_policy.is_none())
+            {
+                continue;
+            }
+            if type_filter.is_some_and(|t| t != rrset.record_type) {
+                continue;
+            }
+
+            let full_name = Self::decode_name(&rrset.name);
+            let name = full_name_to_relative(&full_name, &domain_info.name);
+
+            for value in Self::record_set_values(rrset) {
+                let Ok(data) = self.parse_record_data(&rrset.record_type, &value) else {
+                    continue;
+                };
+
+                if let Some(ref kw) = keyword
+                    && !name.to_lowercase().contains(kw)
+                    && !data.display_value().to_lowercase().contains(kw)
+                {
+                    continue;
+                }
+
+                records.push(DnsRecord {
+                    id: Self::record_id(
+                        &full_name,
+                        &rrset.record_type,
+                        &value,
+                        rrset.set_identifier.as_deref(),
+                    ),
+                    domain_id: domain_id.to_string(),
+                    name: name.clone(),
+                    ttl: rrset.ttl.unwrap_or(300),
+                    data,
+                    proxied: None,
+                    comment: None,
+                    tags: Vec::new(),
+                    created_at: None,
+                    updated_at: None,
+                    routing_policy: routing_policy.clone(),
+                });
+            }
+        }
+
+        let total_count = records.len() as u32;
+        let records = records
+            .into_iter()
+            .skip(((params.page.max(1) - 1) * params.page_size) as usize)
+            .take(params.page_size as usize)
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            records,
+            params.page,
+            params.page_size,
+            total_count,
+        ))
+    }
+
+    /// 向记录集追加一个值（记录集不存在时创建），整个记录集的 TTL 会更新为请求值
+    ///
+    /// 带路由策略时写入对应的策略记录集，记录 ID 中包含记录集标识。
+    async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        ensure_record_type_supported(&req.data, UNSUPPORTED_RECORD_TYPES, self.provider_name())?;
+        ensure_not_proxied(req.proxied, self.provider_name())?;
+        self.validate_ttl(req.ttl)?;
+        validate_record_data(&req.data, self.provider_name())?;
+        let policy = req.routing_policy.as_ref();
+        let set_identifier = self.routing_set_identifier(policy)?;
+        let domain_info = self.get_domain(&req.domain_id).await?;
+        let full_name = relative_to_full_name(&req.name, &domain_info.name).to_lowercase();
+        let record_type = record_type_to_string(&req.data.record_type());
+        let value = Self::record_data_to_value(&req.data);
+
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            domain: Some(req.domain_id.clone()),
+            ..Default::default()
+        };
+
+        let mut change = match self
+            .find_record_set(
+                &req.domain_id,
+                &full_name,
+                record_type,
+                set_identifier.as_deref(),
+            )
+            .await?
+        {
+            Some(rrset) => {
+                let mut values = Self::record_set_values(&rrset);
+                if values.contains(&value) {
+                    return Err(ProviderError::RecordExists {
+                        provider: self.provider_name().to_string(),
+                        record_name: req.name.clone(),
+                        raw_message: None,
+                    });
+                }
+                values.push(value.clone());
+                Self::change("UPSERT", &full_name, record_type, req.ttl, values)
+            }
+            None => Self::change(
+                "CREATE",
+                &full_name,
+                record_type,
+                req.ttl,
+                vec![value.clone()],
+            ),
+        };
+        let id = Self::record_id(&full_name, record_type, &value, set_identifier.as_deref());
+        let routing_policy = set_identifier.as_ref().and(policy.cloned());
+        Self::apply_routing_policy(&mut change.resource_record_set, policy, set_identifier);
+
+        self.change_record_sets(&req.domain_id, vec![change], ctx)
+            .await?;
+
+        Ok(DnsRecord {
+            id,
+            domain_id: req.domain_id.clone(),
+            name: full_name_to_relative(&full_name, &domain_info.name),
+            ttl: req.ttl,
+            data: req.data.clone(),
+            proxied: None,
+            comment: None,
+            tags: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            routing_policy,
+        })
+    }
+
+    /// 在同一个变更批次中移除旧值并写入新值，保证原子性
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<DnsRecord> {
+        ensure_record_type_supported(&req.data, UNSUPPORTED_RECORD_TYPES, self.provider_name())?;
+        ensure_not_proxied(req.proxied, self.provider_name())?;
+        self.validate_ttl(req.ttl)?;
+        validate_record_data(&req.data, self.provider_name())?;
+        let old = self.decode_record_id(record_id)?;
+        let domain_info = self.get_domain(&req.domain_id).await?;
+        let full_name = relative_to_full_name(&req.name, &domain_info.name).to_lowercase();
+        let record_type = record_type_to_string(&req.data.record_type());
+        let value = Self::record_data_to_value(&req.data);
+
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            record_id: Some(record_id.to_string()),
+            domain: Some(req.domain_id.clone()),
+        };
+        let not_found = || ProviderError::RecordNotFound {
+            provider: self.provider_name().to_string(),
+            record_id: record_id.to_string(),
+            raw_message: None,
+        };
+
+        let set_identifier = old.set_identifier.as_deref();
+        let old_rrset = self
+            .find_record_set(&req.domain_id, &old.name, &old.record_type, set_identifier)
+            .await?
+            .ok_or_else(not_found)?;
+        let old_values = Self::record_set_values(&old_rrset);
+        if !old_values.contains(&old.value) {
+            return Err(not_found());
+        }
+        // 更新后的值保留原记录集的路由策略
+        let routing_policy = Self::rrset_routing_policy(&old_rrset);
+
+        let same_set = old.name == full_name && old.record_type == record_type;
+        let mut new_change = if same_set {
+            // 同一记录集内替换值
+            let mut values: Vec<String> = old_values
+                .into_iter()
+                .filter(|v| *v != old.value && *v != value)
+                .collect();
+            values.push(value.clone());
+            Self::change("UPSERT", &full_name, record_type, req.ttl, values)
+        } else {
+            // 名称或类型变化：追加到同一策略下的新记录集
+            let mut new_values = match self
+                .find_record_set(&req.domain_id, &full_name, record_type, set_identifier)
+                .await?
+            {
+                Some(rrset) => Self::record_set_values(&rrset),
+                None => Vec::new(),
+            };
+            if !new_values.contains(&value) {
+                new_values.push(value.clone());
+            }
+            Self::change("UPSERT", &full_name, record_type, req.ttl, new_values)
+        };
+        Self::apply_routing_policy(
+            &mut new_change.resource_record_set,
+            routing_policy.as_ref(),
+            old.set_identifier.clone(),
+        );
+        let changes = if same_set {
+            vec![new_change]
+        } else {
+            // 从旧记录集移除
+            vec![
+                Self::remove_value_change(&old_rrset, &old.name, &old.value),
+                new_change,
+            ]
+        };
+
+        self.change_record_sets(&req.domain_id, changes, ctx)
+            .await?;
+
+        Ok(DnsRecord {
+            id: Self::record_id(&full_name, record_type, &value, set_identifier),
+            domain_id: req.domain_id.clone(),
+            name: full_name_to_relative(&full_name, &domain_info.name),
+            ttl: req.ttl,
+            data: req.data.clone(),
+            proxied: None,
+            comment: None,
+            tags: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            routing_policy,
+        })
+    }
+
+    /// 使用原生 `UPSERT` 写入，语义与 trait 默认实现一致（更新同名同类型的第一条记录）
+    ///
+    /// 值已在记录集中时只更新 TTL；否则替换记录集的第一个值，其余值保持不变。
+    /// 记录集不存在时新建。
+    async fn upsert_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        ensure_record_type_supported(&req.data, UNSUPPORTED_RECORD_TYPES, self.provider_name())?;
+        ensure_not_proxied(req.proxied, self.provider_name())?;
+        self.validate_ttl(req.ttl)?;
+        validate_record_data(&req.data, self.provider_name())?;
+        let policy = req.routing_policy.as_ref();
+        let set_identifier = self.routing_set_identifier(policy)?;
+        let domain_info = self.get_domain(&req.domain_id).await?;
+        let full_name = relative_to_full_name(&req.name, &domain_info.name).to_lowercase();
+        let record_type = record_type_to_string(&req.data.record_type());
+        let value = Self::record_data_to_value(&req.data);
+
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            domain: Some(req.domain_id.clone()),
+            ..Default::default()
+        };
+        let mut values = self
+            .find_record_set(
+                &req.domain_id,
+                &full_name,
+                record_type,
+                set_identifier.as_deref(),
+            )
+            .await?
+            .map(|rrset| Self::record_set_values(&rrset))
+            .unwrap_or_default();
+        if !values.contains(&value) {
+            match values.first_mut() {
+                Some(first) => first.clone_from(&value),
+                None => values.push(value.clone()),
+            }
+        }
+        let mut change = Self::change("UPSERT", &full_name, record_type, req.ttl, values);
+        let id = Self::record_id(&full_name, record_type, &value, set_identifier.as_deref());
+        let routing_policy = set_identifier.as_ref().and(policy.cloned());
+        Self::apply_routing_policy(&mut change.resource_record_set, policy, set_identifier);
+        self.change_record_sets(&req.domain_id, vec![change], ctx)
+            .await?;
+
+        Ok(DnsRecord {
+            id,
+            domain_id: req.domain_id.clone(),
+            name: full_name_to_relative(&full_name, &domain_info.name),
+            ttl: req.ttl,
+            data: req.data.clone(),
+            proxied: None,
+            comment: None,
+            tags: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            routing_policy,
+        })
+    }
+
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
+        let key = self.decode_record_id(record_id)?;
+        let ctx = ErrorContext {
+            record_id: Some(record_id.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let not_found = || ProviderError::RecordNotFound {
+            provider: self.provider_name().to_string(),
+            record_id: record_id.to_string(),
+            raw_message: None,
+        };
+
+        let rrset = self
+            .find_record_set(
+                domain_id,
+                &key.name,
+                &key.record_type,
+                key.set_identifier.as_deref(),
+            )
+            .await?
+            .ok_or_else(not_found)?;
+        if !Self::record_set_values(&rrset).contains(&key.value) {
+            return Err(not_found());
+        }
+
+        let change = Self::remove_value_change(&rrset, &key.name, &key.value);
+        self.change_record_sets(domain_id, vec![change], ctx).await
+    }
+}
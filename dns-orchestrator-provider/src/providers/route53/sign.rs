@@ -0,0 +1,81 @@
+//! AWS Signature Version 4 签名
+
+use sha2::{Digest, Sha256};
+
+use crate::providers::common::hmac_sha256;
+
+use super::{ROUTE53_SERVICE, Route53Provider};
+
+impl Route53Provider {
+    /// 生成 AWS SigV4 Authorization 头
+    /// 参考: <https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_sigv-create-signed-request.html>
+    pub(crate) fn sign(
+        &self,
+        method: &str,
+        uri: &str,
+        query: &str,
+        headers: &[(String, String)],
+        payload: &str,
+        amz_date: &str,
+    ) -> String {
+        let (_, region) = self.endpoint();
+        let date = &amz_date[..8];
+
+        // 1. Query String 排序（参数值已在构造时编码）
+        let canonical_query = if query.is_empty() {
+            String::new()
+        } else {
+            let mut params: Vec<&str> = query.split('&').collect();
+            params.sort_unstable();
+            params.join("&")
+        };
+
+        // 2. 构造规范请求头
+        let mut sorted_headers: Vec<_> = headers.iter().collect();
+        sorted_headers.sort_by_key(|(k, _)| k.to_lowercase());
+
+        let canonical_headers: String = sorted_headers
+            .iter()
+            .map(|(k, v)| format!("{}:{}\n", k.to_lowercase(), v.trim()))
+            .collect();
+
+        let signed_headers: String = sorted_headers
+            .iter()
+            .map(|(k, _)| k.to_lowercase())
+            .collect::<Vec<_>>()
+            .join(";");
+
+        // 3. 构造规范请求
+        let hashed_payload = hex::encode(Sha256::digest(payload.as_bytes()));
+        let canonical_request = format!(
+            "{method}\n{uri}\n{canonical_query}\n{canonical_headers}\n{signed_headers}\n{hashed_payload}"
+        );
+
+        log::debug!("CanonicalRequest:\n{canonical_request}");
+
+        // 4. 构造待签名字符串
+        let credential_scope = format!("{date}/{region}/{ROUTE53_SERVICE}/aws4_request");
+        let hashed_canonical_request = hex::encode(Sha256::digest(canonical_request.as_bytes()));
+        let string_to_sign =
+            format!("AWS4-HMAC-SHA256\n{amz_date}\n{credential_scope}\n{hashed_canonical_request}");
+
+        log::debug!("StringToSign:\n{string_to_sign}");
+
+        // 5. 派生签名密钥
+        let secret_date = hmac_sha256(
+            format!("AWS4{}", self.secret_access_key).as_bytes(),
+            date.as_bytes(),
+        );
+        let secret_region = hmac_sha256(&secret_date, region.as_bytes());
+        let secret_service = hmac_sha256(&secret_region, ROUTE53_SERVICE.as_bytes());
+        let secret_signing = hmac_sha256(&secret_service, b"aws4_request");
+
+        // 6. 计算签名
+        let signature = hex::encode(hmac_sha256(&secret_signing, string_to_sign.as_bytes()));
+
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{credential_scope}, SignedHeaders={signed_headers}, Signature={signature}",
+            self.access_key_id
+        )
+    }
+}
@@ -0,0 +1,216 @@
+//! Route 53 API 类型定义（XML）
+
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
+
+// ============ Route 53 API 响应结构 ============
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListHostedZonesResponse {
+    pub hosted_zones: HostedZones,
+    pub is_truncated: bool,
+    pub next_marker: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HostedZones {
+    #[serde(rename = "HostedZone", default)]
+    pub items: Vec<HostedZone>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HostedZone {
+    /// 形如 `/hostedzone/Z123456`
+    pub id: String,
+    pub name: String,
+    pub resource_record_set_count: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetHostedZoneResponse {
+    pub hosted_zone: HostedZone,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetHostedZoneCountResponse {
+    pub hosted_zone_count: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListResourceRecordSetsResponse {
+    pub resource_record_sets: ResourceRecordSets,
+    pub is_truncated: bool,
+    pub next_record_name: Option<String>,
+    pub next_record_type: Option<String>,
+    pub next_record_identifier: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResourceRecordSets {
+    #[serde(rename = "ResourceRecordSet", default)]
+    pub items: Vec<ResourceRecordSet>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ResourceRecordSet {
+    pub name: String,
+    #[serde(rename = "Type")]
+    pub record_type: String,
+    #[serde(rename = "TTL")]
+    pub ttl: Option<u32>,
+    pub resource_records: Option<ResourceRecords>,
+    /// 路由策略标识（加权/延迟/地理等）
+    pub set_identifier: Option<String>,
+    /// 加权路由的权重
+    pub weight: Option<u8>,
+    /// 延迟路由的区域
+    pub region: Option<String>,
+    /// 地理位置路由的位置
+    pub geo_location: Option<GeoLocation>,
+    /// 别名记录目标
+    pub alias_target: Option<IgnoredAny>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GeoLocation {
+    pub continent_code: Option<String>,
+    pub country_code: Option<String>,
+    /// 州/省代码（不在 `RoutingPolicy::Geo` 的表示范围内）
+    pub subdivision_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResourceRecords {
+    #[serde(rename = "ResourceRecord", default)]
+    pub items: Vec<ResourceRecord>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ResourceRecord {
+    pub value: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ChangeResourceRecordSetsResponse {
+    #[allow(dead_code)]
+    pub change_info: ChangeInfo,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ChangeInfo {
+    #[allow(dead_code)]
+    pub id: String,
+    #[allow(dead_code)]
+    pub status: String,
+}
+
+/// 通用错误响应: `<ErrorResponse><Error><Code/><Message/></Error></ErrorResponse>`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ErrorResponse {
+    pub error: ErrorDetail,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ErrorDetail {
+    pub code: Option<String>,
+    pub message: Option<String>,
+}
+
+/// 变更批次错误: `<InvalidChangeBatch><Messages><Message/></Messages></InvalidChangeBatch>`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct InvalidChangeBatchResponse {
+    pub messages: Option<InvalidChangeBatchMessages>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InvalidChangeBatchMessages {
+    #[serde(rename = "Message", default)]
+    pub items: Vec<String>,
+}
+
+// ============ Route 53 API 请求结构 ============
+
+#[derive(Debug, Serialize)]
+#[serde(rename = "ChangeResourceRecordSetsRequest")]
+pub struct ChangeResourceRecordSetsRequest {
+    #[serde(rename = "@xmlns")]
+    pub xmlns: &'static str,
+    #[serde(rename = "ChangeBatch")]
+    pub change_batch: ChangeBatch,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChangeBatch {
+    #[serde(rename = "Changes")]
+    pub changes: Changes,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Changes {
+    #[serde(rename = "Change")]
+    pub items: Vec<Change>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Change {
+    /// CREATE | DELETE | UPSERT
+    #[serde(rename = "Action")]
+    pub action: &'static str,
+    #[serde(rename = "ResourceRecordSet")]
+    pub resource_record_set: ResourceRecordSetBody,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ResourceRecordSetBody {
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Type")]
+    pub record_type: String,
+    /// 路由策略标识（Route 53 要求下列字段按 XML schema 顺序出现在 TTL 之前）
+    #[serde(rename = "SetIdentifier", skip_serializing_if = "Option::is_none")]
+    pub set_identifier: Option<String>,
+    #[serde(rename = "Weight", skip_serializing_if = "Option::is_none")]
+    pub weight: Option<u8>,
+    #[serde(rename = "Region", skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(rename = "GeoLocation", skip_serializing_if = "Option::is_none")]
+    pub geo_location: Option<GeoLocationBody>,
+    #[serde(rename = "TTL")]
+    pub ttl: u32,
+    #[serde(rename = "ResourceRecords")]
+    pub resource_records: ResourceRecordsBody,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GeoLocationBody {
+    #[serde(rename = "ContinentCode", skip_serializing_if = "Option::is_none")]
+    pub continent_code: Option<String>,
+    #[serde(rename = "CountryCode", skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ResourceRecordsBody {
+    #[serde(rename = "ResourceRecord")]
+    pub items: Vec<ResourceRecordBody>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ResourceRecordBody {
+    #[serde(rename = "Value")]
+    pub value: String,
+}
@@ -0,0 +1,130 @@
+//! 期望状态与实际状态的差异计算
+//!
+//! # 记录匹配规则
+//! 1. 记录按「名称 + 类型」分组，名称经 `canonical_record_name` 规范化
+//!    （不区分大小写，空名称、`@` 与区域名本身视为 `@`，完整域名转为相对名称）
+//! 2. 组内按「值」精确匹配：值取 `RecordData::display_value()`，
+//!    即 MX/SRV 取目标主机、CAA 取 value、其余取记录内容；优先级、权重等不参与匹配
+//! 3. 值匹配的记录：数据、TTL 与 `proxied`（期望值为 `Some` 时）全部一致则不变，否则原地更新；
+//!    Provider 支持记录备注与标签时，`comment` 与 `tags`（不计顺序）也参与比较
+//! 4. 组内剩余未匹配的期望记录与实际记录按顺序两两配对为更新，
+//!    多出的期望记录新建，多出的实际记录删除
+//! 5. 根域 NS 记录通常由服务商托管：期望状态中没有根域 NS 时，不会删除已有的根域 NS
+
+use crate::providers::common::canonical_record_name;
+use crate::types::{
+    BatchUpdateItem, CreateDnsRecordRequest, DnsRecord, DnsRecordType, ProviderFeatures, SyncPlan,
+    UpdateDnsRecordRequest,
+};
+
+/// 备注与标签是否一致（空备注视同未设置，标签不计顺序）
+fn same_metadata(desired: &CreateDnsRecordRequest, existing: &DnsRecord) -> bool {
+    let mut want_tags = desired.tags.clone();
+    let mut have_tags = existing.tags.clone();
+    want_tags.sort_unstable();
+    have_tags.sort_unstable();
+    desired.comment.as_deref().unwrap_or_default()
+        == existing.comment.as_deref().unwrap_or_default()
+        && want_tags == have_tags
+}
+
+/// 期望记录与实际记录是否完全一致
+fn is_unchanged(
+    desired: &CreateDnsRecordRequest,
+    existing: &DnsRecord,
+    features: &ProviderFeatures,
+) -> bool {
+    desired.data == existing.data
+        && desired.ttl == existing.ttl
+        && desired.proxied.is_none_or(|p| existing.proxied == Some(p))
+        && (!features.record_metadata || same_metadata(desired, existing))
+}
+
+fn to_update(
+    domain_id: &str,
+    existing: &DnsRecord,
+    desired: &CreateDnsRecordRequest,
+) -> BatchUpdateItem {
+    BatchUpdateItem {
+        record_id: existing.id.clone(),
+        request: UpdateDnsRecordRequest {
+            domain_id: domain_id.to_string(),
+            name: desired.name.clone(),
+            ttl: desired.ttl,
+            data: desired.data.clone(),
+            proxied: desired.proxied,
+            comment: desired.comment.clone(),
+            tags: desired.tags.clone(),
+        },
+    }
+}
+
+/// 计算同步计划
+pub(crate) fn compute_sync_plan(
+    domain_id: &str,
+    zone_name: &str,
+    existing: Vec<DnsRecord>,
+    desired: &[CreateDnsRecordRequest],
+    features: &ProviderFeatures,
+) -> SyncPlan {
+    let canonical_name = |name: &str| canonical_record_name(name, zone_name);
+    let mut plan = SyncPlan {
+        domain_id: domain_id.to_string(),
+        creates: Vec::new(),
+        updates: Vec::new(),
+        deletes: Vec::new(),
+        unchanged: Vec::new(),
+    };
+
+    let keep_apex_ns = !desired
+        .iter()
+        .any(|d| canonical_name(&d.name) == "@" && d.data.record_type() == DnsRecordType::Ns);
+    let mut remaining: Vec<DnsRecord> = existing
+        .into_iter()
+        .filter(|r| {
+            !(keep_apex_ns
+                && canonical_name(&r.name) == "@"
+                && r.data.record_type() == DnsRecordType::Ns)
+        })
+        .collect();
+    let same_group = |d: &CreateDnsRecordRequest, r: &DnsRecord| {
+        canonical_name(&d.name) == canonical_name(&r.name)
+            && d.data.record_type() == r.data.record_type()
+    };
+
+    // 第一轮：按值匹配
+    let mut unmatched = Vec::new();
+    for want in desired {
+        let matched = remaining.iter().position(|r| {
+            same_group(want, r) && r.data.display_value() == want.data.display_value()
+        });
+        match matched {
+            Some(index) => {
+                let record = remaining.remove(index);
+                if is_unchanged(want, &record, features) {
+                    plan.unchanged.push(record);
+                } else {
+                    plan.updates.push(to_update(domain_id, &record, want));
+                }
+            }
+            None => unmatched.push(want),
+        }
+    }
+
+    // 第二轮：同组内剩余记录配对更新，其余新建
+    for want in unmatched {
+        match remaining.iter().position(|r| same_group(want, r)) {
+            Some(index) => {
+                let record = remaining.remove(index);
+                plan.updates.push(to_update(domain_id, &record, want));
+            }
+            None => plan.creates.push(CreateDnsRecordRequest {
+                domain_id: domain_id.to_string(),
+                ..want.clone()
+            }),
+        }
+    }
+
+    plan.deletes = remaining;
+    plan
+}
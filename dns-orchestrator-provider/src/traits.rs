@@ -1,12 +1,27 @@
+use std::time::Instant;
+
 use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
+use tokio_util::sync::CancellationToken;
 
 use crate::error::{ProviderError, Result};
+use crate::http_client::HttpUtils;
+use crate::providers::common::ensure_simple_routing;
+use crate::sync::compute_sync_plan;
 use crate::types::{
-    BatchCreateResult, BatchDeleteResult, BatchUpdateItem, BatchUpdateResult,
-    CreateDnsRecordRequest, DnsRecord, PaginatedResponse, PaginationParams, ProviderDomain,
-    ProviderMetadata, RecordQueryParams, UpdateDnsRecordRequest,
+    BatchCreateFailure, BatchCreateResult, BatchDeleteFailure, BatchDeleteResult,
+    BatchUpdateFailure, BatchUpdateItem, BatchUpdateResult, CreateDnsRecordRequest, DnsRecord,
+    DnsRecordType, DnssecStatus, PaginatedResponse, PaginationParams, ProviderDomain,
+    ProviderFeatures, ProviderHealth, ProviderMetadata, RecordChange, RecordQueryParams, SyncPlan,
+    SyncResult, UpdateDnsRecordRequest,
 };
 
+/// 批量操作默认的最大并发请求数
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 5;
+
+/// 拉取全部记录时每页的记录数
+const LIST_ALL_PAGE_SIZE: u32 = 100;
+
 /// 原始 API 错误（内部使用）
 #[derive(Debug, Clone)]
 pub(crate) struct RawApiError {
@@ -14,6 +29,8 @@ pub(crate) struct RawApiError {
     pub code: Option<String>,
     /// 原始错误消息
     pub message: String,
+    /// HTTP 状态码（非 HTTP 协议的 Provider 为 `None`）
+    pub status: Option<u16>,
 }
 
 impl RawApiError {
@@ -21,6 +38,7 @@ impl RawApiError {
         Self {
             code: None,
             message: message.into(),
+            status: None,
         }
     }
 
@@ -28,8 +46,15 @@ impl RawApiError {
         Self {
             code: Some(code.into()),
             message: message.into(),
+            status: None,
         }
     }
+
+    /// 附带 HTTP 状态码
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = Some(status);
+        self
+    }
 }
 
 /// 错误上下文信息（内部使用）
@@ -70,11 +95,21 @@ pub(crate) trait ProviderErrorMapper {
     }
 
     /// 快捷方法：未知错误（fallback）
+    ///
+    /// 带 HTTP 状态码时返回 `ApiError`，保留 Provider 原始错误码与消息
     fn unknown_error(&self, raw: RawApiError) -> ProviderError {
-        ProviderError::Unknown {
-            provider: self.provider_name().to_string(),
-            raw_code: raw.code,
-            raw_message: raw.message,
+        match raw.status {
+            Some(status) => ProviderError::ApiError {
+                provider: self.provider_name().to_string(),
+                status,
+                provider_code: raw.code,
+                message: raw.message,
+            },
+            None => ProviderError::Unknown {
+                provider: self.provider_name().to_string(),
+                raw_code: raw.code,
+                raw_message: raw.message,
+            },
         }
     }
 }
@@ -93,9 +128,78 @@ pub trait DnsProvider: Send + Sync {
     where
         Self: Sized;
 
+    /// 校验 TTL 是否在 Provider 允许的范围内（由 `ProviderLimits` 决定）
+    ///
+    /// 在发起网络请求前调用，超出范围时返回带允许范围的 `InvalidParameter`。
+    fn validate_ttl(&self, ttl: u32) -> Result<()>
+    where
+        Self: Sized,
+    {
+        let limits = Self::metadata().limits;
+        if limits.auto_ttl == Some(ttl) || (limits.min_ttl..=limits.max_ttl).contains(&ttl) {
+            return Ok(());
+        }
+        let auto = limits
+            .auto_ttl
+            .map(|auto| format!(", or {auto} for automatic"))
+            .unwrap_or_default();
+        Err(ProviderError::InvalidParameter {
+            provider: self.id().to_string(),
+            param: "ttl".to_string(),
+            detail: format!(
+                "TTL {ttl} is out of range (allowed: {}-{}{auto})",
+                limits.min_ttl, limits.max_ttl
+            ),
+        })
+    }
+
+    /// 实例级的功能支持情况，与 `metadata().features` 一致
+    ///
+    /// `metadata()` 仅限具体类型调用，trait 对象通过此方法获取功能开关。
+    fn features(&self) -> ProviderFeatures {
+        ProviderFeatures::default()
+    }
+
     /// 验证凭证是否有效
     async fn validate_credentials(&self) -> Result<bool>;
 
+    /// 轻量健康检查
+    ///
+    /// 默认实现只请求一条域名（`page_size = 1`），返回延迟与认证状态，
+    /// 适合定期探测；探测失败会体现在返回值中，而不是作为错误返回。
+    async fn health_check(&self) -> Result<ProviderHealth> {
+        let started = Instant::now();
+        let outcome = self
+            .list_domains(&PaginationParams {
+                page: 1,
+                page_size: 1,
+                cursor: None,
+            })
+            .await;
+        let latency_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        let health = match outcome {
+            Ok(_) => ProviderHealth {
+                reachable: true,
+                authenticated: true,
+                latency_ms,
+                error: None,
+            },
+            Err(e) => ProviderHealth {
+                reachable: !matches!(e, ProviderError::NetworkError { .. }),
+                authenticated: !matches!(
+                    e,
+                    ProviderError::InvalidCredentials { .. }
+                        | ProviderError::PermissionDenied { .. }
+                        | ProviderError::NetworkError { .. }
+                ),
+                latency_ms,
+                error: Some(e.to_string()),
+            },
+        };
+        Ok(health)
+    }
+
     /// 获取域名列表 (分页)
     async fn list_domains(
         &self,
@@ -112,6 +216,18 @@ pub trait DnsProvider: Send + Sync {
         params: &RecordQueryParams,
     ) -> Result<PaginatedResponse<DnsRecord>>;
 
+    /// 拉取域名下的全部记录（可取消）
+    ///
+    /// 逐页调用 `list_records`；取消令牌触发时中止进行中的分页请求并返回 `Cancelled`，
+    /// 不再发起后续请求。
+    async fn list_all_records_cancellable(
+        &self,
+        domain_id: &str,
+        cancel: &CancellationToken,
+    ) -> Result<Vec<DnsRecord>> {
+        list_records_all_pages(self, domain_id, None, cancel).await
+    }
+
     /// 创建 DNS 记录
     async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord>;
 
@@ -125,12 +241,148 @@ pub trait DnsProvider: Send + Sync {
     /// 删除 DNS 记录
     async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()>;
 
+    /// 创建或更新 DNS 记录（幂等）
+    ///
+    /// 按名称 + 类型查找已有记录：找到则更新第一条（内容与 TTL 均未变化时直接返回），
+    /// 否则创建。支持原生 upsert 的 Provider（如 Route 53 `UPSERT`）可覆盖此实现。
+    async fn upsert_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        ensure_simple_routing(req.routing_policy.as_ref(), self.id())?;
+        let name = if req.name.is_empty() {
+            "@"
+        } else {
+            req.name.as_str()
+        };
+        let existing = list_records_all_pages(
+            self,
+            &req.domain_id,
+            Some(req.data.record_type()),
+            &CancellationToken::new(),
+        )
+        .await?
+        .into_iter()
+        .find(|r| {
+            r.name.eq_ignore_ascii_case(name) && r.data.record_type() == req.data.record_type()
+        });
+
+        match existing {
+            Some(record) if record.data == req.data && record.ttl == req.ttl => Ok(record),
+            Some(record) => {
+                let update = UpdateDnsRecordRequest {
+                    domain_id: req.domain_id.clone(),
+                    name: req.name.clone(),
+                    ttl: req.ttl,
+                    data: req.data.clone(),
+                    proxied: req.proxied,
+                    comment: req.comment.clone(),
+                    tags: req.tags.clone(),
+                };
+                self.update_record(&record.id, &update).await
+            }
+            None => self.create_record(req).await,
+        }
+    }
+
+    /// 计算将域名记录同步到期望状态所需的变更
+    ///
+    /// 读取域名下全部记录，与 `desired` 比较后分为新建、更新、删除与不变四类，
+    /// 匹配规则见 `crate::sync` 模块文档。此方法不会修改任何记录。
+    async fn plan_sync(
+        &self,
+        domain_id: &str,
+        desired: &[CreateDnsRecordRequest],
+    ) -> Result<SyncPlan> {
+        let zone_name = self.get_domain(domain_id).await?.name;
+        let existing = self
+            .list_all_records_cancellable(domain_id, &CancellationToken::new())
+            .await?;
+
+        Ok(compute_sync_plan(
+            domain_id,
+            &zone_name,
+            existing,
+            desired,
+            &self.features(),
+        ))
+    }
+
+    /// 执行同步计划
+    ///
+    /// 按「删除 -> 更新 -> 新建」的顺序调用批量操作，先删除可避免 CNAME 等记录冲突。
+    /// 单条失败不会中断后续操作，失败项记录在各阶段的结果中。
+    async fn apply_sync(&self, plan: &SyncPlan) -> Result<SyncResult> {
+        let record_ids: Vec<String> = plan.deletes.iter().map(|r| r.id.clone()).collect();
+        let deleted = self
+            .batch_delete_records(&plan.domain_id, &record_ids)
+            .await?;
+        let updated = self.batch_update_records(&plan.updates).await?;
+        let created = self.batch_create_records(&plan.creates).await?;
+
+        Ok(SyncResult {
+            deleted,
+            updated,
+            created,
+        })
+    }
+
+    /// 获取域名的 DNSSEC 状态，以及需要在注册商处发布的 DS 记录
+    ///
+    /// 默认返回 `UnsupportedOperation`，支持托管签名的 Provider 覆盖此方法。
+    async fn get_dnssec_status(&self, domain_id: &str) -> Result<DnssecStatus> {
+        let _ = domain_id;
+        Err(ProviderError::UnsupportedOperation {
+            provider: self.id().to_string(),
+            operation: "get_dnssec_status".to_string(),
+        })
+    }
+
+    /// 为域名启用 DNSSEC 签名，返回启用后的状态
+    ///
+    /// 启用后通常处于 `Pending`，需将返回的 DS 记录提交到注册商后才会生效。
+    async fn enable_dnssec(&self, domain_id: &str) -> Result<DnssecStatus> {
+        let _ = domain_id;
+        Err(ProviderError::UnsupportedOperation {
+            provider: self.id().to_string(),
+            operation: "enable_dnssec".to_string(),
+        })
+    }
+
+    /// 列出域名的记录变更历史（最新的在前），`since` 为 `None` 时返回 Provider 保留的全部日志
+    ///
+    /// 默认返回 `UnsupportedOperation`，提供操作日志的 Provider 覆盖此方法。
+    async fn list_record_changes(
+        &self,
+        domain_id: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<RecordChange>> {
+        let _ = (domain_id, since);
+        Err(ProviderError::UnsupportedOperation {
+            provider: self.id().to_string(),
+            operation: "list_record_changes".to_string(),
+        })
+    }
+
+    /// 批量操作的最大并发请求数
+    ///
+    /// 默认批量实现按此值限制同时进行的单条请求，避免触发速率限制。
+    /// 可通过各 Provider Builder 的 `batch_concurrency()` 调整。
+    fn batch_concurrency(&self) -> usize {
+        DEFAULT_BATCH_CONCURRENCY
+    }
+
+    /// 可取消批量操作每段的记录数
+    ///
+    /// 默认与 `batch_concurrency()` 相同；有原生批量 API 的 Provider 应覆盖为单次请求的最大记录数，
+    /// 避免一次原生批量请求被拆成多个小请求。
+    fn batch_chunk_size(&self) -> usize {
+        self.batch_concurrency()
+    }
+
     /// 批量创建 DNS 记录
     ///
-    /// # 实现状态
-    /// 当前为占位实现，调用会 panic。
+    /// 默认实现以 `batch_concurrency()` 为上限并发调用 `create_record`，
+    /// 单条失败不影响其他记录，结果按请求顺序汇总。
     ///
-    /// # TODO - 实现计划
+    /// # TODO - 原生批量 API
     /// - [ ] Cloudflare: 使用 `POST /zones/{zone_id}/dns_records/batch` API
     ///       文档: https://developers.cloudflare.com/api/resources/dns/subresources/records/methods/batch/
     ///       预期性能: 10,000 条记录快 850 倍
@@ -139,49 +391,289 @@ pub trait DnsProvider: Send + Sync {
     /// - [ ] Aliyun: 调研批量 API 支持
     ///       文档: https://help.aliyun.com/zh/dns/pubz-batch-operation/
     /// - [ ] Huaweicloud: 调研批量 API 支持
-    ///
-    /// # 未来优化
-    /// 默认实现可使用客户端并行循环 `join_all(create_record())`
+    /// - [x] Hetzner: 使用 `POST /records/bulk` API
     async fn batch_create_records(
         &self,
-        _requests: &[CreateDnsRecordRequest],
+        requests: &[CreateDnsRecordRequest],
     ) -> Result<BatchCreateResult> {
-        unimplemented!("批量创建 API 待实现 - 请查看 trait 文档中的 TODO 列表")
+        // 按下标迭代：直接迭代切片引用会在 async_trait 下触发闭包生命周期推断错误
+        let results: Vec<_> = stream::iter(0..requests.len())
+            .map(|i| self.create_record(&requests[i]))
+            .buffered(self.batch_concurrency().max(1))
+            .collect()
+            .await;
+
+        let mut result = BatchCreateResult {
+            success_count: 0,
+            failed_count: 0,
+            created_records: Vec::new(),
+            failures: Vec::new(),
+        };
+        for (index, (req, outcome)) in requests.iter().zip(results).enumerate() {
+            match outcome {
+                Ok(record) => {
+                    result.success_count += 1;
+                    result.created_records.push(record);
+                }
+                Err(e) => {
+                    result.failed_count += 1;
+                    result.failures.push(BatchCreateFailure {
+                        request_index: index,
+                        record_name: req.name.clone(),
+                        reason: e.to_string(),
+                    });
+                }
+            }
+        }
+        Ok(result)
     }
 
     /// 批量更新 DNS 记录
     ///
-    /// # 实现状态
-    /// 当前为占位实现，调用会 panic。
+    /// 默认实现以 `batch_concurrency()` 为上限并发调用 `update_record`。
     ///
-    /// # TODO - 实现计划
+    /// # TODO - 原生批量 API
     /// - [ ] Cloudflare: 使用批量 API
     /// - [ ] DNSPod: 使用 `ModifyRecordBatch` API
     /// - [ ] Aliyun: 调研批量 API 支持
     /// - [ ] Huaweicloud: 使用 `BatchUpdateRecordSetWithLine` API
     ///       文档: https://support.huaweicloud.com/api-dns/BatchUpdateRecordSetWithLine.html
-    async fn batch_update_records(
-        &self,
-        _updates: &[BatchUpdateItem],
-    ) -> Result<BatchUpdateResult> {
-        unimplemented!("批量更新 API 待实现 - 请查看 trait 文档中的 TODO 列表")
+    /// - [x] Hetzner: 使用 `PUT /records/bulk` API
+    async fn batch_update_records(&self, updates: &[BatchUpdateItem]) -> Result<BatchUpdateResult> {
+        let results: Vec<_> = stream::iter(0..updates.len())
+            .map(|i| self.update_record(&updates[i].record_id, &updates[i].request))
+            .buffered(self.batch_concurrency().max(1))
+            .collect()
+            .await;
+
+        let mut result = BatchUpdateResult {
+            success_count: 0,
+            failed_count: 0,
+            updated_records: Vec::new(),
+            failures: Vec::new(),
+        };
+        for (item, outcome) in updates.iter().zip(results) {
+            match outcome {
+                Ok(record) => {
+                    result.success_count += 1;
+                    result.updated_records.push(record);
+                }
+                Err(e) => {
+                    result.failed_count += 1;
+                    result.failures.push(BatchUpdateFailure {
+                        record_id: item.record_id.clone(),
+                        reason: e.to_string(),
+                    });
+                }
+            }
+        }
+        Ok(result)
     }
 
     /// 批量删除 DNS 记录
     ///
-    /// # 实现状态
-    /// 当前为占位实现，调用会 panic。
+    /// 默认实现以 `batch_concurrency()` 为上限并发调用 `delete_record`。
     ///
-    /// # TODO - 实现计划
+    /// # TODO - 原生批量 API
     /// - [ ] Cloudflare: 使用批量 API
     /// - [ ] DNSPod: 使用批量删除 API
     /// - [ ] Aliyun: 调研批量 API 支持
     /// - [ ] Huaweicloud: 调研批量 API 支持
     async fn batch_delete_records(
         &self,
-        _domain_id: &str,
-        _record_ids: &[String],
+        domain_id: &str,
+        record_ids: &[String],
     ) -> Result<BatchDeleteResult> {
-        unimplemented!("批量删除 API 待实现 - 请查看 trait 文档中的 TODO 列表")
+        let results: Vec<_> = stream::iter(0..record_ids.len())
+            .map(|i| self.delete_record(&record_ids[i], domain_id))
+            .buffered(self.batch_concurrency().max(1))
+            .collect()
+            .await;
+
+        let mut result = BatchDeleteResult {
+            success_count: 0,
+            failed_count: 0,
+            failures: Vec::new(),
+        };
+        for (record_id, outcome) in record_ids.iter().zip(results) {
+            match outcome {
+                Ok(()) => result.success_count += 1,
+                Err(e) => {
+                    result.failed_count += 1;
+                    result.failures.push(BatchDeleteFailure {
+                        record_id: record_id.clone(),
+                        reason: e.to_string(),
+                    });
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// 批量创建 DNS 记录（可取消）
+    ///
+    /// 按 `batch_chunk_size()` 分段调用 `batch_create_records`（保留 Provider 的原生批量实现），
+    /// 每段开始前检查取消令牌。取消后未发起的请求记为失败，已完成的结果照常返回。
+    async fn batch_create_records_cancellable(
+        &self,
+        requests: &[CreateDnsRecordRequest],
+        cancel: &CancellationToken,
+    ) -> Result<BatchCreateResult> {
+        let chunk_size = self.batch_chunk_size().max(1);
+        let mut result = BatchCreateResult {
+            success_count: 0,
+            failed_count: 0,
+            created_records: Vec::new(),
+            failures: Vec::new(),
+        };
+        for (chunk_index, chunk) in requests.chunks(chunk_size).enumerate() {
+            let offset = chunk_index * chunk_size;
+            if cancel.is_cancelled() {
+                let reason = cancelled_reason(self.id(), "batch_create_records");
+                result.failed_count += chunk.len();
+                result
+                    .failures
+                    .extend(chunk.iter().enumerate().map(|(i, req)| BatchCreateFailure {
+                        request_index: offset + i,
+                        record_name: req.name.clone(),
+                        reason: reason.clone(),
+                    }));
+                continue;
+            }
+
+            let chunk_result = self.batch_create_records(chunk).await?;
+            result.success_count += chunk_result.success_count;
+            result.failed_count += chunk_result.failed_count;
+            result.created_records.extend(chunk_result.created_records);
+            result
+                .failures
+                .extend(
+                    chunk_result
+                        .failures
+                        .into_iter()
+                        .map(|failure| BatchCreateFailure {
+                            request_index: offset + failure.request_index,
+                            ..failure
+                        }),
+                );
+        }
+        Ok(result)
+    }
+
+    /// 批量更新 DNS 记录（可取消）
+    ///
+    /// 分段方式与 `batch_create_records_cancellable` 相同。
+    async fn batch_update_records_cancellable(
+        &self,
+        updates: &[BatchUpdateItem],
+        cancel: &CancellationToken,
+    ) -> Result<BatchUpdateResult> {
+        let chunk_size = self.batch_chunk_size().max(1);
+        let mut result = BatchUpdateResult {
+            success_count: 0,
+            failed_count: 0,
+            updated_records: Vec::new(),
+            failures: Vec::new(),
+        };
+        for chunk in updates.chunks(chunk_size) {
+            if cancel.is_cancelled() {
+                let reason = cancelled_reason(self.id(), "batch_update_records");
+                result.failed_count += chunk.len();
+                result
+                    .failures
+                    .extend(chunk.iter().map(|item| BatchUpdateFailure {
+                        record_id: item.record_id.clone(),
+                        reason: reason.clone(),
+                    }));
+                continue;
+            }
+
+            let chunk_result = self.batch_update_records(chunk).await?;
+            result.success_count += chunk_result.success_count;
+            result.failed_count += chunk_result.failed_count;
+            result.updated_records.extend(chunk_result.updated_records);
+            result.failures.extend(chunk_result.failures);
+        }
+        Ok(result)
+    }
+
+    /// 批量删除 DNS 记录（可取消）
+    ///
+    /// 分段方式与 `batch_create_records_cancellable` 相同。
+    async fn batch_delete_records_cancellable(
+        &self,
+        domain_id: &str,
+        record_ids: &[String],
+        cancel: &CancellationToken,
+    ) -> Result<BatchDeleteResult> {
+        let chunk_size = self.batch_chunk_size().max(1);
+        let mut result = BatchDeleteResult {
+            success_count: 0,
+            failed_count: 0,
+            failures: Vec::new(),
+        };
+        for chunk in record_ids.chunks(chunk_size) {
+            if cancel.is_cancelled() {
+                let reason = cancelled_reason(self.id(), "batch_delete_records");
+                result.failed_count += chunk.len();
+                result
+                    .failures
+                    .extend(chunk.iter().map(|record_id| BatchDeleteFailure {
+                        record_id: record_id.clone(),
+                        reason: reason.clone(),
+                    }));
+                continue;
+            }
+
+            let chunk_result = self.batch_delete_records(domain_id, chunk).await?;
+            result.success_count += chunk_result.success_count;
+            result.failed_count += chunk_result.failed_count;
+            result.failures.extend(chunk_result.failures);
+        }
+        Ok(result)
+    }
+}
+
+/// 逐页拉取记录（可按类型过滤）
+///
+/// 每页转发上一页的 `next_cursor`，页码分页与游标分页的 Provider 均适用；
+/// 取消令牌触发时中止进行中的请求并返回 `Cancelled`。
+async fn list_records_all_pages<P: DnsProvider + ?Sized>(
+    provider: &P,
+    domain_id: &str,
+    record_type: Option<DnsRecordType>,
+    cancel: &CancellationToken,
+) -> Result<Vec<DnsRecord>> {
+    let mut params = RecordQueryParams {
+        page: 1,
+        page_size: LIST_ALL_PAGE_SIZE,
+        keyword: None,
+        record_type,
+        cursor: None,
+    };
+    let mut records = Vec::new();
+    loop {
+        let response = HttpUtils::cancellable(
+            provider.id(),
+            "list_records",
+            cancel,
+            provider.list_records(domain_id, &params),
+        )
+        .await?;
+        records.extend(response.items);
+        if !response.has_more {
+            return Ok(records);
+        }
+        params.page += 1;
+        params.cursor = response.next_cursor;
+    }
+}
+
+/// 批量操作被取消时，未发起请求的失败原因
+fn cancelled_reason(provider: &str, operation: &str) -> String {
+    ProviderError::Cancelled {
+        provider: provider.to_string(),
+        operation: operation.to_string(),
     }
+    .to_string()
 }
@@ -8,6 +8,12 @@ use serde::{Deserialize, Serialize};
 pub struct PaginationParams {
     pub page: u32,
     pub page_size: u32,
+    /// 游标（上一页响应中的 `next_cursor`），仅基于游标分页的 Provider 使用，页码分页的 Provider 忽略
+    ///
+    /// 传入空字符串表示以游标方式获取第一页；支持游标的 Provider 在第一页（`None`）的响应中
+    /// 同样返回 `next_cursor`，调用方可据此切换为游标分页。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
 }
 
 impl Default for PaginationParams {
@@ -15,6 +21,7 @@ impl Default for PaginationParams {
         Self {
             page: 1,
             page_size: 20,
+            cursor: None,
         }
     }
 }
@@ -31,6 +38,9 @@ pub struct RecordQueryParams {
     /// 记录类型过滤
     #[serde(skip_serializing_if = "Option::is_none")]
     pub record_type: Option<DnsRecordType>,
+    /// 游标（同 `PaginationParams::cursor`；记录列表不支持游标的 Provider 忽略，如 Route 53）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
 }
 
 impl Default for RecordQueryParams {
@@ -40,6 +50,7 @@ impl Default for RecordQueryParams {
             page_size: 20,
             keyword: None,
             record_type: None,
+            cursor: None,
         }
     }
 }
@@ -50,6 +61,7 @@ impl RecordQueryParams {
         PaginationParams {
             page: self.page,
             page_size: self.page_size,
+            cursor: self.cursor.clone(),
         }
     }
 }
@@ -63,6 +75,9 @@ pub struct PaginatedResponse<T> {
     pub page_size: u32,
     pub total_count: u32,
     pub has_more: bool,
+    /// 下一页游标，仅基于游标分页的 Provider 返回
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
 impl<T> PaginatedResponse<T> {
@@ -74,8 +89,17 @@ impl<T> PaginatedResponse<T> {
             page_size,
             total_count,
             has_more,
+            next_cursor: None,
         }
     }
+
+    /// 设置下一页游标（有游标时 `has_more` 为 true）
+    #[must_use]
+    pub fn with_next_cursor(mut self, next_cursor: Option<String>) -> Self {
+        self.has_more = next_cursor.is_some();
+        self.next_cursor = next_cursor;
+        self
+    }
 }
 
 // ============ Provider 相关类型 ============
@@ -92,6 +116,20 @@ pub enum ProviderType {
     Dnspod,
     #[cfg(feature = "huaweicloud")]
     Huaweicloud,
+    #[cfg(feature = "route53")]
+    Route53,
+    #[cfg(feature = "gcloud")]
+    #[serde(rename = "gcloud")]
+    GoogleCloud,
+    #[cfg(feature = "namecheap")]
+    Namecheap,
+    #[cfg(feature = "hetzner")]
+    Hetzner,
+    #[cfg(feature = "rfc2136")]
+    Rfc2136,
+    #[cfg(feature = "desec")]
+    #[serde(rename = "desec")]
+    DeSEC,
 }
 
 impl std::fmt::Display for ProviderType {
@@ -105,6 +143,18 @@ impl std::fmt::Display for ProviderType {
             Self::Dnspod => write!(f, "dnspod"),
             #[cfg(feature = "huaweicloud")]
             Self::Huaweicloud => write!(f, "huaweicloud"),
+            #[cfg(feature = "route53")]
+            Self::Route53 => write!(f, "route53"),
+            #[cfg(feature = "gcloud")]
+            Self::GoogleCloud => write!(f, "gcloud"),
+            #[cfg(feature = "namecheap")]
+            Self::Namecheap => write!(f, "namecheap"),
+            #[cfg(feature = "hetzner")]
+            Self::Hetzner => write!(f, "hetzner"),
+            #[cfg(feature = "rfc2136")]
+            Self::Rfc2136 => write!(f, "rfc2136"),
+            #[cfg(feature = "desec")]
+            Self::DeSEC => write!(f, "desec"),
         }
     }
 }
@@ -145,6 +195,10 @@ pub enum DnsRecordType {
     Ns,
     Srv,
     Caa,
+    Alias,
+    Tlsa,
+    Sshfp,
+    Ds,
 }
 
 /// DNS 记录数据 - 类型安全的多态表示
@@ -183,6 +237,35 @@ pub enum RecordData {
         tag: String,
         value: String,
     },
+
+    /// ALIAS/ANAME 记录：根域可用的别名，由服务商解析为目标的地址
+    ALIAS { target: String },
+
+    /// TLSA 记录：DANE 证书关联
+    #[serde(rename_all = "camelCase")]
+    TLSA {
+        usage: u8,
+        selector: u8,
+        matching_type: u8,
+        certificate_association: String,
+    },
+
+    /// SSHFP 记录：SSH 主机密钥指纹
+    #[serde(rename_all = "camelCase")]
+    SSHFP {
+        algorithm: u8,
+        fingerprint_type: u8,
+        fingerprint: String,
+    },
+
+    /// DS 记录：DNSSEC 委派签名者
+    #[serde(rename_all = "camelCase")]
+    DS {
+        key_tag: u16,
+        algorithm: u8,
+        digest_type: u8,
+        digest: String,
+    },
 }
 
 impl RecordData {
@@ -197,6 +280,10 @@ impl RecordData {
             Self::NS { .. } => DnsRecordType::Ns,
             Self::SRV { .. } => DnsRecordType::Srv,
             Self::CAA { .. } => DnsRecordType::Caa,
+            Self::ALIAS { .. } => DnsRecordType::Alias,
+            Self::TLSA { .. } => DnsRecordType::Tlsa,
+            Self::SSHFP { .. } => DnsRecordType::Sshfp,
+            Self::DS { .. } => DnsRecordType::Ds,
         }
     }
 
@@ -204,12 +291,29 @@ impl RecordData {
     pub fn display_value(&self) -> String {
         match self {
             Self::A { address } | Self::AAAA { address } => address.clone(),
-            Self::CNAME { target } => target.clone(),
+            Self::CNAME { target } | Self::ALIAS { target } => target.clone(),
             Self::MX { exchange, .. } => exchange.clone(),
             Self::TXT { text } => text.clone(),
             Self::NS { nameserver } => nameserver.clone(),
             Self::SRV { target, .. } => target.clone(),
             Self::CAA { value, .. } => value.clone(),
+            Self::TLSA {
+                usage,
+                selector,
+                matching_type,
+                certificate_association,
+            } => format!("{usage} {selector} {matching_type} {certificate_association}"),
+            Self::SSHFP {
+                algorithm,
+                fingerprint_type,
+                fingerprint,
+            } => format!("{algorithm} {fingerprint_type} {fingerprint}"),
+            Self::DS {
+                key_tag,
+                algorithm,
+                digest_type,
+                digest,
+            } => format!("{key_tag} {algorithm} {digest_type} {digest}"),
         }
     }
 }
@@ -227,6 +331,14 @@ pub struct DnsRecord {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub proxied: Option<bool>,
 
+    /// 记录备注（仅支持的 Provider 填充，如 Cloudflare）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+
+    /// 记录标签（仅支持的 Provider 填充，如 Cloudflare）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
     #[serde(with = "crate::utils::datetime")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
@@ -234,6 +346,39 @@ pub struct DnsRecord {
     #[serde(with = "crate::utils::datetime")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// 路由策略（仅支持的 Provider 填充，如 Route 53；简单路由为 `None`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub routing_policy: Option<RoutingPolicy>,
+}
+
+/// 路由策略（Route 53 等高级 Provider 支持，其余 Provider 仅接受 `Simple`）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "content")]
+pub enum RoutingPolicy {
+    /// 简单路由（默认）
+    Simple,
+    /// 加权路由：按权重比例返回同名记录
+    Weighted { weight: u8 },
+    /// 延迟路由：返回延迟最低区域的记录
+    Latency { region: String },
+    /// 地理位置路由：按大洲或国家代码匹配（二者只能设置其一）
+    Geo {
+        continent: Option<String>,
+        country: Option<String>,
+    },
+}
+
+impl RoutingPolicy {
+    /// 策略名称（用于错误信息）
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Simple => "Simple",
+            Self::Weighted { .. } => "Weighted",
+            Self::Latency { .. } => "Latency",
+            Self::Geo { .. } => "Geo",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -244,6 +389,15 @@ pub struct CreateDnsRecordRequest {
     pub ttl: u32,
     pub data: RecordData,
     pub proxied: Option<bool>,
+    /// 记录备注（不支持的 Provider 忽略）
+    #[serde(default)]
+    pub comment: Option<String>,
+    /// 记录标签（不支持的 Provider 忽略）
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// 路由策略（不支持的 Provider 对非 `Simple` 策略返回 `UnsupportedRecordType`）
+    #[serde(default)]
+    pub routing_policy: Option<RoutingPolicy>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -254,6 +408,12 @@ pub struct UpdateDnsRecordRequest {
     pub ttl: u32,
     pub data: RecordData,
     pub proxied: Option<bool>,
+    /// 记录备注（不支持的 Provider 忽略）
+    #[serde(default)]
+    pub comment: Option<String>,
+    /// 记录标签（不支持的 Provider 忽略）
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 // ============ 批量操作类型 ============
@@ -320,6 +480,39 @@ pub struct BatchDeleteFailure {
     pub reason: String,
 }
 
+// ============ 同步类型 ============
+
+/// 期望状态与实际状态之间的同步计划（由 `DnsProvider::plan_sync` 生成）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncPlan {
+    pub domain_id: String,
+    /// 需要新建的记录
+    pub creates: Vec<CreateDnsRecordRequest>,
+    /// 需要原地更新的记录
+    pub updates: Vec<BatchUpdateItem>,
+    /// 需要删除的记录
+    pub deletes: Vec<DnsRecord>,
+    /// 已与期望状态一致的记录
+    pub unchanged: Vec<DnsRecord>,
+}
+
+impl SyncPlan {
+    /// 是否无需任何变更
+    pub fn is_empty(&self) -> bool {
+        self.creates.is_empty() && self.updates.is_empty() && self.deletes.is_empty()
+    }
+}
+
+/// 同步计划的执行结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncResult {
+    pub deleted: BatchDeleteResult,
+    pub updated: BatchUpdateResult,
+    pub created: BatchCreateResult,
+}
+
 // ============ Provider 元数据类型 ============
 
 /// 凭证字段类型
@@ -350,9 +543,15 @@ pub struct ProviderCredentialField {
 pub struct ProviderFeatures {
     /// 是否支持代理功能 (如 Cloudflare 的 CDN 代理)
     pub proxy: bool,
+    /// 是否支持记录备注与标签（`comment` / `tags`）
+    #[serde(default)]
+    pub record_metadata: bool,
+    /// 是否支持非 `Simple` 的路由策略（如 Route 53 的加权、延迟、地理位置路由）
+    #[serde(default)]
+    pub routing_policy: bool,
 }
 
-/// 提供商分页限制
+/// 提供商分页与速率限制
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProviderLimits {
@@ -360,6 +559,18 @@ pub struct ProviderLimits {
     pub max_page_size_domains: u32,
     /// DNS 记录列表的最大分页大小
     pub max_page_size_records: u32,
+    /// 官方文档公布的 API 速率限制（每秒请求数），`None` 表示未公布
+    pub requests_per_second: Option<f64>,
+    /// 批量操作单次请求的最大记录数（无原生批量 API 时为 1）
+    pub max_batch_size: u32,
+    /// 单个区域的最大记录数，`None` 表示未公布或取决于套餐
+    pub max_records_per_zone: Option<u32>,
+    /// 允许的最小 TTL（秒）
+    pub min_ttl: u32,
+    /// 允许的最大 TTL（秒）
+    pub max_ttl: u32,
+    /// 表示「自动」的特殊 TTL 值（如 Cloudflare 的 1），不受上下限约束
+    pub auto_ttl: Option<u32>,
 }
 
 /// 提供商元数据
@@ -374,6 +585,79 @@ pub struct ProviderMetadata {
     pub limits: ProviderLimits,
 }
 
+/// Provider 健康检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderHealth {
+    /// API 是否可达（网络错误时为 false）
+    pub reachable: bool,
+    /// 凭证是否有效
+    pub authenticated: bool,
+    /// 探测请求耗时（毫秒）
+    pub latency_ms: u64,
+    /// 探测失败时的错误信息
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ProviderHealth {
+    /// 可达且凭证有效
+    pub fn is_healthy(&self) -> bool {
+        self.reachable && self.authenticated && self.error.is_none()
+    }
+}
+
+// ============ DNSSEC 相关类型 ============
+
+/// DNSSEC 签名状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DnssecState {
+    /// 未启用
+    Disabled,
+    /// 已启用，等待注册商发布 DS 记录
+    Pending,
+    /// 已生效
+    Active,
+    /// 正在关闭
+    PendingDisable,
+}
+
+/// 域名的 DNSSEC 状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DnssecStatus {
+    pub state: DnssecState,
+    /// 需要在注册商处发布的 DS 记录（均为 `RecordData::DS`），未启用时为空
+    pub ds_records: Vec<RecordData>,
+}
+
+// ============ 记录变更历史 ============
+
+/// 记录变更操作类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RecordChangeAction {
+    Create,
+    Update,
+    Delete,
+    /// 其他操作（启用/暂停记录等）
+    Other,
+}
+
+/// 一条记录变更（由 Provider 的操作日志映射而来）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordChange {
+    /// 变更时间
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    pub action: RecordChangeAction,
+    /// 变更内容摘要（如 `A www 192.0.2.1`）
+    pub record_summary: String,
+    /// 操作者（邮箱、账号 ID 等），日志中没有时为 `None`
+    pub actor: Option<String>,
+}
+
 // ============ 凭证类型 ============
 
 /// 凭证验证错误
@@ -421,6 +705,11 @@ pub enum ProviderCredentials {
     #[serde(rename = "cloudflare")]
     Cloudflare { api_token: String },
 
+    /// Cloudflare Global API Key 认证（旧版账户，与 API Token 二选一）
+    #[cfg(feature = "cloudflare")]
+    #[serde(rename = "cloudflareKey")]
+    CloudflareKey { email: String, api_key: String },
+
     #[cfg(feature = "aliyun")]
     #[serde(rename = "aliyun")]
     Aliyun {
@@ -441,6 +730,48 @@ pub enum ProviderCredentials {
         access_key_id: String,
         secret_access_key: String,
     },
+
+    #[cfg(feature = "route53")]
+    #[serde(rename = "route53")]
+    Route53 {
+        access_key_id: String,
+        secret_access_key: String,
+        region: String,
+    },
+
+    #[cfg(feature = "gcloud")]
+    #[serde(rename = "gcloud")]
+    GoogleCloud {
+        service_account_json: String,
+        project_id: String,
+    },
+
+    #[cfg(feature = "namecheap")]
+    #[serde(rename = "namecheap")]
+    Namecheap {
+        api_user: String,
+        api_key: String,
+        username: String,
+        client_ip: String,
+    },
+
+    #[cfg(feature = "hetzner")]
+    #[serde(rename = "hetzner")]
+    Hetzner { api_token: String },
+
+    #[cfg(feature = "rfc2136")]
+    #[serde(rename = "rfc2136")]
+    Rfc2136 {
+        server: String,
+        zone: String,
+        tsig_key_name: String,
+        tsig_algorithm: String,
+        tsig_secret: String,
+    },
+
+    #[cfg(feature = "desec")]
+    #[serde(rename = "desec")]
+    DeSEC { token: String },
 }
 
 impl ProviderCredentials {
@@ -451,9 +782,7 @@ impl ProviderCredentials {
     ) -> Result<Self, CredentialValidationError> {
         match provider {
             #[cfg(feature = "cloudflare")]
-            ProviderType::Cloudflare => Ok(Self::Cloudflare {
-                api_token: Self::get_required_field(provider, map, "apiToken", "API Token")?,
-            }),
+            ProviderType::Cloudflare => Self::cloudflare_from_map(provider, map),
             #[cfg(feature = "aliyun")]
             ProviderType::Aliyun => Ok(Self::Aliyun {
                 access_key_id: Self::get_required_field(
@@ -489,6 +818,70 @@ impl ProviderCredentials {
                     "Secret Access Key",
                 )?,
             }),
+            #[cfg(feature = "route53")]
+            ProviderType::Route53 => Ok(Self::Route53 {
+                access_key_id: Self::get_required_field(
+                    provider,
+                    map,
+                    "accessKeyId",
+                    "Access Key ID",
+                )?,
+                secret_access_key: Self::get_required_field(
+                    provider,
+                    map,
+                    "secretAccessKey",
+                    "Secret Access Key",
+                )?,
+                // Region 可选，留空时使用 us-east-1
+                region: map
+                    .get("region")
+                    .map(|r| r.trim().to_string())
+                    .filter(|r| !r.is_empty())
+                    .unwrap_or_else(|| "us-east-1".to_string()),
+            }),
+            #[cfg(feature = "gcloud")]
+            ProviderType::GoogleCloud => Ok(Self::GoogleCloud {
+                service_account_json: Self::get_required_field(
+                    provider,
+                    map,
+                    "serviceAccountJson",
+                    "Service Account JSON",
+                )?,
+                project_id: Self::get_required_field(provider, map, "projectId", "Project ID")?,
+            }),
+            #[cfg(feature = "namecheap")]
+            ProviderType::Namecheap => Ok(Self::Namecheap {
+                api_user: Self::get_required_field(provider, map, "apiUser", "API User")?,
+                api_key: Self::get_required_field(provider, map, "apiKey", "API Key")?,
+                username: Self::get_required_field(provider, map, "username", "Username")?,
+                client_ip: Self::get_required_field(provider, map, "clientIp", "Client IP")?,
+            }),
+            #[cfg(feature = "hetzner")]
+            ProviderType::Hetzner => Ok(Self::Hetzner {
+                api_token: Self::get_required_field(provider, map, "apiToken", "API Token")?,
+            }),
+            #[cfg(feature = "rfc2136")]
+            ProviderType::Rfc2136 => Ok(Self::Rfc2136 {
+                server: Self::get_required_field(provider, map, "server", "Server")?,
+                zone: Self::get_required_field(provider, map, "zone", "Zone")?,
+                tsig_key_name: Self::get_required_field(
+                    provider,
+                    map,
+                    "tsigKeyName",
+                    "TSIG Key Name",
+                )?,
+                // 算法可选，留空时使用 hmac-sha256
+                tsig_algorithm: map
+                    .get("tsigAlgorithm")
+                    .map(|a| a.trim().to_string())
+                    .filter(|a| !a.is_empty())
+                    .unwrap_or_else(|| "hmac-sha256".to_string()),
+                tsig_secret: Self::get_required_field(provider, map, "tsigSecret", "TSIG Secret")?,
+            }),
+            #[cfg(feature = "desec")]
+            ProviderType::DeSEC => Ok(Self::DeSEC {
+                token: Self::get_required_field(provider, map, "token", "API Token")?,
+            }),
             #[allow(unreachable_patterns)]
             _ => Err(CredentialValidationError::InvalidFormat {
                 provider: provider.clone(),
@@ -502,6 +895,32 @@ impl ProviderCredentials {
         }
     }
 
+    /// Cloudflare 凭证：API Token 与 Global API Key（邮箱 + Key）必须且只能提供一种
+    #[cfg(feature = "cloudflare")]
+    fn cloudflare_from_map(
+        provider: &ProviderType,
+        map: &std::collections::HashMap<String, String>,
+    ) -> Result<Self, CredentialValidationError> {
+        let has = |key: &str| map.get(key).is_some_and(|v| !v.trim().is_empty());
+        let uses_global_key = has("email") || has("apiKey");
+
+        match (has("apiToken"), uses_global_key) {
+            (true, true) => Err(CredentialValidationError::InvalidFormat {
+                provider: provider.clone(),
+                field: "apiToken".to_string(),
+                label: "API Token".to_string(),
+                reason: "API Token 与 Global API Key 只能填写一种".to_string(),
+            }),
+            (false, true) => Ok(Self::CloudflareKey {
+                email: Self::get_required_field(provider, map, "email", "Email")?,
+                api_key: Self::get_required_field(provider, map, "apiKey", "Global API Key")?,
+            }),
+            _ => Ok(Self::Cloudflare {
+                api_token: Self::get_required_field(provider, map, "apiToken", "API Token")?,
+            }),
+        }
+    }
+
     /// 从 HashMap 中获取必需字段，校验非空
     fn get_required_field(
         provider: &ProviderType,
@@ -528,6 +947,11 @@ impl ProviderCredentials {
     pub fn to_map(&self) -> std::collections::HashMap<String, String> {
         match self {
             Self::Cloudflare { api_token } => [("apiToken".to_string(), api_token.clone())].into(),
+            Self::CloudflareKey { email, api_key } => [
+                ("email".to_string(), email.clone()),
+                ("apiKey".to_string(), api_key.clone()),
+            ]
+            .into(),
             Self::Aliyun {
                 access_key_id,
                 access_key_secret,
@@ -552,16 +976,71 @@ impl ProviderCredentials {
                 ("secretAccessKey".to_string(), secret_access_key.clone()),
             ]
             .into(),
+            Self::Route53 {
+                access_key_id,
+                secret_access_key,
+                region,
+            } => [
+                ("accessKeyId".to_string(), access_key_id.clone()),
+                ("secretAccessKey".to_string(), secret_access_key.clone()),
+                ("region".to_string(), region.clone()),
+            ]
+            .into(),
+            Self::GoogleCloud {
+                service_account_json,
+                project_id,
+            } => [
+                (
+                    "serviceAccountJson".to_string(),
+                    service_account_json.clone(),
+                ),
+                ("projectId".to_string(), project_id.clone()),
+            ]
+            .into(),
+            Self::Namecheap {
+                api_user,
+                api_key,
+                username,
+                client_ip,
+            } => [
+                ("apiUser".to_string(), api_user.clone()),
+                ("apiKey".to_string(), api_key.clone()),
+                ("username".to_string(), username.clone()),
+                ("clientIp".to_string(), client_ip.clone()),
+            ]
+            .into(),
+            Self::Hetzner { api_token } => [("apiToken".to_string(), api_token.clone())].into(),
+            Self::Rfc2136 {
+                server,
+                zone,
+                tsig_key_name,
+                tsig_algorithm,
+                tsig_secret,
+            } => [
+                ("server".to_string(), server.clone()),
+                ("zone".to_string(), zone.clone()),
+                ("tsigKeyName".to_string(), tsig_key_name.clone()),
+                ("tsigAlgorithm".to_string(), tsig_algorithm.clone()),
+                ("tsigSecret".to_string(), tsig_secret.clone()),
+            ]
+            .into(),
+            Self::DeSEC { token } => [("token".to_string(), token.clone())].into(),
         }
     }
 
     /// 获取凭证对应的 provider 类型
     pub fn provider_type(&self) -> ProviderType {
         match self {
-            Self::Cloudflare { .. } => ProviderType::Cloudflare,
+            Self::Cloudflare { .. } | Self::CloudflareKey { .. } => ProviderType::Cloudflare,
             Self::Aliyun { .. } => ProviderType::Aliyun,
             Self::Dnspod { .. } => ProviderType::Dnspod,
             Self::Huaweicloud { .. } => ProviderType::Huaweicloud,
+            Self::Route53 { .. } => ProviderType::Route53,
+            Self::GoogleCloud { .. } => ProviderType::GoogleCloud,
+            Self::Namecheap { .. } => ProviderType::Namecheap,
+            Self::Hetzner { .. } => ProviderType::Hetzner,
+            Self::Rfc2136 { .. } => ProviderType::Rfc2136,
+            Self::DeSEC { .. } => ProviderType::DeSEC,
         }
     }
 }
@@ -0,0 +1,503 @@
+//! BIND 区域文件（RFC 1035 master file）导入与导出
+//!
+//! 解析支持 `$ORIGIN`、`$TTL`、相对/绝对名称、括号跨行与 `;` 注释。
+//! SOA 记录由服务商托管，解析时会被跳过，导出时也不会生成。
+
+use crate::error::{ProviderError, Result};
+use crate::providers::common::{
+    format_quoted_txt, full_name_to_relative, normalize_domain_name, parse_ds_value,
+    parse_sshfp_value, parse_tlsa_value, quote_string, record_type_to_string,
+};
+use crate::types::{CreateDnsRecordRequest, DnsRecord, RecordData};
+
+/// 未指定 `$TTL` 且记录未显式给出 TTL 时使用的默认值
+const DEFAULT_TTL: u32 = 3600;
+
+/// 错误信息中的来源标识
+const SOURCE: &str = "zonefile";
+
+fn parse_error(line: usize, detail: impl std::fmt::Display) -> ProviderError {
+    ProviderError::ParseError {
+        provider: SOURCE.to_string(),
+        detail: format!("第 {line} 行: {detail}"),
+    }
+}
+
+// ============ 词法分析 ============
+
+/// 单个词元
+struct Token {
+    text: String,
+    quoted: bool,
+}
+
+/// 一条逻辑记录（括号内的换行已合并）
+struct Entry {
+    line: usize,
+    /// 行首为空白，表示沿用上一条记录的名称
+    inherits_owner: bool,
+    tokens: Vec<Token>,
+}
+
+/// 将区域文件切分为逻辑记录
+fn tokenize(contents: &str) -> Result<Vec<Entry>> {
+    let mut entries = Vec::new();
+    let mut chars = contents.chars().peekable();
+    let mut line = 1;
+    let mut depth = 0usize;
+    let mut at_line_start = true;
+    let mut current: Option<Entry> = None;
+
+    while let Some(c) = chars.next() {
+        if at_line_start && depth == 0 {
+            at_line_start = false;
+            if current.is_none() {
+                current = Some(Entry {
+                    line,
+                    inherits_owner: c == ' ' || c == '\t',
+                    tokens: Vec::new(),
+                });
+            }
+        }
+
+        match c {
+            '\n' => {
+                if depth == 0
+                    && let Some(entry) = current.take()
+                    && !entry.tokens.is_empty()
+                {
+                    entries.push(entry);
+                }
+                line += 1;
+                at_line_start = true;
+            }
+            ' ' | '\t' | '\r' => {}
+            ';' => while chars.next_if(|&c| c != '\n').is_some() {},
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| parse_error(line, "多余的 ')'"))?;
+            }
+            '"' => {
+                let start = line;
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(d) if d.is_ascii_digit() => {
+                                // \DDD 十进制转义
+                                let mut code = d.to_digit(10).unwrap_or(0);
+                                for _ in 0..2 {
+                                    if let Some(d) = chars.next_if(char::is_ascii_digit) {
+                                        code = code * 10 + d.to_digit(10).unwrap_or(0);
+                                    }
+                                }
+                                text.push(char::from_u32(code).unwrap_or('?'));
+                            }
+                            Some(escaped) => text.push(escaped),
+                            None => return Err(parse_error(start, "引号未闭合")),
+                        },
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            text.push(c);
+                        }
+                        None => return Err(parse_error(start, "引号未闭合")),
+                    }
+                }
+                push_token(&mut current, line, text, true);
+            }
+            _ => {
+                let mut text = String::from(c);
+                while let Some(c) = chars
+                    .next_if(|&c| !matches!(c, ' ' | '\t' | '\r' | '\n' | '(' | ')' | ';' | '"'))
+                {
+                    text.push(c);
+                }
+                push_token(&mut current, line, text, false);
+            }
+        }
+    }
+
+    if depth > 0 {
+        let start = current.as_ref().map_or(line, |e| e.line);
+        return Err(parse_error(start, "括号未闭合"));
+    }
+    if let Some(entry) = current
+        && !entry.tokens.is_empty()
+    {
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+fn push_token(current: &mut Option<Entry>, line: usize, text: String, quoted: bool) {
+    current
+        .get_or_insert_with(|| Entry {
+            line,
+            inherits_owner: false,
+            tokens: Vec::new(),
+        })
+        .tokens
+        .push(Token { text, quoted });
+}
+
+// ============ 语法解析 ============
+
+/// 解析 TTL，支持 BIND 时间单位（如 `1h30m`、`2d`）
+fn parse_ttl(value: &str) -> Option<u32> {
+    if let Ok(ttl) = value.parse() {
+        return Some(ttl);
+    }
+    if !value.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let mut total: u32 = 0;
+    let mut number: u32 = 0;
+    let mut has_number = false;
+    for c in value.chars() {
+        if let Some(d) = c.to_digit(10) {
+            number = number.checked_mul(10)?.checked_add(d)?;
+            has_number = true;
+            continue;
+        }
+        let unit = match c.to_ascii_lowercase() {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            'd' => 86400,
+            'w' => 604_800,
+            _ => return None,
+        };
+        if !has_number {
+            return None;
+        }
+        total = total.checked_add(number.checked_mul(unit)?)?;
+        number = 0;
+        has_number = false;
+    }
+    if has_number {
+        return None;
+    }
+    Some(total)
+}
+
+/// 将名称按当前 origin 展开为完整域名（不带末尾点）
+fn absolute_name(name: &str, origin: &str) -> String {
+    if name == "@" {
+        origin.to_string()
+    } else if name.ends_with('.') {
+        normalize_domain_name(name)
+    } else if origin.is_empty() {
+        name.to_string()
+    } else {
+        format!("{name}.{origin}")
+    }
+}
+
+/// 解析区域文件为创建记录请求
+///
+/// `origin` 为区域名称，返回的记录名称相对于该区域（根域为 `@`）。
+/// `domain_id` 留空，由调用方按目标 Provider 填充。
+pub fn parse_zone_file(contents: &str, origin: &str) -> Result<Vec<CreateDnsRecordRequest>> {
+    let zone = normalize_domain_name(origin).to_lowercase();
+    let mut current_origin = zone.clone();
+    let mut default_ttl: Option<u32> = None;
+    let mut last_ttl: Option<u32> = None;
+    let mut last_owner: Option<String> = None;
+    let mut requests = Vec::new();
+
+    for entry in tokenize(contents)? {
+        let line = entry.line;
+        let mut tokens = entry.tokens.iter().peekable();
+
+        // 控制指令
+        if !entry.inherits_owner
+            && let Some(first) = tokens.peek()
+            && !first.quoted
+            && first.text.starts_with('$')
+        {
+            let directive = first.text.to_uppercase();
+            tokens.next();
+            let arg = tokens
+                .next()
+                .ok_or_else(|| parse_error(line, format!("{directive} 缺少参数")))?;
+            match directive.as_str() {
+                "$ORIGIN" => {
+                    current_origin = absolute_name(&arg.text, &current_origin).to_lowercase();
+                }
+                "$TTL" => {
+                    default_ttl =
+                        Some(parse_ttl(&arg.text).ok_or_else(|| {
+                            parse_error(line, format!("无效的 TTL: {}", arg.text))
+                        })?);
+                }
+                _ => return Err(parse_error(line, format!("不支持的指令: {directive}"))),
+            }
+            continue;
+        }
+
+        // 名称
+        let owner = if entry.inherits_owner {
+            last_owner
+                .clone()
+                .ok_or_else(|| parse_error(line, "缺少记录名称"))?
+        } else {
+            let name = tokens
+                .next()
+                .ok_or_else(|| parse_error(line, "缺少记录名称"))?;
+            absolute_name(&name.text, &current_origin).to_lowercase()
+        };
+        last_owner = Some(owner.clone());
+
+        // TTL 与 class（顺序任意，均可省略）
+        let mut ttl = None;
+        while let Some(token) = tokens.peek() {
+            let upper = token.text.to_uppercase();
+            if matches!(upper.as_str(), "IN" | "CH" | "HS" | "CS") {
+                if upper != "IN" {
+                    return Err(parse_error(line, format!("不支持的 class: {}", token.text)));
+                }
+            } else if let Some(value) = parse_ttl(&token.text) {
+                ttl = Some(value);
+            } else {
+                break;
+            }
+            tokens.next();
+        }
+
+        let record_type = tokens
+            .next()
+            .ok_or_else(|| parse_error(line, "缺少记录类型"))?
+            .text
+            .to_uppercase();
+        let rdata: Vec<&Token> = tokens.collect();
+
+        let ttl = ttl.or(default_ttl).or(last_ttl).unwrap_or(DEFAULT_TTL);
+        last_ttl = Some(ttl);
+
+        if record_type == "SOA" {
+            continue;
+        }
+
+        let data = parse_rdata(&record_type, &rdata, &current_origin, line)?;
+        if owner != zone && !owner.ends_with(&format!(".{zone}")) {
+            return Err(parse_error(line, format!("{owner} 不在区域 {zone} 内")));
+        }
+
+        requests.push(CreateDnsRecordRequest {
+            domain_id: String::new(),
+            name: full_name_to_relative(&owner, &zone),
+            ttl,
+            data,
+            proxied: None,
+            comment: None,
+            tags: Vec::new(),
+            routing_policy: None,
+        });
+    }
+
+    Ok(requests)
+}
+
+/// 解析记录数据部分
+fn parse_rdata(
+    record_type: &str,
+    rdata: &[&Token],
+    origin: &str,
+    line: usize,
+) -> Result<RecordData> {
+    let expect = |count: usize| {
+        if rdata.len() == count {
+            Ok(())
+        } else {
+            Err(parse_error(
+                line,
+                format!(
+                    "{record_type} 记录需要 {count} 个字段，实际为 {}",
+                    rdata.len()
+                ),
+            ))
+        }
+    };
+    let number = |index: usize, field: &str| -> Result<u16> {
+        rdata[index]
+            .text
+            .parse()
+            .map_err(|_| parse_error(line, format!("无效的 {field}: {}", rdata[index].text)))
+    };
+    let name = |index: usize| absolute_name(&rdata[index].text, origin);
+    let joined = || {
+        rdata
+            .iter()
+            .map(|t| t.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    // 将通用解析错误补充上行号
+    let with_line = |e: ProviderError| match e {
+        ProviderError::ParseError { detail, .. } => parse_error(line, detail),
+        other => other,
+    };
+
+    let data = match record_type {
+        "A" | "AAAA" => {
+            expect(1)?;
+            let address = rdata[0].text.clone();
+            let valid = if record_type == "A" {
+                address.parse::<std::net::Ipv4Addr>().is_ok()
+            } else {
+                address.parse::<std::net::Ipv6Addr>().is_ok()
+            };
+            if !valid {
+                return Err(parse_error(line, format!("无效的地址: {address}")));
+            }
+            if record_type == "A" {
+                RecordData::A { address }
+            } else {
+                RecordData::AAAA { address }
+            }
+        }
+        "CNAME" => {
+            expect(1)?;
+            RecordData::CNAME { target: name(0) }
+        }
+        "ALIAS" | "ANAME" => {
+            expect(1)?;
+            RecordData::ALIAS { target: name(0) }
+        }
+        "NS" => {
+            expect(1)?;
+            RecordData::NS {
+                nameserver: name(0),
+            }
+        }
+        "MX" => {
+            expect(2)?;
+            RecordData::MX {
+                priority: number(0, "priority")?,
+                exchange: name(1),
+            }
+        }
+        "SRV" => {
+            expect(4)?;
+            RecordData::SRV {
+                priority: number(0, "priority")?,
+                weight: number(1, "weight")?,
+                port: number(2, "port")?,
+                target: name(3),
+            }
+        }
+        "TXT" | "SPF" => {
+            if rdata.is_empty() {
+                return Err(parse_error(line, "TXT 记录缺少内容"));
+            }
+            // 多个字符串按 DNS 语义直接拼接
+            RecordData::TXT {
+                text: rdata.iter().map(|t| t.text.as_str()).collect(),
+            }
+        }
+        "CAA" => {
+            expect(3)?;
+            RecordData::CAA {
+                flags: rdata[0]
+                    .text
+                    .parse()
+                    .map_err(|_| parse_error(line, format!("无效的 flags: {}", rdata[0].text)))?,
+                tag: rdata[1].text.clone(),
+                value: rdata[2].text.clone(),
+            }
+        }
+        "TLSA" => parse_tlsa_value(&joined(), SOURCE).map_err(with_line)?,
+        "SSHFP" => parse_sshfp_value(&joined(), SOURCE).map_err(with_line)?,
+        "DS" => parse_ds_value(&joined(), SOURCE).map_err(with_line)?,
+        _ => {
+            return Err(parse_error(
+                line,
+                format!("不支持的记录类型: {record_type}"),
+            ));
+        }
+    };
+    Ok(data)
+}
+
+// ============ 导出 ============
+
+/// 将记录导出为区域文件文本
+///
+/// 输出以 `$ORIGIN` 开头，每条记录显式写出 TTL；记录按名称与类型排序，便于纳入版本管理后 diff。
+pub fn records_to_zone_file(records: &[DnsRecord], origin: &str) -> String {
+    let zone = normalize_domain_name(origin).to_lowercase();
+
+    let mut lines: Vec<(String, &'static str, String, u32)> = records
+        .iter()
+        .map(|record| {
+            let name = owner_name(&record.name, &zone);
+            let record_type = record_type_to_string(&record.data.record_type());
+            (name, record_type, format_rdata(&record.data), record.ttl)
+        })
+        .collect();
+    lines.sort_by(|a, b| {
+        owner_sort_key(&a.0)
+            .cmp(&owner_sort_key(&b.0))
+            .then(a.1.cmp(b.1))
+            .then(a.2.cmp(&b.2))
+    });
+
+    let mut output = format!("$ORIGIN {zone}.\n");
+    for (name, record_type, rdata, ttl) in lines {
+        output.push_str(&format!("{name}\t{ttl}\tIN\t{record_type}\t{rdata}\n"));
+    }
+    output
+}
+
+/// 记录名称转换为相对 origin 的写法（根域为 `@`）
+fn owner_name(name: &str, zone: &str) -> String {
+    let name = normalize_domain_name(name).to_lowercase();
+    if name.is_empty() || name == "@" {
+        "@".to_string()
+    } else if name == zone || name.ends_with(&format!(".{zone}")) {
+        full_name_to_relative(&name, zone)
+    } else {
+        name
+    }
+}
+
+/// 根域排在最前
+fn owner_sort_key(name: &str) -> (bool, &str) {
+    (name != "@", name)
+}
+
+/// 目标域名统一写为绝对名称
+fn absolute_target(target: &str) -> String {
+    format!("{}.", normalize_domain_name(target))
+}
+
+/// 格式化记录数据部分
+fn format_rdata(data: &RecordData) -> String {
+    match data {
+        RecordData::A { address } | RecordData::AAAA { address } => address.clone(),
+        RecordData::CNAME { target } | RecordData::ALIAS { target } => absolute_target(target),
+        RecordData::NS { nameserver } => absolute_target(nameserver),
+        RecordData::MX { priority, exchange } => {
+            format!("{priority} {}", absolute_target(exchange))
+        }
+        RecordData::SRV {
+            priority,
+            weight,
+            port,
+            target,
+        } => format!("{priority} {weight} {port} {}", absolute_target(target)),
+        RecordData::TXT { text } => format_quoted_txt(text),
+        RecordData::CAA { flags, tag, value } => {
+            format!("{flags} {tag} {}", quote_string(value))
+        }
+        RecordData::TLSA { .. } | RecordData::SSHFP { .. } | RecordData::DS { .. } => {
+            data.display_value()
+        }
+    }
+}
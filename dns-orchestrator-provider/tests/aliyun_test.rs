@@ -151,6 +151,7 @@ macro_rules! crud_test {
                 page_size: 100,
                 keyword: Some(record_name.clone()),
                 record_type: None,
+                cursor: None,
             };
             if let Ok(response) = ctx.provider.list_records(&domain_id, &cleanup_params).await {
                 for record in response.items {
@@ -168,6 +169,9 @@ macro_rules! crud_test {
                 ttl: 600,
                 data: create_data,
                 proxied: None,
+                comment: None,
+                tags: Vec::new(),
+                routing_policy: None,
             };
 
             let create_result = ctx.provider.create_record(&create_req).await;
@@ -187,6 +191,7 @@ macro_rules! crud_test {
                 page_size: 100,
                 keyword: Some(record_name.clone()),
                 record_type: None,
+                cursor: None,
             };
 
             let list_result = ctx.provider.list_records(&domain_id, &search_params).await;
@@ -203,6 +208,8 @@ macro_rules! crud_test {
                 ttl: 900,
                 data: update_data,
                 proxied: None,
+                comment: None,
+                tags: Vec::new(),
             };
 
             let update_result = ctx.provider.update_record(&record_id, &update_req).await;
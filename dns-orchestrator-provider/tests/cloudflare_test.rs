@@ -10,7 +10,8 @@ mod common;
 
 use common::{TestContext, TestRecordType, get_test_record_data};
 use dns_orchestrator_provider::{
-    CreateDnsRecordRequest, PaginationParams, RecordQueryParams, UpdateDnsRecordRequest,
+    CloudflareProvider, CreateDnsRecordRequest, PaginationParams, RecordQueryParams,
+    UpdateDnsRecordRequest,
 };
 
 // ============ 基础测试 ============
@@ -89,6 +90,103 @@ async fn test_cloudflare_list_records() {
     );
 }
 
+#[tokio::test]
+#[ignore]
+async fn test_cloudflare_get_dnssec_status() {
+    skip_if_no_credentials!("CLOUDFLARE_API_TOKEN", "TEST_DOMAIN");
+
+    let mut ctx = TestContext::cloudflare().expect("创建测试上下文失败");
+    let domain_id = ctx.find_domain_id().await.expect("找不到测试域名");
+
+    let result = ctx.provider.get_dnssec_status(&domain_id).await;
+    assert!(result.is_ok(), "get_dnssec_status 调用失败: {:?}", result);
+
+    let status = result.unwrap();
+    println!(
+        "✓ get_dnssec_status 测试通过: {:?}，DS 记录 {} 条",
+        status.state,
+        status.ds_records.len()
+    );
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_cloudflare_list_record_changes() {
+    skip_if_no_credentials!("CLOUDFLARE_API_TOKEN", "TEST_DOMAIN");
+
+    let mut ctx = TestContext::cloudflare().expect("创建测试上下文失败");
+    let domain_id = ctx.find_domain_id().await.expect("找不到测试域名");
+
+    let since = chrono::Utc::now() - chrono::Duration::days(7);
+    let result = ctx
+        .provider
+        .list_record_changes(&domain_id, Some(since))
+        .await;
+    assert!(result.is_ok(), "list_record_changes 调用失败: {:?}", result);
+
+    let changes = result.unwrap();
+    assert!(
+        changes.iter().all(|c| c.timestamp >= since),
+        "返回了早于 since 的变更"
+    );
+    println!(
+        "✓ list_record_changes 测试通过，共 {} 条变更",
+        changes.len()
+    );
+}
+
+// ============ 批量代理开关测试 ============
+
+#[tokio::test]
+#[ignore]
+async fn test_cloudflare_bulk_set_proxied() {
+    skip_if_no_credentials!("CLOUDFLARE_API_TOKEN", "TEST_DOMAIN");
+
+    let mut ctx = TestContext::cloudflare().expect("创建测试上下文失败");
+    let domain_id = ctx.find_domain_id().await.expect("找不到测试域名");
+    let provider = CloudflareProvider::new(std::env::var("CLOUDFLARE_API_TOKEN").unwrap());
+
+    // 代理记录名称不能包含下划线
+    let record_name = common::generate_test_record_name()
+        .trim_start_matches('_')
+        .to_string();
+    let (create_data, _) = get_test_record_data(TestRecordType::A);
+
+    // 1. 创建未代理的记录
+    let create_req = CreateDnsRecordRequest {
+        domain_id: domain_id.clone(),
+        name: record_name,
+        ttl: 1,
+        data: create_data,
+        proxied: Some(false),
+        comment: None,
+        tags: Vec::new(),
+        routing_policy: None,
+    };
+    let created = ctx.provider.create_record(&create_req).await;
+    assert!(created.is_ok(), "create_record 失败: {:?}", created);
+    let created = created.unwrap();
+    println!("  ✓ 创建成功");
+
+    // 2. 批量开启代理
+    let result = provider
+        .bulk_set_proxied(&domain_id, std::slice::from_ref(&created.id), true)
+        .await;
+    assert!(result.is_ok(), "bulk_set_proxied 调用失败: {:?}", result);
+    let result = result.unwrap();
+    assert_eq!(result.success_count, 1, "失败项: {:?}", result.failures);
+    assert_eq!(
+        result.updated_records[0].proxied,
+        Some(true),
+        "记录应已开启代理"
+    );
+    println!("  ✓ 开启代理成功");
+
+    // 3. 清理
+    ctx.cleanup_record(&created.id, &domain_id).await;
+    println!("✓ bulk_set_proxied 测试通过");
+}
+
 // ============ 清理测试 ============
 
 /// 清理所有残留的测试记录（手动运行）
@@ -127,6 +225,7 @@ macro_rules! crud_test {
                 page_size: 100,
                 keyword: Some(record_name.clone()),
                 record_type: None,
+                cursor: None,
             };
             if let Ok(response) = ctx.provider.list_records(&domain_id, &cleanup_params).await {
                 for record in response.items {
@@ -144,6 +243,9 @@ macro_rules! crud_test {
                 ttl: 600,
                 data: create_data,
                 proxied: None,
+                comment: None,
+                tags: Vec::new(),
+                routing_policy: None,
             };
 
             let create_result = ctx.provider.create_record(&create_req).await;
@@ -163,6 +265,7 @@ macro_rules! crud_test {
                 page_size: 100,
                 keyword: Some(record_name.clone()),
                 record_type: None,
+                cursor: None,
             };
 
             let list_result = ctx.provider.list_records(&domain_id, &search_params).await;
@@ -179,6 +282,8 @@ macro_rules! crud_test {
                 ttl: 300,
                 data: update_data,
                 proxied: None,
+                comment: None,
+                tags: Vec::new(),
             };
 
             let update_result = ctx.provider.update_record(&record_id, &update_req).await;
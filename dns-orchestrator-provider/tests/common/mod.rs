@@ -199,6 +199,124 @@ impl TestContext {
         })
     }
 
+    /// 创建 Route 53 测试上下文
+    pub fn route53() -> Option<Self> {
+        let access_key_id = env::var("ROUTE53_ACCESS_KEY_ID").ok()?;
+        let secret_access_key = env::var("ROUTE53_SECRET_ACCESS_KEY").ok()?;
+        let region = env::var("ROUTE53_REGION").unwrap_or_else(|_| "us-east-1".to_string());
+        let domain = env::var("TEST_DOMAIN").ok()?;
+
+        let credentials = ProviderCredentials::Route53 {
+            access_key_id,
+            secret_access_key,
+            region,
+        };
+        let provider = create_provider(credentials).ok()?;
+
+        Some(Self {
+            provider,
+            domain,
+            domain_id: None,
+        })
+    }
+
+    /// 创建 Google Cloud DNS 测试上下文
+    pub fn gcloud() -> Option<Self> {
+        let service_account_json = env::var("GCLOUD_SERVICE_ACCOUNT_JSON").ok()?;
+        let project_id = env::var("GCLOUD_PROJECT_ID").ok()?;
+        let domain = env::var("TEST_DOMAIN").ok()?;
+
+        let credentials = ProviderCredentials::GoogleCloud {
+            service_account_json,
+            project_id,
+        };
+        let provider = create_provider(credentials).ok()?;
+
+        Some(Self {
+            provider,
+            domain,
+            domain_id: None,
+        })
+    }
+
+    /// 创建 Namecheap 测试上下文
+    pub fn namecheap() -> Option<Self> {
+        let api_user = env::var("NAMECHEAP_API_USER").ok()?;
+        let api_key = env::var("NAMECHEAP_API_KEY").ok()?;
+        let username = env::var("NAMECHEAP_USERNAME").unwrap_or_else(|_| api_user.clone());
+        let client_ip = env::var("NAMECHEAP_CLIENT_IP").ok()?;
+        let domain = env::var("TEST_DOMAIN").ok()?;
+
+        let credentials = ProviderCredentials::Namecheap {
+            api_user,
+            api_key,
+            username,
+            client_ip,
+        };
+        let provider = create_provider(credentials).ok()?;
+
+        Some(Self {
+            provider,
+            domain,
+            domain_id: None,
+        })
+    }
+
+    /// 创建 Hetzner 测试上下文
+    pub fn hetzner() -> Option<Self> {
+        let api_token = env::var("HETZNER_API_TOKEN").ok()?;
+        let domain = env::var("TEST_DOMAIN").ok()?;
+
+        let credentials = ProviderCredentials::Hetzner { api_token };
+        let provider = create_provider(credentials).ok()?;
+
+        Some(Self {
+            provider,
+            domain,
+            domain_id: None,
+        })
+    }
+
+    /// 创建 deSEC 测试上下文
+    pub fn desec() -> Option<Self> {
+        let token = env::var("DESEC_TOKEN").ok()?;
+        let domain = env::var("TEST_DOMAIN").ok()?;
+
+        let credentials = ProviderCredentials::DeSEC { token };
+        let provider = create_provider(credentials).ok()?;
+
+        Some(Self {
+            provider,
+            domain,
+            domain_id: None,
+        })
+    }
+
+    /// 创建 RFC 2136 测试上下文（测试域名即为区域）
+    pub fn rfc2136() -> Option<Self> {
+        let server = env::var("RFC2136_SERVER").ok()?;
+        let tsig_key_name = env::var("RFC2136_TSIG_KEY_NAME").ok()?;
+        let tsig_algorithm =
+            env::var("RFC2136_TSIG_ALGORITHM").unwrap_or_else(|_| "hmac-sha256".to_string());
+        let tsig_secret = env::var("RFC2136_TSIG_SECRET").ok()?;
+        let domain = env::var("TEST_DOMAIN").ok()?;
+
+        let credentials = ProviderCredentials::Rfc2136 {
+            server,
+            zone: domain.clone(),
+            tsig_key_name,
+            tsig_algorithm,
+            tsig_secret,
+        };
+        let provider = create_provider(credentials).ok()?;
+
+        Some(Self {
+            provider,
+            domain,
+            domain_id: None,
+        })
+    }
+
     /// 查找测试域名的 domain_id
     pub async fn find_domain_id(&mut self) -> Option<String> {
         if self.domain_id.is_some() {
@@ -229,6 +347,9 @@ impl TestContext {
                 text: "integration-test".to_string(),
             },
             proxied: None,
+            comment: None,
+            tags: Vec::new(),
+            routing_policy: None,
         };
 
         self.provider.create_record(&request).await.ok()
@@ -246,6 +367,7 @@ impl TestContext {
             page_size: 100,
             keyword: Some("_test-".to_string()),
             record_type: None,
+            cursor: None,
         };
 
         if let Ok(response) = self.provider.list_records(domain_id, &params).await {
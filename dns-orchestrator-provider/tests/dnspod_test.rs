@@ -89,6 +89,32 @@ async fn test_dnspod_list_records() {
     );
 }
 
+#[tokio::test]
+#[ignore]
+async fn test_dnspod_list_record_changes() {
+    skip_if_no_credentials!("DNSPOD_SECRET_ID", "DNSPOD_SECRET_KEY", "TEST_DOMAIN");
+
+    let mut ctx = TestContext::dnspod().expect("创建测试上下文失败");
+    let domain_id = ctx.find_domain_id().await.expect("找不到测试域名");
+
+    let since = chrono::Utc::now() - chrono::Duration::days(7);
+    let result = ctx
+        .provider
+        .list_record_changes(&domain_id, Some(since))
+        .await;
+    assert!(result.is_ok(), "list_record_changes 调用失败: {:?}", result);
+
+    let changes = result.unwrap();
+    assert!(
+        changes.iter().all(|c| c.timestamp >= since),
+        "返回了早于 since 的变更"
+    );
+    println!(
+        "✓ list_record_changes 测试通过，共 {} 条变更",
+        changes.len()
+    );
+}
+
 // ============ 清理测试 ============
 
 /// 清理所有残留的测试记录（手动运行）
@@ -127,6 +153,7 @@ macro_rules! crud_test {
                 page_size: 100,
                 keyword: None,
                 record_type: None,
+                cursor: None,
             };
             if let Ok(response) = ctx.provider.list_records(&domain_id, &list_params).await {
                 for record in response.items {
@@ -144,6 +171,9 @@ macro_rules! crud_test {
                 ttl: 600,
                 data: create_data,
                 proxied: None,
+                comment: None,
+                tags: Vec::new(),
+                routing_policy: None,
             };
 
             let create_result = ctx.provider.create_record(&create_req).await;
@@ -172,6 +202,8 @@ macro_rules! crud_test {
                 ttl: 1200, // DNSPod 要求 TTL >= 600
                 data: update_data,
                 proxied: None,
+                comment: None,
+                tags: Vec::new(),
             };
 
             let update_result = ctx.provider.update_record(&record_id, &update_req).await;
@@ -26,6 +26,12 @@
 //! 
 //!     terminal.draw(|f| view::render(&app , f))       // 渲染 UI
 //!     if app.should_quit{ break }                     // 检查 APP 是否应该退出
+//!     while let Some(msg) = runner.try_recv() {       // 取回已完成的后台任务结果
+//!         update::update(&mut app , msg)
+//!     }
+//!     for task in app.take_tasks() {                  // 把 Update 登记的后台任务交给 TaskRunner
+//!         runner.spawn(task)
+//!     }
 //!     if let Some(event) = poll_event() {             // 轮询获取输入，在此等待 100ms
 //!                                                     // 若用户按键，返回 Some(Event::Key(...))，否则为 None
 //!         let msg = handle_event(event , &app);           // 接收原始事件并分发消息
@@ -33,18 +39,25 @@
 //!     }
 //! }
 
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
 
+use crate::backend::TaskRunner;
 use crate::event;
+use crate::message::AppMessage;
 use crate::model::App;
 use crate::update;
 use crate::util::Term;
 use crate::view;
 
+/// 事件轮询超时，同时也是加载动画的帧间隔
+const TICK_RATE: Duration = Duration::from_millis(100);
+
 /// 运行应用主循环
-pub fn run(terminal: &mut Term, app: &mut App) -> Result<()> {
+pub fn run(terminal: &mut Term, app: &mut App, runner: &TaskRunner) -> Result<()> {
+    let mut last_tick = Instant::now();
+
     loop {
         // 1. 渲染 UI
         terminal.draw(|frame| {
@@ -56,12 +69,33 @@ pub fn run(terminal: &mut Term, app: &mut App) -> Result<()> {
             break;
         }
 
-        // 3. 轮询事件（100ms 超时）
-        if let Some(event) = event::poll_event(Duration::from_millis(100))? {
-            // 4. 处理事件，获取消息
+        // 3. 处理已完成的后台任务结果
+        while let Some(msg) = runner.try_recv() {
+            update::update(app, msg);
+        }
+
+        // 清除已过期的短暂状态消息
+        if app.status_expired() {
+            update::update(app, AppMessage::ClearStatus);
+        }
+
+        // 有后台任务执行时，定时推进加载动画
+        if app.is_busy() && last_tick.elapsed() >= TICK_RATE {
+            update::update(app, AppMessage::Tick);
+            last_tick = Instant::now();
+        }
+
+        // 4. 派发 Update 层登记的后台任务
+        for task in app.take_tasks() {
+            runner.spawn(task);
+        }
+
+        // 5. 轮询事件（100ms 超时）
+        if let Some(event) = event::poll_event(TICK_RATE)? {
+            // 6. 处理事件，获取消息
             let msg = event::handle_event(event, app);
 
-            // 5. 更新状态
+            // 7. 更新状态
             update::update(app, msg);
         }
     }
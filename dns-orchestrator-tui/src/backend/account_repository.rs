@@ -12,7 +12,7 @@ use std::sync::Mutex;
 use tokio::fs;
 
 /// 获取配置目录路径
-fn get_config_dir() -> PathBuf {
+pub(super) fn get_config_dir() -> PathBuf {
     dirs::config_dir()
         .unwrap_or_else(|| PathBuf::from("."))
         .join("dns-orchestrator-tui")
@@ -1,35 +1,96 @@
 //! 配置服务
+//!
+//! 将设置页面的选项（主题、语言、分页模式）保存到配置目录下的 `config.toml`
 
-use anyhow::Result;
+use std::fs;
+use std::path::PathBuf;
 
-use crate::view::theme::Theme;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::account_repository::get_config_dir;
+use crate::model::{Language, PaginationMode, SettingsState, Theme};
+use crate::util::system_language;
 
 /// 应用配置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct AppConfig {
     pub theme: Theme,
-    pub language: String,
+    pub language: Language,
     pub pagination_mode: PaginationMode,
 }
 
-/// 分页模式
-#[derive(Debug, Clone, Copy, Default)]
-pub enum PaginationMode {
-    #[default]
-    Paginated,
-    Infinite,
+impl AppConfig {
+    /// 从设置页面状态生成配置
+    pub fn from_settings(settings: &SettingsState) -> Self {
+        Self {
+            theme: settings.theme,
+            language: settings.language,
+            pagination_mode: settings.pagination_mode,
+        }
+    }
+
+    /// 转换为设置页面状态
+    pub fn into_settings(self) -> SettingsState {
+        SettingsState {
+            theme: self.theme,
+            language: self.language,
+            pagination_mode: self.pagination_mode,
+            ..SettingsState::new()
+        }
+    }
+}
+
+/// 配置文件格式
+///
+/// 字段均为可选，缺失或无法识别的值使用默认值
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct ConfigFile {
+    theme: Option<String>,
+    language: Option<String>,
+    pagination_mode: Option<String>,
 }
 
-impl Default for AppConfig {
-    fn default() -> Self {
+impl From<ConfigFile> for AppConfig {
+    fn from(file: ConfigFile) -> Self {
         Self {
-            theme: Theme::Dark,
-            language: "en-US".to_string(),
-            pagination_mode: PaginationMode::default(),
+            theme: file
+                .theme
+                .as_deref()
+                .and_then(Theme::from_code)
+                .unwrap_or_default(),
+            // 未显式设置语言时，按系统 locale 推断
+            language: file
+                .language
+                .as_deref()
+                .and_then(Language::from_code)
+                .or_else(system_language)
+                .unwrap_or_default(),
+            pagination_mode: file
+                .pagination_mode
+                .as_deref()
+                .and_then(PaginationMode::from_code)
+                .unwrap_or_default(),
         }
     }
 }
 
+impl From<&AppConfig> for ConfigFile {
+    fn from(config: &AppConfig) -> Self {
+        Self {
+            theme: Some(config.theme.code().to_string()),
+            language: Some(config.language.code().to_string()),
+            pagination_mode: Some(config.pagination_mode.code().to_string()),
+        }
+    }
+}
+
+/// 获取配置文件路径
+fn get_config_file() -> PathBuf {
+    get_config_dir().join("config.toml")
+}
+
 /// 配置服务 trait
 pub trait ConfigService: Send + Sync {
     /// 加载配置
@@ -39,17 +100,31 @@ pub trait ConfigService: Send + Sync {
     fn save(&self, config: &AppConfig) -> Result<()>;
 }
 
-/// 本地配置服务
+/// 本地配置服务（TOML 文件）
 pub struct LocalConfigService;
 
 impl ConfigService for LocalConfigService {
     fn load(&self) -> Result<AppConfig> {
-        // TODO: 从配置文件加载
-        Ok(AppConfig::default())
+        let path = get_config_file();
+        if !path.exists() {
+            return Ok(ConfigFile::default().into());
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let file: ConfigFile = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        Ok(file.into())
     }
 
-    fn save(&self, _config: &AppConfig) -> Result<()> {
-        // TODO: 保存到配置文件
-        Ok(())
+    fn save(&self, config: &AppConfig) -> Result<()> {
+        let path = get_config_file();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+
+        let content = toml::to_string_pretty(&ConfigFile::from(config))?;
+        fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,96 @@
+//! 核心库类型与 TUI 模型之间的转换
+//!
+//! TUI 模型只覆盖部分服务商与记录类型，无法表示的数据返回 `None`，由调用方跳过
+
+use dns_orchestrator_core::types::{
+    Account as CoreAccount, AccountStatus as CoreAccountStatus, AppDomain,
+};
+use dns_orchestrator_provider::{
+    DnsRecord as ProviderDnsRecord, DomainStatus as ProviderDomainStatus,
+    ProviderType as CoreProviderType, RecordData as ProviderRecordData,
+};
+
+use crate::model::domain::{
+    Account, AccountStatus, DnsRecord, Domain, DomainStatus, ProviderType, RecordData,
+};
+
+/// 转换服务商类型
+pub fn provider_type(provider: &CoreProviderType) -> Option<ProviderType> {
+    match provider {
+        CoreProviderType::Cloudflare => Some(ProviderType::Cloudflare),
+        CoreProviderType::Aliyun => Some(ProviderType::Aliyun),
+        CoreProviderType::Dnspod => Some(ProviderType::Dnspod),
+        CoreProviderType::Huaweicloud => Some(ProviderType::Huaweicloud),
+        _ => None,
+    }
+}
+
+/// 转换账号
+pub fn account(account: CoreAccount) -> Option<Account> {
+    Some(Account {
+        provider: provider_type(&account.provider)?,
+        status: account.status.map(|status| match status {
+            CoreAccountStatus::Active => AccountStatus::Active,
+            CoreAccountStatus::Error => AccountStatus::Error,
+        }),
+        created_at: account.created_at.format("%Y-%m-%d").to_string(),
+        updated_at: account.updated_at.format("%Y-%m-%d").to_string(),
+        id: account.id,
+        name: account.name,
+        error: account.error,
+    })
+}
+
+/// 转换域名
+pub fn domain(domain: AppDomain) -> Option<Domain> {
+    Some(Domain {
+        provider: provider_type(&domain.provider)?,
+        status: match domain.status {
+            ProviderDomainStatus::Active => DomainStatus::Active,
+            ProviderDomainStatus::Paused => DomainStatus::Paused,
+            ProviderDomainStatus::Pending => DomainStatus::Pending,
+            ProviderDomainStatus::Error => DomainStatus::Error,
+            ProviderDomainStatus::Unknown => DomainStatus::Unknown,
+        },
+        id: domain.id,
+        name: domain.name,
+        account_id: domain.account_id,
+        record_count: domain.record_count,
+    })
+}
+
+/// 转换 DNS 记录
+pub fn dns_record(record: ProviderDnsRecord) -> Option<DnsRecord> {
+    let data = match record.data {
+        ProviderRecordData::A { address } => RecordData::A { address },
+        ProviderRecordData::AAAA { address } => RecordData::Aaaa { address },
+        ProviderRecordData::CNAME { target } => RecordData::Cname { target },
+        ProviderRecordData::MX { priority, exchange } => RecordData::Mx { priority, exchange },
+        ProviderRecordData::TXT { text } => RecordData::Txt { text },
+        ProviderRecordData::NS { nameserver } => RecordData::Ns { nameserver },
+        ProviderRecordData::SRV {
+            priority,
+            weight,
+            port,
+            target,
+        } => RecordData::Srv {
+            priority,
+            weight,
+            port,
+            target,
+        },
+        ProviderRecordData::CAA { flags, tag, value } => RecordData::Caa { flags, tag, value },
+        _ => return None,
+    };
+
+    Some(DnsRecord {
+        id: record.id,
+        domain_id: record.domain_id,
+        name: record.name,
+        ttl: record.ttl,
+        data,
+        proxied: record.proxied,
+        created_at: record.created_at.map(|t| t.to_rfc3339()),
+        updated_at: record.updated_at.map(|t| t.to_rfc3339()),
+    })
+}
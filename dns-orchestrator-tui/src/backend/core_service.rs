@@ -4,6 +4,7 @@
 //! 提供给 TUI 层使用的统一接口
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use dns_orchestrator_core::services::{
     AccountBootstrapService, AccountLifecycleService, AccountMetadataService,
@@ -15,7 +16,13 @@ use dns_orchestrator_core::CoreResult;
 
 use super::account_repository::JsonAccountRepository;
 use super::credential_service::KeyringCredentialStore;
-use super::domain_metadata_repository::InMemoryDomainMetadataRepository;
+use super::domain_metadata_repository::JsonDomainMetadataRepository;
+
+/// 域名列表缓存有效期
+const DOMAIN_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// 覆盖钥匙串服务名的环境变量（多个安装并存时避免凭证互相覆盖）
+const KEYRING_SERVICE_ENV: &str = "DNS_ORCHESTRATOR_KEYRING_SERVICE";
 
 /// TUI 核心服务
 ///
@@ -33,18 +40,29 @@ impl CoreService {
     /// 创建核心服务实例
     pub fn new() -> Self {
         // 1. 创建基础依赖
-        let credential_store = Arc::new(KeyringCredentialStore::new());
+        let credential_store = Arc::new(
+            std::env::var(KEYRING_SERVICE_ENV)
+                .ok()
+                .filter(|name| !name.is_empty())
+                .map_or_else(
+                    KeyringCredentialStore::new,
+                    KeyringCredentialStore::with_service_name,
+                ),
+        );
         let account_repository = Arc::new(JsonAccountRepository::new());
         let provider_registry = Arc::new(InMemoryProviderRegistry::new());
-        let domain_metadata_repository = Arc::new(InMemoryDomainMetadataRepository::new());
+        let domain_metadata_repository = Arc::new(JsonDomainMetadataRepository::new());
 
         // 2. 创建 ServiceContext（供 DomainService/DnsService 使用）
-        let ctx = Arc::new(ServiceContext::new(
-            credential_store.clone(),
-            account_repository.clone(),
-            provider_registry.clone(),
-            domain_metadata_repository,
-        ));
+        let ctx = Arc::new(
+            ServiceContext::new(
+                credential_store.clone(),
+                account_repository.clone(),
+                provider_registry.clone(),
+                domain_metadata_repository,
+            )
+            .with_domain_cache_ttl(DOMAIN_CACHE_TTL),
+        );
 
         // 3. 创建服务实例
         let metadata_service = Arc::new(AccountMetadataService::new(account_repository));
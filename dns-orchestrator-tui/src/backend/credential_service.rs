@@ -10,7 +10,8 @@ use dns_orchestrator_provider::ProviderCredentials;
 use keyring::Entry;
 use std::sync::Mutex;
 
-const SERVICE_NAME: &str = "dns-orchestrator-tui";
+/// 默认的钥匙串服务名
+const DEFAULT_SERVICE_NAME: &str = "dns-orchestrator-tui";
 const CREDENTIALS_KEY: &str = "__all_credentials__";
 
 /// 基于系统钥匙串的凭证存储
@@ -20,13 +21,21 @@ const CREDENTIALS_KEY: &str = "__all_credentials__";
 /// - macOS: Keychain
 /// - Linux: Secret Service (GNOME Keyring / KWallet)
 pub struct KeyringCredentialStore {
+    /// 钥匙串服务名（不同安装使用不同服务名，避免互相覆盖凭证）
+    service_name: String,
     /// 内存缓存，避免频繁访问钥匙串
     cache: Mutex<CredentialsMap>,
 }
 
 impl KeyringCredentialStore {
     pub fn new() -> Self {
+        Self::with_service_name(DEFAULT_SERVICE_NAME)
+    }
+
+    /// 使用指定的钥匙串服务名创建存储
+    pub fn with_service_name(service_name: impl Into<String>) -> Self {
         Self {
+            service_name: service_name.into(),
             cache: Mutex::new(CredentialsMap::new()),
         }
     }
@@ -100,7 +109,7 @@ impl CredentialStore for KeyringCredentialStore {
     }
 
     async fn load_raw_json(&self) -> CoreResult<String> {
-        let entry = Entry::new(SERVICE_NAME, CREDENTIALS_KEY)
+        let entry = Entry::new(&self.service_name, CREDENTIALS_KEY)
             .map_err(|e| CoreError::CredentialError(format!("Failed to create entry: {e}")))?;
 
         match entry.get_password() {
@@ -113,7 +122,7 @@ impl CredentialStore for KeyringCredentialStore {
     }
 
     async fn save_raw_json(&self, json: &str) -> CoreResult<()> {
-        let entry = Entry::new(SERVICE_NAME, CREDENTIALS_KEY)
+        let entry = Entry::new(&self.service_name, CREDENTIALS_KEY)
             .map_err(|e| CoreError::CredentialError(format!("Failed to create entry: {e}")))?;
 
         entry
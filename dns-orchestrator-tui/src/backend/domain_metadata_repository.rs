@@ -6,36 +6,105 @@
 use async_trait::async_trait;
 use dns_orchestrator_core::traits::DomainMetadataRepository;
 use dns_orchestrator_core::types::{DomainMetadata, DomainMetadataKey, DomainMetadataUpdate};
-use dns_orchestrator_core::CoreResult;
-use std::collections::HashMap;
-use std::sync::Mutex;
+use dns_orchestrator_core::{CoreError, CoreResult};
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use tokio::fs;
+use tokio::sync::{MappedMutexGuard, Mutex, MutexGuard};
 
-/// 内存域名元数据仓库
+use super::account_repository::get_config_dir;
+
+type MetadataMap = HashMap<DomainMetadataKey, DomainMetadata>;
+
+/// 获取域名元数据文件路径（与 accounts.json 同目录）
+fn get_metadata_file() -> PathBuf {
+    get_config_dir().join("domain_metadata.json")
+}
+
+/// 基于 JSON 文件的域名元数据仓库
 ///
-/// 简单实现，数据存储在内存中（重启后丢失）
-/// TODO: 实现 JSON 文件持久化
-pub struct InMemoryDomainMetadataRepository {
-    store: Mutex<HashMap<DomainMetadataKey, DomainMetadata>>,
+/// 文件以 `account_id::domain_id` 为键，首次访问时加载到内存，每次修改后整体写回
+pub struct JsonDomainMetadataRepository {
+    /// 内存缓存（`None` 表示尚未从文件加载）
+    store: Mutex<Option<MetadataMap>>,
 }
 
-impl InMemoryDomainMetadataRepository {
+impl JsonDomainMetadataRepository {
     pub fn new() -> Self {
         Self {
-            store: Mutex::new(HashMap::new()),
+            store: Mutex::new(None),
         }
     }
+
+    /// 获取存储（首次访问时从文件加载）
+    async fn store(&self) -> CoreResult<MappedMutexGuard<'_, MetadataMap>> {
+        let mut guard = self.store.lock().await;
+        if guard.is_none() {
+            *guard = Some(Self::load_from_file().await?);
+        }
+        Ok(MutexGuard::map(guard, |store| {
+            store.get_or_insert_with(HashMap::new)
+        }))
+    }
+
+    /// 从文件加载元数据
+    async fn load_from_file() -> CoreResult<MetadataMap> {
+        let path = get_metadata_file();
+
+        if !path.exists() {
+            return Ok(HashMap::new());
+        }
+
+        let content = fs::read_to_string(&path)
+            .await
+            .map_err(|e| CoreError::StorageError(e.to_string()))?;
+
+        let entries: HashMap<String, DomainMetadata> = serde_json::from_str(&content)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+
+        Ok(entries
+            .into_iter()
+            .filter_map(|(key, metadata)| {
+                DomainMetadataKey::from_storage_key(&key).map(|key| (key, metadata))
+            })
+            .collect())
+    }
+
+    /// 保存元数据到文件
+    async fn save_to_file(store: &MetadataMap) -> CoreResult<()> {
+        let dir = get_config_dir();
+        if !dir.exists() {
+            fs::create_dir_all(&dir)
+                .await
+                .map_err(|e| CoreError::StorageError(e.to_string()))?;
+        }
+
+        // 按键排序，保证文件内容稳定
+        let entries: BTreeMap<String, &DomainMetadata> = store
+            .iter()
+            .map(|(key, metadata)| (key.to_storage_key(), metadata))
+            .collect();
+        let content = serde_json::to_string_pretty(&entries)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+
+        fs::write(get_metadata_file(), content)
+            .await
+            .map_err(|e| CoreError::StorageError(e.to_string()))?;
+
+        Ok(())
+    }
 }
 
-impl Default for InMemoryDomainMetadataRepository {
+impl Default for JsonDomainMetadataRepository {
     fn default() -> Self {
         Self::new()
     }
 }
 
 #[async_trait]
-impl DomainMetadataRepository for InMemoryDomainMetadataRepository {
+impl DomainMetadataRepository for JsonDomainMetadataRepository {
     async fn find_by_key(&self, key: &DomainMetadataKey) -> CoreResult<Option<DomainMetadata>> {
-        let store = self.store.lock().unwrap();
+        let store = self.store().await?;
         Ok(store.get(key).cloned())
     }
 
@@ -43,7 +112,7 @@ impl DomainMetadataRepository for InMemoryDomainMetadataRepository {
         &self,
         keys: &[DomainMetadataKey],
     ) -> CoreResult<HashMap<DomainMetadataKey, DomainMetadata>> {
-        let store = self.store.lock().unwrap();
+        let store = self.store().await?;
         let mut result = HashMap::new();
         for key in keys {
             if let Some(metadata) = store.get(key) {
@@ -54,17 +123,17 @@ impl DomainMetadataRepository for InMemoryDomainMetadataRepository {
     }
 
     async fn save(&self, key: &DomainMetadataKey, metadata: &DomainMetadata) -> CoreResult<()> {
-        let mut store = self.store.lock().unwrap();
+        let mut store = self.store().await?;
         if metadata.is_empty() {
             store.remove(key);
         } else {
             store.insert(key.clone(), metadata.clone());
         }
-        Ok(())
+        Self::save_to_file(&store).await
     }
 
     async fn batch_save(&self, entries: &[(DomainMetadataKey, DomainMetadata)]) -> CoreResult<()> {
-        let mut store = self.store.lock().unwrap();
+        let mut store = self.store().await?;
         for (key, metadata) in entries {
             if metadata.is_empty() {
                 store.remove(key);
@@ -72,7 +141,7 @@ impl DomainMetadataRepository for InMemoryDomainMetadataRepository {
                 store.insert(key.clone(), metadata.clone());
             }
         }
-        Ok(())
+        Self::save_to_file(&store).await
     }
 
     async fn update(
@@ -80,7 +149,7 @@ impl DomainMetadataRepository for InMemoryDomainMetadataRepository {
         key: &DomainMetadataKey,
         update: &DomainMetadataUpdate,
     ) -> CoreResult<()> {
-        let mut store = self.store.lock().unwrap();
+        let mut store = self.store().await?;
         let metadata = store.entry(key.clone()).or_insert_with(DomainMetadata::default);
 
         if let Some(is_favorite) = update.is_favorite {
@@ -96,26 +165,26 @@ impl DomainMetadataRepository for InMemoryDomainMetadataRepository {
             metadata.note = note.clone();
         }
 
-        Ok(())
+        Self::save_to_file(&store).await
     }
 
     async fn delete(&self, key: &DomainMetadataKey) -> CoreResult<()> {
-        let mut store = self.store.lock().unwrap();
+        let mut store = self.store().await?;
         store.remove(key);
-        Ok(())
+        Self::save_to_file(&store).await
     }
 
     async fn delete_by_account(&self, account_id: &str) -> CoreResult<()> {
-        let mut store = self.store.lock().unwrap();
+        let mut store = self.store().await?;
         store.retain(|k, _| k.account_id != account_id);
-        Ok(())
+        Self::save_to_file(&store).await
     }
 
     async fn find_favorites_by_account(
         &self,
         account_id: &str,
     ) -> CoreResult<Vec<DomainMetadataKey>> {
-        let store = self.store.lock().unwrap();
+        let store = self.store().await?;
         Ok(store
             .iter()
             .filter(|(k, v)| k.account_id == account_id && v.is_favorite)
@@ -124,7 +193,7 @@ impl DomainMetadataRepository for InMemoryDomainMetadataRepository {
     }
 
     async fn find_by_tag(&self, tag: &str) -> CoreResult<Vec<DomainMetadataKey>> {
-        let store = self.store.lock().unwrap();
+        let store = self.store().await?;
         Ok(store
             .iter()
             .filter(|(_, v)| v.tags.contains(&tag.to_string()))
@@ -133,7 +202,7 @@ impl DomainMetadataRepository for InMemoryDomainMetadataRepository {
     }
 
     async fn list_all_tags(&self) -> CoreResult<Vec<String>> {
-        let store = self.store.lock().unwrap();
+        let store = self.store().await?;
         let mut tags: Vec<String> = store
             .values()
             .flat_map(|m| m.tags.iter().cloned())
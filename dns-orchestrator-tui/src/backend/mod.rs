@@ -42,7 +42,9 @@
 //!         mod core_service;               // 核心服务入口
 //!         mod credential_service;         // 凭证存储（keyring）
 //!         mod account_repository;         // 账号持久化（JSON 文件）
-//!         mod domain_metadata_repository; // 域名元数据存储（内存）
+//!         mod domain_metadata_repository; // 域名元数据持久化（JSON 文件）
+//!         mod task_runner;                // 后台任务执行器
+//!         mod convert;                    // 核心库类型 → TUI 模型转换
 //!
 //!         mod account_service;            // 账号服务（Mock，用于测试）
 //!         mod config_service;             // 配置服务（Mock，用于测试）
@@ -113,7 +115,7 @@
 //!
 //!
 //! ═══════════════════════════════════════════════════════════════════════════
-//! 四、域名元数据仓库（InMemoryDomainMetadataRepository）
+//! 四、域名元数据仓库（JsonDomainMetadataRepository）
 //! ═══════════════════════════════════════════════════════════════════════════
 //!
 //!     在 src/backend/domain_metadata_repository.rs 中定义：
@@ -121,8 +123,8 @@
 //!         实现 dns-orchestrator-core 的 DomainMetadataRepository trait。
 //!         用于存储域名的附加信息（收藏、标签、颜色、备注）。
 //!
-//!         当前实现：内存存储（程序重启后数据丢失）
-//!         TODO: 后续可改为持久化存储
+//!         存储位置：~/.config/dns-orchestrator-tui/domain_metadata.json
+//!         （与 accounts.json 同目录，首次访问时加载，每次修改后写回）
 //!
 //!
 //! ═══════════════════════════════════════════════════════════════════════════
@@ -145,20 +147,40 @@
 //!         ↓
 //!     View 层重新渲染
 //!
+//!
+//! ═══════════════════════════════════════════════════════════════════════════
+//! 六、后台任务（TaskRunner）
+//! ═══════════════════════════════════════════════════════════════════════════
+//!
+//!     在 src/backend/task_runner.rs 中定义：
+//!
+//!         主循环是同步的，CoreService 是异步的，二者通过 TaskRunner 衔接：
+//!             1. Update 层调用 app.request(BackendTask::...) 登记任务
+//!             2. 主循环取出任务，交给 TaskRunner 在 tokio 运行时中执行
+//!             3. 任务完成后，结果以 AppMessage::Backend(...) 送回 channel
+//!             4. 主循环每轮取出结果，交给 Update 层更新对应的 *State
+//!
+//!         工具箱查询（BackendTask::Toolbox）同样走这条路径，
+//!         由 src/backend/toolbox.rs 调用 ToolboxService 并整理为 ToolboxLine。
+//!
 
 mod account_repository;
 mod account_service;
 mod config_service;
+mod convert;
 mod core_service;
 mod credential_service;
 mod domain_metadata_repository;
+mod task_runner;
+mod toolbox;
 
 // 旧的 Mock 服务（保留用于测试）
 pub use account_service::{AccountService, MockAccountService};
-pub use config_service::{ConfigService, LocalConfigService};
+pub use config_service::{AppConfig, ConfigService, LocalConfigService};
 
 // 新的核心服务
 pub use account_repository::JsonAccountRepository;
 pub use core_service::CoreService;
 pub use credential_service::KeyringCredentialStore;
-pub use domain_metadata_repository::InMemoryDomainMetadataRepository;
\ No newline at end of file
+pub use domain_metadata_repository::JsonDomainMetadataRepository;
+pub use task_runner::TaskRunner;
\ No newline at end of file
@@ -0,0 +1,165 @@
+//! 后台任务执行器
+//!
+//! TUI 主循环是同步的，而 CoreService 的接口都是异步的。
+//! TaskRunner 持有一个 tokio 运行时，在后台执行 `BackendTask`，
+//! 完成后通过 channel 把 `AppMessage::Backend` 送回主循环。
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+
+use dns_orchestrator_core::CoreResult;
+use tokio::runtime::Runtime;
+
+use super::convert;
+use super::core_service::CoreService;
+use super::toolbox;
+use crate::message::{AppMessage, BackendMessage, BackendTask};
+use crate::model::domain::{Account, DnsRecord, Domain};
+
+/// 分页加载时每页的数量
+const PAGE_SIZE: u32 = 100;
+
+/// 后台任务执行器
+pub struct TaskRunner {
+    runtime: Runtime,
+    core: Arc<CoreService>,
+    sender: Sender<AppMessage>,
+    receiver: Receiver<AppMessage>,
+}
+
+impl TaskRunner {
+    /// 创建执行器（启动 tokio 运行时）
+    pub fn new(core: CoreService) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()?;
+        let (sender, receiver) = mpsc::channel();
+
+        Ok(Self {
+            runtime,
+            core: Arc::new(core),
+            sender,
+            receiver,
+        })
+    }
+
+    /// 初始化核心服务（恢复账号）
+    pub fn initialize(&self) -> CoreResult<()> {
+        self.runtime.block_on(self.core.initialize())
+    }
+
+    /// 在后台执行任务，结果稍后由 `try_recv` 取回
+    pub fn spawn(&self, task: BackendTask) {
+        let core = self.core.clone();
+        let sender = self.sender.clone();
+
+        self.runtime.spawn(async move {
+            let msg = execute(&core, task).await;
+            // 主循环退出后接收端已释放，结果直接丢弃
+            let _ = sender.send(AppMessage::Backend(msg));
+        });
+    }
+
+    /// 取出一条已完成任务的结果（非阻塞）
+    pub fn try_recv(&self) -> Option<AppMessage> {
+        self.receiver.try_recv().ok()
+    }
+}
+
+/// 执行任务并生成结果消息
+async fn execute(core: &CoreService, task: BackendTask) -> BackendMessage {
+    match task {
+        BackendTask::Accounts => {
+            BackendMessage::Accounts(load_accounts(core).await.map_err(|e| e.to_string()))
+        }
+        BackendTask::Domains { refresh } => {
+            BackendMessage::Domains(load_domains(core, refresh).await.map_err(|e| e.to_string()))
+        }
+        BackendTask::DnsRecords {
+            account_id,
+            domain_id,
+        } => {
+            let result = load_dns_records(core, &account_id, &domain_id)
+                .await
+                .map_err(|e| e.to_string());
+            BackendMessage::DnsRecords {
+                account_id,
+                domain_id,
+                result,
+            }
+        }
+        BackendTask::Toolbox(query) => {
+            let result = toolbox::run(&query).await.map_err(|e| e.to_string());
+            BackendMessage::Toolbox { query, result }
+        }
+    }
+}
+
+async fn load_accounts(core: &CoreService) -> CoreResult<Vec<Account>> {
+    let accounts = core.account_metadata().list_accounts().await?;
+    Ok(accounts.into_iter().filter_map(convert::account).collect())
+}
+
+/// 加载所有账号下的全部域名
+///
+/// 单个账号加载失败不影响其他账号，仅记录日志
+async fn load_domains(core: &CoreService, refresh: bool) -> CoreResult<Vec<Domain>> {
+    let accounts = core.account_metadata().list_accounts().await?;
+    let domain_service = core.domain();
+    let mut domains = Vec::new();
+
+    for account in accounts {
+        let mut page = 1;
+        loop {
+            match domain_service
+                .list_domains(&account.id, Some(page), Some(PAGE_SIZE), refresh)
+                .await
+            {
+                Ok(response) => {
+                    domains.extend(response.items.into_iter().filter_map(convert::domain));
+                    if !response.has_more {
+                        break;
+                    }
+                    page += 1;
+                }
+                Err(e) => {
+                    log::warn!("加载账号 {} 的域名失败: {}", account.name, e);
+                    break;
+                }
+            }
+        }
+    }
+
+    Ok(domains)
+}
+
+/// 加载指定域名的全部 DNS 记录
+async fn load_dns_records(
+    core: &CoreService,
+    account_id: &str,
+    domain_id: &str,
+) -> CoreResult<Vec<DnsRecord>> {
+    let dns_service = core.dns();
+    let mut records = Vec::new();
+    let mut page = 1;
+
+    loop {
+        let response = dns_service
+            .list_records(
+                account_id,
+                domain_id,
+                Some(page),
+                Some(PAGE_SIZE),
+                None,
+                None,
+            )
+            .await?;
+        records.extend(response.items.into_iter().filter_map(convert::dns_record));
+        if !response.has_more {
+            break;
+        }
+        page += 1;
+    }
+
+    Ok(records)
+}
@@ -0,0 +1,320 @@
+//! 工具箱查询
+//!
+//! 调用 `ToolboxService` 执行查询，并把结果整理为 `ToolboxLine` 供视图渲染
+
+use dns_orchestrator_core::services::ToolboxService;
+use dns_orchestrator_core::types::{
+    DnsLookupRecord, DnsLookupResult, DnsPropagationResult, DnssecResult, HttpHeaderCheckRequest,
+    HttpHeaderCheckResult, HttpMethod, IpLookupResult, SslCheckResult, WhoisResult,
+};
+use dns_orchestrator_core::CoreResult;
+
+use crate::message::ToolboxQuery;
+use crate::model::state::ToolboxLine;
+
+/// 执行工具箱查询
+pub async fn run(query: &ToolboxQuery) -> CoreResult<Vec<ToolboxLine>> {
+    let lines = match query {
+        ToolboxQuery::Whois { domain } => whois(ToolboxService::whois_lookup(domain).await?),
+        ToolboxQuery::DnsLookup {
+            domain,
+            record_type,
+            nameserver,
+        } => dns_lookup(
+            ToolboxService::dns_lookup(domain, record_type, nameserver.as_deref()).await?,
+        ),
+        ToolboxQuery::IpLookup { query } => ip_lookup(ToolboxService::ip_lookup(query).await?),
+        ToolboxQuery::SslCheck { domain } => {
+            ssl_check(ToolboxService::ssl_check(domain, None).await?)
+        }
+        ToolboxQuery::HttpHeaders { url, method } => {
+            let request = HttpHeaderCheckRequest {
+                url: url.clone(),
+                method: http_method(method),
+                custom_headers: Vec::new(),
+                body: None,
+                content_type: None,
+            };
+            http_headers(ToolboxService::http_header_check(&request).await?)
+        }
+        ToolboxQuery::DnsPropagation {
+            domain,
+            record_type,
+        } => dns_propagation(ToolboxService::dns_propagation_check(domain, record_type).await?),
+        ToolboxQuery::Dnssec { domain } => {
+            dnssec(ToolboxService::dnssec_check(domain, None).await?)
+        }
+    };
+    Ok(lines)
+}
+
+fn http_method(method: &str) -> HttpMethod {
+    match method {
+        "HEAD" => HttpMethod::HEAD,
+        "POST" => HttpMethod::POST,
+        "PUT" => HttpMethod::PUT,
+        "DELETE" => HttpMethod::DELETE,
+        "PATCH" => HttpMethod::PATCH,
+        "OPTIONS" => HttpMethod::OPTIONS,
+        _ => HttpMethod::GET,
+    }
+}
+
+fn field(label: &str, value: impl Into<String>) -> ToolboxLine {
+    ToolboxLine::Field(label.to_string(), value.into())
+}
+
+fn heading(title: impl Into<String>) -> ToolboxLine {
+    ToolboxLine::Heading(title.into())
+}
+
+fn text(value: impl Into<String>) -> ToolboxLine {
+    ToolboxLine::Text(value.into())
+}
+
+fn or_dash(value: Option<&str>) -> String {
+    value.unwrap_or("-").to_string()
+}
+
+fn millis(value: Option<u64>) -> String {
+    value.map_or_else(|| "-".to_string(), |ms| format!("{ms} ms"))
+}
+
+fn record_line(record: &DnsLookupRecord) -> ToolboxLine {
+    let value = match record.priority {
+        Some(priority) => format!("{} {}", priority, record.value),
+        None => record.value.clone(),
+    };
+    text(format!(
+        "{:<6} {}  {}  (TTL {})",
+        record.record_type, record.name, value, record.ttl
+    ))
+}
+
+fn whois(result: WhoisResult) -> Vec<ToolboxLine> {
+    let mut lines = vec![
+        field("Domain", result.domain),
+        field(
+            "Registered",
+            if result.is_registered {
+                "Yes"
+            } else {
+                "No (available)"
+            },
+        ),
+        field("Registrar", or_dash(result.registrar.as_deref())),
+        field("Created", or_dash(result.creation_date.as_deref())),
+        field("Expires", or_dash(result.expiration_date.as_deref())),
+        field("Updated", or_dash(result.updated_date.as_deref())),
+    ];
+    if !result.name_servers.is_empty() {
+        lines.push(heading("Name Servers"));
+        lines.extend(result.name_servers.into_iter().map(text));
+    }
+    if !result.status.is_empty() {
+        lines.push(heading("Status"));
+        lines.extend(result.status.into_iter().map(text));
+    }
+    lines
+}
+
+fn dns_lookup(result: DnsLookupResult) -> Vec<ToolboxLine> {
+    let mut lines = vec![
+        field("Nameserver", result.nameserver),
+        field("Records", result.records.len().to_string()),
+    ];
+    if result.nxdomain {
+        lines.push(field("Status", "NXDOMAIN (domain does not exist)"));
+    }
+    if !result.records.is_empty() {
+        lines.push(heading("Answers"));
+        lines.extend(result.records.iter().map(record_line));
+    }
+    lines
+}
+
+fn ip_lookup(result: IpLookupResult) -> Vec<ToolboxLine> {
+    let mut lines = vec![field("Query", result.query)];
+    for info in result.results {
+        lines.push(heading(format!("{} ({})", info.ip, info.ip_version)));
+        let location = [info.city, info.region, info.country]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(", ");
+        if !location.is_empty() {
+            lines.push(field("Location", location));
+        }
+        lines.push(field("ISP", or_dash(info.isp.as_deref())));
+        lines.push(field("Organization", or_dash(info.org.as_deref())));
+        if let Some(asn) = info.asn {
+            let as_name = info.as_name.map(|n| format!(" {}", n)).unwrap_or_default();
+            lines.push(field("ASN", format!("{}{}", asn, as_name)));
+        }
+        if let Some(timezone) = info.timezone {
+            lines.push(field("Timezone", timezone));
+        }
+    }
+    lines
+}
+
+fn ssl_check(result: SslCheckResult) -> Vec<ToolboxLine> {
+    let mut lines = vec![
+        field("Host", format!("{}:{}", result.domain, result.port)),
+        field("Connection", result.connection_status),
+    ];
+    if let Some(error) = result.error {
+        lines.push(field("Error", error));
+    }
+    if let Some(cert) = result.cert_info {
+        let validity = if cert.is_expired {
+            "Expired".to_string()
+        } else if !cert.hostname_matches {
+            "Hostname mismatch".to_string()
+        } else if cert.is_valid {
+            format!("Valid ({} days remaining)", cert.days_remaining)
+        } else {
+            "Invalid".to_string()
+        };
+        lines.push(heading("Certificate"));
+        lines.push(field("Status", validity));
+        lines.push(field("Subject", cert.subject));
+        lines.push(field("Issuer", cert.issuer));
+        lines.push(field("Valid From", cert.valid_from));
+        lines.push(field("Valid To", cert.valid_to));
+        lines.push(field("Algorithm", cert.signature_algorithm));
+        if !cert.san.is_empty() {
+            lines.push(heading("Subject Alternative Names"));
+            lines.extend(cert.san.into_iter().map(text));
+        }
+        if !cert.certificate_chain.is_empty() {
+            lines.push(heading("Chain"));
+            lines.extend(
+                cert.certificate_chain
+                    .into_iter()
+                    .map(|item| text(format!("{}  ←  {}", item.subject, item.issuer))),
+            );
+        }
+    }
+    lines
+}
+
+fn http_headers(result: HttpHeaderCheckResult) -> Vec<ToolboxLine> {
+    let mut lines = vec![
+        field("URL", result.url),
+        field(
+            "Status",
+            format!("{} {}", result.status_code, result.status_text),
+        ),
+        field("Response Time", format!("{} ms", result.response_time_ms)),
+        heading("Timing"),
+        field("DNS", millis(result.timing.dns_ms)),
+        field("TCP Connect", millis(result.timing.tcp_connect_ms)),
+        field("TLS Handshake", millis(result.timing.tls_handshake_ms)),
+        field("TTFB", millis(result.timing.ttfb_ms)),
+        heading("Headers"),
+    ];
+    lines.extend(
+        result
+            .headers
+            .into_iter()
+            .map(|header| field(&header.name, header.value)),
+    );
+    if !result.security_analysis.is_empty() {
+        lines.push(heading("Security Headers"));
+        lines.extend(result.security_analysis.into_iter().map(|analysis| {
+            let mark = if analysis.present { "✓" } else { "✗" };
+            text(format!("{} {} ({})", mark, analysis.name, analysis.status))
+        }));
+    }
+    lines
+}
+
+fn dns_propagation(result: DnsPropagationResult) -> Vec<ToolboxLine> {
+    let mut lines = vec![
+        field("Query", format!("{} {}", result.domain, result.record_type)),
+        field(
+            "Consistency",
+            format!("{:.0}%", result.consistency_percentage),
+        ),
+        field("Total Time", format!("{} ms", result.total_time_ms)),
+    ];
+    if !result.grouped_answers.is_empty() {
+        lines.push(heading("Answer Groups"));
+        for (answer, servers) in result.grouped_answers {
+            let answer = if answer.is_empty() {
+                "(no records)".to_string()
+            } else {
+                answer
+            };
+            lines.push(field(&answer, servers.join(", ")));
+        }
+    }
+    lines.push(heading("Servers"));
+    lines.extend(result.results.into_iter().map(|server| {
+        let values = if let Some(error) = server.error {
+            error
+        } else {
+            server
+                .records
+                .iter()
+                .map(|r| r.value.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        text(format!(
+            "{:<20} {:<8} {}",
+            format!("{} ({})", server.server.name, server.server.country_code),
+            server.status,
+            values
+        ))
+    }));
+    lines
+}
+
+fn dnssec(result: DnssecResult) -> Vec<ToolboxLine> {
+    let mut lines = vec![
+        field("Domain", result.domain),
+        field(
+            "DNSSEC",
+            if result.dnssec_enabled {
+                "Enabled"
+            } else {
+                "Disabled"
+            },
+        ),
+        field("Validation", result.validation_status),
+        field("Nameserver", result.nameserver),
+    ];
+    if let Some(error) = result.error {
+        lines.push(field("Error", error));
+    }
+    if !result.dnskey_records.is_empty() {
+        lines.push(heading("DNSKEY"));
+        lines.extend(result.dnskey_records.into_iter().map(|key| {
+            text(format!(
+                "{} tag={} alg={} ({})",
+                key.key_type, key.key_tag, key.algorithm, key.algorithm_name
+            ))
+        }));
+    }
+    if !result.ds_records.is_empty() {
+        lines.push(heading("DS"));
+        lines.extend(result.ds_records.into_iter().map(|ds| {
+            text(format!(
+                "tag={} alg={} digest={} {}",
+                ds.key_tag, ds.algorithm_name, ds.digest_type_name, ds.digest
+            ))
+        }));
+    }
+    if !result.rrsig_records.is_empty() {
+        lines.push(heading("RRSIG"));
+        lines.extend(result.rrsig_records.into_iter().map(|sig| {
+            text(format!(
+                "{} signer={} expires={}",
+                sig.type_covered, sig.signer_name, sig.signature_expiration
+            ))
+        }));
+    }
+    lines
+}
@@ -3,10 +3,15 @@
 use std::time::Duration;
 
 use anyhow::Result;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent,
+    MouseEventKind,
+};
+use ratatui::layout::{Margin, Position, Rect};
 
 use crate::message::{AppMessage, ContentMessage, ModalMessage, NavigationMessage};
 use crate::model::{App, Page};
+use crate::view::layout::main_areas;
 
 
 
@@ -27,6 +32,7 @@ pub fn poll_event(timeout: Duration) -> Result<Option<Event>> {
 pub fn handle_event(event: Event, app: &App) -> AppMessage {
     match event {
         Event::Key(key_event) => handle_key_event(key_event, app),      // 键盘事件
+        Event::Mouse(mouse_event) => handle_mouse_event(mouse_event, app),  // 鼠标事件
         Event::Resize(_, _) => AppMessage::Noop,                                  // 终端窗口大小改变，自动重绘
         _ => AppMessage::Noop,
     }
@@ -35,6 +41,107 @@ pub fn handle_event(event: Event, app: &App) -> AppMessage {
 
 
 
+/// 处理鼠标事件
+///
+/// 按主布局计算点击位置，翻译为导航/内容面板的消息
+fn handle_mouse_event(mouse: MouseEvent, app: &App) -> AppMessage {
+    // 弹窗打开时不响应鼠标
+    if app.modal.is_open() {
+        return AppMessage::Noop;
+    }
+
+    let Ok((width, height)) = crossterm::terminal::size() else {
+        return AppMessage::Noop;
+    };
+    let areas = main_areas(Rect::new(0, 0, width, height), app.logs.visible);
+    let position = Position::new(mouse.column, mouse.row);
+    let in_nav = areas.nav.contains(position);
+    let in_content = areas.content.contains(position);
+
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) if in_nav => {
+            let inner = areas.nav.inner(Margin::new(1, 1));
+            match list_index_at(inner, app.navigation.selected, mouse.row) {
+                Some(index) => AppMessage::Navigation(NavigationMessage::Open(index)),
+                None => AppMessage::Noop,
+            }
+        }
+        MouseEventKind::Down(MouseButton::Left) if in_content => {
+            let inner = areas.content.inner(Margin::new(1, 1));
+            match content_index_at(app, inner, mouse.row) {
+                Some(index) => AppMessage::Content(ContentMessage::Select(index)),
+                None => AppMessage::Noop,
+            }
+        }
+
+        // 滚轮：导航面板切换选中项，内容面板滚动列表或工具箱结果
+        MouseEventKind::ScrollUp if in_nav => {
+            AppMessage::Navigation(NavigationMessage::SelectPrevious)
+        }
+        MouseEventKind::ScrollDown if in_nav => {
+            AppMessage::Navigation(NavigationMessage::SelectNext)
+        }
+        MouseEventKind::ScrollUp if in_content => match app.current_page {
+            Page::Toolbox => AppMessage::Content(ContentMessage::ScrollUp),
+            _ => AppMessage::Content(ContentMessage::SelectPrevious),
+        },
+        MouseEventKind::ScrollDown if in_content => match app.current_page {
+            Page::Toolbox => AppMessage::Content(ContentMessage::ScrollDown),
+            _ => AppMessage::Content(ContentMessage::SelectNext),
+        },
+
+        _ => AppMessage::Noop,
+    }
+}
+
+/// 计算内容面板中被点击的列表项
+fn content_index_at(app: &App, area: Rect, row: u16) -> Option<usize> {
+    match &app.current_page {
+        Page::Accounts => list_index_at(area, app.accounts.selected, row),
+        Page::Domains => {
+            let domains = &app.domains;
+            let area = skip_filter_bar(area, &domains.filter, domains.filtering);
+            list_index_at(area, domains.selected, row)
+        }
+        Page::DnsRecords { .. } => {
+            let records = &app.dns_records;
+            let area = skip_filter_bar(area, &records.filter, records.filtering);
+            list_index_at(area, records.selected, row)
+        }
+        // 设置页首行为空行，各设置项不滚动
+        Page::Settings => row.checked_sub(area.y + 1).map(usize::from),
+        _ => None,
+    }
+}
+
+/// 过滤栏显示时占用列表区域顶部一行（与 `components::filter::render` 一致）
+fn skip_filter_bar(area: Rect, filter: &str, active: bool) -> Rect {
+    if !active && filter.is_empty() {
+        area
+    } else {
+        Rect {
+            y: area.y + 1,
+            height: area.height.saturating_sub(1),
+            ..area
+        }
+    }
+}
+
+/// 根据点击行计算列表项下标
+///
+/// 列表每帧以新的 `ListState` 渲染，选中项超出可见区域时恰好滚动到底部，
+/// 因此可由选中项推算出首个可见项。
+fn list_index_at(area: Rect, selected: usize, row: u16) -> Option<usize> {
+    if row < area.y || row >= area.y + area.height {
+        return None;
+    }
+    let first = (selected + 1).saturating_sub(usize::from(area.height));
+    Some(first + usize::from(row - area.y))
+}
+
+
+
+
 /// 处理键盘事件
 fn handle_key_event(key: KeyEvent, app: &App) -> AppMessage {
     // 重要：只处理 Press 事件，忽略 Release 和 Repeat
@@ -48,6 +155,11 @@ fn handle_key_event(key: KeyEvent, app: &App) -> AppMessage {
         return handle_modal_keys(key, app);
     }
 
+    // 正在输入过滤关键词时，优先处理过滤输入
+    if is_filtering(app) {
+        return handle_filter_keys(key);
+    }
+
     // 全局快捷键（无论焦点在哪里）
     match (key.modifiers, key.code) {
         // Ctrl+C: 强制退出
@@ -69,6 +181,12 @@ fn handle_key_event(key: KeyEvent, app: &App) -> AppMessage {
         // Alt+r: 刷新
         (KeyModifiers::ALT, KeyCode::Char('r')) => return AppMessage::Refresh,
 
+        // Alt+l: 显示/隐藏日志面板
+        (KeyModifiers::ALT, KeyCode::Char('l')) => return AppMessage::ToggleLogs,
+
+        // Alt+v: 切换日志级别
+        (KeyModifiers::ALT, KeyCode::Char('v')) => return AppMessage::CycleLogLevel,
+
         // Esc: 返回/取消
         (KeyModifiers::NONE, KeyCode::Esc) => return AppMessage::GoBack,
 
@@ -166,6 +284,61 @@ fn handle_list_keys(key: KeyEvent) -> AppMessage {
         KeyCode::End => {
             AppMessage::Content(ContentMessage::SelectLast)
         }
+        // /: 打开过滤输入框
+        KeyCode::Char('/') => {
+            AppMessage::Content(ContentMessage::StartFilter)
+        }
+        // y: 复制选中项的值
+        KeyCode::Char('y') => {
+            AppMessage::Content(ContentMessage::Copy)
+        }
+        // s: 切换排序列
+        KeyCode::Char('s') => {
+            AppMessage::Content(ContentMessage::CycleSort)
+        }
+        // S: 切换排序方向
+        KeyCode::Char('S') => {
+            AppMessage::Content(ContentMessage::ToggleSortDirection)
+        }
+        _ => AppMessage::Noop,
+    }
+}
+
+/// 当前页面是否正在输入过滤关键词
+fn is_filtering(app: &App) -> bool {
+    match &app.current_page {
+        Page::Domains => app.domains.filtering,
+        Page::DnsRecords { .. } => app.dns_records.filtering,
+        _ => false,
+    }
+}
+
+/// 处理过滤输入框的按键
+fn handle_filter_keys(key: KeyEvent) -> AppMessage {
+    // Ctrl+C: 强制退出
+    if key.modifiers == KeyModifiers::CONTROL && key.code == KeyCode::Char('c') {
+        return AppMessage::Quit;
+    }
+
+    match key.code {
+        // Enter: 确认过滤
+        KeyCode::Enter => AppMessage::Content(ContentMessage::ApplyFilter),
+
+        // Esc: 清除过滤
+        KeyCode::Esc => AppMessage::Content(ContentMessage::ClearFilter),
+
+        // Backspace: 删除字符
+        KeyCode::Backspace => AppMessage::Content(ContentMessage::FilterBackspace),
+
+        // ↑ ↓: 在过滤结果中移动
+        KeyCode::Up => AppMessage::Content(ContentMessage::SelectPrevious),
+        KeyCode::Down => AppMessage::Content(ContentMessage::SelectNext),
+
+        // 字符输入
+        KeyCode::Char(ch) if !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+            AppMessage::Content(ContentMessage::FilterInput(ch))
+        }
+
         _ => AppMessage::Noop,
     }
 }
@@ -189,6 +362,17 @@ fn handle_toolbox_keys(key: KeyEvent) -> AppMessage {
         KeyCode::Down | KeyCode::Char('j') => {
             AppMessage::Content(ContentMessage::SelectNext)
         }
+        // y: 复制查询结果
+        KeyCode::Char('y') => {
+            AppMessage::Content(ContentMessage::Copy)
+        }
+        // PageUp / PageDown: 滚动结果面板
+        KeyCode::PageUp => {
+            AppMessage::Content(ContentMessage::ScrollUp)
+        }
+        KeyCode::PageDown => {
+            AppMessage::Content(ContentMessage::ScrollDown)
+        }
         _ => AppMessage::Noop,
     }
 }
@@ -205,6 +389,10 @@ fn handle_modal_keys(key: KeyEvent, app: &App) -> AppMessage {
         (KeyModifiers::NONE, KeyCode::Esc) => {
             return AppMessage::Modal(ModalMessage::Close);
         }
+        // Alt+y: 复制结果（弹窗内 y 用于文本输入）
+        (KeyModifiers::ALT, KeyCode::Char('y')) => {
+            return AppMessage::Modal(ModalMessage::Copy);
+        }
         _ => {}
     }
 
@@ -215,7 +403,9 @@ fn handle_modal_keys(key: KeyEvent, app: &App) -> AppMessage {
 
     match modal {
         Modal::AddAccount { focus, .. } => handle_add_account_keys(key, *focus),
-        Modal::ConfirmDelete { .. } => handle_confirm_delete_keys(key),
+        Modal::ConfirmDelete { required_text, .. } => {
+            handle_confirm_delete_keys(key, required_text.is_some())
+        }
         Modal::DnsLookup { focus, .. } => handle_dns_lookup_keys(key, *focus),
         Modal::WhoisLookup { .. }
         | Modal::SslCheck { .. }
@@ -296,7 +486,7 @@ fn handle_add_account_keys(key: KeyEvent, focus: usize) -> AppMessage {
 }
 
 /// 处理确认删除弹窗的按键
-fn handle_confirm_delete_keys(key: KeyEvent) -> AppMessage {
+fn handle_confirm_delete_keys(key: KeyEvent, typed: bool) -> AppMessage {
     match key.code {
         // Tab 或 ← →: 切换焦点
         KeyCode::Tab | KeyCode::Left | KeyCode::Right => {
@@ -306,6 +496,10 @@ fn handle_confirm_delete_keys(key: KeyEvent) -> AppMessage {
         // Enter: 确认
         KeyCode::Enter => AppMessage::Modal(ModalMessage::Confirm),
 
+        // 需要输入确认文本时，接收字符输入
+        KeyCode::Backspace if typed => AppMessage::Modal(ModalMessage::Backspace),
+        KeyCode::Char(c) if typed => AppMessage::Modal(ModalMessage::Input(c)),
+
         _ => AppMessage::Noop,
     }
 }
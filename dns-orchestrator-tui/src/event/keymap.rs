@@ -39,6 +39,8 @@ impl DefaultKeymap {
     pub const HELP: KeyBinding = KeyBinding::alt(KeyCode::Char('h'));
     pub const REFRESH: KeyBinding = KeyBinding::alt(KeyCode::Char('r'));
     pub const BACK: KeyBinding = KeyBinding::key(KeyCode::Esc);
+    pub const TOGGLE_LOGS: KeyBinding = KeyBinding::alt(KeyCode::Char('l'));
+    pub const LOG_LEVEL: KeyBinding = KeyBinding::alt(KeyCode::Char('v'));
 
     // 面板切换
     pub const FOCUS_LEFT: KeyBinding = KeyBinding::key(KeyCode::Left);
@@ -55,4 +57,15 @@ impl DefaultKeymap {
     pub const ACTION_DELETE: KeyBinding = KeyBinding::alt(KeyCode::Char('d'));
     pub const ACTION_IMPORT: KeyBinding = KeyBinding::alt(KeyCode::Char('i'));
     pub const ACTION_EXPORT: KeyBinding = KeyBinding::alt(KeyCode::Char('x'));
+
+    // 过滤
+    pub const FILTER: KeyBinding = KeyBinding::key(KeyCode::Char('/'));
+
+    // 排序
+    pub const SORT: KeyBinding = KeyBinding::key(KeyCode::Char('s'));
+    pub const SORT_DIRECTION: KeyBinding = KeyBinding::new(KeyModifiers::SHIFT, KeyCode::Char('S'));
+
+    // 剪贴板
+    pub const COPY: KeyBinding = KeyBinding::key(KeyCode::Char('y'));
+    pub const MODAL_COPY: KeyBinding = KeyBinding::alt(KeyCode::Char('y'));
 }
\ No newline at end of file
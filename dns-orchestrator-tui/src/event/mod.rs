@@ -63,7 +63,7 @@
 //!         接收以下 Event 类型：
 //!             Event::Key(KeyEvent)                // 键盘事件，发至以下几个函数处理
 //!             Event::Resize(Width , height)       // 终端窗口大小发生变化，重绘终端
-//!             Event::Mouse(MouseEvent)            // 鼠标事件（暂不处理）
+//!             Event::Mouse(MouseEvent)            // 鼠标事件，转入 handle_mouse_event()
 //!
 //!             当接收到键盘事件时，转入 handle_key_events()
 //!             判断：
@@ -72,6 +72,12 @@
 //!                 - 焦点位于导航面板，调用 handle_navigation_keys 处理
 //!                 - 焦点位于内容面板，调用 handle_content_keys 处理
 //!
+//!             当接收到鼠标事件时，转入 handle_mouse_event()
+//!             按 view::layout::main_areas 计算的区域判断点击位置：
+//!                 - 左键点击导航项     → NavigationMessage::Open(index)
+//!                 - 左键点击列表行     → ContentMessage::Select(index)
+//!                 - 滚轮               → SelectPrevious / SelectNext（工具箱页为 ScrollUp / ScrollDown）
+//!
 //!
 //! ═══════════════════════════════════════════════════════════════════════════
 //! 弹窗键盘处理
@@ -17,7 +17,8 @@
 //! 其执行：
 //! fn main() {
 //! 
-//!     init_terminal()         // 首先初始化终端，以为 terminal: Terminal<...>
+//!     TaskRunner::new()       // 启动后台任务执行器（tokio 运行时 + CoreService）
+//!     init_terminal()         // 初始化终端，以为 terminal: Terminal<...>
 //!     model::App:new()        // 创建 APP 实例
 //!     app::run()              // 运行 app.rs 主循环
 //!     restore_terminal()      // 无论成功与否，都恢复终端
@@ -57,21 +58,37 @@ mod view;
 
 use anyhow::Result;
 
-use util::{init_terminal, restore_terminal};
+use backend::{CoreService, TaskRunner};
+use message::BackendTask;
+use util::{init_logger, init_terminal, restore_terminal};
 
 fn main() -> Result<() , anyhow::Error> {
-    // 1. 初始化终端
+    // 0. 安装应用内日志（终端处于备用屏幕时日志写入缓冲区，由日志面板显示）
+    let log_buffer = init_logger();
+
+    // 1. 启动后台任务执行器，恢复已保存的账号
+    let runner = TaskRunner::new(CoreService::new())?;
+    if let Err(e) = runner.initialize() {
+        log::warn!("初始化核心服务失败: {}", e);
+    }
+
+    // 2. 初始化终端
     let mut terminal = init_terminal()?;
 
-    // 2. 创建应用实例
+    // 3. 创建应用实例，并在后台加载账号与域名
     let mut app = model::App::new();
+    app.logs.buffer = log_buffer;
+    app.accounts.loading = true;
+    app.domains.loading = true;
+    app.request(BackendTask::Accounts);
+    app.request(BackendTask::Domains { refresh: false });
 
-    // 3. 运行主循环
-    let result = app::run(&mut terminal, &mut app);
+    // 4. 运行主循环
+    let result = app::run(&mut terminal, &mut app, &runner);
 
-    // 4. 恢复终端（无论成功失败都执行）
+    // 5. 恢复终端（无论成功失败都执行）
     restore_terminal(&mut terminal)?;
 
-    // 5. 返回结果
+    // 6. 返回结果
     return result;
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 //! 应用主消息枚举
 
-use super::{ContentMessage, ModalMessage, NavigationMessage};
+use super::{BackendMessage, ContentMessage, ModalMessage, NavigationMessage};
 
 /// 应用主消息
 #[derive(Debug, Clone)]
@@ -20,6 +20,9 @@ pub enum AppMessage {
     /// 弹窗相关消息
     Modal(ModalMessage),
 
+    /// 后台任务结果
+    Backend(BackendMessage),
+
     /// 返回上一页
     GoBack,
 
@@ -29,9 +32,18 @@ pub enum AppMessage {
     /// 显示帮助
     ShowHelp,
 
+    /// 显示/隐藏日志面板
+    ToggleLogs,
+
+    /// 切换日志面板的最低显示级别
+    CycleLogLevel,
+
     /// 清除状态消息
     ClearStatus,
 
+    /// 定时推进加载动画
+    Tick,
+
     /// 无操作（用于忽略未处理的事件）
     Noop,
 }
\ No newline at end of file
@@ -0,0 +1,107 @@
+//! 后台任务消息
+//!
+//! - `BackendTask`：Update 层发起的异步任务请求，由主循环交给 `TaskRunner` 执行
+//! - `BackendMessage`：异步任务完成后回传给 Update 层的结果
+
+use crate::model::domain::{Account, DnsRecord, Domain};
+use crate::model::state::{ToolboxLine, ToolboxTab};
+
+/// 后台任务请求
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackendTask {
+    /// 加载账号列表
+    Accounts,
+
+    /// 加载所有账号下的域名（`refresh` 为 true 时跳过缓存）
+    Domains { refresh: bool },
+
+    /// 加载指定域名的 DNS 记录
+    DnsRecords {
+        account_id: String,
+        domain_id: String,
+    },
+
+    /// 执行工具箱查询
+    Toolbox(ToolboxQuery),
+}
+
+/// 工具箱查询参数
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolboxQuery {
+    Whois {
+        domain: String,
+    },
+    DnsLookup {
+        domain: String,
+        record_type: String,
+        nameserver: Option<String>,
+    },
+    IpLookup {
+        query: String,
+    },
+    SslCheck {
+        domain: String,
+    },
+    HttpHeaders {
+        url: String,
+        method: String,
+    },
+    DnsPropagation {
+        domain: String,
+        record_type: String,
+    },
+    Dnssec {
+        domain: String,
+    },
+}
+
+impl ToolboxQuery {
+    /// 对应的工具箱标签页
+    pub fn tab(&self) -> ToolboxTab {
+        match self {
+            Self::Whois { .. } => ToolboxTab::Whois,
+            Self::DnsLookup { .. } => ToolboxTab::DnsLookup,
+            Self::IpLookup { .. } => ToolboxTab::IpLookup,
+            Self::SslCheck { .. } => ToolboxTab::SslCheck,
+            Self::HttpHeaders { .. } => ToolboxTab::HttpHeaderCheck,
+            Self::DnsPropagation { .. } => ToolboxTab::DnsPropagation,
+            Self::Dnssec { .. } => ToolboxTab::DnssecCheck,
+        }
+    }
+
+    /// 查询目标（域名、IP 或 URL）
+    pub fn target(&self) -> &str {
+        match self {
+            Self::Whois { domain }
+            | Self::DnsLookup { domain, .. }
+            | Self::SslCheck { domain }
+            | Self::DnsPropagation { domain, .. }
+            | Self::Dnssec { domain } => domain,
+            Self::IpLookup { query } => query,
+            Self::HttpHeaders { url, .. } => url,
+        }
+    }
+}
+
+/// 后台任务结果
+#[derive(Debug, Clone)]
+pub enum BackendMessage {
+    /// 账号列表加载完成
+    Accounts(Result<Vec<Account>, String>),
+
+    /// 域名列表加载完成
+    Domains(Result<Vec<Domain>, String>),
+
+    /// DNS 记录加载完成
+    DnsRecords {
+        account_id: String,
+        domain_id: String,
+        result: Result<Vec<DnsRecord>, String>,
+    },
+
+    /// 工具箱查询完成
+    Toolbox {
+        query: ToolboxQuery,
+        result: Result<Vec<ToolboxLine>, String>,
+    },
+}
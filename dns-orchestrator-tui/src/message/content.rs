@@ -16,6 +16,8 @@ pub enum ContentMessage {
     SelectLast,
     /// 确认选择（进入详情或执行操作）
     Confirm,
+    /// 选择指定项（鼠标点击）
+    Select(usize),
 
     // ========== CRUD 操作 ==========
     /// 添加新项目
@@ -31,9 +33,35 @@ pub enum ContentMessage {
     /// 导出
     Export,
 
+    // ========== 剪贴板 ==========
+    /// 复制当前选中项的值到剪贴板
+    Copy,
+
+    // ========== 过滤 ==========
+    /// 打开过滤输入框
+    StartFilter,
+    /// 输入过滤字符
+    FilterInput(char),
+    /// 删除最后一个过滤字符
+    FilterBackspace,
+    /// 确认过滤（关闭输入框，保留过滤结果）
+    ApplyFilter,
+    /// 清除过滤
+    ClearFilter,
+
+    // ========== 排序 ==========
+    /// 切换排序列
+    CycleSort,
+    /// 切换排序方向
+    ToggleSortDirection,
+
     // ========== 工具箱专用 ==========
     /// 切换工具标签页
     SwitchTab,
     /// 执行工具
     Execute,
+    /// 向上滚动结果面板
+    ScrollUp,
+    /// 向下滚动结果面板
+    ScrollDown,
 }
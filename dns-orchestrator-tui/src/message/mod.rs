@@ -95,11 +95,13 @@
 //! 
 
 mod app;
+mod backend;
 mod modal;
 mod content;
 mod navigation;
 
 pub use app::AppMessage;
+pub use backend::{BackendMessage, BackendTask, ToolboxQuery};
 pub use content::ContentMessage;
 pub use modal::ModalMessage;
 pub use navigation::NavigationMessage;
\ No newline at end of file
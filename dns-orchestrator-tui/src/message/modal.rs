@@ -35,4 +35,7 @@ pub enum ModalMessage {
 
     /// 切换密码可见性
     ToggleSecrets,
+
+    /// 复制查询结果（或输入内容）到剪贴板
+    Copy,
 }
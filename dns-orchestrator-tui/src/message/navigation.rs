@@ -13,4 +13,6 @@ pub enum NavigationMessage {
     SelectFirst,
     /// 跳转到最后一项
     SelectLast,
+    /// 选中并进入指定项（鼠标点击）
+    Open(usize),
 }
\ No newline at end of file
@@ -1,10 +1,18 @@
 //! 应用主状态结构
 
+use std::time::{Duration, Instant};
+
+use crate::backend::{ConfigService, LocalConfigService};
+use crate::message::BackendTask;
+
 use super::{
-    AccountsState, DnsRecordsState, DomainsState, FocusPanel, ModalState, NavigationState, Page,
-    ToolboxState,
+    AccountsState, DnsRecordsState, DomainsState, FocusPanel, LogsState, ModalState,
+    NavigationState, Page, SettingsState, ToolboxState,
 };
 
+/// 短暂状态消息的显示时长
+const TRANSIENT_STATUS_DURATION: Duration = Duration::from_secs(2);
+
 /// 应用主状态
 pub struct App {
     /// 是否应该退出
@@ -22,6 +30,9 @@ pub struct App {
     /// 状态栏消息
     pub status_message: Option<String>,
 
+    /// 状态栏消息的过期时间（None 表示常驻）
+    pub status_expires_at: Option<Instant>,
+
     // === 各页面状态 ===
     /// 账号页面状态
     pub accounts: AccountsState,
@@ -31,9 +42,22 @@ pub struct App {
     pub dns_records: DnsRecordsState,
     /// 工具箱页面状态
     pub toolbox: ToolboxState,
+    /// 设置页面状态
+    pub settings: SettingsState,
 
     /// 弹窗状态
     pub modal: ModalState,
+
+    /// 日志面板状态
+    pub logs: LogsState,
+
+    /// 待执行的后台任务（由主循环取出并交给 TaskRunner）
+    pub pending_tasks: Vec<BackendTask>,
+    /// 已派发但尚未返回结果的后台任务数
+    pub tasks_in_flight: usize,
+
+    /// 动画帧计数（后台任务执行期间定时递增，驱动加载动画）
+    pub tick: usize,
 }
 
 impl App {
@@ -45,11 +69,17 @@ impl App {
             navigation: NavigationState::new(),
             current_page: Page::Home,
             status_message: None,
+            status_expires_at: None,
             accounts: AccountsState::new(),
             domains: DomainsState::new(),
             dns_records: DnsRecordsState::new(),
             toolbox: ToolboxState::new(),
+            settings: load_settings(),
             modal: ModalState::new(),
+            logs: LogsState::new(),
+            pending_tasks: Vec::new(),
+            tasks_in_flight: 0,
+            tick: 0,
         };
 
         // 加载模拟数据（开发阶段）
@@ -62,11 +92,52 @@ impl App {
     /// 设置状态消息
     pub fn set_status(&mut self, message: impl Into<String>) {
         self.status_message = Some(message.into());
+        self.status_expires_at = None;
+    }
+
+    /// 设置短暂显示的状态消息（到期后由主循环清除）
+    pub fn set_transient_status(&mut self, message: impl Into<String>) {
+        self.status_message = Some(message.into());
+        self.status_expires_at = Some(Instant::now() + TRANSIENT_STATUS_DURATION);
+    }
+
+    /// 状态消息是否已过期
+    pub fn status_expired(&self) -> bool {
+        self.status_expires_at
+            .is_some_and(|expires_at| Instant::now() >= expires_at)
     }
 
     /// 清除状态消息
     pub fn clear_status(&mut self) {
         self.status_message = None;
+        self.status_expires_at = None;
+    }
+
+    /// 请求执行后台任务
+    pub fn request(&mut self, task: BackendTask) {
+        self.pending_tasks.push(task);
+    }
+
+    /// 取出所有待执行的后台任务
+    pub fn take_tasks(&mut self) -> Vec<BackendTask> {
+        let tasks = std::mem::take(&mut self.pending_tasks);
+        self.tasks_in_flight += tasks.len();
+        tasks
+    }
+
+    /// 记录一个后台任务已返回结果
+    pub fn task_finished(&mut self) {
+        self.tasks_in_flight = self.tasks_in_flight.saturating_sub(1);
+    }
+
+    /// 是否有后台任务正在执行
+    pub fn is_busy(&self) -> bool {
+        self.tasks_in_flight > 0
+    }
+
+    /// 推进加载动画
+    pub fn advance_tick(&mut self) {
+        self.tick = self.tick.wrapping_add(1);
     }
 }
 
@@ -74,4 +145,15 @@ impl Default for App {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+
+/// 从配置文件加载设置，失败时使用默认设置
+fn load_settings() -> SettingsState {
+    match LocalConfigService.load() {
+        Ok(config) => config.into_settings(),
+        Err(e) => {
+            log::warn!("Failed to load settings: {:#}", e);
+            SettingsState::new()
+        }
+    }
+}
@@ -171,7 +171,7 @@
 //!
 //!         Modal 枚举：每种弹窗都是一个变体，携带该弹窗的所有数据
 //!             - AddAccount { provider_index, name, credential_values, focus, ... }
-//!             - ConfirmDelete { item_type, item_name, item_id, focus }
+//!             - ConfirmDelete { item_type, item_name, item_id, required_text, typed_text, focus }
 //!             - DnsLookup { domain, record_type_index, result, loading, ... }
 //!             - Help, Error { title, message }
 //!             - ... 其他工具弹窗
@@ -207,5 +207,6 @@ pub use focus::FocusPanel;
 pub use navigation::{NavItem, NavItemId, NavigationState};
 pub use page::Page;
 pub use state::{
-    AccountsState, DnsRecordsState, DomainsState, Modal, ModalState, ToolboxState, ToolboxTab,
+    AccountsState, DnsRecordsState, DomainsState, Language, LogsState, Modal, ModalState,
+    PaginationMode, SettingsState, Theme, ToolboxState, ToolboxTab,
 };
@@ -47,6 +47,13 @@ impl AccountsState {
         }
     }
 
+    /// 选择指定项（越界时忽略）
+    pub fn select(&mut self, index: usize) {
+        if index < self.accounts.len() {
+            self.selected = index;
+        }
+    }
+
     /// 获取当前选中的账号
     pub fn selected_account(&self) -> Option<&Account> {
         self.accounts.get(self.selected)
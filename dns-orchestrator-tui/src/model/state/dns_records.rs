@@ -1,6 +1,9 @@
 //! DNS 记录页面状态
 
-use crate::model::domain::{DnsRecord, RecordData};
+use std::cmp::Ordering;
+
+use super::sort::{sort_label, DnsRecordSortColumn, SortDirection};
+use crate::model::domain::DnsRecord;
 
 /// DNS 记录页面状态
 #[derive(Debug, Default)]
@@ -13,6 +16,14 @@ pub struct DnsRecordsState {
     pub loading: bool,
     /// 错误信息
     pub error: Option<String>,
+    /// 过滤关键词
+    pub filter: String,
+    /// 是否正在输入过滤关键词
+    pub filtering: bool,
+    /// 排序列（None 表示保持服务商返回的顺序）
+    pub sort_by: Option<DnsRecordSortColumn>,
+    /// 排序方向
+    pub sort_dir: SortDirection,
     /// 当前域名 ID
     pub domain_id: String,
     /// 当前账号 ID
@@ -34,7 +45,8 @@ impl DnsRecordsState {
 
     /// 选择下一项
     pub fn select_next(&mut self) {
-        if !self.records.is_empty() && self.selected < self.records.len() - 1 {
+        let len = self.visible_indices().len();
+        if len > 0 && self.selected < len - 1 {
             self.selected += 1;
         }
     }
@@ -46,14 +58,111 @@ impl DnsRecordsState {
 
     /// 选择最后一项
     pub fn select_last(&mut self) {
-        if !self.records.is_empty() {
-            self.selected = self.records.len() - 1;
+        let len = self.visible_indices().len();
+        if len > 0 {
+            self.selected = len - 1;
+        }
+    }
+
+    /// 选择指定项（越界时忽略）
+    pub fn select(&mut self, index: usize) {
+        if index < self.visible_indices().len() {
+            self.selected = index;
         }
     }
 
     /// 获取当前选中的记录
     pub fn selected_record(&self) -> Option<&DnsRecord> {
-        self.records.get(self.selected)
+        let index = *self.visible_indices().get(self.selected)?;
+        self.records.get(index)
+    }
+
+    /// 获取过滤、排序后可见项在 `records` 中的下标
+    pub fn visible_indices(&self) -> Vec<usize> {
+        let keyword = self.filter.to_lowercase();
+        let mut indices: Vec<usize> = self
+            .records
+            .iter()
+            .enumerate()
+            .filter(|(_, record)| {
+                keyword.is_empty()
+                    || (record.name.to_lowercase().contains(&keyword)
+                        || record
+                            .data
+                            .display_value()
+                            .to_lowercase()
+                            .contains(&keyword))
+            })
+            .map(|(i, _)| i)
+            .collect();
+
+        if let Some(column) = self.sort_by {
+            indices.sort_by(|&a, &b| {
+                self.sort_dir
+                    .apply(compare(column, &self.records[a], &self.records[b]))
+            });
+        }
+        indices
+    }
+
+    /// 当前选中项在 `records` 中的下标
+    fn selected_index(&self) -> Option<usize> {
+        self.visible_indices().get(self.selected).copied()
+    }
+
+    /// 重新排序后让选中项跟随原来的条目
+    fn reselect(&mut self, index: Option<usize>) {
+        self.selected = index
+            .and_then(|index| self.visible_indices().iter().position(|&i| i == index))
+            .unwrap_or(0);
+    }
+
+    /// 切换排序列
+    pub fn cycle_sort(&mut self) {
+        let index = self.selected_index();
+        self.sort_by = DnsRecordSortColumn::cycle(self.sort_by);
+        self.reselect(index);
+    }
+
+    /// 切换排序方向
+    pub fn toggle_sort_direction(&mut self) {
+        let index = self.selected_index();
+        self.sort_dir = self.sort_dir.toggle();
+        self.reselect(index);
+    }
+
+    /// 排序状态描述
+    pub fn sort_label(&self) -> String {
+        sort_label(self.sort_by.map(DnsRecordSortColumn::name), self.sort_dir)
+    }
+
+    /// 开始输入过滤关键词
+    pub fn start_filter(&mut self) {
+        self.filtering = true;
+    }
+
+    /// 追加过滤字符
+    pub fn push_filter_char(&mut self, ch: char) {
+        self.filter.push(ch);
+        self.selected = 0;
+    }
+
+    /// 删除最后一个过滤字符
+    pub fn pop_filter_char(&mut self) {
+        self.filter.pop();
+        self.selected = 0;
+    }
+
+    /// 结束输入，保留过滤结果
+    pub fn apply_filter(&mut self) {
+        self.filtering = false;
+    }
+
+    /// 清除过滤
+    pub fn clear_filter(&mut self) {
+        self.filter.clear();
+        self.filtering = false;
+        self.selected = 0;
     }
 
     /// 设置记录列表
@@ -69,62 +178,20 @@ impl DnsRecordsState {
         self.account_id = account_id;
         self.domain_id = domain_id;
         self.records.clear();
-        self.selected = 0;
+        self.clear_filter();
     }
+}
 
-    /// 添加模拟数据（开发测试用）
-    pub fn load_mock_data(&mut self) {
-        self.records = vec![
-            DnsRecord {
-                id: "rec_1".to_string(),
-                domain_id: self.domain_id.clone(),
-                name: "@".to_string(),
-                data: RecordData::A {
-                    address: "1.2.3.4".to_string(),
-                },
-                ttl: 600,
-                proxied: Some(false),
-                created_at: None,
-                updated_at: None,
-            },
-            DnsRecord {
-                id: "rec_2".to_string(),
-                domain_id: self.domain_id.clone(),
-                name: "www".to_string(),
-                data: RecordData::Cname {
-                    target: "example.com".to_string(),
-                },
-                ttl: 600,
-                proxied: Some(true),
-                created_at: None,
-                updated_at: None,
-            },
-            DnsRecord {
-                id: "rec_3".to_string(),
-                domain_id: self.domain_id.clone(),
-                name: "@".to_string(),
-                data: RecordData::Mx {
-                    priority: 10,
-                    exchange: "mail.example.com".to_string(),
-                },
-                ttl: 3600,
-                proxied: None,
-                created_at: None,
-                updated_at: None,
-            },
-            DnsRecord {
-                id: "rec_4".to_string(),
-                domain_id: self.domain_id.clone(),
-                name: "@".to_string(),
-                data: RecordData::Txt {
-                    text: "v=spf1 include:_spf.google.com ~all".to_string(),
-                },
-                ttl: 3600,
-                proxied: None,
-                created_at: None,
-                updated_at: None,
-            },
-        ];
-        self.loading = false;
+/// 按指定列比较两个记录
+fn compare(column: DnsRecordSortColumn, a: &DnsRecord, b: &DnsRecord) -> Ordering {
+    match column {
+        DnsRecordSortColumn::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        DnsRecordSortColumn::Type => a
+            .data
+            .record_type()
+            .as_str()
+            .cmp(b.data.record_type().as_str()),
+        DnsRecordSortColumn::Ttl => a.ttl.cmp(&b.ttl),
+        DnsRecordSortColumn::Value => a.data.display_value().cmp(&b.data.display_value()),
     }
 }
@@ -1,5 +1,8 @@
 //! 域名页面状态
 
+use std::cmp::Ordering;
+
+use super::sort::{sort_label, DomainSortColumn, SortDirection};
 use crate::model::domain::{Domain, DomainStatus, ProviderType};
 
 /// 域名页面状态
@@ -13,6 +16,14 @@ pub struct DomainsState {
     pub loading: bool,
     /// 错误信息
     pub error: Option<String>,
+    /// 过滤关键词
+    pub filter: String,
+    /// 是否正在输入过滤关键词
+    pub filtering: bool,
+    /// 排序列（None 表示保持服务商返回的顺序）
+    pub sort_by: Option<DomainSortColumn>,
+    /// 排序方向
+    pub sort_dir: SortDirection,
 }
 
 impl DomainsState {
@@ -30,7 +41,8 @@ impl DomainsState {
 
     /// 选择下一项
     pub fn select_next(&mut self) {
-        if !self.domains.is_empty() && self.selected < self.domains.len() - 1 {
+        let len = self.visible_indices().len();
+        if len > 0 && self.selected < len - 1 {
             self.selected += 1;
         }
     }
@@ -42,14 +54,105 @@ impl DomainsState {
 
     /// 选择最后一项
     pub fn select_last(&mut self) {
-        if !self.domains.is_empty() {
-            self.selected = self.domains.len() - 1;
+        let len = self.visible_indices().len();
+        if len > 0 {
+            self.selected = len - 1;
+        }
+    }
+
+    /// 选择指定项（越界时忽略）
+    pub fn select(&mut self, index: usize) {
+        if index < self.visible_indices().len() {
+            self.selected = index;
         }
     }
 
     /// 获取当前选中的域名
     pub fn selected_domain(&self) -> Option<&Domain> {
-        self.domains.get(self.selected)
+        let index = *self.visible_indices().get(self.selected)?;
+        self.domains.get(index)
+    }
+
+    /// 获取过滤、排序后可见项在 `domains` 中的下标
+    pub fn visible_indices(&self) -> Vec<usize> {
+        let keyword = self.filter.to_lowercase();
+        let mut indices: Vec<usize> = self
+            .domains
+            .iter()
+            .enumerate()
+            .filter(|(_, domain)| {
+                keyword.is_empty() || domain.name.to_lowercase().contains(&keyword)
+            })
+            .map(|(i, _)| i)
+            .collect();
+
+        if let Some(column) = self.sort_by {
+            indices.sort_by(|&a, &b| {
+                self.sort_dir
+                    .apply(compare(column, &self.domains[a], &self.domains[b]))
+            });
+        }
+        indices
+    }
+
+    /// 当前选中项在 `domains` 中的下标
+    fn selected_index(&self) -> Option<usize> {
+        self.visible_indices().get(self.selected).copied()
+    }
+
+    /// 重新排序后让选中项跟随原来的条目
+    fn reselect(&mut self, index: Option<usize>) {
+        self.selected = index
+            .and_then(|index| self.visible_indices().iter().position(|&i| i == index))
+            .unwrap_or(0);
+    }
+
+    /// 切换排序列
+    pub fn cycle_sort(&mut self) {
+        let index = self.selected_index();
+        self.sort_by = DomainSortColumn::cycle(self.sort_by);
+        self.reselect(index);
+    }
+
+    /// 切换排序方向
+    pub fn toggle_sort_direction(&mut self) {
+        let index = self.selected_index();
+        self.sort_dir = self.sort_dir.toggle();
+        self.reselect(index);
+    }
+
+    /// 排序状态描述
+    pub fn sort_label(&self) -> String {
+        sort_label(self.sort_by.map(DomainSortColumn::name), self.sort_dir)
+    }
+
+    /// 开始输入过滤关键词
+    pub fn start_filter(&mut self) {
+        self.filtering = true;
+    }
+
+    /// 追加过滤字符
+    pub fn push_filter_char(&mut self, ch: char) {
+        self.filter.push(ch);
+        self.selected = 0;
+    }
+
+    /// 删除最后一个过滤字符
+    pub fn pop_filter_char(&mut self) {
+        self.filter.pop();
+        self.selected = 0;
+    }
+
+    /// 结束输入，保留过滤结果
+    pub fn apply_filter(&mut self) {
+        self.filtering = false;
+    }
+
+    /// 清除过滤
+    pub fn clear_filter(&mut self) {
+        self.filter.clear();
+        self.filtering = false;
+        self.selected = 0;
     }
 
     /// 设置域名列表
@@ -91,3 +194,23 @@ impl DomainsState {
         self.loading = false;
     }
 }
+
+/// 按指定列比较两个域名
+fn compare(column: DomainSortColumn, a: &Domain, b: &Domain) -> Ordering {
+    match column {
+        DomainSortColumn::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        DomainSortColumn::Status => status_rank(&a.status).cmp(&status_rank(&b.status)),
+        DomainSortColumn::RecordCount => a.record_count.cmp(&b.record_count),
+    }
+}
+
+/// 域名状态的排序权重（正常的排在前面）
+fn status_rank(status: &DomainStatus) -> u8 {
+    match status {
+        DomainStatus::Active => 0,
+        DomainStatus::Pending => 1,
+        DomainStatus::Paused => 2,
+        DomainStatus::Error => 3,
+        DomainStatus::Unknown => 4,
+    }
+}
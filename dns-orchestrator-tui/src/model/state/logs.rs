@@ -0,0 +1,101 @@
+//! 日志面板状态
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Local};
+use log::{Level, LevelFilter};
+
+/// 日志缓冲区最多保留的条数
+const LOG_CAPACITY: usize = 500;
+
+/// 一条日志
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    /// 记录时间
+    pub time: DateTime<Local>,
+    /// 日志级别
+    pub level: Level,
+    /// 日志内容
+    pub message: String,
+}
+
+/// 日志环形缓冲区
+///
+/// 由全局 logger 写入、日志面板读取，超出容量时丢弃最旧的条目。
+#[derive(Debug, Clone, Default)]
+pub struct LogBuffer {
+    entries: Arc<Mutex<VecDeque<LogEntry>>>,
+}
+
+impl LogBuffer {
+    /// 追加一条日志
+    pub fn push(&self, entry: LogEntry) {
+        let Ok(mut entries) = self.entries.lock() else {
+            return;
+        };
+        if entries.len() >= LOG_CAPACITY {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+
+    /// 获取不低于指定级别的最近 `limit` 条日志（按时间顺序）
+    pub fn recent(&self, level: LevelFilter, limit: usize) -> Vec<LogEntry> {
+        let Ok(entries) = self.entries.lock() else {
+            return Vec::new();
+        };
+        let mut recent: Vec<LogEntry> = entries
+            .iter()
+            .rev()
+            .filter(|entry| entry.level <= level)
+            .take(limit)
+            .cloned()
+            .collect();
+        recent.reverse();
+        recent
+    }
+}
+
+/// 日志面板状态
+#[derive(Debug, Clone)]
+pub struct LogsState {
+    /// 是否显示日志面板
+    pub visible: bool,
+    /// 显示的最低日志级别
+    pub level: LevelFilter,
+    /// 日志缓冲区
+    pub buffer: LogBuffer,
+}
+
+impl Default for LogsState {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            level: LevelFilter::Info,
+            buffer: LogBuffer::default(),
+        }
+    }
+}
+
+impl LogsState {
+    /// 创建新的日志面板状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 显示/隐藏日志面板
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    /// 切换显示的最低日志级别（Error → Warn → Info → Debug → Error）
+    pub fn cycle_level(&mut self) {
+        self.level = match self.level {
+            LevelFilter::Error => LevelFilter::Warn,
+            LevelFilter::Warn => LevelFilter::Info,
+            LevelFilter::Info => LevelFilter::Debug,
+            _ => LevelFilter::Error,
+        };
+    }
+}
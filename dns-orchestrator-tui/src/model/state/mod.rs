@@ -5,14 +5,19 @@
 mod accounts;
 mod dns_records;
 mod domains;
+mod logs;
 mod modal;
+mod settings;
+mod sort;
 mod toolbox;
 
 pub use accounts::AccountsState;
 pub use dns_records::DnsRecordsState;
 pub use domains::DomainsState;
+pub use logs::{LogBuffer, LogEntry, LogsState};
 pub use modal::{
     get_all_dns_servers, get_all_providers, get_all_record_types, get_credential_fields,
     DnsRecordTypeOption, DnsServerOption, Modal, ModalState,
 };
-pub use toolbox::{ToolboxState, ToolboxTab};
+pub use settings::{Language, PaginationMode, SettingsItem, SettingsState, Theme};
+pub use toolbox::{toolbox_plain_text, ToolboxLine, ToolboxOutput, ToolboxState, ToolboxTab};
@@ -2,6 +2,8 @@
 
 use crate::model::domain::ProviderType;
 
+use super::ToolboxTab;
+
 /// DNS 记录类型（用于 DNS Lookup 工具）
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DnsRecordTypeOption {
@@ -195,6 +197,10 @@ pub enum Modal {
         item_name: String,
         /// 项目 ID
         item_id: String,
+        /// 需要输入的确认文本（None 表示无需输入，直接确认）
+        required_text: Option<String>,
+        /// 已输入的确认文本
+        typed_text: String,
         /// 焦点：0=取消, 1=确认
         focus: usize,
     },
@@ -313,6 +319,85 @@ impl Modal {
         let credential_count = get_credential_fields(provider).len();
         2 + credential_count // 服务商 + 名称 + 凭证字段
     }
+
+    /// 获取可复制到剪贴板的内容（查询结果优先，否则为输入内容）
+    pub fn copyable_text(&self) -> Option<String> {
+        match self {
+            Self::DnsLookup { domain, result, .. }
+            | Self::WhoisLookup { domain, result, .. }
+            | Self::SslCheck { domain, result, .. }
+            | Self::DnsPropagation { domain, result, .. }
+            | Self::DnssecCheck { domain, result, .. } => {
+                result.clone().or_else(|| non_empty(domain))
+            }
+            Self::IpLookup { input, result, .. } => result.clone().or_else(|| non_empty(input)),
+            Self::HttpHeaderCheck { url, result, .. } => result.clone().or_else(|| non_empty(url)),
+            Self::Error { message, .. } => Some(message.clone()),
+            _ => None,
+        }
+    }
+
+    /// 工具弹窗对应的工具箱标签页
+    pub fn toolbox_tab(&self) -> Option<ToolboxTab> {
+        match self {
+            Self::DnsLookup { .. } => Some(ToolboxTab::DnsLookup),
+            Self::WhoisLookup { .. } => Some(ToolboxTab::Whois),
+            Self::SslCheck { .. } => Some(ToolboxTab::SslCheck),
+            Self::IpLookup { .. } => Some(ToolboxTab::IpLookup),
+            Self::HttpHeaderCheck { .. } => Some(ToolboxTab::HttpHeaderCheck),
+            Self::DnsPropagation { .. } => Some(ToolboxTab::DnsPropagation),
+            Self::DnssecCheck { .. } => Some(ToolboxTab::DnssecCheck),
+            _ => None,
+        }
+    }
+
+    /// 写入工具弹窗的查询结果，并结束加载状态
+    pub fn set_tool_result(&mut self, text: String) {
+        match self {
+            Self::DnsLookup {
+                result, loading, ..
+            }
+            | Self::WhoisLookup {
+                result, loading, ..
+            }
+            | Self::SslCheck {
+                result, loading, ..
+            }
+            | Self::IpLookup {
+                result, loading, ..
+            }
+            | Self::HttpHeaderCheck {
+                result, loading, ..
+            }
+            | Self::DnsPropagation {
+                result, loading, ..
+            }
+            | Self::DnssecCheck {
+                result, loading, ..
+            } => {
+                *result = Some(text);
+                *loading = false;
+            }
+            _ => {}
+        }
+    }
+
+    /// 确认删除弹窗是否已满足确认条件（无需输入，或输入与要求一致）
+    pub fn is_delete_confirmable(&self) -> bool {
+        match self {
+            Self::ConfirmDelete {
+                required_text: Some(required),
+                typed_text,
+                ..
+            } => typed_text == required,
+            Self::ConfirmDelete { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    (!value.is_empty()).then(|| value.to_string())
 }
 
 /// 弹窗状态
@@ -364,6 +449,29 @@ impl ModalState {
             item_type: item_type.to_string(),
             item_name: item_name.to_string(),
             item_id: item_id.to_string(),
+            required_text: None,
+            typed_text: String::new(),
+            focus: 0,
+        });
+    }
+
+    /// 显示需要输入确认文本的删除弹窗
+    ///
+    /// 用于删除账号、批量删除等不可撤销的操作，
+    /// 输入的内容与 `required_text` 一致后才能确认
+    pub fn show_typed_confirm_delete(
+        &mut self,
+        item_type: &str,
+        item_name: &str,
+        item_id: &str,
+        required_text: &str,
+    ) {
+        self.active = Some(Modal::ConfirmDelete {
+            item_type: item_type.to_string(),
+            item_name: item_name.to_string(),
+            item_id: item_id.to_string(),
+            required_text: Some(required_text.to_string()),
+            typed_text: String::new(),
             focus: 0,
         });
     }
@@ -0,0 +1,240 @@
+//! 设置页面状态
+
+/// 主题
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Theme {
+    #[default]
+    Dark,
+    Light,
+    Solarized,
+    Gruvbox,
+    HighContrast,
+}
+
+impl Theme {
+    /// 获取主题名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            Theme::Dark => "Dark",
+            Theme::Light => "Light",
+            Theme::Solarized => "Solarized",
+            Theme::Gruvbox => "Gruvbox",
+            Theme::HighContrast => "High Contrast",
+        }
+    }
+
+    /// 获取配置文件中的主题代码
+    pub fn code(&self) -> &'static str {
+        match self {
+            Theme::Dark => "dark",
+            Theme::Light => "light",
+            Theme::Solarized => "solarized",
+            Theme::Gruvbox => "gruvbox",
+            Theme::HighContrast => "high-contrast",
+        }
+    }
+
+    /// 从主题代码解析
+    pub fn from_code(code: &str) -> Option<Theme> {
+        match code {
+            "dark" => Some(Theme::Dark),
+            "light" => Some(Theme::Light),
+            "solarized" => Some(Theme::Solarized),
+            "gruvbox" => Some(Theme::Gruvbox),
+            "high-contrast" => Some(Theme::HighContrast),
+            _ => None,
+        }
+    }
+
+    /// 切换到下一个主题
+    pub fn next(&self) -> Theme {
+        match self {
+            Theme::Dark => Theme::Light,
+            Theme::Light => Theme::Solarized,
+            Theme::Solarized => Theme::Gruvbox,
+            Theme::Gruvbox => Theme::HighContrast,
+            Theme::HighContrast => Theme::Dark,
+        }
+    }
+}
+
+/// 界面语言
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    #[default]
+    EnUs,
+    ZhCn,
+}
+
+impl Language {
+    /// 获取语言名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::EnUs => "English",
+            Language::ZhCn => "简体中文",
+        }
+    }
+
+    /// 获取语言代码
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::EnUs => "en-US",
+            Language::ZhCn => "zh-CN",
+        }
+    }
+
+    /// 从语言代码解析（如 `zh-CN`、`zh_CN.UTF-8`）
+    pub fn from_code(code: &str) -> Option<Language> {
+        let code = code.to_ascii_lowercase().replace('_', "-");
+        if code.starts_with("zh") {
+            Some(Language::ZhCn)
+        } else if code.starts_with("en") {
+            Some(Language::EnUs)
+        } else {
+            None
+        }
+    }
+
+    /// 切换到下一个语言
+    pub fn next(&self) -> Language {
+        match self {
+            Language::EnUs => Language::ZhCn,
+            Language::ZhCn => Language::EnUs,
+        }
+    }
+}
+
+/// 分页模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PaginationMode {
+    #[default]
+    Paginated,
+    Infinite,
+}
+
+impl PaginationMode {
+    /// 获取分页模式名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            PaginationMode::Paginated => "Paginated",
+            PaginationMode::Infinite => "Infinite Scroll",
+        }
+    }
+
+    /// 获取配置文件中的分页模式代码
+    pub fn code(&self) -> &'static str {
+        match self {
+            PaginationMode::Paginated => "paginated",
+            PaginationMode::Infinite => "infinite",
+        }
+    }
+
+    /// 从分页模式代码解析
+    pub fn from_code(code: &str) -> Option<PaginationMode> {
+        match code {
+            "paginated" => Some(PaginationMode::Paginated),
+            "infinite" => Some(PaginationMode::Infinite),
+            _ => None,
+        }
+    }
+
+    /// 切换到另一种分页模式
+    pub fn next(&self) -> PaginationMode {
+        match self {
+            PaginationMode::Paginated => PaginationMode::Infinite,
+            PaginationMode::Infinite => PaginationMode::Paginated,
+        }
+    }
+}
+
+/// 设置项
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsItem {
+    Theme,
+    Language,
+    PaginationMode,
+}
+
+impl SettingsItem {
+    /// 获取设置项名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            SettingsItem::Theme => "Theme",
+            SettingsItem::Language => "Language",
+            SettingsItem::PaginationMode => "Pagination Mode",
+        }
+    }
+
+    /// 获取所有设置项
+    pub fn all() -> &'static [SettingsItem] {
+        &[
+            SettingsItem::Theme,
+            SettingsItem::Language,
+            SettingsItem::PaginationMode,
+        ]
+    }
+}
+
+/// 设置页面状态
+#[derive(Debug, Default)]
+pub struct SettingsState {
+    /// 当前选中的设置项索引
+    pub selected: usize,
+    /// 当前主题
+    pub theme: Theme,
+    /// 界面语言
+    pub language: Language,
+    /// 分页模式
+    pub pagination_mode: PaginationMode,
+}
+
+impl SettingsState {
+    /// 创建新的设置状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 选择上一项
+    pub fn select_previous(&mut self) {
+        if self.selected > 0 {
+            self.selected -= 1;
+        }
+    }
+
+    /// 选择下一项
+    pub fn select_next(&mut self) {
+        if self.selected < SettingsItem::all().len() - 1 {
+            self.selected += 1;
+        }
+    }
+
+    /// 选择指定项（越界时忽略）
+    pub fn select(&mut self, index: usize) {
+        if index < SettingsItem::all().len() {
+            self.selected = index;
+        }
+    }
+
+    /// 获取当前选中的设置项
+    pub fn selected_item(&self) -> SettingsItem {
+        SettingsItem::all()[self.selected]
+    }
+
+    /// 切换当前选中的设置项
+    pub fn cycle_selected(&mut self) {
+        match self.selected_item() {
+            SettingsItem::Theme => self.theme = self.theme.next(),
+            SettingsItem::Language => self.language = self.language.next(),
+            SettingsItem::PaginationMode => self.pagination_mode = self.pagination_mode.next(),
+        }
+    }
+
+    /// 获取设置项当前值的显示文本
+    pub fn value_of(&self, item: SettingsItem) -> &'static str {
+        match item {
+            SettingsItem::Theme => self.theme.name(),
+            SettingsItem::Language => self.language.name(),
+            SettingsItem::PaginationMode => self.pagination_mode.name(),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! 列表排序
+
+use std::cmp::Ordering;
+
+/// 排序方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    /// 切换方向
+    pub fn toggle(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+
+    /// 按方向调整比较结果
+    pub fn apply(self, ordering: Ordering) -> Ordering {
+        match self {
+            Self::Ascending => ordering,
+            Self::Descending => ordering.reverse(),
+        }
+    }
+
+    /// 方向指示符
+    pub fn arrow(self) -> &'static str {
+        match self {
+            Self::Ascending => "↑",
+            Self::Descending => "↓",
+        }
+    }
+}
+
+/// 域名列表排序列
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DomainSortColumn {
+    Name,
+    Status,
+    RecordCount,
+}
+
+impl DomainSortColumn {
+    /// 列名
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Name => "Name",
+            Self::Status => "Status",
+            Self::RecordCount => "Records",
+        }
+    }
+
+    /// 切换到下一列（最后一列之后回到不排序）
+    pub fn cycle(current: Option<Self>) -> Option<Self> {
+        match current {
+            None => Some(Self::Name),
+            Some(Self::Name) => Some(Self::Status),
+            Some(Self::Status) => Some(Self::RecordCount),
+            Some(Self::RecordCount) => None,
+        }
+    }
+}
+
+/// DNS 记录列表排序列
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DnsRecordSortColumn {
+    Name,
+    Type,
+    Ttl,
+    Value,
+}
+
+impl DnsRecordSortColumn {
+    /// 列名
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Name => "Name",
+            Self::Type => "Type",
+            Self::Ttl => "TTL",
+            Self::Value => "Value",
+        }
+    }
+
+    /// 切换到下一列（最后一列之后回到不排序）
+    pub fn cycle(current: Option<Self>) -> Option<Self> {
+        match current {
+            None => Some(Self::Name),
+            Some(Self::Name) => Some(Self::Type),
+            Some(Self::Type) => Some(Self::Ttl),
+            Some(Self::Ttl) => Some(Self::Value),
+            Some(Self::Value) => None,
+        }
+    }
+}
+
+/// 排序状态描述（用于状态栏）
+pub fn sort_label(column: Option<&'static str>, direction: SortDirection) -> String {
+    match column {
+        Some(name) => format!("Sort: {} {}", name, direction.arrow()),
+        None => "Sort: default order".to_string(),
+    }
+}
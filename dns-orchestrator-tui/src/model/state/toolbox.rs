@@ -1,7 +1,9 @@
 //! 工具箱页面状态
 
+use std::collections::{HashMap, HashSet};
+
 /// 工具箱标签页
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum ToolboxTab {
     #[default]
     Whois,
@@ -67,19 +69,57 @@ impl ToolboxTab {
     }
 }
 
+/// 工具输出中的一行
+#[derive(Debug, Clone, PartialEq)]
+pub enum ToolboxLine {
+    /// 分节标题
+    Heading(String),
+    /// 字段（标签、值）
+    Field(String, String),
+    /// 普通文本
+    Text(String),
+}
+
+impl ToolboxLine {
+    /// 转换为纯文本
+    pub fn to_plain(&self) -> String {
+        match self {
+            ToolboxLine::Heading(title) => format!("[{}]", title),
+            ToolboxLine::Field(label, value) => format!("{}: {}", label, value),
+            ToolboxLine::Text(text) => text.clone(),
+        }
+    }
+}
+
+/// 将工具输出转换为纯文本（用于弹窗显示和复制）
+pub fn toolbox_plain_text(lines: &[ToolboxLine]) -> String {
+    lines
+        .iter()
+        .map(ToolboxLine::to_plain)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 工具的一次执行结果
+#[derive(Debug, Clone)]
+pub struct ToolboxOutput {
+    /// 查询目标（域名、IP 或 URL）
+    pub target: String,
+    /// 结构化输出或错误信息
+    pub result: Result<Vec<ToolboxLine>, String>,
+}
+
 /// 工具箱页面状态
 #[derive(Debug, Default)]
 pub struct ToolboxState {
     /// 当前选中的标签页
     pub current_tab: ToolboxTab,
-    /// 输入内容
-    pub input: String,
-    /// 是否正在执行
-    pub loading: bool,
-    /// 执行结果
-    pub result: Option<String>,
-    /// 错误信息
-    pub error: Option<String>,
+    /// 正在执行的工具
+    pub running: HashSet<ToolboxTab>,
+    /// 各工具最近一次的执行结果
+    pub outputs: HashMap<ToolboxTab, ToolboxOutput>,
+    /// 结果面板的滚动偏移
+    pub scroll: u16,
 }
 
 impl ToolboxState {
@@ -91,36 +131,46 @@ impl ToolboxState {
     /// 切换到下一个标签页
     pub fn next_tab(&mut self) {
         self.current_tab = self.current_tab.next();
-        self.clear_result();
+        self.scroll = 0;
     }
 
     /// 切换到上一个标签页
     pub fn prev_tab(&mut self) {
         self.current_tab = self.current_tab.prev();
-        self.clear_result();
+        self.scroll = 0;
+    }
+
+    /// 当前标签页的工具是否正在执行
+    pub fn is_running(&self) -> bool {
+        self.running.contains(&self.current_tab)
     }
 
-    /// 清除结果
-    pub fn clear_result(&mut self) {
-        self.result = None;
-        self.error = None;
+    /// 当前标签页最近一次的执行结果
+    pub fn current_output(&self) -> Option<&ToolboxOutput> {
+        self.outputs.get(&self.current_tab)
     }
 
-    /// 设置输入内容
-    pub fn set_input(&mut self, input: String) {
-        self.input = input;
+    /// 标记工具开始执行
+    pub fn start(&mut self, tab: ToolboxTab) {
+        self.running.insert(tab);
     }
 
-    /// 获取当前工具的提示文本
-    pub fn placeholder(&self) -> &'static str {
-        match self.current_tab {
-            ToolboxTab::Whois => "Enter domain (e.g., example.com)",
-            ToolboxTab::DnsLookup => "Enter domain (e.g., example.com)",
-            ToolboxTab::IpLookup => "Enter IP or domain",
-            ToolboxTab::SslCheck => "Enter domain (e.g., example.com)",
-            ToolboxTab::HttpHeaderCheck => "Enter URL (e.g., https://example.com)",
-            ToolboxTab::DnsPropagation => "Enter domain (e.g., example.com)",
-            ToolboxTab::DnssecCheck => "Enter domain (e.g., example.com)",
+    /// 记录工具执行结果
+    pub fn finish(&mut self, tab: ToolboxTab, output: ToolboxOutput) {
+        self.running.remove(&tab);
+        self.outputs.insert(tab, output);
+        if tab == self.current_tab {
+            self.scroll = 0;
         }
     }
+
+    /// 向上滚动结果面板
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    /// 向下滚动结果面板
+    pub fn scroll_down(&mut self) {
+        self.scroll = self.scroll.saturating_add(1);
+    }
 }
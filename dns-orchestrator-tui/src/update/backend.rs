@@ -0,0 +1,85 @@
+//! 后台任务结果更新逻辑
+//!
+//! 把 TaskRunner 回传的结果写入对应的页面状态
+
+use crate::message::BackendMessage;
+use crate::model::state::{toolbox_plain_text, ToolboxOutput};
+use crate::model::App;
+
+/// 处理后台任务结果
+pub fn update(app: &mut App, msg: BackendMessage) {
+    match msg {
+        BackendMessage::Accounts(Ok(accounts)) => {
+            let count = accounts.len();
+            app.accounts.set_accounts(accounts);
+            app.set_status(format!("Loaded {} accounts", count));
+        }
+        BackendMessage::Accounts(Err(e)) => {
+            app.accounts.loading = false;
+            app.accounts.error = Some(e.clone());
+            app.set_status(format!("Failed to load accounts: {}", e));
+        }
+
+        BackendMessage::Domains(Ok(domains)) => {
+            let count = domains.len();
+            app.domains.set_domains(domains);
+            app.set_status(format!("Loaded {} domains", count));
+        }
+        BackendMessage::Domains(Err(e)) => {
+            app.domains.loading = false;
+            app.domains.error = Some(e.clone());
+            app.set_status(format!("Failed to load domains: {}", e));
+        }
+
+        BackendMessage::DnsRecords {
+            account_id,
+            domain_id,
+            result,
+        } => {
+            // 结果返回前已切换到其他域名，丢弃过期结果
+            if app.dns_records.account_id != account_id || app.dns_records.domain_id != domain_id {
+                return;
+            }
+
+            match result {
+                Ok(records) => {
+                    let count = records.len();
+                    app.dns_records.set_records(records);
+                    app.set_status(format!("Loaded {} records", count));
+                }
+                Err(e) => {
+                    app.dns_records.loading = false;
+                    app.dns_records.error = Some(e.clone());
+                    app.set_status(format!("Failed to load records: {}", e));
+                }
+            }
+        }
+
+        BackendMessage::Toolbox { query, result } => {
+            let tab = query.tab();
+            match &result {
+                Ok(_) => app.set_status(format!("{} completed: {}", tab.name(), query.target())),
+                Err(e) => app.set_status(format!("{} failed: {}", tab.name(), e)),
+            }
+
+            // 对应的工具弹窗仍打开时，同步显示结果
+            if let Some(modal) = app.modal.active.as_mut() {
+                if modal.toolbox_tab() == Some(tab) {
+                    let text = match &result {
+                        Ok(lines) => toolbox_plain_text(lines),
+                        Err(e) => format!("Error: {}", e),
+                    };
+                    modal.set_tool_result(text);
+                }
+            }
+
+            app.toolbox.finish(
+                tab,
+                ToolboxOutput {
+                    target: query.target().to_string(),
+                    result,
+                },
+            );
+        }
+    }
+}
@@ -2,8 +2,10 @@
 //!
 //! 处理内容面板中的各种操作消息
 
-use crate::message::ContentMessage;
-use crate::model::{App, Page};
+use crate::message::{BackendTask, ContentMessage};
+use crate::backend::{AppConfig, ConfigService, LocalConfigService};
+use crate::model::state::toolbox_plain_text;
+use crate::model::{App, FocusPanel, Page};
 
 /// 处理内容面板消息
 pub fn update(app: &mut App, msg: ContentMessage) {
@@ -24,6 +26,9 @@ pub fn update(app: &mut App, msg: ContentMessage) {
         ContentMessage::Confirm => {
             handle_confirm(app);
         }
+        ContentMessage::Select(index) => {
+            handle_select(app, index);
+        }
 
         // ========== CRUD 操作 ==========
         ContentMessage::Add => {
@@ -44,6 +49,36 @@ pub fn update(app: &mut App, msg: ContentMessage) {
             handle_export(app);
         }
 
+        // ========== 剪贴板 ==========
+        ContentMessage::Copy => {
+            handle_copy(app);
+        }
+
+        // ========== 过滤 ==========
+        ContentMessage::StartFilter => {
+            handle_start_filter(app);
+        }
+        ContentMessage::FilterInput(ch) => {
+            handle_filter_input(app, ch);
+        }
+        ContentMessage::FilterBackspace => {
+            handle_filter_backspace(app);
+        }
+        ContentMessage::ApplyFilter => {
+            handle_apply_filter(app);
+        }
+        ContentMessage::ClearFilter => {
+            handle_clear_filter(app);
+        }
+
+        // ========== 排序 ==========
+        ContentMessage::CycleSort => {
+            handle_cycle_sort(app);
+        }
+        ContentMessage::ToggleSortDirection => {
+            handle_toggle_sort_direction(app);
+        }
+
         // ========== 工具箱专用 ==========
         ContentMessage::SwitchTab => {
             handle_switch_tab(app);
@@ -51,6 +86,12 @@ pub fn update(app: &mut App, msg: ContentMessage) {
         ContentMessage::Execute => {
             handle_execute(app);
         }
+        ContentMessage::ScrollUp => {
+            app.toolbox.scroll_up();
+        }
+        ContentMessage::ScrollDown => {
+            app.toolbox.scroll_down();
+        }
     }
 }
 
@@ -70,6 +111,9 @@ fn handle_select_previous(app: &mut App) {
         Page::Toolbox => {
             app.toolbox.prev_tab();
         }
+        Page::Settings => {
+            app.settings.select_previous();
+        }
         _ => {}
     }
 }
@@ -88,6 +132,9 @@ fn handle_select_next(app: &mut App) {
         Page::Toolbox => {
             app.toolbox.next_tab();
         }
+        Page::Settings => {
+            app.settings.select_next();
+        }
         _ => {}
     }
 }
@@ -122,6 +169,25 @@ fn handle_select_last(app: &mut App) {
     }
 }
 
+fn handle_select(app: &mut App, index: usize) {
+    app.focus = FocusPanel::Content;
+    match &app.current_page {
+        Page::Accounts => {
+            app.accounts.select(index);
+        }
+        Page::Domains => {
+            app.domains.select(index);
+        }
+        Page::DnsRecords { .. } => {
+            app.dns_records.select(index);
+        }
+        Page::Settings => {
+            app.settings.select(index);
+        }
+        _ => {}
+    }
+}
+
 fn handle_confirm(app: &mut App) {
     match &app.current_page {
         Page::Accounts => {
@@ -136,9 +202,13 @@ fn handle_confirm(app: &mut App) {
                 let account_id = domain.account_id.clone();
                 let domain_id = domain.id.clone();
 
-                // 设置 DNS 记录页面的域名信息
+                // 设置 DNS 记录页面的域名信息，并在后台加载记录
                 app.dns_records.set_domain(account_id.clone(), domain_id.clone());
-                app.dns_records.load_mock_data();
+                app.dns_records.loading = true;
+                app.request(BackendTask::DnsRecords {
+                    account_id: account_id.clone(),
+                    domain_id: domain_id.clone(),
+                });
 
                 // 切换页面
                 app.current_page = Page::DnsRecords {
@@ -155,6 +225,20 @@ fn handle_confirm(app: &mut App) {
                 // TODO: 打开编辑弹窗
             }
         }
+        Page::Settings => {
+            // 切换设置项并立即写入配置文件
+            let item = app.settings.selected_item();
+            app.settings.cycle_selected();
+            let config = AppConfig::from_settings(&app.settings);
+            match LocalConfigService.save(&config) {
+                Ok(()) => app.set_status(format!(
+                    "{}: {}",
+                    item.name(),
+                    app.settings.value_of(item)
+                )),
+                Err(e) => app.set_status(format!("Failed to save settings: {}", e)),
+            }
+        }
         _ => {}
     }
 }
@@ -205,7 +289,13 @@ fn handle_delete(app: &mut App) {
     match &app.current_page {
         Page::Accounts => {
             if let Some(account) = app.accounts.selected_account() {
-                app.modal.show_confirm_delete("account", &account.name, &account.id);
+                // 删除账号不可撤销，需要输入账号名确认
+                app.modal.show_typed_confirm_delete(
+                    "account",
+                    &account.name,
+                    &account.id,
+                    &account.name,
+                );
             } else {
                 app.set_status("No account selected");
             }
@@ -247,6 +337,122 @@ fn handle_export(app: &mut App) {
     }
 }
 
+// ========== 剪贴板处理 ==========
+
+fn handle_copy(app: &mut App) {
+    let value = match &app.current_page {
+        Page::Accounts => app.accounts.selected_account().map(|a| a.name.clone()),
+        Page::Domains => app.domains.selected_domain().map(|d| d.name.clone()),
+        Page::DnsRecords { .. } => app
+            .dns_records
+            .selected_record()
+            .map(|r| r.data.display_value()),
+        Page::Toolbox => app
+            .toolbox
+            .current_output()
+            .and_then(|output| output.result.as_ref().ok())
+            .map(|lines| toolbox_plain_text(lines)),
+        _ => None,
+    };
+    super::copy_value(app, value);
+}
+
+// ========== 过滤处理 ==========
+
+fn handle_start_filter(app: &mut App) {
+    match &app.current_page {
+        Page::Domains => {
+            app.domains.start_filter();
+        }
+        Page::DnsRecords { .. } => {
+            app.dns_records.start_filter();
+        }
+        _ => {
+            app.set_status("Filter not supported on this page");
+        }
+    }
+}
+
+fn handle_filter_input(app: &mut App, ch: char) {
+    match &app.current_page {
+        Page::Domains => {
+            app.domains.push_filter_char(ch);
+        }
+        Page::DnsRecords { .. } => {
+            app.dns_records.push_filter_char(ch);
+        }
+        _ => {}
+    }
+}
+
+fn handle_filter_backspace(app: &mut App) {
+    match &app.current_page {
+        Page::Domains => {
+            app.domains.pop_filter_char();
+        }
+        Page::DnsRecords { .. } => {
+            app.dns_records.pop_filter_char();
+        }
+        _ => {}
+    }
+}
+
+fn handle_apply_filter(app: &mut App) {
+    match &app.current_page {
+        Page::Domains => {
+            app.domains.apply_filter();
+        }
+        Page::DnsRecords { .. } => {
+            app.dns_records.apply_filter();
+        }
+        _ => {}
+    }
+}
+
+fn handle_clear_filter(app: &mut App) {
+    match &app.current_page {
+        Page::Domains => {
+            app.domains.clear_filter();
+        }
+        Page::DnsRecords { .. } => {
+            app.dns_records.clear_filter();
+        }
+        _ => {}
+    }
+}
+
+// ========== 排序处理 ==========
+
+fn handle_cycle_sort(app: &mut App) {
+    let label = match &app.current_page {
+        Page::Domains => {
+            app.domains.cycle_sort();
+            app.domains.sort_label()
+        }
+        Page::DnsRecords { .. } => {
+            app.dns_records.cycle_sort();
+            app.dns_records.sort_label()
+        }
+        _ => "Sort not supported on this page".to_string(),
+    };
+    app.set_status(label);
+}
+
+fn handle_toggle_sort_direction(app: &mut App) {
+    let label = match &app.current_page {
+        Page::Domains => {
+            app.domains.toggle_sort_direction();
+            app.domains.sort_label()
+        }
+        Page::DnsRecords { .. } => {
+            app.dns_records.toggle_sort_direction();
+            app.dns_records.sort_label()
+        }
+        _ => "Sort not supported on this page".to_string(),
+    };
+    app.set_status(label);
+}
+
 // ========== 工具箱处理 ==========
 
 fn handle_switch_tab(app: &mut App) {
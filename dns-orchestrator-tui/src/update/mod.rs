@@ -104,11 +104,12 @@
 
 
 
+mod backend;
 mod content;
 mod modal;
 mod navigation;
 
-use crate::message::AppMessage;
+use crate::message::{AppMessage, BackendTask};
 use crate::model::{App, NavItemId, Page};
 
 
@@ -140,6 +141,11 @@ pub fn update(app: &mut App, msg: AppMessage) {
             modal::update(app, modal_msg);
         }
 
+        AppMessage::Backend(backend_msg) => {
+            app.task_finished();
+            backend::update(app, backend_msg);
+        }
+
         AppMessage::GoBack => {
             // 如果有弹窗打开，先关闭弹窗
             if app.modal.is_open() {
@@ -153,8 +159,7 @@ pub fn update(app: &mut App, msg: AppMessage) {
         }
 
         AppMessage::Refresh => {
-            app.set_status("Refreshing...");
-            // TODO: 触发数据刷新
+            handle_refresh(app);
         }
 
         AppMessage::ShowHelp => {
@@ -162,14 +167,72 @@ pub fn update(app: &mut App, msg: AppMessage) {
             app.modal.show_help();
         }
 
+        AppMessage::ToggleLogs => {
+            app.logs.toggle();
+        }
+
+        AppMessage::CycleLogLevel => {
+            if app.logs.visible {
+                app.logs.cycle_level();
+                app.set_transient_status(format!("Log level: {}", app.logs.level));
+            }
+        }
+
         AppMessage::ClearStatus => {
             app.clear_status();
         }
 
+        AppMessage::Tick => {
+            app.advance_tick();
+        }
+
         AppMessage::Noop => {}
     }
 }
 
+/// 重新加载当前页面的数据
+fn handle_refresh(app: &mut App) {
+    match app.current_page.clone() {
+        Page::Accounts => {
+            app.accounts.loading = true;
+            app.request(BackendTask::Accounts);
+        }
+        Page::Domains => {
+            app.domains.loading = true;
+            app.request(BackendTask::Domains { refresh: true });
+        }
+        Page::DnsRecords {
+            account_id,
+            domain_id,
+        } => {
+            app.dns_records.loading = true;
+            app.request(BackendTask::DnsRecords {
+                account_id,
+                domain_id,
+            });
+        }
+        _ => {
+            app.set_status("Nothing to refresh on this page");
+            return;
+        }
+    }
+
+    app.set_status("Refreshing...");
+}
+
+/// 复制内容到系统剪贴板，并在状态栏短暂提示结果
+fn copy_value(app: &mut App, value: Option<String>) {
+    let Some(value) = value else {
+        app.set_transient_status("Nothing to copy");
+        return;
+    };
+
+    match crate::util::copy_to_clipboard(&value) {
+        Ok(()) => app.set_transient_status("Copied"),
+        Err(e) => app.set_status(format!("Copy failed: {}", e)),
+    }
+}
+
 /// 根据导航项 ID 获取对应的页面
 fn page_from_nav_id(id: NavItemId) -> Page {
     match id {
@@ -1,6 +1,6 @@
 //! 弹窗更新逻辑
 
-use crate::message::ModalMessage;
+use crate::message::{BackendTask, ModalMessage, ToolboxQuery};
 use crate::model::state::{get_all_providers, get_credential_fields, Modal};
 use crate::model::App;
 
@@ -10,6 +10,13 @@ pub fn update(app: &mut App, msg: ModalMessage) {
         return;
     };
 
+    // 复制对所有弹窗通用
+    if matches!(msg, ModalMessage::Copy) {
+        let value = modal.copyable_text();
+        super::copy_value(app, value);
+        return;
+    }
+
     match modal {
         Modal::AddAccount { .. } => handle_add_account(app, msg),
         Modal::ConfirmDelete { .. } => handle_confirm_delete(app, msg),
@@ -148,7 +155,7 @@ fn handle_add_account(app: &mut App, msg: ModalMessage) {
             *show_secrets = !*show_secrets;
         }
 
-        ModalMessage::ToggleDeleteFocus => {
+        ModalMessage::ToggleDeleteFocus | ModalMessage::Copy => {
             // 不适用于此弹窗
         }
     }
@@ -156,10 +163,18 @@ fn handle_add_account(app: &mut App, msg: ModalMessage) {
 
 /// 处理确认删除弹窗
 fn handle_confirm_delete(app: &mut App, msg: ModalMessage) {
+    let confirmable = app
+        .modal
+        .active
+        .as_ref()
+        .is_some_and(Modal::is_delete_confirmable);
+
     let Some(Modal::ConfirmDelete {
         ref item_type,
         ref item_name,
         ref item_id,
+        ref required_text,
+        ref mut typed_text,
         ref mut focus,
     }) = app.modal.active
     else {
@@ -181,8 +196,20 @@ fn handle_confirm_delete(app: &mut App, msg: ModalMessage) {
             *focus = if *focus == 0 { 1 } else { 0 };
         }
 
+        ModalMessage::Input(c) => {
+            typed_text.push(c);
+        }
+
+        ModalMessage::Backspace => {
+            typed_text.pop();
+        }
+
         ModalMessage::Confirm => {
-            if *focus == 1 {
+            if *focus == 1 && !confirmable {
+                // 确认文本不一致，不允许删除
+                let required = required_text.clone().unwrap_or_default();
+                app.set_status(format!("Type \"{}\" to confirm deletion", required));
+            } else if *focus == 1 {
                 // 确认删除
                 let item_type = item_type.clone();
                 let item_name = item_name.clone();
@@ -202,6 +229,14 @@ fn handle_confirm_delete(app: &mut App, msg: ModalMessage) {
     }
 }
 
+/// 发起工具箱查询，结果由 update/backend.rs 写回弹窗和工具箱页面
+fn run_tool(app: &mut App, query: ToolboxQuery) {
+    let tab = query.tab();
+    app.set_status(format!("Running {}: {}", tab.name(), query.target()));
+    app.toolbox.start(tab);
+    app.request(BackendTask::Toolbox(query));
+}
+
 /// 处理简单弹窗（帮助、错误）
 fn handle_simple_modal(app: &mut App, msg: ModalMessage) {
     match msg {
@@ -291,18 +326,15 @@ fn handle_dns_lookup(app: &mut App, msg: ModalMessage) {
             }
 
             *loading = true;
-            let record_type = record_types[*record_type_index].name();
-            let dns_server = dns_servers[*dns_server_index].name();
-
-            // TODO: 实际执行 DNS 查询
-            *result = Some(format!(
-                "DNS Lookup for {} (Type: {}, Server: {})\nResult: (To be implemented)",
-                domain, record_type, dns_server
-            ));
-            *loading = false;
-
-            let domain_clone = domain.clone();
-            app.set_status(format!("DNS query completed: {}", domain_clone));
+            *result = None;
+            let query = ToolboxQuery::DnsLookup {
+                domain: domain.trim().to_string(),
+                record_type: record_types[*record_type_index].name().to_string(),
+                nameserver: dns_servers[*dns_server_index]
+                    .address()
+                    .map(str::to_string),
+            };
+            run_tool(app, query);
         }
 
         ModalMessage::Input(ch) => {
@@ -345,15 +377,9 @@ fn handle_whois_lookup(app: &mut App, msg: ModalMessage) {
             }
 
             *loading = true;
-            // TODO: 实际执行 WHOIS 查询
-            *result = Some(format!(
-                "WHOIS Lookup for {}\nResult: (To be implemented)",
-                domain
-            ));
-            *loading = false;
-
-            let domain_clone = domain.clone();
-            app.set_status(format!("WHOIS query completed: {}", domain_clone));
+            *result = None;
+            let query = ToolboxQuery::Whois { domain: domain.trim().to_string() };
+            run_tool(app, query);
         }
 
         ModalMessage::Input(ch) => {
@@ -392,15 +418,9 @@ fn handle_ssl_check(app: &mut App, msg: ModalMessage) {
             }
 
             *loading = true;
-            // TODO: 实际执行 SSL 证书检查
-            *result = Some(format!(
-                "SSL Certificate Check for {}\nResult: (To be implemented)",
-                domain
-            ));
-            *loading = false;
-
-            let domain_clone = domain.clone();
-            app.set_status(format!("SSL check completed: {}", domain_clone));
+            *result = None;
+            let query = ToolboxQuery::SslCheck { domain: domain.trim().to_string() };
+            run_tool(app, query);
         }
 
         ModalMessage::Input(ch) => {
@@ -439,15 +459,9 @@ fn handle_ip_lookup(app: &mut App, msg: ModalMessage) {
             }
 
             *loading = true;
-            // TODO: 实际执行 IP 查询
-            *result = Some(format!(
-                "IP Lookup for {}\nResult: (To be implemented)",
-                input
-            ));
-            *loading = false;
-
-            let input_clone = input.clone();
-            app.set_status(format!("IP lookup completed: {}", input_clone));
+            *result = None;
+            let query = ToolboxQuery::IpLookup { query: input.trim().to_string() };
+            run_tool(app, query);
         }
 
         ModalMessage::Input(ch) => {
@@ -518,17 +532,12 @@ fn handle_http_header_check(app: &mut App, msg: ModalMessage) {
             }
 
             *loading = true;
-            let method = methods[*method_index];
-
-            // TODO: 实际执行 HTTP 头检查
-            *result = Some(format!(
-                "HTTP Header Check for {} (Method: {})\nResult: (To be implemented)",
-                url, method
-            ));
-            *loading = false;
-
-            let url_clone = url.clone();
-            app.set_status(format!("HTTP header check completed: {}", url_clone));
+            *result = None;
+            let query = ToolboxQuery::HttpHeaders {
+                url: url.trim().to_string(),
+                method: methods[*method_index].to_string(),
+            };
+            run_tool(app, query);
         }
 
         ModalMessage::Input(ch) => {
@@ -549,7 +558,7 @@ fn handle_http_header_check(app: &mut App, msg: ModalMessage) {
 
 /// 处理 DNS 传播检查工具弹窗
 fn handle_dns_propagation(app: &mut App, msg: ModalMessage) {
-    use crate::model::state::get_all_record_types;
+    use crate::model::state::{get_all_record_types, DnsRecordTypeOption};
 
     let Some(Modal::DnsPropagation {
         ref mut domain,
@@ -604,18 +613,19 @@ fn handle_dns_propagation(app: &mut App, msg: ModalMessage) {
                 return;
             }
 
-            *loading = true;
-            let record_type = record_types[*record_type_index].name();
-
-            // TODO: 实际执行 DNS 传播检查
-            *result = Some(format!(
-                "DNS Propagation Check for {} (Type: {})\nResult: (To be implemented)",
-                domain, record_type
-            ));
-            *loading = false;
+            let record_type = record_types[*record_type_index];
+            if record_type == DnsRecordTypeOption::All {
+                app.set_status("Please select a specific record type");
+                return;
+            }
 
-            let domain_clone = domain.clone();
-            app.set_status(format!("DNS propagation check completed: {}", domain_clone));
+            *loading = true;
+            *result = None;
+            let query = ToolboxQuery::DnsPropagation {
+                domain: domain.trim().to_string(),
+                record_type: record_type.name().to_string(),
+            };
+            run_tool(app, query);
         }
 
         ModalMessage::Input(ch) => {
@@ -658,15 +668,9 @@ fn handle_dnssec_check(app: &mut App, msg: ModalMessage) {
             }
 
             *loading = true;
-            // TODO: 实际执行 DNSSEC 验证
-            *result = Some(format!(
-                "DNSSEC Check for {}\nResult: (To be implemented)",
-                domain
-            ));
-            *loading = false;
-
-            let domain_clone = domain.clone();
-            app.set_status(format!("DNSSEC check completed: {}", domain_clone));
+            *result = None;
+            let query = ToolboxQuery::Dnssec { domain: domain.trim().to_string() };
+            run_tool(app, query);
         }
 
         ModalMessage::Input(ch) => {
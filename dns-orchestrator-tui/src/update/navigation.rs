@@ -1,7 +1,7 @@
 //! 导航更新逻辑
 
 use crate::message::NavigationMessage;
-use crate::model::{App, NavItemId, Page};
+use crate::model::{App, FocusPanel, NavItemId, Page};
 
 /// 处理导航消息
 pub fn update(app: &mut App, msg: NavigationMessage) {
@@ -15,10 +15,7 @@ pub fn update(app: &mut App, msg: NavigationMessage) {
         }
 
         NavigationMessage::Confirm => {
-            if let Some(id) = app.navigation.current_id() {
-                app.current_page = page_from_nav_id(id);
-                app.clear_status(); // 切换页面时清除状态消息
-            }
+            open_selected(app);
         }
 
         NavigationMessage::SelectFirst => {
@@ -31,6 +28,22 @@ pub fn update(app: &mut App, msg: NavigationMessage) {
                 app.navigation.selected = len - 1;
             }
         }
+
+        NavigationMessage::Open(index) => {
+            if index < app.navigation.items.len() {
+                app.navigation.selected = index;
+                app.focus = FocusPanel::Navigation;
+                open_selected(app);
+            }
+        }
+    }
+}
+
+/// 进入当前选中的导航项对应的页面
+fn open_selected(app: &mut App) {
+    if let Some(id) = app.navigation.current_id() {
+        app.current_page = page_from_nav_id(id);
+        app.clear_status(); // 切换页面时清除状态消息
     }
 }
 
@@ -0,0 +1,27 @@
+//! 系统剪贴板
+
+use std::cell::RefCell;
+
+use anyhow::Result;
+use arboard::Clipboard;
+
+thread_local! {
+    /// 剪贴板实例
+    ///
+    /// Linux 上剪贴板内容由持有者提供，实例释放后内容会丢失，
+    /// 因此在主线程上复用同一个实例
+    static CLIPBOARD: RefCell<Option<Clipboard>> = const { RefCell::new(None) };
+}
+
+/// 复制文本到系统剪贴板
+pub fn copy_to_clipboard(text: &str) -> Result<()> {
+    CLIPBOARD.with(|cell| {
+        let mut cell = cell.borrow_mut();
+        let clipboard = match cell.as_mut() {
+            Some(clipboard) => clipboard,
+            None => cell.insert(Clipboard::new()?),
+        };
+        clipboard.set_text(text)?;
+        Ok(())
+    })
+}
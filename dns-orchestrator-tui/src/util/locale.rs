@@ -0,0 +1,13 @@
+//! 系统语言检测
+
+use crate::model::Language;
+
+/// 根据系统 locale 推断界面语言
+///
+/// 优先使用 `sys-locale` 获取的系统设置，其次读取 `$LANG`；
+/// 无法识别时返回 `None`，由调用方回退到默认语言
+pub fn system_language() -> Option<Language> {
+    sys_locale::get_locale()
+        .or_else(|| std::env::var("LANG").ok())
+        .and_then(|locale| Language::from_code(&locale))
+}
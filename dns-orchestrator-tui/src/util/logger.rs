@@ -0,0 +1,47 @@
+//! 应用内日志
+//!
+//! 终端处于备用屏幕时无法输出日志，因此把日志写入环形缓冲区，由日志面板显示
+
+use chrono::Local;
+use log::{LevelFilter, Log, Metadata, Record};
+
+use crate::model::state::{LogBuffer, LogEntry};
+
+/// 本项目 crate 的日志前缀（其余依赖只记录警告及以上）
+const OWN_TARGET_PREFIX: &str = "dns_orchestrator";
+
+/// 写入环形缓冲区的 logger
+struct BufferLogger {
+    buffer: LogBuffer,
+}
+
+impl Log for BufferLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.target().starts_with(OWN_TARGET_PREFIX) || metadata.level() <= log::Level::Warn
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        self.buffer.push(LogEntry {
+            time: Local::now(),
+            level: record.level(),
+            message: record.args().to_string(),
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+/// 安装全局 logger，返回其写入的缓冲区
+pub fn init_logger() -> LogBuffer {
+    let buffer = LogBuffer::default();
+    let logger = Box::new(BufferLogger {
+        buffer: buffer.clone(),
+    });
+    if log::set_logger(Box::leak(logger)).is_ok() {
+        log::set_max_level(LevelFilter::Debug);
+    }
+    buffer
+}
@@ -29,6 +29,9 @@
 //! 有模块结构：
 //!     src/util/mod.rs
 //!         mod terminal;       // 终端初始化和恢复
+//!         mod clipboard;      // 系统剪贴板
+//!         mod locale;         // 系统语言检测
+//!         mod logger;         // 应用内日志（写入日志面板的环形缓冲区）
 //!
 //!         pub use terminal::{init_terminal, restore_terminal, Term};
 //!
@@ -119,6 +122,12 @@
 //!     —— 去往 src/app.rs 主循环吧
 //!
 
+mod clipboard;
+mod locale;
+mod logger;
 mod terminal;
 
+pub use clipboard::copy_to_clipboard;
+pub use locale::system_language;
+pub use logger::init_logger;
 pub use terminal::{init_terminal, restore_terminal, Term};
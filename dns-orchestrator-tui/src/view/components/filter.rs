@@ -0,0 +1,47 @@
+//! 列表过滤输入框组件
+
+use crate::view::theme::ThemeColors;
+
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// 若需要显示过滤栏，则渲染于顶部一行，返回剩余的列表区域
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    colors: &ThemeColors,
+    filter: &str,
+    active: bool,
+) -> Rect {
+    if !active && filter.is_empty() {
+        return area;
+    }
+
+    let [bar_area, list_area] =
+        Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area);
+
+    let mut spans = vec![
+        Span::styled("  / ", Style::default().fg(colors.key)),
+        Span::styled(filter.to_string(), Style::default().fg(colors.fg)),
+    ];
+    if active {
+        spans.push(Span::styled("_", Style::default().fg(colors.highlight)));
+    }
+
+    frame.render_widget(Paragraph::new(Line::from(spans)), bar_area);
+    list_area
+}
+
+/// 渲染“无匹配项”提示
+pub fn render_no_match(frame: &mut Frame, area: Rect, colors: &ThemeColors) {
+    let content = vec![
+        Line::from(""),
+        Line::styled("  No matches.", Style::default().fg(colors.text_dim)),
+    ];
+    frame.render_widget(Paragraph::new(content), area);
+}
@@ -0,0 +1,67 @@
+//! 日志面板组件
+
+use log::Level;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::model::App;
+use crate::view::theme::{Styles, ThemeColors};
+
+/// 日志面板高度（含边框）
+pub const LOG_PANEL_HEIGHT: u16 = 10;
+
+/// 渲染日志面板（显示最近的日志，最新的在底部）
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let colors = app.settings.theme.colors();
+    let logs = &app.logs;
+
+    let block = Block::default()
+        .title(format!(" Logs [{}+] ", logs.level))
+        .title_style(Styles::title(&colors))
+        .borders(Borders::ALL)
+        .border_style(Styles::border(&colors));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let entries = logs.buffer.recent(logs.level, usize::from(inner.height));
+    let lines: Vec<Line> = if entries.is_empty() {
+        vec![Line::styled(
+            "  No logs yet.",
+            Style::default().fg(colors.text_dim),
+        )]
+    } else {
+        entries
+            .iter()
+            .map(|entry| {
+                Line::from(vec![
+                    Span::styled(
+                        format!(" {} ", entry.time.format("%H:%M:%S")),
+                        Style::default().fg(colors.muted),
+                    ),
+                    Span::styled(
+                        format!("{:5} ", entry.level),
+                        Style::default().fg(level_color(&colors, entry.level)),
+                    ),
+                    Span::styled(&entry.message, Style::default().fg(colors.fg)),
+                ])
+            })
+            .collect()
+    };
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// 日志级别对应的颜色
+fn level_color(colors: &ThemeColors, level: Level) -> Color {
+    match level {
+        Level::Error => colors.error,
+        Level::Warn => colors.warning,
+        Level::Info => colors.success,
+        Level::Debug | Level::Trace => colors.text_dim,
+    }
+}
@@ -1,7 +1,10 @@
 //! 可复用 UI 组件
 
+pub mod filter;
+pub mod log_panel;
 pub mod modal;
 pub mod navigation;
+pub mod spinner;
 pub mod statusbar;
 
 // 后续扩展
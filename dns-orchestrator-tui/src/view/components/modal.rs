@@ -2,7 +2,7 @@
 
 use ratatui::{
     layout::{Alignment, Constraint, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
@@ -12,25 +12,28 @@ use crate::model::state::{
     get_all_dns_servers, get_all_providers, get_all_record_types, get_credential_fields, Modal,
 };
 use crate::model::App;
+use crate::view::components::spinner;
+use crate::view::theme::ThemeColors;
 
 /// 渲染弹窗（如果有活动弹窗）
 pub fn render(app: &App, frame: &mut Frame) {
     let Some(ref modal) = app.modal.active else {
         return;
     };
+    let colors = app.settings.theme.colors();
 
     match modal {
-        Modal::AddAccount { .. } => render_add_account(app, frame, modal),
-        Modal::ConfirmDelete { .. } => render_confirm_delete(frame, modal),
-        Modal::DnsLookup { .. } => render_dns_lookup(frame, modal),
-        Modal::WhoisLookup { .. } => render_whois_lookup(frame, modal),
-        Modal::SslCheck { .. } => render_ssl_check(frame, modal),
-        Modal::IpLookup { .. } => render_ip_lookup(frame, modal),
-        Modal::HttpHeaderCheck { .. } => render_http_header_check(frame, modal),
-        Modal::DnsPropagation { .. } => render_dns_propagation(frame, modal),
-        Modal::DnssecCheck { .. } => render_dnssec_check(frame, modal),
-        Modal::Error { title, message } => render_error(frame, title, message),
-        Modal::Help => render_help(frame),
+        Modal::AddAccount { .. } => render_add_account(app, frame, &colors, modal),
+        Modal::ConfirmDelete { .. } => render_confirm_delete(frame, &colors, modal),
+        Modal::DnsLookup { .. } => render_dns_lookup(frame, &colors, app.tick, modal),
+        Modal::WhoisLookup { .. } => render_whois_lookup(frame, &colors, app.tick, modal),
+        Modal::SslCheck { .. } => render_ssl_check(frame, &colors, app.tick, modal),
+        Modal::IpLookup { .. } => render_ip_lookup(frame, &colors, app.tick, modal),
+        Modal::HttpHeaderCheck { .. } => render_http_header_check(frame, &colors, app.tick, modal),
+        Modal::DnsPropagation { .. } => render_dns_propagation(frame, &colors, app.tick, modal),
+        Modal::DnssecCheck { .. } => render_dnssec_check(frame, &colors, app.tick, modal),
+        Modal::Error { title, message } => render_error(frame, &colors, title, message),
+        Modal::Help => render_help(frame, &colors),
         _ => {}
     }
 }
@@ -43,7 +46,7 @@ fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
 }
 
 /// 渲染添加账号弹窗
-fn render_add_account(app: &App, frame: &mut Frame, modal: &Modal) {
+fn render_add_account(app: &App, frame: &mut Frame, colors: &ThemeColors, modal: &Modal) {
     let Modal::AddAccount {
         provider_index,
         name,
@@ -72,8 +75,8 @@ fn render_add_account(app: &App, frame: &mut Frame, modal: &Modal) {
         .title(" New Account ")
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan))
-        .style(Style::default().bg(Color::Black));
+        .border_style(Style::default().fg(colors.highlight))
+        .style(Style::default().bg(colors.bg));
 
     frame.render_widget(block, area);
 
@@ -86,15 +89,15 @@ fn render_add_account(app: &App, frame: &mut Frame, modal: &Modal) {
     // === 服务商选择 ===
     let provider_focused = *focus == 0;
     let provider_style = if provider_focused {
-        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        Style::default().fg(colors.highlight).add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::White)
+        Style::default().fg(colors.fg)
     };
 
     lines.push(Line::from(vec![
-        Span::styled("DNS Provider", Style::default().fg(Color::Gray)),
+        Span::styled("DNS Provider", Style::default().fg(colors.text_dim)),
         if provider_focused {
-            Span::styled(" (←→ to Switch)", Style::default().fg(Color::DarkGray))
+            Span::styled(" (←→ to Switch)", Style::default().fg(colors.muted))
         } else {
             Span::raw("")
         },
@@ -112,14 +115,14 @@ fn render_add_account(app: &App, frame: &mut Frame, modal: &Modal) {
     // === 账号名称 ===
     let name_focused = *focus == 1;
     let name_style = if name_focused {
-        Style::default().fg(Color::Cyan)
+        Style::default().fg(colors.highlight)
     } else {
-        Style::default().fg(Color::White)
+        Style::default().fg(colors.fg)
     };
 
     lines.push(Line::from(Span::styled(
         "Account Name (Optional)",
-        Style::default().fg(Color::Gray),
+        Style::default().fg(colors.text_dim),
     )));
 
     let name_display = if name.is_empty() && !name_focused {
@@ -131,7 +134,7 @@ fn render_add_account(app: &App, frame: &mut Frame, modal: &Modal) {
     };
 
     let name_line_style = if name.is_empty() && !name_focused {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(colors.muted)
     } else {
         name_style
     };
@@ -142,9 +145,9 @@ fn render_add_account(app: &App, frame: &mut Frame, modal: &Modal) {
     for (i, field) in credential_fields.iter().enumerate() {
         let field_focused = *focus == 2 + i;
         let field_style = if field_focused {
-            Style::default().fg(Color::Cyan)
+            Style::default().fg(colors.highlight)
         } else {
-            Style::default().fg(Color::White)
+            Style::default().fg(colors.fg)
         };
 
         let label = if field.is_secret {
@@ -152,7 +155,7 @@ fn render_add_account(app: &App, frame: &mut Frame, modal: &Modal) {
         } else {
             field.label.to_string()
         };
-        lines.push(Line::from(Span::styled(label, Style::default().fg(Color::Gray))));
+        lines.push(Line::from(Span::styled(label, Style::default().fg(colors.text_dim))));
 
         let value = credential_values.get(i).map(|s| s.as_str()).unwrap_or("");
         let display_value = if field.is_secret && !show_secrets && !value.is_empty() {
@@ -170,7 +173,7 @@ fn render_add_account(app: &App, frame: &mut Frame, modal: &Modal) {
         };
 
         let value_style = if value.is_empty() && !field_focused {
-            Style::default().fg(Color::DarkGray)
+            Style::default().fg(colors.muted)
         } else {
             field_style
         };
@@ -182,19 +185,19 @@ fn render_add_account(app: &App, frame: &mut Frame, modal: &Modal) {
     if let Some(err) = error {
         lines.push(Line::styled(
             format!("  ⚠ {}", err),
-            Style::default().fg(Color::Red),
+            Style::default().fg(colors.error),
         ));
     }
 
     // === 操作提示 ===
     lines.push(Line::from(""));
     lines.push(Line::from(vec![
-        Span::styled("  Tab", Style::default().fg(Color::Yellow)),
-        Span::styled(" Next | ", Style::default().fg(Color::DarkGray)),
-        Span::styled("Enter", Style::default().fg(Color::Yellow)),
-        Span::styled(" Confirm | ", Style::default().fg(Color::DarkGray)),
-        Span::styled("Esc", Style::default().fg(Color::Yellow)),
-        Span::styled(" Cancel", Style::default().fg(Color::DarkGray)),
+        Span::styled("  Tab", Style::default().fg(colors.key)),
+        Span::styled(" Next | ", Style::default().fg(colors.muted)),
+        Span::styled("Enter", Style::default().fg(colors.key)),
+        Span::styled(" Confirm | ", Style::default().fg(colors.muted)),
+        Span::styled("Esc", Style::default().fg(colors.key)),
+        Span::styled(" Cancel", Style::default().fg(colors.muted)),
     ]));
 
     let paragraph = Paragraph::new(lines);
@@ -202,10 +205,12 @@ fn render_add_account(app: &App, frame: &mut Frame, modal: &Modal) {
 }
 
 /// 渲染确认删除弹窗
-fn render_confirm_delete(frame: &mut Frame, modal: &Modal) {
+fn render_confirm_delete(frame: &mut Frame, colors: &ThemeColors, modal: &Modal) {
     let Modal::ConfirmDelete {
         item_type,
         item_name,
+        required_text,
+        typed_text,
         focus,
         ..
     } = modal
@@ -213,42 +218,65 @@ fn render_confirm_delete(frame: &mut Frame, modal: &Modal) {
         return;
     };
 
-    let area = centered_rect(40, 9, frame.area());
+    // 需要输入确认文本时，额外显示提示与输入框
+    let height = if required_text.is_some() { 12 } else { 9 };
+    let area = centered_rect(50, height, frame.area());
     frame.render_widget(Clear, area);
 
     let block = Block::default()
         .title(" Confirm Deletion ")
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Red))
-        .style(Style::default().bg(Color::Black));
+        .border_style(Style::default().fg(colors.error))
+        .style(Style::default().bg(colors.bg));
 
     frame.render_widget(block, area);
 
     let inner = Rect::new(area.x + 2, area.y + 1, area.width - 4, area.height - 2);
 
     let cancel_style = if *focus == 0 {
-        Style::default().fg(Color::Black).bg(Color::White)
+        Style::default().fg(colors.selected_fg).bg(colors.fg)
     } else {
-        Style::default().fg(Color::White)
+        Style::default().fg(colors.fg)
     };
 
-    let confirm_style = if *focus == 1 {
-        Style::default().fg(Color::Black).bg(Color::Red)
+    let confirm_style = if !modal.is_delete_confirmable() {
+        // 确认文本未输入正确时，删除按钮置灰
+        Style::default().fg(colors.muted)
+    } else if *focus == 1 {
+        Style::default().fg(colors.selected_fg).bg(colors.error)
     } else {
-        Style::default().fg(Color::Red)
+        Style::default().fg(colors.error)
     };
 
-    let lines = vec![
+    let mut lines = vec![
         Line::from(""),
         Line::styled(
             format!("  Are you sure to delete {} ?", item_type),
-            Style::default().fg(Color::White),
+            Style::default().fg(colors.fg),
         ),
         Line::styled(
             format!("  \"{}\"", item_name),
-            Style::default().fg(Color::Yellow),
+            Style::default().fg(colors.key),
         ),
+    ];
+
+    if let Some(required) = required_text {
+        lines.push(Line::from(""));
+        lines.push(Line::styled(
+            format!("  Type \"{}\" to confirm:", required),
+            Style::default().fg(colors.text_dim),
+        ));
+        lines.push(Line::from(vec![
+            Span::raw("  "),
+            Span::styled(
+                format!("{}_", typed_text),
+                Style::default().fg(colors.fg).bg(colors.input_bg),
+            ),
+        ]));
+    }
+
+    lines.extend([
         Line::from(""),
         Line::from(vec![
             Span::raw("    "),
@@ -256,14 +284,14 @@ fn render_confirm_delete(frame: &mut Frame, modal: &Modal) {
             Span::raw("    "),
             Span::styled(" Delete ", confirm_style),
         ]),
-    ];
+    ]);
 
     let paragraph = Paragraph::new(lines);
     frame.render_widget(paragraph, inner);
 }
 
 /// 渲染错误弹窗
-fn render_error(frame: &mut Frame, title: &str, message: &str) {
+fn render_error(frame: &mut Frame, colors: &ThemeColors, title: &str, message: &str) {
     let area = centered_rect(50, 8, frame.area());
     frame.render_widget(Clear, area);
 
@@ -271,19 +299,19 @@ fn render_error(frame: &mut Frame, title: &str, message: &str) {
         .title(format!(" {} ", title))
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Red))
-        .style(Style::default().bg(Color::Black));
+        .border_style(Style::default().fg(colors.error))
+        .style(Style::default().bg(colors.bg));
 
     frame.render_widget(block, area);
 
     let inner = Rect::new(area.x + 2, area.y + 2, area.width - 4, area.height - 4);
 
     let lines = vec![
-        Line::styled(message, Style::default().fg(Color::White)),
+        Line::styled(message, Style::default().fg(colors.fg)),
         Line::from(""),
         Line::styled(
             "Press Esc or Enter to close",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(colors.muted),
         ),
     ];
 
@@ -292,61 +320,77 @@ fn render_error(frame: &mut Frame, title: &str, message: &str) {
 }
 
 /// 渲染帮助弹窗
-fn render_help(frame: &mut Frame) {
-    let area = centered_rect(55, 18, frame.area());
+fn render_help(frame: &mut Frame, colors: &ThemeColors) {
+    let area = centered_rect(55, 22, frame.area());
     frame.render_widget(Clear, area);
 
     let block = Block::default()
         .title(" Help ")
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan))
-        .style(Style::default().bg(Color::Black));
+        .border_style(Style::default().fg(colors.highlight))
+        .style(Style::default().bg(colors.bg));
 
     frame.render_widget(block, area);
 
     let inner = Rect::new(area.x + 2, area.y + 1, area.width - 4, area.height - 2);
 
     let lines = vec![
-        Line::styled("Global shortcuts", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        Line::styled("Global shortcuts", Style::default().fg(colors.highlight).add_modifier(Modifier::BOLD)),
         Line::from(""),
         Line::from(vec![
-            Span::styled("  ←→     ", Style::default().fg(Color::Yellow)),
-            Span::styled("Switch panel", Style::default().fg(Color::White)),
+            Span::styled("  ←→     ", Style::default().fg(colors.key)),
+            Span::styled("Switch panel", Style::default().fg(colors.fg)),
+        ]),
+        Line::from(vec![
+            Span::styled("  ↑↓/jk  ", Style::default().fg(colors.key)),
+            Span::styled("Move Up/Down", Style::default().fg(colors.fg)),
         ]),
         Line::from(vec![
-            Span::styled("  ↑↓/jk  ", Style::default().fg(Color::Yellow)),
-            Span::styled("Move Up/Down", Style::default().fg(Color::White)),
+            Span::styled("  Enter  ", Style::default().fg(colors.key)),
+            Span::styled("Confirm", Style::default().fg(colors.fg)),
         ]),
         Line::from(vec![
-            Span::styled("  Enter  ", Style::default().fg(Color::Yellow)),
-            Span::styled("Confirm", Style::default().fg(Color::White)),
+            Span::styled("  Esc    ", Style::default().fg(colors.key)),
+            Span::styled("Back/Cancel", Style::default().fg(colors.fg)),
         ]),
         Line::from(vec![
-            Span::styled("  Esc    ", Style::default().fg(Color::Yellow)),
-            Span::styled("Back/Cancel", Style::default().fg(Color::White)),
+            Span::styled("  Alt+l  ", Style::default().fg(colors.key)),
+            Span::styled("Toggle logs (Alt+v: level)", Style::default().fg(colors.fg)),
         ]),
         Line::from(vec![
-            Span::styled("  q      ", Style::default().fg(Color::Yellow)),
-            Span::styled("Quit", Style::default().fg(Color::White)),
+            Span::styled("  q      ", Style::default().fg(colors.key)),
+            Span::styled("Quit", Style::default().fg(colors.fg)),
         ]),
         Line::from(""),
-        Line::styled("Operation shortcut", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        Line::styled("Operation shortcut", Style::default().fg(colors.highlight).add_modifier(Modifier::BOLD)),
         Line::from(""),
         Line::from(vec![
-            Span::styled("  Alt+a  ", Style::default().fg(Color::Yellow)),
-            Span::styled("Add", Style::default().fg(Color::White)),
+            Span::styled("  Alt+a  ", Style::default().fg(colors.key)),
+            Span::styled("Add", Style::default().fg(colors.fg)),
+        ]),
+        Line::from(vec![
+            Span::styled("  Alt+e  ", Style::default().fg(colors.key)),
+            Span::styled("Edit", Style::default().fg(colors.fg)),
+        ]),
+        Line::from(vec![
+            Span::styled("  Alt+d  ", Style::default().fg(colors.key)),
+            Span::styled("Delete", Style::default().fg(colors.fg)),
+        ]),
+        Line::from(vec![
+            Span::styled("  /      ", Style::default().fg(colors.key)),
+            Span::styled("Filter list", Style::default().fg(colors.fg)),
         ]),
         Line::from(vec![
-            Span::styled("  Alt+e  ", Style::default().fg(Color::Yellow)),
-            Span::styled("Edit", Style::default().fg(Color::White)),
+            Span::styled("  s/S    ", Style::default().fg(colors.key)),
+            Span::styled("Sort column/direction", Style::default().fg(colors.fg)),
         ]),
         Line::from(vec![
-            Span::styled("  Alt+d  ", Style::default().fg(Color::Yellow)),
-            Span::styled("Delete", Style::default().fg(Color::White)),
+            Span::styled("  y      ", Style::default().fg(colors.key)),
+            Span::styled("Copy value (Alt+y in dialogs)", Style::default().fg(colors.fg)),
         ]),
         Line::from(""),
-        Line::styled("Press Esc to close the help", Style::default().fg(Color::DarkGray)),
+        Line::styled("Press Esc to close the help", Style::default().fg(colors.muted)),
     ];
 
     let paragraph = Paragraph::new(lines);
@@ -354,7 +398,7 @@ fn render_help(frame: &mut Frame) {
 }
 
 /// 渲染 DNS 查询工具弹窗
-fn render_dns_lookup(frame: &mut Frame, modal: &Modal) {
+fn render_dns_lookup(frame: &mut Frame, colors: &ThemeColors, tick: usize, modal: &Modal) {
     let Modal::DnsLookup {
         domain,
         record_type_index,
@@ -380,7 +424,7 @@ fn render_dns_lookup(frame: &mut Frame, modal: &Modal) {
     let block = Block::default()
         .title(" DNS Lookup ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(Style::default().fg(colors.highlight));
 
     let inner = block.inner(modal_area);
     frame.render_widget(block, modal_area);
@@ -390,7 +434,7 @@ fn render_dns_lookup(frame: &mut Frame, modal: &Modal) {
 
     // 域名输入框
     let domain_style = if *focus == 0 {
-        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        Style::default().fg(colors.highlight).add_modifier(Modifier::BOLD)
     } else {
         Style::default()
     };
@@ -404,11 +448,11 @@ fn render_dns_lookup(frame: &mut Frame, modal: &Modal) {
                 domain
             },
             if domain.is_empty() {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(colors.muted)
             } else if *focus == 0 {
-                Style::default().fg(Color::White).bg(Color::DarkGray)
+                Style::default().fg(colors.fg).bg(colors.input_bg)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(colors.fg)
             },
         ),
     ]));
@@ -417,52 +461,52 @@ fn render_dns_lookup(frame: &mut Frame, modal: &Modal) {
     // 记录类型选择
     let record_type = &record_types[*record_type_index];
     let record_style = if *focus == 1 {
-        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        Style::default().fg(colors.highlight).add_modifier(Modifier::BOLD)
     } else {
         Style::default()
     };
     lines.push(Line::from(vec![Span::styled("Record Type: ", record_style)]));
     lines.push(Line::from(vec![
-        Span::styled("  < ", if *focus == 1 { Style::default().fg(Color::Yellow) } else { Style::default().fg(Color::DarkGray) }),
+        Span::styled("  < ", if *focus == 1 { Style::default().fg(colors.key) } else { Style::default().fg(colors.muted) }),
         Span::styled(
             record_type.name(),
             if *focus == 1 {
-                Style::default().fg(Color::White).add_modifier(Modifier::BOLD)
+                Style::default().fg(colors.fg).add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(colors.fg)
             },
         ),
-        Span::styled(" >", if *focus == 1 { Style::default().fg(Color::Yellow) } else { Style::default().fg(Color::DarkGray) }),
+        Span::styled(" >", if *focus == 1 { Style::default().fg(colors.key) } else { Style::default().fg(colors.muted) }),
     ]));
     lines.push(Line::from(""));
 
     // DNS 服务器选择
     let dns_server = &dns_servers[*dns_server_index];
     let server_style = if *focus == 2 {
-        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        Style::default().fg(colors.highlight).add_modifier(Modifier::BOLD)
     } else {
         Style::default()
     };
     lines.push(Line::from(vec![Span::styled("DNS Server: ", server_style)]));
     lines.push(Line::from(vec![
-        Span::styled("  < ", if *focus == 2 { Style::default().fg(Color::Yellow) } else { Style::default().fg(Color::DarkGray) }),
+        Span::styled("  < ", if *focus == 2 { Style::default().fg(colors.key) } else { Style::default().fg(colors.muted) }),
         Span::styled(
             dns_server.name(),
             if *focus == 2 {
-                Style::default().fg(Color::White).add_modifier(Modifier::BOLD)
+                Style::default().fg(colors.fg).add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(colors.fg)
             },
         ),
-        Span::styled(" >", if *focus == 2 { Style::default().fg(Color::Yellow) } else { Style::default().fg(Color::DarkGray) }),
+        Span::styled(" >", if *focus == 2 { Style::default().fg(colors.key) } else { Style::default().fg(colors.muted) }),
     ]));
     lines.push(Line::from(""));
 
     // 查询结果
     if *loading {
-        lines.push(Line::styled("Querying...", Style::default().fg(Color::Yellow)));
+        lines.push(Line::styled(format!("{} Querying...", spinner::symbol(tick)), Style::default().fg(colors.key)));
     } else if let Some(ref res) = result {
-        lines.push(Line::styled("Result:", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)));
+        lines.push(Line::styled("Result:", Style::default().fg(colors.success).add_modifier(Modifier::BOLD)));
         for line in res.lines() {
             lines.push(Line::from(line));
         }
@@ -471,7 +515,7 @@ fn render_dns_lookup(frame: &mut Frame, modal: &Modal) {
     lines.push(Line::from(""));
     lines.push(Line::styled(
         " Tab/↑↓: Navigate | ←→: Change selection | Enter: Query | Esc: Close ",
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(colors.muted),
     ));
 
     let paragraph = Paragraph::new(lines);
@@ -479,7 +523,7 @@ fn render_dns_lookup(frame: &mut Frame, modal: &Modal) {
 }
 
 /// 渲染 WHOIS 查询工具弹窗
-fn render_whois_lookup(frame: &mut Frame, modal: &Modal) {
+fn render_whois_lookup(frame: &mut Frame, colors: &ThemeColors, tick: usize, modal: &Modal) {
     let Modal::WhoisLookup {
         domain,
         result,
@@ -499,7 +543,7 @@ fn render_whois_lookup(frame: &mut Frame, modal: &Modal) {
     let block = Block::default()
         .title(" WHOIS Lookup ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(Style::default().fg(colors.highlight));
 
     let inner = block.inner(modal_area);
     frame.render_widget(block, modal_area);
@@ -510,7 +554,7 @@ fn render_whois_lookup(frame: &mut Frame, modal: &Modal) {
     // 域名输入框
     lines.push(Line::from(vec![Span::styled(
         "Domain: ",
-        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        Style::default().fg(colors.highlight).add_modifier(Modifier::BOLD),
     )]));
     lines.push(Line::from(vec![
         Span::styled("  ", Style::default()),
@@ -521,9 +565,9 @@ fn render_whois_lookup(frame: &mut Frame, modal: &Modal) {
                 domain
             },
             if domain.is_empty() {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(colors.muted)
             } else {
-                Style::default().fg(Color::White).bg(Color::DarkGray)
+                Style::default().fg(colors.fg).bg(colors.input_bg)
             },
         ),
     ]));
@@ -531,9 +575,9 @@ fn render_whois_lookup(frame: &mut Frame, modal: &Modal) {
 
     // 查询结果
     if *loading {
-        lines.push(Line::styled("Querying...", Style::default().fg(Color::Yellow)));
+        lines.push(Line::styled(format!("{} Querying...", spinner::symbol(tick)), Style::default().fg(colors.key)));
     } else if let Some(ref res) = result {
-        lines.push(Line::styled("Result:", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)));
+        lines.push(Line::styled("Result:", Style::default().fg(colors.success).add_modifier(Modifier::BOLD)));
         for line in res.lines() {
             lines.push(Line::from(line));
         }
@@ -542,7 +586,7 @@ fn render_whois_lookup(frame: &mut Frame, modal: &Modal) {
     lines.push(Line::from(""));
     lines.push(Line::styled(
         "  Enter: Query | Esc: Close",
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(colors.muted),
     ));
 
     let paragraph = Paragraph::new(lines);
@@ -550,7 +594,7 @@ fn render_whois_lookup(frame: &mut Frame, modal: &Modal) {
 }
 
 /// 渲染 SSL 证书检查工具弹窗
-fn render_ssl_check(frame: &mut Frame, modal: &Modal) {
+fn render_ssl_check(frame: &mut Frame, colors: &ThemeColors, tick: usize, modal: &Modal) {
     let Modal::SslCheck {
         domain,
         result,
@@ -570,7 +614,7 @@ fn render_ssl_check(frame: &mut Frame, modal: &Modal) {
     let block = Block::default()
         .title(" SSL Certificate Check ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(Style::default().fg(colors.highlight));
 
     let inner = block.inner(modal_area);
     frame.render_widget(block, modal_area);
@@ -581,7 +625,7 @@ fn render_ssl_check(frame: &mut Frame, modal: &Modal) {
     // 域名输入框
     lines.push(Line::from(vec![Span::styled(
         "Domain: ",
-        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        Style::default().fg(colors.highlight).add_modifier(Modifier::BOLD),
     )]));
     lines.push(Line::from(vec![
         Span::styled("  ", Style::default()),
@@ -592,9 +636,9 @@ fn render_ssl_check(frame: &mut Frame, modal: &Modal) {
                 domain
             },
             if domain.is_empty() {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(colors.muted)
             } else {
-                Style::default().fg(Color::White).bg(Color::DarkGray)
+                Style::default().fg(colors.fg).bg(colors.input_bg)
             },
         ),
     ]));
@@ -602,9 +646,9 @@ fn render_ssl_check(frame: &mut Frame, modal: &Modal) {
 
     // 查询结果
     if *loading {
-        lines.push(Line::styled("Checking...", Style::default().fg(Color::Yellow)));
+        lines.push(Line::styled(format!("{} Checking...", spinner::symbol(tick)), Style::default().fg(colors.key)));
     } else if let Some(ref res) = result {
-        lines.push(Line::styled("Result:", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)));
+        lines.push(Line::styled("Result:", Style::default().fg(colors.success).add_modifier(Modifier::BOLD)));
         for line in res.lines() {
             lines.push(Line::from(line));
         }
@@ -613,7 +657,7 @@ fn render_ssl_check(frame: &mut Frame, modal: &Modal) {
     lines.push(Line::from(""));
     lines.push(Line::styled(
         "Enter: Check | Esc: Close",
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(colors.muted),
     ));
 
     let paragraph = Paragraph::new(lines);
@@ -621,7 +665,7 @@ fn render_ssl_check(frame: &mut Frame, modal: &Modal) {
 }
 
 /// 渲染 IP 查询工具弹窗
-fn render_ip_lookup(frame: &mut Frame, modal: &Modal) {
+fn render_ip_lookup(frame: &mut Frame, colors: &ThemeColors, tick: usize, modal: &Modal) {
     let Modal::IpLookup {
         input,
         result,
@@ -641,7 +685,7 @@ fn render_ip_lookup(frame: &mut Frame, modal: &Modal) {
     let block = Block::default()
         .title(" IP Lookup ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(Style::default().fg(colors.highlight));
 
     let inner = block.inner(modal_area);
     frame.render_widget(block, modal_area);
@@ -652,7 +696,7 @@ fn render_ip_lookup(frame: &mut Frame, modal: &Modal) {
     // IP 或域名输入框
     lines.push(Line::from(vec![Span::styled(
         "IP or Domain: ",
-        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        Style::default().fg(colors.highlight).add_modifier(Modifier::BOLD),
     )]));
     lines.push(Line::from(vec![
         Span::styled("  ", Style::default()),
@@ -663,9 +707,9 @@ fn render_ip_lookup(frame: &mut Frame, modal: &Modal) {
                 input
             },
             if input.is_empty() {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(colors.muted)
             } else {
-                Style::default().fg(Color::White).bg(Color::DarkGray)
+                Style::default().fg(colors.fg).bg(colors.input_bg)
             },
         ),
     ]));
@@ -673,9 +717,9 @@ fn render_ip_lookup(frame: &mut Frame, modal: &Modal) {
 
     // 查询结果
     if *loading {
-        lines.push(Line::styled("Looking up...", Style::default().fg(Color::Yellow)));
+        lines.push(Line::styled(format!("{} Looking up...", spinner::symbol(tick)), Style::default().fg(colors.key)));
     } else if let Some(ref res) = result {
-        lines.push(Line::styled("Result:", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)));
+        lines.push(Line::styled("Result:", Style::default().fg(colors.success).add_modifier(Modifier::BOLD)));
         for line in res.lines() {
             lines.push(Line::from(line));
         }
@@ -684,7 +728,7 @@ fn render_ip_lookup(frame: &mut Frame, modal: &Modal) {
     lines.push(Line::from(""));
     lines.push(Line::styled(
         "Enter: Lookup | Esc: Close",
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(colors.muted),
     ));
 
     let paragraph = Paragraph::new(lines);
@@ -692,7 +736,12 @@ fn render_ip_lookup(frame: &mut Frame, modal: &Modal) {
 }
 
 /// 渲染 HTTP 头检查工具弹窗
-fn render_http_header_check(frame: &mut Frame, modal: &Modal) {
+fn render_http_header_check(
+    frame: &mut Frame,
+    colors: &ThemeColors,
+    tick: usize,
+    modal: &Modal,
+) {
     let Modal::HttpHeaderCheck {
         url,
         method_index,
@@ -716,7 +765,7 @@ fn render_http_header_check(frame: &mut Frame, modal: &Modal) {
     let block = Block::default()
         .title(" HTTP Header Check ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(Style::default().fg(colors.highlight));
 
     let inner = block.inner(modal_area);
     frame.render_widget(block, modal_area);
@@ -726,7 +775,7 @@ fn render_http_header_check(frame: &mut Frame, modal: &Modal) {
 
     // URL 输入框
     let url_style = if *focus == 0 {
-        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        Style::default().fg(colors.highlight).add_modifier(Modifier::BOLD)
     } else {
         Style::default()
     };
@@ -740,11 +789,11 @@ fn render_http_header_check(frame: &mut Frame, modal: &Modal) {
                 url
             },
             if url.is_empty() {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(colors.muted)
             } else if *focus == 0 {
-                Style::default().fg(Color::White).bg(Color::DarkGray)
+                Style::default().fg(colors.fg).bg(colors.input_bg)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(colors.fg)
             },
         ),
     ]));
@@ -753,30 +802,30 @@ fn render_http_header_check(frame: &mut Frame, modal: &Modal) {
     // HTTP 方法选择
     let method = methods[*method_index];
     let method_style = if *focus == 1 {
-        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        Style::default().fg(colors.highlight).add_modifier(Modifier::BOLD)
     } else {
         Style::default()
     };
     lines.push(Line::from(vec![Span::styled("Method: ", method_style)]));
     lines.push(Line::from(vec![
-        Span::styled("  < ", if *focus == 1 { Style::default().fg(Color::Yellow) } else { Style::default().fg(Color::DarkGray) }),
+        Span::styled("  < ", if *focus == 1 { Style::default().fg(colors.key) } else { Style::default().fg(colors.muted) }),
         Span::styled(
             method,
             if *focus == 1 {
-                Style::default().fg(Color::White).add_modifier(Modifier::BOLD)
+                Style::default().fg(colors.fg).add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(colors.fg)
             },
         ),
-        Span::styled(" >", if *focus == 1 { Style::default().fg(Color::Yellow) } else { Style::default().fg(Color::DarkGray) }),
+        Span::styled(" >", if *focus == 1 { Style::default().fg(colors.key) } else { Style::default().fg(colors.muted) }),
     ]));
     lines.push(Line::from(""));
 
     // 查询结果
     if *loading {
-        lines.push(Line::styled("Checking...", Style::default().fg(Color::Yellow)));
+        lines.push(Line::styled(format!("{} Checking...", spinner::symbol(tick)), Style::default().fg(colors.key)));
     } else if let Some(ref res) = result {
-        lines.push(Line::styled("Result:", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)));
+        lines.push(Line::styled("Result:", Style::default().fg(colors.success).add_modifier(Modifier::BOLD)));
         for line in res.lines() {
             lines.push(Line::from(line));
         }
@@ -785,7 +834,7 @@ fn render_http_header_check(frame: &mut Frame, modal: &Modal) {
     lines.push(Line::from(""));
     lines.push(Line::styled(
         " Tab/↑↓: Navigate | ←→: Change method | Enter: Check | Esc: Close ",
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(colors.muted),
     ));
 
     let paragraph = Paragraph::new(lines);
@@ -793,7 +842,7 @@ fn render_http_header_check(frame: &mut Frame, modal: &Modal) {
 }
 
 /// 渲染 DNS 传播检查工具弹窗
-fn render_dns_propagation(frame: &mut Frame, modal: &Modal) {
+fn render_dns_propagation(frame: &mut Frame, colors: &ThemeColors, tick: usize, modal: &Modal) {
     let Modal::DnsPropagation {
         domain,
         record_type_index,
@@ -817,7 +866,7 @@ fn render_dns_propagation(frame: &mut Frame, modal: &Modal) {
     let block = Block::default()
         .title(" DNS Propagation Check ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(Style::default().fg(colors.highlight));
 
     let inner = block.inner(modal_area);
     frame.render_widget(block, modal_area);
@@ -827,7 +876,7 @@ fn render_dns_propagation(frame: &mut Frame, modal: &Modal) {
 
     // 域名输入框
     let domain_style = if *focus == 0 {
-        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        Style::default().fg(colors.highlight).add_modifier(Modifier::BOLD)
     } else {
         Style::default()
     };
@@ -841,11 +890,11 @@ fn render_dns_propagation(frame: &mut Frame, modal: &Modal) {
                 domain
             },
             if domain.is_empty() {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(colors.muted)
             } else if *focus == 0 {
-                Style::default().fg(Color::White).bg(Color::DarkGray)
+                Style::default().fg(colors.fg).bg(colors.input_bg)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(colors.fg)
             },
         ),
     ]));
@@ -854,30 +903,30 @@ fn render_dns_propagation(frame: &mut Frame, modal: &Modal) {
     // 记录类型选择
     let record_type = &record_types[*record_type_index];
     let record_style = if *focus == 1 {
-        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        Style::default().fg(colors.highlight).add_modifier(Modifier::BOLD)
     } else {
         Style::default()
     };
     lines.push(Line::from(vec![Span::styled("Record Type: ", record_style)]));
     lines.push(Line::from(vec![
-        Span::styled("  < ", if *focus == 1 { Style::default().fg(Color::Yellow) } else { Style::default().fg(Color::DarkGray) }),
+        Span::styled("  < ", if *focus == 1 { Style::default().fg(colors.key) } else { Style::default().fg(colors.muted) }),
         Span::styled(
             record_type.name(),
             if *focus == 1 {
-                Style::default().fg(Color::White).add_modifier(Modifier::BOLD)
+                Style::default().fg(colors.fg).add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(colors.fg)
             },
         ),
-        Span::styled(" >", if *focus == 1 { Style::default().fg(Color::Yellow) } else { Style::default().fg(Color::DarkGray) }),
+        Span::styled(" >", if *focus == 1 { Style::default().fg(colors.key) } else { Style::default().fg(colors.muted) }),
     ]));
     lines.push(Line::from(""));
 
     // 查询结果
     if *loading {
-        lines.push(Line::styled("Checking propagation...", Style::default().fg(Color::Yellow)));
+        lines.push(Line::styled(format!("{} Checking propagation...", spinner::symbol(tick)), Style::default().fg(colors.key)));
     } else if let Some(ref res) = result {
-        lines.push(Line::styled("Result:", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)));
+        lines.push(Line::styled("Result:", Style::default().fg(colors.success).add_modifier(Modifier::BOLD)));
         for line in res.lines() {
             lines.push(Line::from(line));
         }
@@ -886,7 +935,7 @@ fn render_dns_propagation(frame: &mut Frame, modal: &Modal) {
     lines.push(Line::from(""));
     lines.push(Line::styled(
         " Tab/↑↓: Navigate | ←→: Change type | Enter: Check | Esc: Close ",
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(colors.muted),
     ));
 
     let paragraph = Paragraph::new(lines);
@@ -894,7 +943,7 @@ fn render_dns_propagation(frame: &mut Frame, modal: &Modal) {
 }
 
 /// 渲染 DNSSEC 验证工具弹窗
-fn render_dnssec_check(frame: &mut Frame, modal: &Modal) {
+fn render_dnssec_check(frame: &mut Frame, colors: &ThemeColors, tick: usize, modal: &Modal) {
     let Modal::DnssecCheck {
         domain,
         result,
@@ -914,7 +963,7 @@ fn render_dnssec_check(frame: &mut Frame, modal: &Modal) {
     let block = Block::default()
         .title(" DNSSEC Check ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(Style::default().fg(colors.highlight));
 
     let inner = block.inner(modal_area);
     frame.render_widget(block, modal_area);
@@ -925,7 +974,7 @@ fn render_dnssec_check(frame: &mut Frame, modal: &Modal) {
     // 域名输入框
     lines.push(Line::from(vec![Span::styled(
         "Domain: ",
-        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        Style::default().fg(colors.highlight).add_modifier(Modifier::BOLD),
     )]));
     lines.push(Line::from(vec![
         Span::styled("  ", Style::default()),
@@ -936,9 +985,9 @@ fn render_dnssec_check(frame: &mut Frame, modal: &Modal) {
                 domain
             },
             if domain.is_empty() {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(colors.muted)
             } else {
-                Style::default().fg(Color::White).bg(Color::DarkGray)
+                Style::default().fg(colors.fg).bg(colors.input_bg)
             },
         ),
     ]));
@@ -946,9 +995,9 @@ fn render_dnssec_check(frame: &mut Frame, modal: &Modal) {
 
     // 查询结果
     if *loading {
-        lines.push(Line::styled("Checking DNSSEC...", Style::default().fg(Color::Yellow)));
+        lines.push(Line::styled(format!("{} Checking DNSSEC...", spinner::symbol(tick)), Style::default().fg(colors.key)));
     } else if let Some(ref res) = result {
-        lines.push(Line::styled("Result:", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)));
+        lines.push(Line::styled("Result:", Style::default().fg(colors.success).add_modifier(Modifier::BOLD)));
         for line in res.lines() {
             lines.push(Line::from(line));
         }
@@ -957,7 +1006,7 @@ fn render_dnssec_check(frame: &mut Frame, modal: &Modal) {
     lines.push(Line::from(""));
     lines.push(Line::styled(
         "Enter: Check | Esc: Close",
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(colors.muted),
     ));
 
     let paragraph = Paragraph::new(lines);
@@ -14,17 +14,18 @@ use crate::view::theme::Styles;
 /// 渲染导航面板
 pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     let is_focused = app.focus.is_navigation();
+    let colors = app.settings.theme.colors();
 
     // 边框样式
     let border_style = if is_focused {
-        Styles::border_focused()
+        Styles::border_focused(&colors)
     } else {
-        Styles::border()
+        Styles::border(&colors)
     };
 
     let block = Block::default()
         .title(" Navigation ")
-        .title_style(Styles::title())
+        .title_style(Styles::title(&colors))
         .borders(Borders::ALL)
         .border_style(border_style);
 
@@ -41,7 +42,7 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
             let content = format!("{}{} {}", prefix, nav_item.icon, nav_item.label);
 
             let style = if is_selected {
-                Styles::selected()
+                Styles::selected(&colors)
             } else {
                 Style::default()
             };
@@ -52,7 +53,7 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
 
     let list = List::new(items)
         .block(block)
-        .highlight_style(Styles::selected());
+        .highlight_style(Styles::selected(&colors));
 
     // 使用 ListState 来跟踪选中状态
     let mut state = ListState::default();
@@ -0,0 +1,31 @@
+//! 加载动画组件
+
+use ratatui::{layout::Rect, style::Style, text::Line, widgets::Paragraph, Frame};
+
+use crate::view::theme::ThemeColors;
+
+/// 动画帧
+const FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// 获取当前动画帧对应的字符
+pub fn symbol(tick: usize) -> &'static str {
+    FRAMES[tick % FRAMES.len()]
+}
+
+/// 渲染带加载动画的提示
+pub fn render_loading(
+    frame: &mut Frame,
+    area: Rect,
+    colors: &ThemeColors,
+    tick: usize,
+    text: &str,
+) {
+    let content = vec![
+        Line::from(""),
+        Line::styled(
+            format!("  {} {}", symbol(tick), text),
+            Style::default().fg(colors.key),
+        ),
+    ];
+    frame.render_widget(Paragraph::new(content), area);
+}
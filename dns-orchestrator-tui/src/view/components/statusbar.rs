@@ -2,41 +2,51 @@
 
 use ratatui::{
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::Paragraph,
     Frame,
 };
 
 use crate::model::{App, FocusPanel, Page};
+use crate::view::components::spinner;
 use crate::view::theme::Styles;
 
 /// 渲染状态栏
 pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     // 根据当前焦点和页面生成快捷键提示
     let hints = get_hints(app);
+    let colors = app.settings.theme.colors();
 
     // 构建状态栏内容
     let mut spans = Vec::new();
 
+    // 有后台任务执行时，在最左侧显示加载动画
+    if app.is_busy() {
+        spans.push(Span::styled(
+            format!(" {} ", spinner::symbol(app.tick)),
+            Styles::hint_key(&colors),
+        ));
+    }
+
     for (i, (key, desc)) in hints.iter().enumerate() {
         if i > 0 {
-            spans.push(Span::styled(" │ ", Style::default().fg(Color::DarkGray)));
+            spans.push(Span::styled(" │ ", Styles::hint_desc(&colors)));
         }
-        spans.push(Span::styled(*key, Styles::hint_key()));
+        spans.push(Span::styled(*key, Styles::hint_key(&colors)));
         spans.push(Span::raw(" "));
-        spans.push(Span::styled(*desc, Styles::hint_desc()));
+        spans.push(Span::styled(*desc, Styles::hint_desc(&colors)));
     }
 
     // 如果有状态消息，显示在右侧
     if let Some(ref msg) = app.status_message {
         // Add分隔符
-        spans.push(Span::styled(" │ ", Style::default().fg(Color::DarkGray)));
-        spans.push(Span::styled(msg.clone(), Style::default().fg(Color::Yellow)));
+        spans.push(Span::styled(" │ ", Styles::hint_desc(&colors)));
+        spans.push(Span::styled(msg.clone(), Style::default().fg(colors.statusbar_key)));
     }
 
     let content = Line::from(spans);
-    let paragraph = Paragraph::new(content).style(Styles::statusbar());
+    let paragraph = Paragraph::new(content).style(Styles::statusbar(&colors));
 
     frame.render_widget(paragraph, area);
 }
@@ -64,18 +74,28 @@ fn get_hints(app: &App) -> Vec<(&'static str, &'static str)> {
                     hints.push(("Alt+a", "Add"));
                     hints.push(("Alt+d", "Delete"));
                 }
+                Page::Domains if app.domains.filtering => {
+                    push_filter_hints(&mut hints);
+                }
+                Page::DnsRecords { .. } if app.dns_records.filtering => {
+                    push_filter_hints(&mut hints);
+                }
                 Page::Domains => {
                     hints.push(("↑↓", "Select"));
                     hints.push(("Enter", "Enter"));
+                    hints.push(("/", "Filter"));
                 }
                 Page::DnsRecords { .. } => {
                     hints.push(("↑↓", "Select"));
                     hints.push(("Alt+a", "Add"));
+                    hints.push(("/", "Filter"));
                     hints.push(("Esc", "Back"));
                 }
                 Page::Toolbox => {
                     hints.push(("Tab", "Switch Tools"));
                     hints.push(("Enter", "Execute"));
+                    hints.push(("PgUp/PgDn", "Scroll"));
+                    hints.push(("y", "Copy"));
                 }
                 Page::Settings => {
                     hints.push(("↑↓", "Select"));
@@ -85,8 +105,21 @@ fn get_hints(app: &App) -> Vec<(&'static str, &'static str)> {
         }
     }
 
+    // 日志面板
+    if app.logs.visible {
+        hints.push(("Alt+v", "Log Level"));
+    }
+    hints.push(("Alt+l", "Logs"));
+
     // Quit
     hints.push(("Alt+q", "Quit"));
 
     hints
-}
\ No newline at end of file
+}
+
+/// 过滤输入时的快捷键提示
+fn push_filter_hints(hints: &mut Vec<(&'static str, &'static str)>) {
+    hints.push(("↑↓", "Select"));
+    hints.push(("Enter", "Apply"));
+    hints.push(("Esc", "Clear"));
+}
@@ -2,38 +2,46 @@
 
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    style::Style,
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
 use crate::model::{App, Page};
 
-use super::components;
+use super::components::{self, log_panel::LOG_PANEL_HEIGHT};
 use super::pages;
-use super::theme::Styles;
+use super::theme::{Styles, ThemeColors};
+
+/// 主布局各区域
+pub struct MainAreas {
+    /// 标题栏
+    pub title: Rect,
+    /// 左侧导航
+    pub nav: Rect,
+    /// 右侧内容
+    pub content: Rect,
+    /// 日志面板（隐藏时为 None）
+    pub logs: Option<Rect>,
+    /// 状态栏
+    pub status: Rect,
+}
 
-/// 渲染主布局
-pub fn render(app: &App, frame: &mut Frame) {
-    let size = frame.area();
+/// 计算主布局各区域（渲染与鼠标点击定位共用）
+pub fn main_areas(size: Rect, show_logs: bool) -> MainAreas {
+    let log_height = if show_logs { LOG_PANEL_HEIGHT } else { 0 };
 
-    // 三层布局：标题栏 + 主内容区 + 状态栏
+    // 三层布局：标题栏 + 主内容区 + 状态栏（日志面板显示时位于状态栏之上）
     let main_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(1), // 标题栏
-            Constraint::Min(1),    // 主内容区
-            Constraint::Length(1), // 状态栏
+            Constraint::Length(1),          // 标题栏
+            Constraint::Min(1),             // 主内容区
+            Constraint::Length(log_height), // 日志面板
+            Constraint::Length(1),          // 状态栏
         ])
         .split(size);
 
-    let title_area = main_layout[0];
-    let content_area = main_layout[1];
-    let status_area = main_layout[2];
-
-    // 渲染标题栏
-    render_title_bar(frame, title_area);
-
     // 左右分栏布局
     let columns = Layout::default()
         .direction(Direction::Horizontal)
@@ -41,28 +49,55 @@ pub fn render(app: &App, frame: &mut Frame) {
             Constraint::Percentage(20), // 左侧导航
             Constraint::Percentage(80), // 右侧内容
         ])
-        .split(content_area);
+        .split(main_layout[1]);
+
+    MainAreas {
+        title: main_layout[0],
+        nav: columns[0],
+        content: columns[1],
+        logs: show_logs.then_some(main_layout[2]),
+        status: main_layout[3],
+    }
+}
 
-    let nav_area = columns[0];
-    let page_area = columns[1];
+/// 渲染主布局
+pub fn render(app: &App, frame: &mut Frame) {
+    let size = frame.area();
+    let colors = app.settings.theme.colors();
+
+    // 以主题背景色铺满整个终端
+    frame.render_widget(
+        Block::default().style(Style::default().bg(colors.bg).fg(colors.fg)),
+        size,
+    );
+
+    let areas = main_areas(size, app.logs.visible);
+
+    // 渲染标题栏
+    render_title_bar(frame, areas.title, &colors);
 
     // 渲染左侧导航
-    components::navigation::render(app, frame, nav_area);
+    components::navigation::render(app, frame, areas.nav);
 
     // 渲染右侧内容
-    render_page_content(app, frame, page_area);
+    render_page_content(app, frame, areas.content);
+
+    // 渲染日志面板
+    if let Some(logs_area) = areas.logs {
+        components::log_panel::render(app, frame, logs_area);
+    }
 
     // 渲染状态栏
-    components::statusbar::render(app, frame, status_area);
+    components::statusbar::render(app, frame, areas.status);
 
     // 渲染弹窗（在最上层）
     components::modal::render(app, frame);
 }
 
 /// 渲染标题栏
-fn render_title_bar(frame: &mut Frame, area: Rect) {
+fn render_title_bar(frame: &mut Frame, area: Rect, colors: &ThemeColors) {
     let title = Paragraph::new(" DNS Orchestrator v0.1.0")
-        .style(Style::default().bg(Color::Rgb(40, 40, 40)).fg(Color::White));
+        .style(Styles::title_bar(colors));
     frame.render_widget(title, area);
 }
 
@@ -70,15 +105,16 @@ fn render_title_bar(frame: &mut Frame, area: Rect) {
 fn render_page_content(app: &App, frame: &mut Frame, area: Rect) {
     // 内容区域的边框
     let is_focused = app.focus.is_content();
+    let colors = app.settings.theme.colors();
     let border_style = if is_focused {
-        Styles::border_focused()
+        Styles::border_focused(&colors)
     } else {
-        Styles::border()
+        Styles::border(&colors)
     };
 
     let block = Block::default()
         .title(format!(" {} ", app.current_page.title()))
-        .title_style(Styles::title())
+        .title_style(Styles::title(&colors))
         .borders(Borders::ALL)
         .border_style(border_style);
 
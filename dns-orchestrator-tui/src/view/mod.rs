@@ -124,18 +124,24 @@
 //! 四、样式系统
 //! ═══════════════════════════════════════════════════════════════════════════
 //!
-//!     在 src/view/theme.rs 中定义所有样式：
+//!     在 src/view/theme.rs 中定义所有样式。
+//!     颜色取自当前主题（app.settings.theme），视图中不直接写死 Color：
 //!
-//!         Styles::border()            // 普通边框（灰色）
-//!         Styles::border_focused()    // 焦点边框（蓝色）
-//!         Styles::selected()          // 选中项（蓝色背景 + 加粗）
-//!         Styles::statusbar()         // 状态栏（蓝色背景）
-//!         Styles::hint_key()          // 快捷键（黄色 + 加粗）
+//!         let colors = app.settings.theme.colors();
+//!
+//!     主题：Dark / Light / Solarized / Gruvbox / High Contrast，
+//!     在设置页面选中 Theme 后按 Enter 即时切换。
+//!
+//!         Styles::border(&colors)            // 普通边框
+//!         Styles::border_focused(&colors)    // 焦点边框
+//!         Styles::selected(&colors)          // 导航选中项（强调背景 + 加粗）
+//!         Styles::statusbar(&colors)         // 状态栏
+//!         Styles::hint_key(&colors)          // 快捷键（加粗）
 //!
 //!     样式构建：
 //!         Style::default()
-//!             .fg(Color::Rgb(r, g, b))        // 前景色（文字）
-//!             .bg(Color::Rgb(r, g, b))        // 背景色
+//!             .fg(colors.fg)                  // 前景色（文字）
+//!             .bg(colors.selected_bg)         // 背景色
 //!             .add_modifier(Modifier::BOLD)   // 修饰符（加粗等）
 //!
 //!
@@ -2,35 +2,41 @@
 
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState},
     Frame,
 };
 
 use crate::model::App;
+use crate::view::components::spinner;
+use crate::view::theme::ThemeColors;
 
 /// 渲染账号管理页面
 pub fn render(app: &App, frame: &mut Frame, area: Rect) {
-    if app.accounts.accounts.is_empty() {
-        render_empty(frame, area);
+    let colors = app.settings.theme.colors();
+
+    if app.accounts.accounts.is_empty() && app.accounts.loading {
+        spinner::render_loading(frame, area, &colors, app.tick, "Loading accounts...");
+    } else if app.accounts.accounts.is_empty() {
+        render_empty(frame, area, &colors);
     } else {
         render_list(app, frame, area);
     }
 }
 
 /// 渲染空状态
-fn render_empty(frame: &mut Frame, area: Rect) {
+fn render_empty(frame: &mut Frame, area: Rect, colors: &ThemeColors) {
     let content = vec![
         Line::from(""),
         Line::styled(
             "  No accounts configured yet.",
-            Style::default().fg(Color::Gray),
+            Style::default().fg(colors.text_dim),
         ),
         Line::from(""),
         Line::styled(
             "  Press Alt+a to add your first account.",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(colors.muted),
         ),
     ];
 
@@ -40,6 +46,7 @@ fn render_empty(frame: &mut Frame, area: Rect) {
 
 /// 渲染账号列表
 fn render_list(app: &App, frame: &mut Frame, area: Rect) {
+    let colors = app.settings.theme.colors();
     let items: Vec<ListItem> = app
         .accounts
         .accounts
@@ -51,17 +58,17 @@ fn render_list(app: &App, frame: &mut Frame, area: Rect) {
 
             let style = if is_selected {
                 Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Cyan)
+                    .fg(colors.selected_fg)
+                    .bg(colors.selected_bg)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(colors.fg)
             };
 
             let provider_style = if is_selected {
-                Style::default().fg(Color::Black).bg(Color::Cyan)
+                Style::default().fg(colors.selected_fg).bg(colors.selected_bg)
             } else {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(colors.muted)
             };
 
             let line = Line::from(vec![
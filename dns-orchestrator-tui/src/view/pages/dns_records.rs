@@ -2,35 +2,56 @@
 
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, List, ListItem, ListState, Paragraph},
     Frame,
 };
 
 use crate::model::App;
+use crate::view::theme::ThemeColors;
+use crate::view::components::{filter, spinner};
 
 /// 渲染 DNS 记录页面
 pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let colors = app.settings.theme.colors();
+
     if app.dns_records.records.is_empty() {
-        render_empty(frame, area);
+        if app.dns_records.loading {
+            spinner::render_loading(frame, area, &colors, app.tick, "Loading records...");
+        } else {
+            render_empty(frame, area, &colors);
+        }
+        return;
+    }
+
+    let area = filter::render(
+        frame,
+        area,
+        &colors,
+        &app.dns_records.filter,
+        app.dns_records.filtering,
+    );
+    let visible = app.dns_records.visible_indices();
+    if visible.is_empty() {
+        filter::render_no_match(frame, area, &colors);
     } else {
-        render_list(app, frame, area);
+        render_list(app, &visible, frame, area);
     }
 }
 
 /// 渲染空状态
-fn render_empty(frame: &mut Frame, area: Rect) {
+fn render_empty(frame: &mut Frame, area: Rect, colors: &ThemeColors) {
     let content = vec![
         Line::from(""),
         Line::styled(
             "  No DNS records found.",
-            Style::default().fg(Color::Gray),
+            Style::default().fg(colors.text_dim),
         ),
         Line::from(""),
         Line::styled(
             "  Press Alt+a to add a new record, or Esc to go back.",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(colors.muted),
         ),
     ];
 
@@ -39,11 +60,11 @@ fn render_empty(frame: &mut Frame, area: Rect) {
 }
 
 /// 渲染 DNS 记录列表
-fn render_list(app: &App, frame: &mut Frame, area: Rect) {
-    let items: Vec<ListItem> = app
-        .dns_records
-        .records
+fn render_list(app: &App, visible: &[usize], frame: &mut Frame, area: Rect) {
+    let colors = app.settings.theme.colors();
+    let items: Vec<ListItem> = visible
         .iter()
+        .map(|&index| &app.dns_records.records[index])
         .enumerate()
         .map(|(i, record)| {
             let is_selected = i == app.dns_records.selected;
@@ -60,26 +81,26 @@ fn render_list(app: &App, frame: &mut Frame, area: Rect) {
 
             let style = if is_selected {
                 Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Cyan)
+                    .fg(colors.selected_fg)
+                    .bg(colors.selected_bg)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(colors.fg)
             };
 
             let type_style = if is_selected {
                 Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Cyan)
+                    .fg(colors.selected_fg)
+                    .bg(colors.selected_bg)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::Yellow)
+                Style::default().fg(colors.key)
             };
 
             let value_style = if is_selected {
-                Style::default().fg(Color::Black).bg(Color::Cyan)
+                Style::default().fg(colors.selected_fg).bg(colors.selected_bg)
             } else {
-                Style::default().fg(Color::Gray)
+                Style::default().fg(colors.text_dim)
             };
 
             let line = Line::from(vec![
@@ -2,35 +2,56 @@
 
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, List, ListItem, ListState},
     Frame,
 };
 
 use crate::model::App;
+use crate::view::theme::ThemeColors;
+use crate::view::components::{filter, spinner};
 
 /// 渲染域名列表页面
 pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let colors = app.settings.theme.colors();
+
     if app.domains.domains.is_empty() {
-        render_empty(frame, area);
+        if app.domains.loading {
+            spinner::render_loading(frame, area, &colors, app.tick, "Loading domains...");
+        } else {
+            render_empty(frame, area, &colors);
+        }
+        return;
+    }
+
+    let area = filter::render(
+        frame,
+        area,
+        &colors,
+        &app.domains.filter,
+        app.domains.filtering,
+    );
+    let visible = app.domains.visible_indices();
+    if visible.is_empty() {
+        filter::render_no_match(frame, area, &colors);
     } else {
-        render_list(app, frame, area);
+        render_list(app, &visible, frame, area);
     }
 }
 
 /// 渲染空状态
-fn render_empty(frame: &mut Frame, area: Rect) {
+fn render_empty(frame: &mut Frame, area: Rect, colors: &ThemeColors) {
     let content = vec![
         Line::from(""),
         Line::styled(
             "  No domains available.",
-            Style::default().fg(Color::Gray),
+            Style::default().fg(colors.text_dim),
         ),
         Line::from(""),
         Line::styled(
             "  Add an account first to see your domains.",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(colors.muted),
         ),
     ];
 
@@ -39,11 +60,11 @@ fn render_empty(frame: &mut Frame, area: Rect) {
 }
 
 /// 渲染域名列表
-fn render_list(app: &App, frame: &mut Frame, area: Rect) {
-    let items: Vec<ListItem> = app
-        .domains
-        .domains
+fn render_list(app: &App, visible: &[usize], frame: &mut Frame, area: Rect) {
+    let colors = app.settings.theme.colors();
+    let items: Vec<ListItem> = visible
         .iter()
+        .map(|&index| &app.domains.domains[index])
         .enumerate()
         .map(|(i, domain)| {
             let is_selected = i == app.domains.selected;
@@ -53,9 +74,9 @@ fn render_list(app: &App, frame: &mut Frame, area: Rect) {
                 _ => "○",
             };
             let status_color = match domain.status {
-                crate::model::domain::DomainStatus::Active => Color::Green,
-                crate::model::domain::DomainStatus::Pending => Color::Yellow,
-                _ => Color::Gray,
+                crate::model::domain::DomainStatus::Active => colors.success,
+                crate::model::domain::DomainStatus::Pending => colors.warning,
+                _ => colors.text_dim,
             };
             let record_count = domain
                 .record_count
@@ -64,23 +85,23 @@ fn render_list(app: &App, frame: &mut Frame, area: Rect) {
 
             let style = if is_selected {
                 Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Cyan)
+                    .fg(colors.selected_fg)
+                    .bg(colors.selected_bg)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(colors.fg)
             };
 
             let status_style = if is_selected {
-                Style::default().fg(status_color).bg(Color::Cyan)
+                Style::default().fg(status_color).bg(colors.selected_bg)
             } else {
                 Style::default().fg(status_color)
             };
 
             let dim_style = if is_selected {
-                Style::default().fg(Color::Black).bg(Color::Cyan)
+                Style::default().fg(colors.selected_fg).bg(colors.selected_bg)
             } else {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(colors.muted)
             };
 
             let line = Line::from(vec![
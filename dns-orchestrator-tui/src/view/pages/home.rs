@@ -2,7 +2,7 @@
 
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
@@ -11,7 +11,9 @@ use ratatui::{
 use crate::model::App;
 
 /// 渲染首页
-pub fn render(_app: &App, frame: &mut Frame, area: Rect) {
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let colors = app.settings.theme.colors();
+
     // 首页布局：欢迎信息 + 统计信息
     let layout = Layout::default()
         .direction(Direction::Vertical)
@@ -27,13 +29,13 @@ pub fn render(_app: &App, frame: &mut Frame, area: Rect) {
         Line::from(Span::styled(
             "  Welcome to DNS Orchestrator",
             Style::default()
-                .fg(Color::Cyan)
+                .fg(colors.highlight)
                 .add_modifier(Modifier::BOLD),
         )),
         Line::from(""),
         Line::from(Span::styled(
             "  Manage your DNS records across multiple providers",
-            Style::default().fg(Color::Gray),
+            Style::default().fg(colors.text_dim),
         )),
         Line::from(""),
     ];
@@ -54,12 +56,12 @@ pub fn render(_app: &App, frame: &mut Frame, area: Rect) {
     let accounts_block = Block::default()
         .title(" Accounts ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(Style::default().fg(colors.muted));
 
     let accounts_content = Paragraph::new(vec![
         Line::from(""),
-        Line::from(Span::styled("  0", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))),
-        Line::from(Span::styled("  accounts configured", Style::default().fg(Color::Gray))),
+        Line::from(Span::styled("  0", Style::default().fg(colors.success).add_modifier(Modifier::BOLD))),
+        Line::from(Span::styled("  accounts configured", Style::default().fg(colors.text_dim))),
     ])
     .block(accounts_block);
 
@@ -69,12 +71,12 @@ pub fn render(_app: &App, frame: &mut Frame, area: Rect) {
     let domains_block = Block::default()
         .title(" Domains ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(Style::default().fg(colors.muted));
 
     let domains_content = Paragraph::new(vec![
         Line::from(""),
-        Line::from(Span::styled("  0", Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD))),
-        Line::from(Span::styled("  domains managed", Style::default().fg(Color::Gray))),
+        Line::from(Span::styled("  0", Style::default().fg(colors.highlight).add_modifier(Modifier::BOLD))),
+        Line::from(Span::styled("  domains managed", Style::default().fg(colors.text_dim))),
     ])
     .block(domains_block);
 
@@ -2,45 +2,46 @@
 
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::Paragraph,
     Frame,
 };
 
+use crate::model::state::SettingsItem;
 use crate::model::App;
 
 /// 渲染设置页面
-pub fn render(_app: &App, frame: &mut Frame, area: Rect) {
-    let settings = vec![
-        ("Theme", "Dark"),
-        ("Language", "English"),
-        ("Pagination Mode", "Infinite Scroll"),
-    ];
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let settings = &app.settings;
+    let colors = settings.theme.colors();
 
     let mut lines = vec![Line::from("")];
 
-    for (i, (name, value)) in settings.iter().enumerate() {
-        let prefix = if i == 0 { "▶ " } else { "  " };
-        let style = if i == 0 {
+    for (i, item) in SettingsItem::all().iter().enumerate() {
+        let is_selected = i == settings.selected;
+        let value = settings.value_of(*item);
+
+        let prefix = if is_selected { "▶ " } else { "  " };
+        let style = if is_selected {
             Style::default()
-                .fg(Color::White)
+                .fg(colors.fg)
                 .add_modifier(Modifier::BOLD)
         } else {
-            Style::default().fg(Color::Gray)
+            Style::default().fg(colors.text_dim)
         };
 
         lines.push(Line::from(vec![
-            Span::styled(format!("  {}{}", prefix, name), style),
-            Span::styled(": ", Style::default().fg(Color::DarkGray)),
-            Span::styled(*value, Style::default().fg(Color::Cyan)),
+            Span::styled(format!("  {}{}", prefix, item.name()), style),
+            Span::styled(": ", Style::default().fg(colors.muted)),
+            Span::styled(value, Style::default().fg(colors.highlight)),
         ]));
     }
 
     lines.push(Line::from(""));
     lines.push(Line::styled(
         "  Press Enter to change selected setting.",
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(colors.muted),
     ));
 
     let paragraph = Paragraph::new(lines);
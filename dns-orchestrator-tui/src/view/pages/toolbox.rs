@@ -1,17 +1,22 @@
 //! 工具箱页面视图
 
 use ratatui::{
-    layout::Rect,
-    style::{Color, Modifier, Style},
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::Paragraph,
+    widgets::{Paragraph, Wrap},
     Frame,
 };
 
+use crate::model::state::ToolboxLine;
 use crate::model::{App, ToolboxTab};
+use crate::view::components::spinner;
+use crate::view::theme::ThemeColors;
 
 /// 渲染工具箱页面
 pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let colors = app.settings.theme.colors();
+
     let current_tab = app.toolbox.current_tab;
 
     let mut lines = vec![Line::from("")];
@@ -25,10 +30,10 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
         let is_selected = *tab == current_tab;
         let style = if is_selected {
             Style::default()
-                .fg(Color::Cyan)
+                .fg(colors.highlight)
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
         } else {
-            Style::default().fg(Color::Gray)
+            Style::default().fg(colors.text_dim)
         };
         tab_spans.push(Span::styled(tab.name(), style));
     }
@@ -37,7 +42,7 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     lines.push(Line::from(""));
     lines.push(Line::styled(
         "  ────────────────────────────────────────",
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(colors.muted),
     ));
     lines.push(Line::from(""));
 
@@ -46,114 +51,168 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
         ToolboxTab::Whois => {
             lines.push(Line::styled(
                 "  WHOIS Lookup",
-                Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                Style::default().fg(colors.fg).add_modifier(Modifier::BOLD),
             ));
             lines.push(Line::from(""));
             lines.push(Line::styled(
                 "  Query domain registration information.",
-                Style::default().fg(Color::Gray),
+                Style::default().fg(colors.text_dim),
             ));
         }
         ToolboxTab::DnsLookup => {
             lines.push(Line::styled(
                 "  DNS Lookup",
-                Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                Style::default().fg(colors.fg).add_modifier(Modifier::BOLD),
             ));
             lines.push(Line::from(""));
             lines.push(Line::styled(
                 "  Query DNS records for a domain.",
-                Style::default().fg(Color::Gray),
+                Style::default().fg(colors.text_dim),
             ));
         }
         ToolboxTab::IpLookup => {
             lines.push(Line::styled(
                 "  IP Lookup",
-                Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                Style::default().fg(colors.fg).add_modifier(Modifier::BOLD),
             ));
             lines.push(Line::from(""));
             lines.push(Line::styled(
                 "  Get geolocation and ISP information for an IP.",
-                Style::default().fg(Color::Gray),
+                Style::default().fg(colors.text_dim),
             ));
         }
         ToolboxTab::SslCheck => {
             lines.push(Line::styled(
                 "  SSL Certificate Check",
-                Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                Style::default().fg(colors.fg).add_modifier(Modifier::BOLD),
             ));
             lines.push(Line::from(""));
             lines.push(Line::styled(
                 "  Check SSL certificate status and expiry.",
-                Style::default().fg(Color::Gray),
+                Style::default().fg(colors.text_dim),
             ));
         }
         ToolboxTab::HttpHeaderCheck => {
             lines.push(Line::styled(
                 "  HTTP Header Check",
-                Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                Style::default().fg(colors.fg).add_modifier(Modifier::BOLD),
             ));
             lines.push(Line::from(""));
             lines.push(Line::styled(
                 "  Inspect HTTP response headers from a URL.",
-                Style::default().fg(Color::Gray),
+                Style::default().fg(colors.text_dim),
             ));
         }
         ToolboxTab::DnsPropagation => {
             lines.push(Line::styled(
                 "  DNS Propagation Check",
-                Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                Style::default().fg(colors.fg).add_modifier(Modifier::BOLD),
             ));
             lines.push(Line::from(""));
             lines.push(Line::styled(
                 "  Check DNS propagation across global DNS servers.",
-                Style::default().fg(Color::Gray),
+                Style::default().fg(colors.text_dim),
             ));
         }
         ToolboxTab::DnssecCheck => {
             lines.push(Line::styled(
                 "  DNSSEC Validation",
-                Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                Style::default().fg(colors.fg).add_modifier(Modifier::BOLD),
             ));
             lines.push(Line::from(""));
             lines.push(Line::styled(
                 "  Verify DNSSEC configuration for a domain.",
-                Style::default().fg(Color::Gray),
+                Style::default().fg(colors.text_dim),
             ));
         }
     }
 
-    lines.push(Line::from(""));
-    lines.push(Line::styled(
-        format!("  Placeholder: {}", app.toolbox.placeholder()),
-        Style::default().fg(Color::DarkGray),
-    ));
     lines.push(Line::from(""));
     lines.push(Line::styled(
         "  Press Tab to switch tools, Enter to execute.",
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(colors.muted),
     ));
 
-    // 显示结果或错误
-    if let Some(ref result) = app.toolbox.result {
-        lines.push(Line::from(""));
-        lines.push(Line::styled(
-            "  Result:",
-            Style::default().fg(Color::Green),
-        ));
+    let [header_area, result_area] =
+        Layout::vertical([Constraint::Length(lines.len() as u16), Constraint::Min(0)])
+            .areas(area);
+    frame.render_widget(Paragraph::new(lines), header_area);
+
+    render_result(app, &colors, frame, result_area);
+}
+
+/// 渲染当前工具的结果面板
+fn render_result(app: &App, colors: &ThemeColors, frame: &mut Frame, area: Rect) {
+    let toolbox = &app.toolbox;
+    let mut lines = vec![Line::styled(
+        "  ────────────────────────────────────────",
+        Style::default().fg(colors.muted),
+    )];
+
+    if toolbox.is_running() {
         lines.push(Line::styled(
-            format!("  {}", result),
-            Style::default().fg(Color::White),
+            format!("  {} Running...", spinner::symbol(app.tick)),
+            Style::default().fg(colors.key),
         ));
     }
 
-    if let Some(ref error) = app.toolbox.error {
-        lines.push(Line::from(""));
-        lines.push(Line::styled(
-            format!("  Error: {}", error),
-            Style::default().fg(Color::Red),
-        ));
+    match toolbox.current_output() {
+        Some(output) => {
+            lines.push(Line::from(vec![
+                Span::styled("  Result for ", Style::default().fg(colors.success)),
+                Span::styled(
+                    output.target.clone(),
+                    Style::default()
+                        .fg(colors.fg)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]));
+            match &output.result {
+                Ok(result) => {
+                    lines.extend(result.iter().map(|line| render_line(line, colors)));
+                }
+                Err(error) => {
+                    lines.push(Line::styled(
+                        format!("  Error: {}", error),
+                        Style::default().fg(colors.error),
+                    ));
+                }
+            }
+        }
+        None if !toolbox.is_running() => {
+            lines.push(Line::styled(
+                "  No results yet.",
+                Style::default().fg(colors.text_dim),
+            ));
+        }
+        None => {}
     }
 
-    let paragraph = Paragraph::new(lines);
+    let paragraph = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .scroll((toolbox.scroll, 0));
     frame.render_widget(paragraph, area);
 }
+
+/// 渲染结果中的一行
+fn render_line<'a>(line: &'a ToolboxLine, colors: &ThemeColors) -> Line<'a> {
+    match line {
+        ToolboxLine::Heading(title) => Line::styled(
+            format!("  {}", title),
+            Style::default()
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        ),
+        ToolboxLine::Field(label, value) => Line::from(vec![
+            Span::styled(
+                format!("    {:<16}", label),
+                Style::default().fg(colors.text_dim),
+            ),
+            Span::styled(value.as_str(), Style::default().fg(colors.fg)),
+        ]),
+        ToolboxLine::Text(text) => Line::styled(
+            format!("    {}", text),
+            Style::default().fg(colors.fg),
+        ),
+    }
+}
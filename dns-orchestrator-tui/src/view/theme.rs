@@ -2,13 +2,7 @@
 
 use ratatui::style::{Color, Modifier, Style};
 
-/// 主题枚举
-#[derive(Debug, Clone, Copy, Default)]
-pub enum Theme {
-    #[default]
-    Dark,
-    Light,
-}
+use crate::model::Theme;
 
 /// 获取当前主题的颜色方案
 impl Theme {
@@ -16,6 +10,9 @@ impl Theme {
         match self {
             Theme::Dark => ThemeColors::dark(),
             Theme::Light => ThemeColors::light(),
+            Theme::Solarized => ThemeColors::solarized(),
+            Theme::Gruvbox => ThemeColors::gruvbox(),
+            Theme::HighContrast => ThemeColors::high_contrast(),
         }
     }
 }
@@ -23,34 +20,59 @@ impl Theme {
 /// 主题颜色
 #[derive(Debug, Clone)]
 pub struct ThemeColors {
+    /// 背景
     pub bg: Color,
+    /// 主要文字
     pub fg: Color,
+    /// 次要文字
+    pub text_dim: Color,
+    /// 弱化文字（提示、分隔符）
+    pub muted: Color,
     pub border: Color,
     pub border_focused: Color,
+    /// 强调文字（标签、数值）
     pub highlight: Color,
+    /// 快捷键文字
+    pub key: Color,
+    /// 列表 / 按钮选中项
     pub selected_bg: Color,
     pub selected_fg: Color,
+    /// 导航选中项背景
+    pub accent_bg: Color,
+    /// 输入框背景
+    pub input_bg: Color,
+    pub title_bar_bg: Color,
+    pub statusbar_bg: Color,
+    pub statusbar_fg: Color,
+    pub statusbar_key: Color,
     pub success: Color,
     pub warning: Color,
     pub error: Color,
-    pub muted: Color,
 }
 
 impl ThemeColors {
-    /// 深色主题
+    /// 深色主题（终端默认背景）
     pub fn dark() -> Self {
         Self {
-            bg: Color::Rgb(30, 30, 30),
-            fg: Color::Rgb(212, 212, 212),
+            bg: Color::Reset,
+            fg: Color::White,
+            text_dim: Color::Gray,
+            muted: Color::DarkGray,
             border: Color::Rgb(62, 62, 62),
             border_focused: Color::Rgb(0, 122, 204),
-            highlight: Color::Rgb(0, 122, 204),
-            selected_bg: Color::Rgb(38, 79, 120),
-            selected_fg: Color::White,
-            success: Color::Rgb(78, 201, 176),
-            warning: Color::Rgb(206, 145, 120),
-            error: Color::Rgb(244, 135, 113),
-            muted: Color::Rgb(128, 128, 128),
+            highlight: Color::Cyan,
+            key: Color::Yellow,
+            selected_bg: Color::Cyan,
+            selected_fg: Color::Black,
+            accent_bg: Color::Rgb(38, 79, 120),
+            input_bg: Color::DarkGray,
+            title_bar_bg: Color::Rgb(40, 40, 40),
+            statusbar_bg: Color::Rgb(0, 122, 204),
+            statusbar_fg: Color::White,
+            statusbar_key: Color::Yellow,
+            success: Color::Green,
+            warning: Color::Yellow,
+            error: Color::Red,
         }
     }
 
@@ -59,15 +81,98 @@ impl ThemeColors {
         Self {
             bg: Color::Rgb(255, 255, 255),
             fg: Color::Rgb(51, 51, 51),
+            text_dim: Color::Rgb(90, 90, 90),
+            muted: Color::Rgb(140, 140, 140),
             border: Color::Rgb(204, 204, 204),
             border_focused: Color::Rgb(0, 102, 204),
             highlight: Color::Rgb(0, 102, 204),
-            selected_bg: Color::Rgb(204, 232, 255),
-            selected_fg: Color::Black,
+            key: Color::Rgb(176, 96, 0),
+            selected_bg: Color::Rgb(0, 102, 204),
+            selected_fg: Color::Rgb(255, 255, 255),
+            accent_bg: Color::Rgb(204, 232, 255),
+            input_bg: Color::Rgb(230, 230, 230),
+            title_bar_bg: Color::Rgb(230, 230, 230),
+            statusbar_bg: Color::Rgb(0, 102, 204),
+            statusbar_fg: Color::Rgb(255, 255, 255),
+            statusbar_key: Color::Rgb(255, 221, 87),
             success: Color::Rgb(34, 134, 58),
             warning: Color::Rgb(176, 136, 0),
             error: Color::Rgb(215, 58, 73),
-            muted: Color::Rgb(128, 128, 128),
+        }
+    }
+
+    /// Solarized（深色）
+    pub fn solarized() -> Self {
+        Self {
+            bg: Color::Rgb(0, 43, 54),
+            fg: Color::Rgb(147, 161, 161),
+            text_dim: Color::Rgb(131, 148, 150),
+            muted: Color::Rgb(88, 110, 117),
+            border: Color::Rgb(7, 54, 66),
+            border_focused: Color::Rgb(38, 139, 210),
+            highlight: Color::Rgb(42, 161, 152),
+            key: Color::Rgb(181, 137, 0),
+            selected_bg: Color::Rgb(42, 161, 152),
+            selected_fg: Color::Rgb(0, 43, 54),
+            accent_bg: Color::Rgb(7, 54, 66),
+            input_bg: Color::Rgb(7, 54, 66),
+            title_bar_bg: Color::Rgb(7, 54, 66),
+            statusbar_bg: Color::Rgb(38, 139, 210),
+            statusbar_fg: Color::Rgb(253, 246, 227),
+            statusbar_key: Color::Rgb(0, 43, 54),
+            success: Color::Rgb(133, 153, 0),
+            warning: Color::Rgb(203, 75, 22),
+            error: Color::Rgb(220, 50, 47),
+        }
+    }
+
+    /// Gruvbox（深色）
+    pub fn gruvbox() -> Self {
+        Self {
+            bg: Color::Rgb(40, 40, 40),
+            fg: Color::Rgb(235, 219, 178),
+            text_dim: Color::Rgb(189, 174, 147),
+            muted: Color::Rgb(146, 131, 116),
+            border: Color::Rgb(80, 73, 69),
+            border_focused: Color::Rgb(131, 165, 152),
+            highlight: Color::Rgb(142, 192, 124),
+            key: Color::Rgb(250, 189, 47),
+            selected_bg: Color::Rgb(142, 192, 124),
+            selected_fg: Color::Rgb(40, 40, 40),
+            accent_bg: Color::Rgb(80, 73, 69),
+            input_bg: Color::Rgb(60, 56, 54),
+            title_bar_bg: Color::Rgb(29, 32, 33),
+            statusbar_bg: Color::Rgb(69, 133, 136),
+            statusbar_fg: Color::Rgb(235, 219, 178),
+            statusbar_key: Color::Rgb(250, 189, 47),
+            success: Color::Rgb(184, 187, 38),
+            warning: Color::Rgb(254, 128, 25),
+            error: Color::Rgb(251, 73, 52),
+        }
+    }
+
+    /// 高对比度主题
+    pub fn high_contrast() -> Self {
+        Self {
+            bg: Color::Black,
+            fg: Color::White,
+            text_dim: Color::White,
+            muted: Color::Gray,
+            border: Color::White,
+            border_focused: Color::Yellow,
+            highlight: Color::LightCyan,
+            key: Color::LightYellow,
+            selected_bg: Color::Yellow,
+            selected_fg: Color::Black,
+            accent_bg: Color::Blue,
+            input_bg: Color::Blue,
+            title_bar_bg: Color::Black,
+            statusbar_bg: Color::Blue,
+            statusbar_fg: Color::White,
+            statusbar_key: Color::LightYellow,
+            success: Color::LightGreen,
+            warning: Color::LightYellow,
+            error: Color::LightRed,
         }
     }
 }
@@ -77,46 +182,51 @@ pub struct Styles;
 
 impl Styles {
     /// 普通边框样式
-    pub fn border() -> Style {
-        Style::default().fg(Color::Rgb(62, 62, 62))
+    pub fn border(colors: &ThemeColors) -> Style {
+        Style::default().fg(colors.border)
     }
 
     /// 焦点边框样式
-    pub fn border_focused() -> Style {
-        Style::default().fg(Color::Rgb(0, 122, 204))
+    pub fn border_focused(colors: &ThemeColors) -> Style {
+        Style::default().fg(colors.border_focused)
     }
 
     /// 选中项样式
-    pub fn selected() -> Style {
+    pub fn selected(colors: &ThemeColors) -> Style {
         Style::default()
-            .bg(Color::Rgb(38, 79, 120))
-            .fg(Color::White)
+            .bg(colors.accent_bg)
+            .fg(colors.fg)
             .add_modifier(Modifier::BOLD)
     }
 
     /// 标题样式
-    pub fn title() -> Style {
+    pub fn title(colors: &ThemeColors) -> Style {
         Style::default()
-            .fg(Color::Rgb(212, 212, 212))
+            .fg(colors.fg)
             .add_modifier(Modifier::BOLD)
     }
 
+    /// 标题栏样式
+    pub fn title_bar(colors: &ThemeColors) -> Style {
+        Style::default().bg(colors.title_bar_bg).fg(colors.fg)
+    }
+
     /// 状态栏样式
-    pub fn statusbar() -> Style {
+    pub fn statusbar(colors: &ThemeColors) -> Style {
         Style::default()
-            .bg(Color::Rgb(0, 122, 204))
-            .fg(Color::White)
+            .bg(colors.statusbar_bg)
+            .fg(colors.statusbar_fg)
     }
 
     /// 快捷键提示样式
-    pub fn hint_key() -> Style {
+    pub fn hint_key(colors: &ThemeColors) -> Style {
         Style::default()
-            .fg(Color::Yellow)
+            .fg(colors.statusbar_key)
             .add_modifier(Modifier::BOLD)
     }
 
     /// 快捷键说明样式
-    pub fn hint_desc() -> Style {
-        Style::default().fg(Color::Rgb(180, 180, 180))
+    pub fn hint_desc(colors: &ThemeColors) -> Style {
+        Style::default().fg(colors.statusbar_fg)
     }
-}
\ No newline at end of file
+}
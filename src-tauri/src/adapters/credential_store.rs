@@ -30,30 +30,39 @@ mod desktop {
     use std::sync::Arc;
     use tokio::sync::RwLock;
 
-    const SERVICE_NAME: &str = "dns-orchestrator";
+    /// 默认的 Keychain 服务名
+    const DEFAULT_SERVICE_NAME: &str = "dns-orchestrator";
     const CREDENTIALS_KEY: &str = "all-credentials";
 
     /// Tauri 桌面端凭证存储（使用系统 Keychain + 内存缓存）
     pub struct TauriCredentialStore {
+        /// Keychain 服务名（不同安装使用不同服务名，避免互相覆盖凭证）
+        service_name: Arc<str>,
         /// 内存缓存，减少 Keychain 访问频率
         cache: Arc<RwLock<Option<CredentialsMap>>>,
     }
 
     impl TauriCredentialStore {
         pub fn new() -> Self {
+            Self::with_service_name(DEFAULT_SERVICE_NAME)
+        }
+
+        /// 使用指定的 Keychain 服务名创建存储
+        pub fn with_service_name(service_name: impl Into<Arc<str>>) -> Self {
             Self {
+                service_name: service_name.into(),
                 cache: Arc::new(RwLock::new(None)),
             }
         }
 
-        fn get_entry() -> CoreResult<Entry> {
-            Entry::new(SERVICE_NAME, CREDENTIALS_KEY)
+        fn get_entry(service_name: &str) -> CoreResult<Entry> {
+            Entry::new(service_name, CREDENTIALS_KEY)
                 .map_err(|e| CoreError::CredentialError(e.to_string()))
         }
 
         /// 读取原始 JSON（同步方法）
-        fn read_raw_sync() -> CoreResult<String> {
-            let entry = Self::get_entry()?;
+        fn read_raw_sync(service_name: &str) -> CoreResult<String> {
+            let entry = Self::get_entry(service_name)?;
 
             match entry.get_password() {
                 Ok(json) => Ok(json),
@@ -63,8 +72,8 @@ mod desktop {
         }
 
         /// 写入原始 JSON（同步方法）
-        fn write_raw_sync(json: &str) -> CoreResult<()> {
-            let entry = Self::get_entry()?;
+        fn write_raw_sync(service_name: &str, json: &str) -> CoreResult<()> {
+            let entry = Self::get_entry(service_name)?;
             entry
                 .set_password(json)
                 .map_err(|e| CoreError::CredentialError(e.to_string()))?;
@@ -72,8 +81,8 @@ mod desktop {
         }
 
         /// 读取所有凭证（同步方法，支持双格式）
-        fn read_all_sync() -> CoreResult<CredentialsMap> {
-            let json = Self::read_raw_sync()?;
+        fn read_all_sync(service_name: &str) -> CoreResult<CredentialsMap> {
+            let json = Self::read_raw_sync(service_name)?;
 
             // 尝试解析
             match serde_json::from_str::<StorageFormat>(&json) {
@@ -85,10 +94,10 @@ mod desktop {
         }
 
         /// 写入所有凭证（同步方法）
-        fn write_all_sync(credentials: &CredentialsMap) -> CoreResult<()> {
+        fn write_all_sync(service_name: &str, credentials: &CredentialsMap) -> CoreResult<()> {
             let json = serde_json::to_string(credentials)
                 .map_err(|e| CoreError::SerializationError(e.to_string()))?;
-            Self::write_raw_sync(&json)
+            Self::write_raw_sync(service_name, &json)
         }
 
         /// 更新缓存（辅助方法）
@@ -116,9 +125,10 @@ mod desktop {
             }
 
             // 从 Keychain 加载
-            let credentials = tokio::task::spawn_blocking(|| {
-                log::debug!("Loading all credentials from Keychain");
-                Self::read_all_sync()
+            let service_name = Arc::clone(&self.service_name);
+            let credentials = tokio::task::spawn_blocking(move || {
+                log::debug!("Loading all credentials from Keychain ({service_name})");
+                Self::read_all_sync(&service_name)
             })
             .await
             .map_err(|e| CoreError::CredentialError(format!("Task join error: {e}")))?;
@@ -134,8 +144,9 @@ mod desktop {
         }
 
         async fn save_all(&self, credentials: &CredentialsMap) -> CoreResult<()> {
+            let service_name = Arc::clone(&self.service_name);
             let creds_clone = credentials.clone();
-            tokio::task::spawn_blocking(move || Self::write_all_sync(&creds_clone))
+            tokio::task::spawn_blocking(move || Self::write_all_sync(&service_name, &creds_clone))
                 .await
                 .map_err(|e| CoreError::CredentialError(format!("Task join error: {e}")))??;
 
@@ -170,14 +181,16 @@ mod desktop {
         }
 
         async fn load_raw_json(&self) -> CoreResult<String> {
-            tokio::task::spawn_blocking(Self::read_raw_sync)
+            let service_name = Arc::clone(&self.service_name);
+            tokio::task::spawn_blocking(move || Self::read_raw_sync(&service_name))
                 .await
                 .map_err(|e| CoreError::CredentialError(format!("Task join error: {e}")))?
         }
 
         async fn save_raw_json(&self, json: &str) -> CoreResult<()> {
+            let service_name = Arc::clone(&self.service_name);
             let json_clone = json.to_string();
-            tokio::task::spawn_blocking(move || Self::write_raw_sync(&json_clone))
+            tokio::task::spawn_blocking(move || Self::write_raw_sync(&service_name, &json_clone))
                 .await
                 .map_err(|e| CoreError::CredentialError(format!("Task join error: {e}")))?
         }
@@ -0,0 +1,98 @@
+//! 凭证备份
+//!
+//! 迁移前将原始凭证写入数据目录，并按数量保留最近的备份
+
+use std::path::{Path, PathBuf};
+
+use dns_orchestrator_core::error::{CoreError, CoreResult};
+
+/// 保留的凭证备份数量
+pub const CREDENTIAL_BACKUP_KEEP: usize = 5;
+
+const BACKUP_PREFIX: &str = "credentials.backup.";
+const BACKUP_SUFFIX: &str = ".json";
+
+/// 将原始凭证 JSON 写入带时间戳的备份文件
+pub fn backup_credentials_to_dir(data_dir: &Path, raw_json: &str) -> CoreResult<PathBuf> {
+    std::fs::create_dir_all(data_dir)
+        .map_err(|e| CoreError::StorageError(format!("Failed to create data dir: {e}")))?;
+
+    let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
+    let backup_path = data_dir.join(format!("{BACKUP_PREFIX}{timestamp}{BACKUP_SUFFIX}"));
+
+    std::fs::write(&backup_path, raw_json.as_bytes())
+        .map_err(|e| CoreError::StorageError(format!("Failed to write backup: {e}")))?;
+
+    Ok(backup_path)
+}
+
+/// 删除旧的凭证备份，只保留最近 `keep` 个
+///
+/// 返回删除的文件数
+pub fn prune_old_backups(data_dir: &Path, keep: usize) -> CoreResult<usize> {
+    let entries = std::fs::read_dir(data_dir)
+        .map_err(|e| CoreError::StorageError(format!("Failed to read data dir: {e}")))?;
+    let names = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+
+    let mut removed = 0;
+    for name in backups_to_prune(names, keep) {
+        match std::fs::remove_file(data_dir.join(&name)) {
+            Ok(()) => removed += 1,
+            Err(e) => log::warn!("删除旧备份 {name} 失败: {e}"),
+        }
+    }
+    Ok(removed)
+}
+
+/// 从文件名列表中挑出需要删除的备份
+///
+/// 文件名中的时间戳格式为 `%Y%m%d_%H%M%S`，按字典序即按时间排序
+fn backups_to_prune(names: Vec<String>, keep: usize) -> Vec<String> {
+    let mut backups: Vec<String> = names
+        .into_iter()
+        .filter(|name| name.starts_with(BACKUP_PREFIX) && name.ends_with(BACKUP_SUFFIX))
+        .collect();
+    backups.sort_unstable_by(|a, b| b.cmp(a));
+    backups.into_iter().skip(keep).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn backup_name(timestamp: &str) -> String {
+        format!("{BACKUP_PREFIX}{timestamp}{BACKUP_SUFFIX}")
+    }
+
+    #[test]
+    fn test_backups_to_prune_keeps_newest() {
+        let names = vec![
+            backup_name("20240301_120000"),
+            backup_name("20231231_235959"),
+            "accounts.json".to_string(),
+            backup_name("20240615_080000"),
+            backup_name("20240101_000000"),
+        ];
+
+        let pruned = backups_to_prune(names, 2);
+        assert_eq!(
+            pruned,
+            vec![
+                backup_name("20240101_000000"),
+                backup_name("20231231_235959")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_backups_to_prune_under_limit() {
+        let names = vec![
+            backup_name("20240301_120000"),
+            backup_name("20240615_080000"),
+        ];
+        assert!(backups_to_prune(names, 5).is_empty());
+    }
+}
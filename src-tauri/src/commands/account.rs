@@ -2,9 +2,9 @@ use tauri::State;
 
 use crate::error::DnsError;
 use crate::types::{
-    Account, ApiResponse, BatchDeleteResult, CreateAccountRequest, ExportAccountsRequest,
-    ExportAccountsResponse, ImportAccountsRequest, ImportPreview, ImportResult, ProviderMetadata,
-    UpdateAccountRequest,
+    Account, AccountStatus, ApiResponse, BatchDeleteResult, CreateAccountRequest,
+    ExportAccountsRequest, ExportAccountsResponse, ImportAccountsRequest, ImportMergeStrategy,
+    ImportPreview, ImportResult, ProviderMetadata, UpdateAccountRequest,
 };
 use crate::AppState;
 
@@ -36,6 +36,7 @@ fn convert_export_response(
     ExportAccountsResponse {
         content: response.content,
         suggested_filename: response.suggested_filename,
+        warning: response.warning,
     }
 }
 
@@ -50,23 +51,32 @@ fn convert_import_preview(preview: dns_orchestrator_core::types::ImportPreview)
                     name: a.name,
                     provider: a.provider,
                     has_conflict: a.has_conflict,
+                    action: a.action,
+                    import_name: a.import_name,
                 })
                 .collect()
         }),
     }
 }
 
+fn convert_import_failures(
+    failures: Vec<dns_orchestrator_core::types::ImportFailure>,
+) -> Vec<crate::types::ImportFailure> {
+    failures
+        .into_iter()
+        .map(|f| crate::types::ImportFailure {
+            name: f.name,
+            reason: f.reason,
+        })
+        .collect()
+}
+
 fn convert_import_result(result: dns_orchestrator_core::types::ImportResult) -> ImportResult {
     ImportResult {
         success_count: result.success_count,
-        failures: result
-            .failures
-            .into_iter()
-            .map(|f| crate::types::ImportFailure {
-                name: f.name,
-                reason: f.reason,
-            })
-            .collect(),
+        failures: convert_import_failures(result.failures),
+        invalid_credentials: convert_import_failures(result.invalid_credentials),
+        skipped: result.skipped,
     }
 }
 
@@ -133,6 +143,19 @@ pub async fn update_account(
     Ok(ApiResponse::success(convert_account(account)))
 }
 
+/// 重新加载单个账号（重建 Provider），返回新的账号状态
+#[tauri::command]
+pub async fn reload_account(
+    state: State<'_, AppState>,
+    account_id: String,
+) -> Result<ApiResponse<AccountStatus>, DnsError> {
+    let status = state
+        .account_bootstrap_service
+        .reload_account(&account_id)
+        .await?;
+    Ok(ApiResponse::success(convert_account_status(status)))
+}
+
 fn convert_batch_delete_result(
     result: dns_orchestrator_core::types::BatchDeleteResult,
 ) -> BatchDeleteResult {
@@ -199,10 +222,15 @@ pub async fn preview_import(
     state: State<'_, AppState>,
     content: String,
     password: Option<String>,
+    merge_strategy: Option<ImportMergeStrategy>,
 ) -> Result<ApiResponse<ImportPreview>, DnsError> {
     let preview = state
         .import_export_service
-        .preview_import(&content, password.as_deref())
+        .preview_import(
+            &content,
+            password.as_deref(),
+            merge_strategy.unwrap_or_default(),
+        )
         .await?;
 
     Ok(ApiResponse::success(convert_import_preview(preview)))
@@ -217,6 +245,8 @@ pub async fn import_accounts(
     let core_request = dns_orchestrator_core::types::ImportAccountsRequest {
         content: request.content,
         password: request.password,
+        validate_credentials: request.validate_credentials,
+        merge_strategy: request.merge_strategy,
     };
 
     let result = state
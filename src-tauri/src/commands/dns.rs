@@ -2,8 +2,9 @@ use tauri::State;
 
 use crate::error::DnsError;
 use crate::types::{
-    ApiResponse, BatchDeleteRequest, BatchDeleteResult, CreateDnsRecordRequest, DnsRecord,
-    DnsRecordType, PaginatedResponse, UpdateDnsRecordRequest,
+    ApiResponse, BatchCreateResult, BatchDeleteRequest, BatchDeleteResult, CreateDnsRecordRequest,
+    DnsRecord, DnsRecordType, PaginatedResponse, RecordExport, RecordExportFormat,
+    RecordImportResult, RecordMergeStrategy, UpdateDnsRecordRequest,
 };
 use crate::AppState;
 
@@ -51,6 +52,43 @@ pub async fn list_dns_records(
     Ok(ApiResponse::success(response))
 }
 
+/// 拉取域名下的全部 DNS 记录
+///
+/// 传入 `operation_id` 时可通过 `cancel_operation` 中止，不再发起后续分页请求。
+#[tauri::command]
+pub async fn list_all_dns_records(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+    operation_id: Option<String>,
+) -> Result<ApiResponse<Vec<DnsRecord>>, DnsError> {
+    let cancel = state.begin_operation(operation_id.as_deref());
+    let result = state
+        .dns_service
+        .list_all_records(&account_id, &domain_id, &cancel)
+        .await;
+    state.finish_operation(operation_id.as_deref());
+
+    Ok(ApiResponse::success(result?))
+}
+
+/// 取消进行中的操作（操作已结束时忽略）
+#[tauri::command]
+pub fn cancel_operation(state: State<'_, AppState>, operation_id: String) -> bool {
+    let token = state
+        .pending_operations
+        .lock()
+        .ok()
+        .and_then(|operations| operations.get(&operation_id).cloned());
+    match token {
+        Some(token) => {
+            token.cancel();
+            true
+        }
+        None => false,
+    }
+}
+
 /// 创建 DNS 记录
 #[tauri::command]
 pub async fn create_dns_record(
@@ -98,6 +136,22 @@ pub async fn delete_dns_record(
     Ok(ApiResponse::success(()))
 }
 
+/// 批量创建 DNS 记录
+#[tauri::command]
+pub async fn batch_create_dns_records(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+    requests: Vec<CreateDnsRecordRequest>,
+) -> Result<ApiResponse<BatchCreateResult>, DnsError> {
+    let result = state
+        .dns_service
+        .create_records(&account_id, &domain_id, requests)
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
 /// 批量删除 DNS 记录
 #[tauri::command]
 pub async fn batch_delete_dns_records(
@@ -118,3 +172,43 @@ pub async fn batch_delete_dns_records(
 
     Ok(ApiResponse::success(convert_batch_delete_result(result)))
 }
+
+/// 导出域名下的全部 DNS 记录
+#[tauri::command]
+pub async fn export_dns_records(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+    format: RecordExportFormat,
+) -> Result<ApiResponse<RecordExport>, DnsError> {
+    let export = state
+        .dns_service
+        .export_records(&account_id, &domain_id, format)
+        .await?;
+
+    Ok(ApiResponse::success(export))
+}
+
+/// 从 BIND 区域文件 / JSON / CSV 导入 DNS 记录
+#[tauri::command]
+pub async fn import_dns_records(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+    content: String,
+    format: RecordExportFormat,
+    merge_strategy: Option<RecordMergeStrategy>,
+) -> Result<ApiResponse<RecordImportResult>, DnsError> {
+    let result = state
+        .dns_service
+        .import_records(
+            &account_id,
+            &domain_id,
+            &content,
+            format,
+            merge_strategy.unwrap_or_default(),
+        )
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
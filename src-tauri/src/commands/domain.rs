@@ -18,16 +18,19 @@ fn convert_domain(core_domain: dns_orchestrator_core::types::AppDomain) -> Domai
 }
 
 /// 列出账号下的所有域名（分页）
+///
+/// `refresh` 为 true 时跳过后端缓存，直接请求服务商
 #[tauri::command]
 pub async fn list_domains(
     state: State<'_, AppState>,
     account_id: String,
     page: Option<u32>,
     page_size: Option<u32>,
+    refresh: Option<bool>,
 ) -> Result<ApiResponse<PaginatedResponse<Domain>>, DnsError> {
     let response = state
         .domain_service
-        .list_domains(&account_id, page, page_size)
+        .list_domains(&account_id, page, page_size, refresh.unwrap_or(false))
         .await?;
 
     // 转换响应中的 Domain 类型
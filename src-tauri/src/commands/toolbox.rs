@@ -1,4 +1,4 @@
-use dns_orchestrator_core::services::ToolboxService;
+use dns_orchestrator_core::services::{ToolboxOptions, ToolboxService};
 use dns_orchestrator_core::types::{
     DnsLookupResult, DnsPropagationResult, DnssecResult, HttpHeaderCheckRequest,
     HttpHeaderCheckResult, IpLookupResult, SslCheckResult, WhoisResult,
@@ -16,16 +16,28 @@ pub async fn whois_lookup(domain: String) -> Result<ApiResponse<WhoisResult>, St
     Ok(ApiResponse::success(result))
 }
 
-/// DNS 查询
+/// DNS 查询（`bind_addr` 指定查询使用的本地源地址）
 #[tauri::command]
 pub async fn dns_lookup(
     domain: String,
     record_type: String,
     nameserver: Option<String>,
+    bind_addr: Option<String>,
 ) -> Result<ApiResponse<DnsLookupResult>, String> {
-    let result = ToolboxService::dns_lookup(&domain, &record_type, nameserver.as_deref())
-        .await
-        .map_err(|e| e.to_string())?;
+    let result = match bind_addr.as_deref().filter(|addr| !addr.is_empty()) {
+        Some(addr) => {
+            let addr = addr.parse().map_err(|_| format!("无效的源地址: {addr}"))?;
+            ToolboxService::dns_lookup_with_options(
+                &domain,
+                &record_type,
+                nameserver.as_deref(),
+                ToolboxOptions::default().with_bind_addr(addr),
+            )
+            .await
+        }
+        None => ToolboxService::dns_lookup(&domain, &record_type, nameserver.as_deref()).await,
+    }
+    .map_err(|e| e.to_string())?;
 
     Ok(ApiResponse::success(result))
 }
@@ -24,6 +24,10 @@ pub enum DnsError {
     #[error("Account not found: {0}")]
     AccountNotFound(String),
 
+    /// 已存在使用相同凭证的账户
+    #[error("Account already exists: {0}")]
+    DuplicateAccount(String),
+
     #[error("Domain not found: {0}")]
     DomainNotFound(String),
 
@@ -60,6 +64,14 @@ pub enum DnsError {
     #[error("Unsupported file version")]
     UnsupportedFileVersion,
 
+    /// 远端服务限流
+    #[error("Rate limited: {0}")]
+    RateLimited(String),
+
+    /// 操作超时
+    #[error("Timeout: {0}")]
+    Timeout(String),
+
     /// Provider 错误（从库转换）
     #[error("{0}")]
     Provider(#[from] ProviderError),
@@ -71,6 +83,7 @@ impl From<CoreError> for DnsError {
         match err {
             CoreError::ProviderNotFound(s) => Self::ProviderNotFound(s),
             CoreError::AccountNotFound(s) => Self::AccountNotFound(s),
+            CoreError::DuplicateAccount(s) => Self::DuplicateAccount(s),
             CoreError::DomainNotFound(s) => Self::DomainNotFound(s),
             CoreError::RecordNotFound(s) => Self::RecordNotFound(s),
             CoreError::CredentialError(s) | CoreError::StorageError(s) => Self::CredentialError(s),
@@ -86,6 +99,8 @@ impl From<CoreError> for DnsError {
                 provider: "network".to_string(),
                 message: s,
             },
+            CoreError::RateLimited(s) => Self::RateLimited(s),
+            CoreError::Timeout(s) => Self::Timeout(s),
             // v1.7.0 迁移相关错误
             CoreError::MigrationRequired => {
                 Self::CredentialError("Credential migration required".to_string())
@@ -1,10 +1,13 @@
 mod adapters;
+mod backup;
 mod commands;
 mod error;
 mod types;
 
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 #[cfg(target_os = "android")]
 use commands::updater;
@@ -20,6 +23,14 @@ use dns_orchestrator_core::services::{
     ServiceContext,
 };
 use dns_orchestrator_core::traits::InMemoryProviderRegistry;
+use dns_orchestrator_core::CancellationToken;
+
+/// 域名列表缓存有效期
+const DOMAIN_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// 覆盖 Keychain 服务名的环境变量（多个安装并存时避免凭证互相覆盖）
+#[cfg(not(target_os = "android"))]
+const KEYRING_SERVICE_ENV: &str = "DNS_ORCHESTRATOR_KEYRING_SERVICE";
 
 /// 应用全局状态
 pub struct AppState {
@@ -45,13 +56,23 @@ pub struct AppState {
     pub dns_service: DnsService,
     /// 账户恢复是否完成
     pub restore_completed: AtomicBool,
+    /// 进行中的可取消操作（操作 ID -> 取消令牌）
+    pub pending_operations: Mutex<HashMap<String, CancellationToken>>,
 }
 
 impl AppState {
     pub fn new(app_handle: tauri::AppHandle) -> Self {
         // 创建适配器（Android 版本需要 AppHandle）
         #[cfg(not(target_os = "android"))]
-        let credential_store = Arc::new(TauriCredentialStore::new());
+        let credential_store = Arc::new(
+            std::env::var(KEYRING_SERVICE_ENV)
+                .ok()
+                .filter(|name| !name.is_empty())
+                .map_or_else(
+                    TauriCredentialStore::new,
+                    TauriCredentialStore::with_service_name,
+                ),
+        );
 
         #[cfg(target_os = "android")]
         let credential_store = Arc::new(TauriCredentialStore::new(app_handle.clone()));
@@ -61,12 +82,15 @@ impl AppState {
         let domain_metadata_repository = Arc::new(TauriDomainMetadataRepository::new(app_handle));
 
         // 创建服务上下文
-        let ctx = Arc::new(ServiceContext::new(
-            credential_store.clone(),
-            account_repository.clone(),
-            provider_registry.clone(),
-            domain_metadata_repository.clone(),
-        ));
+        let ctx = Arc::new(
+            ServiceContext::new(
+                credential_store.clone(),
+                account_repository.clone(),
+                provider_registry.clone(),
+                domain_metadata_repository.clone(),
+            )
+            .with_domain_cache_ttl(DOMAIN_CACHE_TTL),
+        );
 
         // 创建细粒度账户服务
         let account_metadata_service = Arc::new(AccountMetadataService::new(account_repository));
@@ -103,6 +127,25 @@ impl AppState {
             domain_metadata_service,
             dns_service,
             restore_completed: AtomicBool::new(false),
+            pending_operations: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 登记可取消操作，返回其取消令牌（未指定操作 ID 时不登记，令牌不会被取消）
+    pub fn begin_operation(&self, operation_id: Option<&str>) -> CancellationToken {
+        let token = CancellationToken::new();
+        if let Some(id) = operation_id {
+            if let Ok(mut operations) = self.pending_operations.lock() {
+                operations.insert(id.to_string(), token.clone());
+            }
+        }
+        token
+    }
+
+    /// 操作结束后移除登记
+    pub fn finish_operation(&self, operation_id: Option<&str>) {
+        if let (Some(id), Ok(mut operations)) = (operation_id, self.pending_operations.lock()) {
+            operations.remove(id);
         }
     }
 }
@@ -176,20 +219,14 @@ pub fn run() {
                     ))
                 })?;
 
-                std::fs::create_dir_all(&data_dir).map_err(|e| {
-                    dns_orchestrator_core::error::CoreError::StorageError(format!(
-                        "Failed to create data dir: {e}"
-                    ))
-                })?;
-
-                let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
-                let backup_path = data_dir.join(format!("credentials.backup.{timestamp}.json"));
+                let backup_path = backup::backup_credentials_to_dir(&data_dir, &raw_json)?;
 
-                std::fs::write(&backup_path, raw_json.as_bytes()).map_err(|e| {
-                    dns_orchestrator_core::error::CoreError::StorageError(format!(
-                        "Failed to write backup: {e}"
-                    ))
-                })?;
+                // 清理旧备份（失败不影响迁移）
+                match backup::prune_old_backups(&data_dir, backup::CREDENTIAL_BACKUP_KEEP) {
+                    Ok(0) => {}
+                    Ok(removed) => log::info!("已清理 {removed} 个旧备份"),
+                    Err(e) => log::warn!("清理旧备份失败: {e}"),
+                }
 
                 log::info!("凭证已备份到: {}", backup_path.display());
                 Ok::<_, dns_orchestrator_core::error::CoreError>(backup_path)
@@ -309,6 +346,7 @@ pub fn run() {
         account::list_accounts,
         account::create_account,
         account::update_account,
+        account::reload_account,
         account::delete_account,
         account::batch_delete_accounts,
         account::list_providers,
@@ -334,10 +372,15 @@ pub fn run() {
         domain_metadata::update_domain_metadata,
         // DNS commands
         dns::list_dns_records,
+        dns::list_all_dns_records,
+        dns::cancel_operation,
         dns::create_dns_record,
         dns::update_dns_record,
         dns::delete_dns_record,
+        dns::batch_create_dns_records,
         dns::batch_delete_dns_records,
+        dns::export_dns_records,
+        dns::import_dns_records,
         // Toolbox commands
         toolbox::whois_lookup,
         toolbox::dns_lookup,
@@ -354,6 +397,7 @@ pub fn run() {
         account::list_accounts,
         account::create_account,
         account::update_account,
+        account::reload_account,
         account::delete_account,
         account::batch_delete_accounts,
         account::list_providers,
@@ -379,10 +423,15 @@ pub fn run() {
         domain_metadata::update_domain_metadata,
         // DNS commands
         dns::list_dns_records,
+        dns::list_all_dns_records,
+        dns::cancel_operation,
         dns::create_dns_record,
         dns::update_dns_record,
         dns::delete_dns_record,
+        dns::batch_create_dns_records,
         dns::batch_delete_dns_records,
+        dns::export_dns_records,
+        dns::import_dns_records,
         // Toolbox commands
         toolbox::whois_lookup,
         toolbox::dns_lookup,
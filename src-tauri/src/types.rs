@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 // ============ Re-export 库类型 ============
 
 pub use dns_orchestrator_provider::{
+    // 批量操作类型
+    BatchCreateResult,
     // DNS 记录类型
     CreateDnsRecordRequest,
     DnsRecord,
@@ -24,6 +26,12 @@ pub use dns_orchestrator_provider::{
 
 pub use dns_orchestrator_core::types::DomainMetadata;
 
+// 导入导出类型
+pub use dns_orchestrator_core::types::{
+    ImportAccountAction, ImportMergeStrategy, RecordExport, RecordExportFormat, RecordImportResult,
+    RecordMergeStrategy,
+};
+
 // 工具箱类型
 pub use dns_orchestrator_core::types::BatchDeleteRequest;
 
@@ -124,13 +132,18 @@ pub struct BatchDeleteFailure {
 
 // ============ 导入导出相关类型 ============
 
+fn default_encrypt() -> bool {
+    true
+}
+
 /// 导出请求
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ExportAccountsRequest {
     /// 要导出的账号 ID 列表
     pub account_ids: Vec<String>,
-    /// 是否加密
+    /// 是否加密（默认加密）
+    #[serde(default = "default_encrypt")]
     pub encrypt: bool,
     /// 加密密码（仅当 encrypt=true 时需要）
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -145,6 +158,9 @@ pub struct ExportAccountsResponse {
     pub content: String,
     /// 建议的文件名
     pub suggested_filename: String,
+    /// 安全警告（未加密导出时返回）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
 }
 
 /// 导入请求
@@ -156,6 +172,12 @@ pub struct ImportAccountsRequest {
     /// 解密密码（如果文件加密）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub password: Option<String>,
+    /// 导入前校验凭证
+    #[serde(default)]
+    pub validate_credentials: bool,
+    /// 与现有账号重名时的处理策略
+    #[serde(default)]
+    pub merge_strategy: ImportMergeStrategy,
 }
 
 /// 导入预览（用于显示将要导入的账号）
@@ -179,6 +201,10 @@ pub struct ImportPreviewAccount {
     pub provider: ProviderType,
     /// 是否与现有账号名称冲突
     pub has_conflict: bool,
+    /// 按所选策略的处理方式
+    pub action: ImportAccountAction,
+    /// 导入后的账号名称
+    pub import_name: String,
 }
 
 /// 导入结果
@@ -189,6 +215,10 @@ pub struct ImportResult {
     pub success_count: usize,
     /// 失败的账号及原因
     pub failures: Vec<ImportFailure>,
+    /// 凭证无效的账号（已导入，但标记为错误状态）
+    pub invalid_credentials: Vec<ImportFailure>,
+    /// 因重名被跳过的账号名称
+    pub skipped: Vec<String>,
 }
 
 /// 导入失败项
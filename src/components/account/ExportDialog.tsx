@@ -1,4 +1,4 @@
-import { Download, Loader2, Lock } from "lucide-react"
+import { AlertTriangle, Download, Loader2, Lock } from "lucide-react"
 import { useState } from "react"
 import { useTranslation } from "react-i18next"
 import { toast } from "sonner"
@@ -31,7 +31,7 @@ interface ExportDialogProps {
 export function ExportDialog({ open, onOpenChange, accounts }: ExportDialogProps) {
   const { t } = useTranslation()
   const [selectedIds, setSelectedIds] = useState<Set<string>>(new Set())
-  const [encrypt, setEncrypt] = useState(false)
+  const [encrypt, setEncrypt] = useState(true)
   const [password, setPassword] = useState("")
   const [confirmPassword, setConfirmPassword] = useState("")
   const [isExporting, setIsExporting] = useState(false)
@@ -81,7 +81,7 @@ export function ExportDialog({ open, onOpenChange, accounts }: ExportDialogProps
 
   const resetForm = () => {
     setSelectedIds(new Set())
-    setEncrypt(false)
+    setEncrypt(true)
     setPassword("")
     setConfirmPassword("")
   }
@@ -109,6 +109,9 @@ export function ExportDialog({ open, onOpenChange, accounts }: ExportDialogProps
       })
       if (!saved) return
       toast.success(t("export.success", { count: selectedIds.size }))
+      if (response.data.warning) {
+        toast.warning(t("export.unencryptedWarning"), { duration: 10000 })
+      }
       onOpenChange(false)
       resetForm()
     } catch (err) {
@@ -193,6 +196,13 @@ export function ExportDialog({ open, onOpenChange, accounts }: ExportDialogProps
                 <p className="text-muted-foreground text-xs">{t("export.encryptionNote")}</p>
               </div>
             )}
+
+            {!encrypt && (
+              <p className="flex items-center gap-1 pl-6 text-destructive text-xs">
+                <AlertTriangle className="h-3 w-3 shrink-0" />
+                {t("export.unencryptedWarning")}
+              </p>
+            )}
           </div>
         </div>
 
@@ -1,4 +1,4 @@
-import { AlertTriangle, FileText, Loader2, Lock, Upload } from "lucide-react"
+import { AlertTriangle, FileText, KeyRound, Loader2, Lock, Upload } from "lucide-react"
 import { useCallback, useState } from "react"
 import { useTranslation } from "react-i18next"
 import { toast } from "sonner"
@@ -15,10 +15,23 @@ import {
 import { Input } from "@/components/ui/input"
 import { Label } from "@/components/ui/label"
 import { ScrollArea } from "@/components/ui/scroll-area"
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select"
+import { Switch } from "@/components/ui/switch"
 import { extractErrorMessage, getErrorMessage } from "@/lib/error"
 import { accountService } from "@/services"
 import { openFile } from "@/services/file.service"
-import type { ImportAccountsRequest, ImportPreview } from "@/types"
+import type {
+  ImportAccountsRequest,
+  ImportMergeStrategy,
+  ImportPreview,
+  ImportResult,
+} from "@/types"
 import { getProviderName, ProviderIcon } from "./ProviderIcon"
 
 interface ImportDialogProps {
@@ -29,6 +42,8 @@ interface ImportDialogProps {
 
 type ImportStep = "select" | "password" | "preview" | "importing"
 
+const MERGE_STRATEGIES: ImportMergeStrategy[] = ["rename", "skipExisting", "overwrite"]
+
 export function ImportDialog({ open, onOpenChange, onImportSuccess }: ImportDialogProps) {
   const { t } = useTranslation()
   const [step, setStep] = useState<ImportStep>("select")
@@ -38,6 +53,8 @@ export function ImportDialog({ open, onOpenChange, onImportSuccess }: ImportDial
   const [preview, setPreview] = useState<ImportPreview | null>(null)
   const [isLoading, setIsLoading] = useState(false)
   const [isImporting, setIsImporting] = useState(false)
+  const [validateCredentials, setValidateCredentials] = useState(true)
+  const [mergeStrategy, setMergeStrategy] = useState<ImportMergeStrategy>("rename")
 
   const resetState = useCallback(() => {
     setStep("select")
@@ -47,6 +64,8 @@ export function ImportDialog({ open, onOpenChange, onImportSuccess }: ImportDial
     setPreview(null)
     setIsLoading(false)
     setIsImporting(false)
+    setValidateCredentials(true)
+    setMergeStrategy("rename")
   }, [])
 
   const handleSelectFile = async () => {
@@ -62,7 +81,7 @@ export function ImportDialog({ open, onOpenChange, onImportSuccess }: ImportDial
       setFileName(result.filename)
 
       // 尝试预览（不带密码）
-      const response = await accountService.previewImport(result.content, null)
+      const response = await accountService.previewImport(result.content, null, mergeStrategy)
 
       if (response.success && response.data) {
         setPreview(response.data)
@@ -86,7 +105,7 @@ export function ImportDialog({ open, onOpenChange, onImportSuccess }: ImportDial
 
     setIsLoading(true)
     try {
-      const response = await accountService.previewImport(fileContent, password)
+      const response = await accountService.previewImport(fileContent, password, mergeStrategy)
 
       if (response.success && response.data?.accounts) {
         setPreview(response.data)
@@ -101,11 +120,49 @@ export function ImportDialog({ open, onOpenChange, onImportSuccess }: ImportDial
     }
   }
 
-  const showImportResult = (successCount: number, failures: { name: string; reason: string }[]) => {
+  const handleMergeStrategyChange = async (value: string) => {
+    const strategy = value as ImportMergeStrategy
+    setMergeStrategy(strategy)
+    if (!fileContent) return
+
+    try {
+      const response = await accountService.previewImport(
+        fileContent,
+        preview?.encrypted ? password : null,
+        strategy
+      )
+      if (response.success && response.data) {
+        setPreview(response.data)
+      } else {
+        toast.error(getErrorMessage(response.error))
+      }
+    } catch (err) {
+      toast.error(extractErrorMessage(err))
+    }
+  }
+
+  const showImportResult = ({
+    successCount,
+    failures,
+    invalidCredentials,
+    skipped,
+  }: ImportResult) => {
     if (successCount > 0) {
       toast.success(t("import.success", { count: successCount }))
+    }
+    if (successCount > 0 || invalidCredentials.length > 0) {
       onImportSuccess()
     }
+    if (invalidCredentials.length > 0) {
+      toast.warning(t("import.invalidCredentials", { count: invalidCredentials.length }), {
+        description: invalidCredentials.map((f) => `${f.name}: ${f.reason}`).join("\n"),
+      })
+    }
+    if (skipped.length > 0) {
+      toast.info(t("import.skipped", { count: skipped.length }), {
+        description: skipped.join("\n"),
+      })
+    }
     if (failures.length > 0) {
       toast.warning(t("import.partialFailure", { count: failures.length }), {
         description: failures.map((f) => `${f.name}: ${f.reason}`).join("\n"),
@@ -122,12 +179,14 @@ export function ImportDialog({ open, onOpenChange, onImportSuccess }: ImportDial
       const request: ImportAccountsRequest = {
         content: fileContent,
         password: preview?.encrypted ? password : undefined,
+        validateCredentials,
+        mergeStrategy,
       }
 
       const response = await accountService.importAccounts(request)
 
       if (response.success && response.data) {
-        showImportResult(response.data.successCount, response.data.failures)
+        showImportResult(response.data)
         onOpenChange(false)
         resetState()
       } else {
@@ -216,7 +275,14 @@ export function ImportDialog({ open, onOpenChange, onImportSuccess }: ImportDial
                       className="flex items-center gap-3 py-1"
                     >
                       <ProviderIcon provider={account.provider} className="h-4 w-4" />
-                      <span className="flex-1 truncate text-sm">{account.name}</span>
+                      <span
+                        className={`flex-1 truncate text-sm ${account.action === "skip" ? "text-muted-foreground line-through" : ""}`}
+                      >
+                        {account.action === "rename" ? account.importName : account.name}
+                      </span>
+                      {account.action !== "create" && (
+                        <Badge variant="outline">{t(`import.action.${account.action}`)}</Badge>
+                      )}
                       <span className="text-muted-foreground text-xs">
                         {getProviderName(account.provider)}
                       </span>
@@ -230,11 +296,37 @@ export function ImportDialog({ open, onOpenChange, onImportSuccess }: ImportDial
                 </div>
               </ScrollArea>
               {preview.accounts.some((a) => a.hasConflict) && (
-                <p className="flex items-center gap-1 text-muted-foreground text-xs">
-                  <AlertTriangle className="h-3 w-3" />
-                  {t("import.conflictNote")}
-                </p>
+                <div className="space-y-2">
+                  <Label>{t("import.mergeStrategy")}</Label>
+                  <Select value={mergeStrategy} onValueChange={handleMergeStrategyChange}>
+                    <SelectTrigger>
+                      <SelectValue />
+                    </SelectTrigger>
+                    <SelectContent>
+                      {MERGE_STRATEGIES.map((strategy) => (
+                        <SelectItem key={strategy} value={strategy}>
+                          {t(`import.strategy.${strategy}`)}
+                        </SelectItem>
+                      ))}
+                    </SelectContent>
+                  </Select>
+                  <p className="flex items-center gap-1 text-muted-foreground text-xs">
+                    <AlertTriangle className="h-3 w-3" />
+                    {t("import.conflictNote")}
+                  </p>
+                </div>
               )}
+              <div className="flex items-center justify-between">
+                <div className="flex items-center gap-2">
+                  <KeyRound className="h-4 w-4" />
+                  <Label htmlFor="validate-credentials">{t("import.validateCredentials")}</Label>
+                </div>
+                <Switch
+                  id="validate-credentials"
+                  checked={validateCredentials}
+                  onCheckedChange={setValidateCredentials}
+                />
+              </div>
             </div>
           )}
 
@@ -5,6 +5,7 @@ import {
   MoreHorizontal,
   Pencil,
   Plus,
+  RefreshCw,
   Trash2,
   TriangleAlert,
   Upload,
@@ -70,6 +71,7 @@ export function AccountsPage() {
   // actions 单独获取
   const fetchAccounts = useAccountStore((state) => state.fetchAccounts)
   const deleteAccount = useAccountStore((state) => state.deleteAccount)
+  const reloadAccount = useAccountStore((state) => state.reloadAccount)
   const openExportDialog = useAccountStore((state) => state.openExportDialog)
   const closeExportDialog = useAccountStore((state) => state.closeExportDialog)
   const openImportDialog = useAccountStore((state) => state.openImportDialog)
@@ -224,6 +226,17 @@ export function AccountsPage() {
                                 <Pencil className="mr-2 h-4 w-4" />
                                 {t("account.editAccount")}
                               </DropdownMenuItem>
+                              {hasError && (
+                                <DropdownMenuItem
+                                  onClick={(e) => {
+                                    e.stopPropagation()
+                                    reloadAccount(account.id)
+                                  }}
+                                >
+                                  <RefreshCw className="mr-2 h-4 w-4" />
+                                  {t("account.reloadAccount")}
+                                </DropdownMenuItem>
+                              )}
                               <DropdownMenuItem
                                 onClick={(e) => {
                                   e.stopPropagation()
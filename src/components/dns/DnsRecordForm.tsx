@@ -48,6 +48,16 @@ type FormData = {
   | { type: "NS"; nameserver: string }
   | { type: "SRV"; priority: number; weight: number; port: number; target: string }
   | { type: "CAA"; flags: number; tag: string; value: string }
+  | { type: "ALIAS"; target: string }
+  | {
+      type: "TLSA"
+      usage: number
+      selector: number
+      matchingType: number
+      certificateAssociation: string
+    }
+  | { type: "SSHFP"; algorithm: number; fingerprintType: number; fingerprint: string }
+  | { type: "DS"; keyTag: number; algorithm: number; digestType: number; digest: string }
 )
 
 // 从 DnsRecord 初始化表单数据
@@ -69,7 +79,8 @@ function initFormData(record: DnsRecord | null | undefined): FormData {
     case "AAAA":
       return { ...baseData, type: data.type, address: data.content.address }
     case "CNAME":
-      return { ...baseData, type: "CNAME", target: data.content.target }
+    case "ALIAS":
+      return { ...baseData, type: data.type, target: data.content.target }
     case "MX":
       return {
         ...baseData,
@@ -98,6 +109,32 @@ function initFormData(record: DnsRecord | null | undefined): FormData {
         tag: data.content.tag,
         value: data.content.value,
       }
+    case "TLSA":
+      return {
+        ...baseData,
+        type: "TLSA",
+        usage: data.content.usage,
+        selector: data.content.selector,
+        matchingType: data.content.matchingType,
+        certificateAssociation: data.content.certificateAssociation,
+      }
+    case "SSHFP":
+      return {
+        ...baseData,
+        type: "SSHFP",
+        algorithm: data.content.algorithm,
+        fingerprintType: data.content.fingerprintType,
+        fingerprint: data.content.fingerprint,
+      }
+    case "DS":
+      return {
+        ...baseData,
+        type: "DS",
+        keyTag: data.content.keyTag,
+        algorithm: data.content.algorithm,
+        digestType: data.content.digestType,
+        digest: data.content.digest,
+      }
     default:
       // Exhaustive check: TypeScript will error if new record type is added but not handled
       throw new Error(`Unhandled record type in initFormData: ${(data as { type: string }).type}`)
@@ -144,6 +181,7 @@ export function DnsRecordForm({
       case "AAAA":
         return formData.address
       case "CNAME":
+      case "ALIAS":
         return formData.target
       case "MX":
         return formData.exchange
@@ -155,6 +193,12 @@ export function DnsRecordForm({
         return formData.target
       case "CAA":
         return formData.value
+      case "TLSA":
+        return formData.certificateAssociation
+      case "SSHFP":
+        return formData.fingerprint
+      case "DS":
+        return formData.digest
       default:
         // Exhaustive check: TypeScript will error if new record type is added but not handled
         throw new Error(`Unhandled record type in getCurrentValue: ${(formData as { type: string }).type}`)
@@ -185,6 +229,12 @@ export function DnsRecordForm({
       case "CAA":
         params.tag = formData.tag
         break
+      case "TLSA":
+        params.usage = formData.usage
+        break
+      case "DS":
+        params.keyTag = formData.keyTag
+        break
     }
 
     // 生成基本提示
@@ -208,7 +258,8 @@ export function DnsRecordForm({
       case "AAAA":
         return { type: formData.type, content: { address: formData.address } }
       case "CNAME":
-        return { type: "CNAME", content: { target: formData.target } }
+      case "ALIAS":
+        return { type: formData.type, content: { target: formData.target } }
       case "MX":
         return {
           type: "MX",
@@ -233,6 +284,35 @@ export function DnsRecordForm({
           type: "CAA",
           content: { flags: formData.flags, tag: formData.tag, value: formData.value },
         }
+      case "TLSA":
+        return {
+          type: "TLSA",
+          content: {
+            usage: formData.usage,
+            selector: formData.selector,
+            matchingType: formData.matchingType,
+            certificateAssociation: formData.certificateAssociation,
+          },
+        }
+      case "SSHFP":
+        return {
+          type: "SSHFP",
+          content: {
+            algorithm: formData.algorithm,
+            fingerprintType: formData.fingerprintType,
+            fingerprint: formData.fingerprint,
+          },
+        }
+      case "DS":
+        return {
+          type: "DS",
+          content: {
+            keyTag: formData.keyTag,
+            algorithm: formData.algorithm,
+            digestType: formData.digestType,
+            digest: formData.digest,
+          },
+        }
       default:
         // Exhaustive check: TypeScript will error if new record type is added but not handled
         throw new Error(`Unhandled record type in buildRecordData: ${(formData as { type: string }).type}`)
@@ -270,7 +350,8 @@ export function DnsRecordForm({
         setFormData({ ...baseData, type: newType, address: "" })
         break
       case "CNAME":
-        setFormData({ ...baseData, type: "CNAME", target: "" })
+      case "ALIAS":
+        setFormData({ ...baseData, type: newType, target: "" })
         break
       case "MX":
         setFormData({ ...baseData, type: "MX", priority: 10, exchange: "" })
@@ -287,6 +368,22 @@ export function DnsRecordForm({
       case "CAA":
         setFormData({ ...baseData, type: "CAA", flags: 0, tag: "issue", value: "" })
         break
+      case "TLSA":
+        setFormData({
+          ...baseData,
+          type: "TLSA",
+          usage: 3,
+          selector: 1,
+          matchingType: 1,
+          certificateAssociation: "",
+        })
+        break
+      case "SSHFP":
+        setFormData({ ...baseData, type: "SSHFP", algorithm: 4, fingerprintType: 2, fingerprint: "" })
+        break
+      case "DS":
+        setFormData({ ...baseData, type: "DS", keyTag: 0, algorithm: 13, digestType: 2, digest: "" })
+        break
       default:
         // Exhaustive check: TypeScript will error if new record type is added but not handled
         throw new Error(`Unhandled record type in handleTypeChange: ${newType}`)
@@ -370,7 +467,7 @@ export function DnsRecordForm({
             </div>
           )}
 
-          {formData.type === "CNAME" && (
+          {(formData.type === "CNAME" || formData.type === "ALIAS") && (
             <div className="space-y-2">
               <Label htmlFor="target">{t("dns.value")}</Label>
               <Input
@@ -551,6 +648,188 @@ export function DnsRecordForm({
             </>
           )}
 
+          {formData.type === "TLSA" && (
+            <>
+              <div className="grid grid-cols-3 gap-2">
+                <div className="space-y-2">
+                  <Label htmlFor="usage">{t("dns.tlsaUsage")}</Label>
+                  <Input
+                    id="usage"
+                    type="number"
+                    value={formData.usage}
+                    onChange={(e) =>
+                      setFormData({ ...formData, usage: Number.parseInt(e.target.value, 10) })
+                    }
+                    placeholder="3"
+                    min={0}
+                    max={3}
+                    required
+                  />
+                </div>
+                <div className="space-y-2">
+                  <Label htmlFor="selector">{t("dns.tlsaSelector")}</Label>
+                  <Input
+                    id="selector"
+                    type="number"
+                    value={formData.selector}
+                    onChange={(e) =>
+                      setFormData({ ...formData, selector: Number.parseInt(e.target.value, 10) })
+                    }
+                    placeholder="1"
+                    min={0}
+                    max={1}
+                    required
+                  />
+                </div>
+                <div className="space-y-2">
+                  <Label htmlFor="matchingType">{t("dns.tlsaMatchingType")}</Label>
+                  <Input
+                    id="matchingType"
+                    type="number"
+                    value={formData.matchingType}
+                    onChange={(e) =>
+                      setFormData({
+                        ...formData,
+                        matchingType: Number.parseInt(e.target.value, 10),
+                      })
+                    }
+                    placeholder="1"
+                    min={0}
+                    max={2}
+                    required
+                  />
+                </div>
+              </div>
+              <div className="space-y-2">
+                <Label htmlFor="certificateAssociation">{t("dns.certificateAssociation")}</Label>
+                <Input
+                  id="certificateAssociation"
+                  value={formData.certificateAssociation}
+                  onChange={(e) =>
+                    setFormData({ ...formData, certificateAssociation: e.target.value })
+                  }
+                  placeholder={typeInfo.example}
+                  required
+                />
+                {renderRecordHint()}
+              </div>
+            </>
+          )}
+
+          {formData.type === "SSHFP" && (
+            <>
+              <div className="grid grid-cols-2 gap-2">
+                <div className="space-y-2">
+                  <Label htmlFor="algorithm">{t("dns.sshfpAlgorithm")}</Label>
+                  <Input
+                    id="algorithm"
+                    type="number"
+                    value={formData.algorithm}
+                    onChange={(e) =>
+                      setFormData({ ...formData, algorithm: Number.parseInt(e.target.value, 10) })
+                    }
+                    placeholder="4"
+                    min={1}
+                    max={4}
+                    required
+                  />
+                </div>
+                <div className="space-y-2">
+                  <Label htmlFor="fingerprintType">{t("dns.sshfpFingerprintType")}</Label>
+                  <Input
+                    id="fingerprintType"
+                    type="number"
+                    value={formData.fingerprintType}
+                    onChange={(e) =>
+                      setFormData({
+                        ...formData,
+                        fingerprintType: Number.parseInt(e.target.value, 10),
+                      })
+                    }
+                    placeholder="2"
+                    min={1}
+                    max={2}
+                    required
+                  />
+                </div>
+              </div>
+              <div className="space-y-2">
+                <Label htmlFor="fingerprint">{t("dns.fingerprint")}</Label>
+                <Input
+                  id="fingerprint"
+                  value={formData.fingerprint}
+                  onChange={(e) => setFormData({ ...formData, fingerprint: e.target.value })}
+                  placeholder={typeInfo.example}
+                  required
+                />
+                {renderRecordHint()}
+              </div>
+            </>
+          )}
+
+          {formData.type === "DS" && (
+            <>
+              <div className="grid grid-cols-3 gap-2">
+                <div className="space-y-2">
+                  <Label htmlFor="keyTag">{t("dns.dsKeyTag")}</Label>
+                  <Input
+                    id="keyTag"
+                    type="number"
+                    value={formData.keyTag}
+                    onChange={(e) =>
+                      setFormData({ ...formData, keyTag: Number.parseInt(e.target.value, 10) })
+                    }
+                    placeholder="2371"
+                    min={0}
+                    max={65535}
+                    required
+                  />
+                </div>
+                <div className="space-y-2">
+                  <Label htmlFor="algorithm">{t("dns.dsAlgorithm")}</Label>
+                  <Input
+                    id="algorithm"
+                    type="number"
+                    value={formData.algorithm}
+                    onChange={(e) =>
+                      setFormData({ ...formData, algorithm: Number.parseInt(e.target.value, 10) })
+                    }
+                    placeholder="13"
+                    min={0}
+                    max={255}
+                    required
+                  />
+                </div>
+                <div className="space-y-2">
+                  <Label htmlFor="digestType">{t("dns.dsDigestType")}</Label>
+                  <Input
+                    id="digestType"
+                    type="number"
+                    value={formData.digestType}
+                    onChange={(e) =>
+                      setFormData({ ...formData, digestType: Number.parseInt(e.target.value, 10) })
+                    }
+                    placeholder="2"
+                    min={1}
+                    max={4}
+                    required
+                  />
+                </div>
+              </div>
+              <div className="space-y-2">
+                <Label htmlFor="digest">{t("dns.digest")}</Label>
+                <Input
+                  id="digest"
+                  value={formData.digest}
+                  onChange={(e) => setFormData({ ...formData, digest: e.target.value })}
+                  placeholder={typeInfo.example}
+                  required
+                />
+                {renderRecordHint()}
+              </div>
+            </>
+          )}
+
           {/* TTL */}
           <div className="space-y-2">
             <Label htmlFor="ttl">{t("dns.ttl")}</Label>
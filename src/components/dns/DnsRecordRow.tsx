@@ -34,6 +34,10 @@ const TYPE_COLORS: Record<string, string> = {
   NS: "bg-yellow-100 text-yellow-800 dark:bg-yellow-900 dark:text-yellow-300",
   SRV: "bg-pink-100 text-pink-800 dark:bg-pink-900 dark:text-pink-300",
   CAA: "bg-red-100 text-red-800 dark:bg-red-900 dark:text-red-300",
+  ALIAS: "bg-teal-100 text-teal-800 dark:bg-teal-900 dark:text-teal-300",
+  TLSA: "bg-indigo-100 text-indigo-800 dark:bg-indigo-900 dark:text-indigo-300",
+  SSHFP: "bg-cyan-100 text-cyan-800 dark:bg-cyan-900 dark:text-cyan-300",
+  DS: "bg-purple-100 text-purple-800 dark:bg-purple-900 dark:text-purple-300",
 }
 
 function formatTTL(
@@ -56,6 +60,7 @@ function renderRecordValue(record: DnsRecord) {
     case "AAAA":
       return data.content.address
     case "CNAME":
+    case "ALIAS":
       return data.content.target
     case "MX":
       return (
@@ -97,6 +102,33 @@ function renderRecordValue(record: DnsRecord) {
           {data.content.value}
         </>
       )
+    case "TLSA":
+      return (
+        <>
+          <span className="mr-2 inline-flex items-center rounded-full border border-indigo-400 bg-indigo-100 px-2 py-0.5 font-medium text-indigo-700 text-xs dark:border-indigo-500 dark:bg-indigo-900/50 dark:text-indigo-300">
+            {data.content.usage} {data.content.selector} {data.content.matchingType}
+          </span>
+          {data.content.certificateAssociation}
+        </>
+      )
+    case "SSHFP":
+      return (
+        <>
+          <span className="mr-2 inline-flex items-center rounded-full border border-cyan-400 bg-cyan-100 px-2 py-0.5 font-medium text-cyan-700 text-xs dark:border-cyan-500 dark:bg-cyan-900/50 dark:text-cyan-300">
+            {data.content.algorithm} {data.content.fingerprintType}
+          </span>
+          {data.content.fingerprint}
+        </>
+      )
+    case "DS":
+      return (
+        <>
+          <span className="mr-2 inline-flex items-center rounded-full border border-purple-400 bg-purple-100 px-2 py-0.5 font-medium text-purple-700 text-xs dark:border-purple-500 dark:bg-purple-900/50 dark:text-purple-300">
+            {data.content.keyTag} {data.content.algorithm} {data.content.digestType}
+          </span>
+          {data.content.digest}
+        </>
+      )
   }
 }
 
@@ -109,6 +141,7 @@ function getDisplayText(record: DnsRecord): string {
     case "AAAA":
       return data.content.address
     case "CNAME":
+    case "ALIAS":
       return data.content.target
     case "MX":
       return `[${data.content.priority}] ${data.content.exchange}`
@@ -120,6 +153,12 @@ function getDisplayText(record: DnsRecord): string {
       return `[${data.content.priority}] [${data.content.weight}] [${data.content.port}] ${data.content.target}`
     case "CAA":
       return `[${data.content.flags}] [${data.content.tag}] ${data.content.value}`
+    case "TLSA":
+      return `[${data.content.usage} ${data.content.selector} ${data.content.matchingType}] ${data.content.certificateAssociation}`
+    case "SSHFP":
+      return `[${data.content.algorithm} ${data.content.fingerprintType}] ${data.content.fingerprint}`
+    case "DS":
+      return `[${data.content.keyTag} ${data.content.algorithm} ${data.content.digestType}] ${data.content.digest}`
   }
 }
 
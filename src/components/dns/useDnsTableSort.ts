@@ -20,6 +20,7 @@ function getRecordDisplayValue(record: DnsRecord): string {
     case "AAAA":
       return data.content.address
     case "CNAME":
+    case "ALIAS":
       return data.content.target
     case "MX":
       return data.content.exchange
@@ -31,6 +32,12 @@ function getRecordDisplayValue(record: DnsRecord): string {
       return data.content.target
     case "CAA":
       return data.content.value
+    case "TLSA":
+      return data.content.certificateAssociation
+    case "SSHFP":
+      return data.content.fingerprint
+    case "DS":
+      return data.content.digest
   }
 }
 
@@ -68,7 +68,11 @@ function getStatusInfo(result: SslCheckResult | null, t: (key: string) => string
       bgColor: "bg-destructive/10",
       borderColor: "border-destructive/30",
       icon: XCircle,
-      label: cert.isExpired ? t("toolbox.ssl.expired") : t("toolbox.ssl.invalid"),
+      label: cert.isExpired
+        ? t("toolbox.ssl.expired")
+        : cert.hostnameMatches
+          ? t("toolbox.ssl.invalid")
+          : t("toolbox.ssl.hostnameMismatch"),
     }
   }
 
@@ -55,6 +55,9 @@ export default {
     createFailed: "Failed to create account",
     deleteSuccess: "Account deleted",
     deleteFailed: "Failed to delete account",
+    reloadAccount: "Reload",
+    reloadSuccess: "Account reloaded",
+    reloadFailed: "Failed to reload account, please check its credentials",
     // 批量操作
     selectedCount: "{{count}} selected",
     batchDelete: "Delete Selected",
@@ -89,6 +92,8 @@ export default {
     confirmPassword: "Confirm Password",
     confirmPasswordPlaceholder: "Re-enter password",
     encryptionNote: "Keep your password safe, you cannot recover data without it",
+    unencryptedWarning:
+      "This export is NOT encrypted: all credentials are stored in plain text. Keep the file private and delete it when no longer needed",
     exportButton: "Export",
     noAccountsSelected: "Please select at least one account",
     passwordRequired: "Please enter encryption password",
@@ -110,12 +115,25 @@ export default {
     decrypt: "Decrypt",
     accountCount: "{{count}} account(s)",
     nameConflict: "Name conflicts with existing account",
-    conflictNote:
-      "Marked accounts have the same name as existing ones, they will coexist after import",
+    conflictNote: "Marked accounts have the same name as existing ones",
+    mergeStrategy: "When names conflict",
+    strategy: {
+      rename: "Import with a new name",
+      skipExisting: "Skip existing accounts",
+      overwrite: "Overwrite existing accounts",
+    },
+    action: {
+      skip: "Skip",
+      overwrite: "Overwrite",
+      rename: "Rename",
+    },
+    skipped: "{{count}} account(s) skipped because of name conflicts",
     importButton: "Import",
     importing: "Importing...",
     success: "Successfully imported {{count}} account(s)",
     partialFailure: "{{count}} account(s) failed to import",
+    validateCredentials: "Validate credentials before import",
+    invalidCredentials: "{{count}} account(s) have invalid credentials and were marked as error",
     failed: "Import failed",
     invalidFile: "Invalid import file",
     decryptFailed: "Decryption failed, please check password",
@@ -149,6 +167,17 @@ export default {
     target: "Target",
     flags: "Flags",
     tag: "Tag",
+    tlsaUsage: "Certificate Usage",
+    tlsaSelector: "Selector",
+    tlsaMatchingType: "Matching Type",
+    certificateAssociation: "Certificate Data",
+    sshfpAlgorithm: "Algorithm",
+    sshfpFingerprintType: "Fingerprint Type",
+    fingerprint: "Fingerprint",
+    dsKeyTag: "Key Tag",
+    dsAlgorithm: "Algorithm",
+    dsDigestType: "Digest Type",
+    digest: "Digest",
     proxy: "Proxy",
     proxyHelp: "Enable CDN proxy to hide origin IP",
     actions: "Actions",
@@ -170,6 +199,10 @@ export default {
       NS: "Name Server",
       SRV: "Service Record",
       CAA: "CA Authorization",
+      ALIAS: "Apex Alias",
+      TLSA: "DANE Certificate",
+      SSHFP: "SSH Fingerprint",
+      DS: "Delegation Signer",
     },
     // DNS record live hints
     recordHints: {
@@ -181,6 +214,10 @@ export default {
       NS: "💡 DNS resolution for {{fqdn}} will be handled by {{value}}",
       SRV: "💡 Service {{fqdn}} will point to {{value}}:{{port}} (priority {{priority}}, weight {{weight}})",
       CAA: "💡 {{fqdn}} allows {{value}} to issue SSL certificates (tag: {{tag}})",
+      ALIAS: "💡 {{fqdn}} will resolve to the addresses of {{value}}",
+      TLSA: "💡 TLS clients of {{fqdn}} will verify the certificate against {{value}} (usage {{usage}})",
+      SSHFP: "💡 SSH clients can verify the host key of {{fqdn}} against fingerprint {{value}}",
+      DS: "💡 Resolvers will validate the DNSSEC signatures of {{fqdn}} with key {{keyTag}}",
       proxyEnabled: "(Proxy enabled, real IP will be hidden)",
     },
     // Toast messages
@@ -555,6 +592,7 @@ export default {
       validity: "Validity",
       valid: "Valid",
       invalid: "Invalid",
+      hostnameMismatch: "Hostname Mismatch",
       expired: "Expired",
       expiringSoon: "Expiring Soon",
       daysRemaining: "{{count}} days remaining",
@@ -689,6 +727,7 @@ export default {
     credential_load_failed: "Failed to load credentials: {{detail}}",
     credential_not_found: "Credentials not found",
     credential_save_failed: "Failed to save credentials: {{detail}}",
+    duplicate_account: "An account with these credentials already exists",
     // Provider errors
     provider_create_failed: "Failed to create provider: {{detail}}",
     unsupported_record_type: "Unsupported record type: {{detail}}",
@@ -722,6 +761,8 @@ export default {
         domain_not_found: 'Domain "{{domain}}" not found',
         domain_locked: 'Domain "{{domain}}" is locked or disabled',
         permission_denied: "Permission denied",
+        server_failure: "DNS server failed to process the request (SERVFAIL)",
+        refused: "DNS server refused the request (REFUSED)",
         parse_error: "Response parse failed: {{detail}}",
         unknown: "Unknown error",
       },
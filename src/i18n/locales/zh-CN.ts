@@ -54,6 +54,9 @@ export default {
     createFailed: "创建账号失败",
     deleteSuccess: "账号已删除",
     deleteFailed: "删除账号失败",
+    reloadAccount: "重新加载",
+    reloadSuccess: "账号已重新加载",
+    reloadFailed: "重新加载账号失败，请检查凭证",
     // 批量操作
     selectedCount: "已选择 {{count}} 个账户",
     batchDelete: "批量删除",
@@ -87,6 +90,7 @@ export default {
     confirmPassword: "确认密码",
     confirmPasswordPlaceholder: "再次输入密码",
     encryptionNote: "请妥善保管密码，遗失密码将无法恢复账号数据",
+    unencryptedWarning: "此导出未加密：所有凭证均以明文保存，请妥善保管文件并在不再需要时删除",
     exportButton: "导出",
     noAccountsSelected: "请选择至少一个账号",
     passwordRequired: "请输入加密密码",
@@ -108,11 +112,25 @@ export default {
     decrypt: "解密",
     accountCount: "{{count}} 个账号",
     nameConflict: "与现有账号名称冲突",
-    conflictNote: "标记的账号与现有账号名称相同，导入后将同时存在",
+    conflictNote: "标记的账号与现有账号名称相同",
+    mergeStrategy: "名称冲突时",
+    strategy: {
+      rename: "以新名称导入",
+      skipExisting: "跳过已存在的账号",
+      overwrite: "覆盖已存在的账号",
+    },
+    action: {
+      skip: "跳过",
+      overwrite: "覆盖",
+      rename: "重命名",
+    },
+    skipped: "{{count}} 个账号因名称冲突被跳过",
     importButton: "导入",
     importing: "正在导入...",
     success: "成功导入 {{count}} 个账号",
     partialFailure: "{{count}} 个账号导入失败",
+    validateCredentials: "导入前校验凭证",
+    invalidCredentials: "{{count}} 个账号凭证无效，已标记为错误状态",
     failed: "导入失败",
     invalidFile: "无效的导入文件",
     decryptFailed: "解密失败，请检查密码",
@@ -144,6 +162,17 @@ export default {
     target: "目标",
     flags: "标志",
     tag: "标签",
+    tlsaUsage: "证书用途",
+    tlsaSelector: "选择器",
+    tlsaMatchingType: "匹配类型",
+    certificateAssociation: "证书数据",
+    sshfpAlgorithm: "算法",
+    sshfpFingerprintType: "指纹类型",
+    fingerprint: "指纹",
+    dsKeyTag: "密钥标签",
+    dsAlgorithm: "算法",
+    dsDigestType: "摘要类型",
+    digest: "摘要",
     proxy: "代理",
     proxyHelp: "启用 CDN 代理，隐藏源站 IP",
     actions: "操作",
@@ -165,6 +194,10 @@ export default {
       NS: "名称服务器",
       SRV: "服务记录",
       CAA: "CA 授权",
+      ALIAS: "根域别名",
+      TLSA: "DANE 证书",
+      SSHFP: "SSH 指纹",
+      DS: "DNSSEC 委派签名",
     },
     // DNS 记录实时提示
     recordHints: {
@@ -176,6 +209,10 @@ export default {
       NS: "💡 {{fqdn}} 的域名解析将由 {{value}} 负责",
       SRV: "💡 服务 {{fqdn}} 将指向 {{value}}:{{port}}（优先级 {{priority}}，权重 {{weight}}）",
       CAA: "💡 {{fqdn}} 允许 {{value}} 签发 SSL 证书（标签：{{tag}}）",
+      ALIAS: "💡 {{fqdn}} 将解析为 {{value}} 的地址",
+      TLSA: "💡 访问 {{fqdn}} 的 TLS 客户端将按 {{value}} 校验证书（用途 {{usage}}）",
+      SSHFP: "💡 SSH 客户端可按指纹 {{value}} 校验 {{fqdn}} 的主机密钥",
+      DS: "💡 解析器将使用密钥 {{keyTag}} 校验 {{fqdn}} 的 DNSSEC 签名",
       proxyEnabled: "（已启用代理，真实 IP 将被隐藏）",
     },
     // Toast messages
@@ -549,6 +586,7 @@ export default {
       validity: "有效期",
       valid: "证书有效",
       invalid: "证书无效",
+      hostnameMismatch: "证书与域名不匹配",
       expired: "证书已过期",
       expiringSoon: "即将过期",
       daysRemaining: "剩余 {{count}} 天",
@@ -682,6 +720,7 @@ export default {
     credential_load_failed: "凭证加载失败: {{detail}}",
     credential_not_found: "凭证未找到",
     credential_save_failed: "保存凭证失败: {{detail}}",
+    duplicate_account: "已存在使用相同凭证的账号",
     // Provider 错误
     provider_create_failed: "Provider 创建失败: {{detail}}",
     unsupported_record_type: "不支持的记录类型: {{detail}}",
@@ -715,6 +754,8 @@ export default {
         domain_not_found: '域名 "{{domain}}" 不存在',
         domain_locked: '域名 "{{domain}}" 已被锁定或禁用',
         permission_denied: "操作被拒绝，权限不足",
+        server_failure: "DNS 服务器处理请求失败（SERVFAIL）",
+        refused: "DNS 服务器拒绝了请求（REFUSED）",
         parse_error: "响应解析失败: {{detail}}",
         unknown: "未知错误",
       },
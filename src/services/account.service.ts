@@ -4,12 +4,14 @@
 
 import type {
   Account,
+  AccountStatus,
   ApiResponse,
   BatchDeleteResult,
   CreateAccountRequest,
   ExportAccountsRequest,
   ExportAccountsResponse,
   ImportAccountsRequest,
+  ImportMergeStrategy,
   ImportPreview,
   ImportResult,
   ProviderInfo,
@@ -34,6 +36,10 @@ class AccountService {
     return transport.invoke("update_account", { request })
   }
 
+  reloadAccount(accountId: string): Promise<ApiResponse<AccountStatus>> {
+    return transport.invoke("reload_account", { accountId })
+  }
+
   batchDeleteAccounts(accountIds: string[]): Promise<ApiResponse<BatchDeleteResult>> {
     return transport.invoke("batch_delete_accounts", { accountIds })
   }
@@ -46,8 +52,12 @@ class AccountService {
     return transport.invoke("export_accounts", { request })
   }
 
-  previewImport(content: string, password: string | null): Promise<ApiResponse<ImportPreview>> {
-    return transport.invoke("preview_import", { content, password })
+  previewImport(
+    content: string,
+    password: string | null,
+    mergeStrategy?: ImportMergeStrategy
+  ): Promise<ApiResponse<ImportPreview>> {
+    return transport.invoke("preview_import", { content, password, mergeStrategy })
   }
 
   importAccounts(request: ImportAccountsRequest): Promise<ApiResponse<ImportResult>> {
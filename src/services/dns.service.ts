@@ -4,11 +4,16 @@
 
 import type {
   ApiResponse,
+  BatchCreateResult,
   BatchDeleteRequest,
   BatchDeleteResult,
   CreateDnsRecordRequest,
   DnsRecord,
   PaginatedResponse,
+  RecordExport,
+  RecordExportFormat,
+  RecordImportResult,
+  RecordMergeStrategy,
   UpdateDnsRecordRequest,
 } from "@/types"
 import { transport } from "./transport"
@@ -27,6 +32,20 @@ class DnsService {
     return transport.invoke("list_dns_records", params)
   }
 
+  /** 拉取全部记录，传入 operationId 时可用 cancelOperation 中止 */
+  listAllRecords(
+    accountId: string,
+    domainId: string,
+    operationId?: string
+  ): Promise<ApiResponse<DnsRecord[]>> {
+    return transport.invoke("list_all_dns_records", { accountId, domainId, operationId })
+  }
+
+  /** 取消进行中的操作，返回是否找到该操作 */
+  cancelOperation(operationId: string): Promise<boolean> {
+    return transport.invoke("cancel_operation", { operationId })
+  }
+
   createRecord(
     accountId: string,
     request: CreateDnsRecordRequest
@@ -46,12 +65,44 @@ class DnsService {
     return transport.invoke("delete_dns_record", { accountId, recordId, domainId })
   }
 
+  batchCreateRecords(
+    accountId: string,
+    domainId: string,
+    requests: CreateDnsRecordRequest[]
+  ): Promise<ApiResponse<BatchCreateResult>> {
+    return transport.invoke("batch_create_dns_records", { accountId, domainId, requests })
+  }
+
   batchDeleteRecords(
     accountId: string,
     request: BatchDeleteRequest
   ): Promise<ApiResponse<BatchDeleteResult>> {
     return transport.invoke("batch_delete_dns_records", { accountId, request })
   }
+
+  exportRecords(
+    accountId: string,
+    domainId: string,
+    format: RecordExportFormat
+  ): Promise<ApiResponse<RecordExport>> {
+    return transport.invoke("export_dns_records", { accountId, domainId, format })
+  }
+
+  importRecords(
+    accountId: string,
+    domainId: string,
+    content: string,
+    format: RecordExportFormat,
+    mergeStrategy?: RecordMergeStrategy
+  ): Promise<ApiResponse<RecordImportResult>> {
+    return transport.invoke("import_dns_records", {
+      accountId,
+      domainId,
+      content,
+      format,
+      mergeStrategy,
+    })
+  }
 }
 
 export const dnsService = new DnsService()
@@ -9,9 +9,10 @@ class DomainService {
   listDomains(
     accountId: string,
     page?: number,
-    pageSize?: number
+    pageSize?: number,
+    refresh?: boolean
   ): Promise<ApiResponse<PaginatedResponse<Domain>>> {
-    return transport.invoke("list_domains", { accountId, page, pageSize })
+    return transport.invoke("list_domains", { accountId, page, pageSize, refresh })
   }
 
   getDomain(accountId: string, domainId: string): Promise<ApiResponse<Domain>> {
@@ -19,9 +19,10 @@ class ToolboxService {
   dnsLookup(
     domain: string,
     recordType: string,
-    nameserver: string | null
+    nameserver: string | null,
+    bindAddr?: string
   ): Promise<ApiResponse<DnsLookupResult>> {
-    return transport.invoke("dns_lookup", { domain, recordType, nameserver })
+    return transport.invoke("dns_lookup", { domain, recordType, nameserver, bindAddr })
   }
 
   ipLookup(query: string): Promise<ApiResponse<IpLookupResult>> {
@@ -5,7 +5,9 @@
 
 import type {
   Account,
+  AccountStatus,
   ApiResponse,
+  BatchCreateResult,
   BatchDeleteRequest,
   BatchDeleteResult,
   BatchTagRequest,
@@ -24,11 +26,16 @@ import type {
   HttpHeaderCheckRequest,
   HttpHeaderCheckResult,
   ImportAccountsRequest,
+  ImportMergeStrategy,
   ImportPreview,
   ImportResult,
   IpLookupResult,
   PaginatedResponse,
   ProviderInfo,
+  RecordExport,
+  RecordExportFormat,
+  RecordImportResult,
+  RecordMergeStrategy,
   SslCheckResult,
   UpdateDnsRecordRequest,
   WhoisResult,
@@ -51,6 +58,10 @@ export interface CommandMap {
     args: { accountId: string }
     result: ApiResponse<void>
   }
+  reload_account: {
+    args: { accountId: string }
+    result: ApiResponse<AccountStatus>
+  }
   batch_delete_accounts: {
     args: { accountIds: string[] }
     result: ApiResponse<BatchDeleteResult>
@@ -64,7 +75,7 @@ export interface CommandMap {
     result: ApiResponse<ExportAccountsResponse>
   }
   preview_import: {
-    args: { content: string; password: string | null }
+    args: { content: string; password: string | null; mergeStrategy?: ImportMergeStrategy }
     result: ApiResponse<ImportPreview>
   }
   import_accounts: {
@@ -78,7 +89,7 @@ export interface CommandMap {
 
   // Domain commands
   list_domains: {
-    args: { accountId: string; page?: number; pageSize?: number }
+    args: { accountId: string; page?: number; pageSize?: number; refresh?: boolean }
     result: ApiResponse<PaginatedResponse<Domain>>
   }
   get_domain: {
@@ -148,6 +159,14 @@ export interface CommandMap {
     }
     result: ApiResponse<PaginatedResponse<DnsRecord>>
   }
+  list_all_dns_records: {
+    args: { accountId: string; domainId: string; operationId?: string }
+    result: ApiResponse<DnsRecord[]>
+  }
+  cancel_operation: {
+    args: { operationId: string }
+    result: boolean
+  }
   create_dns_record: {
     args: { accountId: string; request: CreateDnsRecordRequest }
     result: ApiResponse<DnsRecord>
@@ -160,10 +179,28 @@ export interface CommandMap {
     args: { accountId: string; recordId: string; domainId: string }
     result: ApiResponse<void>
   }
+  batch_create_dns_records: {
+    args: { accountId: string; domainId: string; requests: CreateDnsRecordRequest[] }
+    result: ApiResponse<BatchCreateResult>
+  }
   batch_delete_dns_records: {
     args: { accountId: string; request: BatchDeleteRequest }
     result: ApiResponse<BatchDeleteResult>
   }
+  export_dns_records: {
+    args: { accountId: string; domainId: string; format: RecordExportFormat }
+    result: ApiResponse<RecordExport>
+  }
+  import_dns_records: {
+    args: {
+      accountId: string
+      domainId: string
+      content: string
+      format: RecordExportFormat
+      mergeStrategy?: RecordMergeStrategy
+    }
+    result: ApiResponse<RecordImportResult>
+  }
 
   // Toolbox commands
   whois_lookup: {
@@ -171,7 +208,7 @@ export interface CommandMap {
     result: ApiResponse<WhoisResult>
   }
   dns_lookup: {
-    args: { domain: string; recordType: string; nameserver: string | null }
+    args: { domain: string; recordType: string; nameserver: string | null; bindAddr?: string }
     result: ApiResponse<DnsLookupResult>
   }
   ip_lookup: {
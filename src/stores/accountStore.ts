@@ -40,6 +40,7 @@ interface AccountState {
   createAccount: (request: CreateAccountRequest) => Promise<Account | null>
   updateAccount: (request: UpdateAccountRequest) => Promise<Account | null>
   deleteAccount: (id: string) => Promise<boolean>
+  reloadAccount: (id: string) => Promise<boolean>
   selectAccount: (id: string | null) => void
   setExpandedAccountId: (id: string | null) => void
   clearFieldErrors: () => void
@@ -172,6 +173,31 @@ export const useAccountStore = create<AccountState>((set, get) => ({
     }
   },
 
+  reloadAccount: async (id) => {
+    try {
+      const response = await accountService.reloadAccount(id)
+      if (!(response.success && response.data)) {
+        toast.error(getErrorMessage(response.error))
+        return false
+      }
+      // 刷新列表以获取最新的状态与错误信息
+      const listResponse = await accountService.listAccounts()
+      if (listResponse.success && listResponse.data) {
+        set({ accounts: listResponse.data })
+      }
+      if (response.data === "active") {
+        useDomainStore.getState().clearAccountCache(id)
+        toast.success(i18n.t("account.reloadSuccess"))
+        return true
+      }
+      toast.error(i18n.t("account.reloadFailed"))
+      return false
+    } catch (err) {
+      toast.error(extractErrorMessage(err))
+      return false
+    }
+  },
+
   updateAccount: async (request) => {
     set({ isUpdating: true, error: null, fieldErrors: {} })
     try {
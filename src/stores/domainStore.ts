@@ -462,7 +462,7 @@ export const useDomainStore = create<DomainState>((set, get) => ({
 
     try {
       const pageSize = getDomainPageSize(accountId)
-      const response = await domainService.listDomains(accountId, 1, pageSize)
+      const response = await domainService.listDomains(accountId, 1, pageSize, true)
       if (response.success && response.data) {
         set((state) => ({
           domainsByAccount: {
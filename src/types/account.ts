@@ -22,6 +22,13 @@ export type ProviderCredentials =
         api_token: string
       }
     }
+  | {
+      provider: "cloudflareKey"
+      credentials: {
+        email: string
+        api_key: string
+      }
+    }
   | {
       provider: "aliyun"
       credentials: {
@@ -63,7 +70,8 @@ export interface UpdateAccountRequest {
 /** 导出请求 */
 export interface ExportAccountsRequest {
   accountIds: string[]
-  encrypt: boolean
+  /** 是否加密（默认 true） */
+  encrypt?: boolean
   password?: string
 }
 
@@ -71,14 +79,26 @@ export interface ExportAccountsRequest {
 export interface ExportAccountsResponse {
   content: string
   suggestedFilename: string
+  /** 未加密导出时的安全警告 */
+  warning?: string
 }
 
 /** 导入请求 */
 export interface ImportAccountsRequest {
   content: string
   password?: string
+  /** 导入前校验凭证，无效的账号以错误状态导入 */
+  validateCredentials?: boolean
+  /** 与现有账号重名时的处理策略（默认 rename） */
+  mergeStrategy?: ImportMergeStrategy
 }
 
+/** 导入时与现有账号重名的处理策略 */
+export type ImportMergeStrategy = "skipExisting" | "overwrite" | "rename"
+
+/** 单个账号在导入时的处理方式 */
+export type ImportAccountAction = "create" | "skip" | "overwrite" | "rename"
+
 /** 导入预览 */
 export interface ImportPreview {
   encrypted: boolean
@@ -91,12 +111,19 @@ export interface ImportPreviewAccount {
   name: string
   provider: string
   hasConflict: boolean
+  action: ImportAccountAction
+  /** 导入后的账号名称 */
+  importName: string
 }
 
 /** 导入结果 */
 export interface ImportResult {
   successCount: number
   failures: ImportFailure[]
+  /** 凭证无效的账号（已导入，但标记为错误状态） */
+  invalidCredentials: ImportFailure[]
+  /** 因重名被跳过的账号名称 */
+  skipped: string[]
 }
 
 /** 导入失败项 */
@@ -1,8 +1,8 @@
 /** DNS 记录类型枚举 */
-export type DnsRecordType = "A" | "AAAA" | "CNAME" | "MX" | "TXT" | "NS" | "SRV" | "CAA"
+export type DnsRecordType = "A" | "AAAA" | "CNAME" | "MX" | "TXT" | "NS" | "SRV" | "CAA" | "ALIAS" | "TLSA" | "SSHFP" | "DS"
 
 /** 所有可用的记录类型列表 */
-export const RECORD_TYPES: DnsRecordType[] = ["A", "AAAA", "CNAME", "MX", "TXT", "NS", "SRV", "CAA"]
+export const RECORD_TYPES: DnsRecordType[] = ["A", "AAAA", "CNAME", "MX", "TXT", "NS", "SRV", "CAA", "ALIAS", "TLSA", "SSHFP", "DS"]
 
 /** DNS 记录数据 - 类型安全的多态表示 */
 export type RecordData =
@@ -14,6 +14,18 @@ export type RecordData =
   | { type: "NS"; content: { nameserver: string } }
   | { type: "SRV"; content: { priority: number; weight: number; port: number; target: string } }
   | { type: "CAA"; content: { flags: number; tag: string; value: string } }
+  | { type: "ALIAS"; content: { target: string } }
+  | {
+      type: "TLSA"
+      content: {
+        usage: number
+        selector: number
+        matchingType: number
+        certificateAssociation: string
+      }
+    }
+  | { type: "SSHFP"; content: { algorithm: number; fingerprintType: number; fingerprint: string } }
+  | { type: "DS"; content: { keyTag: number; algorithm: number; digestType: number; digest: string } }
 
 /** DNS 记录 */
 export interface DnsRecord {
@@ -23,10 +35,23 @@ export interface DnsRecord {
   ttl: number
   data: RecordData
   proxied?: boolean
+  /** 记录备注（仅支持的服务商，如 Cloudflare） */
+  comment?: string
+  /** 记录标签（仅支持的服务商，如 Cloudflare） */
+  tags?: string[]
   createdAt?: string
   updatedAt?: string
+  /** 路由策略（仅支持的服务商，如 Route 53） */
+  routingPolicy?: RoutingPolicy
 }
 
+/** 路由策略（仅 Route 53 等服务商支持非 Simple 策略） */
+export type RoutingPolicy =
+  | { type: "Simple" }
+  | { type: "Weighted"; content: { weight: number } }
+  | { type: "Latency"; content: { region: string } }
+  | { type: "Geo"; content: { continent?: string; country?: string } }
+
 /** 创建 DNS 记录请求 */
 export interface CreateDnsRecordRequest {
   domainId: string
@@ -34,6 +59,9 @@ export interface CreateDnsRecordRequest {
   ttl: number
   data: RecordData
   proxied?: boolean
+  comment?: string
+  tags?: string[]
+  routingPolicy?: RoutingPolicy
 }
 
 /** 更新 DNS 记录请求 */
@@ -43,6 +71,8 @@ export interface UpdateDnsRecordRequest {
   ttl: number
   data: RecordData
   proxied?: boolean
+  comment?: string
+  tags?: string[]
 }
 
 /** 批量删除请求 */
@@ -51,6 +81,22 @@ export interface BatchDeleteRequest {
   recordIds: string[]
 }
 
+/** 批量创建结果 */
+export interface BatchCreateResult {
+  successCount: number
+  failedCount: number
+  createdRecords: DnsRecord[]
+  failures: BatchCreateFailure[]
+}
+
+/** 批量创建失败项 */
+export interface BatchCreateFailure {
+  /** 对应请求在列表中的下标 */
+  requestIndex: number
+  recordName: string
+  reason: string
+}
+
 /** 批量删除结果 */
 export interface BatchDeleteResult {
   successCount: number
@@ -64,6 +110,43 @@ export interface BatchDeleteFailure {
   reason: string
 }
 
+/** DNS 记录导出格式 */
+export type RecordExportFormat = "bind" | "json" | "csv"
+
+/** DNS 记录导出结果 */
+export interface RecordExport {
+  /** 区域名称 */
+  origin: string
+  recordCount: number
+  content: string
+  suggestedFilename: string
+}
+
+/** 导入记录与已有记录（名称 + 类型相同）冲突时的处理策略 */
+export type RecordMergeStrategy = "skipExisting" | "overwrite"
+
+/** 单条记录的导入结果 */
+export type RecordImportAction = "created" | "updated" | "skipped" | "failed"
+
+/** 单条记录的导入明细 */
+export interface RecordImportOutcome {
+  name: string
+  recordType: DnsRecordType
+  value: string
+  action: RecordImportAction
+  recordId?: string
+  reason?: string
+}
+
+/** DNS 记录导入结果 */
+export interface RecordImportResult {
+  createdCount: number
+  updatedCount: number
+  skippedCount: number
+  failedCount: number
+  outcomes: RecordImportOutcome[]
+}
+
 /** 常用 TTL 选项 */
 export const TTL_OPTIONS = [
   { value: 1, labelKey: "dns.ttlAuto" },
@@ -89,4 +172,8 @@ export const RECORD_TYPE_INFO: Record<DnsRecordType, { descriptionKey: string; e
     NS: { descriptionKey: "dns.recordTypes.NS", example: "ns1.example.com" },
     SRV: { descriptionKey: "dns.recordTypes.SRV", example: "sip.example.com" },
     CAA: { descriptionKey: "dns.recordTypes.CAA", example: "letsencrypt.org" },
+    ALIAS: { descriptionKey: "dns.recordTypes.ALIAS", example: "app.example.net" },
+    TLSA: { descriptionKey: "dns.recordTypes.TLSA", example: "e3b0c44298fc1c149afbf4c8996fb924..." },
+    SSHFP: { descriptionKey: "dns.recordTypes.SSHFP", example: "123456789abcdef67890123456789abcdef67890" },
+    DS: { descriptionKey: "dns.recordTypes.DS", example: "2bb183af5f22588179a53b0a98631fad1a292118..." },
   }
@@ -17,6 +17,7 @@ export interface ApiResponse<T> {
 export type DnsErrorCode =
   | "ProviderNotFound"
   | "AccountNotFound"
+  | "DuplicateAccount" // 已存在使用相同凭证的账号
   | "DomainNotFound"
   | "RecordNotFound"
   | "CredentialError"
@@ -28,6 +29,8 @@ export type DnsErrorCode =
   | "ImportExportError"
   | "NoAccountsSelected" // 导出时没有选中账号
   | "UnsupportedFileVersion" // 导入时版本不支持
+  | "RateLimited" // 远端服务限流（如 WHOIS）
+  | "Timeout" // 操作超时（工具箱查询）
   | "Provider" // ProviderError 变体
 
 /** 凭证验证错误详情 */
@@ -43,12 +46,18 @@ export type ProviderErrorCode =
   | "RecordExists"
   | "RecordNotFound"
   | "InvalidParameter"
+  | "UnsupportedOperation"
   | "QuotaExceeded"
+  | "RateLimited"
   | "DomainNotFound"
   | "DomainLocked"
   | "PermissionDenied"
+  | "ServerFailure"
+  | "Refused"
+  | "Cancelled"
   | "ParseError"
   | "SerializationError"
+  | "ApiError"
   | "Unknown"
 
 /** Provider 错误详情（根据 code 不同，结构不同） */
@@ -73,12 +82,29 @@ export type ProviderErrorDetails =
       param: string
       detail: string
     }
+  | { code: "UnsupportedOperation"; provider: string; operation: string }
   | { code: "QuotaExceeded"; provider: string; raw_message?: string }
+  | {
+      code: "RateLimited"
+      provider: string
+      retry_after_secs?: number
+      raw_message?: string
+    }
   | { code: "DomainNotFound"; provider: string; domain: string; raw_message?: string }
   | { code: "DomainLocked"; provider: string; domain: string; raw_message?: string }
   | { code: "PermissionDenied"; provider: string; raw_message?: string }
+  | { code: "ServerFailure"; provider: string; raw_message?: string }
+  | { code: "Refused"; provider: string; raw_message?: string }
+  | { code: "Cancelled"; provider: string; operation: string }
   | { code: "ParseError"; provider: string; detail: string }
   | { code: "SerializationError"; provider: string; detail: string }
+  | {
+      code: "ApiError"
+      provider: string
+      status: number
+      provider_code?: string
+      message: string
+    }
   | {
       code: "Unknown"
       provider: string
@@ -100,6 +126,8 @@ export interface ApiError {
 export interface PaginationParams {
   page: number
   pageSize: number
+  /** 游标（上一页的 nextCursor），仅游标分页的服务商使用 */
+  cursor?: string
 }
 
 /** 分页响应 */
@@ -109,4 +137,6 @@ export interface PaginatedResponse<T> {
   pageSize: number
   totalCount: number
   hasMore: boolean
+  /** 下一页游标，仅游标分页的服务商返回 */
+  nextCursor?: string
 }
@@ -11,14 +11,30 @@ export interface ProviderCredentialField {
 export interface ProviderFeatures {
   /** 是否支持代理功能 (如 Cloudflare 的 CDN 代理) */
   proxy: boolean
+  /** 是否支持记录备注与标签 (comment / tags) */
+  recordMetadata: boolean
+  /** 是否支持非 Simple 的路由策略 (如 Route 53 的加权、延迟、地理位置路由) */
+  routingPolicy: boolean
 }
 
-/** 提供商分页限制 */
+/** 提供商分页与速率限制 */
 export interface ProviderLimits {
   /** 域名列表的最大分页大小 */
   maxPageSizeDomains: number
   /** DNS 记录列表的最大分页大小 */
   maxPageSizeRecords: number
+  /** 官方文档公布的 API 速率限制（每秒请求数），null 表示未公布 */
+  requestsPerSecond: number | null
+  /** 批量操作单次请求的最大记录数（无原生批量 API 时为 1） */
+  maxBatchSize: number
+  /** 单个区域的最大记录数，null 表示未公布或取决于套餐 */
+  maxRecordsPerZone: number | null
+  /** 允许的最小 TTL（秒） */
+  minTtl: number
+  /** 允许的最大 TTL（秒） */
+  maxTtl: number
+  /** 表示「自动」的特殊 TTL 值（如 Cloudflare 的 1），null 表示无 */
+  autoTtl: number | null
 }
 
 /** 提供商信息 (从后端获取) */
@@ -8,6 +8,8 @@ export interface WhoisResult {
   nameServers: string[]
   status: string[]
   raw: string
+  /** 是否已注册（未注册的域名可以注册） */
+  isRegistered: boolean
 }
 
 /** DNS 查询记录 */
@@ -17,6 +19,17 @@ export interface DnsLookupRecord {
   value: string
   ttl: number
   priority?: number
+  /** CAA 结构化数据（仅 CAA 记录） */
+  caa?: CaaRecord
+}
+
+/** CAA 记录结构化数据 */
+export interface CaaRecord {
+  /** 标志位（128 表示 critical） */
+  flags: number
+  /** 标签: issue / issuewild / iodef 等 */
+  tag: string
+  value: string
 }
 
 /** DNS 查询结果（包含 nameserver 信息） */
@@ -25,6 +38,8 @@ export interface DnsLookupResult {
   nameserver: string
   /** 查询记录列表 */
   records: DnsLookupRecord[]
+  /** 域名不存在（NXDOMAIN），此时 records 为空 */
+  nxdomain: boolean
 }
 
 /** IP 地理位置信息 */
@@ -65,6 +80,8 @@ export interface SslCertInfo {
   daysRemaining: number
   isExpired: boolean
   isValid: boolean
+  /** 证书 SAN 是否覆盖查询的域名 */
+  hostnameMatches: boolean
   san: string[]
   serialNumber: string
   signatureAlgorithm: string
@@ -76,6 +93,16 @@ export interface CertChainItem {
   subject: string
   issuer: string
   isCa: boolean
+  /** 是否为自签名证书 */
+  selfSigned: boolean
+  /** 是否带有名称约束扩展 */
+  nameConstrained: boolean
+  /** 密钥用途，如 digitalSignature、keyCertSign */
+  keyUsage: string[]
+  /** 扩展密钥用途，如 serverAuth；未知用途以 OID 表示 */
+  extendedKeyUsage: string[]
+  /** 证书原文（PEM 格式） */
+  pem: string
 }
 
 /** SSL 检查结果（包含连接状态） */
@@ -130,6 +157,16 @@ export interface HttpHeaderCheckResult {
   contentLength?: number
   rawRequest: string
   rawResponse: string
+  /** 各阶段耗时 */
+  timing: HttpTiming
+}
+
+/** HTTP 请求各阶段耗时（毫秒），无法测得的阶段为 null */
+export interface HttpTiming {
+  dnsMs: number | null
+  tcpConnectMs: number | null
+  tlsHandshakeMs: number | null
+  ttfbMs: number | null
 }
 
 /** 查询历史项 */
@@ -152,7 +189,15 @@ export const DNS_RECORD_TYPES = [
   "SOA",
   "SRV",
   "CAA",
+  "TLSA",
   "PTR",
+  "HTTPS",
+  "SVCB",
+  "URI",
+  "LOC",
+  "HINFO",
+  "SMIMEA",
+  "OPENPGPKEY",
   "ALL",
 ] as const
 
@@ -183,6 +228,8 @@ export interface DnsPropagationResult {
   totalTimeMs: number
   consistencyPercentage: number
   uniqueValues: string[]
+  /** 按应答内容分组的服务器：[应答记录集, 服务器名称列表]，服务器多的分组在前 */
+  groupedAnswers: [string, string[]][]
 }
 
 /** DNSSEC DNSKEY 记录 */